@@ -13,7 +13,7 @@ use crate::tag_utils::SwfSlice;
 
 #[cfg(test)]
 #[macro_use]
-mod test_utils;
+pub(crate) mod test_utils;
 
 #[macro_use]
 pub mod function;
@@ -12,7 +12,7 @@ use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, MovieClip, TDisplayObject, TDisplayObjectContainer};
 use crate::ecma_conversions::f64_to_wrapping_u32;
 use crate::string::{AvmString, WStr, WString};
-use crate::tag_utils::SwfSlice;
+use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::vminterface::Instantiator;
 use crate::{avm_error, avm_warn};
 use gc_arena::{Gc, GcCell, MutationContext};
@@ -23,6 +23,7 @@ use smallvec::SmallVec;
 use std::borrow::Cow;
 use std::cell::{Ref, RefMut};
 use std::fmt;
+use std::sync::Arc;
 use swf::avm1::read::Reader;
 use swf::avm1::types::*;
 use url::form_urlencoded;
@@ -1156,6 +1157,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                     } else {
                         let future = self.context.load_manager.load_movie_into_clip(
                             self.context.player.clone().unwrap(),
+                            self.base_movie(),
                             level,
                             &url,
                             RequestOptions::get(),
@@ -1255,6 +1257,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                     );
                     let future = self.context.load_manager.load_form_into_object(
                         self.context.player.clone().unwrap(),
+                        self.base_movie(),
                         target_obj,
                         &url,
                         opts,
@@ -1279,6 +1282,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 } else {
                     let future = self.context.load_manager.load_movie_into_clip(
                         self.context.player.clone().unwrap(),
+                        self.base_movie(),
                         clip_target,
                         &url,
                         opts,
@@ -1294,6 +1298,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             if let Some(clip_target) = clip_target {
                 let future = self.context.load_manager.load_movie_into_clip(
                     self.context.player.clone().unwrap(),
+                    self.base_movie(),
                     clip_target,
                     &url.to_utf8_lossy(),
                     RequestOptions::get(),
@@ -2818,6 +2823,14 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         self.base_clip
     }
 
+    /// Gets the movie that contains the executing bytecode.
+    /// Loads are made in the security sandbox of this movie.
+    pub fn base_movie(&self) -> Arc<SwfMovie> {
+        self.base_clip
+            .movie()
+            .unwrap_or_else(|| self.context.swf.clone())
+    }
+
     /// Gets the current target clip of this stack frame.
     /// This is the movie clip to which `GotoFrame` and other actions apply.
     /// Changed via `ActionSetTarget`/`ActionSetTarget2`.
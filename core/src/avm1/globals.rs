@@ -35,11 +35,14 @@ pub mod gradient_bevel_filter;
 pub mod gradient_glow_filter;
 mod key;
 mod load_vars;
+mod local_connection;
 mod math;
 mod matrix;
 pub(crate) mod mouse;
 pub(crate) mod movie_clip;
 mod movie_clip_loader;
+mod net_connection;
+mod net_stream;
 pub(crate) mod number;
 mod object;
 mod point;
@@ -49,6 +52,7 @@ pub(crate) mod shared_object;
 mod sound;
 mod stage;
 pub(crate) mod string;
+mod style_sheet;
 pub(crate) mod system;
 pub(crate) mod system_capabilities;
 pub(crate) mod system_ime;
@@ -59,6 +63,7 @@ mod transform;
 mod video;
 mod xml;
 mod xml_node;
+mod xml_socket;
 
 const GLOBAL_DECLS: &[Declaration] = declare_properties! {
     "trace" => method(trace; DONT_ENUM);
@@ -348,16 +353,15 @@ pub fn create_timer<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     // `setInterval` was added in Flash Player 6 but is not version-gated.
     use crate::avm1::timer::TimerCallback;
-    let (callback, i) = match args.get(0) {
-        Some(Value::Object(o)) if o.as_executable().is_some() => (TimerCallback::Function(*o), 1),
+    let (func, method_name, i) = match args.get(0) {
+        Some(Value::Object(o)) if o.as_executable().is_some() => (*o, None, 1),
         Some(Value::Object(o)) => (
-            TimerCallback::Method {
-                this: *o,
-                method_name: args
-                    .get(1)
+            *o,
+            Some(
+                args.get(1)
                     .unwrap_or(&Value::Undefined)
                     .coerce_to_string(activation)?,
-            },
+            ),
             2,
         ),
         _ => return Ok(Value::Undefined),
@@ -373,10 +377,19 @@ pub fn create_timer<'gc>(
         vec![]
     };
 
+    let callback = match method_name {
+        Some(method_name) => TimerCallback::Avm1Method {
+            this: func,
+            method_name,
+            params,
+        },
+        None => TimerCallback::Avm1Function { func, params },
+    };
+
     let id = activation
         .context
         .timers
-        .add_timer(callback, interval, params, is_timeout);
+        .add_timer(callback, interval, is_timeout);
 
     Ok(id.into())
 }
@@ -597,6 +610,7 @@ pub fn create_globals<'gc>(
 
     let text_field_proto = text_field::create_proto(gc_context, object_proto, function_proto);
     let text_format_proto = text_format::create_proto(gc_context, object_proto, function_proto);
+    let style_sheet_proto = style_sheet::create_proto(gc_context, object_proto, function_proto);
 
     let array_proto = array::create_proto(gc_context, object_proto, function_proto);
 
@@ -612,6 +626,12 @@ pub fn create_globals<'gc>(
     let number_proto = number::create_proto(gc_context, object_proto, function_proto);
     let boolean_proto = boolean::create_proto(gc_context, object_proto, function_proto);
     let load_vars_proto = load_vars::create_proto(gc_context, object_proto, function_proto);
+    let net_connection_proto =
+        net_connection::create_proto(gc_context, object_proto, function_proto);
+    let net_stream_proto = net_stream::create_proto(gc_context, object_proto, function_proto);
+    let xml_socket_proto = xml_socket::create_proto(gc_context, object_proto, function_proto);
+    let local_connection_proto =
+        local_connection::create_proto(gc_context, object_proto, function_proto);
     let matrix_proto = matrix::create_proto(gc_context, object_proto, function_proto);
     let point_proto = point::create_proto(gc_context, object_proto, function_proto);
     let rectangle_proto = rectangle::create_proto(gc_context, object_proto, function_proto);
@@ -685,6 +705,34 @@ pub fn create_globals<'gc>(
         Some(function_proto),
         load_vars_proto,
     );
+    let net_connection = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(net_connection::constructor),
+        constructor_to_fn!(net_connection::constructor),
+        Some(function_proto),
+        net_connection_proto,
+    );
+    let net_stream = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(net_stream::constructor),
+        constructor_to_fn!(net_stream::constructor),
+        Some(function_proto),
+        net_stream_proto,
+    );
+    let xml_socket = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(xml_socket::constructor),
+        constructor_to_fn!(xml_socket::constructor),
+        Some(function_proto),
+        xml_socket_proto,
+    );
+    let local_connection = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(local_connection::constructor),
+        constructor_to_fn!(local_connection::constructor),
+        Some(function_proto),
+        local_connection_proto,
+    );
     let movie_clip = FunctionObject::constructor(
         gc_context,
         Executable::Native(movie_clip::constructor),
@@ -714,6 +762,19 @@ pub fn create_globals<'gc>(
         Some(function_proto),
         text_format_proto,
     );
+    let style_sheet = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(style_sheet::constructor),
+        constructor_to_fn!(style_sheet::constructor),
+        Some(function_proto),
+        style_sheet_proto,
+    );
+    text_field.define_value(
+        gc_context,
+        "StyleSheet",
+        style_sheet.into(),
+        Attribute::DONT_ENUM,
+    );
     let array = array::create_array_object(gc_context, array_proto, function_proto);
     let xmlnode = FunctionObject::constructor(
         gc_context,
@@ -1002,6 +1063,18 @@ pub fn create_globals<'gc>(
         load_vars.into(),
         Attribute::DONT_ENUM,
     );
+    globals.define_value(
+        gc_context,
+        "NetConnection",
+        net_connection.into(),
+        Attribute::DONT_ENUM,
+    );
+    globals.define_value(
+        gc_context,
+        "NetStream",
+        net_stream.into(),
+        Attribute::DONT_ENUM,
+    );
     globals.define_value(
         gc_context,
         "MovieClip",
@@ -1029,6 +1102,18 @@ pub fn create_globals<'gc>(
     );
     globals.define_value(gc_context, "XMLNode", xmlnode.into(), Attribute::DONT_ENUM);
     globals.define_value(gc_context, "XML", xml.into(), Attribute::DONT_ENUM);
+    globals.define_value(
+        gc_context,
+        "XMLSocket",
+        xml_socket.into(),
+        Attribute::DONT_ENUM,
+    );
+    globals.define_value(
+        gc_context,
+        "LocalConnection",
+        local_connection.into(),
+        Attribute::DONT_ENUM,
+    );
     globals.define_value(gc_context, "String", string.into(), Attribute::DONT_ENUM);
     globals.define_value(gc_context, "Number", number.into(), Attribute::DONT_ENUM);
     globals.define_value(gc_context, "Boolean", boolean.into(), Attribute::DONT_ENUM);
@@ -3,14 +3,25 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::function::{Executable, FunctionObject};
+use crate::avm1::globals::bitmap_filter::avm1_to_filter;
+use crate::avm1::globals::color_transform::object_to_color_transform;
+use crate::avm1::globals::display_object::value_to_blend_mode;
+use crate::avm1::globals::matrix::object_to_matrix;
 use crate::avm1::object::bitmap_data::BitmapDataObject;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Object, TObject, Value};
-use crate::bitmap::bitmap_data::{BitmapData, ChannelOptions, Color};
+use crate::avm_warn;
+use crate::bitmap::bitmap_data::{
+    BitmapData, ChannelOptions, Color, IBitmapDrawable, ThresholdOperation,
+};
 use crate::bitmap::is_size_valid;
 use crate::character::Character;
+use crate::color_transform::ColorTransform;
 use crate::display_object::TDisplayObject;
+use crate::matrix::Matrix;
+use crate::transform::Transform;
 use gc_arena::{GcCell, MutationContext};
+use swf::BlendMode;
 
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "height" => property(height);
@@ -484,22 +495,154 @@ pub fn noise<'gc>(
 }
 
 pub fn apply_filter<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    log::warn!("BitmapData.applyFilter - not yet implemented");
+    if let Some(bitmap_data) = this.as_bitmap_data_object() {
+        if !bitmap_data.disposed() {
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+            let filter = match args.get(3) {
+                Some(Value::Object(filter)) => avm1_to_filter(*filter),
+                _ => None,
+            };
+
+            if let (Some(src_bitmap), Some(filter)) =
+                (source_bitmap.as_bitmap_data_object(), filter)
+            {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .apply_filter(source_bitmap_ref, source_rect, dest_point, &filter);
+
+                    return Ok(0.into());
+                }
+            }
+        }
+    }
+
     Ok((-1).into())
 }
 
 pub fn draw<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.draw - not yet implemented");
+            let source = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            let source = if let Some(source_object) = source.as_display_object() {
+                let movie = activation.base_movie();
+                if let Some(other) = activation
+                    .context
+                    .security
+                    .find_inaccessible_movie(&movie, source_object)
+                {
+                    avm_warn!(
+                        activation,
+                        "BitmapData.draw: {} cannot access {}",
+                        movie.url().unwrap_or_default(),
+                        other.url().unwrap_or_default()
+                    );
+                    return Ok(Value::Undefined);
+                }
+                IBitmapDrawable::DisplayObject(source_object)
+            } else if let Some(source_bitmap) = source.as_bitmap_data_object() {
+                if source_bitmap.disposed() {
+                    return Ok(Value::Undefined);
+                }
+
+                // Drawing a bitmap into itself must draw from a copy.
+                let source_data = source_bitmap.bitmap_data();
+                if GcCell::ptr_eq(source_data, bitmap_data.bitmap_data()) {
+                    let copy = source_data.read().clone();
+                    IBitmapDrawable::BitmapData(GcCell::allocate(
+                        activation.context.gc_context,
+                        copy,
+                    ))
+                } else {
+                    IBitmapDrawable::BitmapData(source_data)
+                }
+            } else {
+                return Ok(Value::Undefined);
+            };
+
+            let matrix = match args.get(1) {
+                Some(Value::Object(matrix)) => object_to_matrix(*matrix, activation)?,
+                _ => Matrix::IDENTITY,
+            };
+
+            let color_transform = match args.get(2) {
+                Some(Value::Object(color_transform)) => {
+                    object_to_color_transform(*color_transform, activation)?
+                }
+                _ => ColorTransform::default(),
+            };
+
+            let blend_mode = match args.get(3) {
+                Some(value) => value_to_blend_mode(activation, *value)?.unwrap_or_default(),
+                None => BlendMode::Normal,
+            };
+
+            let clip_rect = match args.get(4) {
+                Some(Value::Object(clip_rect)) => {
+                    let x = clip_rect.get("x", activation)?.coerce_to_f64(activation)? as i32;
+                    let y = clip_rect.get("y", activation)?.coerce_to_f64(activation)? as i32;
+                    let width = clip_rect
+                        .get("width", activation)?
+                        .coerce_to_f64(activation)? as i32;
+                    let height = clip_rect
+                        .get("height", activation)?
+                        .coerce_to_f64(activation)? as i32;
+                    Some((x, y, width, height))
+                }
+                _ => None,
+            };
+
+            let smoothing = args
+                .get(5)
+                .unwrap_or(&false.into())
+                .as_bool(activation.swf_version());
+
+            let transform = Transform {
+                matrix,
+                color_transform,
+            };
+
+            let mc = activation.context.gc_context;
+            bitmap_data.bitmap_data().write(mc).draw(
+                source,
+                transform,
+                smoothing,
+                blend_mode,
+                clip_rect,
+                &mut activation.context,
+            );
+
             return Ok(Value::Undefined);
         }
     }
@@ -508,14 +651,27 @@ pub fn draw<'gc>(
 }
 
 pub fn generate_filter_rect<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.generateFilterRect - not yet implemented");
-            return Ok(Value::Undefined);
+            let source_rect = rectangle_arg(activation, args.get(0))?;
+            let filter = match args.get(1) {
+                Some(Value::Object(filter)) => avm1_to_filter(*filter),
+                _ => None,
+            };
+
+            let (x, y, width, height) = match filter {
+                Some(filter) => BitmapData::generate_filter_rect(source_rect, &filter),
+                None => source_rect,
+            };
+            let proto = activation.context.avm1.prototypes.rectangle_constructor;
+            return proto.construct(
+                activation,
+                &[x.into(), y.into(), width.into(), height.into()],
+            );
         }
     }
 
@@ -678,14 +834,78 @@ pub fn perlin_noise<'gc>(
 }
 
 pub fn hit_test<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.hitTest - not yet implemented");
-            return Ok(Value::Undefined);
+            let (first_x, first_y) = point_arg(activation, args.get(0))?;
+            let first_alpha_threshold = args
+                .get(1)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_u32(activation)?
+                .min(255) as u8;
+            let second_object = args
+                .get(2)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+
+            if let Some(second_bitmap) = second_object.as_bitmap_data_object() {
+                if second_bitmap.disposed() {
+                    return Ok((-1).into());
+                }
+
+                let (second_x, second_y) = point_arg(activation, args.get(3))?;
+                let second_alpha_threshold = args
+                    .get(4)
+                    .unwrap_or(&1.into())
+                    .coerce_to_u32(activation)?
+                    .min(255) as u8;
+
+                let test_point = (second_x - first_x, second_y - first_y);
+                let first_bitmap = bitmap_data.bitmap_data();
+                let second_bitmap = second_bitmap.bitmap_data();
+                let result = if GcCell::ptr_eq(first_bitmap, second_bitmap) {
+                    let read = first_bitmap.read();
+                    read.hit_test_bitmap(
+                        first_alpha_threshold,
+                        &read,
+                        test_point,
+                        second_alpha_threshold,
+                    )
+                } else {
+                    first_bitmap.read().hit_test_bitmap(
+                        first_alpha_threshold,
+                        &second_bitmap.read(),
+                        test_point,
+                        second_alpha_threshold,
+                    )
+                };
+
+                return Ok(result.into());
+            }
+
+            let constructor = activation.context.avm1.prototypes.rectangle_constructor;
+            let proto = activation.context.avm1.prototypes.rectangle;
+            if second_object.is_instance_of(activation, constructor, proto)? {
+                let (x, y, width, height) = rectangle_arg(activation, Some(&second_object.into()))?;
+                let result = bitmap_data.bitmap_data().read().hit_test_rectangle(
+                    first_alpha_threshold,
+                    (x - first_x, y - first_y),
+                    (width, height),
+                );
+
+                return Ok(result.into());
+            }
+
+            let (x, y) = point_arg(activation, Some(&second_object.into()))?;
+            let result = bitmap_data
+                .bitmap_data()
+                .read()
+                .hit_test_point(first_alpha_threshold, (x - first_x, y - first_y));
+
+            return Ok(result.into());
         }
     }
 
@@ -1007,14 +1227,54 @@ pub fn palette_map<'gc>(
 }
 
 pub fn pixel_dissolve<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.pixelDissolve - not yet implemented");
-            return Ok(Value::Undefined);
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+            let random_seed = args.get(3).unwrap_or(&0.into()).coerce_to_i32(activation)?;
+            let num_pixels = match args.get(4) {
+                Some(num_pixels) => num_pixels.coerce_to_i32(activation)?,
+                // By default, a thirtieth of the rectangle is dissolved.
+                None => source_rect.2.saturating_mul(source_rect.3) / 30,
+            };
+            let fill_color = args.get(5).unwrap_or(&0.into()).coerce_to_u32(activation)?;
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let read;
+                    let source: Option<&BitmapData> =
+                        if GcCell::ptr_eq(src_bitmap_data_cell, bitmap_data.bitmap_data()) {
+                            None
+                        } else {
+                            read = src_bitmap_data_cell.read();
+                            Some(&read)
+                        };
+
+                    let seed = bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .pixel_dissolve(
+                            source,
+                            source_rect,
+                            dest_point,
+                            random_seed,
+                            num_pixels,
+                            fill_color,
+                        );
+
+                    return Ok(seed.into());
+                }
+            }
         }
     }
 
@@ -1050,14 +1310,72 @@ pub fn scroll<'gc>(
 }
 
 pub fn threshold<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(bitmap_data) = this.as_bitmap_data_object() {
         if !bitmap_data.disposed() {
-            log::warn!("BitmapData.threshold - not yet implemented");
-            return Ok(Value::Undefined);
+            let source_bitmap = args
+                .get(0)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_object(activation);
+            let source_rect = rectangle_arg(activation, args.get(1))?;
+            let dest_point = point_arg(activation, args.get(2))?;
+            let operation = args
+                .get(3)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_string(activation)?;
+            let operation = match ThresholdOperation::from_wstr(&operation) {
+                Some(operation) => operation,
+                None => return Ok(0.into()),
+            };
+            let threshold = args
+                .get(4)
+                .unwrap_or(&Value::Undefined)
+                .coerce_to_u32(activation)?;
+            let color = args.get(5).unwrap_or(&0.into()).coerce_to_u32(activation)?;
+            let mask = args
+                .get(6)
+                .unwrap_or(&0xFFFFFFFFu32.into())
+                .coerce_to_u32(activation)?;
+            let copy_source = args
+                .get(7)
+                .unwrap_or(&false.into())
+                .as_bool(activation.swf_version());
+
+            if let Some(src_bitmap) = source_bitmap.as_bitmap_data_object() {
+                if !src_bitmap.disposed() {
+                    // dealing with object aliasing...
+                    let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+                    let src_bitmap_data_cell = src_bitmap.bitmap_data();
+                    let src_bitmap_gc_ref; // only initialized if source is a different object than self
+                    let source_bitmap_ref = // holds the reference to either of the ones above
+                        if GcCell::ptr_eq(src_bitmap.bitmap_data(), bitmap_data.bitmap_data()) {
+                            src_bitmap_clone = src_bitmap_data_cell.read().clone();
+                            &src_bitmap_clone
+                        } else {
+                            src_bitmap_gc_ref = src_bitmap_data_cell.read();
+                            &src_bitmap_gc_ref
+                        };
+
+                    let modified_count = bitmap_data
+                        .bitmap_data()
+                        .write(activation.context.gc_context)
+                        .threshold(
+                            source_bitmap_ref,
+                            source_rect,
+                            dest_point,
+                            operation,
+                            threshold,
+                            color,
+                            mask,
+                            copy_source,
+                        );
+
+                    return Ok(modified_count.into());
+                }
+            }
         }
     }
 
@@ -1126,6 +1444,38 @@ pub fn compare<'gc>(
     }
 }
 
+/// Read the `x`, `y`, `width` and `height` of a rectangle argument.
+fn rectangle_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Option<&Value<'gc>>,
+) -> Result<(i32, i32, i32, i32), Error<'gc>> {
+    let rectangle = value
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_object(activation);
+    let x = rectangle.get("x", activation)?.coerce_to_f64(activation)? as i32;
+    let y = rectangle.get("y", activation)?.coerce_to_f64(activation)? as i32;
+    let width = rectangle
+        .get("width", activation)?
+        .coerce_to_f64(activation)? as i32;
+    let height = rectangle
+        .get("height", activation)?
+        .coerce_to_f64(activation)? as i32;
+    Ok((x, y, width, height))
+}
+
+/// Read the `x` and `y` of a point argument.
+fn point_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Option<&Value<'gc>>,
+) -> Result<(i32, i32), Error<'gc>> {
+    let point = value
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_object(activation);
+    let x = point.get("x", activation)?.coerce_to_f64(activation)? as i32;
+    let y = point.get("y", activation)?.coerce_to_f64(activation)? as i32;
+    Ok((x, y))
+}
+
 pub fn create_proto<'gc>(
     gc_context: MutationContext<'gc, '_>,
     proto: Object<'gc>,
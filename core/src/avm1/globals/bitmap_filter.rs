@@ -2,9 +2,12 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::object::bevel_filter::BevelFilterType;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
-use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::avm1::{ArrayObject, Object, ScriptObject, TObject, Value};
+use crate::display_object::TDisplayObject;
 use gc_arena::MutationContext;
+use swf::{Color, Filter, Fixed16, Fixed8, GradientRecord};
 
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "clone" => method(clone);
@@ -24,7 +27,14 @@ pub fn clone<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this.as_blur_filter_object() {
-        let proto = activation.context.avm1.prototypes.blur_filter_constructor;
+        let proto = activation
+            .context
+            .avm1
+            .activation
+            .context
+            .avm1
+            .prototypes()
+            .blur_filter_constructor;
 
         let blur_x = this.get("blurX", activation)?;
         let blur_y = this.get("blurY", activation)?;
@@ -35,7 +45,14 @@ pub fn clone<'gc>(
     }
 
     if let Some(this) = this.as_bevel_filter_object() {
-        let proto = activation.context.avm1.prototypes.bevel_filter_constructor;
+        let proto = activation
+            .context
+            .avm1
+            .activation
+            .context
+            .avm1
+            .prototypes()
+            .bevel_filter_constructor;
 
         let distance = this.get("distance", activation)?;
         let angle = this.get("angle", activation)?;
@@ -71,7 +88,14 @@ pub fn clone<'gc>(
     }
 
     if let Some(this) = this.as_glow_filter_object() {
-        let proto = activation.context.avm1.prototypes.glow_filter_constructor;
+        let proto = activation
+            .context
+            .avm1
+            .activation
+            .context
+            .avm1
+            .prototypes()
+            .glow_filter_constructor;
 
         let color = this.get("color", activation)?;
         let alpha = this.get("alpha", activation)?;
@@ -272,6 +296,410 @@ pub fn clone<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Convert an AVM1 filter object into the filter that it describes.
+///
+/// Returns `None` for objects that aren't filters, and for filters that can't
+/// be placed on a display object.
+pub fn avm1_to_filter(object: Object<'_>) -> Option<Filter> {
+    if let Some(filter) = object.as_blur_filter_object() {
+        return Some(Filter::BlurFilter(Box::new(swf::BlurFilter {
+            blur_x: Fixed16::from_f64(filter.blur_x()),
+            blur_y: Fixed16::from_f64(filter.blur_y()),
+            num_passes: num_passes(filter.quality()),
+        })));
+    }
+
+    if let Some(filter) = object.as_glow_filter_object() {
+        return Some(Filter::GlowFilter(Box::new(swf::GlowFilter {
+            color: color(filter.color() as u32, filter.alpha()),
+            blur_x: Fixed16::from_f64(filter.blur_x()),
+            blur_y: Fixed16::from_f64(filter.blur_y()),
+            strength: Fixed8::from_f64(filter.strength()),
+            is_inner: filter.inner(),
+            is_knockout: filter.knockout(),
+            num_passes: num_passes(filter.quality()),
+        })));
+    }
+
+    if let Some(filter) = object.as_drop_shadow_filter_object() {
+        return Some(Filter::DropShadowFilter(Box::new(swf::DropShadowFilter {
+            color: color(filter.color(), filter.alpha()),
+            blur_x: Fixed16::from_f64(filter.blur_x()),
+            blur_y: Fixed16::from_f64(filter.blur_y()),
+            angle: Fixed16::from_f64(filter.angle().to_radians()),
+            distance: Fixed16::from_f64(filter.distance()),
+            strength: Fixed8::from_f64(filter.strength()),
+            is_inner: filter.inner(),
+            is_knockout: filter.knockout(),
+            num_passes: num_passes(filter.quality()),
+        })));
+    }
+
+    if let Some(filter) = object.as_bevel_filter_object() {
+        let (is_inner, is_on_top) = bevel_type_flags(filter.get_type());
+        return Some(Filter::BevelFilter(Box::new(swf::BevelFilter {
+            shadow_color: color(filter.shadow_color(), filter.shadow_alpha()),
+            highlight_color: color(filter.highlight_color(), filter.highlight_alpha()),
+            blur_x: Fixed16::from_f64(filter.blur_x()),
+            blur_y: Fixed16::from_f64(filter.blur_y()),
+            angle: Fixed16::from_f64(filter.angle().to_radians()),
+            distance: Fixed16::from_f64(filter.distance()),
+            strength: Fixed8::from_f64(filter.strength()),
+            is_inner,
+            is_knockout: filter.knockout(),
+            is_on_top,
+            num_passes: num_passes(filter.quality()),
+        })));
+    }
+
+    if let Some(filter) = object.as_gradient_glow_filter_object() {
+        let (is_inner, is_on_top) = bevel_type_flags(filter.get_type());
+        return Some(Filter::GradientGlowFilter(Box::new(
+            swf::GradientGlowFilter {
+                colors: gradient_records(&filter.colors(), &filter.alphas(), &filter.ratios()),
+                blur_x: Fixed16::from_f64(filter.blur_x()),
+                blur_y: Fixed16::from_f64(filter.blur_y()),
+                angle: Fixed16::from_f64(filter.angle().to_radians()),
+                distance: Fixed16::from_f64(filter.distance()),
+                strength: Fixed8::from_f64(filter.strength()),
+                is_inner,
+                is_knockout: filter.knockout(),
+                is_on_top,
+                num_passes: num_passes(filter.quality()),
+            },
+        )));
+    }
+
+    if let Some(filter) = object.as_gradient_bevel_filter_object() {
+        let (is_inner, is_on_top) = bevel_type_flags(filter.get_type());
+        return Some(Filter::GradientBevelFilter(Box::new(
+            swf::GradientBevelFilter {
+                colors: gradient_records(&filter.colors(), &filter.alphas(), &filter.ratios()),
+                blur_x: Fixed16::from_f64(filter.blur_x()),
+                blur_y: Fixed16::from_f64(filter.blur_y()),
+                angle: Fixed16::from_f64(filter.angle().to_radians()),
+                distance: Fixed16::from_f64(filter.distance()),
+                strength: Fixed8::from_f64(filter.strength()),
+                is_inner,
+                is_knockout: filter.knockout(),
+                is_on_top,
+                num_passes: num_passes(filter.quality()),
+            },
+        )));
+    }
+
+    if let Some(filter) = object.as_color_matrix_filter_object() {
+        let mut matrix = [Fixed16::ZERO; 20];
+        for (value, fixed) in filter.matrix().iter().zip(matrix.iter_mut()) {
+            *fixed = Fixed16::from_f64(*value);
+        }
+        return Some(Filter::ColorMatrixFilter(Box::new(
+            swf::ColorMatrixFilter { matrix },
+        )));
+    }
+
+    if let Some(filter) = object.as_convolution_filter_object() {
+        return Some(Filter::ConvolutionFilter(Box::new(
+            swf::ConvolutionFilter {
+                num_matrix_rows: filter.matrix_y(),
+                num_matrix_cols: filter.matrix_x(),
+                matrix: filter.matrix().into_iter().map(Fixed16::from_f64).collect(),
+                divisor: Fixed16::from_f64(filter.divisor()),
+                bias: Fixed16::from_f64(filter.bias()),
+                default_color: color(filter.color(), filter.alpha()),
+                is_clamped: filter.clamp(),
+                is_preserve_alpha: filter.preserve_alpha(),
+            },
+        )));
+    }
+
+    None
+}
+
+/// Create an AVM1 filter object that describes a filter.
+pub fn filter_to_avm1<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    filter: &Filter,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let (constructor, properties): (Object<'gc>, Vec<(&'static str, Value<'gc>)>) = match filter {
+        Filter::BlurFilter(filter) => (
+            activation.context.avm1.prototypes().blur_filter_constructor,
+            vec![
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::GlowFilter(filter) => (
+            activation.context.avm1.prototypes().glow_filter_constructor,
+            vec![
+                ("color", filter.color.to_rgb().into()),
+                ("alpha", alpha(&filter.color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                ("inner", filter.is_inner.into()),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::DropShadowFilter(filter) => (
+            activation
+                .context
+                .avm1
+                .prototypes()
+                .drop_shadow_filter_constructor,
+            vec![
+                ("color", filter.color.to_rgb().into()),
+                ("alpha", alpha(&filter.color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("angle", filter.angle.to_f64().to_degrees().into()),
+                ("distance", filter.distance.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                ("inner", filter.is_inner.into()),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::BevelFilter(filter) => (
+            activation
+                .context
+                .avm1
+                .prototypes()
+                .bevel_filter_constructor,
+            vec![
+                ("shadowColor", filter.shadow_color.to_rgb().into()),
+                ("shadowAlpha", alpha(&filter.shadow_color).into()),
+                ("highlightColor", filter.highlight_color.to_rgb().into()),
+                ("highlightAlpha", alpha(&filter.highlight_color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("angle", filter.angle.to_f64().to_degrees().into()),
+                ("distance", filter.distance.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                (
+                    "type",
+                    bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                ),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::GradientGlowFilter(filter) => {
+            let [colors, alphas, ratios] = gradient_arrays(activation, &filter.colors);
+            (
+                activation
+                    .context
+                    .avm1
+                    .prototypes()
+                    .gradient_glow_filter_constructor,
+                vec![
+                    ("colors", colors),
+                    ("alphas", alphas),
+                    ("ratios", ratios),
+                    ("blurX", filter.blur_x.to_f64().into()),
+                    ("blurY", filter.blur_y.to_f64().into()),
+                    ("angle", filter.angle.to_f64().to_degrees().into()),
+                    ("distance", filter.distance.to_f64().into()),
+                    ("strength", filter.strength.to_f64().into()),
+                    (
+                        "type",
+                        bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                    ),
+                    ("knockout", filter.is_knockout.into()),
+                    ("quality", filter.num_passes.into()),
+                ],
+            )
+        }
+        Filter::GradientBevelFilter(filter) => {
+            let [colors, alphas, ratios] = gradient_arrays(activation, &filter.colors);
+            (
+                activation
+                    .context
+                    .avm1
+                    .prototypes()
+                    .gradient_bevel_filter_constructor,
+                vec![
+                    ("colors", colors),
+                    ("alphas", alphas),
+                    ("ratios", ratios),
+                    ("blurX", filter.blur_x.to_f64().into()),
+                    ("blurY", filter.blur_y.to_f64().into()),
+                    ("angle", filter.angle.to_f64().to_degrees().into()),
+                    ("distance", filter.distance.to_f64().into()),
+                    ("strength", filter.strength.to_f64().into()),
+                    (
+                        "type",
+                        bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                    ),
+                    ("knockout", filter.is_knockout.into()),
+                    ("quality", filter.num_passes.into()),
+                ],
+            )
+        }
+        Filter::ColorMatrixFilter(filter) => {
+            let matrix = ArrayObject::new(
+                activation.context.gc_context,
+                activation.context.avm1.prototypes().array,
+                filter.matrix.iter().map(|value| value.to_f64().into()),
+            );
+            (
+                activation
+                    .context
+                    .avm1
+                    .prototypes()
+                    .color_matrix_filter_constructor,
+                vec![("matrix", matrix.into())],
+            )
+        }
+        Filter::ConvolutionFilter(filter) => {
+            let matrix = ArrayObject::new(
+                activation.context.gc_context,
+                activation.context.avm1.prototypes().array,
+                filter.matrix.iter().map(|value| value.to_f64().into()),
+            );
+            (
+                activation
+                    .context
+                    .avm1
+                    .prototypes()
+                    .convolution_filter_constructor,
+                vec![
+                    ("matrixX", filter.num_matrix_cols.into()),
+                    ("matrixY", filter.num_matrix_rows.into()),
+                    ("matrix", matrix.into()),
+                    ("divisor", filter.divisor.to_f64().into()),
+                    ("bias", filter.bias.to_f64().into()),
+                    ("preserveAlpha", filter.is_preserve_alpha.into()),
+                    ("clamp", filter.is_clamped.into()),
+                    ("color", filter.default_color.to_rgb().into()),
+                    ("alpha", alpha(&filter.default_color).into()),
+                ],
+            )
+        }
+    };
+
+    let object = constructor
+        .construct(activation, &[])?
+        .coerce_to_object(activation);
+    for (name, value) in properties {
+        object.set(name, value, activation)?;
+    }
+
+    Ok(object)
+}
+
+/// Implements the `filters` property getter of display objects.
+///
+/// A new array of new filter objects is created every time, so changes made
+/// to them only apply once they are assigned back to `filters`.
+pub fn get_filters<'gc>(
+    this: impl TDisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mut filters = Vec::new();
+    for filter in this.filters() {
+        filters.push(filter_to_avm1(activation, &filter)?.into());
+    }
+
+    Ok(ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        filters,
+    )
+    .into())
+}
+
+/// Implements the `filters` property setter of display objects.
+pub fn set_filters<'gc>(
+    this: impl TDisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let mut filters = Vec::new();
+    if let Value::Object(array) = value {
+        for i in 0..array.length(activation)? {
+            if let Value::Object(filter) = array.get_element(activation, i) {
+                filters.extend(avm1_to_filter(filter));
+            }
+        }
+    }
+
+    this.set_filters(activation.context.gc_context, filters);
+    Ok(())
+}
+
+fn num_passes(quality: i32) -> u8 {
+    quality.clamp(0, 15) as u8
+}
+
+fn color(rgb: u32, alpha: f64) -> Color {
+    Color::from_rgb(rgb, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+fn alpha(color: &Color) -> f64 {
+    f64::from(color.a) / 255.0
+}
+
+/// Convert a bevel type into the `is_inner` and `is_on_top` flags of a filter.
+fn bevel_type_flags(type_: BevelFilterType) -> (bool, bool) {
+    match type_ {
+        BevelFilterType::Inner => (true, false),
+        BevelFilterType::Outer => (false, false),
+        BevelFilterType::Full => (false, true),
+    }
+}
+
+fn bevel_type_name(is_inner: bool, is_on_top: bool) -> &'static str {
+    match (is_inner, is_on_top) {
+        (_, true) => "full",
+        (true, false) => "inner",
+        (false, false) => "outer",
+    }
+}
+
+fn gradient_records(colors: &[u32], alphas: &[f64], ratios: &[u8]) -> Vec<GradientRecord> {
+    colors
+        .iter()
+        .zip(alphas.iter())
+        .zip(ratios.iter())
+        .map(|((rgb, alpha), ratio)| GradientRecord {
+            ratio: *ratio,
+            color: color(*rgb, *alpha),
+        })
+        .collect()
+}
+
+/// Create the `colors`, `alphas` and `ratios` arrays of a gradient filter.
+fn gradient_arrays<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    records: &[GradientRecord],
+) -> [Value<'gc>; 3] {
+    let array_proto = activation.context.avm1.prototypes().array;
+    let gc_context = activation.context.gc_context;
+    [
+        ArrayObject::new(
+            gc_context,
+            array_proto,
+            records.iter().map(|r| r.color.to_rgb().into()),
+        )
+        .into(),
+        ArrayObject::new(
+            gc_context,
+            array_proto,
+            records.iter().map(|r| alpha(&r.color).into()),
+        )
+        .into(),
+        ArrayObject::new(
+            gc_context,
+            array_proto,
+            records.iter().map(|r| r.ratio.into()),
+        )
+        .into(),
+    ]
+}
+
 pub fn create_proto<'gc>(
     gc_context: MutationContext<'gc, '_>,
     proto: Object<'gc>,
@@ -2,7 +2,8 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
-use crate::avm1::globals::display_object;
+use crate::avm1::globals::bitmap_filter::{get_filters, set_filters};
+use crate::avm1::globals::display_object::{self, blend_mode, set_blend_mode};
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Object, ScriptObject, TObject, Value};
 use crate::display_object::{Avm1Button, TDisplayObject};
@@ -38,6 +39,8 @@ macro_rules! button_setter {
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "enabled" => property(button_getter!(enabled), button_setter!(set_enabled));
     "useHandCursor" => property(button_getter!(use_hand_cursor), button_setter!(set_use_hand_cursor));
+    "blendMode" => property(button_getter!(blend_mode), button_setter!(set_blend_mode));
+    "filters" => property(button_getter!(get_filters), button_setter!(set_filters));
 };
 
 pub fn create_proto<'gc>(
@@ -8,6 +8,7 @@ use crate::avm1::{Object, ScriptObject, TObject, Value};
 use crate::display_object::{DisplayObject, Lists, TDisplayObject, TDisplayObjectContainer};
 use crate::string::AvmString;
 use gc_arena::MutationContext;
+use swf::BlendMode;
 
 /// Depths used/returned by ActionScript are offset by this amount from depths used inside the SWF/by the VM.
 /// The depth of objects placed on the timeline in the Flash IDE start from 0 in the SWF,
@@ -40,6 +41,51 @@ pub fn define_display_object_proto<'gc>(
     define_properties_on(OBJECT_DECLS, gc_context, object, fn_proto);
 }
 
+/// Convert a value to a blend mode.
+///
+/// Blend modes can be given either by name or by their number in the SWF
+/// format. `None` is returned for unknown blend modes.
+pub fn value_to_blend_mode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<Option<BlendMode>, Error<'gc>> {
+    Ok(match value {
+        Value::Number(n) if (1.0..=255.0).contains(&n) && n.fract() == 0.0 => {
+            BlendMode::from_u8(n as u8)
+        }
+        Value::Number(_) => None,
+        value => value
+            .coerce_to_string(activation)?
+            .to_utf8_lossy()
+            .parse()
+            .ok(),
+    })
+}
+
+/// Implements the `blendMode` property getter of display objects.
+pub fn blend_mode<'gc>(
+    this: impl TDisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mode = AvmString::new_utf8(activation.context.gc_context, this.blend_mode().to_string());
+    Ok(mode.into())
+}
+
+/// Implements the `blendMode` property setter of display objects.
+///
+/// Unknown blend modes are ignored.
+pub fn set_blend_mode<'gc>(
+    this: impl TDisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    if let Some(mode) = value_to_blend_mode(activation, value)? {
+        this.set_blend_mode(activation.context.gc_context, mode);
+    }
+
+    Ok(())
+}
+
 pub fn get_global<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
@@ -21,12 +21,21 @@ pub fn get_available<'gc>(
     Ok(activation.context.external_interface.available().into())
 }
 
+/// Whether the calling movie's sandbox allows it to use `ExternalInterface`.
+fn is_allowed(activation: &Activation<'_, '_, '_>) -> bool {
+    let movie = activation.base_movie();
+    activation
+        .context
+        .security
+        .can_use_external_interface(&movie, activation.context.swf)
+}
+
 pub fn add_callback<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if args.len() < 3 {
+    if args.len() < 3 || !is_allowed(activation) {
         return Ok(false.into());
     }
 
@@ -53,7 +62,7 @@ pub fn call<'gc>(
     _this: Object<'gc>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if args.is_empty() {
+    if args.is_empty() || !is_allowed(activation) {
         return Ok(Value::Null);
     }
 
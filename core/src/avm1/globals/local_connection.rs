@@ -0,0 +1,139 @@
+//! `LocalConnection` impl
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::object::local_connection_object::LocalConnectionObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{AvmString, Object, TObject, Value};
+use crate::external::Value as ExternalValue;
+use crate::local_connection::{movie_domain, LocalConnectionManager};
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+    "domain" => method(domain; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `LocalConnection`
+pub fn constructor<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.into())
+}
+
+/// The domain of the movie that is running the current code.
+fn current_domain(activation: &mut Activation<'_, '_, '_>) -> String {
+    match activation.base_clip().movie() {
+        Some(movie) => movie_domain(&movie),
+        None => movie_domain(activation.context.swf),
+    }
+}
+
+/// Implements `LocalConnection.connect`.
+///
+/// Returns `false` if this object is already listening, or if another
+/// connection is already listening under the name.
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(connection) = this.as_local_connection_object() {
+        let name = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => return Ok(false.into()),
+            Some(name) => name.coerce_to_string(activation)?.to_string(),
+        };
+        if name.is_empty() || name.contains(':') || connection.handle().is_some() {
+            return Ok(false.into());
+        }
+
+        let domain = current_domain(activation);
+        if let Some(handle) =
+            LocalConnectionManager::connect(&mut activation.context, this.into(), &domain, &name)
+        {
+            connection.set_handle(activation.context.gc_context, Some(handle));
+            return Ok(true.into());
+        }
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `LocalConnection.send`.
+///
+/// Returns whether the message was sent; whether it was delivered is
+/// reported to `onStatus`.
+fn send<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (name, method) = match (args.get(0), args.get(1)) {
+        (Some(name @ Value::String(_)), Some(method @ Value::String(_))) => (
+            name.coerce_to_string(activation)?.to_string(),
+            method.coerce_to_string(activation)?.to_string(),
+        ),
+        _ => return Ok(false.into()),
+    };
+    if name.is_empty() || method.is_empty() {
+        return Ok(false.into());
+    }
+
+    let mut message_args = Vec::new();
+    for arg in args.iter().skip(2) {
+        message_args.push(ExternalValue::from_avm1(activation, *arg)?);
+    }
+
+    let domain = current_domain(activation);
+    LocalConnectionManager::send(
+        &mut activation.context,
+        this.into(),
+        &domain,
+        &name,
+        &method,
+        message_args,
+    );
+
+    Ok(true.into())
+}
+
+/// Implements `LocalConnection.close`.
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(connection) = this.as_local_connection_object() {
+        if let Some(handle) = connection.handle() {
+            LocalConnectionManager::close(&mut activation.context, handle);
+        }
+        connection.set_handle(activation.context.gc_context, None);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.domain`.
+fn domain<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let domain = current_domain(activation);
+    Ok(AvmString::new_utf8(activation.context.gc_context, domain).into())
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let local_connection = LocalConnectionObject::empty_object(gc_context, Some(proto));
+    let object = local_connection.as_script_object().unwrap();
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    local_connection.into()
+}
@@ -2,6 +2,7 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::bitmap_filter;
 use crate::avm1::globals::display_object::{self, AVM_DEPTH_BIAS, AVM_MAX_DEPTH};
 use crate::avm1::globals::matrix::gradient_object_to_matrix;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
@@ -102,6 +103,11 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "attachBitmap" => method(mc_method!(attach_bitmap); DONT_ENUM | DONT_DELETE);
     "removeMovieClip" => method(remove_movie_clip; DONT_ENUM | DONT_DELETE);
     "transform" => property(mc_getter!(transform), mc_setter!(set_transform); DONT_ENUM);
+    "blendMode" => property(mc_getter!(display_object::blend_mode), mc_setter!(display_object::set_blend_mode); DONT_DELETE | DONT_ENUM);
+    "filters" => property(mc_getter!(bitmap_filter::get_filters), mc_setter!(bitmap_filter::set_filters); DONT_DELETE | DONT_ENUM);
+    "scale9Grid" => property(mc_getter!(scale_nine_grid), mc_setter!(set_scale_nine_grid); DONT_DELETE | DONT_ENUM);
+    "scrollRect" => property(mc_getter!(scroll_rect), mc_setter!(set_scroll_rect); DONT_DELETE | DONT_ENUM);
+    "cacheAsBitmap" => property(mc_getter!(cache_as_bitmap), mc_setter!(set_cache_as_bitmap); DONT_DELETE | DONT_ENUM);
     "enabled" => property(mc_getter!(enabled), mc_setter!(set_enabled); DONT_DELETE | DONT_ENUM);
     "focusEnabled" => property(mc_getter!(focus_enabled), mc_setter!(set_focus_enabled); DONT_DELETE | DONT_ENUM);
     "_lockroot" => property(mc_getter!(lock_root), mc_setter!(set_lock_root); DONT_DELETE | DONT_ENUM);
@@ -1324,6 +1330,7 @@ fn load_movie<'gc>(
     let (url, opts) = activation.locals_into_request_options(&url, method);
     let future = activation.context.load_manager.load_movie_into_clip(
         activation.context.player.clone().unwrap(),
+        activation.base_movie(),
         DisplayObject::MovieClip(target),
         &url,
         opts,
@@ -1348,6 +1355,7 @@ fn load_variables<'gc>(
     let target = target.object().coerce_to_object(activation);
     let future = activation.context.load_manager.load_form_into_object(
         activation.context.player.clone().unwrap(),
+        activation.base_movie(),
         target,
         &url,
         opts,
@@ -1387,6 +1395,103 @@ fn set_transform<'gc>(
     Ok(())
 }
 
+/// Create a `flash.geom.Rectangle` with the given bounds, or `undefined` if
+/// there are no bounds.
+fn bounds_to_rectangle<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    bounds: Option<BoundingBox>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bounds) = bounds {
+        let constructor = activation.context.avm1.prototypes.rectangle_constructor;
+        let result = constructor.construct(
+            activation,
+            &[
+                bounds.x_min.to_pixels().into(),
+                bounds.y_min.to_pixels().into(),
+                bounds.width().to_pixels().into(),
+                bounds.height().to_pixels().into(),
+            ],
+        )?;
+        Ok(result)
+    } else {
+        Ok(Value::Undefined)
+    }
+}
+
+/// Read the bounds of a `flash.geom.Rectangle`-like object.
+/// Any value other than an object clears the bounds.
+fn rectangle_to_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<Option<BoundingBox>, Error<'gc>> {
+    if let Value::Object(rect) = value {
+        let x = rect.get("x", activation)?.coerce_to_f64(activation)?;
+        let y = rect.get("y", activation)?.coerce_to_f64(activation)?;
+        let width = rect.get("width", activation)?.coerce_to_f64(activation)?;
+        let height = rect.get("height", activation)?.coerce_to_f64(activation)?;
+        Ok(Some(BoundingBox {
+            x_min: Twips::from_pixels(x),
+            y_min: Twips::from_pixels(y),
+            x_max: Twips::from_pixels(x + width),
+            y_max: Twips::from_pixels(y + height),
+            valid: true,
+        }))
+    } else {
+        Ok(None)
+    }
+}
+
+fn scale_nine_grid<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    bounds_to_rectangle(activation, this.scaling_grid())
+}
+
+fn set_scale_nine_grid<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let grid = rectangle_to_bounds(activation, value)?;
+    this.set_scaling_grid(activation.context.gc_context, grid);
+    Ok(())
+}
+
+fn scroll_rect<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    bounds_to_rectangle(activation, this.scroll_rect())
+}
+
+fn set_scroll_rect<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let scroll_rect = rectangle_to_bounds(activation, value)?;
+    this.set_scroll_rect(activation.context.gc_context, scroll_rect);
+    Ok(())
+}
+
+fn cache_as_bitmap<'gc>(
+    this: MovieClip<'gc>,
+    _activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.is_bitmap_cached().into())
+}
+
+fn set_cache_as_bitmap<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let cache = value.as_bool(activation.swf_version());
+    this.set_bitmap_cached(activation.context.gc_context, cache);
+    Ok(())
+}
+
 fn enabled<'gc>(
     this: MovieClip<'gc>,
     _activation: &mut Activation<'_, 'gc, '_>,
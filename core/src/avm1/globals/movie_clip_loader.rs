@@ -60,6 +60,7 @@ fn load_clip<'gc>(
             if let Some(target) = target {
                 let future = activation.context.load_manager.load_movie_into_clip(
                     activation.context.player.clone().unwrap(),
+                    activation.base_movie(),
                     target,
                     &url.to_utf8_lossy(),
                     RequestOptions::get(),
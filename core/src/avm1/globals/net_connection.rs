@@ -0,0 +1,102 @@
+//! `NetConnection` impl
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::property::Attribute;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, ScriptObject, TObject, Value};
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `NetConnection`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.define_value(
+        activation.context.gc_context,
+        "isConnected",
+        false.into(),
+        Attribute::empty(),
+    );
+    Ok(this.into())
+}
+
+/// Call `onStatus` with an info object describing a change in the connection.
+fn trigger_status<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    code: &'static str,
+    level: &'static str,
+) -> Result<(), Error<'gc>> {
+    let info = ScriptObject::object(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().object),
+    );
+    info.define_value(
+        activation.context.gc_context,
+        "code",
+        code.into(),
+        Attribute::empty(),
+    );
+    info.define_value(
+        activation.context.gc_context,
+        "level",
+        level.into(),
+        Attribute::empty(),
+    );
+    this.call_method("onStatus".into(), &[info.into()], activation)?;
+    Ok(())
+}
+
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // Only local connections, which are used to play FLV files, are supported.
+    let is_local = matches!(
+        args.get(0).unwrap_or(&Value::Undefined),
+        Value::Null | Value::Undefined
+    );
+    this.set("isConnected", is_local.into(), activation)?;
+
+    if is_local {
+        trigger_status(activation, this, "NetConnection.Connect.Success", "status")?;
+    } else {
+        trigger_status(activation, this, "NetConnection.Connect.Failed", "error")?;
+    }
+
+    Ok(is_local.into())
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if this
+        .get("isConnected", activation)?
+        .as_bool(activation.swf_version())
+    {
+        this.set("isConnected", false.into(), activation)?;
+        trigger_status(activation, this, "NetConnection.Connect.Closed", "status")?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::object(gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    object.into()
+}
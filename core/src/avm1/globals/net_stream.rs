@@ -0,0 +1,192 @@
+//! `NetStream` impl
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::object::net_stream_object::NetStreamObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, TObject, Value};
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "play" => method(play; DONT_ENUM | DONT_DELETE);
+    "pause" => method(pause; DONT_ENUM | DONT_DELETE);
+    "seek" => method(seek; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+    "setBufferTime" => method(set_buffer_time; DONT_ENUM | DONT_DELETE);
+    "time" => property(time; DONT_ENUM | DONT_DELETE);
+    "bufferTime" => property(buffer_time; DONT_ENUM | DONT_DELETE);
+    "bufferLength" => property(buffer_length; DONT_ENUM | DONT_DELETE);
+    "bytesLoaded" => property(bytes_loaded; DONT_ENUM | DONT_DELETE);
+    "bytesTotal" => property(bytes_total; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `NetStream`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        let net_stream = net_stream.net_stream();
+        net_stream.set_avm_object(activation.context.gc_context, this.into());
+        net_stream.set_movie(activation.context.gc_context, activation.base_movie());
+    }
+
+    Ok(this.into())
+}
+
+fn play<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        let url = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        net_stream
+            .net_stream()
+            .play(&mut activation.context, &url.to_utf8_lossy());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn pause<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        // Without an argument, `pause` toggles between pausing and resuming.
+        let is_paused = match args.get(0) {
+            None | Some(Value::Undefined) => None,
+            Some(value) => Some(value.as_bool(activation.swf_version())),
+        };
+        net_stream
+            .net_stream()
+            .pause(&mut activation.context, is_paused);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn seek<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        let offset = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_f64(activation)?;
+        net_stream
+            .net_stream()
+            .seek(&mut activation.context, offset);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        net_stream.net_stream().close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn set_buffer_time<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        let buffer_time = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_f64(activation)?;
+        net_stream
+            .net_stream()
+            .set_buffer_time(activation.context.gc_context, buffer_time);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn time<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        return Ok(net_stream.net_stream().time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn buffer_time<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        return Ok(net_stream.net_stream().buffer_time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn buffer_length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        return Ok(net_stream.net_stream().buffer_length().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn bytes_loaded<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        return Ok(net_stream.net_stream().bytes_loaded().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn bytes_total<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(net_stream) = this.as_net_stream_object() {
+        return Ok(net_stream.net_stream().bytes_total().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let net_stream = NetStreamObject::empty_object(gc_context, Some(proto));
+    let object = net_stream.as_script_object().unwrap();
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    net_stream.into()
+}
@@ -11,7 +11,6 @@ use crate::string::AvmString;
 use flash_lso::types::Value as AmfValue;
 use flash_lso::types::{AMFVersion, Element, Lso};
 use gc_arena::MutationContext;
-use std::borrow::Cow;
 
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "clear" => method(clear; DONT_ENUM | DONT_DELETE);
@@ -213,39 +212,31 @@ fn deserialize_lso<'gc>(
     Ok(obj.into())
 }
 
-pub fn get_local<'gc>(
-    activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
-    args: &[Value<'gc>],
-) -> Result<Value<'gc>, Error<'gc>> {
-    // TODO: It appears that Flash does some kind of escaping here:
-    // the name "foo\uD800" correspond to a file named "fooE#FB#FB#D.sol".
-
-    let name = args
-        .get(0)
-        .unwrap_or(&Value::Undefined)
-        .coerce_to_string(activation)?;
-    let name = name.to_utf8_lossy();
-
+/// Determine the storage key for a local shared object.
+///
+/// Shared objects are sandboxed per-domain. By default, they are keyed based on the
+/// SWF URL, but the `local_path` parameter can modify this path.
+///
+/// Returns `None` if the shared object cannot be created, in which case `getLocal`
+/// returns `null`.
+pub fn local_shared_object_name(
+    movie_url: Option<&str>,
+    name: &str,
+    local_path: Option<&str>,
+    secure: bool,
+) -> Option<String> {
     const INVALID_CHARS: &str = "~%&\\;:\"',<>?# ";
     if name.contains(|c| INVALID_CHARS.contains(c)) {
         log::error!("SharedObject::get_local: Invalid character in name");
-        return Ok(Value::Null);
+        return None;
     }
 
-    let movie = if let Some(movie) = activation.base_clip().movie() {
-        movie
-    } else {
-        log::error!("SharedObject::get_local: Movie was None");
-        return Ok(Value::Null);
-    };
-
-    let mut movie_url = if let Some(url) = movie.url() {
+    let mut movie_url = if let Some(url) = movie_url {
         if let Ok(url) = url::Url::parse(url) {
             url
         } else {
             log::error!("SharedObject::get_local: Unable to parse movie URL");
-            return Ok(Value::Null);
+            return None;
         }
     } else {
         // No URL (loading local data). Use a dummy URL to allow SharedObjects to work.
@@ -254,17 +245,12 @@ pub fn get_local<'gc>(
     movie_url.set_query(None);
     movie_url.set_fragment(None);
 
-    let secure = args
-        .get(2)
-        .unwrap_or(&Value::Undefined)
-        .as_bool(activation.swf_version());
-
     // Secure parameter disallows using the shared object from non-HTTPS.
     if secure && movie_url.scheme() != "https" {
         log::warn!(
             "SharedObject.get_local: Tried to load a secure shared object from non-HTTPS origin"
         );
-        return Ok(Value::Null);
+        return None;
     }
 
     // Shared objects are sandboxed per-domain.
@@ -284,33 +270,18 @@ pub fn get_local<'gc>(
         movie_url.host_str().unwrap_or_default()
     };
 
-    let local_path = if let Some(Value::String(local_path)) = args.get(1) {
+    let local_path = if let Some(local_path) = local_path {
         // Empty local path always fails.
         if local_path.is_empty() {
-            return Ok(Value::Null);
+            return None;
         }
 
         // Remove leading/trailing slashes.
-        let mut local_path = local_path.to_utf8_lossy();
-        if local_path.ends_with('/') {
-            match &mut local_path {
-                Cow::Owned(p) => {
-                    p.pop();
-                }
-                Cow::Borrowed(p) => *p = &p[..p.len() - 1],
-            }
-        }
-        if local_path.starts_with('/') {
-            match &mut local_path {
-                Cow::Owned(p) => {
-                    p.remove(0);
-                }
-                Cow::Borrowed(p) => *p = &p[1..],
-            }
-        }
+        let local_path = local_path.strip_suffix('/').unwrap_or(local_path);
+        let local_path = local_path.strip_prefix('/').unwrap_or(local_path);
 
         // Verify that local_path is a prefix of the SWF path.
-        if movie_path.starts_with(local_path.as_ref())
+        if movie_path.starts_with(local_path)
             && (local_path.is_empty()
                 || movie_path.len() == local_path.len()
                 || movie_path[local_path.len()..].starts_with('/'))
@@ -318,10 +289,10 @@ pub fn get_local<'gc>(
             local_path
         } else {
             log::warn!("SharedObject.get_local: localPath parameter does not match SWF path");
-            return Ok(Value::Null);
+            return None;
         }
     } else {
-        Cow::Borrowed(movie_path)
+        movie_path
     };
 
     // Final SO path: foo.com/folder/game.swf/SOName
@@ -334,9 +305,50 @@ pub fn get_local<'gc>(
     // so let's disallow them altogether.
     if full_name.split('/').any(|s| s.starts_with('.')) {
         log::error!("SharedObject.get_local: Invalid path with .. segments");
-        return Ok(Value::Null);
+        return None;
     }
 
+    Some(full_name)
+}
+
+pub fn get_local<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // TODO: It appears that Flash does some kind of escaping here:
+    // the name "foo\uD800" correspond to a file named "fooE#FB#FB#D.sol".
+
+    let name = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let name = name.to_utf8_lossy();
+
+    let movie = if let Some(movie) = activation.base_clip().movie() {
+        movie
+    } else {
+        log::error!("SharedObject::get_local: Movie was None");
+        return Ok(Value::Null);
+    };
+
+    let local_path = if let Some(Value::String(local_path)) = args.get(1) {
+        Some(local_path.to_utf8_lossy())
+    } else {
+        None
+    };
+
+    let secure = args
+        .get(2)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    let full_name =
+        match local_shared_object_name(movie.url(), &name, local_path.as_deref(), secure) {
+            Some(full_name) => full_name,
+            None => return Ok(Value::Null),
+        };
+
     // Check if this is referencing an existing shared object
     if let Some(so) = activation.context.shared_objects.get(&full_name) {
         return Ok((*so).into());
@@ -1,5 +1,5 @@
 //! AVM1 Sound object
-//! TODO: Sound position, transform, loadSound
+//! TODO: Sound position, transform
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
@@ -15,7 +15,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "duration" => property(duration; DONT_ENUM | DONT_DELETE | READ_ONLY);
     "getDuration" => method(duration; DONT_ENUM | DONT_DELETE | READ_ONLY);
     "setDuration" => method(set_duration; DONT_ENUM | DONT_DELETE | READ_ONLY);
-    "id3" => method(id3; DONT_ENUM | DONT_DELETE | READ_ONLY);
+    "id3" => property(id3; DONT_ENUM | DONT_DELETE | READ_ONLY);
     "getBytesLoaded" => method(get_bytes_loaded; DONT_ENUM | DONT_DELETE | READ_ONLY);
     "getBytesTotal" => method(get_bytes_total; DONT_ENUM | DONT_DELETE | READ_ONLY);
     "getPan" => method(get_pan; DONT_ENUM | DONT_DELETE | READ_ONLY);
@@ -137,12 +137,16 @@ fn set_duration<'gc>(
 
 fn get_bytes_loaded<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if activation.swf_version() >= 6 {
-        avm_warn!(activation, "Sound.getBytesLoaded: Unimplemented");
-        Ok(1.into())
+        // Sounds that weren't loaded by `loadSound` are always fully loaded.
+        let bytes_loaded = this
+            .as_sound_object()
+            .and_then(|sound| sound.bytes_loaded())
+            .unwrap_or(1);
+        Ok(bytes_loaded.into())
     } else {
         Ok(Value::Undefined)
     }
@@ -150,12 +154,15 @@ fn get_bytes_loaded<'gc>(
 
 fn get_bytes_total<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if activation.swf_version() >= 6 {
-        avm_warn!(activation, "Sound.getBytesTotal: Unimplemented");
-        Ok(1.into())
+        let bytes_total = match this.as_sound_object() {
+            Some(sound) if sound.bytes_loaded().is_some() => sound.bytes_total(),
+            _ => Some(1),
+        };
+        Ok(bytes_total.map_or(Value::Undefined, |bytes| bytes.into()))
     } else {
         Ok(Value::Undefined)
     }
@@ -229,23 +236,56 @@ fn get_volume<'gc>(
 
 fn id3<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
+    this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if activation.swf_version() >= 6 {
-        avm_warn!(activation, "Sound.id3: Unimplemented");
+        if let Some(id3) = this.as_sound_object().and_then(|sound| sound.id3()) {
+            return Ok(id3.into());
+        }
     }
     Ok(Value::Undefined)
 }
 
 fn load_sound<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
-    _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    this: Object<'gc>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if activation.swf_version() >= 6 {
-        avm_warn!(activation, "Sound.loadSound: Unimplemented");
+    let url = match args.get(0) {
+        Some(url) => url.coerce_to_string(activation)?,
+        None => return Ok(Value::Undefined),
+    };
+    let is_streaming = args
+        .get(1)
+        .unwrap_or(&Value::Undefined)
+        .as_bool(activation.swf_version());
+
+    if let Some(sound_object) = this.as_sound_object() {
+        // Loading a new sound replaces the attached one.
+        if let Some(instance) = sound_object.sound_instance() {
+            activation.context.stop_sound(instance);
+        }
+        let gc_context = activation.context.gc_context;
+        sound_object.set_sound(gc_context, None);
+        sound_object.set_sound_instance(gc_context, None);
+        sound_object.set_duration(gc_context, None);
+        sound_object.set_position(gc_context, 0);
+        sound_object.set_progress(gc_context, Some(0), None);
+        sound_object.set_id3(gc_context, None);
+
+        let future = activation.context.load_manager.load_sound_into_object(
+            activation.context.player.clone().unwrap(),
+            activation.base_movie(),
+            sound_object,
+            &url.to_utf8_lossy(),
+            is_streaming,
+        );
+        activation.context.navigator.spawn_future(future);
+    } else {
+        avm_warn!(activation, "Sound.loadSound: this is not a Sound");
     }
+
     Ok(Value::Undefined)
 }
 
@@ -0,0 +1,174 @@
+//! `TextField.StyleSheet` impl
+
+use crate::avm1::object::style_sheet_object::StyleSheetObject;
+use crate::avm1::object::text_format_object::TextFormatObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{
+    Activation, ArrayObject, AvmString, Error, Object, ScriptObject, TObject, Value,
+};
+use crate::html::{Style, StyleSheet};
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "clear" => method(clear);
+    "getStyle" => method(get_style);
+    "getStyleNames" => method(get_style_names);
+    "parseCSS" => method(parse_css);
+    "setStyle" => method(set_style);
+    "transform" => method(transform);
+};
+
+/// Implements `TextField.StyleSheet`
+pub fn constructor<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.into())
+}
+
+/// Read the properties of an object as the declarations of a style.
+fn object_to_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Style, Error<'gc>> {
+    let mut style = Style::new();
+    for name in object.get_keys(activation) {
+        let value = object.get(name, activation)?.coerce_to_string(activation)?;
+        style.push((name.as_wstr().into(), value.as_wstr().into()));
+    }
+    Ok(style)
+}
+
+fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = this.as_style_sheet_object() {
+        style_sheet
+            .style_sheet_mut(activation.context.gc_context)
+            .clear();
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn get_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = this.as_style_sheet_object() {
+        let name = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let style = match style_sheet.style_sheet().style(&name) {
+            Some(style) => style.clone(),
+            None => return Ok(Value::Null),
+        };
+
+        // A copy of the style is returned, so changing it doesn't change the style sheet.
+        let object = ScriptObject::object(
+            activation.context.gc_context,
+            Some(activation.context.avm1.prototypes.object),
+        );
+        for (name, value) in style {
+            let name = AvmString::new(activation.context.gc_context, name);
+            let value = AvmString::new(activation.context.gc_context, value);
+            object.set(name, value.into(), activation)?;
+        }
+        return Ok(object.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn get_style_names<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = this.as_style_sheet_object() {
+        let names: Vec<Value<'gc>> = style_sheet
+            .style_sheet()
+            .style_names()
+            .map(|name| AvmString::new(activation.context.gc_context, name).into())
+            .collect();
+        return Ok(ArrayObject::new(
+            activation.context.gc_context,
+            activation.context.avm1.prototypes().array,
+            names,
+        )
+        .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn parse_css<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = this.as_style_sheet_object() {
+        let css = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        return Ok(style_sheet
+            .style_sheet_mut(activation.context.gc_context)
+            .parse_css(&css)
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn set_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(style_sheet) = this.as_style_sheet_object() {
+        let name = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let style = match args.get(1) {
+            Some(Value::Object(object)) => Some(object_to_style(activation, *object)?),
+            _ => None,
+        };
+        style_sheet
+            .style_sheet_mut(activation.context.gc_context)
+            .set_style(&name, style);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(Value::Object(object)) = args.get(0) {
+        let style = object_to_style(activation, *object)?;
+        let text_format = StyleSheet::transform(&style);
+        return Ok(TextFormatObject::new(activation, text_format).into());
+    }
+
+    Ok(Value::Null)
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let style_sheet = StyleSheetObject::empty_object(gc_context, Some(proto));
+    let object = style_sheet.as_script_object().unwrap();
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    style_sheet.into()
+}
@@ -38,26 +38,6 @@ impl fmt::Display for CpuArchitecture {
     }
 }
 
-/// Available type of sandbox for a given SWF
-#[allow(dead_code)]
-pub enum SandboxType {
-    Remote,
-    LocalWithFile,
-    LocalWithNetwork,
-    LocalTrusted,
-}
-
-impl fmt::Display for SandboxType {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.write_str(match self {
-            SandboxType::Remote => "remote",
-            SandboxType::LocalWithFile => "localWithFile",
-            SandboxType::LocalWithNetwork => "localWithNetwork",
-            SandboxType::LocalTrusted => "localTrusted",
-        })
-    }
-}
-
 /// The available host operating systems
 #[allow(dead_code)]
 pub enum OperatingSystem {
@@ -285,8 +265,6 @@ pub struct SystemProperties {
     pub manufacturer: Manufacturer,
     /// The os of the host
     pub os: OperatingSystem,
-    /// The type of the player sandbox
-    pub sandbox_type: SandboxType,
     /// The cpu architecture of the platform
     pub cpu_architecture: CpuArchitecture,
     /// The highest supported h264 decoder level
@@ -412,7 +390,6 @@ impl Default for SystemProperties {
             dpi: 1_f32,
             manufacturer: Manufacturer::Linux,
             os: OperatingSystem::Linux,
-            sandbox_type: SandboxType::LocalTrusted,
             cpu_architecture: CpuArchitecture::X86,
             idc_level: "5.1".into(),
         }
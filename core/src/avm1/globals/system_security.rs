@@ -17,36 +17,55 @@ const OBJECT_DECLS: &[Declaration] = declare_properties! {
     "chooseLocalSwfPath" => property(get_choose_local_swf_path);
 };
 
+/// Grant movies from the given domains access to the calling movie.
+fn grant_domains<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    allow_insecure: bool,
+) -> Result<(), Error<'gc>> {
+    let movie = activation.base_movie();
+    for domain in args {
+        let domain = domain.coerce_to_string(activation)?;
+        activation
+            .context
+            .security
+            .allow_domain(&movie, &domain.to_utf8_lossy(), allow_insecure);
+    }
+
+    Ok(())
+}
+
 fn allow_domain<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "System.security.allowDomain() not implemented");
+    grant_domains(activation, args, false)?;
     Ok(Value::Undefined)
 }
 
 fn allow_insecure_domain<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(
-        activation,
-        "System.security.allowInsecureDomain() not implemented"
-    );
+    grant_domains(activation, args, true)?;
     Ok(Value::Undefined)
 }
 
 fn load_policy_file<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(
-        activation,
-        "System.security.loadPolicyFile() not implemented"
-    );
+    let url = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?
+        .to_utf8_lossy()
+        .into_owned();
+    let url = activation.context.navigator.resolve_relative_url(&url);
+    activation.context.security.load_policy_file(&url);
     Ok(Value::Undefined)
 }
 
@@ -64,9 +83,10 @@ fn get_sandbox_type<'gc>(
     _this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
+    let movie = activation.base_movie();
     Ok(AvmString::new_utf8(
         activation.context.gc_context,
-        activation.context.system.sandbox_type.to_string(),
+        activation.context.security.sandbox_type(&movie).to_string(),
     )
     .into())
 }
@@ -1,5 +1,6 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::bitmap_filter;
 use crate::avm1::globals::display_object;
 use crate::avm1::object::text_format_object::TextFormatObject;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
@@ -66,16 +67,21 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "borderColor" => property(tf_getter!(border_color), tf_setter!(set_border_color));
     "bottomScroll" => property(tf_getter!(bottom_scroll));
     "embedFonts" => property(tf_getter!(embed_fonts), tf_setter!(set_embed_fonts));
+    "blendMode" => property(tf_getter!(display_object::blend_mode), tf_setter!(display_object::set_blend_mode));
+    "filters" => property(tf_getter!(bitmap_filter::get_filters), tf_setter!(bitmap_filter::set_filters));
     "hscroll" => property(tf_getter!(hscroll), tf_setter!(set_hscroll));
     "html" => property(tf_getter!(html), tf_setter!(set_html));
     "htmlText" => property(tf_getter!(html_text), tf_setter!(set_html_text));
     "length" => property(tf_getter!(length));
+    "maxChars" => property(tf_getter!(max_chars), tf_setter!(set_max_chars));
     "maxhscroll" => property(tf_getter!(maxhscroll));
     "maxscroll" => property(tf_getter!(maxscroll));
     "multiline" => property(tf_getter!(multiline), tf_setter!(set_multiline));
     "password" => property(tf_getter!(password), tf_setter!(set_password));
+    "restrict" => property(tf_getter!(restrict), tf_setter!(set_restrict));
     "scroll" => property(tf_getter!(scroll), tf_setter!(set_scroll));
     "selectable" => property(tf_getter!(selectable), tf_setter!(set_selectable));
+    "styleSheet" => property(tf_getter!(style_sheet), tf_setter!(set_style_sheet));
     "text" => property(tf_getter!(text), tf_setter!(set_text));
     "textColor" => property(tf_getter!(text_color), tf_setter!(set_text_color));
     "textHeight" => property(tf_getter!(text_height));
@@ -123,6 +129,80 @@ pub fn set_password<'gc>(
     Ok(())
 }
 
+pub fn restrict<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(restrict) = this.restrict() {
+        return Ok(AvmString::new(activation.context.gc_context, restrict).into());
+    }
+
+    // Unset `restrict` returns null, not undefined
+    Ok(Value::Null)
+}
+
+pub fn set_restrict<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let restrict = match value {
+        Value::Undefined | Value::Null => None,
+        v => Some(v.coerce_to_string(activation)?),
+    };
+    this.set_restrict(restrict.as_deref(), &mut activation.context);
+    Ok(())
+}
+
+pub fn max_chars<'gc>(
+    this: EditText<'gc>,
+    _activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    // A field without a limit returns null
+    match this.max_chars() {
+        0 => Ok(Value::Null),
+        max_chars => Ok(max_chars.into()),
+    }
+}
+
+pub fn set_max_chars<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let max_chars = match value {
+        Value::Undefined | Value::Null => 0,
+        v => v.coerce_to_i32(activation)?,
+    };
+    this.set_max_chars(max_chars, &mut activation.context);
+    Ok(())
+}
+
+pub fn style_sheet<'gc>(
+    this: EditText<'gc>,
+    _activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this
+        .style_sheet()
+        .and_then(|object| object.as_avm1_object().ok())
+        .map_or(Value::Undefined, Value::Object))
+}
+
+pub fn set_style_sheet<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let style_sheet = match value {
+        Value::Object(object) => object.as_style_sheet_object(),
+        _ => None,
+    };
+    let style_sheet =
+        style_sheet.map(|object| (Object::from(object).into(), object.style_sheet().clone()));
+    this.set_style_sheet(style_sheet, &mut activation.context);
+    Ok(())
+}
+
 fn get_new_text_format<'gc>(
     text_field: EditText<'gc>,
     activation: &mut Activation<'_, 'gc, '_>,
@@ -250,7 +330,9 @@ fn replace_text<'gc>(
         .unwrap_or(Value::Undefined)
         .coerce_to_string(activation)?;
 
-    text_field.replace_text(from as usize, to as usize, &text, &mut activation.context);
+    let (from, to) = (from as usize, to as usize);
+    let text = text_field.restrict_text(from, to, &text);
+    text_field.replace_text(from, to, &text, &mut activation.context);
 
     Ok(Value::Undefined)
 }
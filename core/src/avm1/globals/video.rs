@@ -4,10 +4,16 @@ use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm1::globals::display_object;
 use crate::avm1::object::Object;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::value::Value;
-use crate::avm1::ScriptObject;
+use crate::avm1::{ScriptObject, TObject};
+use crate::display_object::TDisplayObject;
 use gc_arena::MutationContext;
 
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "attachVideo" => method(attach_video; DONT_ENUM | DONT_DELETE);
+};
+
 /// Implements `Video`
 pub fn constructor<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -17,6 +23,25 @@ pub fn constructor<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Video.attachVideo`
+fn attach_video<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this.as_display_object().and_then(|this| this.as_video()) {
+        let stream = match args.get(0) {
+            Some(Value::Object(object)) => object
+                .as_net_stream_object()
+                .map(|net_stream| net_stream.net_stream()),
+            _ => None,
+        };
+        video.attach_netstream(activation.context.gc_context, stream);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn create_proto<'gc>(
     gc_context: MutationContext<'gc, '_>,
     proto: Object<'gc>,
@@ -25,6 +50,7 @@ pub fn create_proto<'gc>(
     let object = ScriptObject::object(gc_context, Some(proto));
 
     display_object::define_display_object_proto(gc_context, object, fn_proto);
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
 
     object.into()
 }
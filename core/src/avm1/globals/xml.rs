@@ -264,6 +264,7 @@ fn spawn_xml_fetch<'gc>(
 
     let future = activation.context.load_manager.load_form_into_load_vars(
         activation.context.player.clone().unwrap(),
+        activation.base_movie(),
         loader_object,
         &url.to_utf8_lossy(),
         request_options,
@@ -0,0 +1,128 @@
+//! `XMLSocket` impl
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::object::xml_socket_object::XmlSocketObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, TObject, Value};
+use crate::socket::{SocketKind, SocketManager};
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+    "onData" => method(on_data; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `XMLSocket`
+pub fn constructor<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.into())
+}
+
+/// Implements `XMLSocket.connect`.
+///
+/// Returns whether the connection attempt was started; its outcome is
+/// reported to `onConnect`.
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(xml_socket) = this.as_xml_socket_object() {
+        let host = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => None,
+            Some(host) => Some(host.coerce_to_string(activation)?.to_string()),
+        };
+        let port = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        // AVM1 sockets can't connect to the well-known ports.
+        if !(1024..=65535).contains(&port) {
+            return Ok(false.into());
+        }
+
+        if let Some(handle) = xml_socket.handle() {
+            SocketManager::close(&mut activation.context, handle);
+        }
+        let handle = SocketManager::connect(
+            &mut activation.context,
+            this.into(),
+            SocketKind::Xml,
+            host.as_deref(),
+            port as u16,
+        );
+        xml_socket.set_handle(activation.context.gc_context, Some(handle));
+        return Ok(true.into());
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `XMLSocket.send`.
+///
+/// The object is sent as a string, followed by a null byte.
+fn send<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(handle) = this.as_xml_socket_object().and_then(|o| o.handle()) {
+        let message = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let mut data = message.to_utf8_lossy().into_owned().into_bytes();
+        data.push(0);
+        SocketManager::send(&mut activation.context, handle, data);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.close`.
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(xml_socket) = this.as_xml_socket_object() {
+        if let Some(handle) = xml_socket.handle() {
+            SocketManager::close(&mut activation.context, handle);
+        }
+        xml_socket.set_handle(activation.context.gc_context, None);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements the default `XMLSocket.onData`, which parses the message and
+/// passes it to `onXML`.
+fn on_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let src = args.get(0).cloned().unwrap_or(Value::Undefined);
+    let xml_constructor = activation.context.avm1.prototypes().xml_constructor;
+    let xml = xml_constructor.construct(activation, &[src])?;
+    this.call_method("onXML".into(), &[xml], activation)?;
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let xml_socket = XmlSocketObject::empty_object(gc_context, Some(proto));
+    let object = xml_socket.as_script_object().unwrap();
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    xml_socket.into()
+}
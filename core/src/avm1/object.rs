@@ -21,11 +21,15 @@ use crate::avm1::object::drop_shadow_filter::DropShadowFilterObject;
 use crate::avm1::object::glow_filter::GlowFilterObject;
 use crate::avm1::object::gradient_bevel_filter::GradientBevelFilterObject;
 use crate::avm1::object::gradient_glow_filter::GradientGlowFilterObject;
+use crate::avm1::object::local_connection_object::LocalConnectionObject;
+use crate::avm1::object::net_stream_object::NetStreamObject;
+use crate::avm1::object::style_sheet_object::StyleSheetObject;
 use crate::avm1::object::text_format_object::TextFormatObject;
 use crate::avm1::object::transform_object::TransformObject;
 use crate::avm1::object::xml_attributes_object::XmlAttributesObject;
 use crate::avm1::object::xml_node_object::XmlNodeObject;
 use crate::avm1::object::xml_object::XmlObject;
+use crate::avm1::object::xml_socket_object::XmlSocketObject;
 use crate::avm1::{AvmString, ScriptObject, SoundObject, StageObject, Value};
 use crate::display_object::DisplayObject;
 use crate::xml::XmlNode;
@@ -47,10 +51,13 @@ pub mod drop_shadow_filter;
 pub mod glow_filter;
 pub mod gradient_bevel_filter;
 pub mod gradient_glow_filter;
+pub mod local_connection_object;
+pub mod net_stream_object;
 pub mod script_object;
 pub mod shared_object;
 pub mod sound_object;
 pub mod stage_object;
+pub mod style_sheet_object;
 pub mod super_object;
 pub mod text_format_object;
 pub mod transform_object;
@@ -58,6 +65,7 @@ pub mod value_object;
 pub mod xml_attributes_object;
 pub mod xml_node_object;
 pub mod xml_object;
+pub mod xml_socket_object;
 
 /// Represents an object that can be directly interacted with by the AVM
 /// runtime.
@@ -91,6 +99,10 @@ pub mod xml_object;
         DateObject(DateObject<'gc>),
         BitmapData(BitmapDataObject<'gc>),
         TextFormatObject(TextFormatObject<'gc>),
+        StyleSheetObject(StyleSheetObject<'gc>),
+        NetStreamObject(NetStreamObject<'gc>),
+        XmlSocketObject(XmlSocketObject<'gc>),
+        LocalConnectionObject(LocalConnectionObject<'gc>),
     }
 )]
 pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy {
@@ -621,6 +633,26 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         None
     }
 
+    /// Get the underlying `StyleSheetObject`, if it exists
+    fn as_style_sheet_object(&self) -> Option<StyleSheetObject<'gc>> {
+        None
+    }
+
+    /// Get the underlying `NetStreamObject`, if it exists
+    fn as_net_stream_object(&self) -> Option<NetStreamObject<'gc>> {
+        None
+    }
+
+    /// Get the underlying `XmlSocketObject`, if it exists
+    fn as_xml_socket_object(&self) -> Option<XmlSocketObject<'gc>> {
+        None
+    }
+
+    /// Get the underlying `LocalConnectionObject`, if it exists
+    fn as_local_connection_object(&self) -> Option<LocalConnectionObject<'gc>> {
+        None
+    }
+
     fn as_ptr(&self) -> *const ObjectPtr;
 
     /// Check if this object is in the prototype chain of the specified test object.
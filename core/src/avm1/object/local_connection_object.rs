@@ -0,0 +1,59 @@
+use crate::avm1::{Object, ScriptObject, TObject};
+use crate::impl_custom_object;
+use crate::local_connection::LocalConnectionHandle;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::fmt;
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct LocalConnectionObject<'gc>(GcCell<'gc, LocalConnectionData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct LocalConnectionData<'gc> {
+    /// The underlying script object.
+    base: ScriptObject<'gc>,
+
+    /// The handle of the connection, if it is listening.
+    #[collect(require_static)]
+    handle: Option<LocalConnectionHandle>,
+}
+
+impl fmt::Debug for LocalConnectionObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let this = self.0.read();
+        f.debug_struct("LocalConnectionObject")
+            .field("handle", &this.handle)
+            .finish()
+    }
+}
+
+impl<'gc> LocalConnectionObject<'gc> {
+    pub fn empty_object(gc_context: MutationContext<'gc, '_>, proto: Option<Object<'gc>>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            LocalConnectionData {
+                base: ScriptObject::object(gc_context, proto),
+                handle: None,
+            },
+        ))
+    }
+
+    pub fn handle(&self) -> Option<LocalConnectionHandle> {
+        self.0.read().handle
+    }
+
+    pub fn set_handle(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        handle: Option<LocalConnectionHandle>,
+    ) {
+        self.0.write(gc_context).handle = handle;
+    }
+}
+
+impl<'gc> TObject<'gc> for LocalConnectionObject<'gc> {
+    impl_custom_object!(base {
+        bare_object(as_local_connection_object -> LocalConnectionObject::empty_object);
+    });
+}
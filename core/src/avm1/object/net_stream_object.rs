@@ -0,0 +1,49 @@
+use crate::avm1::{Object, ScriptObject, TObject};
+use crate::impl_custom_object;
+use crate::streams::NetStream;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::fmt;
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct NetStreamObject<'gc>(GcCell<'gc, NetStreamData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct NetStreamData<'gc> {
+    /// The underlying script object.
+    base: ScriptObject<'gc>,
+
+    net_stream: NetStream<'gc>,
+}
+
+impl fmt::Debug for NetStreamObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let this = self.0.read();
+        f.debug_struct("NetStreamObject")
+            .field("net_stream", &this.net_stream)
+            .finish()
+    }
+}
+
+impl<'gc> NetStreamObject<'gc> {
+    pub fn empty_object(gc_context: MutationContext<'gc, '_>, proto: Option<Object<'gc>>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            NetStreamData {
+                base: ScriptObject::object(gc_context, proto),
+                net_stream: NetStream::new(gc_context),
+            },
+        ))
+    }
+
+    pub fn net_stream(&self) -> NetStream<'gc> {
+        self.0.read().net_stream
+    }
+}
+
+impl<'gc> TObject<'gc> for NetStreamObject<'gc> {
+    impl_custom_object!(base {
+        bare_object(as_net_stream_object -> NetStreamObject::empty_object);
+    });
+}
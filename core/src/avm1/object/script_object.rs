@@ -547,9 +547,11 @@ mod tests {
     use crate::avm1::{Avm1, Timers};
     use crate::avm2::Avm2;
     use crate::backend::audio::{AudioManager, NullAudioBackend};
+    use crate::backend::font::NullFontBackend;
     use crate::backend::log::NullLogBackend;
     use crate::backend::navigator::NullNavigatorBackend;
     use crate::backend::render::NullRenderer;
+    use crate::backend::socket::NullSocketBackend;
     use crate::backend::storage::MemoryStorageBackend;
     use crate::backend::ui::NullUiBackend;
     use crate::backend::video::NullVideoBackend;
@@ -558,7 +560,11 @@ mod tests {
     use crate::focus_tracker::FocusTracker;
     use crate::library::Library;
     use crate::loader::LoadManager;
+    use crate::local_connection::LocalConnectionManager;
     use crate::prelude::*;
+    use crate::security::Security;
+    use crate::socket::SocketManager;
+    use crate::streams::StreamManager;
     use crate::tag_utils::SwfMovie;
     use crate::vminterface::Instantiator;
     use gc_arena::rootless_arena;
@@ -594,12 +600,17 @@ mod tests {
                 action_queue: &mut crate::context::ActionQueue::new(),
                 audio: &mut NullAudioBackend::new(),
                 audio_manager: &mut AudioManager::new(),
+                stream_manager: &mut StreamManager::new(),
                 ui: &mut NullUiBackend::new(),
                 library: &mut Library::empty(),
                 navigator: &mut NullNavigatorBackend::new(),
                 renderer: &mut NullRenderer::new(),
                 log: &mut NullLogBackend::new(),
                 video: &mut NullVideoBackend::new(),
+                fonts: &mut NullFontBackend::new(),
+                sockets: &mut NullSocketBackend::new(),
+                socket_manager: &mut SocketManager::new(),
+                local_connection_manager: &mut LocalConnectionManager::new(),
                 mouse_over_object: None,
                 mouse_down_object: None,
                 input: &Default::default(),
@@ -608,6 +619,7 @@ mod tests {
                 player: None,
                 load_manager: &mut LoadManager::new(),
                 system: &mut SystemProperties::default(),
+                security: &mut Security::default(),
                 instance_counter: &mut 0,
                 storage: &mut MemoryStorageBackend::default(),
                 shared_objects: &mut HashMap::new(),
@@ -37,6 +37,15 @@ pub struct SoundObjectData<'gc> {
 
     /// Duration of the currently attached sound in milliseconds.
     duration: Option<u32>,
+
+    /// The number of bytes loaded by `loadSound`, or `None` if it wasn't called.
+    bytes_loaded: Option<u32>,
+
+    /// The size of the file loaded by `loadSound`, once it is known.
+    bytes_total: Option<u32>,
+
+    /// The ID3 tags of the file loaded by `loadSound`.
+    id3: Option<Object<'gc>>,
 }
 
 impl fmt::Debug for SoundObject<'_> {
@@ -64,6 +73,9 @@ impl<'gc> SoundObject<'gc> {
                 owner: None,
                 position: 0,
                 duration: None,
+                bytes_loaded: None,
+                bytes_total: None,
+                id3: None,
             },
         ))
     }
@@ -115,6 +127,33 @@ impl<'gc> SoundObject<'gc> {
     pub fn set_position(self, gc_context: MutationContext<'gc, '_>, position: u32) {
         self.0.write(gc_context).position = position;
     }
+
+    pub fn bytes_loaded(self) -> Option<u32> {
+        self.0.read().bytes_loaded
+    }
+
+    pub fn bytes_total(self) -> Option<u32> {
+        self.0.read().bytes_total
+    }
+
+    pub fn set_progress(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        bytes_loaded: Option<u32>,
+        bytes_total: Option<u32>,
+    ) {
+        let mut write = self.0.write(gc_context);
+        write.bytes_loaded = bytes_loaded;
+        write.bytes_total = bytes_total;
+    }
+
+    pub fn id3(self) -> Option<Object<'gc>> {
+        self.0.read().id3
+    }
+
+    pub fn set_id3(self, gc_context: MutationContext<'gc, '_>, id3: Option<Object<'gc>>) {
+        self.0.write(gc_context).id3 = id3;
+    }
 }
 
 impl<'gc> TObject<'gc> for SoundObject<'gc> {
@@ -0,0 +1,54 @@
+use crate::avm1::{Object, ScriptObject, TObject};
+use crate::html::StyleSheet;
+use crate::impl_custom_object;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+use std::fmt;
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct StyleSheetObject<'gc>(GcCell<'gc, StyleSheetData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct StyleSheetData<'gc> {
+    /// The underlying script object.
+    base: ScriptObject<'gc>,
+
+    style_sheet: StyleSheet,
+}
+
+impl fmt::Debug for StyleSheetObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let this = self.0.read();
+        f.debug_struct("StyleSheetObject")
+            .field("style_sheet", &this.style_sheet)
+            .finish()
+    }
+}
+
+impl<'gc> StyleSheetObject<'gc> {
+    pub fn empty_object(gc_context: MutationContext<'gc, '_>, proto: Option<Object<'gc>>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            StyleSheetData {
+                base: ScriptObject::object(gc_context, proto),
+                style_sheet: StyleSheet::new(),
+            },
+        ))
+    }
+
+    pub fn style_sheet(&self) -> Ref<StyleSheet> {
+        Ref::map(self.0.read(), |o| &o.style_sheet)
+    }
+
+    pub fn style_sheet_mut(&self, gc_context: MutationContext<'gc, '_>) -> RefMut<StyleSheet> {
+        RefMut::map(self.0.write(gc_context), |o| &mut o.style_sheet)
+    }
+}
+
+impl<'gc> TObject<'gc> for StyleSheetObject<'gc> {
+    impl_custom_object!(base {
+        bare_object(as_style_sheet_object -> StyleSheetObject::empty_object);
+    });
+}
@@ -0,0 +1,55 @@
+use crate::avm1::{Object, ScriptObject, TObject};
+use crate::backend::socket::SocketHandle;
+use crate::impl_custom_object;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::fmt;
+
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct XmlSocketObject<'gc>(GcCell<'gc, XmlSocketData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct XmlSocketData<'gc> {
+    /// The underlying script object.
+    base: ScriptObject<'gc>,
+
+    /// The handle of the connection, if it was opened.
+    #[collect(require_static)]
+    handle: Option<SocketHandle>,
+}
+
+impl fmt::Debug for XmlSocketObject<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let this = self.0.read();
+        f.debug_struct("XmlSocketObject")
+            .field("handle", &this.handle)
+            .finish()
+    }
+}
+
+impl<'gc> XmlSocketObject<'gc> {
+    pub fn empty_object(gc_context: MutationContext<'gc, '_>, proto: Option<Object<'gc>>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            XmlSocketData {
+                base: ScriptObject::object(gc_context, proto),
+                handle: None,
+            },
+        ))
+    }
+
+    pub fn handle(&self) -> Option<SocketHandle> {
+        self.0.read().handle
+    }
+
+    pub fn set_handle(&self, gc_context: MutationContext<'gc, '_>, handle: Option<SocketHandle>) {
+        self.0.write(gc_context).handle = handle;
+    }
+}
+
+impl<'gc> TObject<'gc> for XmlSocketObject<'gc> {
+    impl_custom_object!(base {
+        bare_object(as_xml_socket_object -> XmlSocketObject::empty_object);
+    });
+}
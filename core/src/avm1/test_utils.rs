@@ -4,9 +4,11 @@ use crate::avm1::globals::system::SystemProperties;
 use crate::avm1::{Avm1, Object, Timers, UpdateContext};
 use crate::avm2::Avm2;
 use crate::backend::audio::{AudioManager, NullAudioBackend};
+use crate::backend::font::NullFontBackend;
 use crate::backend::log::NullLogBackend;
 use crate::backend::navigator::NullNavigatorBackend;
 use crate::backend::render::NullRenderer;
+use crate::backend::socket::NullSocketBackend;
 use crate::backend::storage::MemoryStorageBackend;
 use crate::backend::ui::NullUiBackend;
 use crate::backend::video::NullVideoBackend;
@@ -15,7 +17,11 @@ use crate::display_object::{MovieClip, Stage, TDisplayObject};
 use crate::focus_tracker::FocusTracker;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::local_connection::LocalConnectionManager;
 use crate::prelude::*;
+use crate::security::Security;
+use crate::socket::SocketManager;
+use crate::streams::StreamManager;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
 use gc_arena::{rootless_arena, MutationContext};
@@ -56,6 +62,10 @@ where
             renderer: &mut NullRenderer::new(),
             log: &mut NullLogBackend::new(),
             video: &mut NullVideoBackend::new(),
+            fonts: &mut NullFontBackend::new(),
+            sockets: &mut NullSocketBackend::new(),
+            socket_manager: &mut SocketManager::new(),
+            local_connection_manager: &mut LocalConnectionManager::new(),
             mouse_over_object: None,
             mouse_down_object: None,
             input: &Default::default(),
@@ -64,6 +74,7 @@ where
             player: None,
             load_manager: &mut LoadManager::new(),
             system: &mut SystemProperties::default(),
+            security: &mut Security::default(),
             instance_counter: &mut 0,
             storage: &mut MemoryStorageBackend::default(),
             shared_objects: &mut HashMap::new(),
@@ -81,6 +92,7 @@ where
             times_get_time_called: 0,
             time_offset: &mut 0,
             audio_manager: &mut AudioManager::new(),
+            stream_manager: &mut StreamManager::new(),
             frame_rate: &mut frame_rate,
         };
         context.stage.replace_at_depth(&mut context, root, 0);
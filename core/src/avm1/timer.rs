@@ -4,9 +4,13 @@
 //! The timers are stored in a priority queue, where we check if the nearest timer
 //! is ready to tick each frame.
 //!
-//! TODO: Could we use this for AVM2 timers as well?
+//! Both AVM1 and AVM2 timers are scheduled here, so that timers created by
+//! either VM fire in a consistent order.
 
 use crate::avm1::{Activation, ActivationIdentifier, AvmString, Object, TObject, Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Object as Avm2Object, TObject as _, Value as Avm2Value,
+};
 use crate::context::UpdateContext;
 use gc_arena::Collect;
 use std::collections::{binary_heap::PeekMut, BinaryHeap};
@@ -79,22 +83,32 @@ impl<'gc> Timers<'gc> {
                 break;
             }
 
-            // TODO: Can we avoid these clones?
-            let params = timer.params.clone();
+            // TODO: Can we avoid this clone?
             let callback = timer.callback.clone();
 
             match callback {
-                TimerCallback::Function(function) => {
-                    let _ = function.call(
+                TimerCallback::Avm1Function { func, params } => {
+                    let _ = func.call(
                         "[Timer Callback]".into(),
                         &mut activation,
                         Value::Undefined,
                         &params,
                     );
                 }
-                TimerCallback::Method { this, method_name } => {
+                TimerCallback::Avm1Method {
+                    this,
+                    method_name,
+                    params,
+                } => {
                     let _ = this.call_method(method_name, &params, &mut activation);
                 }
+                TimerCallback::Avm2Callback { closure, params } => {
+                    let mut avm2_activation =
+                        Avm2Activation::from_nothing(activation.context.reborrow());
+                    if let Err(e) = closure.call(None, &params, &mut avm2_activation) {
+                        log::error!("Unhandled AVM2 error in timer callback: {}", e);
+                    }
+                }
             }
 
             crate::player::Player::run_actions(&mut activation.context);
@@ -146,7 +160,6 @@ impl<'gc> Timers<'gc> {
         &mut self,
         callback: TimerCallback<'gc>,
         interval: i32,
-        params: Vec<Value<'gc>>,
         is_timeout: bool,
     ) -> i32 {
         // SANITY: Set a minimum interval so we don't spam too much.
@@ -157,7 +170,6 @@ impl<'gc> Timers<'gc> {
         let timer = Timer {
             id,
             callback,
-            params,
             tick_time: self.cur_time + interval,
             interval,
             is_timeout,
@@ -216,9 +228,6 @@ struct Timer<'gc> {
     /// A callback is either a function object, or a parent object with a method name.
     callback: TimerCallback<'gc>,
 
-    /// The parameters to pass to the callback function.
-    params: Vec<Value<'gc>>,
-
     /// The time when this timer should fire.
     tick_time: u64,
 
@@ -259,9 +268,22 @@ impl Ord for Timer<'_> {
 #[derive(Debug, Collect, Clone)]
 #[collect(no_drop)]
 pub enum TimerCallback<'gc> {
-    Function(Object<'gc>),
-    Method {
+    /// An AVM1 function, called with the given parameters.
+    Avm1Function {
+        func: Object<'gc>,
+        params: Vec<Value<'gc>>,
+    },
+
+    /// A named method of an AVM1 object, called with the given parameters.
+    Avm1Method {
         this: Object<'gc>,
         method_name: AvmString<'gc>,
+        params: Vec<Value<'gc>>,
+    },
+
+    /// An AVM2 closure, called with the given parameters.
+    Avm2Callback {
+        closure: Avm2Object<'gc>,
+        params: Vec<Avm2Value<'gc>>,
     },
 }
@@ -1,5 +1,6 @@
 //! ActionScript Virtual Machine 2 (AS3) support
 
+use crate::avm2::e4x::XmlSettings;
 use crate::avm2::globals::{SystemClasses, SystemPrototypes};
 use crate::avm2::method::Method;
 use crate::avm2::object::EventObject;
@@ -9,6 +10,7 @@ use crate::string::AvmString;
 use crate::tag_utils::SwfSlice;
 use fnv::FnvHashMap;
 use gc_arena::{Collect, MutationContext};
+use std::collections::HashMap;
 use std::rc::Rc;
 use swf::avm2::read::Reader;
 
@@ -22,10 +24,12 @@ macro_rules! avm_debug {
 }
 
 pub mod activation;
+mod amf;
 mod array;
 mod bytearray;
 mod class;
 mod domain;
+mod e4x;
 mod events;
 mod function;
 mod globals;
@@ -45,6 +49,7 @@ mod vtable;
 
 pub use crate::avm2::activation::Activation;
 pub use crate::avm2::array::ArrayStorage;
+pub use crate::avm2::bytearray::ByteArrayStorage;
 pub use crate::avm2::domain::Domain;
 pub use crate::avm2::events::{Event, EventData};
 pub use crate::avm2::names::{Namespace, QName};
@@ -87,6 +92,20 @@ pub struct Avm2<'gc> {
     /// collector does not support weak references.
     broadcast_list: FnvHashMap<AvmString<'gc>, Vec<Object<'gc>>>,
 
+    /// Settings that control E4X parsing and printing, as set by the static
+    /// properties of the `XML` class.
+    xml_settings: XmlSettings,
+
+    /// Classes registered with `registerClassAlias`, keyed by their alias.
+    ///
+    /// These are used to name the traits of objects serialized with AMF, and
+    /// to reconstruct instances of the same class when deserializing them.
+    class_aliases: FnvHashMap<AvmString<'gc>, ClassObject<'gc>>,
+
+    /// Local shared objects created with `SharedObject.getLocal`, keyed by
+    /// their storage name.
+    shared_objects: HashMap<String, Object<'gc>>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 }
@@ -102,6 +121,9 @@ impl<'gc> Avm2<'gc> {
             system_prototypes: None,
             system_classes: None,
             broadcast_list: Default::default(),
+            xml_settings: Default::default(),
+            class_aliases: Default::default(),
+            shared_objects: HashMap::new(),
 
             #[cfg(feature = "avm_debug")]
             debug_output: false,
@@ -249,6 +271,24 @@ impl<'gc> Avm2<'gc> {
         Ok(())
     }
 
+    /// Write every local shared object opened by AVM2 code to storage.
+    ///
+    /// This is called when the player shuts down, so that data is saved even
+    /// if the movie never called `flush`.
+    pub fn flush_shared_objects(context: &mut UpdateContext<'_, 'gc, '_>) {
+        let shared_objects: Vec<Object<'gc>> =
+            context.avm2.shared_objects.values().copied().collect();
+        let mut activation = Activation::from_nothing(context.reborrow());
+
+        for shared_object in shared_objects {
+            if let Err(e) =
+                globals::flash::net::sharedobject::flush_to_storage(&mut activation, shared_object)
+            {
+                log::error!("Unable to flush SharedObject: {}", e);
+            }
+        }
+    }
+
     pub fn run_stack_frame_for_callable(
         callable: Object<'gc>,
         reciever: Option<Object<'gc>>,
@@ -274,7 +314,12 @@ impl<'gc> Avm2<'gc> {
         let mut read = Reader::new(abc.as_ref());
 
         let abc_file = Rc::new(read.read()?);
-        let tunit = TranslationUnit::from_abc(abc_file.clone(), domain, context.gc_context);
+        let tunit = TranslationUnit::from_abc(
+            abc_file.clone(),
+            abc.movie.clone(),
+            domain,
+            context.gc_context,
+        );
 
         for i in (0..abc_file.scripts.len()).rev() {
             let mut script = tunit.load_script(i as u32, context)?;
@@ -3,10 +3,12 @@
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
+use crate::avm2::e4x::{escape_attribute_value, escape_element_value, E4XNode};
 use crate::avm2::method::{BytecodeMethod, Method, ParamConfig};
 use crate::avm2::names::{Multiname, Namespace, QName};
 use crate::avm2::object::{
     ArrayObject, ByteArrayObject, ClassObject, FunctionObject, NamespaceObject, ScriptObject,
+    XmlListObject,
 };
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::scope::{Scope, ScopeChain, ScopeStack};
@@ -14,12 +16,14 @@ use crate::avm2::script::Script;
 use crate::avm2::value::Value;
 use crate::avm2::{value, Avm2, Error};
 use crate::context::UpdateContext;
-use crate::string::{AvmString, WStr, WString};
+use crate::string::AvmString;
 use crate::swf::extensions::ReadSwfExt;
+use crate::tag_utils::SwfMovie;
 use gc_arena::{Gc, GcCell, MutationContext};
 use smallvec::SmallVec;
 use std::borrow::Cow;
 use std::cmp::{min, Ordering};
+use std::sync::Arc;
 use swf::avm2::read::Reader;
 use swf::avm2::types::{
     Class as AbcClass, Index, Method as AbcMethod, Multiname as AbcMultiname,
@@ -115,6 +119,13 @@ pub struct Activation<'a, 'gc: 'a, 'gc_context: 'a> {
     /// current domain instead.
     caller_domain: Domain<'gc>,
 
+    /// The movie of the original AS3 caller.
+    ///
+    /// Like `caller_domain`, this is intended for builtin methods. Several
+    /// movies may share a domain, so this is tracked separately: it is the
+    /// movie that the bytecode method or script was loaded from.
+    caller_movie: Arc<SwfMovie>,
+
     /// The class that yielded the currently executing method.
     ///
     /// This is used to maintain continuity when multiple methods supercall
@@ -151,6 +162,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
     /// `Activation`.
     pub fn from_nothing(context: UpdateContext<'a, 'gc, 'gc_context>) -> Self {
         let local_registers = GcCell::allocate(context.gc_context, RegisterSet::new(0));
+        let caller_movie = context.swf.clone();
 
         Self {
             this: None,
@@ -162,6 +174,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             scope_stack: ScopeStack::new(),
             outer: ScopeChain::new(context.avm2.globals),
             caller_domain: context.avm2.globals,
+            caller_movie,
             subclass_object: None,
             activation_class: None,
             context,
@@ -176,13 +189,13 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
     ) -> Result<Self, Error> {
         let (method, global_object, domain) = script.init();
 
-        let num_locals = match method {
-            Method::Native { .. } => 0,
+        let (num_locals, caller_movie) = match method {
+            Method::Native { .. } => (0, context.swf.clone()),
             Method::Bytecode(bytecode) => {
                 let body: Result<_, Error> = bytecode.body().ok_or_else(|| {
                     "Cannot execute non-native method (for script) without body".into()
                 });
-                body?.num_locals
+                (body?.num_locals, bytecode.translation_unit().movie())
             }
         };
         let local_registers =
@@ -203,6 +216,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             scope_stack: ScopeStack::new(),
             outer: ScopeChain::new(domain),
             caller_domain: domain,
+            caller_movie,
             subclass_object: None,
             activation_class: None,
             context,
@@ -423,6 +437,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             scope_stack: ScopeStack::new(),
             outer,
             caller_domain: outer.domain(),
+            caller_movie: method.translation_unit().movie(),
             subclass_object,
             activation_class,
             context,
@@ -486,6 +501,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         subclass_object: Option<ClassObject<'gc>>,
         outer: ScopeChain<'gc>,
         caller_domain: Domain<'gc>,
+        caller_movie: Arc<SwfMovie>,
     ) -> Result<Self, Error> {
         let local_registers = GcCell::allocate(context.gc_context, RegisterSet::new(0));
 
@@ -499,6 +515,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
             scope_stack: ScopeStack::new(),
             outer,
             caller_domain,
+            caller_movie,
             subclass_object,
             activation_class: None,
             context,
@@ -594,6 +611,14 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         self.caller_domain
     }
 
+    /// Returns the movie of the original AS3 caller.
+    ///
+    /// Code that doesn't belong to any loaded movie, such as the player
+    /// globals, is treated as part of the root movie.
+    pub fn caller_movie(&self) -> Arc<SwfMovie> {
+        self.caller_movie.clone()
+    }
+
     /// Returns the global scope of this activation.
     ///
     /// The global scope refers to scope at the bottom of the
@@ -824,6 +849,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 Op::SetProperty { index } => self.op_set_property(method, index),
                 Op::InitProperty { index } => self.op_init_property(method, index),
                 Op::DeleteProperty { index } => self.op_delete_property(method, index),
+                Op::GetDescendants { index } => self.op_get_descendants(method, index),
                 Op::GetSuper { index } => self.op_get_super(method, index),
                 Op::SetSuper { index } => self.op_set_super(method, index),
                 Op::In => self.op_in(),
@@ -939,6 +965,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                 Op::TypeOf => self.op_type_of(),
                 Op::EscXAttr => self.op_esc_xattr(),
                 Op::EscXElem => self.op_esc_elem(),
+                Op::CheckFilter => self.op_check_filter(),
                 Op::LookupSwitch {
                     default_offset,
                     case_offsets,
@@ -1460,6 +1487,21 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    fn op_get_descendants(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        index: Index<AbcMultiname>,
+    ) -> Result<FrameControl<'gc>, Error> {
+        let multiname = self.pool_multiname(method, index)?;
+        let object = self.context.avm2.pop().coerce_to_object(self)?;
+
+        let descendants = object.get_descendants(&multiname, self)?;
+
+        self.context.avm2.push(descendants);
+
+        Ok(FrameControl::Continue)
+    }
+
     fn op_in(&mut self) -> Result<FrameControl<'gc>, Error> {
         let obj = self.context.avm2.pop().coerce_to_object(self)?;
         let name_value = self.context.avm2.pop();
@@ -1862,7 +1904,19 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         let value2 = self.context.avm2.pop();
         let value1 = self.context.avm2.pop();
 
-        // TODO: Special handling required for `Date` and ECMA-357/E4X `XML`
+        // ECMA-357 11.4.1: adding two XML values concatenates them into a new
+        // `XMLList`.
+        if let (Value::Object(o1), Value::Object(o2)) = (value1, value2) {
+            if let (Some(mut nodes), Some(nodes2)) = (xml_nodes(o1), xml_nodes(o2)) {
+                nodes.extend(nodes2);
+                let list = XmlListObject::new(self, nodes)?;
+                self.context.avm2.push(list);
+
+                return Ok(FrameControl::Continue);
+            }
+        }
+
+        // TODO: Special handling required for `Date`
         let sum_value = match (value1, value2) {
             (Value::Number(n1), Value::Number(n2)) => Value::Number(n1 + n2),
             (Value::String(s), value2) => Value::String(AvmString::concat(
@@ -2631,7 +2685,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
                             "object"
                         }
                     }
-                    Object::XmlObject(_) => {
+                    Object::XmlObject(_) | Object::XmlListObject(_) => {
                         if is_not_subclass {
                             "xml"
                         } else {
@@ -2654,23 +2708,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         let s = self.context.avm2.pop().coerce_to_string(self)?;
 
         // Implementation of `EscapeAttributeValue` from ECMA-357(10.2.1.2)
-        let mut r = WString::with_capacity(s.len(), s.is_wide());
-        for c in &s {
-            let escape: &[u8] = match u8::try_from(c) {
-                Ok(b'"') => b"&quot;",
-                Ok(b'<') => b"&lt;",
-                Ok(b'&') => b"&amp;",
-                Ok(b'\x0A') => b"&#xA;",
-                Ok(b'\x0D') => b"&#xD;",
-                Ok(b'\x09') => b"&#x9;",
-                _ => {
-                    r.push(c);
-                    continue;
-                }
-            };
-
-            r.push_str(WStr::from_units(escape));
-        }
+        let r = escape_attribute_value(&s);
         self.context
             .avm2
             .push(AvmString::new(self.context.gc_context, r));
@@ -2684,20 +2722,7 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
 
         // contrary to the avmplus documentation, this escapes the value on the top of the stack using EscapeElementValue from ECMA-357 *NOT* EscapeAttributeValue.
         // Implementation of `EscapeElementValue` from ECMA-357(10.2.1.1)
-        let mut r = WString::with_capacity(s.len(), s.is_wide());
-        for c in &s {
-            let escape: &[u8] = match u8::try_from(c) {
-                Ok(b'<') => b"&lt;",
-                Ok(b'>') => b"&gt;",
-                Ok(b'&') => b"&amp;",
-                _ => {
-                    r.push(c);
-                    continue;
-                }
-            };
-
-            r.push_str(WStr::from_units(escape));
-        }
+        let r = escape_element_value(&s);
         self.context
             .avm2
             .push(AvmString::new(self.context.gc_context, r));
@@ -2705,6 +2730,25 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 
+    /// Implements `Op::CheckFilter`
+    fn op_check_filter(&mut self) -> Result<FrameControl<'gc>, Error> {
+        let value = self.context.avm2.pop();
+
+        if let Value::Object(o) = value {
+            if o.as_xml_object().is_some() || o.as_xml_list_object().is_some() {
+                self.context.avm2.push(value);
+
+                return Ok(FrameControl::Continue);
+            }
+        }
+
+        Err(format!(
+            "TypeError: Error #1123: Filter operator not supported on type {}.",
+            value.coerce_to_string(self)?
+        )
+        .into())
+    }
+
     /// Implements `Op::LookupSwitch`
     fn op_lookup_switch<'b>(
         &mut self,
@@ -3028,3 +3072,14 @@ impl<'a, 'gc, 'gc_context> Activation<'a, 'gc, 'gc_context> {
         Ok(FrameControl::Continue)
     }
 }
+
+/// Retrieve the nodes of an `XML` or `XMLList` object, as if it were a list.
+fn xml_nodes<'gc>(object: Object<'gc>) -> Option<Vec<E4XNode<'gc>>> {
+    if let Some(xml) = object.as_xml_object() {
+        return Some(vec![xml.node()]);
+    }
+
+    object
+        .as_xml_list_object()
+        .map(|list| list.children().clone())
+}
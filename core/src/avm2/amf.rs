@@ -0,0 +1,1794 @@
+//! AMF serialization of AVM2 values.
+//!
+//! AMF3 is read and written directly against a `ByteArray`, since objects
+//! that implement `IExternalizable` read and write their own data from the
+//! same stream in the middle of (de)serialization. AMF0 values are decoded
+//! with `flash_lso`, which is also used for shared objects saved by older
+//! movies.
+
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::bytearray::{ByteArrayStorage, ObjectEncoding};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{
+    ArrayObject, ByteArrayObject, ClassObject, Object, TObject, VectorObject,
+};
+use crate::avm2::traits::TraitKind;
+use crate::avm2::vector::VectorStorage;
+use crate::avm2::{Activation, Error, Value};
+use crate::string::{AvmString, WStr};
+use flash_lso::amf0::read::AMF0Decoder;
+use flash_lso::types::Value as AmfValue;
+use fnv::FnvHashMap;
+use std::convert::TryInto;
+
+const AMF0_NUMBER: u8 = 0x00;
+const AMF0_BOOLEAN: u8 = 0x01;
+const AMF0_STRING: u8 = 0x02;
+const AMF0_OBJECT: u8 = 0x03;
+const AMF0_NULL: u8 = 0x05;
+const AMF0_UNDEFINED: u8 = 0x06;
+const AMF0_REFERENCE: u8 = 0x07;
+const AMF0_ECMA_ARRAY: u8 = 0x08;
+const AMF0_OBJECT_END: u8 = 0x09;
+const AMF0_STRICT_ARRAY: u8 = 0x0A;
+const AMF0_DATE: u8 = 0x0B;
+const AMF0_LONG_STRING: u8 = 0x0C;
+const AMF0_XML: u8 = 0x0F;
+const AMF0_TYPED_OBJECT: u8 = 0x10;
+const AMF0_AVMPLUS: u8 = 0x11;
+
+const AMF3_UNDEFINED: u8 = 0x00;
+const AMF3_NULL: u8 = 0x01;
+const AMF3_FALSE: u8 = 0x02;
+const AMF3_TRUE: u8 = 0x03;
+const AMF3_INTEGER: u8 = 0x04;
+const AMF3_DOUBLE: u8 = 0x05;
+const AMF3_STRING: u8 = 0x06;
+const AMF3_XML_DOC: u8 = 0x07;
+const AMF3_DATE: u8 = 0x08;
+const AMF3_ARRAY: u8 = 0x09;
+const AMF3_OBJECT: u8 = 0x0A;
+const AMF3_XML: u8 = 0x0B;
+const AMF3_BYTE_ARRAY: u8 = 0x0C;
+const AMF3_VECTOR_INT: u8 = 0x0D;
+const AMF3_VECTOR_UINT: u8 = 0x0E;
+const AMF3_VECTOR_DOUBLE: u8 = 0x0F;
+const AMF3_VECTOR_OBJECT: u8 = 0x10;
+const AMF3_DICTIONARY: u8 = 0x11;
+
+/// The range of numbers that AMF3 can store as a 29-bit integer.
+const AMF3_INT_MIN: f64 = -(1 << 28) as f64;
+const AMF3_INT_MAX: f64 = ((1 << 28) - 1) as f64;
+
+/// The header of a shared object file, up to the length of its name.
+const LSO_MAGIC: [u8; 2] = [0x00, 0xBF];
+const LSO_SIGNATURE: [u8; 10] = *b"TCSO\x00\x04\x00\x00\x00\x00";
+
+/// Register a class under an alias, so that its instances keep their type
+/// when they are serialized and read back.
+pub fn register_class_alias<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    alias: AvmString<'gc>,
+    class: ClassObject<'gc>,
+) {
+    let aliases = &mut activation.avm2().class_aliases;
+    aliases.retain(|_, aliased| !Object::ptr_eq(*aliased, class));
+    aliases.insert(alias, class);
+}
+
+/// Look up the class registered under an alias.
+pub fn class_by_alias<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    alias: AvmString<'gc>,
+) -> Option<ClassObject<'gc>> {
+    activation.avm2().class_aliases.get(&alias).copied()
+}
+
+/// Look up the alias that a class was registered under.
+fn alias_of_class<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    class: ClassObject<'gc>,
+) -> Option<AvmString<'gc>> {
+    activation
+        .avm2()
+        .class_aliases
+        .iter()
+        .find(|(_, aliased)| Object::ptr_eq(**aliased, class))
+        .map(|(alias, _)| *alias)
+}
+
+/// The traits of an object, as they are described in AMF3.
+#[derive(Clone, PartialEq)]
+struct AmfTraits<'gc> {
+    /// The alias of the object's class, or an empty string for anonymous
+    /// objects.
+    class_name: AvmString<'gc>,
+
+    /// The public sealed properties of the class, in serialization order.
+    sealed: Vec<AvmString<'gc>>,
+
+    /// Whether the object can hold dynamic properties.
+    dynamic: bool,
+
+    /// Whether the object serializes itself with `writeExternal`.
+    externalizable: bool,
+}
+
+impl<'gc> AmfTraits<'gc> {
+    /// Traits of a plain, dynamic `Object`.
+    fn anonymous() -> Self {
+        Self {
+            class_name: "".into(),
+            sealed: Vec::new(),
+            dynamic: true,
+            externalizable: false,
+        }
+    }
+
+    /// Describe the traits of an object.
+    fn of(activation: &mut Activation<'_, 'gc, '_>, object: Object<'gc>) -> Result<Self, Error> {
+        let class = match object.instance_of() {
+            Some(class) if !Object::ptr_eq(class, activation.avm2().classes().object) => class,
+            _ => return Ok(Self::anonymous()),
+        };
+
+        let class_name = alias_of_class(activation, class).unwrap_or_else(|| "".into());
+
+        let iexternalizable = activation.avm2().classes().iexternalizable;
+        if class.has_class_in_chain(iexternalizable, activation)? {
+            return Ok(Self {
+                class_name,
+                sealed: Vec::new(),
+                dynamic: false,
+                externalizable: true,
+            });
+        }
+
+        Ok(Self {
+            class_name,
+            sealed: sealed_properties(class),
+            dynamic: !class.inner_class_definition().read().is_sealed(),
+            externalizable: false,
+        })
+    }
+}
+
+/// List the public properties of a class that AMF serializes: its variables,
+/// and its accessors that can be both read and written.
+///
+/// Superclass properties come first.
+fn sealed_properties<'gc>(class: ClassObject<'gc>) -> Vec<AvmString<'gc>> {
+    let mut chain = Vec::new();
+    let mut next = Some(class);
+    while let Some(class) = next {
+        chain.push(class);
+        next = class.superclass_object();
+    }
+
+    let mut sealed = Vec::new();
+    let mut getters = Vec::new();
+    let mut setters = Vec::new();
+    for class in chain.into_iter().rev() {
+        let class_def = class.inner_class_definition();
+        let read = class_def.read();
+        for instance_trait in read.instance_traits() {
+            let name = instance_trait.name();
+            if !name.namespace().is_public() {
+                continue;
+            }
+
+            match instance_trait.kind() {
+                TraitKind::Slot { .. } => sealed.push(name.local_name()),
+                TraitKind::Getter { .. } => getters.push(name.local_name()),
+                TraitKind::Setter { .. } => setters.push(name.local_name()),
+                _ => {}
+            }
+        }
+    }
+
+    for getter in getters {
+        if setters.contains(&getter) && !sealed.contains(&getter) {
+            sealed.push(getter);
+        }
+    }
+
+    sealed
+}
+
+/// Collect the enumerable dynamic properties of an object.
+///
+/// `Dictionary` keys are returned as-is, so object keys stay objects.
+fn dynamic_properties<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Vec<(Value<'gc>, Value<'gc>)>, Error> {
+    let dictionary = object.as_dictionary_object();
+    let mut properties = Vec::new();
+    let mut last_index = 0;
+    while let Some(index) = object.get_next_enumerant(last_index, activation)? {
+        if index == 0 {
+            break;
+        }
+
+        let name = object.get_enumerant_name(index, activation)?;
+        let value = match (name, dictionary) {
+            (Value::Object(key), Some(dictionary)) => dictionary.get_property_by_object(key),
+            _ => object.get_enumerant_value(index, activation)?,
+        };
+        properties.push((name, value));
+        last_index = index;
+    }
+
+    Ok(properties)
+}
+
+/// Check if a value is a function, which AMF does not serialize.
+fn is_function(value: &Value<'_>) -> bool {
+    matches!(value, Value::Object(object) if object.as_executable().is_some())
+}
+
+/// Write bytes at the current position of a `ByteArray`.
+fn write_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    target: Object<'gc>,
+    bytes: &[u8],
+) -> Result<(), Error> {
+    target
+        .as_bytearray_mut(activation.context.gc_context)
+        .ok_or("Error: AMF data can only be written to a ByteArray")?
+        .write_bytes(bytes)
+}
+
+/// Read bytes from the current position of a `ByteArray`.
+fn read_bytes(source: Object<'_>, amount: usize) -> Result<Vec<u8>, Error> {
+    let bytearray = source
+        .as_bytearray()
+        .ok_or("Error: AMF data can only be read from a ByteArray")?;
+    let bytes = bytearray.read_bytes(amount)?.to_vec();
+
+    Ok(bytes)
+}
+
+/// Writes AVM2 values as AMF3 into a `ByteArray`.
+struct Amf3Writer<'gc> {
+    target: Object<'gc>,
+    strings: FnvHashMap<AvmString<'gc>, u32>,
+    objects: FnvHashMap<Object<'gc>, u32>,
+    traits: Vec<AmfTraits<'gc>>,
+}
+
+impl<'gc> Amf3Writer<'gc> {
+    fn new(target: Object<'gc>) -> Self {
+        Self {
+            target,
+            strings: Default::default(),
+            objects: Default::default(),
+            traits: Vec::new(),
+        }
+    }
+
+    fn write_bytes(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
+        write_bytes(activation, self.target, bytes)
+    }
+
+    fn write_u8(&self, activation: &mut Activation<'_, 'gc, '_>, value: u8) -> Result<(), Error> {
+        self.write_bytes(activation, &[value])
+    }
+
+    /// Write a variable-length 29-bit unsigned integer.
+    fn write_u29(&self, activation: &mut Activation<'_, 'gc, '_>, value: u32) -> Result<(), Error> {
+        let value = value & 0x1FFF_FFFF;
+        if value < 0x80 {
+            self.write_bytes(activation, &[value as u8])
+        } else if value < 0x4000 {
+            self.write_bytes(activation, &[(value >> 7) as u8 | 0x80, value as u8 & 0x7F])
+        } else if value < 0x20_0000 {
+            self.write_bytes(
+                activation,
+                &[
+                    (value >> 14) as u8 | 0x80,
+                    (value >> 7) as u8 | 0x80,
+                    value as u8 & 0x7F,
+                ],
+            )
+        } else {
+            self.write_bytes(
+                activation,
+                &[
+                    (value >> 22) as u8 | 0x80,
+                    (value >> 15) as u8 | 0x80,
+                    (value >> 8) as u8 | 0x80,
+                    value as u8,
+                ],
+            )
+        }
+    }
+
+    /// Write a string without a marker, as used in values, property names and
+    /// class names.
+    fn write_string(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        string: AvmString<'gc>,
+    ) -> Result<(), Error> {
+        if string.is_empty() {
+            return self.write_u29(activation, 1);
+        }
+
+        if let Some(index) = self.strings.get(&string) {
+            return self.write_u29(activation, index << 1);
+        }
+        self.strings.insert(string, self.strings.len() as u32);
+
+        let utf8 = string.to_utf8_lossy();
+        self.write_u29(activation, ((utf8.len() as u32) << 1) | 1)?;
+        self.write_bytes(activation, utf8.as_bytes())
+    }
+
+    /// Write a reference to an object that was already written.
+    ///
+    /// Objects that have not been written yet are added to the reference
+    /// table, and `false` is returned so that the caller writes them out.
+    fn write_reference(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        object: Object<'gc>,
+    ) -> Result<bool, Error> {
+        if let Some(index) = self.objects.get(&object) {
+            self.write_u29(activation, index << 1)?;
+            return Ok(true);
+        }
+
+        self.objects.insert(object, self.objects.len() as u32);
+        Ok(false)
+    }
+
+    fn write_number(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: f64,
+    ) -> Result<(), Error> {
+        let is_integral = value.fract() == 0.0 && !(value == 0.0 && value.is_sign_negative());
+        if is_integral && (AMF3_INT_MIN..=AMF3_INT_MAX).contains(&value) {
+            self.write_u8(activation, AMF3_INTEGER)?;
+            self.write_u29(activation, value as i32 as u32)
+        } else {
+            self.write_u8(activation, AMF3_DOUBLE)?;
+            self.write_bytes(activation, &value.to_be_bytes())
+        }
+    }
+
+    fn write_value(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: Value<'gc>,
+    ) -> Result<(), Error> {
+        match value {
+            Value::Undefined => self.write_u8(activation, AMF3_UNDEFINED),
+            Value::Null => self.write_u8(activation, AMF3_NULL),
+            Value::Bool(false) => self.write_u8(activation, AMF3_FALSE),
+            Value::Bool(true) => self.write_u8(activation, AMF3_TRUE),
+            Value::Number(n) => self.write_number(activation, n),
+            Value::Unsigned(u) => self.write_number(activation, u as f64),
+            Value::Integer(i) => self.write_number(activation, i as f64),
+            Value::String(s) => {
+                self.write_u8(activation, AMF3_STRING)?;
+                self.write_string(activation, s)
+            }
+            Value::Object(o) => self.write_object(activation, o),
+        }
+    }
+
+    fn write_object(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        object: Object<'gc>,
+    ) -> Result<(), Error> {
+        if object.as_executable().is_some() {
+            return self.write_u8(activation, AMF3_UNDEFINED);
+        }
+
+        if let Some(date) = object.as_date_object() {
+            self.write_u8(activation, AMF3_DATE)?;
+            if self.write_reference(activation, object)? {
+                return Ok(());
+            }
+
+            let time = date
+                .date_time()
+                .map(|date_time| date_time.timestamp_millis() as f64)
+                .unwrap_or(f64::NAN);
+            self.write_u29(activation, 1)?;
+            return self.write_bytes(activation, &time.to_be_bytes());
+        }
+
+        if let Some(xml) = object.as_xml_object() {
+            self.write_u8(activation, AMF3_XML)?;
+            if self.write_reference(activation, object)? {
+                return Ok(());
+            }
+
+            let xml_string = xml.node().xml_to_xml_string(activation);
+            let utf8 = xml_string.to_utf8_lossy();
+            self.write_u29(activation, ((utf8.len() as u32) << 1) | 1)?;
+            return self.write_bytes(activation, utf8.as_bytes());
+        }
+
+        let bytes = object.as_bytearray().map(|bytes| bytes.bytes().to_vec());
+        if let Some(bytes) = bytes {
+            self.write_u8(activation, AMF3_BYTE_ARRAY)?;
+            if self.write_reference(activation, object)? {
+                return Ok(());
+            }
+
+            self.write_u29(activation, ((bytes.len() as u32) << 1) | 1)?;
+            return self.write_bytes(activation, &bytes);
+        }
+
+        if object.as_array_storage().is_some() {
+            return self.write_array(activation, object);
+        }
+
+        if object.as_vector_storage().is_some() {
+            return self.write_vector(activation, object);
+        }
+
+        if object.as_dictionary_object().is_some() {
+            return self.write_dictionary(activation, object);
+        }
+
+        self.write_plain_object(activation, object)
+    }
+
+    fn write_array(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        array: Object<'gc>,
+    ) -> Result<(), Error> {
+        self.write_u8(activation, AMF3_ARRAY)?;
+        if self.write_reference(activation, array)? {
+            return Ok(());
+        }
+
+        let dense: Vec<Value<'gc>> = array
+            .as_array_storage()
+            .map(|storage| {
+                storage
+                    .iter()
+                    .map(|value| value.unwrap_or(Value::Undefined))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.write_u29(activation, ((dense.len() as u32) << 1) | 1)?;
+
+        // Array indices are enumerated as numbers, named properties as strings.
+        for (name, value) in dynamic_properties(activation, array)? {
+            if let Value::String(name) = name {
+                if !is_function(&value) {
+                    self.write_string(activation, name)?;
+                    self.write_value(activation, value)?;
+                }
+            }
+        }
+        self.write_string(activation, "".into())?;
+
+        for value in dense {
+            self.write_value(activation, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_vector(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        vector: Object<'gc>,
+    ) -> Result<(), Error> {
+        let (values, is_fixed, value_type) = match vector.as_vector_storage() {
+            Some(storage) => (
+                storage.iter().collect::<Vec<_>>(),
+                storage.is_fixed(),
+                storage.value_type(),
+            ),
+            None => return Ok(()),
+        };
+
+        let classes = activation.avm2().classes();
+        let marker = if Object::ptr_eq(value_type, classes.int) {
+            AMF3_VECTOR_INT
+        } else if Object::ptr_eq(value_type, classes.uint) {
+            AMF3_VECTOR_UINT
+        } else if Object::ptr_eq(value_type, classes.number) {
+            AMF3_VECTOR_DOUBLE
+        } else {
+            AMF3_VECTOR_OBJECT
+        };
+        let is_any = Object::ptr_eq(value_type, classes.object);
+
+        self.write_u8(activation, marker)?;
+        if self.write_reference(activation, vector)? {
+            return Ok(());
+        }
+
+        self.write_u29(activation, ((values.len() as u32) << 1) | 1)?;
+        self.write_u8(activation, is_fixed as u8)?;
+
+        match marker {
+            AMF3_VECTOR_INT => {
+                for value in values {
+                    let value = value.coerce_to_i32(activation)?;
+                    self.write_bytes(activation, &value.to_be_bytes())?;
+                }
+            }
+            AMF3_VECTOR_UINT => {
+                for value in values {
+                    let value = value.coerce_to_u32(activation)?;
+                    self.write_bytes(activation, &value.to_be_bytes())?;
+                }
+            }
+            AMF3_VECTOR_DOUBLE => {
+                for value in values {
+                    let value = value.coerce_to_number(activation)?;
+                    self.write_bytes(activation, &value.to_be_bytes())?;
+                }
+            }
+            _ => {
+                let type_name = if is_any {
+                    "".into()
+                } else if let Some(alias) = alias_of_class(activation, value_type) {
+                    alias
+                } else {
+                    let name = value_type.inner_class_definition().read().name();
+                    name.to_qualified_name(activation.context.gc_context)
+                };
+                self.write_string(activation, type_name)?;
+
+                for value in values {
+                    self.write_value(activation, value)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_dictionary(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        dictionary: Object<'gc>,
+    ) -> Result<(), Error> {
+        self.write_u8(activation, AMF3_DICTIONARY)?;
+        if self.write_reference(activation, dictionary)? {
+            return Ok(());
+        }
+
+        let entries = dynamic_properties(activation, dictionary)?;
+        self.write_u29(activation, ((entries.len() as u32) << 1) | 1)?;
+        // Weak keys are not tracked, so dictionaries are always written as strong.
+        self.write_u8(activation, 0)?;
+
+        for (key, value) in entries {
+            self.write_value(activation, key)?;
+            self.write_value(activation, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_plain_object(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        object: Object<'gc>,
+    ) -> Result<(), Error> {
+        self.write_u8(activation, AMF3_OBJECT)?;
+        if self.write_reference(activation, object)? {
+            return Ok(());
+        }
+
+        let traits = AmfTraits::of(activation, object)?;
+        if let Some(index) = self.traits.iter().position(|known| *known == traits) {
+            self.write_u29(activation, ((index as u32) << 2) | 1)?;
+        } else {
+            let mut header = ((traits.sealed.len() as u32) << 4) | 0b11;
+            if traits.externalizable {
+                header |= 0b100;
+            }
+            if traits.dynamic {
+                header |= 0b1000;
+            }
+            self.write_u29(activation, header)?;
+            self.write_string(activation, traits.class_name)?;
+            for name in &traits.sealed {
+                self.write_string(activation, *name)?;
+            }
+            self.traits.push(traits.clone());
+        }
+
+        if traits.externalizable {
+            object.call_property(
+                &Multiname::public("writeExternal"),
+                &[self.target.into()],
+                activation,
+            )?;
+            return Ok(());
+        }
+
+        for name in traits.sealed {
+            let value = object.get_property(&Multiname::public(name), activation)?;
+            self.write_value(activation, value)?;
+        }
+
+        if traits.dynamic {
+            for (name, value) in dynamic_properties(activation, object)? {
+                if is_function(&value) {
+                    continue;
+                }
+
+                let name = name.coerce_to_string(activation)?;
+                self.write_string(activation, name)?;
+                self.write_value(activation, value)?;
+            }
+            self.write_string(activation, "".into())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads AMF3 values from a `ByteArray`.
+struct Amf3Reader<'gc> {
+    source: Object<'gc>,
+    strings: Vec<AvmString<'gc>>,
+    objects: Vec<Object<'gc>>,
+    traits: Vec<AmfTraits<'gc>>,
+}
+
+impl<'gc> Amf3Reader<'gc> {
+    fn new(source: Object<'gc>) -> Self {
+        Self {
+            source,
+            strings: Vec::new(),
+            objects: Vec::new(),
+            traits: Vec::new(),
+        }
+    }
+
+    fn read_bytes(&self, amount: usize) -> Result<Vec<u8>, Error> {
+        read_bytes(self.source, amount)
+    }
+
+    fn read_u8(&self) -> Result<u8, Error> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_double(&self) -> Result<f64, Error> {
+        let bytes = self.read_bytes(8)?;
+        Ok(f64::from_be_bytes(bytes[..].try_into().unwrap()))
+    }
+
+    /// Read a variable-length 29-bit unsigned integer.
+    fn read_u29(&self) -> Result<u32, Error> {
+        let mut value = 0;
+        for i in 0..4 {
+            let byte = self.read_u8()? as u32;
+            if i == 3 {
+                value = (value << 8) | byte;
+                break;
+            }
+
+            value = (value << 7) | (byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Read the header of a value that may be a reference to an earlier
+    /// object.
+    ///
+    /// Returns either the referenced object, or the remaining bits of the
+    /// header for an inline value.
+    fn read_object_header(&self) -> Result<Result<Object<'gc>, u32>, Error> {
+        let header = self.read_u29()?;
+        if header & 1 == 0 {
+            let object = self
+                .objects
+                .get((header >> 1) as usize)
+                .copied()
+                .ok_or("Error: Invalid AMF3 object reference")?;
+            return Ok(Ok(object));
+        }
+
+        Ok(Err(header >> 1))
+    }
+
+    /// Read a string without a marker.
+    fn read_string(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<AvmString<'gc>, Error> {
+        let header = self.read_u29()?;
+        if header & 1 == 0 {
+            return self
+                .strings
+                .get((header >> 1) as usize)
+                .copied()
+                .ok_or_else(|| "Error: Invalid AMF3 string reference".into());
+        }
+
+        let length = (header >> 1) as usize;
+        if length == 0 {
+            return Ok("".into());
+        }
+
+        let bytes = self.read_bytes(length)?;
+        let string = AvmString::new_utf8_bytes(activation.context.gc_context, bytes);
+        self.strings.push(string);
+
+        Ok(string)
+    }
+
+    fn read_value(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let marker = self.read_u8()?;
+        Ok(match marker {
+            AMF3_UNDEFINED => Value::Undefined,
+            AMF3_NULL => Value::Null,
+            AMF3_FALSE => false.into(),
+            AMF3_TRUE => true.into(),
+            AMF3_INTEGER => (((self.read_u29()? << 3) as i32) >> 3).into(),
+            AMF3_DOUBLE => self.read_double()?.into(),
+            AMF3_STRING => self.read_string(activation)?.into(),
+            AMF3_XML_DOC | AMF3_XML => match self.read_object_header()? {
+                Ok(xml) => xml.into(),
+                Err(length) => {
+                    let bytes = self.read_bytes(length as usize)?;
+                    let string = AvmString::new_utf8_bytes(activation.context.gc_context, bytes);
+                    let xml_class = activation.avm2().classes().xml;
+                    let xml = xml_class.construct(activation, &[string.into()])?;
+                    self.objects.push(xml);
+                    xml.into()
+                }
+            },
+            AMF3_DATE => match self.read_object_header()? {
+                Ok(date) => date.into(),
+                Err(_) => {
+                    let time = self.read_double()?;
+                    let date_class = activation.avm2().classes().date;
+                    let date = date_class.construct(activation, &[time.into()])?;
+                    self.objects.push(date);
+                    date.into()
+                }
+            },
+            AMF3_ARRAY => self.read_array(activation)?,
+            AMF3_OBJECT => self.read_object(activation)?,
+            AMF3_BYTE_ARRAY => match self.read_object_header()? {
+                Ok(bytearray) => bytearray.into(),
+                Err(length) => {
+                    let bytes = self.read_bytes(length as usize)?;
+                    let storage = ByteArrayStorage::from_vec(bytes);
+                    let bytearray = ByteArrayObject::from_storage(activation, storage)?;
+                    self.objects.push(bytearray);
+                    bytearray.into()
+                }
+            },
+            AMF3_VECTOR_INT | AMF3_VECTOR_UINT | AMF3_VECTOR_DOUBLE | AMF3_VECTOR_OBJECT => {
+                self.read_vector(activation, marker)?
+            }
+            AMF3_DICTIONARY => self.read_dictionary(activation)?,
+            _ => return Err(format!("Error: Unknown AMF3 marker {:#04x}", marker).into()),
+        })
+    }
+
+    fn read_array(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let length = match self.read_object_header()? {
+            Ok(array) => return Ok(array.into()),
+            Err(length) => length as usize,
+        };
+
+        let mut array = ArrayObject::empty(activation)?;
+        self.objects.push(array);
+
+        loop {
+            let name = self.read_string(activation)?;
+            if name.is_empty() {
+                break;
+            }
+
+            let value = self.read_value(activation)?;
+            array.set_property(&QName::dynamic_name(name).into(), value, activation)?;
+        }
+
+        for index in 0..length {
+            let value = self.read_value(activation)?;
+            if let Some(mut storage) = array.as_array_storage_mut(activation.context.gc_context) {
+                storage.set(index, value);
+            }
+        }
+
+        Ok(array.into())
+    }
+
+    fn read_object(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let header = match self.read_object_header()? {
+            Ok(object) => return Ok(object.into()),
+            Err(header) => header,
+        };
+
+        let traits = if header & 1 == 0 {
+            self.traits
+                .get((header >> 1) as usize)
+                .cloned()
+                .ok_or("Error: Invalid AMF3 traits reference")?
+        } else {
+            let class_name = self.read_string(activation)?;
+            let mut sealed = Vec::new();
+            for _ in 0..(header >> 3) {
+                sealed.push(self.read_string(activation)?);
+            }
+
+            let traits = AmfTraits {
+                class_name,
+                sealed,
+                dynamic: header & 0b100 != 0,
+                externalizable: header & 0b10 != 0,
+            };
+            self.traits.push(traits.clone());
+            traits
+        };
+
+        let class = if traits.class_name.is_empty() {
+            None
+        } else {
+            class_by_alias(activation, traits.class_name)
+        };
+        let class = class.unwrap_or_else(|| activation.avm2().classes().object);
+        let mut object = class.construct(activation, &[])?;
+        self.objects.push(object);
+
+        if traits.externalizable {
+            let iexternalizable = activation.avm2().classes().iexternalizable;
+            if !object.is_of_type(iexternalizable, activation)? {
+                return Err(format!(
+                    "ArgumentError: Error #2173: Unable to read object in stream. The class {} does not implement flash.utils.IExternalizable but is aliased to an externalizable class.",
+                    traits.class_name
+                )
+                .into());
+            }
+
+            object.call_property(
+                &Multiname::public("readExternal"),
+                &[self.source.into()],
+                activation,
+            )?;
+            return Ok(object.into());
+        }
+
+        for name in traits.sealed {
+            let value = self.read_value(activation)?;
+            object.set_property(&Multiname::public(name), value, activation)?;
+        }
+
+        if traits.dynamic {
+            loop {
+                let name = self.read_string(activation)?;
+                if name.is_empty() {
+                    break;
+                }
+
+                let value = self.read_value(activation)?;
+                object.set_property(&QName::dynamic_name(name).into(), value, activation)?;
+            }
+        }
+
+        Ok(object.into())
+    }
+
+    /// Find the class named by the type of a serialized object vector.
+    fn vector_type(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        type_name: AvmString<'gc>,
+    ) -> ClassObject<'gc> {
+        let object_class = activation.avm2().classes().object;
+        if type_name.is_empty() || &*type_name == WStr::from_units(b"*") {
+            return object_class;
+        }
+
+        if let Some(class) = class_by_alias(activation, type_name) {
+            return class;
+        }
+
+        let qname = QName::from_qualified_name(type_name, activation.context.gc_context);
+        activation
+            .domain()
+            .get_defined_value(activation, qname)
+            .ok()
+            .and_then(|class| class.as_object())
+            .and_then(|class| class.as_class_object())
+            .unwrap_or(object_class)
+    }
+
+    fn read_vector(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        marker: u8,
+    ) -> Result<Value<'gc>, Error> {
+        let length = match self.read_object_header()? {
+            Ok(vector) => return Ok(vector.into()),
+            Err(length) => length as usize,
+        };
+        let is_fixed = self.read_u8()? != 0;
+
+        let value_type = match marker {
+            AMF3_VECTOR_INT => activation.avm2().classes().int,
+            AMF3_VECTOR_UINT => activation.avm2().classes().uint,
+            AMF3_VECTOR_DOUBLE => activation.avm2().classes().number,
+            _ => {
+                let type_name = self.read_string(activation)?;
+                self.vector_type(activation, type_name)
+            }
+        };
+
+        let storage = VectorStorage::new(0, false, value_type, activation);
+        let vector = VectorObject::from_vector(storage, activation)?;
+        self.objects.push(vector);
+
+        for _ in 0..length {
+            let value = match marker {
+                AMF3_VECTOR_INT => {
+                    let bytes = self.read_bytes(4)?;
+                    i32::from_be_bytes(bytes[..].try_into().unwrap()).into()
+                }
+                AMF3_VECTOR_UINT => {
+                    let bytes = self.read_bytes(4)?;
+                    u32::from_be_bytes(bytes[..].try_into().unwrap()).into()
+                }
+                AMF3_VECTOR_DOUBLE => self.read_double()?.into(),
+                _ => self
+                    .read_value(activation)?
+                    .coerce_to_type(activation, value_type)?,
+            };
+
+            if let Some(mut storage) = vector.as_vector_storage_mut(activation.context.gc_context) {
+                storage.push(value)?;
+            }
+        }
+
+        if let Some(mut storage) = vector.as_vector_storage_mut(activation.context.gc_context) {
+            storage.set_is_fixed(is_fixed);
+        }
+
+        Ok(vector.into())
+    }
+
+    fn read_dictionary(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let length = match self.read_object_header()? {
+            Ok(dictionary) => return Ok(dictionary.into()),
+            Err(length) => length,
+        };
+        let weak_keys = self.read_u8()? != 0;
+
+        let dictionary_class = activation.avm2().classes().dictionary;
+        let mut dictionary = dictionary_class.construct(activation, &[weak_keys.into()])?;
+        self.objects.push(dictionary);
+
+        for _ in 0..length {
+            let key = self.read_value(activation)?;
+            let value = self.read_value(activation)?;
+            match (key, dictionary.as_dictionary_object()) {
+                (Value::Object(key), Some(dictionary)) => {
+                    dictionary.set_property_by_object(key, value, activation.context.gc_context)
+                }
+                _ => {
+                    let key = key.coerce_to_string(activation)?;
+                    dictionary.set_property(&QName::dynamic_name(key).into(), value, activation)?;
+                }
+            }
+        }
+
+        Ok(dictionary.into())
+    }
+}
+
+/// Writes AVM2 values as AMF0 into a `ByteArray`.
+///
+/// Values that AMF0 cannot represent are written as AMF3.
+struct Amf0Writer<'gc> {
+    target: Object<'gc>,
+    objects: FnvHashMap<Object<'gc>, u16>,
+}
+
+impl<'gc> Amf0Writer<'gc> {
+    fn new(target: Object<'gc>) -> Self {
+        Self {
+            target,
+            objects: Default::default(),
+        }
+    }
+
+    fn write_bytes(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        bytes: &[u8],
+    ) -> Result<(), Error> {
+        write_bytes(activation, self.target, bytes)
+    }
+
+    fn write_u8(&self, activation: &mut Activation<'_, 'gc, '_>, value: u8) -> Result<(), Error> {
+        self.write_bytes(activation, &[value])
+    }
+
+    /// Write a string without a marker, as used in property and class names.
+    fn write_utf8(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        string: AvmString<'gc>,
+    ) -> Result<(), Error> {
+        let utf8 = string.to_utf8_lossy();
+        let length = utf8.len().min(u16::MAX as usize);
+        self.write_bytes(activation, &(length as u16).to_be_bytes())?;
+        self.write_bytes(activation, &utf8.as_bytes()[..length])
+    }
+
+    /// Write the properties of an object, followed by the object end marker.
+    fn write_properties(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        properties: Vec<(AvmString<'gc>, Value<'gc>)>,
+    ) -> Result<(), Error> {
+        for (name, value) in properties {
+            if is_function(&value) {
+                continue;
+            }
+
+            self.write_utf8(activation, name)?;
+            self.write_value(activation, value)?;
+        }
+
+        self.write_utf8(activation, "".into())?;
+        self.write_u8(activation, AMF0_OBJECT_END)
+    }
+
+    /// Write a reference to an object that was already written.
+    ///
+    /// Objects that have not been written yet are added to the reference
+    /// table, and `false` is returned so that the caller writes them out.
+    fn write_reference(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        object: Object<'gc>,
+    ) -> Result<bool, Error> {
+        if let Some(index) = self.objects.get(&object) {
+            self.write_u8(activation, AMF0_REFERENCE)?;
+            self.write_bytes(activation, &index.to_be_bytes())?;
+            return Ok(true);
+        }
+
+        if self.objects.len() < u16::MAX as usize {
+            self.objects.insert(object, self.objects.len() as u16);
+        }
+        Ok(false)
+    }
+
+    fn write_value(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: Value<'gc>,
+    ) -> Result<(), Error> {
+        match value {
+            Value::Undefined => self.write_u8(activation, AMF0_UNDEFINED),
+            Value::Null => self.write_u8(activation, AMF0_NULL),
+            Value::Bool(b) => self.write_bytes(activation, &[AMF0_BOOLEAN, b as u8]),
+            Value::Number(_) | Value::Unsigned(_) | Value::Integer(_) => {
+                let value = value.coerce_to_number(activation)?;
+                self.write_u8(activation, AMF0_NUMBER)?;
+                self.write_bytes(activation, &value.to_be_bytes())
+            }
+            Value::String(s) => {
+                let utf8 = s.to_utf8_lossy();
+                if utf8.len() > u16::MAX as usize {
+                    self.write_u8(activation, AMF0_LONG_STRING)?;
+                    self.write_bytes(activation, &(utf8.len() as u32).to_be_bytes())?;
+                } else {
+                    self.write_u8(activation, AMF0_STRING)?;
+                    self.write_bytes(activation, &(utf8.len() as u16).to_be_bytes())?;
+                }
+                self.write_bytes(activation, utf8.as_bytes())
+            }
+            Value::Object(o) => self.write_object(activation, o),
+        }
+    }
+
+    fn write_object(
+        &mut self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        object: Object<'gc>,
+    ) -> Result<(), Error> {
+        if object.as_executable().is_some() {
+            return self.write_u8(activation, AMF0_UNDEFINED);
+        }
+
+        if let Some(date) = object.as_date_object() {
+            let time = date
+                .date_time()
+                .map(|date_time| date_time.timestamp_millis() as f64)
+                .unwrap_or(f64::NAN);
+            self.write_u8(activation, AMF0_DATE)?;
+            self.write_bytes(activation, &time.to_be_bytes())?;
+            // Time zone, which is unused.
+            return self.write_bytes(activation, &[0, 0]);
+        }
+
+        if let Some(xml) = object.as_xml_object() {
+            let xml_string = xml.node().xml_to_xml_string(activation);
+            let utf8 = xml_string.to_utf8_lossy();
+            self.write_u8(activation, AMF0_XML)?;
+            self.write_bytes(activation, &(utf8.len() as u32).to_be_bytes())?;
+            return self.write_bytes(activation, utf8.as_bytes());
+        }
+
+        let is_amf3_only = object.as_bytearray().is_some()
+            || object.as_vector_storage().is_some()
+            || object.as_dictionary_object().is_some();
+        let traits = if object.as_array_storage().is_none() && !is_amf3_only {
+            Some(AmfTraits::of(activation, object)?)
+        } else {
+            None
+        };
+
+        if is_amf3_only || matches!(&traits, Some(traits) if traits.externalizable) {
+            self.write_u8(activation, AMF0_AVMPLUS)?;
+            return Amf3Writer::new(self.target).write_value(activation, object.into());
+        }
+
+        if self.write_reference(activation, object)? {
+            return Ok(());
+        }
+
+        if let Some(traits) = traits {
+            if traits.class_name.is_empty() {
+                self.write_u8(activation, AMF0_OBJECT)?;
+            } else {
+                self.write_u8(activation, AMF0_TYPED_OBJECT)?;
+                self.write_utf8(activation, traits.class_name)?;
+            }
+
+            let mut properties = Vec::new();
+            for name in traits.sealed {
+                let value = object.get_property(&Multiname::public(name), activation)?;
+                properties.push((name, value));
+            }
+            if traits.dynamic {
+                for (name, value) in dynamic_properties(activation, object)? {
+                    properties.push((name.coerce_to_string(activation)?, value));
+                }
+            }
+
+            return self.write_properties(activation, properties);
+        }
+
+        let dense: Vec<Value<'gc>> = object
+            .as_array_storage()
+            .map(|storage| {
+                storage
+                    .iter()
+                    .map(|value| value.unwrap_or(Value::Undefined))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let named: Vec<_> = dynamic_properties(activation, object)?
+            .into_iter()
+            .filter_map(|(name, value)| match name {
+                Value::String(name) => Some((name, value)),
+                _ => None,
+            })
+            .collect();
+
+        if named.is_empty() {
+            self.write_u8(activation, AMF0_STRICT_ARRAY)?;
+            self.write_bytes(activation, &(dense.len() as u32).to_be_bytes())?;
+            for value in dense {
+                self.write_value(activation, value)?;
+            }
+            return Ok(());
+        }
+
+        self.write_u8(activation, AMF0_ECMA_ARRAY)?;
+        self.write_bytes(activation, &(dense.len() as u32).to_be_bytes())?;
+        let mut properties = Vec::with_capacity(dense.len() + named.len());
+        for (index, value) in dense.into_iter().enumerate() {
+            let name = AvmString::new_utf8(activation.context.gc_context, index.to_string());
+            properties.push((name, value));
+        }
+        properties.extend(named);
+
+        self.write_properties(activation, properties)
+    }
+}
+
+/// Convert a value decoded by `flash_lso` into an AVM2 value.
+pub fn deserialize_value<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: &AmfValue,
+) -> Result<Value<'gc>, Error> {
+    Ok(match value {
+        AmfValue::Undefined => Value::Undefined,
+        AmfValue::Null => Value::Null,
+        AmfValue::Bool(b) => Value::Bool(*b),
+        AmfValue::Integer(i) => Value::Integer(*i),
+        AmfValue::Number(n) => Value::Number(*n),
+        AmfValue::String(s) => Value::String(AvmString::new_utf8(activation.context.gc_context, s)),
+        AmfValue::ByteArray(bytes) => {
+            let storage = ByteArrayStorage::from_vec(bytes.clone());
+            let bytearray = ByteArrayObject::from_storage(activation, storage)?;
+            bytearray.into()
+        }
+        AmfValue::StrictArray(values) => {
+            let mut arr: Vec<Option<Value<'gc>>> = Vec::with_capacity(values.len());
+            for value in values {
+                arr.push(Some(deserialize_value(activation, value)?));
+            }
+            let storage = ArrayStorage::from_storage(arr);
+            let array = ArrayObject::from_storage(activation, storage)?;
+            array.into()
+        }
+        AmfValue::ECMAArray(values, elements, _) => {
+            // First lets create an array out of `values` (dense portion), then we add the elements onto it.
+            let mut arr: Vec<Option<Value<'gc>>> = Vec::with_capacity(values.len());
+            for value in values {
+                arr.push(Some(deserialize_value(activation, value)?));
+            }
+            let storage = ArrayStorage::from_storage(arr);
+            let mut array = ArrayObject::from_storage(activation, storage)?;
+            // Now lets add each element as a property
+            for element in elements {
+                array.set_property(
+                    &QName::new(
+                        Namespace::public(),
+                        AvmString::new_utf8(activation.context.gc_context, element.name()),
+                    )
+                    .into(),
+                    deserialize_value(activation, element.value())?,
+                    activation,
+                )?;
+            }
+            array.into()
+        }
+        AmfValue::Object(properties, class_definition) => {
+            // Typed objects are reconstructed as their aliased class, if it
+            // has been registered.
+            let class = class_definition
+                .as_ref()
+                .filter(|class_definition| !class_definition.name.is_empty())
+                .and_then(|class_definition| {
+                    let alias =
+                        AvmString::new_utf8(activation.context.gc_context, &class_definition.name);
+                    class_by_alias(activation, alias)
+                });
+            let obj_class = class.unwrap_or_else(|| activation.avm2().classes().object);
+            let mut obj = obj_class.construct(activation, &[])?;
+            for property in properties {
+                obj.set_property(
+                    &QName::new(
+                        Namespace::public(),
+                        AvmString::new_utf8(activation.context.gc_context, property.name()),
+                    )
+                    .into(),
+                    deserialize_value(activation, property.value())?,
+                    activation,
+                )?;
+            }
+            obj.into()
+        }
+        AmfValue::Date(time, _) => {
+            let date_class = activation.avm2().classes().date;
+            date_class.construct(activation, &[(*time).into()])?.into()
+        }
+        AmfValue::XML(content, _) => {
+            let content = AvmString::new_utf8(activation.context.gc_context, content);
+            let xml_class = activation.avm2().classes().xml;
+            xml_class.construct(activation, &[content.into()])?.into()
+        }
+        AmfValue::AMF3(value) => deserialize_value(activation, value)?,
+        // AMF3-only values are read natively, so they never reach here.
+        _ => Value::Undefined,
+    })
+}
+
+/// Write a value to a `ByteArray` in its current object encoding.
+pub fn write_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    target: Object<'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error> {
+    let encoding = target
+        .as_bytearray()
+        .map(|bytearray| bytearray.object_encoding())
+        .ok_or("Error: AMF data can only be written to a ByteArray")?;
+
+    match encoding {
+        ObjectEncoding::Amf0 => Amf0Writer::new(target).write_value(activation, value),
+        ObjectEncoding::Amf3 => Amf3Writer::new(target).write_value(activation, value),
+    }
+}
+
+/// Read a value from a `ByteArray` in its current object encoding.
+pub fn read_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    source: Object<'gc>,
+) -> Result<Value<'gc>, Error> {
+    let encoding = source
+        .as_bytearray()
+        .map(|bytearray| bytearray.object_encoding())
+        .ok_or("Error: AMF data can only be read from a ByteArray")?;
+
+    match encoding {
+        ObjectEncoding::Amf0 => {
+            let (bytes_left, amf) = {
+                let bytearray = source.as_bytearray().unwrap();
+                let bytes = bytearray.read_at(bytearray.bytes_available(), bytearray.position())?;
+                let mut decoder = AMF0Decoder::default();
+                let (extra, amf) = decoder
+                    .parse_single_element(bytes)
+                    .map_err(|_| "Error: Invalid object")?;
+                (extra.len(), amf)
+            };
+
+            if let Some(bytearray) = source.as_bytearray() {
+                bytearray.set_position(bytearray.len() - bytes_left);
+            }
+
+            deserialize_value(activation, &amf)
+        }
+        ObjectEncoding::Amf3 => Amf3Reader::new(source).read_value(activation),
+    }
+}
+
+/// Serialize the properties of a shared object's `data` into the contents of
+/// an AMF3 `.sol` file.
+pub fn serialize_lso<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    name: &str,
+    data: Object<'gc>,
+) -> Result<Vec<u8>, Error> {
+    let target = ByteArrayObject::from_storage(activation, ByteArrayStorage::new())?;
+    let mut writer = Amf3Writer::new(target);
+
+    for (name, value) in dynamic_properties(activation, data)? {
+        if is_function(&value) {
+            continue;
+        }
+
+        let name = name.coerce_to_string(activation)?;
+        writer.write_string(activation, name)?;
+        writer.write_value(activation, value)?;
+        writer.write_u8(activation, 0)?;
+    }
+
+    let body = target
+        .as_bytearray()
+        .map(|bytearray| bytearray.bytes().to_vec())
+        .unwrap_or_default();
+
+    let name = name.as_bytes();
+    let length = LSO_SIGNATURE.len() + 2 + name.len() + 4 + body.len();
+    let mut bytes = Vec::with_capacity(LSO_MAGIC.len() + 4 + length);
+    bytes.extend_from_slice(&LSO_MAGIC);
+    bytes.extend_from_slice(&(length as u32).to_be_bytes());
+    bytes.extend_from_slice(&LSO_SIGNATURE);
+    bytes.extend_from_slice(&(name.len() as u16).to_be_bytes());
+    bytes.extend_from_slice(name);
+    bytes.extend_from_slice(&3u32.to_be_bytes());
+    bytes.extend_from_slice(&body);
+
+    Ok(bytes)
+}
+
+/// Deserialize the contents of a `.sol` file into a new object holding the
+/// properties it stores.
+///
+/// AMF0 files, as saved by AVM1 movies, are also supported.
+pub fn deserialize_lso<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    bytes: &[u8],
+) -> Result<Object<'gc>, Error> {
+    let object_class = activation.avm2().classes().object;
+    let mut data = object_class.construct(activation, &[])?;
+
+    let header_length = LSO_MAGIC.len() + 4 + LSO_SIGNATURE.len();
+    let name_length = bytes
+        .get(header_length..header_length + 2)
+        .map(|length| u16::from_be_bytes(length.try_into().unwrap()) as usize)
+        .ok_or("Error: Invalid shared object")?;
+    let body_start = header_length + 2 + name_length + 4;
+    let version = bytes
+        .get(body_start - 4..body_start)
+        .map(|version| u32::from_be_bytes(version.try_into().unwrap()))
+        .ok_or("Error: Invalid shared object")?;
+
+    if version != 3 {
+        let lso = flash_lso::read::Reader::default()
+            .parse(bytes)
+            .map_err(|_| "Error: Invalid shared object")?;
+        for element in &lso.body {
+            let name = AvmString::new_utf8(activation.context.gc_context, &element.name);
+            let value = deserialize_value(activation, element.value())?;
+            data.set_property(&QName::dynamic_name(name).into(), value, activation)?;
+        }
+
+        return Ok(data);
+    }
+
+    let storage = ByteArrayStorage::from_vec(bytes[body_start..].to_vec());
+    let source = ByteArrayObject::from_storage(activation, storage)?;
+    let mut reader = Amf3Reader::new(source);
+
+    while source
+        .as_bytearray()
+        .map(|bytearray| bytearray.bytes_available() > 0)
+        .unwrap_or(false)
+    {
+        let name = reader.read_string(activation)?;
+        let value = reader.read_value(activation)?;
+        reader.read_u8()?;
+        data.set_property(&QName::dynamic_name(name).into(), value, activation)?;
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm1::test_utils::with_avm;
+    use crate::avm2::Avm2;
+
+    /// Run a test with an AVM2 activation, once the player globals have been
+    /// loaded.
+    fn with_avm2<F>(test: F)
+    where
+        F: for<'gc> FnOnce(&mut Activation<'_, 'gc, '_>) -> Result<(), Error>,
+    {
+        with_avm(19, |activation, _root| {
+            Avm2::load_player_globals(&mut activation.context).expect("Player globals should load");
+            let mut activation = Activation::from_nothing(activation.context.reborrow());
+            if let Err(e) = test(&mut activation) {
+                panic!("Encountered exception during test: {}", e);
+            }
+
+            Ok(())
+        });
+    }
+
+    /// Write a value as AMF3, and read it back.
+    ///
+    /// Returns the bytes that were written, and the value that was read.
+    fn round_trip<'gc>(
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: Value<'gc>,
+    ) -> Result<(Vec<u8>, Value<'gc>), Error> {
+        let target = ByteArrayObject::from_storage(activation, ByteArrayStorage::new())?;
+        write_object(activation, target, value)?;
+
+        let bytes = target.as_bytearray().unwrap().bytes().to_vec();
+        target.as_bytearray().unwrap().set_position(0);
+        let read = read_object(activation, target)?;
+        assert_eq!(
+            target.as_bytearray().unwrap().bytes_available(),
+            0,
+            "the whole value should be read back"
+        );
+
+        Ok((bytes, read))
+    }
+
+    fn get<'gc>(
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: Value<'gc>,
+        name: &'static str,
+    ) -> Result<Value<'gc>, Error> {
+        value
+            .coerce_to_object(activation)?
+            .get_property(&Multiname::public(name), activation)
+    }
+
+    #[test]
+    fn dynamic_object() {
+        with_avm2(|activation| {
+            let object_class = activation.avm2().classes().object;
+            let mut object = object_class.construct(activation, &[])?;
+            object.set_property(&Multiname::public("a"), 1.5.into(), activation)?;
+
+            let (bytes, read) = round_trip(activation, object.into())?;
+            assert_eq!(
+                bytes,
+                [
+                    AMF3_OBJECT,
+                    0x0B,
+                    0x01,
+                    0x03,
+                    b'a',
+                    AMF3_DOUBLE,
+                    0x3F,
+                    0xF8,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0x01
+                ]
+            );
+            assert_eq!(get(activation, read, "a")?, 1.5.into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn sealed_object_with_alias() {
+        with_avm2(|activation| {
+            let point_class = activation.avm2().classes().point;
+            register_class_alias(activation, "flash.geom.Point".into(), point_class);
+
+            let point = point_class.construct(activation, &[1.into(), (-2).into()])?;
+            let (bytes, read) = round_trip(activation, point.into())?;
+
+            let mut expected = vec![AMF3_OBJECT, 0x23, 0x21];
+            expected.extend_from_slice(b"flash.geom.Point");
+            expected.extend_from_slice(&[0x03, b'x', 0x03, b'y']);
+            expected.extend_from_slice(&[AMF3_INTEGER, 0x01, AMF3_INTEGER, 0xFF, 0xFF, 0xFF, 0xFE]);
+            assert_eq!(bytes, expected);
+
+            let read_class = read.coerce_to_object(activation)?.instance_of();
+            assert!(read_class.map_or(false, |class| Object::ptr_eq(class, point_class)));
+            assert_eq!(get(activation, read, "x")?, 1.into());
+            assert_eq!(get(activation, read, "y")?, (-2).into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn sealed_object_without_alias() {
+        with_avm2(|activation| {
+            let point_class = activation.avm2().classes().point;
+            let point = point_class.construct(activation, &[3.into(), 4.into()])?;
+            let (bytes, read) = round_trip(activation, point.into())?;
+
+            // Unaliased classes are anonymous, and are read back as plain
+            // objects holding their sealed properties.
+            assert_eq!(&bytes[..5], &[AMF3_OBJECT, 0x23, 0x01, 0x03, b'x']);
+            let read_class = read.coerce_to_object(activation)?.instance_of();
+            let object_class = activation.avm2().classes().object;
+            assert!(read_class.map_or(false, |class| Object::ptr_eq(class, object_class)));
+            assert_eq!(get(activation, read, "x")?, 3.into());
+            assert_eq!(get(activation, read, "y")?, 4.into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn register_class_alias_replaces_old_alias() {
+        with_avm2(|activation| {
+            let point_class = activation.avm2().classes().point;
+            register_class_alias(activation, "first".into(), point_class);
+            register_class_alias(activation, "second".into(), point_class);
+
+            assert!(class_by_alias(activation, "first".into()).is_none());
+            assert!(class_by_alias(activation, "second".into())
+                .map_or(false, |class| Object::ptr_eq(class, point_class)));
+            assert_eq!(
+                alias_of_class(activation, point_class),
+                Some("second".into())
+            );
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn int_vector() {
+        with_avm2(|activation| {
+            let int_class = activation.avm2().classes().int;
+            let mut storage = VectorStorage::new(0, false, int_class, activation);
+            storage.push(1.into())?;
+            storage.push((-2).into())?;
+            let vector = VectorObject::from_vector(storage, activation)?;
+
+            let (bytes, read) = round_trip(activation, vector.into())?;
+            assert_eq!(
+                bytes,
+                [
+                    AMF3_VECTOR_INT,
+                    0x05,
+                    0x00,
+                    0,
+                    0,
+                    0,
+                    1,
+                    0xFF,
+                    0xFF,
+                    0xFF,
+                    0xFE
+                ]
+            );
+
+            let read = read.coerce_to_object(activation)?;
+            let storage = read.as_vector_storage().unwrap();
+            assert!(Object::ptr_eq(storage.value_type(), int_class));
+            assert!(!storage.is_fixed());
+            assert_eq!(storage.get(0)?, 1.into());
+            assert_eq!(storage.get(1)?, (-2).into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn object_vector() {
+        with_avm2(|activation| {
+            let point_class = activation.avm2().classes().point;
+            register_class_alias(activation, "flash.geom.Point".into(), point_class);
+
+            let point = point_class.construct(activation, &[5.into(), 6.into()])?;
+            let mut storage = VectorStorage::new(0, false, point_class, activation);
+            storage.push(point.into())?;
+            storage.push(Value::Null)?;
+            storage.set_is_fixed(true);
+            let vector = VectorObject::from_vector(storage, activation)?;
+
+            let (bytes, read) = round_trip(activation, vector.into())?;
+            assert_eq!(&bytes[..4], &[AMF3_VECTOR_OBJECT, 0x05, 0x01, 0x21]);
+
+            let read = read.coerce_to_object(activation)?;
+            let storage = read.as_vector_storage().unwrap();
+            assert!(Object::ptr_eq(storage.value_type(), point_class));
+            assert!(storage.is_fixed());
+            let first = storage.get(0)?;
+            assert_eq!(storage.get(1)?, Value::Null);
+            drop(storage);
+            assert_eq!(get(activation, first, "x")?, 5.into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn dictionary_with_object_keys() {
+        with_avm2(|activation| {
+            let dictionary_class = activation.avm2().classes().dictionary;
+            let dictionary = dictionary_class.construct(activation, &[])?;
+            let object_class = activation.avm2().classes().object;
+            let key = object_class.construct(activation, &[])?;
+            dictionary
+                .as_dictionary_object()
+                .unwrap()
+                .set_property_by_object(key, "value".into(), activation.context.gc_context);
+
+            let (bytes, read) = round_trip(activation, dictionary.into())?;
+            assert_eq!(&bytes[..3], &[AMF3_DICTIONARY, 0x03, 0x00]);
+
+            let read = read.coerce_to_object(activation)?;
+            assert!(read.as_dictionary_object().is_some());
+            let entries = dynamic_properties(activation, read)?;
+            assert_eq!(entries.len(), 1);
+            let (read_key, read_value) = entries[0];
+            assert!(matches!(read_key, Value::Object(_)));
+            assert_eq!(read_value, "value".into());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn bytearray() {
+        with_avm2(|activation| {
+            let storage = ByteArrayStorage::from_vec(vec![1, 2, 3]);
+            let bytearray = ByteArrayObject::from_storage(activation, storage)?;
+
+            let (bytes, read) = round_trip(activation, bytearray.into())?;
+            assert_eq!(bytes, [AMF3_BYTE_ARRAY, 0x07, 1, 2, 3]);
+
+            let read = read.coerce_to_object(activation)?;
+            assert_eq!(read.as_bytearray().unwrap().bytes(), &[1, 2, 3]);
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn string_and_object_references() {
+        with_avm2(|activation| {
+            let object_class = activation.avm2().classes().object;
+            let object = object_class.construct(activation, &[])?;
+            let values = ["abc".into(), "abc".into(), object.into(), object.into()];
+            let array = ArrayObject::from_storage(activation, ArrayStorage::from_args(&values))?;
+
+            let (bytes, read) = round_trip(activation, array.into())?;
+            assert_eq!(
+                bytes,
+                [
+                    AMF3_ARRAY,
+                    0x09,
+                    0x01,
+                    AMF3_STRING,
+                    0x07,
+                    b'a',
+                    b'b',
+                    b'c',
+                    AMF3_STRING,
+                    0x00,
+                    AMF3_OBJECT,
+                    0x0B,
+                    0x01,
+                    0x01,
+                    AMF3_OBJECT,
+                    0x02
+                ]
+            );
+
+            let read = read.coerce_to_object(activation)?;
+            let storage = read.as_array_storage().unwrap();
+            assert_eq!(storage.get(1), Some("abc".into()));
+            assert_eq!(storage.get(2), storage.get(3));
+            assert!(matches!(storage.get(2), Some(Value::Object(_))));
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn trait_references() {
+        with_avm2(|activation| {
+            let object_class = activation.avm2().classes().object;
+            let first = object_class.construct(activation, &[])?;
+            let second = object_class.construct(activation, &[])?;
+            let values = [first.into(), second.into()];
+            let array = ArrayObject::from_storage(activation, ArrayStorage::from_args(&values))?;
+
+            let (bytes, read) = round_trip(activation, array.into())?;
+            assert_eq!(
+                bytes,
+                [
+                    AMF3_ARRAY,
+                    0x05,
+                    0x01,
+                    AMF3_OBJECT,
+                    0x0B,
+                    0x01,
+                    0x01,
+                    AMF3_OBJECT,
+                    0x01,
+                    0x01
+                ]
+            );
+
+            let read = read.coerce_to_object(activation)?;
+            let storage = read.as_array_storage().unwrap();
+            assert_ne!(storage.get(0), storage.get(1));
+
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,1096 @@
+//! XML tree structure for ECMA-357 (E4X) `XML` and `XMLList` objects.
+
+use crate::avm2::activation::Activation;
+use crate::avm2::names::{Multiname, Namespace};
+use crate::avm2::Error;
+use crate::string::{AvmString, WStr, WString};
+use gc_arena::{Collect, GcCell, MutationContext};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::cell::Ref;
+use std::fmt;
+
+/// The namespace URI bound to the `xml` prefix in every document.
+const XML_NAMESPACE_URI: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Global settings which control how E4X markup is parsed and printed.
+///
+/// These are exposed to ActionScript as static properties of the `XML` class.
+#[derive(Clone, Collect, Debug)]
+#[collect(require_static)]
+pub struct XmlSettings {
+    pub ignore_comments: bool,
+    pub ignore_processing_instructions: bool,
+    pub ignore_whitespace: bool,
+    pub pretty_printing: bool,
+    pub pretty_indent: i32,
+}
+
+impl Default for XmlSettings {
+    fn default() -> Self {
+        Self {
+            ignore_comments: true,
+            ignore_processing_instructions: true,
+            ignore_whitespace: true,
+            pretty_printing: true,
+            pretty_indent: 2,
+        }
+    }
+}
+
+/// A namespace binding, as declared by an `xmlns` attribute.
+#[derive(Copy, Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct E4XNamespace<'gc> {
+    /// The prefix this namespace is bound to, or `None` for the default
+    /// namespace.
+    pub prefix: Option<AvmString<'gc>>,
+
+    /// The namespace URI.
+    pub uri: AvmString<'gc>,
+}
+
+/// A single node in an E4X tree.
+///
+/// Unlike AVM1 XML nodes, E4X nodes do not own their script objects: any
+/// number of `XML` objects (or `XMLList` entries) may refer to the same node.
+#[derive(Copy, Clone, Collect)]
+#[collect(no_drop)]
+pub struct E4XNode<'gc>(GcCell<'gc, E4XNodeData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct E4XNodeData<'gc> {
+    /// The element that contains this node.
+    parent: Option<E4XNode<'gc>>,
+
+    /// The namespace of this node. Only elements and attributes have one.
+    namespace: Option<E4XNamespace<'gc>>,
+
+    /// The local name of this node. Only elements, attributes, and processing
+    /// instructions have one.
+    local_name: Option<AvmString<'gc>>,
+
+    kind: E4XNodeKind<'gc>,
+}
+
+#[derive(Collect, Debug)]
+#[collect(no_drop)]
+pub enum E4XNodeKind<'gc> {
+    Text(AvmString<'gc>),
+    CData(AvmString<'gc>),
+    Comment(AvmString<'gc>),
+    ProcessingInstruction(AvmString<'gc>),
+    Attribute(AvmString<'gc>),
+    Element {
+        namespace_declarations: Vec<E4XNamespace<'gc>>,
+        attributes: Vec<E4XNode<'gc>>,
+        children: Vec<E4XNode<'gc>>,
+    },
+}
+
+impl<'gc> E4XNode<'gc> {
+    fn new(
+        mc: MutationContext<'gc, '_>,
+        parent: Option<E4XNode<'gc>>,
+        namespace: Option<E4XNamespace<'gc>>,
+        local_name: Option<AvmString<'gc>>,
+        kind: E4XNodeKind<'gc>,
+    ) -> Self {
+        E4XNode(GcCell::allocate(
+            mc,
+            E4XNodeData {
+                parent,
+                namespace,
+                local_name,
+                kind,
+            },
+        ))
+    }
+
+    /// Construct a new, empty element.
+    pub fn element(
+        mc: MutationContext<'gc, '_>,
+        namespace: Option<E4XNamespace<'gc>>,
+        local_name: AvmString<'gc>,
+        parent: Option<E4XNode<'gc>>,
+    ) -> Self {
+        Self::new(
+            mc,
+            parent,
+            namespace,
+            Some(local_name),
+            E4XNodeKind::Element {
+                namespace_declarations: Vec::new(),
+                attributes: Vec::new(),
+                children: Vec::new(),
+            },
+        )
+    }
+
+    /// Construct a new text node.
+    pub fn text(
+        mc: MutationContext<'gc, '_>,
+        text: AvmString<'gc>,
+        parent: Option<E4XNode<'gc>>,
+    ) -> Self {
+        Self::new(mc, parent, None, None, E4XNodeKind::Text(text))
+    }
+
+    /// Construct a new attribute node.
+    pub fn attribute(
+        mc: MutationContext<'gc, '_>,
+        namespace: Option<E4XNamespace<'gc>>,
+        local_name: AvmString<'gc>,
+        value: AvmString<'gc>,
+        parent: Option<E4XNode<'gc>>,
+    ) -> Self {
+        Self::new(
+            mc,
+            parent,
+            namespace,
+            Some(local_name),
+            E4XNodeKind::Attribute(value),
+        )
+    }
+
+    /// Parse a string of XML markup into a list of top-level nodes.
+    ///
+    /// Whitespace, comments, and processing instructions are retained or
+    /// discarded according to the current `XmlSettings`.
+    pub fn parse(
+        string: &WStr,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Vec<Self>, Error> {
+        let settings = activation.avm2().xml_settings.clone();
+        let mc = activation.context.gc_context;
+        let data_utf8 = string.to_utf8_lossy();
+        let mut parser = Reader::from_str(&data_utf8);
+        let mut buf = Vec::new();
+        let mut top_level = Vec::new();
+        let mut open_tags: Vec<E4XNode<'gc>> = Vec::new();
+
+        fn push_node<'gc>(
+            mc: MutationContext<'gc, '_>,
+            node: E4XNode<'gc>,
+            open_tags: &[E4XNode<'gc>],
+            top_level: &mut Vec<E4XNode<'gc>>,
+        ) {
+            if let Some(parent) = open_tags.last() {
+                parent.append_child(mc, node);
+            } else {
+                top_level.push(node);
+            }
+        }
+
+        loop {
+            match parser.read_event(&mut buf)? {
+                Event::Start(bs) => {
+                    let child = Self::from_start_event(activation, &bs, open_tags.last().copied())?;
+                    push_node(mc, child, &open_tags, &mut top_level);
+                    open_tags.push(child);
+                }
+                Event::Empty(bs) => {
+                    let child = Self::from_start_event(activation, &bs, open_tags.last().copied())?;
+                    push_node(mc, child, &open_tags, &mut top_level);
+                }
+                Event::End(_) => {
+                    open_tags.pop();
+                }
+                Event::Text(bt) => {
+                    let text = bt.unescaped()?;
+                    let is_whitespace_char = |c: &u8| matches!(*c, b'\t' | b'\n' | b'\r' | b' ');
+                    let text = if settings.ignore_whitespace {
+                        let start = text.iter().position(|c| !is_whitespace_char(c));
+                        let end = text.iter().rposition(|c| !is_whitespace_char(c));
+                        match (start, end) {
+                            (Some(start), Some(end)) => &text[start..=end],
+                            _ => &[][..],
+                        }
+                    } else {
+                        &text[..]
+                    };
+
+                    if !text.is_empty() {
+                        let text = AvmString::new_utf8_bytes(mc, text)?;
+                        let node = Self::text(mc, text, open_tags.last().copied());
+                        push_node(mc, node, &open_tags, &mut top_level);
+                    }
+                }
+                Event::CData(bt) => {
+                    let text = AvmString::new_utf8_bytes(mc, bt.escaped())?;
+                    let node = Self::new(
+                        mc,
+                        open_tags.last().copied(),
+                        None,
+                        None,
+                        E4XNodeKind::CData(text),
+                    );
+                    push_node(mc, node, &open_tags, &mut top_level);
+                }
+                Event::Comment(bt) if !settings.ignore_comments => {
+                    let text = AvmString::new_utf8_bytes(mc, bt.escaped())?;
+                    let node = Self::new(
+                        mc,
+                        open_tags.last().copied(),
+                        None,
+                        None,
+                        E4XNodeKind::Comment(text),
+                    );
+                    push_node(mc, node, &open_tags, &mut top_level);
+                }
+                Event::PI(bt) if !settings.ignore_processing_instructions => {
+                    let contents = bt.escaped();
+                    let (name, value) = match contents.iter().position(|c| c.is_ascii_whitespace())
+                    {
+                        Some(split) => (&contents[..split], &contents[split + 1..]),
+                        None => (contents, &[][..]),
+                    };
+                    let node = Self::new(
+                        mc,
+                        open_tags.last().copied(),
+                        None,
+                        Some(AvmString::new_utf8_bytes(mc, name)?),
+                        E4XNodeKind::ProcessingInstruction(AvmString::new_utf8_bytes(mc, value)?),
+                    );
+                    push_node(mc, node, &open_tags, &mut top_level);
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+
+            buf.clear();
+        }
+
+        if !open_tags.is_empty() {
+            return Err("TypeError: Error #1085: The element type must be terminated by the matching end-tag.".into());
+        }
+
+        Ok(top_level)
+    }
+
+    /// Construct an element from a quick-xml start tag, resolving the
+    /// namespaces of the element and all of its attributes.
+    fn from_start_event(
+        activation: &mut Activation<'_, 'gc, '_>,
+        bs: &BytesStart<'_>,
+        parent: Option<E4XNode<'gc>>,
+    ) -> Result<Self, Error> {
+        let mc = activation.context.gc_context;
+        let mut namespace_declarations = Vec::new();
+        let mut raw_attributes = Vec::new();
+
+        for attribute in bs.attributes() {
+            let attribute = attribute?;
+            let value = AvmString::new_utf8_bytes(mc, attribute.unescaped_value()?)?;
+
+            if attribute.key == b"xmlns" {
+                namespace_declarations.push(E4XNamespace {
+                    prefix: None,
+                    uri: value,
+                });
+            } else if let Some(prefix) = attribute.key.strip_prefix(b"xmlns:") {
+                namespace_declarations.push(E4XNamespace {
+                    prefix: Some(AvmString::new_utf8_bytes(mc, prefix)?),
+                    uri: value,
+                });
+            } else {
+                raw_attributes.push((attribute.key.to_vec(), value));
+            }
+        }
+
+        let resolve = |prefix: Option<&[u8]>, is_attribute: bool| -> Result<_, Error> {
+            let prefix = match prefix {
+                Some(prefix) => Some(AvmString::new_utf8_bytes(mc, prefix)?),
+                // Unprefixed attributes never take on the default namespace.
+                None if is_attribute => return Ok(None),
+                None => None,
+            };
+
+            let uri = namespace_declarations
+                .iter()
+                .find(|ns| ns.prefix == prefix)
+                .map(|ns| ns.uri)
+                .or_else(|| parent.and_then(|parent| parent.resolve_prefix(prefix)));
+
+            match (prefix, uri) {
+                (_, Some(uri)) if !uri.is_empty() => Ok(Some(E4XNamespace { prefix, uri })),
+                (None, _) => Ok(None),
+                (Some(prefix), _) if &prefix == b"xml" => Ok(Some(E4XNamespace {
+                    prefix: Some(prefix),
+                    uri: XML_NAMESPACE_URI.into(),
+                })),
+                (Some(prefix), _) => Err(format!(
+                    "TypeError: Error #1083: The prefix \"{}\" for element \"{}\" is not bound.",
+                    prefix,
+                    String::from_utf8_lossy(bs.name())
+                )
+                .into()),
+            }
+        };
+
+        let (prefix, local_name) = split_name(bs.name());
+        let element = Self::new(
+            mc,
+            parent,
+            resolve(prefix, false)?,
+            Some(AvmString::new_utf8_bytes(mc, local_name)?),
+            E4XNodeKind::Element {
+                namespace_declarations: namespace_declarations.clone(),
+                attributes: Vec::new(),
+                children: Vec::new(),
+            },
+        );
+
+        for (key, value) in raw_attributes {
+            let (prefix, local_name) = split_name(&key);
+            let attribute = Self::attribute(
+                mc,
+                resolve(prefix, true)?,
+                AvmString::new_utf8_bytes(mc, local_name)?,
+                value,
+                Some(element),
+            );
+
+            if let E4XNodeKind::Element { attributes, .. } = &mut element.0.write(mc).kind {
+                attributes.push(attribute);
+            }
+        }
+
+        Ok(element)
+    }
+
+    /// Find the namespace URI bound to a given prefix on this element or any
+    /// of its ancestors.
+    fn resolve_prefix(self, prefix: Option<AvmString<'gc>>) -> Option<AvmString<'gc>> {
+        let mut node = Some(self);
+
+        while let Some(current) = node {
+            let read = current.0.read();
+            if let E4XNodeKind::Element {
+                namespace_declarations,
+                ..
+            } = &read.kind
+            {
+                if let Some(ns) = namespace_declarations.iter().find(|ns| ns.prefix == prefix) {
+                    return Some(ns.uri);
+                }
+            }
+
+            node = read.parent;
+        }
+
+        None
+    }
+
+    /// List every namespace declaration in scope on this node, from the
+    /// closest declaration outwards.
+    pub fn in_scope_namespaces(self) -> Vec<E4XNamespace<'gc>> {
+        let mut declarations: Vec<E4XNamespace<'gc>> = Vec::new();
+        let mut node = Some(self);
+
+        while let Some(current) = node {
+            let read = current.0.read();
+            if let E4XNodeKind::Element {
+                namespace_declarations,
+                ..
+            } = &read.kind
+            {
+                for ns in namespace_declarations {
+                    if !declarations.iter().any(|d| d.prefix == ns.prefix) {
+                        declarations.push(*ns);
+                    }
+                }
+            }
+
+            node = read.parent;
+        }
+
+        declarations
+    }
+
+    /// List the namespaces declared on this element.
+    pub fn namespace_declarations(self) -> Vec<E4XNamespace<'gc>> {
+        match &self.0.read().kind {
+            E4XNodeKind::Element {
+                namespace_declarations,
+                ..
+            } => namespace_declarations.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Declare a namespace on this element, replacing any declaration of the
+    /// same prefix.
+    ///
+    /// Declaring a namespace on a non-element node does nothing.
+    pub fn add_namespace_declaration(
+        self,
+        mc: MutationContext<'gc, '_>,
+        namespace: E4XNamespace<'gc>,
+    ) {
+        if let E4XNodeKind::Element {
+            namespace_declarations,
+            ..
+        } = &mut self.0.write(mc).kind
+        {
+            namespace_declarations.retain(|ns| ns.prefix != namespace.prefix);
+            namespace_declarations.push(namespace);
+        }
+    }
+
+    pub fn ptr_eq(a: E4XNode<'gc>, b: E4XNode<'gc>) -> bool {
+        GcCell::ptr_eq(a.0, b.0)
+    }
+
+    pub fn parent(self) -> Option<E4XNode<'gc>> {
+        self.0.read().parent
+    }
+
+    pub fn local_name(self) -> Option<AvmString<'gc>> {
+        self.0.read().local_name
+    }
+
+    pub fn namespace(self) -> Option<E4XNamespace<'gc>> {
+        self.0.read().namespace
+    }
+
+    /// Rename this node.
+    pub fn set_name(
+        self,
+        mc: MutationContext<'gc, '_>,
+        namespace: Option<E4XNamespace<'gc>>,
+        local_name: AvmString<'gc>,
+    ) {
+        let mut write = self.0.write(mc);
+        write.namespace = namespace;
+        write.local_name = Some(local_name);
+    }
+
+    pub fn kind(&self) -> Ref<'_, E4XNodeKind<'gc>> {
+        Ref::map(self.0.read(), |read| &read.kind)
+    }
+
+    pub fn is_element(self) -> bool {
+        matches!(self.0.read().kind, E4XNodeKind::Element { .. })
+    }
+
+    pub fn is_attribute(self) -> bool {
+        matches!(self.0.read().kind, E4XNodeKind::Attribute(_))
+    }
+
+    /// Indicates if this is a text or CDATA node.
+    pub fn is_text(self) -> bool {
+        matches!(
+            self.0.read().kind,
+            E4XNodeKind::Text(_) | E4XNodeKind::CData(_)
+        )
+    }
+
+    /// The value of `XML.nodeKind()` for this node.
+    pub fn node_kind(self) -> &'static str {
+        match self.0.read().kind {
+            E4XNodeKind::Text(_) | E4XNodeKind::CData(_) => "text",
+            E4XNodeKind::Comment(_) => "comment",
+            E4XNodeKind::ProcessingInstruction(_) => "processing-instruction",
+            E4XNodeKind::Attribute(_) => "attribute",
+            E4XNodeKind::Element { .. } => "element",
+        }
+    }
+
+    /// List the children of this node. Non-element nodes have no children.
+    pub fn children(self) -> Vec<E4XNode<'gc>> {
+        match &self.0.read().kind {
+            E4XNodeKind::Element { children, .. } => children.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// List the attributes of this node. Non-element nodes have no
+    /// attributes.
+    pub fn attributes(self) -> Vec<E4XNode<'gc>> {
+        match &self.0.read().kind {
+            E4XNodeKind::Element { attributes, .. } => attributes.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Add a node to the end of this element's children.
+    ///
+    /// The child is reparented to this node. Attempting to append to a
+    /// non-element node does nothing.
+    pub fn append_child(self, mc: MutationContext<'gc, '_>, child: E4XNode<'gc>) {
+        if let E4XNodeKind::Element { children, .. } = &mut self.0.write(mc).kind {
+            children.push(child);
+        } else {
+            return;
+        }
+
+        child.0.write(mc).parent = Some(self);
+    }
+
+    /// Insert a node into this element's children at a given index.
+    ///
+    /// The child is reparented to this node. Indices past the end of the
+    /// children append the node. Inserting into a non-element node does
+    /// nothing.
+    pub fn insert_child(self, mc: MutationContext<'gc, '_>, index: usize, child: E4XNode<'gc>) {
+        if let E4XNodeKind::Element { children, .. } = &mut self.0.write(mc).kind {
+            children.insert(index.min(children.len()), child);
+        } else {
+            return;
+        }
+
+        child.0.write(mc).parent = Some(self);
+    }
+
+    /// Remove a single child (or attribute) from this element.
+    pub fn remove_child(self, mc: MutationContext<'gc, '_>, child: E4XNode<'gc>) {
+        if let E4XNodeKind::Element {
+            attributes,
+            children,
+            ..
+        } = &mut self.0.write(mc).kind
+        {
+            attributes.retain(|a| !E4XNode::ptr_eq(*a, child));
+            children.retain(|c| !E4XNode::ptr_eq(*c, child));
+        }
+
+        child.0.write(mc).parent = None;
+    }
+
+    /// Replace a child of this element with another node, keeping its
+    /// position.
+    pub fn replace_child(self, mc: MutationContext<'gc, '_>, old: E4XNode<'gc>, new: E4XNode<'gc>) {
+        let replaced = match &mut self.0.write(mc).kind {
+            E4XNodeKind::Element { children, .. } => {
+                match children.iter_mut().find(|c| E4XNode::ptr_eq(**c, old)) {
+                    Some(slot) => {
+                        *slot = new;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        };
+
+        if replaced {
+            old.0.write(mc).parent = None;
+            new.0.write(mc).parent = Some(self);
+        }
+    }
+
+    /// Remove every child of this element that matches a given name.
+    ///
+    /// Returns `true` if any children were removed.
+    pub fn remove_matching(self, mc: MutationContext<'gc, '_>, name: &Multiname<'gc>) -> bool {
+        let matching: Vec<_> = if name.is_attribute() {
+            self.attributes()
+        } else {
+            self.children()
+        }
+        .into_iter()
+        .filter(|node| node.matches_name(name))
+        .collect();
+
+        if let E4XNodeKind::Element {
+            attributes,
+            children,
+            ..
+        } = &mut self.0.write(mc).kind
+        {
+            attributes.retain(|a| !matching.iter().any(|m| E4XNode::ptr_eq(*a, *m)));
+            children.retain(|c| !matching.iter().any(|m| E4XNode::ptr_eq(*c, *m)));
+        }
+
+        for node in matching.iter() {
+            node.0.write(mc).parent = None;
+        }
+
+        !matching.is_empty()
+    }
+
+    /// Set the value of an attribute on this element, creating it if it does
+    /// not exist yet.
+    pub fn set_attribute(
+        self,
+        mc: MutationContext<'gc, '_>,
+        name: &Multiname<'gc>,
+        value: AvmString<'gc>,
+    ) {
+        if !self.is_element() {
+            return;
+        }
+
+        if let Some(existing) = self
+            .attributes()
+            .into_iter()
+            .find(|attr| attr.matches_name(name))
+        {
+            existing.0.write(mc).kind = E4XNodeKind::Attribute(value);
+            return;
+        }
+
+        if let Some(local_name) = name.local_name() {
+            let attribute = Self::attribute(mc, None, local_name, value, Some(self));
+            if let E4XNodeKind::Element { attributes, .. } = &mut self.0.write(mc).kind {
+                attributes.push(attribute);
+            }
+        }
+    }
+
+    /// Replace all of the children of this element with a single text node.
+    ///
+    /// Non-element nodes simply take on the new value.
+    pub fn replace_with_text(self, mc: MutationContext<'gc, '_>, text: AvmString<'gc>) {
+        if self.is_element() {
+            let text_node = Self::text(mc, text, Some(self));
+            if let E4XNodeKind::Element { children, .. } = &mut self.0.write(mc).kind {
+                *children = vec![text_node];
+            }
+
+            return;
+        }
+
+        let mut write = self.0.write(mc);
+        write.kind = match write.kind {
+            E4XNodeKind::Attribute(_) => E4XNodeKind::Attribute(text),
+            E4XNodeKind::Comment(_) => E4XNodeKind::Comment(text),
+            E4XNodeKind::ProcessingInstruction(_) => E4XNodeKind::ProcessingInstruction(text),
+            _ => E4XNodeKind::Text(text),
+        };
+    }
+
+    /// Make a deep copy of this node and all of its descendants.
+    ///
+    /// The copy has no parent.
+    pub fn deep_copy(self, mc: MutationContext<'gc, '_>) -> Self {
+        let read = self.0.read();
+        let kind = match &read.kind {
+            E4XNodeKind::Text(t) => E4XNodeKind::Text(*t),
+            E4XNodeKind::CData(t) => E4XNodeKind::CData(*t),
+            E4XNodeKind::Comment(t) => E4XNodeKind::Comment(*t),
+            E4XNodeKind::ProcessingInstruction(t) => E4XNodeKind::ProcessingInstruction(*t),
+            E4XNodeKind::Attribute(t) => E4XNodeKind::Attribute(*t),
+            E4XNodeKind::Element {
+                namespace_declarations,
+                ..
+            } => E4XNodeKind::Element {
+                namespace_declarations: namespace_declarations.clone(),
+                attributes: Vec::new(),
+                children: Vec::new(),
+            },
+        };
+
+        let copy = Self::new(mc, None, read.namespace, read.local_name, kind);
+
+        if let E4XNodeKind::Element {
+            attributes,
+            children,
+            ..
+        } = &read.kind
+        {
+            let attributes: Vec<_> = attributes
+                .iter()
+                .map(|a| {
+                    let a = a.deep_copy(mc);
+                    a.0.write(mc).parent = Some(copy);
+                    a
+                })
+                .collect();
+            let children: Vec<_> = children
+                .iter()
+                .map(|c| {
+                    let c = c.deep_copy(mc);
+                    c.0.write(mc).parent = Some(copy);
+                    c
+                })
+                .collect();
+
+            if let E4XNodeKind::Element {
+                attributes: copy_attributes,
+                children: copy_children,
+                ..
+            } = &mut copy.0.write(mc).kind
+            {
+                *copy_attributes = attributes;
+                *copy_children = children;
+            }
+        }
+
+        copy
+    }
+
+    /// Determine if this node matches a given (possibly attribute) name.
+    ///
+    /// A multiname with no local name (or a local name of `*`) matches any
+    /// node of the correct type.
+    pub fn matches_name(self, name: &Multiname<'gc>) -> bool {
+        let read = self.0.read();
+
+        let local_name = name.local_name().filter(|n| n != b"*");
+        if name.is_attribute() != matches!(read.kind, E4XNodeKind::Attribute(_)) {
+            return false;
+        }
+
+        // The any name matches every child, including text nodes.
+        if local_name.is_none() && name.namespace_set().any(|ns| ns.is_any()) {
+            return true;
+        }
+
+        if !matches!(
+            read.kind,
+            E4XNodeKind::Element { .. } | E4XNodeKind::Attribute(_)
+        ) {
+            return false;
+        }
+
+        if let Some(local_name) = local_name {
+            if read.local_name != Some(local_name) {
+                return false;
+            }
+        }
+
+        let uri = read.namespace.map(|ns| ns.uri).unwrap_or_else(|| "".into());
+
+        name.namespace_set().any(|ns| match ns {
+            Namespace::Any => true,
+            Namespace::Namespace(ns_uri) | Namespace::Package(ns_uri) => *ns_uri == uri,
+            _ => false,
+        })
+    }
+
+    /// Collect all descendants of this node that match a given name.
+    ///
+    /// Attribute names match the attributes of this node and all of its
+    /// descendant elements.
+    pub fn descendants(self, name: &Multiname<'gc>, out: &mut Vec<E4XNode<'gc>>) {
+        if name.is_attribute() {
+            out.extend(
+                self.attributes()
+                    .into_iter()
+                    .filter(|attr| attr.matches_name(name)),
+            );
+        }
+
+        for child in self.children() {
+            if !name.is_attribute() && child.matches_name(name) {
+                out.push(child);
+            }
+
+            child.descendants(name, out);
+        }
+    }
+
+    /// Indicates if this node contains simple content (text, attributes, or
+    /// elements that do not contain other elements).
+    pub fn has_simple_content(self) -> bool {
+        match &self.0.read().kind {
+            E4XNodeKind::Comment(_) | E4XNodeKind::ProcessingInstruction(_) => false,
+            E4XNodeKind::Element { children, .. } => !children.iter().any(|c| c.is_element()),
+            _ => true,
+        }
+    }
+
+    /// Indicates if this node is an element that contains other elements.
+    pub fn has_complex_content(self) -> bool {
+        match &self.0.read().kind {
+            E4XNodeKind::Element { children, .. } => children.iter().any(|c| c.is_element()),
+            _ => false,
+        }
+    }
+
+    /// Structurally compare two nodes, as per the E4X equality algorithm.
+    pub fn equals(self, other: E4XNode<'gc>) -> bool {
+        if Self::ptr_eq(self, other) {
+            return true;
+        }
+
+        let this = self.0.read();
+        let that = other.0.read();
+
+        if this.local_name != that.local_name
+            || this.namespace.map(|ns| ns.uri) != that.namespace.map(|ns| ns.uri)
+        {
+            return false;
+        }
+
+        match (&this.kind, &that.kind) {
+            (E4XNodeKind::Text(a), E4XNodeKind::Text(b))
+            | (E4XNodeKind::CData(a), E4XNodeKind::CData(b))
+            | (E4XNodeKind::Text(a), E4XNodeKind::CData(b))
+            | (E4XNodeKind::CData(a), E4XNodeKind::Text(b))
+            | (E4XNodeKind::Comment(a), E4XNodeKind::Comment(b))
+            | (E4XNodeKind::ProcessingInstruction(a), E4XNodeKind::ProcessingInstruction(b))
+            | (E4XNodeKind::Attribute(a), E4XNodeKind::Attribute(b)) => a == b,
+            (
+                E4XNodeKind::Element {
+                    attributes: a_attrs,
+                    children: a_children,
+                    ..
+                },
+                E4XNodeKind::Element {
+                    attributes: b_attrs,
+                    children: b_children,
+                    ..
+                },
+            ) => {
+                a_attrs.len() == b_attrs.len()
+                    && a_children.len() == b_children.len()
+                    && a_attrs.iter().all(|a| b_attrs.iter().any(|b| a.equals(*b)))
+                    && a_children
+                        .iter()
+                        .zip(b_children.iter())
+                        .all(|(a, b)| a.equals(*b))
+            }
+            _ => false,
+        }
+    }
+
+    /// Convert this node to a string, as per the E4X `ToString` algorithm.
+    ///
+    /// Nodes with simple content yield their text; all others yield their
+    /// markup.
+    pub fn xml_to_string(self, activation: &mut Activation<'_, 'gc, '_>) -> AvmString<'gc> {
+        match &self.0.read().kind {
+            E4XNodeKind::Text(text) | E4XNodeKind::CData(text) | E4XNodeKind::Attribute(text) => {
+                return *text
+            }
+            E4XNodeKind::Element { children, .. } if self.has_simple_content() => {
+                let mut out = WString::new();
+                for child in children {
+                    if let E4XNodeKind::Text(text) | E4XNodeKind::CData(text) = &*child.kind() {
+                        out.push_str(text);
+                    }
+                }
+                return AvmString::new(activation.context.gc_context, out);
+            }
+            _ => {}
+        }
+
+        self.xml_to_xml_string(activation)
+    }
+
+    /// Convert this node to markup, as per the E4X `ToXMLString` algorithm.
+    pub fn xml_to_xml_string(self, activation: &mut Activation<'_, 'gc, '_>) -> AvmString<'gc> {
+        let settings = activation.avm2().xml_settings.clone();
+        let mut out = WString::new();
+
+        // Namespaces declared by our ancestors still need to be declared when
+        // this node is printed on its own.
+        let own_declarations = match &*self.kind() {
+            E4XNodeKind::Element {
+                namespace_declarations,
+                ..
+            } => namespace_declarations.clone(),
+            _ => Vec::new(),
+        };
+        let inherited: Vec<_> = self
+            .parent()
+            .map(|p| p.in_scope_namespaces())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|ns| !own_declarations.iter().any(|own| own.prefix == ns.prefix))
+            .collect();
+
+        self.write_xml_string(&mut out, &settings, 0, &inherited);
+
+        AvmString::new(activation.context.gc_context, out)
+    }
+
+    fn write_xml_string(
+        self,
+        out: &mut WString,
+        settings: &XmlSettings,
+        indent_level: usize,
+        inherited_declarations: &[E4XNamespace<'gc>],
+    ) {
+        let read = self.0.read();
+        let write_indent = |out: &mut WString| {
+            if settings.pretty_printing {
+                for _ in 0..indent_level {
+                    out.push_byte(b' ');
+                }
+            }
+        };
+
+        match &read.kind {
+            E4XNodeKind::Text(text) => {
+                let text: &WStr = if settings.pretty_printing {
+                    text.trim()
+                } else {
+                    text
+                };
+                out.push_str(&escape_element_value(text));
+            }
+            E4XNodeKind::Attribute(value) => {
+                out.push_str(&escape_attribute_value(value));
+            }
+            E4XNodeKind::CData(text) => {
+                write_indent(out);
+                out.push_str(WStr::from_units(b"<![CDATA["));
+                out.push_str(text);
+                out.push_str(WStr::from_units(b"]]>"));
+            }
+            E4XNodeKind::Comment(text) => {
+                write_indent(out);
+                out.push_str(WStr::from_units(b"<!--"));
+                out.push_str(text);
+                out.push_str(WStr::from_units(b"-->"));
+            }
+            E4XNodeKind::ProcessingInstruction(text) => {
+                write_indent(out);
+                out.push_str(WStr::from_units(b"<?"));
+                if let Some(name) = read.local_name {
+                    out.push_str(&name);
+                }
+                if !text.is_empty() {
+                    out.push_byte(b' ');
+                    out.push_str(text);
+                }
+                out.push_str(WStr::from_units(b"?>"));
+            }
+            E4XNodeKind::Element {
+                namespace_declarations,
+                attributes,
+                children,
+            } => {
+                write_indent(out);
+                out.push_byte(b'<');
+                write_qualified_name(out, read.namespace, read.local_name);
+
+                for ns in inherited_declarations.iter().chain(namespace_declarations) {
+                    out.push_str(WStr::from_units(b" xmlns"));
+                    if let Some(prefix) = ns.prefix {
+                        out.push_byte(b':');
+                        out.push_str(&prefix);
+                    }
+                    out.push_str(WStr::from_units(b"=\""));
+                    out.push_str(&escape_attribute_value(&ns.uri));
+                    out.push_byte(b'"');
+                }
+
+                for attribute in attributes {
+                    let attribute = attribute.0.read();
+                    out.push_byte(b' ');
+                    write_qualified_name(out, attribute.namespace, attribute.local_name);
+                    out.push_str(WStr::from_units(b"=\""));
+                    if let E4XNodeKind::Attribute(value) = &attribute.kind {
+                        out.push_str(&escape_attribute_value(value));
+                    }
+                    out.push_byte(b'"');
+                }
+
+                if children.is_empty() {
+                    out.push_str(WStr::from_units(b"/>"));
+                    return;
+                }
+
+                out.push_byte(b'>');
+
+                let indent_children =
+                    settings.pretty_printing && (children.len() > 1 || !children[0].is_text());
+                let child_indent = if indent_children {
+                    indent_level + settings.pretty_indent.max(0) as usize
+                } else {
+                    0
+                };
+
+                for child in children {
+                    if indent_children {
+                        out.push_byte(b'\n');
+                    }
+
+                    child.write_xml_string(out, settings, child_indent, &[]);
+                }
+
+                if indent_children {
+                    out.push_byte(b'\n');
+                    write_indent(out);
+                }
+
+                out.push_str(WStr::from_units(b"</"));
+                write_qualified_name(out, read.namespace, read.local_name);
+                out.push_byte(b'>');
+            }
+        }
+    }
+}
+
+impl<'gc> fmt::Debug for E4XNode<'gc> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let read = self.0.read();
+        f.debug_struct("E4XNode")
+            .field("ptr", &self.0.as_ptr())
+            .field("local_name", &read.local_name)
+            .field("kind", &self.node_kind())
+            .finish()
+    }
+}
+
+/// Split a raw XML name into its prefix and local name.
+fn split_name(name: &[u8]) -> (Option<&[u8]>, &[u8]) {
+    match name.iter().position(|c| *c == b':') {
+        Some(split) => (Some(&name[..split]), &name[split + 1..]),
+        None => (None, name),
+    }
+}
+
+fn write_qualified_name<'gc>(
+    out: &mut WString,
+    namespace: Option<E4XNamespace<'gc>>,
+    local_name: Option<AvmString<'gc>>,
+) {
+    if let Some(prefix) = namespace.and_then(|ns| ns.prefix) {
+        out.push_str(&prefix);
+        out.push_byte(b':');
+    }
+
+    if let Some(local_name) = local_name {
+        out.push_str(&local_name);
+    }
+}
+
+/// Implementation of `EscapeAttributeValue` from ECMA-357(10.2.1.2)
+pub fn escape_attribute_value(s: &WStr) -> WString {
+    let mut r = WString::with_capacity(s.len(), s.is_wide());
+    for c in s {
+        let escape: &[u8] = match u8::try_from(c) {
+            Ok(b'"') => b"&quot;",
+            Ok(b'<') => b"&lt;",
+            Ok(b'&') => b"&amp;",
+            Ok(b'\x0A') => b"&#xA;",
+            Ok(b'\x0D') => b"&#xD;",
+            Ok(b'\x09') => b"&#x9;",
+            _ => {
+                r.push(c);
+                continue;
+            }
+        };
+
+        r.push_str(WStr::from_units(escape));
+    }
+
+    r
+}
+
+/// Implementation of `EscapeElementValue` from ECMA-357(10.2.1.1)
+pub fn escape_element_value(s: &WStr) -> WString {
+    let mut r = WString::with_capacity(s.len(), s.is_wide());
+    for c in s {
+        let escape: &[u8] = match u8::try_from(c) {
+            Ok(b'<') => b"&lt;",
+            Ok(b'>') => b"&gt;",
+            Ok(b'&') => b"&amp;",
+            _ => {
+                r.push(c);
+                continue;
+            }
+        };
+
+        r.push_str(WStr::from_units(escape));
+    }
+
+    r
+}
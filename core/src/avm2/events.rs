@@ -101,6 +101,32 @@ pub enum EventData<'gc> {
         button_down: bool,
         delta: i32,
     },
+    Progress {
+        bytes_loaded: u64,
+        bytes_total: u64,
+    },
+    IOError {
+        text: AvmString<'gc>,
+        error_id: i32,
+    },
+    SecurityError {
+        text: AvmString<'gc>,
+        error_id: i32,
+    },
+    HttpStatus {
+        status: i32,
+    },
+    NetStatus {
+        info: Object<'gc>,
+    },
+    Data {
+        data: AvmString<'gc>,
+    },
+    Status {
+        code: Option<AvmString<'gc>>,
+        level: AvmString<'gc>,
+    },
+    Timer,
 }
 
 impl<'gc> EventData<'gc> {
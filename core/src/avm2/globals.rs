@@ -17,7 +17,7 @@ mod array;
 mod boolean;
 mod class;
 mod date;
-mod flash;
+pub(crate) mod flash;
 mod function;
 mod global_scope;
 mod int;
@@ -80,6 +80,7 @@ pub struct SystemPrototypes<'gc> {
     pub vector: Object<'gc>,
     pub soundtransform: Object<'gc>,
     pub soundchannel: Object<'gc>,
+    pub id3info: Object<'gc>,
     pub bitmap: Object<'gc>,
     pub bitmapdata: Object<'gc>,
     pub date: Object<'gc>,
@@ -88,6 +89,28 @@ pub struct SystemPrototypes<'gc> {
     pub nativemenu: Object<'gc>,
     pub contextmenu: Object<'gc>,
     pub mouseevent: Object<'gc>,
+    pub progressevent: Object<'gc>,
+    pub ioerrorevent: Object<'gc>,
+    pub securityerrorevent: Object<'gc>,
+    pub httpstatusevent: Object<'gc>,
+    pub netstatusevent: Object<'gc>,
+    pub dataevent: Object<'gc>,
+    pub statusevent: Object<'gc>,
+    pub urlvariables: Object<'gc>,
+    pub matrix: Object<'gc>,
+    pub colortransform: Object<'gc>,
+    pub transform: Object<'gc>,
+    pub timerevent: Object<'gc>,
+    pub dictionary: Object<'gc>,
+    pub iexternalizable: Object<'gc>,
+    pub bevelfilter: Object<'gc>,
+    pub blurfilter: Object<'gc>,
+    pub colormatrixfilter: Object<'gc>,
+    pub convolutionfilter: Object<'gc>,
+    pub dropshadowfilter: Object<'gc>,
+    pub glowfilter: Object<'gc>,
+    pub gradientbevelfilter: Object<'gc>,
+    pub gradientglowfilter: Object<'gc>,
 }
 
 impl<'gc> SystemPrototypes<'gc> {
@@ -142,6 +165,7 @@ impl<'gc> SystemPrototypes<'gc> {
             vector: empty,
             soundtransform: empty,
             soundchannel: empty,
+            id3info: empty,
             bitmap: empty,
             bitmapdata: empty,
             date: empty,
@@ -150,6 +174,28 @@ impl<'gc> SystemPrototypes<'gc> {
             nativemenu: empty,
             contextmenu: empty,
             mouseevent: empty,
+            progressevent: empty,
+            ioerrorevent: empty,
+            securityerrorevent: empty,
+            httpstatusevent: empty,
+            netstatusevent: empty,
+            dataevent: empty,
+            statusevent: empty,
+            urlvariables: empty,
+            matrix: empty,
+            colortransform: empty,
+            transform: empty,
+            timerevent: empty,
+            dictionary: empty,
+            iexternalizable: empty,
+            bevelfilter: empty,
+            blurfilter: empty,
+            colormatrixfilter: empty,
+            convolutionfilter: empty,
+            dropshadowfilter: empty,
+            glowfilter: empty,
+            gradientbevelfilter: empty,
+            gradientglowfilter: empty,
         }
     }
 }
@@ -194,6 +240,7 @@ pub struct SystemClasses<'gc> {
     pub vector: ClassObject<'gc>,
     pub soundtransform: ClassObject<'gc>,
     pub soundchannel: ClassObject<'gc>,
+    pub id3info: ClassObject<'gc>,
     pub bitmap: ClassObject<'gc>,
     pub bitmapdata: ClassObject<'gc>,
     pub date: ClassObject<'gc>,
@@ -202,6 +249,28 @@ pub struct SystemClasses<'gc> {
     pub nativemenu: ClassObject<'gc>,
     pub contextmenu: ClassObject<'gc>,
     pub mouseevent: ClassObject<'gc>,
+    pub progressevent: ClassObject<'gc>,
+    pub ioerrorevent: ClassObject<'gc>,
+    pub securityerrorevent: ClassObject<'gc>,
+    pub httpstatusevent: ClassObject<'gc>,
+    pub netstatusevent: ClassObject<'gc>,
+    pub dataevent: ClassObject<'gc>,
+    pub statusevent: ClassObject<'gc>,
+    pub urlvariables: ClassObject<'gc>,
+    pub matrix: ClassObject<'gc>,
+    pub colortransform: ClassObject<'gc>,
+    pub transform: ClassObject<'gc>,
+    pub timerevent: ClassObject<'gc>,
+    pub dictionary: ClassObject<'gc>,
+    pub iexternalizable: ClassObject<'gc>,
+    pub bevelfilter: ClassObject<'gc>,
+    pub blurfilter: ClassObject<'gc>,
+    pub colormatrixfilter: ClassObject<'gc>,
+    pub convolutionfilter: ClassObject<'gc>,
+    pub dropshadowfilter: ClassObject<'gc>,
+    pub glowfilter: ClassObject<'gc>,
+    pub gradientbevelfilter: ClassObject<'gc>,
+    pub gradientglowfilter: ClassObject<'gc>,
 }
 
 impl<'gc> SystemClasses<'gc> {
@@ -256,6 +325,7 @@ impl<'gc> SystemClasses<'gc> {
             vector: object,
             soundtransform: object,
             soundchannel: object,
+            id3info: object,
             bitmap: object,
             bitmapdata: object,
             date: object,
@@ -264,6 +334,28 @@ impl<'gc> SystemClasses<'gc> {
             nativemenu: object,
             contextmenu: object,
             mouseevent: object,
+            progressevent: object,
+            ioerrorevent: object,
+            securityerrorevent: object,
+            httpstatusevent: object,
+            netstatusevent: object,
+            dataevent: object,
+            statusevent: object,
+            urlvariables: object,
+            matrix: object,
+            colortransform: object,
+            transform: object,
+            timerevent: object,
+            dictionary: object,
+            iexternalizable: object,
+            bevelfilter: object,
+            blurfilter: object,
+            colormatrixfilter: object,
+            convolutionfilter: object,
+            dropshadowfilter: object,
+            glowfilter: object,
+            gradientbevelfilter: object,
+            gradientglowfilter: object,
         }
     }
 }
@@ -543,6 +635,11 @@ pub fn load_player_globals<'gc>(
         flash::system::capabilities::create_class(mc),
         script,
     )?;
+    class(
+        activation,
+        flash::system::loader_context::create_class(mc),
+        script,
+    )?;
     class(
         activation,
         flash::system::security::create_class(mc),
@@ -573,11 +670,42 @@ pub fn load_player_globals<'gc>(
         flash::events::mouseevent::create_class(mc),
         script
     );
-    class(
+    avm2_system_class!(
+        ioerrorevent,
         activation,
         flash::events::ioerrorevent::create_class(mc),
-        script,
-    )?;
+        script
+    );
+    avm2_system_class!(
+        securityerrorevent,
+        activation,
+        flash::events::securityerrorevent::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        httpstatusevent,
+        activation,
+        flash::events::httpstatusevent::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        netstatusevent,
+        activation,
+        flash::events::netstatusevent::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        dataevent,
+        activation,
+        flash::events::dataevent::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        statusevent,
+        activation,
+        flash::events::statusevent::create_class(mc),
+        script
+    );
     class(
         activation,
         flash::events::contextmenuevent::create_class(mc),
@@ -588,11 +716,12 @@ pub fn load_player_globals<'gc>(
         flash::events::keyboardevent::create_class(mc),
         script,
     )?;
-    class(
+    avm2_system_class!(
+        progressevent,
         activation,
         flash::events::progressevent::create_class(mc),
-        script,
-    )?;
+        script
+    );
     class(
         activation,
         flash::events::activityevent::create_class(mc),
@@ -609,6 +738,12 @@ pub fn load_player_globals<'gc>(
         flash::events::eventphase::create_class(mc),
         script,
     )?;
+    avm2_system_class!(
+        timerevent,
+        activation,
+        flash::events::timerevent::create_class(mc),
+        script
+    );
 
     // package `flash.utils`
     avm2_system_class!(
@@ -628,11 +763,18 @@ pub fn load_player_globals<'gc>(
         script,
     )?;
 
-    class(
+    avm2_system_class!(
+        dictionary,
         activation,
         flash::utils::dictionary::create_class(mc),
-        script,
-    )?;
+        script
+    );
+    avm2_system_class!(
+        iexternalizable,
+        activation,
+        flash::utils::iexternalizable::create_interface(mc),
+        script
+    );
 
     class(activation, flash::utils::timer::create_class(mc), script)?;
 
@@ -677,6 +819,38 @@ pub fn load_player_globals<'gc>(
         script,
     )?;
 
+    function(
+        activation,
+        "flash.utils",
+        "setInterval",
+        flash::utils::set_interval,
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.utils",
+        "clearInterval",
+        flash::utils::clear_interval,
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.utils",
+        "setTimeout",
+        flash::utils::set_timeout,
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.utils",
+        "clearTimeout",
+        flash::utils::clear_timeout,
+        script,
+    )?;
+
     // package `flash.display`
     class(
         activation,
@@ -756,6 +930,11 @@ pub fn load_player_globals<'gc>(
         flash::display::capsstyle::create_class(mc),
         script,
     )?;
+    class(
+        activation,
+        flash::display::blendmode::create_class(mc),
+        script,
+    )?;
     class(activation, flash::display::loader::create_class(mc), script)?;
     avm2_system_class!(
         loaderinfo,
@@ -828,8 +1007,89 @@ pub fn load_player_globals<'gc>(
         script,
     )?;
 
+    // package `flash.filters`
+    class(
+        activation,
+        flash::filters::bitmapfilter::create_class(mc),
+        script,
+    )?;
+    avm2_system_class!(
+        bevelfilter,
+        activation,
+        flash::filters::bevelfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        blurfilter,
+        activation,
+        flash::filters::blurfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        colormatrixfilter,
+        activation,
+        flash::filters::colormatrixfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        convolutionfilter,
+        activation,
+        flash::filters::convolutionfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        dropshadowfilter,
+        activation,
+        flash::filters::dropshadowfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        glowfilter,
+        activation,
+        flash::filters::glowfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        gradientbevelfilter,
+        activation,
+        flash::filters::gradientbevelfilter::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        gradientglowfilter,
+        activation,
+        flash::filters::gradientglowfilter::create_class(mc),
+        script
+    );
+    class(
+        activation,
+        flash::filters::displacementmapfilter::create_class(mc),
+        script,
+    )?;
+    class(
+        activation,
+        flash::filters::bitmapfilterquality::create_class(mc),
+        script,
+    )?;
+    class(
+        activation,
+        flash::filters::bitmapfiltertype::create_class(mc),
+        script,
+    )?;
+
     // package `flash.geom`
-    class(activation, flash::geom::matrix::create_class(mc), script)?;
+    avm2_system_class!(
+        colortransform,
+        activation,
+        flash::geom::color_transform::create_class(mc),
+        script
+    );
+    avm2_system_class!(
+        matrix,
+        activation,
+        flash::geom::matrix::create_class(mc),
+        script
+    );
     avm2_system_class!(
         point,
         activation,
@@ -842,6 +1102,12 @@ pub fn load_player_globals<'gc>(
         flash::geom::rectangle::create_class(mc),
         script
     );
+    avm2_system_class!(
+        transform,
+        activation,
+        flash::geom::transform::create_class(mc),
+        script
+    );
 
     // package `flash.media`
     avm2_system_class!(
@@ -868,6 +1134,17 @@ pub fn load_player_globals<'gc>(
         flash::media::soundchannel::create_class(mc),
         script
     );
+    class(
+        activation,
+        flash::media::soundloadercontext::create_class(mc),
+        script,
+    )?;
+    avm2_system_class!(
+        id3info,
+        activation,
+        flash::media::id3info::create_class(mc),
+        script
+    );
 
     // package `flash.ui`
     avm2_system_class!(
@@ -892,6 +1169,19 @@ pub fn load_player_globals<'gc>(
         script
     );
 
+    class(
+        activation,
+        flash::net::netconnection::create_class(mc),
+        script,
+    )?;
+    class(activation, flash::net::netstream::create_class(mc), script)?;
+    class(activation, flash::net::socket::create_class(mc), script)?;
+    class(activation, flash::net::xml_socket::create_class(mc), script)?;
+    class(
+        activation,
+        flash::net::local_connection::create_class(mc),
+        script,
+    )?;
     class(
         activation,
         flash::net::object_encoding::create_class(mc),
@@ -902,6 +1192,52 @@ pub fn load_player_globals<'gc>(
         flash::net::url_request::create_class(mc),
         script,
     )?;
+    class(
+        activation,
+        flash::net::url_request_header::create_class(mc),
+        script,
+    )?;
+    class(
+        activation,
+        flash::net::url_request_method::create_class(mc),
+        script,
+    )?;
+    avm2_system_class!(
+        urlvariables,
+        activation,
+        flash::net::url_variables::create_class(mc),
+        script
+    );
+    class(activation, flash::net::url_loader::create_class(mc), script)?;
+    class(
+        activation,
+        flash::net::url_loader_data_format::create_class(mc),
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.net",
+        "navigateToURL",
+        flash::net::navigate_to_url,
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.net",
+        "registerClassAlias",
+        flash::net::register_class_alias,
+        script,
+    )?;
+
+    function(
+        activation,
+        "flash.net",
+        "getClassByAlias",
+        flash::net::get_class_by_alias,
+        script,
+    )?;
 
     // package `flash.text`
     avm2_system_class!(
@@ -932,6 +1268,11 @@ pub fn load_player_globals<'gc>(
         script,
     )?;
     class(activation, flash::text::font::create_class(mc), script)?;
+    class(
+        activation,
+        flash::text::stylesheet::create_class(mc),
+        script,
+    )?;
 
     // package `flash.crypto`
     function(
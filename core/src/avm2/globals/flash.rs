@@ -4,6 +4,7 @@ pub mod crypto;
 pub mod display;
 pub mod events;
 pub mod external;
+pub mod filters;
 pub mod geom;
 pub mod media;
 pub mod net;
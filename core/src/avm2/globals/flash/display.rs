@@ -3,6 +3,7 @@
 pub mod actionscriptversion;
 pub mod bitmap;
 pub mod bitmapdata;
+pub mod blendmode;
 pub mod capsstyle;
 pub mod displayobject;
 pub mod displayobjectcontainer;
@@ -1,16 +1,31 @@
 //! `flash.display.BitmapData` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::filters::object_to_filter;
+use crate::avm2::globals::flash::geom::color_transform::object_to_color_transform;
+use crate::avm2::globals::flash::geom::matrix::object_to_matrix;
+use crate::avm2::globals::flash::geom::rectangle::create_rectangle;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{bitmapdata_allocator, Object, TObject};
+use crate::avm2::object::{
+    bitmapdata_allocator, BitmapDataObject, ByteArrayObject, Object, TObject,
+};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
-use crate::bitmap::bitmap_data::BitmapData;
+use crate::bitmap::bitmap_data::{
+    BitmapData, ChannelOptions, Color, IBitmapDrawable, ThresholdOperation,
+};
+use crate::bitmap::color_transform_params::ColorTransformParams;
 use crate::bitmap::is_size_valid;
 use crate::character::Character;
+use crate::color_transform::ColorTransform;
+use crate::display_object::TDisplayObject;
+use crate::matrix::Matrix;
+use crate::transform::Transform;
 use gc_arena::{GcCell, MutationContext};
+use swf::BlendMode;
 
 /// Implements `flash.display.BitmapData`'s instance constructor.
 pub fn instance_init<'gc>(
@@ -21,6 +36,12 @@ pub fn instance_init<'gc>(
     if let Some(this) = this {
         activation.super_init(this, &[])?;
 
+        if this.as_bitmap_data().is_some() {
+            // This object was created from existing bitmap data (for example,
+            // by `clone`), so there is nothing left to initialize.
+            return Ok(Value::Undefined);
+        }
+
         let name = this.instance_of_class_definition().map(|c| c.read().name());
         let character = this
             .instance_of()
@@ -180,35 +201,1394 @@ pub fn get_pixel<'gc>(
     Ok(Value::Undefined)
 }
 
-/// Construct `BitmapData`'s class.
-pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
-    let class = Class::new(
-        QName::new(Namespace::package("flash.display"), "BitmapData"),
-        Some(QName::new(Namespace::package(""), "Object").into()),
-        Method::from_builtin(instance_init, "<BitmapData instance initializer>", mc),
-        Method::from_builtin(class_init, "<BitmapData class initializer>", mc),
-        mc,
-    );
+/// Implements `BitmapData.rect`'s getter.
+pub fn rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let (width, height) = (bitmap_data.read().width(), bitmap_data.read().height());
+        return create_rectangle(activation, (0.0, 0.0, width as f64, height as f64));
+    }
 
-    let mut write = class.write(mc);
+    Ok(Value::Undefined)
+}
 
-    write.set_attributes(ClassAttributes::SEALED);
-    write.set_instance_allocator(bitmapdata_allocator);
+/// Implements `BitmapData.getPixel32`.
+pub fn get_pixel32<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let pixel: u32 = bitmap_data.read().get_pixel32(x, y).into();
+        return Ok(pixel.into());
+    }
 
-    write.implements(QName::new(Namespace::package("flash.display"), "IBitmapDrawable").into());
+    Ok(Value::Undefined)
+}
 
-    const PUBLIC_INSTANCE_PROPERTIES: &[(
-        &str,
-        Option<NativeMethodImpl>,
-        Option<NativeMethodImpl>,
-    )] = &[
-        ("width", Some(width), None),
-        ("height", Some(height), None),
-        ("transparent", Some(transparent), None),
-    ];
-    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+/// Implements `BitmapData.setPixel`.
+pub fn set_pixel<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let color = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .set_pixel(x, y, color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setPixel32`.
+pub fn set_pixel32<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let color = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .set_pixel32(x, y, color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getPixels`.
+pub fn get_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let rectangle = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let rectangle = get_rectangle(activation, rectangle)?;
+
+        let mut storage = ByteArrayStorage::new();
+        let read = bitmap_data.read();
+        let (min_x, min_y, end_x, end_y) = clip_rectangle(rectangle, read.width(), read.height());
+        for y in min_y..end_y {
+            for x in min_x..end_x {
+                storage.write_unsigned_int(read.get_pixel32(x as i32, y as i32).into())?;
+            }
+        }
+        drop(read);
+        storage.set_position(0);
+
+        return Ok(ByteArrayObject::from_storage(activation, storage)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.setPixels`.
+pub fn set_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let rectangle = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let rectangle = get_rectangle(activation, rectangle)?;
+        let bytearray = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let storage = bytearray
+            .as_bytearray()
+            .ok_or("TypeError: Error #1034: Type Coercion failed: cannot convert value to flash.utils.ByteArray.")?;
+
+        let mut write = bitmap_data.write(activation.context.gc_context);
+        let (min_x, min_y, end_x, end_y) = clip_rectangle(rectangle, write.width(), write.height());
+        for y in min_y..end_y {
+            for x in min_x..end_x {
+                let color = storage
+                    .read_unsigned_int()
+                    .map_err(|_| "EOFError: Error #2030: End of file was encountered.")?;
+                write.set_pixel32(x as i32, y as i32, (color as i32).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.copyPixels`.
+pub fn copy_pixels<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+        let alpha_bitmap = args
+            .get(3)
+            .and_then(|v| v.as_object())
+            .and_then(|o| o.as_bitmap_data());
+        let alpha_point = match args.get(4) {
+            Some(Value::Object(alpha_point)) => get_point(activation, *alpha_point)?,
+            _ => (0, 0),
+        };
+        let merge_alpha = args
+            .get(5)
+            .unwrap_or(&Value::Bool(false))
+            .coerce_to_boolean();
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            // Copying from a bitmap to itself must read from a snapshot.
+            let source_bitmap_clone: BitmapData;
+            let source_bitmap_gc_ref;
+            let source_bitmap_ref = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                source_bitmap_clone = source_bitmap.read().clone();
+                &source_bitmap_clone
+            } else {
+                source_bitmap_gc_ref = source_bitmap.read();
+                &source_bitmap_gc_ref
+            };
+
+            if let Some(alpha_bitmap) = alpha_bitmap {
+                check_not_disposed(alpha_bitmap)?;
+
+                let alpha_bitmap_clone: BitmapData;
+                let alpha_bitmap_gc_ref;
+                let alpha_bitmap_ref = if GcCell::ptr_eq(alpha_bitmap, bitmap_data) {
+                    alpha_bitmap_clone = alpha_bitmap.read().clone();
+                    &alpha_bitmap_clone
+                } else {
+                    alpha_bitmap_gc_ref = alpha_bitmap.read();
+                    &alpha_bitmap_gc_ref
+                };
+
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .copy_pixels(
+                        source_bitmap_ref,
+                        source_rect,
+                        dest_point,
+                        Some((alpha_bitmap_ref, alpha_point, merge_alpha)),
+                    );
+            } else {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .copy_pixels(source_bitmap_ref, source_rect, dest_point, None);
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.copyChannel`.
+pub fn copy_channel<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (src_min_x, src_min_y, src_width, src_height) = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (dest_x, dest_y) = get_point(activation, dest_point)?;
+        let source_channel = args
+            .get(3)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let dest_channel = args
+            .get(4)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            let source_bitmap_clone: BitmapData;
+            let source_bitmap_gc_ref;
+            let source_bitmap_ref = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                source_bitmap_clone = source_bitmap.read().clone();
+                &source_bitmap_clone
+            } else {
+                source_bitmap_gc_ref = source_bitmap.read();
+                &source_bitmap_gc_ref
+            };
+
+            let mut write = bitmap_data.write(activation.context.gc_context);
+            let dest_point = (
+                (dest_x.max(0) as u32).min(write.width()),
+                (dest_y.max(0) as u32).min(write.height()),
+            );
+            let src_min_x = src_min_x.max(0) as u32;
+            let src_min_y = src_min_y.max(0) as u32;
+            let src_rect = (
+                src_min_x,
+                src_min_y,
+                src_min_x + src_width.max(0) as u32,
+                src_min_y + src_height.max(0) as u32,
+            );
+
+            write.copy_channel(
+                dest_point,
+                src_rect,
+                source_bitmap_ref,
+                source_channel,
+                dest_channel,
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.fillRect`.
+pub fn fill_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let rectangle = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let rectangle = get_rectangle(activation, rectangle)?;
+        let color = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        // Only the part of the rectangle that overlaps the bitmap is filled.
+        let mut write = bitmap_data.write(activation.context.gc_context);
+        let (min_x, min_y, end_x, end_y) = clip_rectangle(rectangle, write.width(), write.height());
+        write.fill_rect(min_x, min_y, end_x - min_x, end_y - min_y, color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.floodFill`.
+pub fn flood_fill<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let color = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        let mut write = bitmap_data.write(activation.context.gc_context);
+        if write.is_point_in_bounds(x, y) {
+            let color = Color::from(color).to_premultiplied_alpha(write.transparency());
+            write.flood_fill(x as u32, y as u32, color);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.noise`.
+pub fn noise<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let seed = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let low = args
+            .get(1)
+            .unwrap_or(&0.into())
+            .coerce_to_u32(activation)?
+            .min(255) as u8;
+        let high = args
+            .get(2)
+            .unwrap_or(&255.into())
+            .coerce_to_u32(activation)?
+            .min(255) as u8;
+        let channel_options = ChannelOptions::from_bits_truncate(
+            args.get(3)
+                .unwrap_or(&(ChannelOptions::RGB.bits() as i32).into())
+                .coerce_to_u32(activation)? as u8,
+        );
+        let gray_scale = args
+            .get(4)
+            .unwrap_or(&Value::Bool(false))
+            .coerce_to_boolean();
+
+        bitmap_data.write(activation.context.gc_context).noise(
+            seed,
+            low,
+            high.max(low),
+            channel_options,
+            gray_scale,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
 
-    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[("getPixel", get_pixel)];
+/// Implements `BitmapData.perlinNoise`.
+pub fn perlin_noise<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let base_x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_number(activation)?;
+        let base_y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_number(activation)?;
+        let num_octaves = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)? as usize;
+        let seed = args
+            .get(3)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)? as i64;
+        let stitch = args.get(4).unwrap_or(&Value::Undefined).coerce_to_boolean();
+        let fractal_noise = args.get(5).unwrap_or(&Value::Undefined).coerce_to_boolean();
+        let channel_options = ChannelOptions::from_bits_truncate(
+            args.get(6)
+                .unwrap_or(&(ChannelOptions::RGB.bits() as i32).into())
+                .coerce_to_u32(activation)? as u8,
+        );
+        let grayscale = args
+            .get(7)
+            .unwrap_or(&Value::Bool(false))
+            .coerce_to_boolean();
+        let offsets = args.get(8).and_then(|v| v.as_object());
+
+        let mut octave_offsets = Vec::with_capacity(num_octaves);
+        for i in 0..num_octaves {
+            let offset = match offsets {
+                Some(offsets) => offsets.get_property(
+                    &QName::new(Namespace::public(), i.to_string()).into(),
+                    activation,
+                )?,
+                None => Value::Undefined,
+            };
+
+            octave_offsets.push(match offset {
+                Value::Object(point) => {
+                    let x = get_number(activation, point, "x")?;
+                    let y = get_number(activation, point, "y")?;
+                    (x, y)
+                }
+                _ => (0.0, 0.0),
+            });
+        }
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .perlin_noise(
+                (base_x, base_y),
+                num_octaves,
+                seed,
+                stitch,
+                fractal_noise,
+                channel_options,
+                grayscale,
+                octave_offsets,
+            );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.paletteMap`.
+pub fn palette_map<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+
+        let mut get_channel = |index: usize, shift: usize| -> Result<[u32; 256], Error> {
+            let mut array = [0_u32; 256];
+            for (i, item) in array.iter_mut().enumerate() {
+                *item = (i << shift) as u32;
+            }
+
+            if let Some(Value::Object(arg)) = args.get(index) {
+                for (i, item) in array.iter_mut().enumerate() {
+                    *item = arg
+                        .get_property(
+                            &QName::new(Namespace::public(), i.to_string()).into(),
+                            activation,
+                        )?
+                        .coerce_to_u32(activation)?;
+                }
+            }
+
+            Ok(array)
+        };
+
+        let red_array = get_channel(3, 16)?;
+        let green_array = get_channel(4, 8)?;
+        let blue_array = get_channel(5, 0)?;
+        let alpha_array = get_channel(6, 24)?;
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .palette_map(
+                        None,
+                        source_rect,
+                        dest_point,
+                        (red_array, green_array, blue_array, alpha_array),
+                    );
+            } else {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .palette_map(
+                        Some(&source_bitmap.read()),
+                        source_rect,
+                        dest_point,
+                        (red_array, green_array, blue_array, alpha_array),
+                    );
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.merge`.
+pub fn merge<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+        let red_mult = args
+            .get(3)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let green_mult = args
+            .get(4)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let blue_mult = args
+            .get(5)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let alpha_mult = args
+            .get(6)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            let source_bitmap_clone: BitmapData;
+            let source_bitmap_gc_ref;
+            let source_bitmap_ref = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                source_bitmap_clone = source_bitmap.read().clone();
+                &source_bitmap_clone
+            } else {
+                source_bitmap_gc_ref = source_bitmap.read();
+                &source_bitmap_gc_ref
+            };
+
+            bitmap_data.write(activation.context.gc_context).merge(
+                source_bitmap_ref,
+                source_rect,
+                dest_point,
+                (red_mult, green_mult, blue_mult, alpha_mult),
+            );
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.scroll`.
+pub fn scroll<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let x = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let y = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        bitmap_data
+            .write(activation.context.gc_context)
+            .scroll(x, y);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.compare`.
+pub fn compare<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    const EQUIVALENT: i32 = 0;
+    const DIFFERENT_WIDTHS: i32 = -3;
+    const DIFFERENT_HEIGHTS: i32 = -4;
+
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let other = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let other = match other.as_bitmap_data() {
+            Some(other) => other,
+            None => {
+                return Err("TypeError: Error #1034: Type Coercion failed: cannot convert value to flash.display.BitmapData.".into())
+            }
+        };
+        check_not_disposed(other)?;
+
+        if GcCell::ptr_eq(bitmap_data, other) {
+            return Ok(EQUIVALENT.into());
+        }
+
+        let bitmap_data = bitmap_data.read();
+        let other = other.read();
+
+        if bitmap_data.width() != other.width() {
+            return Ok(DIFFERENT_WIDTHS.into());
+        }
+
+        if bitmap_data.height() != other.height() {
+            return Ok(DIFFERENT_HEIGHTS.into());
+        }
+
+        let difference = BitmapData::compare(&bitmap_data, &other);
+        drop(bitmap_data);
+        drop(other);
+
+        return match difference {
+            Some(difference) => {
+                let class = activation.avm2().classes().bitmapdata;
+                let difference = GcCell::allocate(activation.context.gc_context, difference);
+                Ok(BitmapDataObject::from_bitmap_data(activation, difference, class)?.into())
+            }
+            None => Ok(EQUIVALENT.into()),
+        };
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.lock`.
+///
+/// Changes to bitmap data are only uploaded to the renderer when it is next
+/// drawn, so there is nothing to do here.
+pub fn lock<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.unlock`.
+pub fn unlock<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.clone`.
+pub fn clone<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+
+        let mut new_bitmap_data = BitmapData::default();
+        {
+            let read = bitmap_data.read();
+            new_bitmap_data.set_pixels(
+                read.width(),
+                read.height(),
+                read.transparency(),
+                read.pixels().to_vec(),
+            );
+        }
+
+        let class = activation.avm2().classes().bitmapdata;
+        let new_bitmap_data = GcCell::allocate(activation.context.gc_context, new_bitmap_data);
+
+        return Ok(BitmapDataObject::from_bitmap_data(activation, new_bitmap_data, class)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.dispose`.
+pub fn dispose<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        // Disposing an already-disposed bitmap is not an error.
+        bitmap_data.write(activation.context.gc_context).dispose();
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.colorTransform`.
+pub fn color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let rectangle = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let rectangle = get_rectangle(activation, rectangle)?;
+        let color_transform = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let params = ColorTransformParams {
+            red_multiplier: get_number(activation, color_transform, "redMultiplier")?,
+            green_multiplier: get_number(activation, color_transform, "greenMultiplier")?,
+            blue_multiplier: get_number(activation, color_transform, "blueMultiplier")?,
+            alpha_multiplier: get_number(activation, color_transform, "alphaMultiplier")?,
+            red_offset: get_number(activation, color_transform, "redOffset")?,
+            green_offset: get_number(activation, color_transform, "greenOffset")?,
+            blue_offset: get_number(activation, color_transform, "blueOffset")?,
+            alpha_offset: get_number(activation, color_transform, "alphaOffset")?,
+        };
+
+        let mut write = bitmap_data.write(activation.context.gc_context);
+        let (min_x, min_y, end_x, end_y) = clip_rectangle(rectangle, write.width(), write.height());
+        write.color_transform(min_x, min_y, end_x, end_y, &params);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.getColorBoundsRect`.
+pub fn get_color_bounds_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let mask = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let color = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let find_color = args
+            .get(2)
+            .unwrap_or(&Value::Bool(true))
+            .coerce_to_boolean();
+
+        let (x, y, width, height) = bitmap_data
+            .read()
+            .color_bounds_rect(find_color, mask, color);
+
+        return create_rectangle(
+            activation,
+            (x as f64, y as f64, width as f64, height as f64),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.threshold`.
+pub fn threshold<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+        let operation = args
+            .get(3)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let operation = match ThresholdOperation::from_wstr(&operation) {
+            Some(operation) => operation,
+            None => return Err("ArgumentError: Error #2005: Parameter 3 is of the incorrect type. Should be type Operation.".into()),
+        };
+        let threshold = args
+            .get(4)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let color = args.get(5).unwrap_or(&0.into()).coerce_to_u32(activation)?;
+        let mask = args
+            .get(6)
+            .unwrap_or(&Value::Unsigned(0xFFFFFFFF))
+            .coerce_to_u32(activation)?;
+        let copy_source = args
+            .get(7)
+            .unwrap_or(&Value::Bool(false))
+            .coerce_to_boolean();
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            let source_bitmap_clone: BitmapData;
+            let source_bitmap_gc_ref;
+            let source_bitmap_ref = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                source_bitmap_clone = source_bitmap.read().clone();
+                &source_bitmap_clone
+            } else {
+                source_bitmap_gc_ref = source_bitmap.read();
+                &source_bitmap_gc_ref
+            };
+
+            let modified_count = bitmap_data.write(activation.context.gc_context).threshold(
+                source_bitmap_ref,
+                source_rect,
+                dest_point,
+                operation,
+                threshold,
+                color,
+                mask,
+                copy_source,
+            );
+
+            return Ok(modified_count.into());
+        }
+    }
+
+    Ok(0.into())
+}
+
+/// Implements `BitmapData.hitTest`.
+pub fn hit_test<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let first_point = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let (first_x, first_y) = get_point(activation, first_point)?;
+        let first_alpha_threshold = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?
+            .min(255) as u8;
+        let second_object = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let second_point = match args.get(3) {
+            Some(Value::Object(second_point)) => Some(get_point(activation, *second_point)?),
+            _ => None,
+        };
+        let second_alpha_threshold = args
+            .get(4)
+            .unwrap_or(&1.into())
+            .coerce_to_u32(activation)?
+            .min(255) as u8;
+
+        let second_bitmap = second_object.as_bitmap_data().or_else(|| {
+            second_object
+                .as_display_object()
+                .and_then(|dobj| dobj.as_bitmap())
+                .and_then(|bitmap| bitmap.bitmap_data())
+        });
+
+        if let Some(second_bitmap) = second_bitmap {
+            check_not_disposed(second_bitmap)?;
+
+            let (second_x, second_y) = second_point.ok_or(
+                "TypeError: Error #2007: Parameter secondBitmapDataPoint must be non-null.",
+            )?;
+            let test_point = (second_x - first_x, second_y - first_y);
+            let result = if GcCell::ptr_eq(bitmap_data, second_bitmap) {
+                let read = bitmap_data.read();
+                read.hit_test_bitmap(
+                    first_alpha_threshold,
+                    &read,
+                    test_point,
+                    second_alpha_threshold,
+                )
+            } else {
+                bitmap_data.read().hit_test_bitmap(
+                    first_alpha_threshold,
+                    &second_bitmap.read(),
+                    test_point,
+                    second_alpha_threshold,
+                )
+            };
+
+            return Ok(result.into());
+        }
+
+        let rectangle_class = activation.avm2().classes().rectangle;
+        if second_object.is_of_type(rectangle_class, activation)? {
+            let (x, y, width, height) = get_rectangle(activation, second_object)?;
+            let result = bitmap_data.read().hit_test_rectangle(
+                first_alpha_threshold,
+                (x.saturating_sub(first_x), y.saturating_sub(first_y)),
+                (width, height),
+            );
+
+            return Ok(result.into());
+        }
+
+        let point_class = activation.avm2().classes().point;
+        if second_object.is_of_type(point_class, activation)? {
+            let (x, y) = get_point(activation, second_object)?;
+            let result = bitmap_data
+                .read()
+                .hit_test_point(first_alpha_threshold, (x - first_x, y - first_y));
+
+            return Ok(result.into());
+        }
+
+        return Err("TypeError: Error #1034: Type Coercion failed: secondObject must be a Point, Rectangle, Bitmap or BitmapData.".into());
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `BitmapData.applyFilter`.
+pub fn apply_filter<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+        let filter = match args.get(3) {
+            Some(Value::Object(filter)) => object_to_filter(*filter, activation)?,
+            _ => return Err("TypeError: Error #2007: Parameter filter must be non-null.".into()),
+        };
+        let filter = match filter {
+            Some(filter) => filter,
+            None => {
+                log::warn!("BitmapData.applyFilter - unsupported filter");
+                return Ok(Value::Undefined);
+            }
+        };
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            let source_bitmap_clone: BitmapData;
+            let source_bitmap_gc_ref;
+            let source_bitmap_ref = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                source_bitmap_clone = source_bitmap.read().clone();
+                &source_bitmap_clone
+            } else {
+                source_bitmap_gc_ref = source_bitmap.read();
+                &source_bitmap_gc_ref
+            };
+
+            bitmap_data
+                .write(activation.context.gc_context)
+                .apply_filter(source_bitmap_ref, source_rect, dest_point, &filter);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.generateFilterRect`.
+pub fn generate_filter_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_rect = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let filter = match args.get(1) {
+            Some(Value::Object(filter)) => object_to_filter(*filter, activation)?,
+            _ => return Err("TypeError: Error #2007: Parameter filter must be non-null.".into()),
+        };
+
+        let (x, y, width, height) = match filter {
+            Some(filter) => BitmapData::generate_filter_rect(source_rect, &filter),
+            None => source_rect,
+        };
+        return create_rectangle(
+            activation,
+            (x as f64, y as f64, width as f64, height as f64),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `BitmapData.pixelDissolve`.
+pub fn pixel_dissolve<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let source_rect = get_rectangle(activation, source_rect)?;
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let dest_point = get_point(activation, dest_point)?;
+        let random_seed = args.get(3).unwrap_or(&0.into()).coerce_to_i32(activation)?;
+        let num_pixels = match args.get(4) {
+            Some(num_pixels) => num_pixels.coerce_to_i32(activation)?,
+            // By default, a thirtieth of the rectangle is dissolved.
+            None => source_rect.2.saturating_mul(source_rect.3) / 30,
+        };
+        let fill_color = args.get(5).unwrap_or(&0.into()).coerce_to_u32(activation)?;
+
+        if let Some(source_bitmap) = source_bitmap.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            let seed = if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .pixel_dissolve(
+                        None,
+                        source_rect,
+                        dest_point,
+                        random_seed,
+                        num_pixels,
+                        fill_color,
+                    )
+            } else {
+                bitmap_data
+                    .write(activation.context.gc_context)
+                    .pixel_dissolve(
+                        Some(&source_bitmap.read()),
+                        source_rect,
+                        dest_point,
+                        random_seed,
+                        num_pixels,
+                        fill_color,
+                    )
+            };
+
+            return Ok(seed.into());
+        }
+    }
+
+    Ok(0.into())
+}
+
+/// Implements `BitmapData.draw`.
+pub fn draw<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        check_not_disposed(bitmap_data)?;
+        let source = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let source = if let Some(source_object) = source.as_display_object() {
+            let movie = activation.caller_movie();
+            if let Some(other) = activation
+                .context
+                .security
+                .find_inaccessible_movie(&movie, source_object)
+            {
+                return Err(format!(
+                    "SecurityError: Error #2122: Security sandbox violation: BitmapData.draw: {} cannot access {}.",
+                    movie.url().unwrap_or_default(),
+                    other.url().unwrap_or_default()
+                )
+                .into());
+            }
+            IBitmapDrawable::DisplayObject(source_object)
+        } else if let Some(source_bitmap) = source.as_bitmap_data() {
+            check_not_disposed(source_bitmap)?;
+
+            // Drawing a bitmap into itself must draw from a copy.
+            if GcCell::ptr_eq(source_bitmap, bitmap_data) {
+                let copy = source_bitmap.read().clone();
+                IBitmapDrawable::BitmapData(GcCell::allocate(activation.context.gc_context, copy))
+            } else {
+                IBitmapDrawable::BitmapData(source_bitmap)
+            }
+        } else {
+            return Err(
+                "TypeError: Error #1034: Type Coercion failed: source must be an IBitmapDrawable."
+                    .into(),
+            );
+        };
+
+        let matrix = match args.get(1) {
+            Some(Value::Object(matrix)) => object_to_matrix(*matrix, activation)?,
+            _ => Matrix::IDENTITY,
+        };
+
+        let color_transform = match args.get(2) {
+            Some(Value::Object(color_transform)) => {
+                object_to_color_transform(*color_transform, activation)?
+            }
+            _ => ColorTransform::default(),
+        };
+
+        // Unknown blend modes draw normally.
+        let blend_mode = match args.get(3) {
+            Some(Value::Undefined) | Some(Value::Null) | None => BlendMode::Normal,
+            Some(value) => value
+                .coerce_to_string(activation)?
+                .to_utf8_lossy()
+                .parse()
+                .unwrap_or_default(),
+        };
+
+        let clip_rect = match args.get(4) {
+            Some(Value::Object(clip_rect)) => Some(get_rectangle(activation, *clip_rect)?),
+            _ => None,
+        };
+
+        let smoothing = args
+            .get(5)
+            .unwrap_or(&Value::Bool(false))
+            .coerce_to_boolean();
+
+        let transform = Transform {
+            matrix,
+            color_transform,
+        };
+
+        let mc = activation.context.gc_context;
+        bitmap_data.write(mc).draw(
+            source,
+            transform,
+            smoothing,
+            blend_mode,
+            clip_rect,
+            &mut activation.context,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Raise an error if a `BitmapData` has already been disposed.
+fn check_not_disposed(bitmap_data: GcCell<'_, BitmapData<'_>>) -> Result<(), Error> {
+    if bitmap_data.read().disposed() {
+        return Err("ArgumentError: Error #2015: Invalid BitmapData.".into());
+    }
+
+    Ok(())
+}
+
+/// Read a numeric public property off of an object.
+fn get_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<f64, Error> {
+    object
+        .get_property(&QName::new(Namespace::public(), name).into(), activation)?
+        .coerce_to_number(activation)
+}
+
+/// Read the coordinates of a `Point`, truncated to whole pixels.
+fn get_point<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    point: Object<'gc>,
+) -> Result<(i32, i32), Error> {
+    let x = get_number(activation, point, "x")?;
+    let y = get_number(activation, point, "y")?;
+
+    Ok((x as i32, y as i32))
+}
+
+/// Read the `x`, `y`, `width` and `height` of a `Rectangle`, truncated to
+/// whole pixels.
+///
+/// Out of range values saturate, so the far edges of the rectangle may not
+/// fit in an `i32`; use `clip_rectangle` rather than adding them up.
+fn get_rectangle<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    rectangle: Object<'gc>,
+) -> Result<(i32, i32, i32, i32), Error> {
+    let x = get_number(activation, rectangle, "x")?;
+    let y = get_number(activation, rectangle, "y")?;
+    let width = get_number(activation, rectangle, "width")?;
+    let height = get_number(activation, rectangle, "height")?;
+
+    Ok((x as i32, y as i32, width as i32, height as i32))
+}
+
+/// Clip a rectangle read by `get_rectangle` to the bounds of a bitmap of the
+/// given size, returning `(min_x, min_y, end_x, end_y)`.
+fn clip_rectangle(
+    (x, y, width, height): (i32, i32, i32, i32),
+    bitmap_width: u32,
+    bitmap_height: u32,
+) -> (u32, u32, u32, u32) {
+    let clip = |min: i32, size: i32, max: u32| {
+        let max = i64::from(max);
+        let start = i64::from(min).clamp(0, max);
+        let end = (i64::from(min) + i64::from(size)).clamp(start, max);
+        (start as u32, end as u32)
+    };
+    let (min_x, end_x) = clip(x, width, bitmap_width);
+    let (min_y, end_y) = clip(y, height, bitmap_height);
+    (min_x, min_y, end_x, end_y)
+}
+
+/// Construct `BitmapData`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.display"), "BitmapData"),
+        Some(QName::new(Namespace::package(""), "Object").into()),
+        Method::from_builtin(instance_init, "<BitmapData instance initializer>", mc),
+        Method::from_builtin(class_init, "<BitmapData class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+    write.set_instance_allocator(bitmapdata_allocator);
+
+    write.implements(QName::new(Namespace::package("flash.display"), "IBitmapDrawable").into());
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("width", Some(width), None),
+        ("height", Some(height), None),
+        ("transparent", Some(transparent), None),
+        ("rect", Some(rect), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("getPixel", get_pixel),
+        ("getPixel32", get_pixel32),
+        ("setPixel", set_pixel),
+        ("setPixel32", set_pixel32),
+        ("getPixels", get_pixels),
+        ("setPixels", set_pixels),
+        ("copyPixels", copy_pixels),
+        ("copyChannel", copy_channel),
+        ("fillRect", fill_rect),
+        ("floodFill", flood_fill),
+        ("noise", noise),
+        ("perlinNoise", perlin_noise),
+        ("paletteMap", palette_map),
+        ("merge", merge),
+        ("scroll", scroll),
+        ("compare", compare),
+        ("lock", lock),
+        ("unlock", unlock),
+        ("clone", clone),
+        ("dispose", dispose),
+        ("colorTransform", color_transform),
+        ("getColorBoundsRect", get_color_bounds_rect),
+        ("threshold", threshold),
+        ("hitTest", hit_test),
+        ("draw", draw),
+        ("applyFilter", apply_filter),
+        ("generateFilterRect", generate_filter_rect),
+        ("pixelDissolve", pixel_dissolve),
+    ];
     write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
 
     class
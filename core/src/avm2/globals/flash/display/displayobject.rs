@@ -2,16 +2,23 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
+use crate::avm2::globals::flash::filters::{filter_to_object, object_to_filter};
+use crate::avm2::globals::flash::geom::color_transform::object_to_color_transform;
+use crate::avm2::globals::flash::geom::matrix::object_to_matrix;
+use crate::avm2::globals::flash::geom::point::create_point;
+use crate::avm2::globals::flash::geom::rectangle::create_rectangle;
 use crate::avm2::method::{Method, NativeMethodImpl};
-use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{stage_allocator, LoaderInfoObject, Object, TObject};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{stage_allocator, ArrayObject, LoaderInfoObject, Object, TObject};
 use crate::avm2::value::Value;
-use crate::avm2::Error;
+use crate::avm2::{ArrayStorage, Error};
+use crate::bounding_box::BoundingBox;
 use crate::display_object::{DisplayObject, HitTestOptions, TDisplayObject};
+use crate::string::AvmString;
 use crate::types::{Degrees, Percent};
 use crate::vminterface::Instantiator;
 use gc_arena::{GcCell, MutationContext};
-use swf::Twips;
+use swf::{BlendMode, Color, Twips};
 
 /// Implements `flash.display.DisplayObject`'s instance constructor.
 pub fn instance_init<'gc>(
@@ -576,6 +583,461 @@ pub fn loader_info<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Read a numeric property of an object.
+fn get_number<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<f64, Error> {
+    object
+        .get_property(&Multiname::public(name), activation)?
+        .coerce_to_number(activation)
+}
+
+/// Read the coordinates of a `Point` argument.
+fn point_arg<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+) -> Result<(Twips, Twips), Error> {
+    let point = match args.get(0) {
+        Some(Value::Object(point)) => *point,
+        _ => return Err("TypeError: Error #2007: Parameter point must be non-null.".into()),
+    };
+
+    let x = get_number(activation, point, "x")?;
+    let y = get_number(activation, point, "y")?;
+
+    Ok((Twips::from_pixels(x), Twips::from_pixels(y)))
+}
+
+/// Implements `localToGlobal`.
+pub fn local_to_global<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let local = point_arg(activation, args)?;
+        let (x, y) = dobj.local_to_global(local);
+
+        return create_point(activation, (x.to_pixels(), y.to_pixels()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `globalToLocal`.
+pub fn global_to_local<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let global = point_arg(activation, args)?;
+        let (x, y) = dobj.global_to_local(global);
+
+        return create_point(activation, (x.to_pixels(), y.to_pixels()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `getBounds`.
+pub fn get_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let target = match args.get(0) {
+            Some(Value::Object(target)) => target.as_display_object().unwrap_or(dobj),
+            _ => dobj,
+        };
+
+        let bounds = dobj.bounds();
+        let out_bounds = if DisplayObject::ptr_eq(dobj, target) {
+            // Getting the object's bounds in its own coordinate space; no AABB transform needed.
+            bounds
+        } else {
+            // Transform AABB to target space.
+            // Note that this doesn't produce as tight of an AABB as if we had used `bounds_with_transform` with
+            // the final matrix, but this matches Flash's behavior.
+            let to_global_matrix = dobj.local_to_global_matrix();
+            let to_target_matrix = target.global_to_local_matrix();
+            bounds.transform(&(to_target_matrix * to_global_matrix))
+        };
+
+        return create_rectangle(
+            activation,
+            (
+                out_bounds.x_min.to_pixels(),
+                out_bounds.y_min.to_pixels(),
+                out_bounds.width().to_pixels(),
+                out_bounds.height().to_pixels(),
+            ),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `getRect`.
+///
+/// TODO: This should get the bounds ignoring strokes. Always equal to or smaller than `getBounds`.
+pub fn get_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    get_bounds(activation, this, args)
+}
+
+/// Implements `transform`'s getter.
+pub fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let transform_class = activation.avm2().classes().transform;
+        return Ok(transform_class
+            .construct(activation, &[this.into()])?
+            .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `transform`'s setter.
+pub fn set_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let transform = match args.get(0) {
+            Some(Value::Object(transform)) => *transform,
+            _ => {
+                return Err("TypeError: Error #2007: Parameter transform must be non-null.".into())
+            }
+        };
+
+        let matrix = transform
+            .get_property(&Multiname::public("matrix"), activation)?
+            .coerce_to_object(activation)?;
+        let matrix = object_to_matrix(matrix, activation)?;
+        let color_transform = transform
+            .get_property(&Multiname::public("colorTransform"), activation)?
+            .coerce_to_object(activation)?;
+        let color_transform = object_to_color_transform(color_transform, activation)?;
+
+        dobj.set_matrix(activation.context.gc_context, &matrix);
+        dobj.set_color_transform(activation.context.gc_context, &color_transform);
+        dobj.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `mask`'s getter.
+pub fn mask<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj.masker().map_or(Value::Null, |m| m.object2()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `mask`'s setter.
+pub fn set_mask<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mask = match args.get(0) {
+            Some(Value::Object(mask)) => mask.as_display_object(),
+            _ => None,
+        };
+
+        let context = &mut activation.context;
+        dobj.set_clip_depth(context.gc_context, 0);
+        dobj.set_masker(context.gc_context, mask, true);
+        if let Some(m) = mask {
+            m.set_maskee(context.gc_context, Some(dobj), true);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `scrollRect`'s getter.
+pub fn scroll_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        if let Some(rect) = dobj.scroll_rect() {
+            return create_rectangle(
+                activation,
+                (
+                    rect.x_min.to_pixels(),
+                    rect.y_min.to_pixels(),
+                    rect.width().to_pixels(),
+                    rect.height().to_pixels(),
+                ),
+            );
+        }
+
+        return Ok(Value::Null);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `scrollRect`'s setter.
+pub fn set_scroll_rect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let scroll_rect = match args.get(0) {
+            Some(Value::Object(rect)) => {
+                let x = get_number(activation, *rect, "x")?;
+                let y = get_number(activation, *rect, "y")?;
+                let width = get_number(activation, *rect, "width")?;
+                let height = get_number(activation, *rect, "height")?;
+
+                Some(BoundingBox {
+                    x_min: Twips::from_pixels(x),
+                    y_min: Twips::from_pixels(y),
+                    x_max: Twips::from_pixels(x + width),
+                    y_max: Twips::from_pixels(y + height),
+                    valid: true,
+                })
+            }
+            _ => None,
+        };
+
+        dobj.set_scroll_rect(activation.context.gc_context, scroll_rect);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `scale9Grid`'s getter.
+pub fn scale_nine_grid<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        if let Some(grid) = dobj.scaling_grid() {
+            return create_rectangle(
+                activation,
+                (
+                    grid.x_min.to_pixels(),
+                    grid.y_min.to_pixels(),
+                    grid.width().to_pixels(),
+                    grid.height().to_pixels(),
+                ),
+            );
+        }
+
+        return Ok(Value::Null);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `scale9Grid`'s setter.
+pub fn set_scale_nine_grid<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let grid = match args.get(0) {
+            Some(Value::Object(rect)) => {
+                let x = get_number(activation, *rect, "x")?;
+                let y = get_number(activation, *rect, "y")?;
+                let width = get_number(activation, *rect, "width")?;
+                let height = get_number(activation, *rect, "height")?;
+
+                Some(BoundingBox {
+                    x_min: Twips::from_pixels(x),
+                    y_min: Twips::from_pixels(y),
+                    x_max: Twips::from_pixels(x + width),
+                    y_max: Twips::from_pixels(y + height),
+                    valid: true,
+                })
+            }
+            _ => None,
+        };
+
+        dobj.set_scaling_grid(activation.context.gc_context, grid);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `blendMode`'s getter.
+pub fn blend_mode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mode = dobj.blend_mode().to_string();
+        return Ok(AvmString::new_utf8(activation.context.gc_context, mode).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `blendMode`'s setter.
+pub fn set_blend_mode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mode = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        let mode: BlendMode = mode.to_utf8_lossy().parse().map_err(|_| {
+            "ArgumentError: Error #2008: Parameter blendMode must be one of the accepted values."
+        })?;
+        dobj.set_blend_mode(activation.context.gc_context, mode);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `cacheAsBitmap`'s getter.
+pub fn cache_as_bitmap<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj.is_bitmap_cached().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `cacheAsBitmap`'s setter.
+pub fn set_cache_as_bitmap<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let cache = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_boolean();
+        dobj.set_bitmap_cached(activation.context.gc_context, cache);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `opaqueBackground`'s getter.
+pub fn opaque_background<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(match dobj.opaque_background() {
+            Some(color) => {
+                (((color.r as u32) << 16) | ((color.g as u32) << 8) | color.b as u32).into()
+            }
+            None => Value::Null,
+        });
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `opaqueBackground`'s setter.
+pub fn set_opaque_background<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let color = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Undefined | Value::Null => None,
+            value => Some(Color::from_rgb(value.coerce_to_u32(activation)?, 255)),
+        };
+        dobj.set_opaque_background(activation.context.gc_context, color);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `filters`'s getter.
+///
+/// A new array of new filter objects is created every time, so changes made
+/// to them only apply once they are assigned back to `filters`.
+pub fn filters<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let mut filters = Vec::new();
+        for filter in dobj.filters() {
+            filters.push(filter_to_object(activation, &filter)?.into());
+        }
+
+        return Ok(
+            ArrayObject::from_storage(activation, ArrayStorage::from_args(&filters))?.into(),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `filters`'s setter.
+pub fn set_filters<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let storage = match args.get(0) {
+            Some(Value::Object(filters)) => filters.as_array_storage().map(|s| s.clone()),
+            _ => None,
+        };
+
+        let mut swf_filters = Vec::new();
+        if let Some(storage) = &storage {
+            for filter in storage.iter().flatten() {
+                if let Value::Object(filter) = filter {
+                    swf_filters.extend(object_to_filter(filter, activation)?);
+                }
+            }
+        }
+        dobj.set_filters(activation.context.gc_context, swf_filters);
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `DisplayObject`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -618,12 +1080,36 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("mouseX", Some(mouse_x), None),
         ("mouseY", Some(mouse_y), None),
         ("loaderInfo", Some(loader_info), None),
+        ("transform", Some(transform), Some(set_transform)),
+        ("mask", Some(mask), Some(set_mask)),
+        ("scrollRect", Some(scroll_rect), Some(set_scroll_rect)),
+        (
+            "scale9Grid",
+            Some(scale_nine_grid),
+            Some(set_scale_nine_grid),
+        ),
+        ("blendMode", Some(blend_mode), Some(set_blend_mode)),
+        (
+            "cacheAsBitmap",
+            Some(cache_as_bitmap),
+            Some(set_cache_as_bitmap),
+        ),
+        (
+            "opaqueBackground",
+            Some(opaque_background),
+            Some(set_opaque_background),
+        ),
+        ("filters", Some(filters), Some(set_filters)),
     ];
     write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
 
     const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
         ("hitTestPoint", hit_test_point),
         ("hitTestObject", hit_test_object),
+        ("localToGlobal", local_to_global),
+        ("globalToLocal", global_to_local),
+        ("getBounds", get_bounds),
+        ("getRect", get_rect),
     ];
     write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
 
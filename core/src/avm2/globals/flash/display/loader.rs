@@ -2,11 +2,20 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
-use crate::avm2::method::Method;
-use crate::avm2::names::{Namespace, QName};
+use crate::avm2::domain::Domain;
+use crate::avm2::events::{dispatch_event, Event, EventData};
+use crate::avm2::globals::flash::net::url_request::request_into_url_and_options;
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{EventObject, LoaderInfoObject, LoaderStream, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Object};
+use crate::display_object::{DisplayObject, MovieClip, TDisplayObject, TDisplayObjectContainer};
+use crate::loader::Avm2LoaderSource;
+use crate::tag_utils::SwfMovie;
 use gc_arena::{GcCell, MutationContext};
+use std::sync::Arc;
 
 /// Implements `flash.display.Loader`'s class constructor.
 pub fn class_init<'gc>(
@@ -19,10 +28,263 @@ pub fn class_init<'gc>(
 
 /// Implements `flash.display.Loader`'s instance constructor.
 pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        if this.as_display_object().is_none() {
+            let class_object = this
+                .instance_of()
+                .ok_or("Attempted to construct Loader on a bare object")?;
+            let movie = Arc::new(SwfMovie::empty(activation.context.swf.version()));
+            let new_do =
+                MovieClip::new_with_avm2(movie, this, class_object, activation.context.gc_context);
+
+            this.init_display_object(activation.context.gc_context, new_do.into());
+        }
+
+        let content_loader_info = LoaderInfoObject::not_yet_loaded(activation, this)?;
+        this.set_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "contentLoaderInfo").into(),
+            content_loader_info.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Retrieve the `LoaderInfo` that describes a `Loader`'s content.
+fn content_loader_info_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    this.get_property(
+        &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "contentLoaderInfo").into(),
+        activation,
+    )?
+    .coerce_to_object(activation)
+}
+
+/// Retrieve the content display object currently held by a `Loader`.
+fn loaded_content<'gc>(this: Object<'gc>) -> Option<DisplayObject<'gc>> {
+    this.as_display_object()
+        .and_then(|dobj| dobj.as_container())
+        .and_then(|container| container.child_by_index(0))
+}
+
+/// Implements `Loader.content`
+pub fn content<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(content) = this.and_then(loaded_content) {
+        return Ok(content.object2());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `Loader.contentLoaderInfo`
+pub fn content_loader_info<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(content_loader_info_object(activation, this)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Determine the application domain that a `Loader` should load code into.
+///
+/// Content shares the domain named by the given `LoaderContext`, if any, and
+/// otherwise gets a new child domain of the caller's domain.
+fn loader_context_domain<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    context: Option<&Value<'gc>>,
+) -> Result<Domain<'gc>, Error> {
+    if let Some(Value::Object(context)) = context {
+        let domain = context
+            .get_property(&Multiname::public("applicationDomain"), activation)?
+            .as_object()
+            .and_then(|domain| domain.as_application_domain());
+
+        if let Some(domain) = domain {
+            return Ok(domain);
+        }
+    }
+
+    let parent_domain = activation.caller_domain();
+    Ok(Domain::movie_domain(activation, parent_domain))
+}
+
+/// Cancel any pending load and start loading new content into a `Loader`.
+fn spawn_load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    context: Option<&Value<'gc>>,
+    source: Avm2LoaderSource,
+) -> Result<(), Error> {
+    let domain = loader_context_domain(activation, context)?;
+    let content_loader_info = content_loader_info_object(activation, this)?;
+
+    activation.context.load_manager.cancel_avm2_loader(this);
+
+    let future = activation.context.load_manager.load_movie_into_avm2_loader(
+        activation.context.player.clone().unwrap(),
+        activation.caller_movie(),
+        this,
+        content_loader_info,
+        domain,
+        source,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(())
+}
+
+/// Implements `Loader.load`
+pub fn load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let request = match args.get(0) {
+            Some(Value::Object(request)) => *request,
+            _ => return Err("TypeError: Error #2007: Parameter request must be non-null.".into()),
+        };
+
+        let (url, options) = request_into_url_and_options(activation, request)?;
+        spawn_load(
+            activation,
+            this,
+            args.get(1),
+            Avm2LoaderSource::Url(url, options),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.loadBytes`
+pub fn load_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let bytes = match args.get(0) {
+            Some(Value::Object(bytes)) => bytes
+                .as_bytearray()
+                .map(|bytes| bytes.bytes().to_vec())
+                .ok_or("TypeError: Error #1034: Parameter bytes must be a ByteArray.")?,
+            _ => return Err("TypeError: Error #2007: Parameter bytes must be non-null.".into()),
+        };
+
+        spawn_load(
+            activation,
+            this,
+            args.get(1),
+            Avm2LoaderSource::Bytes(bytes),
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Remove a `Loader`'s content, optionally stopping it first.
+fn unload_content<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    stop: bool,
+) -> Result<(), Error> {
+    let content = match loaded_content(this) {
+        Some(content) => content,
+        None => return Ok(()),
+    };
+
+    if stop {
+        stop_recursive(activation, content);
+    }
+
+    if let Some(mut container) = this
+        .as_display_object()
+        .and_then(|dobj| dobj.as_container())
+    {
+        container.clear(&mut activation.context);
+    }
+    content.unload(&mut activation.context);
+
+    let content_loader_info = content_loader_info_object(activation, this)?;
+    if let Some(loader_info) = content_loader_info.as_loader_info_object() {
+        loader_info.set_loader_stream(activation.context.gc_context, LoaderStream::NotYetLoaded);
+    }
+
+    let unload_evt = EventObject::from_event(activation, Event::new("unload", EventData::Empty))?;
+    dispatch_event(activation, content_loader_info, unload_evt)?;
+
+    Ok(())
+}
+
+/// Stop the playback and sounds of a display object and all of its children.
+fn stop_recursive<'gc>(activation: &mut Activation<'_, 'gc, '_>, dobj: DisplayObject<'gc>) {
+    if let Some(mc) = dobj.as_movie_clip() {
+        mc.stop(&mut activation.context);
+    }
+
+    activation.context.stop_sounds_with_display_object(dobj);
+
+    if let Some(container) = dobj.as_container() {
+        for child in container.iter_render_list() {
+            stop_recursive(activation, child);
+        }
+    }
+}
+
+/// Implements `Loader.unload`
+pub fn unload<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        unload_content(activation, this, false)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.unloadAndStop`
+pub fn unload_and_stop<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        unload_content(activation, this, true)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Loader.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.context.load_manager.cancel_avm2_loader(this);
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -41,5 +303,33 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         mc,
     );
 
+    let mut write = class.write(mc);
+
+    write.define_private_slot_instance_traits(&[(
+        NS_RUFFLE_INTERNAL,
+        "contentLoaderInfo",
+        "flash.display",
+        "LoaderInfo",
+    )]);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("content", Some(content), None),
+        ("contentLoaderInfo", Some(content_loader_info), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("close", close),
+        ("load", load),
+        ("loadBytes", load_bytes),
+        ("unload", unload),
+        ("unloadAndStop", unload_and_stop),
+    ];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
     class
 }
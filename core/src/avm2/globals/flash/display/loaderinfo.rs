@@ -12,6 +12,10 @@ use crate::display_object::TDisplayObject;
 use gc_arena::{GcCell, MutationContext};
 use swf::{write_swf, Compression};
 
+/// The error thrown when reading properties of content that hasn't loaded.
+const NOT_YET_LOADED: &str =
+    "Error #2099: The loading object is not sufficiently loaded to provide this information.";
+
 /// Implements `flash.display.LoaderInfo`'s instance constructor.
 pub fn instance_init<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -55,6 +59,7 @@ pub fn action_script_version<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have an AS version".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(movie, _) => {
                     let library = activation
                         .context
@@ -81,6 +86,7 @@ pub fn application_domain<'gc>(
                 LoaderStream::Stage => {
                     return Ok(DomainObject::from_domain(activation, activation.domain())?.into());
                 }
+                LoaderStream::NotYetLoaded => return Ok(Value::Null),
                 LoaderStream::Swf(movie, _) => {
                     let domain = activation
                         .context
@@ -109,6 +115,7 @@ pub fn bytes_total<'gc>(
         if let Some(loader_stream) = this.as_loader_stream() {
             match &*loader_stream {
                 LoaderStream::Stage => return Ok(activation.context.swf.compressed_len().into()),
+                LoaderStream::NotYetLoaded => return Ok(0.into()),
                 LoaderStream::Swf(movie, _) => {
                     return Ok(movie.compressed_len().into());
                 }
@@ -129,6 +136,7 @@ pub fn content<'gc>(
         if let Some(loader_stream) = this.as_loader_stream() {
             match &*loader_stream {
                 LoaderStream::Stage => return Ok(activation.context.stage.root_clip().object2()),
+                LoaderStream::NotYetLoaded => return Ok(Value::Null),
                 LoaderStream::Swf(_, root) => {
                     return Ok(root.object2());
                 }
@@ -149,6 +157,7 @@ pub fn content_type<'gc>(
         if let Some(loader_stream) = this.as_loader_stream() {
             match &*loader_stream {
                 LoaderStream::Stage => return Ok(Value::Null),
+                LoaderStream::NotYetLoaded => return Ok(Value::Null),
                 LoaderStream::Swf(_, _) => {
                     return Ok("application/x-shockwave-flash".into());
                 }
@@ -171,6 +180,7 @@ pub fn frame_rate<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have a frame rate".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(root, _) => {
                     return Ok(root.frame_rate().to_f64().into());
                 }
@@ -193,6 +203,7 @@ pub fn height<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have a height".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(root, _) => {
                     return Ok(root.height().to_pixels().into());
                 }
@@ -224,6 +235,7 @@ pub fn swf_version<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have a SWF version".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(root, _) => {
                     return Ok(root.version().into());
                 }
@@ -245,6 +257,7 @@ pub fn url<'gc>(
             let root = match &*loader_stream {
                 LoaderStream::Stage => activation.context.swf,
                 LoaderStream::Swf(root, _) => root,
+                LoaderStream::NotYetLoaded => return Ok(Value::Null),
             };
 
             let url = root.url().unwrap_or("");
@@ -267,6 +280,7 @@ pub fn width<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have a width".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(root, _) => {
                     return Ok(root.width().to_pixels().into());
                 }
@@ -288,6 +302,7 @@ pub fn bytes<'gc>(
             let root = match &*loader_stream {
                 LoaderStream::Stage => activation.context.swf,
                 LoaderStream::Swf(root, _) => root,
+                LoaderStream::NotYetLoaded => return Ok(Value::Null),
             };
 
             let ba_class = activation.context.avm2.classes().bytearray;
@@ -339,6 +354,7 @@ pub fn loader_url<'gc>(
                 LoaderStream::Stage => {
                     return Err("Error: The stage's loader info does not have a loader URL".into())
                 }
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
                 LoaderStream::Swf(root, _) => {
                     let loader_url = root.loader_url().or_else(|| root.url()).unwrap_or("");
                     return Ok(
@@ -363,6 +379,7 @@ pub fn parameters<'gc>(
             let root = match &*loader_stream {
                 LoaderStream::Stage => activation.context.swf,
                 LoaderStream::Swf(root, _) => root,
+                LoaderStream::NotYetLoaded => return Err(NOT_YET_LOADED.into()),
             };
 
             let mut params_obj = activation
@@ -389,6 +406,19 @@ pub fn parameters<'gc>(
     Ok(Value::Undefined)
 }
 
+/// `loader` getter
+pub fn loader<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(loader_info) = this.and_then(|this| this.as_loader_info_object()) {
+        return Ok(loader_info.loader().map_or(Value::Null, Value::Object));
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `LoaderInfo`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -427,6 +457,7 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("url", Some(url), None),
         ("width", Some(width), None),
         ("bytes", Some(bytes), None),
+        ("loader", Some(loader), None),
         ("loaderURL", Some(loader_url), None),
         ("parameters", Some(parameters), None),
     ];
@@ -2,12 +2,18 @@
 
 pub mod activityevent;
 pub mod contextmenuevent;
+pub mod dataevent;
 pub mod event;
 pub mod eventdispatcher;
 pub mod eventphase;
 pub mod fullscreenevent;
+pub mod httpstatusevent;
 pub mod ieventdispatcher;
 pub mod ioerrorevent;
 pub mod keyboardevent;
 pub mod mouseevent;
+pub mod netstatusevent;
 pub mod progressevent;
+pub mod securityerrorevent;
+pub mod statusevent;
+pub mod timerevent;
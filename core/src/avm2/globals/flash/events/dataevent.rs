@@ -0,0 +1,110 @@
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::events::EventData;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.events.DataEvent`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, args)?; // Event uses the first three parameters
+
+        let data = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| "".into())
+            .coerce_to_string(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            evt.set_event_data(EventData::Data { data });
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.DataEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `data`'s getter.
+pub fn data<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::Data { data } = evt.event_data() {
+                return Ok((*data).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `data`'s setter.
+pub fn set_data<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            evt.set_event_data(EventData::Data { data: value });
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `DataEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    // TODO: This should extend `TextEvent`, which we don't have yet.
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "DataEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init, "<DataEvent instance initializer>", mc),
+        Method::from_builtin(class_init, "<DataEvent class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("data", Some(data), Some(set_data))];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const CONSTANTS: &[(&str, &str)] = &[
+        ("DATA", "data"),
+        ("UPLOAD_COMPLETE_DATA", "uploadCompleteData"),
+    ];
+
+    write.define_public_constant_string_class_traits(CONSTANTS);
+
+    class
+}
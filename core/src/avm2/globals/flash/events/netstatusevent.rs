@@ -0,0 +1,98 @@
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::events::EventData;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.events.NetStatusEvent`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, args)?; // Event uses the first three parameters
+
+        if let Some(Value::Object(info)) = args.get(3) {
+            if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+                evt.set_event_data(EventData::NetStatus { info: *info });
+            }
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.NetStatusEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `info`'s getter.
+pub fn info<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::NetStatus { info } = evt.event_data() {
+                return Ok((*info).into());
+            }
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `info`'s setter.
+pub fn set_info<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(Value::Object(info)) = args.get(0) {
+            if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+                evt.set_event_data(EventData::NetStatus { info: *info });
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `NetStatusEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "NetStatusEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init, "<NetStatusEvent instance initializer>", mc),
+        Method::from_builtin(class_init, "<NetStatusEvent class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("info", Some(info), Some(set_info))];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const CONSTANTS: &[(&str, &str)] = &[("NET_STATUS", "netStatus")];
+
+    write.define_public_constant_string_class_traits(CONSTANTS);
+
+    class
+}
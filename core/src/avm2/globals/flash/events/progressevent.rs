@@ -1,8 +1,9 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
-use crate::avm2::method::Method;
+use crate::avm2::events::EventData;
+use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::Object;
+use crate::avm2::object::{Object, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::{GcCell, MutationContext};
@@ -15,6 +16,24 @@ pub fn instance_init<'gc>(
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this {
         activation.super_init(this, args)?; // Event uses the first three parameters
+
+        let bytes_loaded = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_number(activation)?;
+        let bytes_total = args
+            .get(4)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_number(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            evt.set_event_data(EventData::Progress {
+                bytes_loaded: bytes_loaded as u64,
+                bytes_total: bytes_total as u64,
+            });
+        }
     }
     Ok(Value::Undefined)
 }
@@ -28,6 +47,86 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `bytesLoaded`'s getter.
+pub fn bytes_loaded<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::Progress { bytes_loaded, .. } = evt.event_data() {
+                return Ok((*bytes_loaded as f64).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `bytesLoaded`'s setter.
+pub fn set_bytes_loaded<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            if let EventData::Progress { bytes_loaded, .. } = evt.event_data_mut() {
+                *bytes_loaded = value as u64;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `bytesTotal`'s getter.
+pub fn bytes_total<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::Progress { bytes_total, .. } = evt.event_data() {
+                return Ok((*bytes_total as f64).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `bytesTotal`'s setter.
+pub fn set_bytes_total<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            if let EventData::Progress { bytes_total, .. } = evt.event_data_mut() {
+                *bytes_total = value as u64;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `ProgressEvent`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -42,6 +141,16 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
 
     write.set_attributes(ClassAttributes::SEALED);
 
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("bytesLoaded", Some(bytes_loaded), Some(set_bytes_loaded)),
+        ("bytesTotal", Some(bytes_total), Some(set_bytes_total)),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
     const CONSTANTS: &[(&str, &str)] = &[
         ("PROGRESS", "progress"),
         ("SOCKET_DATA", "socketData"),
@@ -0,0 +1,138 @@
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::events::EventData;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.events.SecurityErrorEvent`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, args)?; // ErrorEvent, Event use these
+
+        let text = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| "".into())
+            .coerce_to_string(activation)?;
+        let error_id = args
+            .get(4)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_i32(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            evt.set_event_data(EventData::SecurityError { text, error_id });
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.SecurityErrorEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `text`'s getter.
+pub fn text<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::SecurityError { text, .. } = evt.event_data() {
+                return Ok((*text).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `text`'s setter.
+pub fn set_text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            if let EventData::SecurityError { text, .. } = evt.event_data_mut() {
+                *text = value;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `errorID`'s getter.
+pub fn error_id<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::SecurityError { error_id, .. } = evt.event_data() {
+                return Ok((*error_id).into());
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `SecurityErrorEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "SecurityErrorEvent"),
+        // TODO: this should derive SecurityErrorEvent -> ErrorEvent -> TextEvent -> Event
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(
+            instance_init,
+            "<SecurityErrorEvent instance initializer>",
+            mc,
+        ),
+        Method::from_builtin(class_init, "<SecurityErrorEvent class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("text", Some(text), Some(set_text)),
+        ("errorID", Some(error_id), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const CONSTANTS: &[(&str, &str)] = &[("SECURITY_ERROR", "securityError")];
+
+    write.define_public_constant_string_class_traits(CONSTANTS);
+
+    class
+}
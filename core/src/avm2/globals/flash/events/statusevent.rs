@@ -0,0 +1,157 @@
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::events::EventData;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.events.StatusEvent`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, args)?; // Event uses the first three parameters
+
+        let code = match args.get(3) {
+            None | Some(Value::Undefined) | Some(Value::Null) => None,
+            Some(code) => Some(code.coerce_to_string(activation)?),
+        };
+        let level = args
+            .get(4)
+            .cloned()
+            .unwrap_or_else(|| "".into())
+            .coerce_to_string(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            evt.set_event_data(EventData::Status { code, level });
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.events.StatusEvent`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `code`'s getter.
+pub fn code<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::Status {
+                code: Some(code), ..
+            } = evt.event_data()
+            {
+                return Ok((*code).into());
+            }
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `code`'s setter.
+pub fn set_code<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => None,
+            Some(value) => Some(value.coerce_to_string(activation)?),
+        };
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            if let EventData::Status { code, .. } = evt.event_data_mut() {
+                *code = value;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `level`'s getter.
+pub fn level<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(evt) = this.as_event() {
+            if let EventData::Status { level, .. } = evt.event_data() {
+                return Ok((*level).into());
+            }
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `level`'s setter.
+pub fn set_level<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        if let Some(mut evt) = this.as_event_mut(activation.context.gc_context) {
+            if let EventData::Status { level, .. } = evt.event_data_mut() {
+                *level = value;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `StatusEvent`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.events"), "StatusEvent"),
+        Some(QName::new(Namespace::package("flash.events"), "Event").into()),
+        Method::from_builtin(instance_init, "<StatusEvent instance initializer>", mc),
+        Method::from_builtin(class_init, "<StatusEvent class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("code", Some(code), Some(set_code)),
+        ("level", Some(level), Some(set_level)),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const CONSTANTS: &[(&str, &str)] = &[("STATUS", "status")];
+
+    write.define_public_constant_string_class_traits(CONSTANTS);
+
+    class
+}
@@ -26,11 +26,33 @@ fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Raise a `SecurityError` if the calling movie's sandbox doesn't allow it to
+/// use `ExternalInterface`.
+fn check_access(activation: &Activation<'_, '_, '_>) -> Result<(), Error> {
+    let movie = activation.caller_movie();
+    let root = activation.context.swf;
+    if activation
+        .context
+        .security
+        .can_use_external_interface(&movie, root)
+    {
+        return Ok(());
+    }
+
+    Err(format!(
+        "SecurityError: Error #2060: Security sandbox violation: ExternalInterface caller {} cannot access {}.",
+        movie.url().unwrap_or_default(),
+        root.url().unwrap_or_default()
+    )
+    .into())
+}
+
 pub fn call<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    check_access(activation)?;
     if args.is_empty() {
         return Ok(Value::Null);
     }
@@ -66,6 +88,7 @@ pub fn add_callback<'gc>(
     _this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    check_access(activation)?;
     if args.len() < 2 {
         return Ok(Value::Undefined);
     }
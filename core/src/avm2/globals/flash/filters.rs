@@ -0,0 +1,600 @@
+//! `flash.filters` namespace
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{ArrayObject, ClassObject, Object, TObject};
+use crate::avm2::traits::Trait;
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayStorage, Error};
+use swf::{Color, Filter, Fixed16, Fixed8, GradientRecord};
+
+pub mod bevelfilter;
+pub mod bitmapfilter;
+pub mod bitmapfilterquality;
+pub mod bitmapfiltertype;
+pub mod blurfilter;
+pub mod colormatrixfilter;
+pub mod convolutionfilter;
+pub mod displacementmapfilter;
+pub mod dropshadowfilter;
+pub mod glowfilter;
+pub mod gradientbevelfilter;
+pub mod gradientglowfilter;
+
+/// The default value of a filter property.
+///
+/// This also decides the type that constructor arguments for the property
+/// are coerced to.
+#[derive(Clone, Copy)]
+pub enum PropertyDefault {
+    Number(f64),
+    Int(i32),
+    Uint(u32),
+    Boolean(bool),
+    String(&'static str),
+
+    /// An instance of the class with the given package and name, which
+    /// defaults to `null`. Arrays are copied rather than shared.
+    Object(&'static str, &'static str),
+}
+
+/// The properties of a filter class, in the order of its constructor's
+/// parameters.
+pub type FilterProperties = &'static [(&'static str, PropertyDefault)];
+
+impl PropertyDefault {
+    fn type_name(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Number(_) => ("", "Number"),
+            Self::Int(_) => ("", "int"),
+            Self::Uint(_) => ("", "uint"),
+            Self::Boolean(_) => ("", "Boolean"),
+            Self::String(_) => ("", "String"),
+            Self::Object(package, name) => (package, name),
+        }
+    }
+
+    fn value<'gc>(self) -> Value<'gc> {
+        match self {
+            Self::Number(value) => value.into(),
+            Self::Int(value) => value.into(),
+            Self::Uint(value) => value.into(),
+            Self::Boolean(value) => value.into(),
+            Self::String(value) => value.into(),
+            Self::Object(..) => Value::Null,
+        }
+    }
+
+    fn coerce<'gc>(
+        self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        value: Value<'gc>,
+    ) -> Result<Value<'gc>, Error> {
+        Ok(match self {
+            Self::Number(_) => value.coerce_to_number(activation)?.into(),
+            Self::Int(_) => value.coerce_to_i32(activation)?.into(),
+            Self::Uint(_) => value.coerce_to_u32(activation)?.into(),
+            Self::Boolean(_) => value.coerce_to_boolean().into(),
+            Self::String(_) => value.coerce_to_string(activation)?.into(),
+            Self::Object(..) => {
+                let storage = value
+                    .as_object()
+                    .and_then(|array| array.as_array_storage().map(|storage| storage.clone()));
+                match (storage, value) {
+                    (Some(storage), _) => ArrayObject::from_storage(activation, storage)?.into(),
+                    (None, Value::Undefined) => Value::Null,
+                    (None, value) => value,
+                }
+            }
+        })
+    }
+}
+
+/// Define a public slot on a filter class for each of its properties.
+pub fn define_properties<'gc>(class: &mut Class<'gc>, properties: FilterProperties) {
+    for &(name, default) in properties {
+        let (type_package, type_name) = default.type_name();
+        class.define_instance_trait(Trait::from_slot(
+            QName::new(Namespace::public(), name),
+            QName::new(Namespace::package(type_package), type_name).into(),
+            None,
+        ));
+    }
+}
+
+/// Set the properties of a new filter object from its constructor's
+/// arguments, falling back to their defaults.
+pub fn init_properties<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+    args: &[Value<'gc>],
+    properties: FilterProperties,
+) -> Result<(), Error> {
+    for (i, &(name, default)) in properties.iter().enumerate() {
+        let value = match args.get(i) {
+            Some(value) => default.coerce(activation, *value)?,
+            None => default.value(),
+        };
+        this.set_property(&Multiname::public(name), value, activation)?;
+    }
+
+    Ok(())
+}
+
+/// Find the `flash.filters` class that an object is an instance of, along
+/// with that class's name and properties.
+///
+/// Instances of classes that extend a filter class are treated as instances
+/// of the filter class.
+fn filter_class<'gc>(
+    object: Object<'gc>,
+) -> Option<(ClassObject<'gc>, &'static str, FilterProperties)> {
+    let mut class = object.instance_of();
+    while let Some(class_object) = class {
+        let name = class_object.inner_class_definition().read().name();
+        if name.namespace().is_package("flash.filters") {
+            let local_name = name.local_name();
+            let local_name = local_name.to_utf8_lossy();
+            return FILTER_CLASSES
+                .iter()
+                .find(|(class_name, _)| *class_name == local_name)
+                .map(|&(class_name, properties)| (class_object, class_name, properties));
+        }
+        class = class_object.superclass_object();
+    }
+
+    None
+}
+
+/// Every class in `flash.filters` that filter objects can be instances of.
+const FILTER_CLASSES: &[(&str, FilterProperties)] = &[
+    ("BitmapFilter", &[]),
+    ("BevelFilter", bevelfilter::PROPERTIES),
+    ("BlurFilter", blurfilter::PROPERTIES),
+    ("ColorMatrixFilter", colormatrixfilter::PROPERTIES),
+    ("ConvolutionFilter", convolutionfilter::PROPERTIES),
+    ("DisplacementMapFilter", displacementmapfilter::PROPERTIES),
+    ("DropShadowFilter", dropshadowfilter::PROPERTIES),
+    ("GlowFilter", glowfilter::PROPERTIES),
+    ("GradientBevelFilter", gradientbevelfilter::PROPERTIES),
+    ("GradientGlowFilter", gradientglowfilter::PROPERTIES),
+];
+
+/// Create a copy of a filter object, as an instance of its `flash.filters`
+/// class.
+pub fn clone_filter<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Value<'gc>, Error> {
+    let (class, _, properties) = match filter_class(object) {
+        Some(class) => class,
+        None => return Ok(Value::Null),
+    };
+
+    let mut args = Vec::with_capacity(properties.len());
+    for &(name, _) in properties {
+        args.push(get(activation, object, name)?);
+    }
+
+    Ok(class.construct(activation, &args)?.into())
+}
+
+/// Read a public property of a filter object.
+fn get<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Value<'gc>, Error> {
+    object.get_property(&Multiname::public(name), activation)
+}
+
+fn get_f64<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<f64, Error> {
+    get(activation, object, name)?.coerce_to_number(activation)
+}
+
+fn get_fixed16<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Fixed16, Error> {
+    Ok(Fixed16::from_f64(get_f64(activation, object, name)?))
+}
+
+fn get_bool<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<bool, Error> {
+    Ok(get(activation, object, name)?.coerce_to_boolean())
+}
+
+/// Read an angle in degrees, converting it to radians.
+fn get_angle<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Fixed16, Error> {
+    Ok(Fixed16::from_f64(
+        get_f64(activation, object, "angle")?.to_radians(),
+    ))
+}
+
+fn get_strength<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Fixed8, Error> {
+    Ok(Fixed8::from_f64(get_f64(activation, object, "strength")?))
+}
+
+fn get_num_passes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<u8, Error> {
+    let quality = get(activation, object, "quality")?.coerce_to_i32(activation)?;
+    Ok(quality.clamp(0, 15) as u8)
+}
+
+/// Read a color and its alpha from two properties of a filter object.
+fn get_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    color: &'static str,
+    alpha: &'static str,
+) -> Result<Color, Error> {
+    let rgb = get(activation, object, color)?.coerce_to_u32(activation)?;
+    let alpha = get_f64(activation, object, alpha)?;
+    Ok(Color::from_rgb(
+        rgb,
+        (alpha.clamp(0.0, 1.0) * 255.0).round() as u8,
+    ))
+}
+
+/// Read the `type` of a bevel or gradient filter as `is_inner` and
+/// `is_on_top` flags.
+fn get_bevel_type<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<(bool, bool), Error> {
+    let type_ = get(activation, object, "type")?.coerce_to_string(activation)?;
+    Ok(if &type_ == b"inner" {
+        (true, false)
+    } else if &type_ == b"full" {
+        (false, true)
+    } else {
+        (false, false)
+    })
+}
+
+/// Read a public array property of a filter object as a list of values.
+fn get_array<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+    name: &'static str,
+) -> Result<Vec<Value<'gc>>, Error> {
+    Ok(match get(activation, object, name)? {
+        Value::Object(array) => match array.as_array_storage() {
+            Some(storage) => storage
+                .iter()
+                .map(|value| value.unwrap_or(Value::Undefined))
+                .collect(),
+            None => Vec::new(),
+        },
+        _ => Vec::new(),
+    })
+}
+
+fn get_gradient<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Vec<GradientRecord>, Error> {
+    let colors = get_array(activation, object, "colors")?;
+    let alphas = get_array(activation, object, "alphas")?;
+    let ratios = get_array(activation, object, "ratios")?;
+
+    let mut records = Vec::with_capacity(colors.len());
+    for ((color, alpha), ratio) in colors.iter().zip(alphas.iter()).zip(ratios.iter()) {
+        let rgb = color.coerce_to_u32(activation)?;
+        let alpha = alpha.coerce_to_number(activation)?;
+        let ratio = ratio.coerce_to_number(activation)?;
+        records.push(GradientRecord {
+            ratio: ratio.clamp(0.0, 255.0) as u8,
+            color: Color::from_rgb(rgb, (alpha.clamp(0.0, 1.0) * 255.0).round() as u8),
+        });
+    }
+
+    Ok(records)
+}
+
+/// Convert a `flash.filters` object into the filter that it describes.
+///
+/// Filters are recognized by the name of their class. Returns `None` for
+/// objects that aren't filters, and for filters that can't be rendered.
+pub fn object_to_filter<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Option<Filter>, Error> {
+    let class_name = match filter_class(object) {
+        Some((_, class_name, _)) => class_name,
+        None => return Ok(None),
+    };
+
+    let filter = match class_name {
+        "BlurFilter" => Filter::BlurFilter(Box::new(swf::BlurFilter {
+            blur_x: get_fixed16(activation, object, "blurX")?,
+            blur_y: get_fixed16(activation, object, "blurY")?,
+            num_passes: get_num_passes(activation, object)?,
+        })),
+        "GlowFilter" => Filter::GlowFilter(Box::new(swf::GlowFilter {
+            color: get_color(activation, object, "color", "alpha")?,
+            blur_x: get_fixed16(activation, object, "blurX")?,
+            blur_y: get_fixed16(activation, object, "blurY")?,
+            strength: get_strength(activation, object)?,
+            is_inner: get_bool(activation, object, "inner")?,
+            is_knockout: get_bool(activation, object, "knockout")?,
+            num_passes: get_num_passes(activation, object)?,
+        })),
+        "DropShadowFilter" => Filter::DropShadowFilter(Box::new(swf::DropShadowFilter {
+            color: get_color(activation, object, "color", "alpha")?,
+            blur_x: get_fixed16(activation, object, "blurX")?,
+            blur_y: get_fixed16(activation, object, "blurY")?,
+            angle: get_angle(activation, object)?,
+            distance: get_fixed16(activation, object, "distance")?,
+            strength: get_strength(activation, object)?,
+            is_inner: get_bool(activation, object, "inner")?,
+            is_knockout: get_bool(activation, object, "knockout")?,
+            num_passes: get_num_passes(activation, object)?,
+        })),
+        "BevelFilter" => {
+            let (is_inner, is_on_top) = get_bevel_type(activation, object)?;
+            Filter::BevelFilter(Box::new(swf::BevelFilter {
+                shadow_color: get_color(activation, object, "shadowColor", "shadowAlpha")?,
+                highlight_color: get_color(activation, object, "highlightColor", "highlightAlpha")?,
+                blur_x: get_fixed16(activation, object, "blurX")?,
+                blur_y: get_fixed16(activation, object, "blurY")?,
+                angle: get_angle(activation, object)?,
+                distance: get_fixed16(activation, object, "distance")?,
+                strength: get_strength(activation, object)?,
+                is_inner,
+                is_knockout: get_bool(activation, object, "knockout")?,
+                is_on_top,
+                num_passes: get_num_passes(activation, object)?,
+            }))
+        }
+        "GradientGlowFilter" => {
+            let (is_inner, is_on_top) = get_bevel_type(activation, object)?;
+            Filter::GradientGlowFilter(Box::new(swf::GradientGlowFilter {
+                colors: get_gradient(activation, object)?,
+                blur_x: get_fixed16(activation, object, "blurX")?,
+                blur_y: get_fixed16(activation, object, "blurY")?,
+                angle: get_angle(activation, object)?,
+                distance: get_fixed16(activation, object, "distance")?,
+                strength: get_strength(activation, object)?,
+                is_inner,
+                is_knockout: get_bool(activation, object, "knockout")?,
+                is_on_top,
+                num_passes: get_num_passes(activation, object)?,
+            }))
+        }
+        "GradientBevelFilter" => {
+            let (is_inner, is_on_top) = get_bevel_type(activation, object)?;
+            Filter::GradientBevelFilter(Box::new(swf::GradientBevelFilter {
+                colors: get_gradient(activation, object)?,
+                blur_x: get_fixed16(activation, object, "blurX")?,
+                blur_y: get_fixed16(activation, object, "blurY")?,
+                angle: get_angle(activation, object)?,
+                distance: get_fixed16(activation, object, "distance")?,
+                strength: get_strength(activation, object)?,
+                is_inner,
+                is_knockout: get_bool(activation, object, "knockout")?,
+                is_on_top,
+                num_passes: get_num_passes(activation, object)?,
+            }))
+        }
+        "ColorMatrixFilter" => {
+            let mut matrix = [Fixed16::ZERO; 20];
+            let values = get_array(activation, object, "matrix")?;
+            for (value, fixed) in values.iter().zip(matrix.iter_mut()) {
+                *fixed = Fixed16::from_f64(value.coerce_to_number(activation)?);
+            }
+            Filter::ColorMatrixFilter(Box::new(swf::ColorMatrixFilter { matrix }))
+        }
+        "ConvolutionFilter" => {
+            let num_matrix_cols = get(activation, object, "matrixX")?.coerce_to_u32(activation)?;
+            let num_matrix_rows = get(activation, object, "matrixY")?.coerce_to_u32(activation)?;
+            let mut matrix = Vec::new();
+            for value in get_array(activation, object, "matrix")? {
+                matrix.push(Fixed16::from_f64(value.coerce_to_number(activation)?));
+            }
+            Filter::ConvolutionFilter(Box::new(swf::ConvolutionFilter {
+                num_matrix_rows: num_matrix_rows.min(15) as u8,
+                num_matrix_cols: num_matrix_cols.min(15) as u8,
+                matrix,
+                divisor: get_fixed16(activation, object, "divisor")?,
+                bias: get_fixed16(activation, object, "bias")?,
+                default_color: get_color(activation, object, "color", "alpha")?,
+                is_clamped: get_bool(activation, object, "clamp")?,
+                is_preserve_alpha: get_bool(activation, object, "preserveAlpha")?,
+            }))
+        }
+        _ => return Ok(None),
+    };
+
+    Ok(Some(filter))
+}
+
+/// The alpha of a color, from 0 to 1.
+fn alpha(color: &Color) -> f64 {
+    f64::from(color.a) / 255.0
+}
+
+/// The `type` of a bevel or gradient filter with the given flags.
+fn bevel_type_name(is_inner: bool, is_on_top: bool) -> &'static str {
+    if is_on_top {
+        "full"
+    } else if is_inner {
+        "inner"
+    } else {
+        "outer"
+    }
+}
+
+/// Create the `colors`, `alphas` and `ratios` arrays of a gradient filter.
+fn gradient_arrays<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    records: &[GradientRecord],
+) -> Result<[Value<'gc>; 3], Error> {
+    let colors: Vec<_> = records.iter().map(|r| r.color.to_rgb().into()).collect();
+    let alphas: Vec<_> = records.iter().map(|r| alpha(&r.color).into()).collect();
+    let ratios: Vec<_> = records.iter().map(|r| r.ratio.into()).collect();
+    Ok([
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&colors))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&alphas))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&ratios))?.into(),
+    ])
+}
+
+/// Create a `flash.filters` object that describes a filter.
+pub fn filter_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    filter: &Filter,
+) -> Result<Object<'gc>, Error> {
+    let (class, properties): (ClassObject<'gc>, Vec<(&'static str, Value<'gc>)>) = match filter {
+        Filter::BlurFilter(filter) => (
+            activation.avm2().classes().blurfilter,
+            vec![
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::GlowFilter(filter) => (
+            activation.avm2().classes().glowfilter,
+            vec![
+                ("color", filter.color.to_rgb().into()),
+                ("alpha", alpha(&filter.color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                ("inner", filter.is_inner.into()),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::DropShadowFilter(filter) => (
+            activation.avm2().classes().dropshadowfilter,
+            vec![
+                ("color", filter.color.to_rgb().into()),
+                ("alpha", alpha(&filter.color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("angle", filter.angle.to_f64().to_degrees().into()),
+                ("distance", filter.distance.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                ("inner", filter.is_inner.into()),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::BevelFilter(filter) => (
+            activation.avm2().classes().bevelfilter,
+            vec![
+                ("shadowColor", filter.shadow_color.to_rgb().into()),
+                ("shadowAlpha", alpha(&filter.shadow_color).into()),
+                ("highlightColor", filter.highlight_color.to_rgb().into()),
+                ("highlightAlpha", alpha(&filter.highlight_color).into()),
+                ("blurX", filter.blur_x.to_f64().into()),
+                ("blurY", filter.blur_y.to_f64().into()),
+                ("angle", filter.angle.to_f64().to_degrees().into()),
+                ("distance", filter.distance.to_f64().into()),
+                ("strength", filter.strength.to_f64().into()),
+                (
+                    "type",
+                    bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                ),
+                ("knockout", filter.is_knockout.into()),
+                ("quality", filter.num_passes.into()),
+            ],
+        ),
+        Filter::GradientGlowFilter(filter) => {
+            let [colors, alphas, ratios] = gradient_arrays(activation, &filter.colors)?;
+            (
+                activation.avm2().classes().gradientglowfilter,
+                vec![
+                    ("colors", colors),
+                    ("alphas", alphas),
+                    ("ratios", ratios),
+                    ("blurX", filter.blur_x.to_f64().into()),
+                    ("blurY", filter.blur_y.to_f64().into()),
+                    ("angle", filter.angle.to_f64().to_degrees().into()),
+                    ("distance", filter.distance.to_f64().into()),
+                    ("strength", filter.strength.to_f64().into()),
+                    (
+                        "type",
+                        bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                    ),
+                    ("knockout", filter.is_knockout.into()),
+                    ("quality", filter.num_passes.into()),
+                ],
+            )
+        }
+        Filter::GradientBevelFilter(filter) => {
+            let [colors, alphas, ratios] = gradient_arrays(activation, &filter.colors)?;
+            (
+                activation.avm2().classes().gradientbevelfilter,
+                vec![
+                    ("colors", colors),
+                    ("alphas", alphas),
+                    ("ratios", ratios),
+                    ("blurX", filter.blur_x.to_f64().into()),
+                    ("blurY", filter.blur_y.to_f64().into()),
+                    ("angle", filter.angle.to_f64().to_degrees().into()),
+                    ("distance", filter.distance.to_f64().into()),
+                    ("strength", filter.strength.to_f64().into()),
+                    (
+                        "type",
+                        bevel_type_name(filter.is_inner, filter.is_on_top).into(),
+                    ),
+                    ("knockout", filter.is_knockout.into()),
+                    ("quality", filter.num_passes.into()),
+                ],
+            )
+        }
+        Filter::ColorMatrixFilter(filter) => {
+            let matrix: Vec<_> = filter.matrix.iter().map(|v| v.to_f64().into()).collect();
+            let matrix = ArrayObject::from_storage(activation, ArrayStorage::from_args(&matrix))?;
+            (
+                activation.avm2().classes().colormatrixfilter,
+                vec![("matrix", matrix.into())],
+            )
+        }
+        Filter::ConvolutionFilter(filter) => {
+            let matrix: Vec<_> = filter.matrix.iter().map(|v| v.to_f64().into()).collect();
+            let matrix = ArrayObject::from_storage(activation, ArrayStorage::from_args(&matrix))?;
+            (
+                activation.avm2().classes().convolutionfilter,
+                vec![
+                    ("matrixX", filter.num_matrix_cols.into()),
+                    ("matrixY", filter.num_matrix_rows.into()),
+                    ("matrix", matrix.into()),
+                    ("divisor", filter.divisor.to_f64().into()),
+                    ("bias", filter.bias.to_f64().into()),
+                    ("preserveAlpha", filter.is_preserve_alpha.into()),
+                    ("clamp", filter.is_clamped.into()),
+                    ("color", filter.default_color.to_rgb().into()),
+                    ("alpha", alpha(&filter.default_color).into()),
+                ],
+            )
+        }
+    };
+
+    let mut object = class.construct(activation, &[])?;
+    for (name, value) in properties {
+        object.set_property(&Multiname::public(name), value, activation)?;
+    }
+
+    Ok(object)
+}
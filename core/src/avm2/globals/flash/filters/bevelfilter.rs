@@ -0,0 +1,72 @@
+//! `flash.filters.BevelFilter` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::filters::{
+    define_properties, init_properties, FilterProperties, PropertyDefault,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// The properties of a `BevelFilter`, in the order of its constructor's
+/// parameters.
+pub const PROPERTIES: FilterProperties = &[
+    ("distance", PropertyDefault::Number(4.0)),
+    ("angle", PropertyDefault::Number(45.0)),
+    ("highlightColor", PropertyDefault::Uint(0xFFFFFF)),
+    ("highlightAlpha", PropertyDefault::Number(1.0)),
+    ("shadowColor", PropertyDefault::Uint(0)),
+    ("shadowAlpha", PropertyDefault::Number(1.0)),
+    ("blurX", PropertyDefault::Number(4.0)),
+    ("blurY", PropertyDefault::Number(4.0)),
+    ("strength", PropertyDefault::Number(1.0)),
+    ("quality", PropertyDefault::Int(1)),
+    ("type", PropertyDefault::String("inner")),
+    ("knockout", PropertyDefault::Boolean(false)),
+];
+
+/// Implements `flash.filters.BevelFilter`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+        init_properties(activation, this, args, PROPERTIES)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.filters.BevelFilter`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `BevelFilter`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.filters"), "BevelFilter"),
+        Some(QName::new(Namespace::package("flash.filters"), "BitmapFilter").into()),
+        Method::from_builtin(instance_init, "<BevelFilter instance initializer>", mc),
+        Method::from_builtin(class_init, "<BevelFilter class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED | ClassAttributes::FINAL);
+
+    define_properties(&mut write, PROPERTIES);
+
+    class
+}
@@ -0,0 +1,80 @@
+//! `flash.filters.ColorMatrixFilter` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::filters::{
+    define_properties, init_properties, FilterProperties, PropertyDefault,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{ArrayObject, Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::{ArrayStorage, Error};
+use gc_arena::{GcCell, MutationContext};
+
+/// The properties of a `ColorMatrixFilter`, in the order of its constructor's
+/// parameters.
+pub const PROPERTIES: FilterProperties = &[("matrix", PropertyDefault::Object("", "Array"))];
+
+/// The matrix that maps every color to itself.
+const IDENTITY: [f64; 20] = [
+    1.0, 0.0, 0.0, 0.0, 0.0, //
+    0.0, 1.0, 0.0, 0.0, 0.0, //
+    0.0, 0.0, 1.0, 0.0, 0.0, //
+    0.0, 0.0, 0.0, 1.0, 0.0, //
+];
+
+/// Implements `flash.filters.ColorMatrixFilter`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+        init_properties(activation, this, args, PROPERTIES)?;
+
+        // Without a matrix, the filter leaves colors unchanged.
+        let matrix = Multiname::public("matrix");
+        if let Value::Null = this.get_property(&matrix, activation)? {
+            let identity: Vec<_> = IDENTITY.iter().map(|&value| value.into()).collect();
+            let identity =
+                ArrayObject::from_storage(activation, ArrayStorage::from_args(&identity))?;
+            this.set_property(&matrix, identity.into(), activation)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.filters.ColorMatrixFilter`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `ColorMatrixFilter`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.filters"), "ColorMatrixFilter"),
+        Some(QName::new(Namespace::package("flash.filters"), "BitmapFilter").into()),
+        Method::from_builtin(
+            instance_init,
+            "<ColorMatrixFilter instance initializer>",
+            mc,
+        ),
+        Method::from_builtin(class_init, "<ColorMatrixFilter class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED | ClassAttributes::FINAL);
+
+    define_properties(&mut write, PROPERTIES);
+
+    class
+}
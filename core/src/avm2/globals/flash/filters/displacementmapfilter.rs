@@ -0,0 +1,76 @@
+//! `flash.filters.DisplacementMapFilter` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::filters::{
+    define_properties, init_properties, FilterProperties, PropertyDefault,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// The properties of a `DisplacementMapFilter`, in the order of its constructor's
+/// parameters.
+pub const PROPERTIES: FilterProperties = &[
+    (
+        "mapBitmap",
+        PropertyDefault::Object("flash.display", "BitmapData"),
+    ),
+    ("mapPoint", PropertyDefault::Object("flash.geom", "Point")),
+    ("componentX", PropertyDefault::Uint(0)),
+    ("componentY", PropertyDefault::Uint(0)),
+    ("scaleX", PropertyDefault::Number(0.0)),
+    ("scaleY", PropertyDefault::Number(0.0)),
+    ("mode", PropertyDefault::String("wrap")),
+    ("color", PropertyDefault::Uint(0)),
+    ("alpha", PropertyDefault::Number(0.0)),
+];
+
+/// Implements `flash.filters.DisplacementMapFilter`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+        init_properties(activation, this, args, PROPERTIES)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.filters.DisplacementMapFilter`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `DisplacementMapFilter`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.filters"), "DisplacementMapFilter"),
+        Some(QName::new(Namespace::package("flash.filters"), "BitmapFilter").into()),
+        Method::from_builtin(
+            instance_init,
+            "<DisplacementMapFilter instance initializer>",
+            mc,
+        ),
+        Method::from_builtin(class_init, "<DisplacementMapFilter class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED | ClassAttributes::FINAL);
+
+    define_properties(&mut write, PROPERTIES);
+
+    class
+}
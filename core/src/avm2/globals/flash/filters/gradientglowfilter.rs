@@ -0,0 +1,75 @@
+//! `flash.filters.GradientGlowFilter` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::filters::{
+    define_properties, init_properties, FilterProperties, PropertyDefault,
+};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::Object;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// The properties of a `GradientGlowFilter`, in the order of its constructor's
+/// parameters.
+pub const PROPERTIES: FilterProperties = &[
+    ("distance", PropertyDefault::Number(4.0)),
+    ("angle", PropertyDefault::Number(45.0)),
+    ("colors", PropertyDefault::Object("", "Array")),
+    ("alphas", PropertyDefault::Object("", "Array")),
+    ("ratios", PropertyDefault::Object("", "Array")),
+    ("blurX", PropertyDefault::Number(4.0)),
+    ("blurY", PropertyDefault::Number(4.0)),
+    ("strength", PropertyDefault::Number(1.0)),
+    ("quality", PropertyDefault::Int(1)),
+    ("type", PropertyDefault::String("inner")),
+    ("knockout", PropertyDefault::Boolean(false)),
+];
+
+/// Implements `flash.filters.GradientGlowFilter`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+        init_properties(activation, this, args, PROPERTIES)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.filters.GradientGlowFilter`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `GradientGlowFilter`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.filters"), "GradientGlowFilter"),
+        Some(QName::new(Namespace::package("flash.filters"), "BitmapFilter").into()),
+        Method::from_builtin(
+            instance_init,
+            "<GradientGlowFilter instance initializer>",
+            mc,
+        ),
+        Method::from_builtin(class_init, "<GradientGlowFilter class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED | ClassAttributes::FINAL);
+
+    define_properties(&mut write, PROPERTIES);
+
+    class
+}
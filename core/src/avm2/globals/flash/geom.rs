@@ -1,5 +1,7 @@
 //! `flash.geom` namespace
 
+pub mod color_transform;
 pub mod matrix;
 pub mod point;
 pub mod rectangle;
+pub mod transform;
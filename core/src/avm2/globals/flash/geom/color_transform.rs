@@ -0,0 +1,267 @@
+//! `flash.geom.ColorTransform` builtin/prototype
+
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::{Activation, Error, Namespace, Object, QName, TObject, Value};
+use crate::color_transform::ColorTransform;
+use crate::string::AvmString;
+use gc_arena::{GcCell, MutationContext};
+use swf::Fixed8;
+
+/// The names of the multiplier and offset properties of a `ColorTransform`,
+/// in constructor argument order.
+const PROPERTIES: [&str; 8] = [
+    "redMultiplier",
+    "greenMultiplier",
+    "blueMultiplier",
+    "alphaMultiplier",
+    "redOffset",
+    "greenOffset",
+    "blueOffset",
+    "alphaOffset",
+];
+
+fn get_values<'gc>(
+    this: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<[f64; 8], Error> {
+    let mut values = [0.0; 8];
+    for (value, name) in values.iter_mut().zip(PROPERTIES) {
+        *value = this
+            .get_property(&QName::new(Namespace::public(), name).into(), activation)?
+            .coerce_to_number(activation)?;
+    }
+
+    Ok(values)
+}
+
+fn set_values<'gc>(
+    this: &mut Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    values: [f64; 8],
+) -> Result<(), Error> {
+    for (value, name) in values.into_iter().zip(PROPERTIES) {
+        this.set_property(
+            &QName::new(Namespace::public(), name).into(),
+            value.into(),
+            activation,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Create a new `ColorTransform` object holding the values of a core color
+/// transform.
+pub fn color_transform_to_object<'gc>(
+    color_transform: &ColorTransform,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let color_transform_class = activation.avm2().classes().colortransform;
+    let args = [
+        color_transform.r_mult.to_f64().into(),
+        color_transform.g_mult.to_f64().into(),
+        color_transform.b_mult.to_f64().into(),
+        color_transform.a_mult.to_f64().into(),
+        color_transform.r_add.into(),
+        color_transform.g_add.into(),
+        color_transform.b_add.into(),
+        color_transform.a_add.into(),
+    ];
+
+    color_transform_class.construct(activation, &args)
+}
+
+/// Read the values of a `ColorTransform` object into a core color transform.
+pub fn object_to_color_transform<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<ColorTransform, Error> {
+    let [r_mult, g_mult, b_mult, a_mult, r_add, g_add, b_add, a_add] =
+        get_values(object, activation)?;
+
+    Ok(ColorTransform {
+        r_mult: Fixed8::from_f64(r_mult),
+        g_mult: Fixed8::from_f64(g_mult),
+        b_mult: Fixed8::from_f64(b_mult),
+        a_mult: Fixed8::from_f64(a_mult),
+        r_add: r_add as i16,
+        g_add: g_add as i16,
+        b_add: b_add as i16,
+        a_add: a_add as i16,
+    })
+}
+
+/// Implements `flash.geom.ColorTransform`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let mut values = [1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+        for (value, arg) in values.iter_mut().zip(args) {
+            *value = arg.coerce_to_number(activation)?;
+        }
+
+        set_values(&mut this, activation, values)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.ColorTransform`'s class initializer.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `color`'s getter.
+pub fn color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let [.., red_offset, green_offset, blue_offset, _] = get_values(this, activation)?;
+        let color = ((red_offset as u32 & 0xFF) << 16)
+            | ((green_offset as u32 & 0xFF) << 8)
+            | (blue_offset as u32 & 0xFF);
+
+        return Ok(color.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `color`'s setter.
+///
+/// Setting the color replaces the color multipliers and offsets with a solid
+/// color, leaving alpha untouched.
+pub fn set_color<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let color = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_u32(activation)?;
+
+        let mut values = get_values(this, activation)?;
+        values[0] = 0.0;
+        values[1] = 0.0;
+        values[2] = 0.0;
+        values[4] = ((color >> 16) & 0xFF) as f64;
+        values[5] = ((color >> 8) & 0xFF) as f64;
+        values[6] = (color & 0xFF) as f64;
+
+        set_values(&mut this, activation, values)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `concat`.
+///
+/// The second transform is applied first, followed by this one.
+pub fn concat<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let second = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let first = get_values(this, activation)?;
+        let second = get_values(second, activation)?;
+
+        let values = [
+            first[0] * second[0],
+            first[1] * second[1],
+            first[2] * second[2],
+            first[3] * second[3],
+            first[0] * second[4] + first[4],
+            first[1] * second[5] + first[5],
+            first[2] * second[6] + first[6],
+            first[3] * second[7] + first[7],
+        ];
+
+        set_values(&mut this, activation, values)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `toString`.
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let values = get_values(this, activation)?;
+        let mut fields = Vec::with_capacity(values.len());
+        for (name, value) in PROPERTIES.iter().zip(values) {
+            let value = Value::from(value).coerce_to_string(activation)?;
+            fields.push(format!("{}={}", name, value));
+        }
+
+        return Ok(AvmString::new_utf8(
+            activation.context.gc_context,
+            format!("({})", fields.join(", ")),
+        )
+        .into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `ColorTransform`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "ColorTransform"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(instance_init, "<ColorTransform instance initializer>", mc),
+        Method::from_builtin(class_init, "<ColorTransform class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    const PUBLIC_INSTANCE_NUMBER_SLOTS: &[(&str, Option<f64>)] = &[
+        ("redMultiplier", None),
+        ("greenMultiplier", None),
+        ("blueMultiplier", None),
+        ("alphaMultiplier", None),
+        ("redOffset", None),
+        ("greenOffset", None),
+        ("blueOffset", None),
+        ("alphaOffset", None),
+    ];
+    write.define_public_slot_number_instance_traits(PUBLIC_INSTANCE_NUMBER_SLOTS);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("color", Some(color), Some(set_color))];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("concat", concat), ("toString", to_string)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
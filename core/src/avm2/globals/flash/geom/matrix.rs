@@ -3,7 +3,48 @@
 use crate::avm2::class::Class;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::{Activation, Error, Namespace, Object, QName, TObject, Value};
+use crate::matrix::Matrix;
 use gc_arena::{GcCell, MutationContext};
+use swf::Twips;
+
+/// Create a new `Matrix` object holding the values of a core matrix.
+pub fn matrix_to_object<'gc>(
+    matrix: Matrix,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let matrix_class = activation.avm2().classes().matrix;
+    let args = [
+        matrix.a.into(),
+        matrix.b.into(),
+        matrix.c.into(),
+        matrix.d.into(),
+        matrix.tx.to_pixels().into(),
+        matrix.ty.to_pixels().into(),
+    ];
+
+    matrix_class.construct(activation, &args)
+}
+
+/// Read the values of a `Matrix` object into a core matrix.
+pub fn object_to_matrix<'gc>(
+    object: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Matrix, Error> {
+    let mut get = |name: &'static str| -> Result<f64, Error> {
+        object
+            .get_property(&QName::new(Namespace::public(), name).into(), activation)?
+            .coerce_to_number(activation)
+    };
+
+    Ok(Matrix {
+        a: get("a")? as f32,
+        b: get("b")? as f32,
+        c: get("c")? as f32,
+        d: get("d")? as f32,
+        tx: Twips::from_pixels(get("tx")?),
+        ty: Twips::from_pixels(get("ty")?),
+    })
+}
 
 #[allow(clippy::too_many_arguments)]
 fn set_values<'gc>(
@@ -0,0 +1,238 @@
+//! `flash.geom.Transform` builtin/prototype
+
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::geom::color_transform::{
+    color_transform_to_object, object_to_color_transform,
+};
+use crate::avm2::globals::flash::geom::matrix::{matrix_to_object, object_to_matrix};
+use crate::avm2::globals::flash::geom::rectangle::create_rectangle;
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::{Activation, Error, Namespace, Object, QName, TObject, Value};
+use crate::display_object::{DisplayObject, TDisplayObject};
+use gc_arena::{GcCell, MutationContext};
+
+/// Retrieve the display object that a `Transform` object controls.
+fn display_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+) -> Result<Option<DisplayObject<'gc>>, Error> {
+    if let Some(this) = this {
+        let dobj = this.get_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "displayObject").into(),
+            activation,
+        )?;
+
+        return Ok(dobj.as_object().and_then(|dobj| dobj.as_display_object()));
+    }
+
+    Ok(None)
+}
+
+/// Implements `flash.geom.Transform`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let dobj = match args.get(0) {
+            Some(Value::Object(dobj)) if dobj.as_display_object().is_some() => *dobj,
+            _ => {
+                return Err(
+                    "TypeError: Error #2007: Parameter displayObject must be non-null.".into(),
+                )
+            }
+        };
+
+        this.set_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "displayObject").into(),
+            dobj.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.geom.Transform`'s class initializer.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `matrix`'s getter.
+pub fn matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let matrix = *dobj.base().matrix();
+        return Ok(matrix_to_object(matrix, activation)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `matrix`'s setter.
+pub fn set_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        if let Some(Value::Object(matrix)) = args.get(0) {
+            let matrix = object_to_matrix(*matrix, activation)?;
+            dobj.set_matrix(activation.context.gc_context, &matrix);
+            dobj.set_transformed_by_script(activation.context.gc_context, true);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `colorTransform`'s getter.
+pub fn color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let color_transform = *dobj.base().color_transform();
+        return Ok(color_transform_to_object(&color_transform, activation)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `colorTransform`'s setter.
+pub fn set_color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let color_transform = match args.get(0) {
+            Some(Value::Object(color_transform)) => {
+                object_to_color_transform(*color_transform, activation)?
+            }
+            _ => {
+                return Err(
+                    "TypeError: Error #2007: Parameter colorTransform must be non-null.".into(),
+                )
+            }
+        };
+
+        dobj.set_color_transform(activation.context.gc_context, &color_transform);
+        dobj.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `concatenatedMatrix`'s getter.
+pub fn concatenated_matrix<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let matrix = dobj.local_to_global_matrix();
+        return Ok(matrix_to_object(matrix, activation)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `concatenatedColorTransform`'s getter.
+pub fn concatenated_color_transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        // Walk through parents to get combined color transform.
+        let mut color_transform = *dobj.base().color_transform();
+        let mut node = dobj.parent();
+        while let Some(display_object) = node {
+            color_transform = *display_object.base().color_transform() * color_transform;
+            node = display_object.parent();
+        }
+
+        return Ok(color_transform_to_object(&color_transform, activation)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `pixelBounds`'s getter.
+pub fn pixel_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(dobj) = display_object(activation, this)? {
+        let bounds = dobj.world_bounds();
+        return create_rectangle(
+            activation,
+            (
+                bounds.x_min.to_pixels(),
+                bounds.y_min.to_pixels(),
+                bounds.width().to_pixels(),
+                bounds.height().to_pixels(),
+            ),
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Transform`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.geom"), "Transform"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(instance_init, "<Transform instance initializer>", mc),
+        Method::from_builtin(class_init, "<Transform class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_attributes(ClassAttributes::SEALED);
+
+    write.define_private_slot_instance_traits(&[(
+        NS_RUFFLE_INTERNAL,
+        "displayObject",
+        "flash.display",
+        "DisplayObject",
+    )]);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        (
+            "colorTransform",
+            Some(color_transform),
+            Some(set_color_transform),
+        ),
+        (
+            "concatenatedColorTransform",
+            Some(concatenated_color_transform),
+            None,
+        ),
+        ("concatenatedMatrix", Some(concatenated_matrix), None),
+        ("matrix", Some(matrix), Some(set_matrix)),
+        ("pixelBounds", Some(pixel_bounds), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    class
+}
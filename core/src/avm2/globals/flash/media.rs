@@ -1,7 +1,9 @@
 //! `flash.media` namespace
 
+pub mod id3info;
 pub mod sound;
 pub mod soundchannel;
+pub mod soundloadercontext;
 pub mod soundmixer;
 pub mod soundtransform;
 pub mod video;
@@ -0,0 +1,61 @@
+//! `flash.media.ID3Info` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.media.ID3Info`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.ID3Info`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `ID3Info`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "ID3Info"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(instance_init, "<ID3Info instance initializer>", mc),
+        Method::from_builtin(class_init, "<ID3Info class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    // Frames without a named property are stored as dynamic properties.
+    write.set_attributes(ClassAttributes::FINAL);
+
+    const PUBLIC_INSTANCE_SLOTS: &[(&str, &str, &str)] = &[
+        ("album", "", "String"),
+        ("artist", "", "String"),
+        ("comment", "", "String"),
+        ("genre", "", "String"),
+        ("songName", "", "String"),
+        ("track", "", "String"),
+        ("year", "", "String"),
+    ];
+    write.define_public_slot_instance_traits(PUBLIC_INSTANCE_SLOTS);
+
+    class
+}
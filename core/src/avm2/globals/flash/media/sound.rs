@@ -2,13 +2,16 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::flash::net::url_request::request_into_url_and_options;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{sound_allocator, Object, SoundChannelObject, TObject};
+use crate::avm2::object::{sound_allocator, Object, QueuedPlay, SoundChannelObject, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::character::Character;
 use crate::display_object::SoundTransform;
+use crate::security::DataAccess;
+use crate::string::AvmString;
 use gc_arena::{GcCell, MutationContext};
 use swf::{SoundEvent, SoundInfo};
 
@@ -16,7 +19,7 @@ use swf::{SoundEvent, SoundInfo};
 pub fn instance_init<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this {
         activation.super_init(this, &[])?;
@@ -44,6 +47,10 @@ pub fn instance_init<'gc>(
                 }
             }
         }
+
+        if let Some(Value::Object(request)) = args.get(0) {
+            spawn_load(activation, this, *request)?;
+        }
     }
 
     Ok(Value::Undefined)
@@ -58,12 +65,33 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Sound.bytesLoaded`
+pub fn bytes_loaded<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(sound_object) = this.and_then(|this| this.as_sound_object()) {
+        if sound_object.url().is_some() {
+            return Ok(sound_object.bytes_loaded().into());
+        }
+    }
+
+    bytes_total(activation, this, args)
+}
+
 /// Implements `Sound.bytesTotal`
 pub fn bytes_total<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(sound_object) = this.and_then(|this| this.as_sound_object()) {
+        if sound_object.url().is_some() {
+            return Ok(sound_object.bytes_total().into());
+        }
+    }
+
     if let Some(sound) = this.and_then(|this| this.as_sound()) {
         if let Some(length) = activation.context.audio.get_sound_size(sound) {
             return Ok((length).into());
@@ -73,23 +101,75 @@ pub fn bytes_total<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Sound.id3`
+pub fn id3<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(id3) = this
+        .and_then(|this| this.as_sound_object())
+        .and_then(|sound_object| sound_object.id3())
+    {
+        return Ok(id3.into());
+    }
+
+    let id3info = activation.avm2().classes().id3info;
+    Ok(id3info.construct(activation, &[])?.into())
+}
+
 /// Implements `Sound.isBuffering`
 pub fn is_buffering<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    //STUB: We do not yet support network-loaded sounds.
+    if let Some(sound_object) = this.and_then(|this| this.as_sound_object()) {
+        return Ok(sound_object.is_loading().into());
+    }
+
+    Ok(false.into())
+}
+
+/// Implements `Sound.isURLInaccessible`
+///
+/// A sound from another domain is inaccessible unless a policy file grants
+/// the calling movie access to it.
+pub fn is_url_inaccessible<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(url) = this
+        .and_then(|this| this.as_sound_object())
+        .and_then(|sound_object| sound_object.url())
+    {
+        let url = activation
+            .context
+            .navigator
+            .resolve_relative_url(&url)
+            .into_owned();
+        let movie = activation.caller_movie();
+        let access = activation.context.security.data_access(&movie, &url);
+        return Ok((access != DataAccess::Allowed).into());
+    }
+
     Ok(false.into())
 }
 
 /// Implements `Sound.url`
 pub fn url<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    //STUB: We do not yet support network-loaded sounds.
+    if let Some(url) = this
+        .and_then(|this| this.as_sound_object())
+        .and_then(|sound_object| sound_object.url())
+    {
+        return Ok(AvmString::new_utf8(activation.context.gc_context, url).into());
+    }
+
     Ok(Value::Null)
 }
 
@@ -114,7 +194,7 @@ pub fn play<'gc>(
     this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    if let Some(sound) = this.and_then(|this| this.as_sound()) {
+    if let Some(this) = this {
         let position = args
             .get(0)
             .cloned()
@@ -132,29 +212,21 @@ pub fn play<'gc>(
             .coerce_to_object(activation)
             .ok();
 
+        let sound = match this.as_sound() {
+            Some(sound) => sound,
+            None => return queue_play(activation, this, position, num_loops, sound_transform),
+        };
+
         if let Some(duration) = activation.context.audio.get_sound_duration(sound) {
             if position > duration {
                 return Ok(Value::Null);
             }
         }
 
-        let in_sample = if position > 0.0 {
-            Some((position / 1000.0 * 44100.0) as u32)
-        } else {
-            None
-        };
-
-        let sound_info = SoundInfo {
-            event: SoundEvent::Start,
-            in_sample,
-            out_sample: None,
-            num_loops: num_loops.max(1) as u16,
-            envelope: None,
-        };
-
-        if let Some(instance) = activation
-            .context
-            .start_sound(sound, &sound_info, None, None)
+        if let Some(instance) =
+            activation
+                .context
+                .start_sound(sound, &sound_info(position, num_loops), None, None)
         {
             if let Some(sound_transform) = sound_transform {
                 let st = SoundTransform::from_avm2_object(activation, sound_transform)?;
@@ -174,6 +246,58 @@ pub fn play<'gc>(
     Ok(Value::Null)
 }
 
+/// Play a sound that is still loading once it has loaded.
+///
+/// The returned channel gets its sound instance when the sound starts.
+fn queue_play<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    position: f64,
+    num_loops: i32,
+    sound_transform: Option<Object<'gc>>,
+) -> Result<Value<'gc>, Error> {
+    let sound_object = match this.as_sound_object() {
+        Some(sound_object) if sound_object.is_loading() => sound_object,
+        _ => return Ok(Value::Null),
+    };
+
+    let sound_transform = match sound_transform {
+        Some(sound_transform) => Some(SoundTransform::from_avm2_object(
+            activation,
+            sound_transform,
+        )?),
+        None => None,
+    };
+    let channel = SoundChannelObject::empty(activation)?;
+    sound_object.queue_play(
+        activation.context.gc_context,
+        QueuedPlay {
+            channel,
+            sound_info: sound_info(position, num_loops),
+            sound_transform,
+        },
+    );
+
+    Ok(channel.into())
+}
+
+/// The settings to start a sound with for a `Sound.play` call.
+fn sound_info(position: f64, num_loops: i32) -> SoundInfo {
+    let in_sample = if position > 0.0 {
+        Some((position / 1000.0 * 44100.0) as u32)
+    } else {
+        None
+    };
+
+    SoundInfo {
+        event: SoundEvent::Start,
+        in_sample,
+        out_sample: None,
+        num_loops: num_loops.max(1) as u16,
+        envelope: None,
+    }
+}
+
 /// Stubs `Sound.extract`
 pub fn extract<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -183,22 +307,74 @@ pub fn extract<'gc>(
     Err("Sound.extract is a stub.".into())
 }
 
-/// Stubs `Sound.close`
+/// Implements `Sound.close`
 pub fn close<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("Sound.close is a stub.".into())
+    if let Some(this) = this {
+        if let Some(sound_object) = this.as_sound_object() {
+            if !sound_object.is_loading() {
+                return Err(
+                    "IOError: Error #2029: This URLStream object does not have a stream opened."
+                        .into(),
+                );
+            }
+
+            activation.context.load_manager.cancel_avm2_sound(this);
+            sound_object.stop_loading(activation.context.gc_context);
+        }
+    }
+
+    Ok(Value::Undefined)
 }
 
-/// Stubs `Sound.load`
+/// Implements `Sound.load`
 pub fn load<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("Sound.load is a stub.".into())
+    if let Some(this) = this {
+        let request = match args.get(0) {
+            Some(Value::Object(request)) => *request,
+            _ => return Err("TypeError: Error #2007: Parameter stream must be non-null.".into()),
+        };
+
+        spawn_load(activation, this, request)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Start loading a `URLRequest` into a `Sound`.
+///
+/// Each `Sound` can only load one file, and library sounds can't load any.
+fn spawn_load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    request: Object<'gc>,
+) -> Result<(), Error> {
+    if let Some(sound_object) = this.as_sound_object() {
+        if this.as_sound().is_some() || sound_object.url().is_some() {
+            return Err("IOError: Error #2037: Functions called in incorrect sequence, or earlier call was unsuccessful.".into());
+        }
+
+        let (url, request_options) = request_into_url_and_options(activation, request)?;
+        sound_object.start_loading(activation.context.gc_context, url.clone());
+
+        let future = activation.context.load_manager.load_sound_into_avm2_sound(
+            activation.context.player.clone().unwrap(),
+            activation.caller_movie(),
+            this,
+            &url,
+            request_options,
+        );
+        activation.context.navigator.spawn_future(future);
+    }
+
+    Ok(())
 }
 
 /// Stubs `Sound.loadCompressedDataFromByteArray`
@@ -239,10 +415,11 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         Option<NativeMethodImpl>,
         Option<NativeMethodImpl>,
     )] = &[
-        ("bytesLoaded", Some(bytes_total), None),
+        ("bytesLoaded", Some(bytes_loaded), None),
         ("bytesTotal", Some(bytes_total), None),
+        ("id3", Some(id3), None),
         ("isBuffering", Some(is_buffering), None),
-        ("isURLInaccessible", Some(is_buffering), None),
+        ("isURLInaccessible", Some(is_url_inaccessible), None),
         ("url", Some(url), None),
         ("length", Some(length), None),
     ];
@@ -0,0 +1,68 @@
+//! `flash.media.SoundLoaderContext` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.media.SoundLoaderContext`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let buffer_time = args.get(0).cloned().unwrap_or_else(|| 1000.into());
+        let check_policy_file = args.get(1).cloned().unwrap_or_else(|| false.into());
+        this.set_property(&Multiname::public("bufferTime"), buffer_time, activation)?;
+        this.set_property(
+            &Multiname::public("checkPolicyFile"),
+            check_policy_file,
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.media.SoundLoaderContext`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Construct `SoundLoaderContext`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.media"), "SoundLoaderContext"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(
+            instance_init,
+            "<SoundLoaderContext instance initializer>",
+            mc,
+        ),
+        Method::from_builtin(class_init, "<SoundLoaderContext class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.set_attributes(ClassAttributes::SEALED);
+
+    const PUBLIC_INSTANCE_SLOTS: &[(&str, &str, &str)] = &[
+        ("bufferTime", "", "Number"),
+        ("checkPolicyFile", "", "Boolean"),
+    ];
+    write.define_public_slot_instance_traits(PUBLIC_INSTANCE_SLOTS);
+
+    class
+}
@@ -2,21 +2,51 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
-use crate::avm2::method::Method;
+use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::Object;
+use crate::avm2::object::{Object, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
+use crate::display_object::{TDisplayObject, Video};
+use crate::tag_utils::SwfMovie;
+use crate::vminterface::AvmType;
 use gc_arena::{GcCell, MutationContext};
+use std::sync::Arc;
 
 /// Implements `flash.media.Video`'s instance constructor.
 pub fn instance_init<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this {
         activation.super_init(this, &[])?;
+
+        if this.as_display_object().is_none() {
+            let width = args
+                .get(0)
+                .cloned()
+                .unwrap_or_else(|| 320.into())
+                .coerce_to_i32(activation)?;
+            let height = args
+                .get(1)
+                .cloned()
+                .unwrap_or_else(|| 240.into())
+                .coerce_to_i32(activation)?;
+
+            let movie = Arc::new(SwfMovie::empty(activation.context.swf.version()));
+            let library = activation.context.library.library_for_movie_mut(movie);
+            library.force_avm_type(AvmType::Avm2);
+
+            let new_do = Video::new_with_avm2(
+                &mut activation.context,
+                this,
+                width.max(0) as u16,
+                height.max(0) as u16,
+            );
+
+            this.init_display_object(activation.context.gc_context, new_do.into());
+        }
     }
 
     Ok(Value::Undefined)
@@ -31,6 +61,26 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Video.attachNetStream`
+pub fn attach_net_stream<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(video) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_video())
+    {
+        let stream = match args.get(0) {
+            Some(Value::Object(object)) => object.as_netstream(),
+            _ => None,
+        };
+        video.attach_netstream(activation.context.gc_context, stream);
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `Video`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -45,5 +95,9 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
 
     write.set_attributes(ClassAttributes::SEALED);
 
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("attachNetStream", attach_net_stream)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
     class
 }
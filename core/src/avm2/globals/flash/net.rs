@@ -1,5 +1,116 @@
 //! `flash.net` namespace
 
+use crate::avm2::amf;
+use crate::avm2::names::Multiname;
+use crate::avm2::object::TObject;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::backend::navigator::NavigationMethod;
+use indexmap::IndexMap;
+use url::form_urlencoded;
+
+pub mod local_connection;
+pub mod netconnection;
+pub mod netstream;
 pub mod object_encoding;
 pub mod sharedobject;
+pub mod socket;
+pub mod url_loader;
+pub mod url_loader_data_format;
 pub mod url_request;
+pub mod url_request_header;
+pub mod url_request_method;
+pub mod url_variables;
+pub mod xml_socket;
+
+/// Implements `flash.net.navigateToURL`
+pub fn navigate_to_url<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let request = match args.get(0) {
+        Some(Value::Object(request)) => *request,
+        _ => return Err("TypeError: Error #2007: Parameter request must be non-null.".into()),
+    };
+    let window = match args.get(1) {
+        None | Some(Value::Undefined) | Some(Value::Null) => None,
+        Some(window) => Some(window.coerce_to_string(activation)?.to_string()),
+    };
+
+    let url = request
+        .get_property(&Multiname::public("url"), activation)?
+        .coerce_to_string(activation)?
+        .to_string();
+    let method = request
+        .get_property(&Multiname::public("method"), activation)?
+        .coerce_to_string(activation)?;
+    let method = NavigationMethod::from_method_str(&method).unwrap_or(NavigationMethod::Get);
+
+    let vars_method = match request.get_property(&Multiname::public("data"), activation)? {
+        Value::Undefined | Value::Null => None,
+        data => {
+            let data = data
+                .coerce_to_string(activation)?
+                .to_utf8_lossy()
+                .into_owned();
+            let vars: IndexMap<String, String> = form_urlencoded::parse(data.as_bytes())
+                .into_owned()
+                .collect();
+            Some((method, vars))
+        }
+    };
+
+    activation
+        .context
+        .navigator
+        .navigate_to_url(url, window, vars_method);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.registerClassAlias`
+pub fn register_class_alias<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let alias = match args.get(0) {
+        None | Some(Value::Undefined) | Some(Value::Null) => {
+            return Err("TypeError: Error #2007: Parameter aliasName must be non-null.".into())
+        }
+        Some(alias) => alias.coerce_to_string(activation)?,
+    };
+    let class = match args.get(1) {
+        Some(Value::Object(class)) => class
+            .as_class_object()
+            .ok_or("TypeError: Error #1034: Parameter classObject must be a Class.")?,
+        _ => return Err("TypeError: Error #2007: Parameter classObject must be non-null.".into()),
+    };
+
+    amf::register_class_alias(activation, alias, class);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.getClassByAlias`
+pub fn get_class_by_alias<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let alias = match args.get(0) {
+        None | Some(Value::Undefined) | Some(Value::Null) => {
+            return Err("TypeError: Error #2007: Parameter aliasName must be non-null.".into())
+        }
+        Some(alias) => alias.coerce_to_string(activation)?,
+    };
+
+    match amf::class_by_alias(activation, alias) {
+        Some(class) => Ok(class.into()),
+        None => Err(format!(
+            "ReferenceError: Error #1014: Class {} could not be found.",
+            alias
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,246 @@
+//! `flash.net.LocalConnection` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{local_connection_allocator, Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::external::Value as ExternalValue;
+use crate::local_connection::{movie_domain, LocalConnectionManager};
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use gc_arena::{GcCell, MutationContext};
+
+const ALREADY_CONNECTED: &str =
+    "ArgumentError: Error #2082: Connect failed because the object is already connected.";
+
+/// Implements `flash.net.LocalConnection`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.LocalConnection`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.connect`.
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(connection) = this.and_then(|this| this.as_local_connection()) {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        if name.is_empty() {
+            return Err(
+                "ArgumentError: Error #2007: Parameter connectionName must be non-null.".into(),
+            );
+        }
+
+        // Connecting fails if this object or another one already listens.
+        // Flash reports names containing a colon with the same error.
+        let handle = if connection.handle().is_none() && !name.contains(b':') {
+            let domain = movie_domain(&activation.caller_movie());
+            LocalConnectionManager::connect(
+                &mut activation.context,
+                AvmObject::Avm2(connection.into()),
+                &domain,
+                &name.to_string(),
+            )
+        } else {
+            None
+        };
+        match handle {
+            Some(handle) => connection.set_handle(activation.context.gc_context, Some(handle)),
+            None => return Err(ALREADY_CONNECTED.into()),
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.close`.
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(connection) = this.and_then(|this| this.as_local_connection()) {
+        match connection.handle() {
+            Some(handle) => {
+                LocalConnectionManager::close(&mut activation.context, handle);
+                connection.set_handle(activation.context.gc_context, None);
+            }
+            None => {
+                return Err(
+                    "ArgumentError: Error #2083: Close failed because the object is not connected."
+                        .into(),
+                )
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.send`.
+///
+/// The outcome is reported by a later `status` event.
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let method = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        if name.is_empty() {
+            return Err(
+                "ArgumentError: Error #2007: Parameter connectionName must be non-null.".into(),
+            );
+        }
+        if method.is_empty() {
+            return Err(
+                "ArgumentError: Error #2007: Parameter methodName must be non-null.".into(),
+            );
+        }
+
+        let mut message_args = Vec::new();
+        for arg in args.iter().skip(2) {
+            message_args.push(ExternalValue::from_avm2(activation, *arg)?);
+        }
+
+        let domain = movie_domain(&activation.caller_movie());
+        LocalConnectionManager::send(
+            &mut activation.context,
+            AvmObject::Avm2(this),
+            &domain,
+            &name.to_string(),
+            &method.to_string(),
+            message_args,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.allowDomain` and `allowInsecureDomain`.
+pub fn allow_domain<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(connection) = this.and_then(|this| this.as_local_connection()) {
+        for domain in args {
+            let domain = domain.coerce_to_string(activation)?;
+            connection.allow_domain(activation.context.gc_context, domain.to_string());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.client`'s getter.
+pub fn client<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(connection) = this.as_local_connection() {
+            return Ok(connection.client().unwrap_or(this).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.client`'s setter.
+pub fn set_client<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(connection) = this.and_then(|this| this.as_local_connection()) {
+        match args.get(0) {
+            Some(Value::Object(client)) => {
+                connection.set_client(activation.context.gc_context, Some(*client))
+            }
+            _ => return Err("TypeError: Error #2004: One of the parameters is invalid.".into()),
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.domain`'s getter.
+pub fn domain<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let domain = movie_domain(&activation.caller_movie());
+    Ok(AvmString::new_utf8(activation.context.gc_context, domain).into())
+}
+
+/// Construct `LocalConnection`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "LocalConnection"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<LocalConnection instance initializer>", mc),
+        Method::from_builtin(class_init, "<LocalConnection class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_instance_allocator(local_connection_allocator);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("client", Some(client), Some(set_client)),
+        ("domain", Some(domain), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("allowDomain", allow_domain),
+        ("allowInsecureDomain", allow_domain),
+        ("close", close),
+        ("connect", connect),
+        ("send", send),
+    ];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
@@ -0,0 +1,159 @@
+//! `flash.net.NetConnection` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::events::{dispatch_event, Event, EventData};
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{EventObject, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.net.NetConnection`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.NetConnection`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Set whether a `NetConnection` is connected.
+fn set_connected<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+    is_connected: bool,
+) -> Result<(), Error> {
+    this.set_property(
+        &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "connected").into(),
+        is_connected.into(),
+        activation,
+    )
+}
+
+/// Dispatch a `NetStatusEvent` describing a change in the connection.
+fn dispatch_status<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    code: &'static str,
+    level: &'static str,
+) -> Result<(), Error> {
+    let mut info = activation
+        .avm2()
+        .classes()
+        .object
+        .construct(activation, &[])?;
+    info.set_property(&QName::dynamic_name("code").into(), code.into(), activation)?;
+    info.set_property(
+        &QName::dynamic_name("level").into(),
+        level.into(),
+        activation,
+    )?;
+
+    let event = EventObject::from_event(
+        activation,
+        Event::new("netStatus", EventData::NetStatus { info }),
+    )?;
+    dispatch_event(activation, this, event)?;
+
+    Ok(())
+}
+
+/// Implements `NetConnection.connect`
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        // Only local connections, which are used to play FLV files, are supported.
+        let is_local = matches!(
+            args.get(0).unwrap_or(&Value::Null),
+            Value::Null | Value::Undefined
+        );
+        set_connected(activation, this, is_local)?;
+
+        if is_local {
+            dispatch_status(activation, this, "NetConnection.Connect.Success", "status")?;
+        } else {
+            dispatch_status(activation, this, "NetConnection.Connect.Failed", "error")?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetConnection.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if connected(activation, Some(this), &[])?.coerce_to_boolean() {
+            set_connected(activation, this, false)?;
+            dispatch_status(activation, this, "NetConnection.Connect.Closed", "status")?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetConnection.connected`'s getter.
+pub fn connected<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return this.get_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "connected").into(),
+            activation,
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `NetConnection`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "NetConnection"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<NetConnection instance initializer>", mc),
+        Method::from_builtin(class_init, "<NetConnection class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    write.define_private_slot_instance_traits(&[(NS_RUFFLE_INTERNAL, "connected", "", "Boolean")]);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("connected", Some(connected), None)];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("connect", connect), ("close", close)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
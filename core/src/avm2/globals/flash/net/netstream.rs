@@ -0,0 +1,275 @@
+//! `flash.net.NetStream` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{netstream_allocator, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.net.NetStream`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.NetStream`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.play`
+pub fn play<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        let url = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        net_stream.play(&mut activation.context, &url.to_utf8_lossy());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.pause`
+pub fn pause<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        net_stream.pause(&mut activation.context, Some(true));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.resume`
+pub fn resume<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        net_stream.pause(&mut activation.context, Some(false));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.togglePause`
+pub fn toggle_pause<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        net_stream.pause(&mut activation.context, None);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.seek`
+pub fn seek<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        let offset = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+        net_stream.seek(&mut activation.context, offset);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        net_stream.close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.time`'s getter.
+pub fn time<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        return Ok(net_stream.time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.bufferTime`'s getter.
+pub fn buffer_time<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        return Ok(net_stream.buffer_time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.bufferTime`'s setter.
+pub fn set_buffer_time<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        let buffer_time = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+        net_stream.set_buffer_time(activation.context.gc_context, buffer_time);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.bufferLength`'s getter.
+pub fn buffer_length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        return Ok(net_stream.buffer_length().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.bytesLoaded`'s getter.
+pub fn bytes_loaded<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        return Ok(net_stream.bytes_loaded().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.bytesTotal`'s getter.
+pub fn bytes_total<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        return Ok(net_stream.bytes_total().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.client`'s getter.
+pub fn client<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if let Some(net_stream) = this.as_netstream() {
+            return Ok(net_stream.avm2_client().unwrap_or(this).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `NetStream.client`'s setter.
+pub fn set_client<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(net_stream) = this.and_then(|this| this.as_netstream()) {
+        let client = match args.get(0) {
+            Some(Value::Object(client)) => *client,
+            _ => return Err("TypeError: Error #2004: One of the parameters is invalid.".into()),
+        };
+        net_stream.set_avm2_client(activation.context.gc_context, Some(client));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `NetStream`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "NetStream"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<NetStream instance initializer>", mc),
+        Method::from_builtin(class_init, "<NetStream class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_instance_allocator(netstream_allocator);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("bufferLength", Some(buffer_length), None),
+        ("bufferTime", Some(buffer_time), Some(set_buffer_time)),
+        ("bytesLoaded", Some(bytes_loaded), None),
+        ("bytesTotal", Some(bytes_total), None),
+        ("client", Some(client), Some(set_client)),
+        ("time", Some(time), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("close", close),
+        ("pause", pause),
+        ("play", play),
+        ("resume", resume),
+        ("seek", seek),
+        ("togglePause", toggle_pause),
+    ];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
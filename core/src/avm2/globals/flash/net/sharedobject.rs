@@ -1,10 +1,15 @@
 //! `flash.net.SharedObject` builtin/prototype
 
+use crate::avm1::globals::shared_object::local_shared_object_name;
+use crate::avm2::amf;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
 use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::Multiname;
 use crate::avm2::object::TObject;
 use crate::avm2::traits::Trait;
 use crate::avm2::{Activation, Error, Namespace, Object, QName, Value};
+use crate::string::AvmString;
 use gc_arena::{GcCell, MutationContext};
 
 fn instance_init<'gc>(
@@ -39,24 +44,154 @@ fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Retrieve the storage key of a shared object.
+fn storage_name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<String, Error> {
+    Ok(this
+        .get_property(
+            &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "name").into(),
+            activation,
+        )?
+        .coerce_to_string(activation)?
+        .to_string())
+}
+
+/// Retrieve the `data` object of a shared object.
+fn data_object<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<Object<'gc>, Error> {
+    this.get_property(&Multiname::public("data"), activation)?
+        .coerce_to_object(activation)
+}
+
+/// Write a shared object's data to storage.
+///
+/// Returns `false` if the storage backend refused the data.
+pub fn flush_to_storage<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<bool, Error> {
+    let name = storage_name(activation, this)?;
+    let data = data_object(activation, this)?;
+
+    let file_name = name.rsplit('/').next().unwrap_or("<unknown>");
+    let bytes = amf::serialize_lso(activation, file_name, data)?;
+
+    Ok(activation.context.storage.put(&name, &bytes))
+}
+
 fn get_local<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("SharedObject.getLocal not implemented");
+    let name = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?
+        .to_string();
+    let local_path = match args.get(1) {
+        None | Some(Value::Undefined) | Some(Value::Null) => None,
+        Some(local_path) => Some(local_path.coerce_to_string(activation)?.to_string()),
+    };
+    let secure = args
+        .get(2)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_boolean();
+
+    let movie = activation.caller_movie();
+    let full_name = local_shared_object_name(movie.url(), &name, local_path.as_deref(), secure)
+        .ok_or("Error: Error #2134: Cannot create SharedObject.")?;
+
+    // Each name refers to a single shared object for the lifetime of the player.
+    if let Some(shared_object) = activation.avm2().shared_objects.get(&full_name) {
+        return Ok((*shared_object).into());
+    }
+
     let class = activation.context.avm2.classes().sharedobject;
-    let new_shared_object = class.construct(activation, &[])?;
+    let mut shared_object = class.construct(activation, &[])?;
+    shared_object.set_property(
+        &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), "name").into(),
+        AvmString::new_utf8(activation.context.gc_context, &full_name).into(),
+        activation,
+    )?;
 
-    Ok(new_shared_object.into())
+    // Load the data object from storage if it existed prior
+    if let Some(saved) = activation.context.storage.get(&full_name) {
+        match amf::deserialize_lso(activation, &saved) {
+            Ok(data) => {
+                shared_object.set_property(&Multiname::public("data"), data.into(), activation)?
+            }
+            Err(e) => log::warn!("Unable to read SharedObject {}: {}", full_name, e),
+        }
+    }
+
+    activation
+        .avm2()
+        .shared_objects
+        .insert(full_name, shared_object);
+
+    Ok(shared_object.into())
 }
 
 fn flush<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        if !flush_to_storage(activation, this)? {
+            return Err("Error: Error #2130: Unable to flush SharedObject.".into());
+        }
+
+        return Ok("flushed".into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let data = data_object(activation, this)?;
+
+        let mut names = Vec::new();
+        let mut last_index = 0;
+        while let Some(index) = data.get_next_enumerant(last_index, activation)? {
+            if index == 0 {
+                break;
+            }
+
+            names.push(data.get_enumerant_name(index, activation)?);
+            last_index = index;
+        }
+
+        for name in names {
+            let name = name.coerce_to_string(activation)?;
+            data.delete_property(activation, &QName::dynamic_name(name).into())?;
+        }
+
+        let name = storage_name(activation, this)?;
+        activation.context.storage.remove_key(&name);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("SharedObject.flush not implemented");
+    // Local shared objects have no connection to close.
     Ok(Value::Undefined)
 }
 
@@ -79,10 +214,13 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         None,
     ));
 
+    write.define_private_slot_instance_traits(&[(NS_RUFFLE_INTERNAL, "name", "", "String")]);
+
     const PUBLIC_CLASS_METHODS: &[(&str, NativeMethodImpl)] = &[("getLocal", get_local)];
     write.define_public_builtin_class_methods(mc, PUBLIC_CLASS_METHODS);
 
-    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[("flush", flush)];
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("clear", clear), ("close", close), ("flush", flush)];
     write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
     class
 }
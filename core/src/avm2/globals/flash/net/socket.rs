@@ -0,0 +1,820 @@
+//! `flash.net.Socket` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::Endian;
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{socket_allocator, Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::socket::{SocketKind, SocketManager};
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use encoding_rs::Encoding;
+use encoding_rs::UTF_8;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.net.Socket`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if !matches!(
+            args.get(0),
+            None | Some(Value::Undefined) | Some(Value::Null)
+        ) {
+            connect_socket(activation, this, SocketKind::Binary, args)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.Socket`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Start connecting a `Socket` or `XMLSocket` to the host and port in `args`.
+///
+/// Any previous connection of the socket is closed first.
+pub fn connect_socket<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    kind: SocketKind,
+    args: &[Value<'gc>],
+) -> Result<(), Error> {
+    let host = match args.get(0) {
+        None | Some(Value::Undefined) | Some(Value::Null) => None,
+        Some(host) => Some(host.coerce_to_string(activation)?.to_string()),
+    };
+    let port = args
+        .get(1)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_i32(activation)?;
+    if !(1..=65535).contains(&port) {
+        return Err("SecurityError: Error #2003: Invalid socket port number specified.".into());
+    }
+
+    close_socket(activation, this);
+    let handle = SocketManager::connect(
+        &mut activation.context,
+        AvmObject::Avm2(this),
+        kind,
+        host.as_deref(),
+        port as u16,
+    );
+    if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+        socket.set_handle(Some(handle));
+    }
+
+    Ok(())
+}
+
+/// Close the connection of a `Socket` or `XMLSocket`, if it has one.
+pub fn close_socket<'gc>(activation: &mut Activation<'_, 'gc, '_>, this: Object<'gc>) {
+    let handle = this
+        .as_socket_mut(activation.context.gc_context)
+        .and_then(|mut socket| {
+            let handle = socket.handle();
+            socket.set_handle(None);
+            handle
+        });
+    if let Some(handle) = handle {
+        SocketManager::close(&mut activation.context, handle);
+    }
+}
+
+/// Whether a `Socket` or `XMLSocket` is connected.
+pub fn is_connected<'gc>(activation: &mut Activation<'_, 'gc, '_>, this: Object<'gc>) -> bool {
+    this.as_socket()
+        .and_then(|socket| socket.handle())
+        .map(|handle| activation.context.socket_manager.is_connected(handle))
+        .unwrap_or(false)
+}
+
+/// Fail with the error that Flash Player throws when reading from or
+/// writing to a socket that isn't connected.
+fn check_connected<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<(), Error> {
+    if is_connected(activation, this) {
+        Ok(())
+    } else {
+        Err("IOError: Error #2002: Operation attempted on invalid socket.".into())
+    }
+}
+
+/// Implements `Socket.connect`.
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        connect_socket(activation, this, SocketKind::Binary, args)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.close`.
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        close_socket(activation, this);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.flush`.
+pub fn flush<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let data = this
+            .as_socket_mut(activation.context.gc_context)
+            .map(|mut socket| (socket.handle(), socket.take_output()));
+        if let Some((Some(handle), data)) = data {
+            if !data.is_empty() {
+                SocketManager::send(&mut activation.context, handle, data);
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.connected`'s getter.
+pub fn connected<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(is_connected(activation, this).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.bytesAvailable`'s getter.
+pub fn bytes_available<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(socket) = this.as_ref().and_then(|this| this.as_socket()) {
+        return Ok(Value::Unsigned(socket.input().bytes_available() as u32));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.bytesPending`'s getter.
+pub fn bytes_pending<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(socket) = this.as_ref().and_then(|this| this.as_socket()) {
+        return Ok(Value::Unsigned(socket.bytes_pending() as u32));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.endian`'s getter.
+pub fn endian<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(socket) = this.as_ref().and_then(|this| this.as_socket()) {
+        return Ok(match socket.endian() {
+            Endian::Big => "bigEndian".into(),
+            Endian::Little => "littleEndian".into(),
+        });
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.endian`'s setter.
+pub fn set_endian<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let endian = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let endian = if &endian == b"bigEndian" {
+            Endian::Big
+        } else if &endian == b"littleEndian" {
+            Endian::Little
+        } else {
+            return Err("Parameter type must be one of the accepted values.".into());
+        };
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.set_endian(endian);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.timeout`'s getter.
+pub fn timeout<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(socket) = this.as_ref().and_then(|this| this.as_socket()) {
+        return Ok(Value::Unsigned(socket.timeout()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.timeout`'s setter.
+pub fn set_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let timeout = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.set_timeout(timeout);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_boolean<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Bool(socket.input().read_boolean()?));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_byte<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Integer(socket.input().read_byte()? as i32));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_unsigned_byte<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Unsigned(socket.input().read_unsigned_byte()? as u32));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_short<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Integer(socket.input().read_short()? as i32));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_unsigned_short<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Unsigned(socket.input().read_unsigned_short()? as u32));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_int<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Integer(socket.input().read_int()?));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_unsigned_int<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Unsigned(socket.input().read_unsigned_int()?));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_float<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Number(socket.input().read_float()? as f64));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_double<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(Value::Number(socket.input().read_double()?));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_utf<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        if let Some(socket) = this.as_socket() {
+            let string = socket.input().read_utf()?;
+            return Ok(AvmString::new_utf8(activation.context.gc_context, string).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_utf_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let len = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        if let Some(socket) = this.as_socket() {
+            return Ok(AvmString::new_utf8(
+                activation.context.gc_context,
+                String::from_utf8_lossy(socket.input().read_bytes(len as usize)?),
+            )
+            .into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn read_multibyte<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let len = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let charset_label = args
+            .get(1)
+            .unwrap_or(&"UTF-8".into())
+            .coerce_to_string(activation)?;
+        if let Some(socket) = this.as_socket() {
+            let bytes = socket.input().read_bytes(len as usize)?;
+            let encoder =
+                Encoding::for_label(charset_label.to_utf8_lossy().as_bytes()).unwrap_or(UTF_8);
+            let (decoded_str, _, _) = encoder.decode(bytes);
+            return Ok(AvmString::new_utf8(activation.context.gc_context, decoded_str).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.readBytes`, which reads into a `ByteArray`.
+pub fn read_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let bytearray = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let offset = args
+            .get(1)
+            .unwrap_or(&Value::Unsigned(0))
+            .coerce_to_u32(activation)? as usize;
+        let length = args
+            .get(2)
+            .unwrap_or(&Value::Unsigned(0))
+            .coerce_to_u32(activation)? as usize;
+
+        if let Some(socket) = this.as_socket() {
+            let to_write = socket.input().read_bytes(
+                // If length is 0, read all of the received bytes
+                if length != 0 {
+                    length
+                } else {
+                    socket.input().bytes_available()
+                },
+            )?;
+
+            let mut ba_write = bytearray
+                .as_bytearray_mut(activation.context.gc_context)
+                .ok_or("ArgumentError: Parameter must be a bytearray")?;
+
+            ba_write.write_at(to_write, offset)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_boolean<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let value = args.get(0).unwrap_or(&Value::Undefined).coerce_to_boolean();
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_boolean(value)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_byte<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let byte = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_bytes(&[byte as u8])?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_short<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let num = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_short(num as i16)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_int<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let num = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_i32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_int(num)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_unsigned_int<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let num = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_unsigned_int(num)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_float<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let num = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_number(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_float(num as f32)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_double<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let num = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_number(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_double(num)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_utf<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let string = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_utf(&string.to_utf8_lossy())?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_utf_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let string = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket
+                .output_mut()
+                .write_bytes(string.to_utf8_lossy().as_bytes())?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn write_multibyte<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let string = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let charset_label = args
+            .get(1)
+            .unwrap_or(&"UTF-8".into())
+            .coerce_to_string(activation)?;
+        let encoder =
+            Encoding::for_label(charset_label.to_utf8_lossy().as_bytes()).unwrap_or(UTF_8);
+        let utf8 = string.to_utf8_lossy();
+        let (encoded_bytes, _, _) = encoder.encode(&utf8);
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_bytes(&encoded_bytes)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.writeBytes`, which writes from a `ByteArray`.
+pub fn write_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        check_connected(activation, this)?;
+        let bytearray = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+        let offset = args
+            .get(1)
+            .unwrap_or(&Value::Unsigned(0))
+            .coerce_to_u32(activation)? as usize;
+        let length = args
+            .get(2)
+            .unwrap_or(&Value::Unsigned(0))
+            .coerce_to_u32(activation)? as usize;
+
+        let ba_read = bytearray
+            .as_bytearray()
+            .ok_or("ArgumentError: Parameter must be a bytearray")?;
+        let to_write = ba_read.read_at(
+            // If length is 0, write the remaining bytes of the ByteArray from the offset
+            if length != 0 {
+                length
+            } else {
+                ba_read.len().saturating_sub(offset)
+            },
+            offset,
+        )?;
+
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.output_mut().write_bytes(to_write)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `Socket`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "Socket"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<Socket instance initializer>", mc),
+        Method::from_builtin(class_init, "<Socket class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_instance_allocator(socket_allocator);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("bytesAvailable", Some(bytes_available), None),
+        ("bytesPending", Some(bytes_pending), None),
+        ("connected", Some(connected), None),
+        ("endian", Some(endian), Some(set_endian)),
+        ("timeout", Some(timeout), Some(set_timeout)),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("close", close),
+        ("connect", connect),
+        ("flush", flush),
+        ("readBoolean", read_boolean),
+        ("readByte", read_byte),
+        ("readBytes", read_bytes),
+        ("readDouble", read_double),
+        ("readFloat", read_float),
+        ("readInt", read_int),
+        ("readMultiByte", read_multibyte),
+        ("readShort", read_short),
+        ("readUnsignedByte", read_unsigned_byte),
+        ("readUnsignedInt", read_unsigned_int),
+        ("readUnsignedShort", read_unsigned_short),
+        ("readUTF", read_utf),
+        ("readUTFBytes", read_utf_bytes),
+        ("writeBoolean", write_boolean),
+        ("writeByte", write_byte),
+        ("writeBytes", write_bytes),
+        ("writeDouble", write_double),
+        ("writeFloat", write_float),
+        ("writeInt", write_int),
+        ("writeMultiByte", write_multibyte),
+        ("writeShort", write_short),
+        ("writeUnsignedInt", write_unsigned_int),
+        ("writeUTF", write_utf),
+        ("writeUTFBytes", write_utf_bytes),
+    ];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
@@ -0,0 +1,138 @@
+//! `flash.net.URLLoader` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::net::url_request::request_into_url_and_options;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::TObject;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::loader::DataFormat;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.net.URLLoader`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.URLLoader`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        this.set_property(&Multiname::public("dataFormat"), "text".into(), activation)?;
+
+        if let Some(Value::Object(request)) = args.get(0) {
+            spawn_fetch(activation, this, *request)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLLoader.load`
+pub fn load<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let request = match args.get(0) {
+            Some(Value::Object(request)) => *request,
+            _ => return Err("TypeError: Error #2007: Parameter request must be non-null.".into()),
+        };
+
+        spawn_fetch(activation, this, request)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLLoader.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.context.load_manager.cancel_url_loader(this);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Start loading a `URLRequest` into a `URLLoader`.
+fn spawn_fetch<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut loader_object: Object<'gc>,
+    request: Object<'gc>,
+) -> Result<(), Error> {
+    let data_format = loader_object
+        .get_property(&Multiname::public("dataFormat"), activation)?
+        .coerce_to_string(activation)?;
+    let data_format = if &data_format == b"binary" {
+        DataFormat::Binary
+    } else if &data_format == b"variables" {
+        DataFormat::Variables
+    } else {
+        DataFormat::Text
+    };
+
+    // Starting a new load abandons any previous one.
+    activation
+        .context
+        .load_manager
+        .cancel_url_loader(loader_object);
+
+    loader_object.set_property(&Multiname::public("bytesLoaded"), 0.into(), activation)?;
+    loader_object.set_property(&Multiname::public("bytesTotal"), 0.into(), activation)?;
+
+    let (url, request_options) = request_into_url_and_options(activation, request)?;
+    let future = activation.context.load_manager.load_data_into_url_loader(
+        activation.context.player.clone().unwrap(),
+        activation.caller_movie(),
+        loader_object,
+        &url,
+        request_options,
+        data_format,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(())
+}
+
+/// Construct `URLLoader`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "URLLoader"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<URLLoader instance initializer>", mc),
+        Method::from_builtin(class_init, "<URLLoader class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    const PUBLIC_INSTANCE_SLOTS: &[(&str, &str, &str)] = &[
+        ("data", "", "Object"),
+        ("dataFormat", "", "String"),
+        ("bytesLoaded", "", "uint"),
+        ("bytesTotal", "", "uint"),
+    ];
+    write.define_public_slot_instance_traits(PUBLIC_INSTANCE_SLOTS);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("load", load), ("close", close)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
@@ -3,9 +3,12 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
 use crate::avm2::method::Method;
-use crate::avm2::names::{Namespace, QName};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::TObject;
 use crate::avm2::value::Value;
+use crate::avm2::ArrayObject;
 use crate::avm2::{Error, Object};
+use crate::backend::navigator::{NavigationMethod, RequestOptions};
 use gc_arena::{GcCell, MutationContext};
 
 /// Implements `flash.net.URLRequest`'s class constructor.
@@ -19,13 +22,90 @@ pub fn class_init<'gc>(
 
 /// Implements `flash.net.URLRequest`'s instance constructor.
 pub fn instance_init<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let url = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Undefined | Value::Null => Value::Null,
+            url => url.coerce_to_string(activation)?.into(),
+        };
+        this.set_property(&Multiname::public("url"), url, activation)?;
+        this.set_property(&Multiname::public("method"), "GET".into(), activation)?;
+        this.set_property(
+            &Multiname::public("contentType"),
+            "application/x-www-form-urlencoded".into(),
+            activation,
+        )?;
+        this.set_property(
+            &Multiname::public("requestHeaders"),
+            ArrayObject::empty(activation)?.into(),
+            activation,
+        )?;
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Convert a `URLRequest` into the URL and request options to fetch it with.
+///
+/// `GET` requests have their data appended onto the URL's query string,
+/// while `POST` requests send it as the request body.
+pub fn request_into_url_and_options<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    request: Object<'gc>,
+) -> Result<(String, RequestOptions), Error> {
+    let url = request
+        .get_property(&Multiname::public("url"), activation)?
+        .coerce_to_string(activation)?
+        .to_string();
+    let method = request
+        .get_property(&Multiname::public("method"), activation)?
+        .coerce_to_string(activation)?;
+    let method = NavigationMethod::from_method_str(&method).unwrap_or(NavigationMethod::Get);
+
+    let data = request.get_property(&Multiname::public("data"), activation)?;
+    let body = match data {
+        Value::Undefined | Value::Null => None,
+        Value::Object(object) if object.as_bytearray().is_some() => {
+            Some(object.as_bytearray().unwrap().bytes().to_vec())
+        }
+        data => Some(
+            data.coerce_to_string(activation)?
+                .to_utf8_lossy()
+                .into_owned()
+                .into_bytes(),
+        ),
+    };
+
+    match (method, body) {
+        (NavigationMethod::Post, body) => {
+            let content_type = request
+                .get_property(&Multiname::public("contentType"), activation)?
+                .coerce_to_string(activation)?
+                .to_string();
+
+            Ok((
+                url,
+                RequestOptions::post(body.map(|body| (body, content_type))),
+            ))
+        }
+        (NavigationMethod::Get, Some(body)) if !body.is_empty() => {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            let query = String::from_utf8_lossy(&body);
+
+            Ok((
+                format!("{}{}{}", url, separator, query),
+                RequestOptions::get(),
+            ))
+        }
+        (NavigationMethod::Get, _) => Ok((url, RequestOptions::get())),
+    }
+}
+
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
         QName::new(Namespace::package("flash.net"), "URLRequest"),
@@ -38,5 +118,14 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
     let mut write = class.write(mc);
     write.set_attributes(ClassAttributes::FINAL | ClassAttributes::SEALED);
 
+    const PUBLIC_INSTANCE_SLOTS: &[(&str, &str, &str)] = &[
+        ("url", "", "String"),
+        ("method", "", "String"),
+        ("data", "", "Object"),
+        ("contentType", "", "String"),
+        ("requestHeaders", "", "Array"),
+    ];
+    write.define_public_slot_instance_traits(PUBLIC_INSTANCE_SLOTS);
+
     class
 }
@@ -0,0 +1,115 @@
+//! `flash.net.URLVariables` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::TObject;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::string::AvmString;
+use gc_arena::{GcCell, MutationContext};
+use url::form_urlencoded;
+
+/// Implements `flash.net.URLVariables`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.URLVariables`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        match args.get(0) {
+            None | Some(Value::Undefined) | Some(Value::Null) => {}
+            Some(_) => {
+                decode(activation, Some(this), args)?;
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLVariables.decode`
+pub fn decode<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        let source = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?
+            .to_utf8_lossy()
+            .into_owned();
+
+        if !source.is_empty() && !source.contains('=') {
+            return Err("Error #2101: The String passed to URLVariables.decode() must be a URL-encoded query string containing name/value pairs.".into());
+        }
+
+        for (name, value) in form_urlencoded::parse(source.as_bytes()) {
+            let name = AvmString::new_utf8(activation.context.gc_context, name);
+            let value = AvmString::new_utf8(activation.context.gc_context, value);
+            this.set_property(&Multiname::public(name), value.into(), activation)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `URLVariables.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let mut serializer = form_urlencoded::Serializer::new(String::new());
+
+    if let Some(this) = this {
+        for i in 1.. {
+            match this.get_enumerant_name(i, activation)? {
+                Value::Undefined => break,
+                name => {
+                    let name = name.coerce_to_string(activation)?;
+                    let value = this
+                        .get_property(&Multiname::public(name), activation)?
+                        .coerce_to_string(activation)?;
+                    serializer.append_pair(&name.to_utf8_lossy(), &value.to_utf8_lossy());
+                }
+            }
+        }
+    }
+
+    Ok(AvmString::new_utf8(activation.context.gc_context, serializer.finish()).into())
+}
+
+/// Construct `URLVariables`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "URLVariables"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(instance_init, "<URLVariables instance initializer>", mc),
+        Method::from_builtin(class_init, "<URLVariables class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("decode", decode), ("toString", to_string)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
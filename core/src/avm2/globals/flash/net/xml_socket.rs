@@ -0,0 +1,168 @@
+//! `flash.net.XMLSocket` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::globals::flash::net::socket::{close_socket, connect_socket, is_connected};
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{socket_allocator, Object, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::socket::{SocketKind, SocketManager};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.net.XMLSocket`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if !matches!(
+            args.get(0),
+            None | Some(Value::Undefined) | Some(Value::Null)
+        ) {
+            connect_socket(activation, this, SocketKind::Xml, args)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.net.XMLSocket`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.connect`.
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        connect_socket(activation, this, SocketKind::Xml, args)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.close`.
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        close_socket(activation, this);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.send`.
+///
+/// The object is sent as a string, followed by a null byte.
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let handle = match this.as_socket().and_then(|socket| socket.handle()) {
+            Some(handle) if activation.context.socket_manager.is_connected(handle) => handle,
+            _ => return Err("IOError: Error #2002: Operation attempted on invalid socket.".into()),
+        };
+
+        let message = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let mut data = message.to_utf8_lossy().into_owned().into_bytes();
+        data.push(0);
+        SocketManager::send(&mut activation.context, handle, data);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.connected`'s getter.
+pub fn connected<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(is_connected(activation, this).into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.timeout`'s getter.
+pub fn timeout<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(socket) = this.as_ref().and_then(|this| this.as_socket()) {
+        return Ok(Value::Unsigned(socket.timeout()));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XMLSocket.timeout`'s setter.
+pub fn set_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let timeout = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        if let Some(mut socket) = this.as_socket_mut(activation.context.gc_context) {
+            socket.set_timeout(timeout);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Construct `XMLSocket`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.net"), "XMLSocket"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<XMLSocket instance initializer>", mc),
+        Method::from_builtin(class_init, "<XMLSocket class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_instance_allocator(socket_allocator);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("connected", Some(connected), None),
+        ("timeout", Some(timeout), Some(set_timeout)),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("close", close), ("connect", connect), ("send", send)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
@@ -3,5 +3,6 @@
 
 pub mod application_domain;
 pub mod capabilities;
+pub mod loader_context;
 pub mod security;
 pub mod system;
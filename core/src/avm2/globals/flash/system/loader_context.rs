@@ -0,0 +1,84 @@
+//! `flash.system.LoaderContext` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::class::Class;
+use crate::avm2::method::Method;
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::TObject;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.system.LoaderContext`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.system.LoaderContext`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut this) = this {
+        activation.super_init(this, &[])?;
+
+        let check_policy_file = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(|| false.into())
+            .coerce_to_boolean();
+        let application_domain = args.get(1).cloned().unwrap_or(Value::Null);
+        let security_domain = args.get(2).cloned().unwrap_or(Value::Null);
+
+        this.set_property(
+            &Multiname::public("checkPolicyFile"),
+            check_policy_file.into(),
+            activation,
+        )?;
+        this.set_property(
+            &Multiname::public("applicationDomain"),
+            application_domain,
+            activation,
+        )?;
+        this.set_property(
+            &Multiname::public("securityDomain"),
+            security_domain,
+            activation,
+        )?;
+        this.set_property(
+            &Multiname::public("allowCodeImport"),
+            true.into(),
+            activation,
+        )?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.system"), "LoaderContext"),
+        Some(QName::new(Namespace::public(), "Object").into()),
+        Method::from_builtin(instance_init, "<LoaderContext instance initializer>", mc),
+        Method::from_builtin(class_init, "<LoaderContext class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+
+    const PUBLIC_INSTANCE_SLOTS: &[(&str, &str, &str)] = &[
+        ("allowCodeImport", "", "Boolean"),
+        ("applicationDomain", "flash.system", "ApplicationDomain"),
+        ("checkPolicyFile", "", "Boolean"),
+        ("parameters", "", "Object"),
+        ("securityDomain", "", "Object"),
+    ];
+    write.define_public_slot_instance_traits(PUBLIC_INSTANCE_SLOTS);
+
+    class
+}
@@ -31,34 +31,61 @@ fn sandbox_type<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    let sandbox_type = activation.context.system.sandbox_type.to_string();
+    let movie = activation.caller_movie();
+    let sandbox_type = activation.context.security.sandbox_type(&movie).to_string();
     return Ok(AvmString::new_utf8(activation.context.gc_context, sandbox_type).into());
 }
 
+/// Grant movies from the given domains access to the calling movie.
+fn grant_domains<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    allow_insecure: bool,
+) -> Result<(), Error> {
+    let movie = activation.caller_movie();
+    for domain in args {
+        let domain = domain.coerce_to_string(activation)?;
+        activation
+            .context
+            .security
+            .allow_domain(&movie, &domain.to_utf8_lossy(), allow_insecure);
+    }
+
+    Ok(())
+}
+
 fn allow_domain<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("Security.allowDomain not implemented");
+    grant_domains(activation, args, false)?;
     Ok(Value::Undefined)
 }
 
 fn allow_insecure_domain<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("Security.allowInsecureDomain not implemented");
+    grant_domains(activation, args, true)?;
     Ok(Value::Undefined)
 }
 
 fn load_policy_file<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    log::warn!("Security.loadPolicyFile not implemented");
+    let url = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_string(activation)?
+        .to_utf8_lossy()
+        .into_owned();
+    let url = activation.context.navigator.resolve_relative_url(&url);
+    activation.context.security.load_policy_file(&url);
     Ok(Value::Undefined)
 }
 
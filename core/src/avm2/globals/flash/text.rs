@@ -1,6 +1,7 @@
 //! `flash.text` namespace
 
 pub mod font;
+pub mod stylesheet;
 pub mod textfield;
 pub mod textfieldautosize;
 pub mod textfieldtype;
@@ -0,0 +1,220 @@
+//! `flash.text.StyleSheet` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::class::Class;
+use crate::avm2::method::{Method, NativeMethodImpl};
+use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{stylesheet_allocator, ArrayObject, Object, TObject, TextFormatObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::html::{Style, StyleSheet};
+use crate::string::AvmString;
+use gc_arena::{GcCell, MutationContext};
+
+/// Implements `flash.text.StyleSheet`'s instance constructor.
+pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.text.StyleSheet`'s class constructor.
+pub fn class_init<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(Value::Undefined)
+}
+
+/// Read the dynamic properties of an object as the declarations of a style.
+fn object_to_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    object: Object<'gc>,
+) -> Result<Style, Error> {
+    let mut style = Style::new();
+    let mut last_index = 0;
+    while let Some(index) = object.get_next_enumerant(last_index, activation)? {
+        if index == 0 {
+            break;
+        }
+
+        let name = object
+            .get_enumerant_name(index, activation)?
+            .coerce_to_string(activation)?;
+        let value = object
+            .get_enumerant_value(index, activation)?
+            .coerce_to_string(activation)?;
+        style.push((name.as_wstr().into(), value.as_wstr().into()));
+        last_index = index;
+    }
+
+    Ok(style)
+}
+
+/// Implements `StyleSheet.styleNames`'s getter.
+pub fn style_names<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(style_sheet) = this.as_ref().and_then(|this| this.as_style_sheet()) {
+        let names: Vec<Value<'gc>> = style_sheet
+            .style_names()
+            .map(|name| AvmString::new(activation.context.gc_context, name).into())
+            .collect();
+        drop(style_sheet);
+        let storage = ArrayStorage::from_args(&names);
+        return Ok(ArrayObject::from_storage(activation, storage)?.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `StyleSheet.clear`.
+pub fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(mut style_sheet) =
+        this.and_then(|this| this.as_style_sheet_mut(activation.context.gc_context))
+    {
+        style_sheet.clear();
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `StyleSheet.getStyle`.
+pub fn get_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let style = match this.as_style_sheet().and_then(|s| s.style(&name).cloned()) {
+            Some(style) => style,
+            None => return Ok(Value::Null),
+        };
+
+        // A copy of the style is returned, so changing it doesn't change the style sheet.
+        let mut object = activation
+            .avm2()
+            .classes()
+            .object
+            .construct(activation, &[])?;
+        for (name, value) in style {
+            let name = AvmString::new(activation.context.gc_context, name);
+            let value = AvmString::new(activation.context.gc_context, value);
+            object.set_property(&QName::dynamic_name(name).into(), value.into(), activation)?;
+        }
+        return Ok(object.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `StyleSheet.parseCSS`.
+pub fn parse_css<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let css = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        if let Some(mut style_sheet) = this.as_style_sheet_mut(activation.context.gc_context) {
+            // Malformed CSS is silently ignored.
+            style_sheet.parse_css(&css);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `StyleSheet.setStyle`.
+pub fn set_style<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let style = match args.get(1) {
+            Some(Value::Object(object)) => Some(object_to_style(activation, *object)?),
+            _ => None,
+        };
+        if let Some(mut style_sheet) = this.as_style_sheet_mut(activation.context.gc_context) {
+            style_sheet.set_style(&name, style);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `StyleSheet.transform`.
+pub fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(Value::Object(object)) = args.get(0) {
+        let style = object_to_style(activation, *object)?;
+        let text_format = StyleSheet::transform(&style);
+        return Ok(TextFormatObject::from_text_format(activation, text_format)?.into());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Construct `StyleSheet`'s class.
+pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
+    let class = Class::new(
+        QName::new(Namespace::package("flash.text"), "StyleSheet"),
+        Some(QName::new(Namespace::package("flash.events"), "EventDispatcher").into()),
+        Method::from_builtin(instance_init, "<StyleSheet instance initializer>", mc),
+        Method::from_builtin(class_init, "<StyleSheet class initializer>", mc),
+        mc,
+    );
+
+    let mut write = class.write(mc);
+    write.set_instance_allocator(stylesheet_allocator);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("styleNames", Some(style_names), None)];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("clear", clear),
+        ("getStyle", get_style),
+        ("parseCSS", parse_css),
+        ("setStyle", set_style),
+        ("transform", transform),
+    ];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
+    class
+}
@@ -278,6 +278,120 @@ pub fn set_display_as_password<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn max_chars<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        return Ok(this.max_chars().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_max_chars<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        let max_chars = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        this.set_max_chars(max_chars, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn restrict<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        return match this.restrict() {
+            Some(restrict) => Ok(AvmString::new(activation.context.gc_context, restrict).into()),
+            None => Ok(Value::Null),
+        };
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_restrict<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        let restrict = match args.get(0).cloned().unwrap_or(Value::Null) {
+            Value::Undefined | Value::Null => None,
+            value => Some(value.coerce_to_string(activation)?),
+        };
+
+        this.set_restrict(restrict.as_deref(), &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn style_sheet<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        return Ok(this
+            .style_sheet()
+            .and_then(|object| object.as_avm2_object().ok())
+            .map_or(Value::Null, Value::Object));
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn set_style_sheet<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        let style_sheet = match args.get(0) {
+            Some(Value::Object(object)) => object
+                .as_style_sheet()
+                .map(|style_sheet| ((*object).into(), style_sheet.clone())),
+            _ => None,
+        };
+
+        this.set_style_sheet(style_sheet, &mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn embed_fonts<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
     this: Option<Object<'gc>>,
@@ -751,13 +865,10 @@ pub fn replace_text<'gc>(
             .cloned()
             .unwrap_or(Value::Undefined)
             .coerce_to_string(activation)?;
+        let (begin_index, end_index) = (begin_index as usize, end_index as usize);
+        let value = this.restrict_text(begin_index, end_index, &value);
 
-        this.replace_text(
-            begin_index as usize,
-            end_index as usize,
-            &value,
-            &mut activation.context,
-        );
+        this.replace_text(begin_index, end_index, &value, &mut activation.context);
     }
 
     Ok(Value::Undefined)
@@ -886,8 +997,11 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("embedFonts", Some(embed_fonts), Some(set_embed_fonts)),
         ("htmlText", Some(html_text), Some(set_html_text)),
         ("length", Some(length), None),
+        ("maxChars", Some(max_chars), Some(set_max_chars)),
         ("multiline", Some(multiline), Some(set_multiline)),
+        ("restrict", Some(restrict), Some(set_restrict)),
         ("selectable", Some(selectable), Some(set_selectable)),
+        ("styleSheet", Some(style_sheet), Some(set_style_sheet)),
         ("text", Some(text), Some(set_text)),
         ("textColor", Some(text_color), Some(set_text_color)),
         ("textHeight", Some(text_height), None),
@@ -1,5 +1,6 @@
 //! `flash.utils` namespace
 
+use crate::avm1::timer::TimerCallback;
 use crate::avm2::object::TObject;
 use crate::avm2::QName;
 use crate::avm2::{Activation, Error, Object, Value};
@@ -9,6 +10,7 @@ pub mod bytearray;
 pub mod compression_algorithm;
 pub mod dictionary;
 pub mod endian;
+pub mod iexternalizable;
 pub mod proxy;
 pub mod timer;
 
@@ -99,3 +101,86 @@ pub fn get_definition_by_name<'gc>(
     let qname = QName::from_qualified_name(name, activation.context.gc_context);
     appdomain.get_defined_value(activation, qname)
 }
+
+/// Register a timer that calls a closure, as used by `setInterval` and
+/// `setTimeout`.
+fn create_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+    is_timeout: bool,
+) -> Result<Value<'gc>, Error> {
+    let closure = match args.get(0) {
+        Some(Value::Object(closure)) if closure.as_executable().is_some() => *closure,
+        _ => {
+            return Err(
+                "TypeError: Error #1034: Type Coercion failed: closure must be a Function.".into(),
+            )
+        }
+    };
+
+    let delay = args
+        .get(1)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_number(activation)?;
+    let params = args.get(2..).unwrap_or_default().to_vec();
+
+    let id = activation.context.timers.add_timer(
+        TimerCallback::Avm2Callback { closure, params },
+        delay as i32,
+        is_timeout,
+    );
+
+    Ok((id as u32).into())
+}
+
+/// Remove a timer created by `setInterval` or `setTimeout`.
+fn remove_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let id = args
+        .get(0)
+        .cloned()
+        .unwrap_or(Value::Undefined)
+        .coerce_to_u32(activation)?;
+    activation.context.timers.remove(id as i32);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `flash.utils.setInterval`
+pub fn set_interval<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    create_timer(activation, args, false)
+}
+
+/// Implements `flash.utils.clearInterval`
+pub fn clear_interval<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    remove_timer(activation, args)
+}
+
+/// Implements `flash.utils.setTimeout`
+pub fn set_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    create_timer(activation, args, true)
+}
+
+/// Implements `flash.utils.clearTimeout`
+pub fn clear_timeout<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    remove_timer(activation, args)
+}
@@ -8,6 +8,16 @@ use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::{GcCell, MutationContext};
 
+/// Construct the error thrown when a subclass fails to override one of the
+/// `flash_proxy` methods.
+fn not_overridden(code: u32, method: &str) -> Error {
+    format!(
+        "IllegalOperationError: Error #{}: The Proxy class does not implement {}. It must be overridden by a subclass.",
+        code, method
+    )
+    .into()
+}
+
 /// Implements `flash.utils.Proxy`'s instance constructor.
 pub fn instance_init<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -32,7 +42,7 @@ pub fn get_property<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("getproperty is not implemented for this Proxy".into())
+    Err(not_overridden(2088, "getProperty"))
 }
 
 /// Implements `Proxy.setProperty`
@@ -41,7 +51,7 @@ pub fn set_property<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("setproperty is not implemented for this Proxy".into())
+    Err(not_overridden(2089, "setProperty"))
 }
 
 /// Implements `Proxy.deleteProperty`
@@ -50,7 +60,7 @@ pub fn delete_property<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("deleteproperty is not implemented for this Proxy".into())
+    Err(not_overridden(2092, "deleteProperty"))
 }
 
 /// Implements `Proxy.callProperty`
@@ -59,7 +69,7 @@ pub fn call_property<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("callproperty is not implemented for this Proxy".into())
+    Err(not_overridden(2090, "callProperty"))
 }
 
 /// Implements `Proxy.hasProperty`
@@ -68,7 +78,7 @@ pub fn has_property<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("hasproperty is not implemented for this Proxy".into())
+    Err(not_overridden(2091, "hasProperty"))
 }
 
 /// Implements `Proxy.isAttribute`
@@ -77,7 +87,9 @@ pub fn is_attribute<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("isattribute is not implemented for this Proxy".into())
+    // Names handed to the `flash_proxy` methods don't retain whether they
+    // were accessed as attributes.
+    Ok(false.into())
 }
 
 /// Implements `Proxy.getDescendants`
@@ -86,7 +98,7 @@ pub fn get_descendants<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("getdescendants is not implemented for this Proxy".into())
+    Err(not_overridden(2093, "getDescendants"))
 }
 
 /// Implements `Proxy.nextNameIndex`
@@ -95,7 +107,7 @@ pub fn next_name_index<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("hasnext/nextNameIndex is not implemented for this Proxy".into())
+    Err(not_overridden(2105, "nextNameIndex"))
 }
 
 /// Implements `Proxy.nextName`
@@ -104,7 +116,7 @@ pub fn next_name<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("nextname is not implemented for this Proxy".into())
+    Err(not_overridden(2106, "nextName"))
 }
 
 /// Implements `Proxy.nextValue`
@@ -113,7 +125,7 @@ pub fn next_value<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("nextvalue is not implemented for this Proxy".into())
+    Err(not_overridden(2107, "nextValue"))
 }
 
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
@@ -1,13 +1,60 @@
 //! `flash.utils.Timer` builtin/prototype
 
+use crate::avm1::timer::TimerCallback;
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
-use crate::avm2::method::Method;
+use crate::avm2::events::{dispatch_event, Event, EventData};
+use crate::avm2::globals::NS_RUFFLE_INTERNAL;
+use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
+use crate::avm2::object::{EventObject, FunctionObject, TObject};
+use crate::avm2::scope::ScopeChain;
 use crate::avm2::value::Value;
 use crate::avm2::{Error, Object};
 use gc_arena::{GcCell, MutationContext};
 
+/// The value of the `timerId` slot while a `Timer` is not running.
+const NOT_RUNNING: i32 = -1;
+
+/// Read one of a `Timer`'s private slots.
+fn get_slot<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+    name: &'static str,
+) -> Result<Value<'gc>, Error> {
+    this.get_property(
+        &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), name).into(),
+        activation,
+    )
+}
+
+/// Write one of a `Timer`'s private slots.
+fn set_slot<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    mut this: Object<'gc>,
+    name: &'static str,
+    value: Value<'gc>,
+) -> Result<(), Error> {
+    this.set_property(
+        &QName::new(Namespace::private(NS_RUFFLE_INTERNAL), name).into(),
+        value,
+        activation,
+    )
+}
+
+/// Coerce a `Timer` delay, throwing if it is out of range.
+fn coerce_delay<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<f64, Error> {
+    let delay = value.coerce_to_number(activation)?;
+    if delay < 0.0 || !delay.is_finite() {
+        return Err("RangeError: Error #2066: The Timer delay specified is out of range.".into());
+    }
+
+    Ok(delay)
+}
+
 /// Implements `flash.utils.Timer`'s class constructor.
 pub fn class_init<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
@@ -19,10 +66,249 @@ pub fn class_init<'gc>(
 
 /// Implements `flash.utils.Timer`'s instance constructor.
 pub fn instance_init<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        let delay = coerce_delay(activation, args.get(0).cloned().unwrap_or(Value::Undefined))?;
+        let repeat_count = args
+            .get(1)
+            .cloned()
+            .unwrap_or_else(|| 0.into())
+            .coerce_to_i32(activation)?;
+
+        set_slot(activation, this, "delay", delay.into())?;
+        set_slot(activation, this, "repeatCount", repeat_count.into())?;
+        set_slot(activation, this, "currentCount", 0.into())?;
+        set_slot(activation, this, "timerId", NOT_RUNNING.into())?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Called by the player's timer list every time a running `Timer` ticks.
+fn on_tick<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let current_count =
+            get_slot(activation, this, "currentCount")?.coerce_to_i32(activation)?;
+        let current_count = current_count.wrapping_add(1);
+        set_slot(activation, this, "currentCount", current_count.into())?;
+
+        let timer_evt = EventObject::from_event(activation, Event::new("timer", EventData::Timer))?;
+        dispatch_event(activation, this, timer_evt)?;
+
+        // Event handlers may have stopped or reconfigured the timer.
+        let current_count =
+            get_slot(activation, this, "currentCount")?.coerce_to_i32(activation)?;
+        let repeat_count = get_slot(activation, this, "repeatCount")?.coerce_to_i32(activation)?;
+        if is_running(activation, this)? && repeat_count != 0 && current_count >= repeat_count {
+            stop_timer(activation, this)?;
+
+            let complete_evt =
+                EventObject::from_event(activation, Event::new("timerComplete", EventData::Timer))?;
+            dispatch_event(activation, this, complete_evt)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Check if a `Timer` is currently registered with the player.
+fn is_running<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<bool, Error> {
+    let id = get_slot(activation, this, "timerId")?.coerce_to_i32(activation)?;
+    Ok(id != NOT_RUNNING)
+}
+
+/// Register a `Timer` with the player, if it is not already running.
+fn start_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<(), Error> {
+    if is_running(activation, this)? {
+        return Ok(());
+    }
+
+    let delay = get_slot(activation, this, "delay")?.coerce_to_number(activation)?;
+    let method = Method::from_builtin(
+        on_tick,
+        "<Timer tick handler>",
+        activation.context.gc_context,
+    );
+    let scope = ScopeChain::new(activation.context.avm2.globals);
+    let on_tick = FunctionObject::from_method(activation, method, scope, Some(this), None);
+
+    let id = activation.context.timers.add_timer(
+        TimerCallback::Avm2Callback {
+            closure: on_tick.into(),
+            params: vec![],
+        },
+        delay as i32,
+        false,
+    );
+    set_slot(activation, this, "timerId", id.into())
+}
+
+/// Unregister a `Timer` from the player, if it is running.
+fn stop_timer<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Object<'gc>,
+) -> Result<(), Error> {
+    let id = get_slot(activation, this, "timerId")?.coerce_to_i32(activation)?;
+    if id != NOT_RUNNING {
+        activation.context.timers.remove(id);
+        set_slot(activation, this, "timerId", NOT_RUNNING.into())?;
+    }
+
+    Ok(())
+}
+
+/// Implements `Timer.start`
+pub fn start<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        start_timer(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.stop`
+pub fn stop<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        stop_timer(activation, this)?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.reset`
+pub fn reset<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        stop_timer(activation, this)?;
+        set_slot(activation, this, "currentCount", 0.into())?;
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.currentCount`'s getter.
+pub fn current_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return get_slot(activation, this, "currentCount");
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.delay`'s getter.
+pub fn delay<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return get_slot(activation, this, "delay");
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.delay`'s setter.
+///
+/// Changing the delay of a running timer restarts it with the new delay.
+pub fn set_delay<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let delay = coerce_delay(activation, args.get(0).cloned().unwrap_or(Value::Undefined))?;
+        set_slot(activation, this, "delay", delay.into())?;
+
+        if is_running(activation, this)? {
+            stop_timer(activation, this)?;
+            start_timer(activation, this)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.repeatCount`'s getter.
+pub fn repeat_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return get_slot(activation, this, "repeatCount");
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.repeatCount`'s setter.
+///
+/// A running timer stops immediately if it has already reached its new
+/// repeat count.
+pub fn set_repeat_count<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let repeat_count = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        set_slot(activation, this, "repeatCount", repeat_count.into())?;
+
+        let current_count =
+            get_slot(activation, this, "currentCount")?.coerce_to_i32(activation)?;
+        if repeat_count != 0 && current_count >= repeat_count {
+            stop_timer(activation, this)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Timer.running`'s getter.
+pub fn running<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        return Ok(is_running(activation, this)?.into());
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -35,5 +321,30 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         mc,
     );
 
+    let mut write = class.write(mc);
+
+    write.define_private_slot_instance_traits(&[
+        (NS_RUFFLE_INTERNAL, "delay", "", "Number"),
+        (NS_RUFFLE_INTERNAL, "repeatCount", "", "int"),
+        (NS_RUFFLE_INTERNAL, "currentCount", "", "int"),
+        (NS_RUFFLE_INTERNAL, "timerId", "", "int"),
+    ]);
+
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[
+        ("currentCount", Some(current_count), None),
+        ("delay", Some(delay), Some(set_delay)),
+        ("repeatCount", Some(repeat_count), Some(set_repeat_count)),
+        ("running", Some(running), None),
+    ];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
+    const PUBLIC_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] =
+        &[("reset", reset), ("start", start), ("stop", stop)];
+    write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
+
     class
 }
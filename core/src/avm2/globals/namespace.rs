@@ -2,28 +2,102 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
-use crate::avm2::method::Method;
+use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{namespace_allocator, Object};
+use crate::avm2::object::{namespace_allocator, Object, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
+use crate::string::AvmString;
 use gc_arena::{GcCell, MutationContext};
 
 /// Implements `Namespace`'s instance initializer.
+///
+/// A namespace is constructed from a URI, optionally preceded by the prefix
+/// that is bound to it.
 pub fn instance_init<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("Namespace constructor is a stub.".into())
+    if let Some(this) = this.and_then(|this| this.as_namespace_object()) {
+        let (prefix, uri) = match args {
+            [] => (Some("".into()), "".into()),
+            [Value::Object(o)] if o.as_namespace_object().is_some() => {
+                let namespace = o.as_namespace_object().unwrap();
+                (namespace.prefix(), namespace.namespace().as_uri())
+            }
+            [value] => {
+                let uri = value_to_uri(activation, *value)?;
+                // Only the empty namespace gets a prefix by default.
+                let prefix = if uri.is_empty() {
+                    Some("".into())
+                } else {
+                    None
+                };
+                (prefix, uri)
+            }
+            [prefix, uri, ..] => {
+                let uri = value_to_uri(activation, *uri)?;
+                let prefix = match prefix {
+                    Value::Undefined => None,
+                    prefix => Some(prefix.coerce_to_string(activation)?),
+                };
+
+                match prefix {
+                    Some(prefix) if uri.is_empty() && !prefix.is_empty() => {
+                        return Err(format!(
+                            "TypeError: Error #1098: Illegal prefix {} for no namespace.",
+                            prefix
+                        )
+                        .into());
+                    }
+                    prefix => (prefix, uri),
+                }
+            }
+        };
+
+        this.init_namespace(
+            activation.context.gc_context,
+            prefix,
+            Namespace::Namespace(uri),
+        );
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Convert a value passed to the `Namespace` constructor into a URI.
+///
+/// The URI of a `QName` is used as-is.
+fn value_to_uri<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<AvmString<'gc>, Error> {
+    let qname = value
+        .as_object()
+        .and_then(|o| o.as_qname_object())
+        .and_then(|q| q.qname().map(|q| *q));
+
+    match qname {
+        Some(qname) => Ok(qname.namespace().as_uri()),
+        None => value.coerce_to_string(activation),
+    }
+}
+
+/// Implements `Namespace`'s class call handler.
 fn class_call<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
+    activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
-    Err("Namespace constructor is a stub.".into())
+    if let [Value::Object(o)] = args {
+        if o.as_namespace_object().is_some() {
+            return Ok((*o).into());
+        }
+    }
+
+    let namespace_class = activation.avm2().classes().namespace;
+    Ok(namespace_class.construct(activation, args)?.into())
 }
 
 /// Implements `Namespace`'s native instance initializer.
@@ -48,6 +122,34 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Namespace.prefix`'s getter
+pub fn prefix<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this.and_then(|this| this.as_namespace_object()) {
+        if let Some(prefix) = this.prefix() {
+            return Ok(prefix.into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Namespace.uri`'s getter
+pub fn uri<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this.and_then(|this| this.as_namespace_object()) {
+        return Ok(this.namespace().as_uri().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Construct `Namespace`'s class.
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
@@ -71,5 +173,12 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         mc,
     ));
 
+    const PUBLIC_INSTANCE_PROPERTIES: &[(
+        &str,
+        Option<NativeMethodImpl>,
+        Option<NativeMethodImpl>,
+    )] = &[("prefix", Some(prefix), None), ("uri", Some(uri), None)];
+    write.define_public_builtin_instance_properties(mc, PUBLIC_INSTANCE_PROPERTIES);
+
     class
 }
@@ -200,6 +200,59 @@ pub fn source<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Create the result array of a successful match.
+///
+/// The array holds the matched substring followed by the value of each
+/// capture group, and has `index` and `input` properties as well as one
+/// property for each named group.
+pub fn match_result<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    text: AvmString<'gc>,
+    matched: &regress::Match,
+) -> Result<Object<'gc>, Error> {
+    let substrings = matched
+        .groups()
+        .map(|range| range.map(|r| WString::from(&text[r])));
+
+    let storage = ArrayStorage::from_iter(substrings.map(|s| match s {
+        None => Value::Undefined,
+        Some(s) => AvmString::new(activation.context.gc_context, s).into(),
+    }));
+
+    let object = ArrayObject::from_storage(activation, storage)?;
+
+    for (name, range) in matched.named_groups() {
+        let value = match range {
+            None => Value::Undefined,
+            Some(range) => AvmString::new(activation.context.gc_context, &text[range]).into(),
+        };
+
+        object.set_property_local(
+            &QName::new(
+                Namespace::public(),
+                AvmString::new_utf8(activation.context.gc_context, name),
+            )
+            .into(),
+            value,
+            activation,
+        )?;
+    }
+
+    object.set_property_local(
+        &QName::new(Namespace::public(), "index").into(),
+        Value::Number(matched.start() as f64),
+        activation,
+    )?;
+
+    object.set_property_local(
+        &QName::new(Namespace::public(), "input").into(),
+        text.into(),
+        activation,
+    )?;
+
+    Ok(object)
+}
+
 /// Implements `RegExp.exec`
 pub fn exec<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -207,44 +260,20 @@ pub fn exec<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
     if let Some(this) = this {
-        if let Some(mut re) = this.as_regexp_mut(activation.context.gc_context) {
-            let text = args
-                .get(0)
-                .unwrap_or(&Value::Undefined)
-                .coerce_to_string(activation)?;
-
-            let (storage, index) = match re.exec(text) {
-                Some(matched) => {
-                    let substrings = matched
-                        .groups()
-                        .map(|range| range.map(|r| WString::from(&text[r])));
-
-                    let storage = ArrayStorage::from_iter(substrings.map(|s| match s {
-                        None => Value::Undefined,
-                        Some(s) => AvmString::new(activation.context.gc_context, s).into(),
-                    }));
-
-                    (storage, matched.start())
-                }
-                None => return Ok(Value::Null),
-            };
-
-            let object = ArrayObject::from_storage(activation, storage)?;
-
-            object.set_property_local(
-                &QName::new(Namespace::public(), "index").into(),
-                Value::Number(index as f64),
-                activation,
-            )?;
-
-            object.set_property_local(
-                &QName::new(Namespace::public(), "input").into(),
-                text.into(),
-                activation,
-            )?;
-
-            return Ok(object.into());
-        }
+        let text = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        let matched = match this.as_regexp_mut(activation.context.gc_context) {
+            Some(mut re) => re.exec(text),
+            None => return Ok(Value::Undefined),
+        };
+
+        return match matched {
+            Some(matched) => Ok(match_result(activation, text, &matched)?.into()),
+            None => Ok(Value::Null),
+        };
     }
 
     Ok(Value::Undefined)
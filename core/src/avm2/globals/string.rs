@@ -2,6 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::globals::regexp::match_result;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::names::{Namespace, QName};
 use crate::avm2::object::{primitive_allocator, Object, TObject};
@@ -9,9 +10,10 @@ use crate::avm2::regexp::RegExpFlags;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::avm2::{ArrayObject, ArrayStorage};
-use crate::string::{AvmString, WString};
+use crate::string::{AvmString, WStr, WString};
 use gc_arena::{GcCell, MutationContext};
 use std::iter;
+use std::ops::Range;
 
 /// Implements `String`'s instance initializer.
 pub fn instance_init<'gc>(
@@ -212,6 +214,21 @@ fn last_index_of<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Convert the pattern argument of `String.match` or `String.search` into a
+/// `RegExp`.
+fn pattern_to_regexp<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    pattern: &Value<'gc>,
+) -> Result<Object<'gc>, Error> {
+    let regexp_class = activation.avm2().classes().regexp;
+    if !pattern.is_of_type(activation, regexp_class)? {
+        let string = pattern.coerce_to_string(activation)?;
+        regexp_class.construct(activation, &[Value::String(string)])
+    } else {
+        pattern.coerce_to_object(activation)
+    }
+}
+
 /// Implements `String.match`
 fn match_s<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -220,14 +237,7 @@ fn match_s<'gc>(
 ) -> Result<Value<'gc>, Error> {
     if let (Some(this), pattern) = (this, args.get(0).unwrap_or(&Value::Undefined)) {
         let this = Value::from(this).coerce_to_string(activation)?;
-
-        let regexp_class = activation.avm2().classes().regexp;
-        let pattern = if !pattern.is_of_type(activation, regexp_class)? {
-            let string = pattern.coerce_to_string(activation)?;
-            regexp_class.construct(activation, &[Value::String(string)])?
-        } else {
-            pattern.coerce_to_object(activation)?
-        };
+        let pattern = pattern_to_regexp(activation, pattern)?;
 
         if let Some(mut regexp) = pattern.as_regexp_mut(activation.context.gc_context) {
             let mut storage = ArrayStorage::new(0);
@@ -248,30 +258,21 @@ fn match_s<'gc>(
                 if old_last_index == regexp.last_index() {
                     regexp.set_last_index(1);
                 }
+                drop(regexp);
                 return Ok(ArrayObject::from_storage(activation, storage)
                     .unwrap()
                     .into());
-            } else {
-                let old = regexp.last_index();
-                regexp.set_last_index(0);
-                if let Some(result) = regexp.exec(this) {
-                    let substrings = result.groups().map(|range| &this[range.unwrap_or(0..0)]);
+            }
 
-                    let mut storage = ArrayStorage::new(0);
-                    for substring in substrings {
-                        storage
-                            .push(AvmString::new(activation.context.gc_context, substring).into());
-                    }
-                    regexp.set_last_index(old);
-                    return Ok(ArrayObject::from_storage(activation, storage)
-                        .unwrap()
-                        .into());
-                } else {
-                    regexp.set_last_index(old);
-                    // If the pattern parameter is a String or a non-global regular expression
-                    // and no match is found, the method returns null
-                    return Ok(Value::Null);
-                }
+            // A non-global match ignores and keeps `lastIndex`.
+            let result = regexp.find_at(this, 0);
+            drop(regexp);
+
+            // If the pattern parameter is a String or a non-global regular expression
+            // and no match is found, the method returns null
+            return match result {
+                Some(result) => Ok(match_result(activation, this, &result)?.into()),
+                None => Ok(Value::Null),
             };
         };
     }
@@ -279,6 +280,178 @@ fn match_s<'gc>(
     Ok(Value::Null)
 }
 
+/// A match of the pattern of `String.replace` or `String.split`.
+///
+/// All ranges are UTF-16 indices into the searched string.
+struct PatternMatch {
+    range: Range<usize>,
+
+    /// The range of each capture group that took part in the match.
+    captures: Vec<Option<Range<usize>>>,
+
+    /// The name and range of each named capture group.
+    named_groups: Vec<(String, Option<Range<usize>>)>,
+}
+
+impl PatternMatch {
+    /// A match of a plain string pattern, which has no capture groups.
+    fn literal(range: Range<usize>) -> Self {
+        Self {
+            range,
+            captures: Vec::new(),
+            named_groups: Vec::new(),
+        }
+    }
+}
+
+impl From<regress::Match> for PatternMatch {
+    fn from(matched: regress::Match) -> Self {
+        let named_groups = matched
+            .named_groups()
+            .map(|(name, range)| (name.to_string(), range))
+            .collect();
+
+        Self {
+            range: matched.range,
+            captures: matched.captures,
+            named_groups,
+        }
+    }
+}
+
+/// The replacement argument of `String.replace`.
+enum Replacement<'gc> {
+    /// A function called with each match, returning its replacement.
+    Function(Object<'gc>),
+
+    /// A string that may contain `$` substitution patterns.
+    String(AvmString<'gc>),
+}
+
+impl<'gc> Replacement<'gc> {
+    /// Compute the replacement text of a match.
+    fn replace(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        text: AvmString<'gc>,
+        matched: &PatternMatch,
+    ) -> Result<WString, Error> {
+        match self {
+            Replacement::Function(function) => {
+                let mc = activation.context.gc_context;
+                let mut args = Vec::with_capacity(matched.captures.len() + 3);
+                args.push(AvmString::new(mc, &text[matched.range.clone()]).into());
+                for capture in &matched.captures {
+                    args.push(match capture {
+                        Some(range) => AvmString::new(mc, &text[range.clone()]).into(),
+                        None => Value::Undefined,
+                    });
+                }
+                args.push(matched.range.start.into());
+                args.push(text.into());
+
+                let replacement = function
+                    .call(None, &args, activation)?
+                    .coerce_to_string(activation)?;
+                Ok(WString::from(replacement.as_wstr()))
+            }
+            Replacement::String(replacement) => Ok(expand_replacement(&text, matched, replacement)),
+        }
+    }
+}
+
+/// Expand the `$` substitution patterns of a replacement string.
+///
+/// Supports `$$`, `$&`, `` $` ``, `$'`, `$n`, `$nn` and `$<name>`. Anything
+/// else is inserted as-is.
+fn expand_replacement(text: &WStr, matched: &PatternMatch, replacement: &WStr) -> WString {
+    let digit_at = |i: usize| {
+        replacement
+            .get(i)
+            .and_then(|c| u8::try_from(c).ok())
+            .filter(u8::is_ascii_digit)
+            .map(|c| (c - b'0') as usize)
+    };
+
+    let mut ret = WString::new();
+    let mut i = 0;
+    while let Some(c) = replacement.get(i) {
+        if c != u16::from(b'$') {
+            ret.push(c);
+            i += 1;
+            continue;
+        }
+
+        match replacement.get(i + 1).and_then(|c| u8::try_from(c).ok()) {
+            Some(b'$') => {
+                ret.push(c);
+                i += 2;
+            }
+            Some(b'&') => {
+                ret.push_str(&text[matched.range.clone()]);
+                i += 2;
+            }
+            Some(b'`') => {
+                ret.push_str(&text[..matched.range.start]);
+                i += 2;
+            }
+            Some(b'\'') => {
+                ret.push_str(&text[matched.range.end..]);
+                i += 2;
+            }
+            Some(b'0'..=b'9') => {
+                let group_count = matched.captures.len();
+                let first = digit_at(i + 1).unwrap_or_default();
+                let two_digits = digit_at(i + 2).map(|second| first * 10 + second);
+
+                // Prefer a two-digit group number, if such a group exists.
+                let (group, length) = match two_digits {
+                    Some(group) if (1..=group_count).contains(&group) => (group, 3),
+                    _ if (1..=group_count).contains(&first) => (first, 2),
+                    _ => {
+                        ret.push(c);
+                        i += 1;
+                        continue;
+                    }
+                };
+
+                if let Some(range) = &matched.captures[group - 1] {
+                    ret.push_str(&text[range.clone()]);
+                }
+                i += length;
+            }
+            Some(b'<') if !matched.named_groups.is_empty() => {
+                let end = (i + 2..replacement.len())
+                    .find(|j| replacement.get(*j) == Some(u16::from(b'>')));
+                let end = match end {
+                    Some(end) => end,
+                    None => {
+                        ret.push(c);
+                        i += 1;
+                        continue;
+                    }
+                };
+
+                let name = replacement[i + 2..end].to_utf8_lossy();
+                let group = matched
+                    .named_groups
+                    .iter()
+                    .find(|(group_name, _)| *group_name == name);
+                if let Some((_, Some(range))) = group {
+                    ret.push_str(&text[range.clone()]);
+                }
+                i = end + 1;
+            }
+            _ => {
+                ret.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    ret
+}
+
 /// Implements `String.replace`
 fn replace<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -291,70 +464,92 @@ fn replace<'gc>(
         let pattern = args.get(0).unwrap_or(&Value::Undefined);
         let replacement = args.get(1).unwrap_or(&Value::Undefined);
 
-        if replacement
-            .coerce_to_object(activation)?
-            .as_function_object()
-            .is_some()
+        let replacement = match replacement
+            .as_object()
+            .filter(|replacement| replacement.as_executable().is_some())
         {
-            log::warn!("string.replace(_, function) - not implemented");
-            return Err("NotImplemented".into());
-        }
-        let replacement = replacement.coerce_to_string(activation)?;
+            Some(function) => Replacement::Function(function),
+            None => Replacement::String(replacement.coerce_to_string(activation)?),
+        };
 
-        if replacement.find(b'$').is_some() {
-            log::warn!("string.replace(_, \"...$...\") - not implemented");
-            return Err("NotImplemented".into());
-        }
+        let mut ret = WString::new();
+        let mut start = 0;
 
-        if let Some(mut regexp) = pattern
-            .coerce_to_object(activation)?
-            .as_regexp_mut(activation.context.gc_context)
+        if let Some(regexp) = pattern
+            .as_object()
+            .filter(|pattern| pattern.as_regexp().is_some())
         {
-            let mut ret = WString::new();
-            let mut start = 0;
-
-            let old = regexp.last_index();
-            regexp.set_last_index(0);
-
-            while let Some(result) = regexp.exec(this) {
-                ret.push_str(&this[start..result.start()]);
-                ret.push_str(&replacement);
-
-                start = regexp.last_index();
-
-                if result.range().is_empty() {
-                    let last_index = regexp.last_index();
-                    if last_index == this.len() {
-                        break;
-                    }
-                    regexp.set_last_index(last_index + 1);
-                }
-
-                if !regexp.flags().contains(RegExpFlags::GLOBAL) {
+            let global = regexp
+                .as_regexp()
+                .map(|regexp| regexp.flags().contains(RegExpFlags::GLOBAL))
+                .unwrap_or(false);
+
+            // Matching always starts from the beginning, and `lastIndex` is
+            // left untouched.
+            let mut search_from = 0;
+            while search_from <= this.len() {
+                let result = regexp
+                    .as_regexp_mut(activation.context.gc_context)
+                    .and_then(|mut regexp| regexp.find_at(this, search_from));
+                let result = match result {
+                    Some(result) => PatternMatch::from(result),
+                    None => break,
+                };
+
+                ret.push_str(&this[start..result.range.start]);
+                ret.push_str(&replacement.replace(activation, this, &result)?);
+                start = result.range.end;
+
+                if !global {
                     break;
                 }
-            }
-
-            regexp.set_last_index(old);
-
-            ret.push_str(&this[start..]);
 
-            return Ok(AvmString::new(activation.context.gc_context, ret).into());
+                search_from = if result.range.is_empty() {
+                    result.range.end + 1
+                } else {
+                    result.range.end
+                };
+            }
         } else {
             let pattern = pattern.coerce_to_string(activation)?;
             if let Some(position) = this.find(&pattern) {
-                let mut ret = WString::from(&this[..position]);
-                ret.push_str(&replacement);
-                ret.push_str(&this[position + pattern.len()..]);
-                return Ok(AvmString::new(activation.context.gc_context, ret).into());
-            } else {
-                return Ok(this.into());
+                let result = PatternMatch::literal(position..position + pattern.len());
+                ret.push_str(&this[..position]);
+                ret.push_str(&replacement.replace(activation, this, &result)?);
+                start = result.range.end;
             }
         }
+
+        ret.push_str(&this[start..]);
+
+        return Ok(AvmString::new(activation.context.gc_context, ret).into());
     }
     Ok(Value::Undefined)
 }
 
+/// Implements `String.search`
+fn search<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let (Some(this), pattern) = (this, args.get(0).unwrap_or(&Value::Undefined)) {
+        let this = Value::from(this).coerce_to_string(activation)?;
+        let pattern = pattern_to_regexp(activation, pattern)?;
+
+        // The search ignores the `g` flag and `lastIndex`.
+        if let Some(mut regexp) = pattern.as_regexp_mut(activation.context.gc_context) {
+            return Ok(regexp
+                .find_at(this, 0)
+                .map(|result| result.start() as i32)
+                .unwrap_or(-1)
+                .into());
+        }
+    }
+
+    Ok((-1).into())
+}
+
 /// Implements `String.slice`
 fn slice<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -403,20 +598,22 @@ fn split<'gc>(
                     .into(),
             );
         }
-        if delimiter
-            .coerce_to_object(activation)?
-            .as_regexp()
-            .is_some()
-        {
-            log::warn!("string.split(regex) - not implemented");
-        }
         let this = Value::from(this).coerce_to_string(activation)?;
-        let delimiter = delimiter.coerce_to_string(activation)?;
         let limit = match args.get(1).unwrap_or(&Value::Undefined) {
             Value::Undefined => usize::MAX,
             limit => limit.coerce_to_i32(activation)?.max(0) as usize,
         };
 
+        if let Some(regexp) = delimiter
+            .as_object()
+            .filter(|delimiter| delimiter.as_regexp().is_some())
+        {
+            let storage = split_regexp(activation, this, regexp, limit);
+            return Ok(ArrayObject::from_storage(activation, storage)?.into());
+        }
+
+        let delimiter = delimiter.coerce_to_string(activation)?;
+
         let storage = if delimiter.is_empty() {
             // When using an empty delimiter, Str::split adds an extra beginning and trailing item, but Flash does not.
             // e.g., split("foo", "") returns ["", "f", "o", "o", ""] in Rust but ["f, "o", "o"] in Flash.
@@ -444,6 +641,72 @@ fn split<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Split a string around the matches of a regular expression.
+///
+/// This follows ECMA-262's `String.prototype.split`: captured groups are
+/// spliced into the result, and empty matches never split at the position
+/// where the previous match ended.
+fn split_regexp<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: AvmString<'gc>,
+    regexp: Object<'gc>,
+    limit: usize,
+) -> ArrayStorage<'gc> {
+    let mc = activation.context.gc_context;
+    let find = |start: usize| {
+        regexp
+            .as_regexp_mut(mc)
+            .and_then(|mut regexp| regexp.find_at(this, start))
+    };
+
+    let mut storage = ArrayStorage::new(0);
+    if limit == 0 {
+        return storage;
+    }
+
+    if this.is_empty() {
+        if find(0).is_none() {
+            storage.push(this.into());
+        }
+        return storage;
+    }
+
+    let mut last_end = 0;
+    let mut search_from = 0;
+    while search_from < this.len() {
+        let result = match find(search_from) {
+            Some(result) if result.start() < this.len() => result,
+            _ => break,
+        };
+
+        if result.end() == last_end {
+            search_from = result.start() + 1;
+            continue;
+        }
+
+        storage.push(AvmString::new(mc, &this[last_end..result.start()]).into());
+        if storage.length() >= limit {
+            return storage;
+        }
+
+        for capture in &result.captures {
+            storage.push(match capture {
+                Some(range) => AvmString::new(mc, &this[range.clone()]).into(),
+                None => Value::Undefined,
+            });
+            if storage.length() >= limit {
+                return storage;
+            }
+        }
+
+        last_end = result.end();
+        search_from = last_end;
+    }
+
+    storage.push(AvmString::new(mc, &this[last_end..]).into());
+    storage
+}
+
 /// Implements `String.substr`
 fn substr<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
@@ -551,6 +814,7 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("lastIndexOf", last_index_of),
         ("match", match_s),
         ("replace", replace),
+        ("search", search),
         ("slice", slice),
         ("split", split),
         ("substr", substr),
@@ -1,29 +1,863 @@
 //! XML builtin and prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
-use crate::avm2::method::{Method, ParamConfig};
-use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{xml_allocator, Object};
+use crate::avm2::e4x::{E4XNamespace, E4XNode};
+use crate::avm2::method::{Method, NativeMethodImpl, ParamConfig};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{
+    xml_allocator, ArrayObject, FunctionObject, NamespaceObject, Object, QNameObject, TObject,
+    XmlListObject, XmlObject,
+};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::{GcCell, MutationContext};
 
+/// Convert a value into a single XML node, as per the E4X `ToXML` algorithm.
+///
+/// `XML` objects yield their own node; strings are parsed as markup.
+pub fn value_to_node<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<E4XNode<'gc>, Error> {
+    if let Value::Object(object) = value {
+        if let Some(xml) = object.as_xml_object() {
+            return Ok(xml.node());
+        }
+
+        if let Some(list) = object.as_xml_list_object() {
+            return match &list.children()[..] {
+                [child] => Ok(*child),
+                _ => Err("TypeError: Error #1088: The markup in the document following the root element must be well-formed.".into()),
+            };
+        }
+    }
+
+    let markup = match value {
+        Value::Undefined | Value::Null => "".into(),
+        value => value.coerce_to_string(activation)?,
+    };
+    let mut nodes = E4XNode::parse(&markup, activation)?;
+
+    match nodes.len() {
+        0 => Ok(E4XNode::text(activation.context.gc_context, "".into(), None)),
+        1 => Ok(nodes.remove(0)),
+        _ => Err("TypeError: Error #1088: The markup in the document following the root element must be well-formed.".into()),
+    }
+}
+
+/// Convert a value passed to an E4X method into a name to match nodes
+/// against.
+///
+/// `QName` objects are used as-is, and the string `*` matches any name.
+pub fn value_to_multiname<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+    is_attribute: bool,
+) -> Result<Multiname<'gc>, Error> {
+    let qname = value
+        .as_object()
+        .and_then(|o| o.as_qname_object())
+        .and_then(|q| q.qname().map(|q| *q));
+
+    let mut multiname = match qname {
+        Some(qname) if &qname.local_name() != b"*" => qname.into(),
+        Some(_) => Multiname::any(),
+        None => {
+            let name = value.coerce_to_string(activation)?;
+            if &name == b"*" {
+                Multiname::any()
+            } else {
+                Multiname::public(name)
+            }
+        }
+    };
+
+    multiname.set_is_attribute(is_attribute);
+
+    Ok(multiname)
+}
+
 /// Implements `XML`'s instance initializer.
 pub fn instance_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if let (Some(xml), Some(value)) = (this.as_xml_object(), args.get(0)) {
+            if !matches!(value, Value::Undefined) {
+                // Constructing from existing XML yields a copy of it.
+                let node = value_to_node(activation, *value)?;
+                let node = if matches!(value, Value::Object(_)) {
+                    node.deep_copy(activation.context.gc_context)
+                } else {
+                    node
+                };
+
+                xml.set_node(activation.context.gc_context, node);
+            }
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML`'s class call handler.
+fn class_call<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    if let Some(object) = value.as_object() {
+        if object.as_xml_object().is_some() {
+            return Ok(value);
+        }
+    }
+
+    let node = value_to_node(activation, value)?;
+
+    Ok(XmlObject::from_node(activation, node)?.into())
+}
+
+/// Implements `XML`'s class initializer
+pub fn class_init<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let scope = activation.create_scopechain();
+        let gc_context = activation.context.gc_context;
+        let this_class = this.as_class_object().unwrap();
+        let xml_proto = this_class.prototype();
+
+        const PROTOTYPE_METHODS: &[(&str, NativeMethodImpl)] = &[
+            ("toString", to_string),
+            ("toXMLString", to_xml_string),
+            ("valueOf", value_of),
+        ];
+
+        for &(name, method) in PROTOTYPE_METHODS {
+            xml_proto.set_property_local(
+                &Multiname::public(name),
+                FunctionObject::from_method(
+                    activation,
+                    Method::from_builtin(method, name, gc_context),
+                    scope,
+                    None,
+                    Some(this_class),
+                )
+                .into(),
+                activation,
+            )?;
+            xml_proto.set_local_property_is_enumerable(gc_context, name.into(), false)?;
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Retrieve the node of an `XML` object.
+fn this_node<'gc>(this: Option<Object<'gc>>) -> Result<E4XNode<'gc>, Error> {
+    this.and_then(|this| this.as_xml_object())
+        .map(|xml| xml.node())
+        .ok_or_else(|| "TypeError: XML method called on incompatible object".into())
+}
+
+/// Implements `XML.toString`
+pub fn to_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(list) = this.and_then(|this| this.as_xml_list_object()) {
+        return Ok(list.xml_list_to_string(activation).into());
+    }
+
+    Ok(this_node(this)?.xml_to_string(activation).into())
+}
+
+/// Implements `XML.toXMLString`
+pub fn to_xml_string<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    if let Some(list) = this.and_then(|this| this.as_xml_list_object()) {
+        return Ok(list.xml_list_to_xml_string(activation).into());
+    }
+
+    Ok(this_node(this)?.xml_to_xml_string(activation).into())
+}
+
+/// Implements `XML.valueOf`
+pub fn value_of<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.name`
+pub fn name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+
+    if let Some(local_name) = node.local_name() {
+        let namespace = match node.namespace() {
+            Some(ns) => Namespace::Namespace(ns.uri),
+            None => Namespace::public(),
+        };
+
+        return Ok(QNameObject::from_qname(activation, QName::new(namespace, local_name))?.into());
+    }
+
+    Ok(Value::Null)
+}
+
+/// Implements `XML.localName`
+pub fn local_name<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this_node(this)?
+        .local_name()
+        .map(|name| name.into())
+        .unwrap_or(Value::Null))
+}
+
+/// Implements `XML.namespace`
+pub fn namespace<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+
+    match args.get(0) {
+        Some(prefix) if !matches!(prefix, Value::Undefined) => {
+            let prefix = prefix.coerce_to_string(activation)?;
+            let declaration = node
+                .in_scope_namespaces()
+                .into_iter()
+                .find(|ns| ns.prefix.unwrap_or_default() == prefix);
+
+            match declaration {
+                Some(ns) => Ok(NamespaceObject::from_prefix_and_namespace(
+                    activation,
+                    Some(ns.prefix.unwrap_or_else(|| "".into())),
+                    Namespace::Namespace(ns.uri),
+                )?
+                .into()),
+                None => Ok(Value::Undefined),
+            }
+        }
+        _ => {
+            if !node.is_element() && !node.is_attribute() {
+                return Ok(Value::Null);
+            }
+
+            let uri = node.namespace().map(|ns| ns.uri).unwrap_or_default();
+            Ok(NamespaceObject::from_namespace(activation, Namespace::Namespace(uri))?.into())
+        }
+    }
+}
+
+/// Implements `XML.nodeKind`
+pub fn node_kind<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this_node(this)?.node_kind().into())
+}
+
+/// Implements `XML.length`
+pub fn length<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    Ok(1.into())
+}
+
+/// Implements `XML.parent`
+pub fn parent<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    match this_node(this)?.parent() {
+        Some(parent) => Ok(XmlObject::from_node(activation, parent)?.into()),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `XML.childIndex`
+pub fn child_index<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+
+    if let Some(parent) = node.parent() {
+        if let Some(index) = parent
+            .children()
+            .iter()
+            .position(|c| E4XNode::ptr_eq(*c, node))
+        {
+            return Ok(index.into());
+        }
+    }
+
+    Ok((-1).into())
+}
+
+/// Implements `XML.children`
+pub fn children<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let children = this_node(this)?.children();
+
+    Ok(XmlListObject::new(activation, children)?.into())
+}
+
+/// Implements `XML.child`
+pub fn child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let name = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    let children = if let Value::Number(_) | Value::Integer(_) | Value::Unsigned(_) = name {
+        let index = name.coerce_to_u32(activation)? as usize;
+        node.children().get(index).copied().into_iter().collect()
+    } else {
+        let name = value_to_multiname(activation, name, false)?;
+        node.children()
+            .into_iter()
+            .filter(|c| c.matches_name(&name))
+            .collect()
+    };
+
+    Ok(XmlListObject::new(activation, children)?.into())
+}
+
+/// Implements `XML.elements`
+pub fn elements<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let name = value_to_multiname(
+        activation,
+        args.get(0).cloned().unwrap_or_else(|| "*".into()),
+        false,
+    )?;
+    let children = node
+        .children()
+        .into_iter()
+        .filter(|c| c.is_element() && c.matches_name(&name))
+        .collect();
+
+    Ok(XmlListObject::new(activation, children)?.into())
+}
+
+/// Implements `XML.attributes`
+pub fn attributes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let attributes = this_node(this)?.attributes();
+
+    Ok(XmlListObject::new(activation, attributes)?.into())
+}
+
+/// Implements `XML.attribute`
+pub fn attribute<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let name = value_to_multiname(
+        activation,
+        args.get(0).cloned().unwrap_or(Value::Undefined),
+        true,
+    )?;
+    let attributes = node
+        .attributes()
+        .into_iter()
+        .filter(|a| a.matches_name(&name))
+        .collect();
+
+    Ok(XmlListObject::new(activation, attributes)?.into())
+}
+
+/// Implements `XML.descendants`
+pub fn descendants<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = value_to_multiname(
+        activation,
+        args.get(0).cloned().unwrap_or_else(|| "*".into()),
+        false,
+    )?;
+
+    match this {
+        Some(this) => this.get_descendants(&name, activation),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `XML.text`
+pub fn text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let text = this_node(this)?
+        .children()
+        .into_iter()
+        .filter(|c| c.is_text())
+        .collect();
+
+    Ok(XmlListObject::new(activation, text)?.into())
+}
+
+/// Implements `XML.comments`
+pub fn comments<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let comments = this_node(this)?
+        .children()
+        .into_iter()
+        .filter(|c| c.node_kind() == "comment")
+        .collect();
+
+    Ok(XmlListObject::new(activation, comments)?.into())
+}
+
+/// Implements `XML.copy`
+pub fn copy<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let copy = this_node(this)?.deep_copy(activation.context.gc_context);
+
+    Ok(XmlObject::from_node(activation, copy)?.into())
+}
+
+/// Insert a value into the children of an element, as per the E4X
+/// `[[Insert]]` algorithm.
+///
+/// Lists insert all of their items. Nodes are moved from any parent they
+/// already have.
+fn insert_value<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    node: E4XNode<'gc>,
+    mut index: usize,
+    value: Value<'gc>,
+) -> Result<(), Error> {
+    let mc = activation.context.gc_context;
+
+    let new_children = match value.as_object().and_then(|o| o.as_xml_list_object()) {
+        Some(list) => list.children().clone(),
+        None => vec![value_to_node(activation, value)?],
+    };
+
+    for child in new_children {
+        if let Some(old_parent) = child.parent() {
+            // Moving a child further along in the same element shifts the
+            // insertion point back.
+            if E4XNode::ptr_eq(old_parent, node)
+                && old_parent
+                    .children()
+                    .iter()
+                    .position(|c| E4XNode::ptr_eq(*c, child))
+                    .map_or(false, |i| i < index)
+            {
+                index -= 1;
+            }
+
+            old_parent.remove_child(mc, child);
+        }
+
+        node.insert_child(mc, index, child);
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Find the index of a child of `node` given as an `XML` object (or an
+/// `XMLList` of one item).
+fn child_position<'gc>(node: E4XNode<'gc>, value: Value<'gc>) -> Option<usize> {
+    let object = value.as_object()?;
+    let child = match (object.as_xml_object(), object.as_xml_list_object()) {
+        (Some(xml), _) => xml.node(),
+        (None, Some(list)) if list.length() == 1 => list.children()[0],
+        _ => return None,
+    };
+
+    node.children()
+        .iter()
+        .position(|c| E4XNode::ptr_eq(*c, child))
+}
+
+/// Implements `XML.appendChild`
+pub fn append_child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    insert_value(activation, node, node.children().len(), value)?;
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.prependChild`
+pub fn prepend_child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    insert_value(activation, node, 0, value)?;
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.insertChildAfter`
+///
+/// A `null` reference child inserts at the start of the children.
+pub fn insert_child_after<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let reference = args.get(0).cloned().unwrap_or(Value::Undefined);
+    let value = args.get(1).cloned().unwrap_or(Value::Undefined);
+
+    if !node.is_element() {
+        return Ok(Value::Undefined);
+    }
+
+    let index = match reference {
+        Value::Null => 0,
+        reference => match child_position(node, reference) {
+            Some(i) => i + 1,
+            None => return Ok(Value::Undefined),
+        },
+    };
+
+    insert_value(activation, node, index, value)?;
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.insertChildBefore`
+///
+/// A `null` reference child inserts at the end of the children.
+pub fn insert_child_before<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let reference = args.get(0).cloned().unwrap_or(Value::Undefined);
+    let value = args.get(1).cloned().unwrap_or(Value::Undefined);
+
+    if !node.is_element() {
+        return Ok(Value::Undefined);
+    }
+
+    let index = match reference {
+        Value::Null => node.children().len(),
+        reference => match child_position(node, reference) {
+            Some(i) => i,
+            None => return Ok(Value::Undefined),
+        },
+    };
+
+    insert_value(activation, node, index, value)?;
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.setName`
+pub fn set_name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let name = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    if node.is_text() || node.node_kind() == "comment" {
+        return Ok(Value::Undefined);
+    }
+
+    let qname = name
+        .as_object()
+        .and_then(|o| o.as_qname_object())
+        .and_then(|q| q.qname().map(|q| *q));
+    let (uri, local_name) = match qname {
+        Some(qname) => {
+            let uri = match qname.namespace() {
+                Namespace::Any => "".into(),
+                ns => ns.as_uri(),
+            };
+            (uri, qname.local_name())
+        }
+        None => ("".into(), name.coerce_to_string(activation)?),
+    };
+
+    // Processing instructions have no namespace. Other nodes reuse the
+    // prefix of a declaration of their new namespace, or declare it.
+    let namespace = if uri.is_empty() || node.node_kind() == "processing-instruction" {
+        None
+    } else {
+        let declaration = node
+            .in_scope_namespaces()
+            .into_iter()
+            .find(|ns| ns.uri == uri);
+
+        Some(declaration.unwrap_or_else(|| {
+            let ns = E4XNamespace { prefix: None, uri };
+            if node.is_element() {
+                node.add_namespace_declaration(activation.context.gc_context, ns);
+            }
+            ns
+        }))
+    };
+
+    node.set_name(activation.context.gc_context, namespace, local_name);
+
     Ok(Value::Undefined)
 }
 
-/// Implements `XML`'s class initializer
-pub fn class_init<'gc>(
+/// Implements `XML.addNamespace`
+///
+/// Namespaces without a prefix are not declared.
+pub fn add_namespace<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let node = this_node(this)?;
+    let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    let namespace = match value.as_object().and_then(|o| o.as_namespace_object()) {
+        Some(namespace) => namespace,
+        None => {
+            let namespace_class = activation.avm2().classes().namespace;
+            namespace_class
+                .construct(activation, &[value])?
+                .as_namespace_object()
+                .ok_or("TypeError: Namespace constructor did not return a namespace")?
+        }
+    };
+
+    if let Some(prefix) = namespace.prefix() {
+        let uri = namespace.namespace().as_uri();
+        // The empty prefix declares the default namespace.
+        let prefix = if prefix.is_empty() {
+            None
+        } else {
+            Some(prefix)
+        };
+
+        if prefix.is_some() || !uri.is_empty() {
+            node.add_namespace_declaration(
+                activation.context.gc_context,
+                E4XNamespace { prefix, uri },
+            );
+        }
+    }
+
+    Ok(this.map(|this| this.into()).unwrap_or(Value::Undefined))
+}
+
+/// Implements `XML.namespaceDeclarations`
+pub fn namespace_declarations<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let mut declarations = Vec::new();
+    for ns in this_node(this)?.namespace_declarations() {
+        let prefix = ns.prefix.unwrap_or_else(|| "".into());
+        let namespace = NamespaceObject::from_prefix_and_namespace(
+            activation,
+            Some(prefix),
+            Namespace::Namespace(ns.uri),
+        )?;
+        declarations.push(namespace.into());
+    }
+
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&declarations))?.into())
+}
+
+/// Implements `XML.hasSimpleContent`
+pub fn has_simple_content<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this_node(this)?.has_simple_content().into())
+}
+
+/// Implements `XML.hasComplexContent`
+pub fn has_complex_content<'gc>(
     _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this_node(this)?.has_complex_content().into())
+}
+
+/// Implements `XML.ignoreComments`'s getter
+pub fn ignore_comments<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    Ok(activation.avm2().xml_settings.ignore_comments.into())
+}
+
+/// Implements `XML.ignoreComments`'s setter
+pub fn set_ignore_comments<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).unwrap_or(&Value::Undefined).coerce_to_boolean();
+    activation.avm2().xml_settings.ignore_comments = value;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML.ignoreProcessingInstructions`'s getter
+pub fn ignore_processing_instructions<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(activation
+        .avm2()
+        .xml_settings
+        .ignore_processing_instructions
+        .into())
+}
+
+/// Implements `XML.ignoreProcessingInstructions`'s setter
+pub fn set_ignore_processing_instructions<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).unwrap_or(&Value::Undefined).coerce_to_boolean();
+    activation
+        .avm2()
+        .xml_settings
+        .ignore_processing_instructions = value;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML.ignoreWhitespace`'s getter
+pub fn ignore_whitespace<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(activation.avm2().xml_settings.ignore_whitespace.into())
+}
+
+/// Implements `XML.ignoreWhitespace`'s setter
+pub fn set_ignore_whitespace<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).unwrap_or(&Value::Undefined).coerce_to_boolean();
+    activation.avm2().xml_settings.ignore_whitespace = value;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML.prettyPrinting`'s getter
+pub fn pretty_printing<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(activation.avm2().xml_settings.pretty_printing.into())
+}
+
+/// Implements `XML.prettyPrinting`'s setter
+pub fn set_pretty_printing<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).unwrap_or(&Value::Undefined).coerce_to_boolean();
+    activation.avm2().xml_settings.pretty_printing = value;
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `XML.prettyIndent`'s getter
+pub fn pretty_indent<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(activation.avm2().xml_settings.pretty_indent.into())
+}
+
+/// Implements `XML.prettyIndent`'s setter
+pub fn set_pretty_indent<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_i32(activation)?;
+    activation.avm2().xml_settings.pretty_indent = value;
+
     Ok(Value::Undefined)
 }
 
@@ -48,6 +882,65 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
 
     let mut write = class.write(mc);
     write.set_instance_allocator(xml_allocator);
+    write.set_call_handler(Method::from_builtin(class_call, "<XML call handler>", mc));
+
+    const AS3_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("addNamespace", add_namespace),
+        ("appendChild", append_child),
+        ("attribute", attribute),
+        ("attributes", attributes),
+        ("child", child),
+        ("childIndex", child_index),
+        ("children", children),
+        ("comments", comments),
+        ("copy", copy),
+        ("descendants", descendants),
+        ("elements", elements),
+        ("hasComplexContent", has_complex_content),
+        ("hasSimpleContent", has_simple_content),
+        ("insertChildAfter", insert_child_after),
+        ("insertChildBefore", insert_child_before),
+        ("length", length),
+        ("localName", local_name),
+        ("name", name),
+        ("namespace", namespace),
+        ("namespaceDeclarations", namespace_declarations),
+        ("nodeKind", node_kind),
+        ("parent", parent),
+        ("prependChild", prepend_child),
+        ("setName", set_name),
+        ("text", text),
+        ("toString", to_string),
+        ("toXMLString", to_xml_string),
+        ("valueOf", value_of),
+    ];
+    write.define_as3_builtin_instance_methods(mc, AS3_INSTANCE_METHODS);
+
+    const PUBLIC_CLASS_PROPERTIES: &[(&str, Option<NativeMethodImpl>, Option<NativeMethodImpl>)] =
+        &[
+            (
+                "ignoreComments",
+                Some(ignore_comments),
+                Some(set_ignore_comments),
+            ),
+            (
+                "ignoreProcessingInstructions",
+                Some(ignore_processing_instructions),
+                Some(set_ignore_processing_instructions),
+            ),
+            (
+                "ignoreWhitespace",
+                Some(ignore_whitespace),
+                Some(set_ignore_whitespace),
+            ),
+            (
+                "prettyPrinting",
+                Some(pretty_printing),
+                Some(set_pretty_printing),
+            ),
+            ("prettyIndent", Some(pretty_indent), Some(set_pretty_indent)),
+        ];
+    write.define_public_builtin_class_properties(mc, PUBLIC_CLASS_PROPERTIES);
 
     class
 }
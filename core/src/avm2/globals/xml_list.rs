@@ -2,31 +2,330 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
-use crate::avm2::method::{Method, ParamConfig};
-use crate::avm2::names::{Namespace, QName};
-use crate::avm2::object::{xml_allocator, Object};
+use crate::avm2::e4x::E4XNode;
+use crate::avm2::globals::xml::{to_string, to_xml_string, value_of, value_to_multiname};
+use crate::avm2::method::{Method, NativeMethodImpl, ParamConfig};
+use crate::avm2::names::{Multiname, Namespace, QName};
+use crate::avm2::object::{
+    xml_list_allocator, FunctionObject, Object, TObject, XmlListObject, XmlObject,
+};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use gc_arena::{GcCell, MutationContext};
 
+/// Convert a value into a list of XML nodes, as per the E4X `ToXMLList`
+/// algorithm.
+pub fn value_to_nodes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    value: Value<'gc>,
+) -> Result<Vec<E4XNode<'gc>>, Error> {
+    if let Value::Object(object) = value {
+        if let Some(xml) = object.as_xml_object() {
+            return Ok(vec![xml.node()]);
+        }
+
+        if let Some(list) = object.as_xml_list_object() {
+            return Ok(list.children().clone());
+        }
+    }
+
+    match value {
+        Value::Undefined | Value::Null => Ok(Vec::new()),
+        value => {
+            let markup = value.coerce_to_string(activation)?;
+            E4XNode::parse(&markup, activation)
+        }
+    }
+}
+
 /// Implements `XMLList`'s instance initializer.
 pub fn instance_init<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        activation.super_init(this, &[])?;
+
+        if let (Some(list), Some(value)) = (this.as_xml_list_object(), args.get(0)) {
+            if !matches!(value, Value::Undefined) {
+                let children = value_to_nodes(activation, *value)?;
+                list.set_children(activation.context.gc_context, children);
+            }
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Implements `XMLList`'s class call handler.
+fn class_call<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let value = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    if let Some(object) = value.as_object() {
+        if object.as_xml_list_object().is_some() {
+            return Ok(value);
+        }
+    }
+
+    let children = value_to_nodes(activation, value)?;
+
+    Ok(XmlListObject::new(activation, children)?.into())
+}
+
 /// Implements `XMLList`'s class initializer
 pub fn class_init<'gc>(
-    _activation: &mut Activation<'_, 'gc, '_>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error> {
+    if let Some(this) = this {
+        let scope = activation.create_scopechain();
+        let gc_context = activation.context.gc_context;
+        let this_class = this.as_class_object().unwrap();
+        let xml_list_proto = this_class.prototype();
+
+        const PROTOTYPE_METHODS: &[(&str, NativeMethodImpl)] = &[
+            ("toString", to_string),
+            ("toXMLString", to_xml_string),
+            ("valueOf", value_of),
+        ];
+
+        for &(name, method) in PROTOTYPE_METHODS {
+            xml_list_proto.set_property_local(
+                &Multiname::public(name),
+                FunctionObject::from_method(
+                    activation,
+                    Method::from_builtin(method, name, gc_context),
+                    scope,
+                    None,
+                    Some(this_class),
+                )
+                .into(),
+                activation,
+            )?;
+            xml_list_proto.set_local_property_is_enumerable(gc_context, name.into(), false)?;
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Retrieve the nodes of an `XMLList` object.
+fn this_children<'gc>(this: Option<Object<'gc>>) -> Result<Vec<E4XNode<'gc>>, Error> {
+    this.and_then(|this| this.as_xml_list_object())
+        .map(|list| list.children().clone())
+        .ok_or_else(|| "TypeError: XMLList method called on incompatible object".into())
+}
+
+/// Build a new list out of the results of calling a function on every node
+/// in this list.
+fn flat_map<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    f: impl Fn(E4XNode<'gc>) -> Vec<E4XNode<'gc>>,
+) -> Result<Value<'gc>, Error> {
+    let children = this_children(this)?.into_iter().flat_map(f).collect();
+
+    Ok(XmlListObject::new(activation, children)?.into())
+}
+
+/// Implements `XMLList.length`
+pub fn length<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this_children(this)?.len().into())
+}
+
+/// Implements `XMLList.children`
+pub fn children<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    flat_map(activation, this, |node| node.children())
+}
+
+/// Implements `XMLList.child`
+pub fn child<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = args.get(0).cloned().unwrap_or(Value::Undefined);
+
+    if let Value::Number(_) | Value::Integer(_) | Value::Unsigned(_) = name {
+        let index = name.coerce_to_u32(activation)? as usize;
+        return flat_map(activation, this, |node| {
+            node.children().get(index).copied().into_iter().collect()
+        });
+    }
+
+    let name = value_to_multiname(activation, name, false)?;
+    flat_map(activation, this, |node| {
+        node.children()
+            .into_iter()
+            .filter(|c| c.matches_name(&name))
+            .collect()
+    })
+}
+
+/// Implements `XMLList.elements`
+pub fn elements<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = args.get(0).cloned().unwrap_or_else(|| "*".into());
+    let name = value_to_multiname(activation, name, false)?;
+
+    flat_map(activation, this, |node| {
+        node.children()
+            .into_iter()
+            .filter(|c| c.is_element() && c.matches_name(&name))
+            .collect()
+    })
+}
+
+/// Implements `XMLList.attributes`
+pub fn attributes<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    flat_map(activation, this, |node| node.attributes())
+}
+
+/// Implements `XMLList.attribute`
+pub fn attribute<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = args.get(0).cloned().unwrap_or(Value::Undefined);
+    let name = value_to_multiname(activation, name, true)?;
+
+    flat_map(activation, this, |node| {
+        node.attributes()
+            .into_iter()
+            .filter(|a| a.matches_name(&name))
+            .collect()
+    })
+}
+
+/// Implements `XMLList.descendants`
+pub fn descendants<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let name = args.get(0).cloned().unwrap_or_else(|| "*".into());
+    let name = value_to_multiname(activation, name, false)?;
+
+    match this {
+        Some(this) => this.get_descendants(&name, activation),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `XMLList.text`
+pub fn text<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    flat_map(activation, this, |node| {
+        node.children()
+            .into_iter()
+            .filter(|c| c.is_text())
+            .collect()
+    })
+}
+
+/// Implements `XMLList.comments`
+pub fn comments<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    flat_map(activation, this, |node| {
+        node.children()
+            .into_iter()
+            .filter(|c| c.node_kind() == "comment")
+            .collect()
+    })
+}
+
+/// Implements `XMLList.copy`
+pub fn copy<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let mc = activation.context.gc_context;
+
+    flat_map(activation, this, |node| vec![node.deep_copy(mc)])
+}
+
+/// Implements `XMLList.parent`
+///
+/// This yields the common parent of every node in the list, if there is one.
+pub fn parent<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let children = this_children(this)?;
+    let parent = children.first().and_then(|first| first.parent());
+
+    match parent {
+        Some(parent)
+            if children
+                .iter()
+                .all(|c| c.parent().map(|p| E4XNode::ptr_eq(p, parent)) == Some(true)) =>
+        {
+            Ok(XmlObject::from_node(activation, parent)?.into())
+        }
+        _ => Ok(Value::Undefined),
+    }
+}
+
+/// Implements `XMLList.hasSimpleContent`
+pub fn has_simple_content<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    Ok(this
+        .and_then(|this| this.as_xml_list_object())
+        .map(|list| list.has_simple_content())
+        .unwrap_or(false)
+        .into())
+}
+
+/// Implements `XMLList.hasComplexContent`
+pub fn has_complex_content<'gc>(
+    _activation: &mut Activation<'_, 'gc, '_>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error> {
+    let children = this_children(this)?;
+
+    let has_complex_content = match &children[..] {
+        [] => false,
+        [child] => child.has_complex_content(),
+        children => children.iter().any(|c| c.is_element()),
+    };
+
+    Ok(has_complex_content.into())
+}
+
 pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>> {
     let class = Class::new(
         QName::new(Namespace::public(), "XMLList"),
@@ -47,7 +346,32 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
     );
 
     let mut write = class.write(mc);
-    write.set_instance_allocator(xml_allocator);
+    write.set_instance_allocator(xml_list_allocator);
+    write.set_call_handler(Method::from_builtin(
+        class_call,
+        "<XMLList call handler>",
+        mc,
+    ));
+
+    const AS3_INSTANCE_METHODS: &[(&str, NativeMethodImpl)] = &[
+        ("attribute", attribute),
+        ("attributes", attributes),
+        ("child", child),
+        ("children", children),
+        ("comments", comments),
+        ("copy", copy),
+        ("descendants", descendants),
+        ("elements", elements),
+        ("hasComplexContent", has_complex_content),
+        ("hasSimpleContent", has_simple_content),
+        ("length", length),
+        ("parent", parent),
+        ("text", text),
+        ("toString", to_string),
+        ("toXMLString", to_xml_string),
+        ("valueOf", value_of),
+    ];
+    write.define_as3_builtin_instance_methods(mc, AS3_INSTANCE_METHODS);
 
     class
 }
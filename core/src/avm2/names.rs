@@ -284,6 +284,10 @@ pub struct Multiname<'gc> {
     /// The type parameters required to satisfy this multiname. If empty, then
     /// this multiname is satisfied by any type parameters in any amount.
     params: Vec<Multiname<'gc>>,
+
+    /// Whether or not this multiname refers to an XML attribute (e.g. it was
+    /// read from a `QNameA`, `MultinameA`, or other attribute name constant).
+    is_attribute: bool,
 }
 
 impl<'gc> Multiname<'gc> {
@@ -337,6 +341,7 @@ impl<'gc> Multiname<'gc> {
                 )?,
                 name: Some(name.coerce_to_string(activation)?),
                 params: Vec::new(),
+                is_attribute: matches!(abc_multiname, AbcMultiname::MultinameLA { .. }),
             }),
             _ => Err("Cannot assemble early-bound multinames using from_multiname_late".into()),
         }
@@ -355,7 +360,7 @@ impl<'gc> Multiname<'gc> {
         abc_multiname: &AbcMultiname,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Self, Error> {
-        Ok(match abc_multiname {
+        let mut multiname = match abc_multiname {
             AbcMultiname::QName { namespace, name } | AbcMultiname::QNameA { namespace, name } => {
                 Self {
                     ns: vec![Namespace::from_abc_namespace(
@@ -366,6 +371,7 @@ impl<'gc> Multiname<'gc> {
                     name: translation_unit
                         .pool_string_option(name.0, activation.context.gc_context)?,
                     params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::RTQName { name } | AbcMultiname::RTQNameA { name } => {
@@ -376,6 +382,7 @@ impl<'gc> Multiname<'gc> {
                     name: translation_unit
                         .pool_string_option(name.0, activation.context.gc_context)?,
                     params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::RTQNameL | AbcMultiname::RTQNameLA => {
@@ -386,6 +393,7 @@ impl<'gc> Multiname<'gc> {
                     ns: vec![*ns],
                     name: Some(name),
                     params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::Multiname {
@@ -403,6 +411,7 @@ impl<'gc> Multiname<'gc> {
                 )?,
                 name: translation_unit.pool_string_option(name.0, activation.context.gc_context)?,
                 params: Vec::new(),
+                is_attribute: false,
             },
             AbcMultiname::MultinameL { .. } | AbcMultiname::MultinameLA { .. } => {
                 let name = activation.avm2().pop();
@@ -411,7 +420,23 @@ impl<'gc> Multiname<'gc> {
             AbcMultiname::TypeName { .. } => {
                 return Err("Recursive TypeNames are not supported!".into())
             }
-        })
+        };
+
+        multiname.is_attribute = Self::is_abc_attribute(abc_multiname);
+
+        Ok(multiname)
+    }
+
+    /// Determine if an ABC multiname constant names an XML attribute.
+    fn is_abc_attribute(abc_multiname: &AbcMultiname) -> bool {
+        matches!(
+            abc_multiname,
+            AbcMultiname::QNameA { .. }
+                | AbcMultiname::RTQNameA { .. }
+                | AbcMultiname::RTQNameLA
+                | AbcMultiname::MultinameA { .. }
+                | AbcMultiname::MultinameLA { .. }
+        )
     }
 
     /// Retrieve a given multiname index from the ABC file, yielding an error
@@ -499,7 +524,8 @@ impl<'gc> Multiname<'gc> {
             .get(actual_index)
             .ok_or_else(|| format!("Unknown multiname constant {}", multiname_index.0).into());
 
-        Ok(match abc_multiname? {
+        let abc_multiname = abc_multiname?;
+        let mut multiname = match abc_multiname {
             AbcMultiname::QName { namespace, name } | AbcMultiname::QNameA { namespace, name } => {
                 Self {
                     ns: vec![Namespace::from_abc_namespace(
@@ -509,6 +535,7 @@ impl<'gc> Multiname<'gc> {
                     )?],
                     name: translation_unit.pool_string_option(name.0, mc)?,
                     params: Vec::new(),
+                    is_attribute: false,
                 }
             }
             AbcMultiname::Multiname {
@@ -522,6 +549,7 @@ impl<'gc> Multiname<'gc> {
                 ns: Self::abc_namespace_set(translation_unit, *namespace_set, mc)?,
                 name: translation_unit.pool_string_option(name.0, mc)?,
                 params: Vec::new(),
+                is_attribute: false,
             },
             AbcMultiname::TypeName {
                 base_type,
@@ -550,7 +578,11 @@ impl<'gc> Multiname<'gc> {
                 base
             }
             _ => return Err(format!("Multiname {} is not static", multiname_index.0).into()),
-        })
+        };
+
+        multiname.is_attribute = Self::is_abc_attribute(abc_multiname);
+
+        Ok(multiname)
     }
 
     /// Indicates the any type (any name in any namespace).
@@ -559,6 +591,7 @@ impl<'gc> Multiname<'gc> {
             ns: vec![Namespace::Any],
             name: None,
             params: Vec::new(),
+            is_attribute: false,
         }
     }
 
@@ -567,6 +600,7 @@ impl<'gc> Multiname<'gc> {
             ns: vec![Namespace::public()],
             name: Some(name.into()),
             params: Vec::new(),
+            is_attribute: false,
         }
     }
 
@@ -598,6 +632,17 @@ impl<'gc> Multiname<'gc> {
         ns_match && name_match
     }
 
+    /// Indicates if this multiname refers to an XML attribute.
+    pub fn is_attribute(&self) -> bool {
+        self.is_attribute
+    }
+
+    /// Mark this multiname as referring to (or not referring to) an XML
+    /// attribute.
+    pub fn set_is_attribute(&mut self, is_attribute: bool) {
+        self.is_attribute = is_attribute;
+    }
+
     /// List the parameters that the selected class must match.
     pub fn params(&self) -> &[Multiname<'gc>] {
         &self.params[..]
@@ -610,6 +655,7 @@ impl<'gc> From<QName<'gc>> for Multiname<'gc> {
             ns: vec![q.ns],
             name: Some(q.name),
             params: Vec::new(),
+            is_attribute: false,
         }
     }
 }
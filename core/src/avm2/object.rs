@@ -17,7 +17,8 @@ use crate::avm2::Error;
 use crate::backend::audio::{SoundHandle, SoundInstanceHandle};
 use crate::bitmap::bitmap_data::BitmapData;
 use crate::display_object::DisplayObject;
-use crate::html::TextFormat;
+use crate::html::{StyleSheet, TextFormat};
+use crate::streams::NetStream;
 use crate::string::AvmString;
 use gc_arena::{Collect, GcCell, MutationContext};
 use ruffle_macros::enum_trait_object;
@@ -36,17 +37,22 @@ mod domain_object;
 mod event_object;
 mod function_object;
 mod loaderinfo_object;
+mod local_connection_object;
 mod namespace_object;
+mod netstream_object;
 mod primitive_object;
 mod proxy_object;
 mod qname_object;
 mod regexp_object;
 mod script_object;
+mod socket_object;
 mod sound_object;
 mod soundchannel_object;
 mod stage_object;
+mod stylesheet_object;
 mod textformat_object;
 mod vector_object;
+mod xml_list_object;
 mod xml_object;
 
 pub use crate::avm2::object::array_object::{array_allocator, ArrayObject};
@@ -62,17 +68,24 @@ pub use crate::avm2::object::function_object::FunctionObject;
 pub use crate::avm2::object::loaderinfo_object::{
     loaderinfo_allocator, LoaderInfoObject, LoaderStream,
 };
+pub use crate::avm2::object::local_connection_object::{
+    local_connection_allocator, LocalConnectionObject,
+};
 pub use crate::avm2::object::namespace_object::{namespace_allocator, NamespaceObject};
+pub use crate::avm2::object::netstream_object::{netstream_allocator, NetStreamObject};
 pub use crate::avm2::object::primitive_object::{primitive_allocator, PrimitiveObject};
 pub use crate::avm2::object::proxy_object::{proxy_allocator, ProxyObject};
 pub use crate::avm2::object::qname_object::{qname_allocator, QNameObject};
 pub use crate::avm2::object::regexp_object::{regexp_allocator, RegExpObject};
 pub use crate::avm2::object::script_object::{ScriptObject, ScriptObjectData};
-pub use crate::avm2::object::sound_object::{sound_allocator, SoundObject};
+pub use crate::avm2::object::socket_object::{socket_allocator, SocketObject, SocketStorage};
+pub use crate::avm2::object::sound_object::{sound_allocator, QueuedPlay, SoundObject};
 pub use crate::avm2::object::soundchannel_object::{soundchannel_allocator, SoundChannelObject};
 pub use crate::avm2::object::stage_object::{stage_allocator, StageObject};
+pub use crate::avm2::object::stylesheet_object::{stylesheet_allocator, StyleSheetObject};
 pub use crate::avm2::object::textformat_object::{textformat_allocator, TextFormatObject};
 pub use crate::avm2::object::vector_object::{vector_allocator, VectorObject};
+pub use crate::avm2::object::xml_list_object::{xml_list_allocator, XmlListObject};
 pub use crate::avm2::object::xml_object::{xml_allocator, XmlObject};
 
 /// Represents an object that can be directly interacted with by the AVM2
@@ -92,6 +105,7 @@ pub use crate::avm2::object::xml_object::{xml_allocator, XmlObject};
         EventObject(EventObject<'gc>),
         DispatchObject(DispatchObject<'gc>),
         XmlObject(XmlObject<'gc>),
+        XmlListObject(XmlListObject<'gc>),
         RegExpObject(RegExpObject<'gc>),
         ByteArrayObject(ByteArrayObject<'gc>),
         LoaderInfoObject(LoaderInfoObject<'gc>),
@@ -104,7 +118,11 @@ pub use crate::avm2::object::xml_object::{xml_allocator, XmlObject};
         DictionaryObject(DictionaryObject<'gc>),
         QNameObject(QNameObject<'gc>),
         TextFormatObject(TextFormatObject<'gc>),
+        StyleSheetObject(StyleSheetObject<'gc>),
         ProxyObject(ProxyObject<'gc>),
+        NetStreamObject(NetStreamObject<'gc>),
+        SocketObject(SocketObject<'gc>),
+        LocalConnectionObject(LocalConnectionObject<'gc>),
     }
 )]
 pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy {
@@ -471,6 +489,18 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         }
     }
 
+    /// Retrieve all descendants of this object that match a given name.
+    ///
+    /// This corresponds directly to the AVM2 operation `getdescendants`, which
+    /// is only supported by XML objects (and objects that emulate them).
+    fn get_descendants(
+        self,
+        _multiname: &Multiname<'gc>,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        Err("TypeError: Error #1016: Descendants operator (..) not supported on this type.".into())
+    }
+
     /// Retrieve the `__proto__` of a given object.
     ///
     /// The proto is another object used to resolve methods across a class of
@@ -855,6 +885,11 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         None
     }
 
+    /// Unwrap this object as a `NamespaceObject`
+    fn as_namespace_object(self) -> Option<NamespaceObject<'gc>> {
+        None
+    }
+
     /// Unwrap this object as a `QNameObject`
     fn as_qname_object(self) -> Option<QNameObject<'gc>> {
         None
@@ -968,6 +1003,11 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         None
     }
 
+    /// Unwrap this object as a loader info object.
+    fn as_loader_info_object(&self) -> Option<LoaderInfoObject<'gc>> {
+        None
+    }
+
     /// Unwrap this object's loader stream
     fn as_loader_stream(&self) -> Option<Ref<LoaderStream<'gc>>> {
         None
@@ -983,6 +1023,11 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     /// This does nothing if the object is not a sound.
     fn set_sound(self, _mc: MutationContext<'gc, '_>, _sound: SoundHandle) {}
 
+    /// Unwrap this object as a sound.
+    fn as_sound_object(self) -> Option<SoundObject<'gc>> {
+        None
+    }
+
     /// Unwrap this object's sound instance handle.
     fn as_sound_channel(self) -> Option<SoundChannelObject<'gc>> {
         None
@@ -1021,6 +1066,16 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
         None
     }
 
+    /// Get this object as an `XmlObject`, if it is one.
+    fn as_xml_object(&self) -> Option<XmlObject<'gc>> {
+        None
+    }
+
+    /// Get this object as an `XmlListObject`, if it is one.
+    fn as_xml_list_object(&self) -> Option<XmlListObject<'gc>> {
+        None
+    }
+
     /// Unwrap this object as a text format.
     fn as_text_format(&self) -> Option<Ref<TextFormat>> {
         None
@@ -1030,6 +1085,36 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     fn as_text_format_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<TextFormat>> {
         None
     }
+
+    /// Unwrap this object as a style sheet.
+    fn as_style_sheet(&self) -> Option<Ref<StyleSheet>> {
+        None
+    }
+
+    /// Unwrap this object as a mutable style sheet.
+    fn as_style_sheet_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<StyleSheet>> {
+        None
+    }
+
+    /// Unwrap this object as a net stream.
+    fn as_netstream(&self) -> Option<NetStream<'gc>> {
+        None
+    }
+
+    /// Unwrap this object as a socket.
+    fn as_socket(&self) -> Option<Ref<SocketStorage>> {
+        None
+    }
+
+    /// Unwrap this object as a mutable socket.
+    fn as_socket_mut(&self, _mc: MutationContext<'gc, '_>) -> Option<RefMut<SocketStorage>> {
+        None
+    }
+
+    /// Unwrap this object as a local connection.
+    fn as_local_connection(&self) -> Option<LocalConnectionObject<'gc>> {
+        None
+    }
 }
 
 pub enum ObjectPtr {}
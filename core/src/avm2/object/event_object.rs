@@ -54,6 +54,14 @@ impl<'gc> EventObject<'gc> {
             EventData::Empty => activation.avm2().classes().event,
             EventData::FullScreen { .. } => activation.avm2().classes().fullscreenevent,
             EventData::Mouse { .. } => activation.avm2().classes().mouseevent,
+            EventData::Progress { .. } => activation.avm2().classes().progressevent,
+            EventData::IOError { .. } => activation.avm2().classes().ioerrorevent,
+            EventData::SecurityError { .. } => activation.avm2().classes().securityerrorevent,
+            EventData::HttpStatus { .. } => activation.avm2().classes().httpstatusevent,
+            EventData::NetStatus { .. } => activation.avm2().classes().netstatusevent,
+            EventData::Data { .. } => activation.avm2().classes().dataevent,
+            EventData::Status { .. } => activation.avm2().classes().statusevent,
+            EventData::Timer => activation.avm2().classes().timerevent,
         };
 
         let proto = class.prototype();
@@ -25,6 +25,7 @@ pub fn loaderinfo_allocator<'gc>(
         LoaderInfoObjectData {
             base,
             loaded_stream: None,
+            loader: None,
         },
     ))
     .into())
@@ -45,6 +46,11 @@ pub enum LoaderStream<'gc> {
     ///
     /// The associated `DisplayObject` is the root movieclip.
     Swf(Arc<SwfMovie>, DisplayObject<'gc>),
+
+    /// The content of a `Loader` that has not finished loading yet.
+    ///
+    /// Most properties yield the not-yet-loaded error.
+    NotYetLoaded,
 }
 
 /// An Object which represents a loadable object, such as a SWF movie or image
@@ -61,6 +67,9 @@ pub struct LoaderInfoObjectData<'gc> {
 
     /// The loaded stream that this gets it's info from.
     loaded_stream: Option<LoaderStream<'gc>>,
+
+    /// The `Loader` object that owns this loader info, if any.
+    loader: Option<Object<'gc>>,
 }
 
 impl<'gc> LoaderInfoObject<'gc> {
@@ -80,6 +89,7 @@ impl<'gc> LoaderInfoObject<'gc> {
             LoaderInfoObjectData {
                 base,
                 loaded_stream,
+                loader: None,
             },
         ))
         .into();
@@ -101,6 +111,7 @@ impl<'gc> LoaderInfoObject<'gc> {
             LoaderInfoObjectData {
                 base,
                 loaded_stream: Some(LoaderStream::Stage),
+                loader: None,
             },
         ))
         .into();
@@ -110,6 +121,42 @@ impl<'gc> LoaderInfoObject<'gc> {
 
         Ok(this)
     }
+
+    /// Create a loader info object for the content of a `Loader` that has
+    /// not been loaded yet.
+    pub fn not_yet_loaded(
+        activation: &mut Activation<'_, 'gc, '_>,
+        loader: Object<'gc>,
+    ) -> Result<Object<'gc>, Error> {
+        let class = activation.avm2().classes().loaderinfo;
+        let proto = activation.avm2().prototypes().loaderinfo;
+        let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+        let mut this: Object<'gc> = LoaderInfoObject(GcCell::allocate(
+            activation.context.gc_context,
+            LoaderInfoObjectData {
+                base,
+                loaded_stream: Some(LoaderStream::NotYetLoaded),
+                loader: Some(loader),
+            },
+        ))
+        .into();
+        this.install_instance_slots(activation);
+
+        class.call_native_init(Some(this), &[], activation)?;
+
+        Ok(this)
+    }
+
+    /// The `Loader` object that owns this loader info, if any.
+    pub fn loader(self) -> Option<Object<'gc>> {
+        self.0.read().loader
+    }
+
+    /// Replace the stream this loader info gets its info from.
+    pub fn set_loader_stream(self, mc: MutationContext<'gc, '_>, stream: LoaderStream<'gc>) {
+        self.0.write(mc).loaded_stream = Some(stream);
+    }
 }
 
 impl<'gc> TObject<'gc> for LoaderInfoObject<'gc> {
@@ -136,6 +183,10 @@ impl<'gc> TObject<'gc> for LoaderInfoObject<'gc> {
         }
     }
 
+    fn as_loader_info_object(&self) -> Option<LoaderInfoObject<'gc>> {
+        Some(*self)
+    }
+
     /// Unwrap this object's loader stream
     fn as_loader_stream(&self) -> Option<Ref<LoaderStream<'gc>>> {
         if self.0.read().loaded_stream.is_some() {
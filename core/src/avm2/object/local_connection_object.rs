@@ -0,0 +1,107 @@
+//! Object representation for LocalConnection
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::local_connection::LocalConnectionHandle;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+
+/// A class instance allocator that allocates LocalConnection objects.
+pub fn local_connection_allocator<'gc>(
+    class: ClassObject<'gc>,
+    proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+    Ok(LocalConnectionObject(GcCell::allocate(
+        activation.context.gc_context,
+        LocalConnectionObjectData {
+            base,
+            handle: None,
+            client: None,
+            allowed_domains: Vec::new(),
+        },
+    ))
+    .into())
+}
+
+#[derive(Clone, Collect, Debug, Copy)]
+#[collect(no_drop)]
+pub struct LocalConnectionObject<'gc>(GcCell<'gc, LocalConnectionObjectData<'gc>>);
+
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct LocalConnectionObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The handle of the connection, if it is listening.
+    #[collect(require_static)]
+    handle: Option<LocalConnectionHandle>,
+
+    /// The object that received methods are invoked on, if it isn't this one.
+    client: Option<Object<'gc>>,
+
+    /// The domains passed to `allowDomain` and `allowInsecureDomain`.
+    #[collect(require_static)]
+    allowed_domains: Vec<String>,
+}
+
+impl<'gc> LocalConnectionObject<'gc> {
+    pub fn handle(self) -> Option<LocalConnectionHandle> {
+        self.0.read().handle
+    }
+
+    pub fn set_handle(self, mc: MutationContext<'gc, '_>, handle: Option<LocalConnectionHandle>) {
+        self.0.write(mc).handle = handle;
+    }
+
+    pub fn client(self) -> Option<Object<'gc>> {
+        self.0.read().client
+    }
+
+    pub fn set_client(self, mc: MutationContext<'gc, '_>, client: Option<Object<'gc>>) {
+        self.0.write(mc).client = client;
+    }
+
+    /// Accept messages from movies of `domain`, or of every domain if it is `*`.
+    pub fn allow_domain(self, mc: MutationContext<'gc, '_>, domain: String) {
+        self.0.write(mc).allowed_domains.push(domain);
+    }
+
+    /// Whether messages from movies of `domain` were allowed.
+    pub fn is_domain_allowed(self, domain: &str) -> bool {
+        self.0
+            .read()
+            .allowed_domains
+            .iter()
+            .any(|allowed| allowed == "*" || allowed.eq_ignore_ascii_case(domain))
+    }
+}
+
+impl<'gc> TObject<'gc> for LocalConnectionObject<'gc> {
+    fn base(&self) -> Ref<ScriptObjectData<'gc>> {
+        Ref::map(self.0.read(), |read| &read.base)
+    }
+
+    fn base_mut(&self, mc: MutationContext<'gc, '_>) -> RefMut<ScriptObjectData<'gc>> {
+        RefMut::map(self.0.write(mc), |write| &mut write.base)
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Value::Object(Object::from(*self)))
+    }
+
+    /// Unwrap this object as a local connection.
+    fn as_local_connection(&self) -> Option<LocalConnectionObject<'gc>> {
+        Some(*self)
+    }
+}
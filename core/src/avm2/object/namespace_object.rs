@@ -6,6 +6,7 @@ use crate::avm2::object::script_object::ScriptObjectData;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
+use crate::string::AvmString;
 use gc_arena::{Collect, GcCell, MutationContext};
 use std::cell::{Ref, RefMut};
 
@@ -22,6 +23,7 @@ pub fn namespace_allocator<'gc>(
         NamespaceObjectData {
             base,
             namespace: Namespace::public(),
+            prefix: None,
         },
     ))
     .into())
@@ -40,6 +42,9 @@ pub struct NamespaceObjectData<'gc> {
 
     /// The namespace name this object is associated with.
     namespace: Namespace<'gc>,
+
+    /// The prefix bound to this namespace, if it has one.
+    prefix: Option<AvmString<'gc>>,
 }
 
 impl<'gc> NamespaceObject<'gc> {
@@ -47,6 +52,15 @@ impl<'gc> NamespaceObject<'gc> {
     pub fn from_namespace(
         activation: &mut Activation<'_, 'gc, '_>,
         namespace: Namespace<'gc>,
+    ) -> Result<Object<'gc>, Error> {
+        Self::from_prefix_and_namespace(activation, None, namespace)
+    }
+
+    /// Box a namespace bound to a prefix into an object.
+    pub fn from_prefix_and_namespace(
+        activation: &mut Activation<'_, 'gc, '_>,
+        prefix: Option<AvmString<'gc>>,
+        namespace: Namespace<'gc>,
     ) -> Result<Object<'gc>, Error> {
         let class = activation.avm2().classes().namespace;
         let proto = activation.avm2().prototypes().namespace;
@@ -54,7 +68,11 @@ impl<'gc> NamespaceObject<'gc> {
 
         let mut this: Object<'gc> = NamespaceObject(GcCell::allocate(
             activation.context.gc_context,
-            NamespaceObjectData { base, namespace },
+            NamespaceObjectData {
+                base,
+                namespace,
+                prefix,
+            },
         ))
         .into();
         this.install_instance_slots(activation);
@@ -63,6 +81,25 @@ impl<'gc> NamespaceObject<'gc> {
 
         Ok(this)
     }
+
+    pub fn namespace(self) -> Namespace<'gc> {
+        self.0.read().namespace
+    }
+
+    pub fn prefix(self) -> Option<AvmString<'gc>> {
+        self.0.read().prefix
+    }
+
+    pub fn init_namespace(
+        self,
+        mc: MutationContext<'gc, '_>,
+        prefix: Option<AvmString<'gc>>,
+        namespace: Namespace<'gc>,
+    ) {
+        let mut write = self.0.write(mc);
+        write.prefix = prefix;
+        write.namespace = namespace;
+    }
 }
 
 impl<'gc> TObject<'gc> for NamespaceObject<'gc> {
@@ -89,4 +126,8 @@ impl<'gc> TObject<'gc> for NamespaceObject<'gc> {
     fn as_namespace(&self) -> Option<Ref<Namespace<'gc>>> {
         Some(Ref::map(self.0.read(), |s| &s.namespace))
     }
+
+    fn as_namespace_object(self) -> Option<NamespaceObject<'gc>> {
+        Some(self)
+    }
 }
@@ -0,0 +1,66 @@
+//! Object representation for NetStream
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::streams::NetStream;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+
+/// A class instance allocator that allocates NetStream objects.
+pub fn netstream_allocator<'gc>(
+    class: ClassObject<'gc>,
+    proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let base = ScriptObjectData::base_new(Some(proto), Some(class));
+    let net_stream = NetStream::new(activation.context.gc_context);
+
+    let object: Object<'gc> = NetStreamObject(GcCell::allocate(
+        activation.context.gc_context,
+        NetStreamObjectData { base, net_stream },
+    ))
+    .into();
+    net_stream.set_avm_object(activation.context.gc_context, object.into());
+    net_stream.set_movie(activation.context.gc_context, activation.caller_movie());
+
+    Ok(object)
+}
+
+#[derive(Clone, Collect, Debug, Copy)]
+#[collect(no_drop)]
+pub struct NetStreamObject<'gc>(GcCell<'gc, NetStreamObjectData<'gc>>);
+
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct NetStreamObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    net_stream: NetStream<'gc>,
+}
+
+impl<'gc> TObject<'gc> for NetStreamObject<'gc> {
+    fn base(&self) -> Ref<ScriptObjectData<'gc>> {
+        Ref::map(self.0.read(), |read| &read.base)
+    }
+
+    fn base_mut(&self, mc: MutationContext<'gc, '_>) -> RefMut<ScriptObjectData<'gc>> {
+        RefMut::map(self.0.write(mc), |write| &mut write.base)
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Value::Object(Object::from(*self)))
+    }
+
+    /// Unwrap this object as a net stream.
+    fn as_netstream(&self) -> Option<NetStream<'gc>> {
+        Some(self.0.read().net_stream)
+    }
+}
@@ -58,24 +58,12 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         multiname: &Multiname<'gc>,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Value<'gc>, Error> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    return self.call_property(
-                        &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "getProperty")
-                            .into(),
-                        &[qname.into()],
-                        activation,
-                    );
-                }
-            }
+        if let Some(name) = proxy_name(activation, multiname)? {
+            return self.call_property(
+                &flash_proxy_method("getProperty"),
+                &[name.into()],
+                activation,
+            );
         }
 
         if !self
@@ -95,26 +83,14 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         value: Value<'gc>,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<(), Error> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    self.call_property(
-                        &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "setProperty")
-                            .into(),
-                        &[qname.into(), value],
-                        activation,
-                    )?;
-
-                    return Ok(());
-                }
-            }
+        if let Some(name) = proxy_name(activation, multiname)? {
+            self.call_property(
+                &flash_proxy_method("setProperty"),
+                &[name.into(), value],
+                activation,
+            )?;
+
+            return Ok(());
         }
 
         if !self
@@ -138,27 +114,11 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         arguments: &[Value<'gc>],
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Value<'gc>, Error> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    let mut args = vec![qname.into()];
-                    args.extend_from_slice(arguments);
-
-                    return self.call_property(
-                        &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "callProperty")
-                            .into(),
-                        &args[..],
-                        activation,
-                    );
-                }
-            }
+        if let Some(name) = proxy_name(activation, multiname)? {
+            let mut args = vec![name.into()];
+            args.extend_from_slice(arguments);
+
+            return self.call_property(&flash_proxy_method("callProperty"), &args[..], activation);
         }
 
         Err(format!(
@@ -173,29 +133,14 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         activation: &mut Activation<'_, 'gc, '_>,
         multiname: &Multiname<'gc>,
     ) -> Result<bool, Error> {
-        // NOTE: This is incorrect behavior.
-        // `QName` should instead store the whole multiname's namespace set,
-        // so that it can be used to index other objects using the same
-        // namespace set.
-        if let Some(local_name) = multiname.local_name() {
-            for namespace in multiname.namespace_set() {
-                if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
-                    let qname =
-                        QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
-
-                    return Ok(self
-                        .call_property(
-                            &QName::new(
-                                Namespace::Namespace(NS_FLASH_PROXY.into()),
-                                "deleteProperty",
-                            )
-                            .into(),
-                            &[qname.into()],
-                            activation,
-                        )?
-                        .coerce_to_boolean());
-                }
-            }
+        if let Some(name) = proxy_name(activation, multiname)? {
+            return Ok(self
+                .call_property(
+                    &flash_proxy_method("deleteProperty"),
+                    &[name.into()],
+                    activation,
+                )?
+                .coerce_to_boolean());
         }
 
         // Unknown properties on a dynamic class delete successfully.
@@ -205,19 +150,52 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
             .unwrap_or(false));
     }
 
+    fn delete_property(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<bool, Error> {
+        // Proxy subclasses are usually sealed, so unlike other objects we
+        // can't refuse to delete unknown properties before `deleteProperty`
+        // gets a say in the matter.
+        match self.vtable().and_then(|vtable| vtable.get_trait(multiname)) {
+            None => self.delete_property_local(activation, multiname),
+            _ => Ok(false),
+        }
+    }
+
     fn has_property_via_in(
         self,
         activation: &mut Activation<'_, 'gc, '_>,
-        name: &Multiname<'gc>,
+        multiname: &Multiname<'gc>,
     ) -> Result<bool, Error> {
-        Ok(self
-            .call_property(
-                &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "hasProperty").into(),
-                // this should probably pass the multiname as-is? See above
-                &[name.local_name().unwrap().into()],
+        if let Some(name) = proxy_name(activation, multiname)? {
+            return Ok(self
+                .call_property(
+                    &flash_proxy_method("hasProperty"),
+                    &[name.into()],
+                    activation,
+                )?
+                .coerce_to_boolean());
+        }
+
+        Ok(self.has_property(multiname))
+    }
+
+    fn get_descendants(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        if let Some(name) = proxy_name(activation, multiname)? {
+            return self.call_property(
+                &flash_proxy_method("getDescendants"),
+                &[name.into()],
                 activation,
-            )?
-            .coerce_to_boolean())
+            );
+        }
+
+        Err("TypeError: Error #1016: Descendants operator (..) not supported on this type.".into())
     }
 
     fn get_next_enumerant(
@@ -225,14 +203,20 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         last_index: u32,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Option<u32>, Error> {
-        Ok(Some(
-            self.call_property(
-                &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "nextNameIndex").into(),
+        let next_index = self
+            .call_property(
+                &flash_proxy_method("nextNameIndex"),
                 &[last_index.into()],
                 activation,
             )?
-            .coerce_to_u32(activation)?,
-        ))
+            .coerce_to_u32(activation)?;
+
+        // `nextNameIndex` signals the end of enumeration by returning zero.
+        if next_index == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(next_index))
     }
 
     fn get_enumerant_name(
@@ -240,11 +224,7 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         index: u32,
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Value<'gc>, Error> {
-        self.call_property(
-            &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "nextName").into(),
-            &[index.into()],
-            activation,
-        )
+        self.call_property(&flash_proxy_method("nextName"), &[index.into()], activation)
     }
 
     fn get_enumerant_value(
@@ -253,9 +233,42 @@ impl<'gc> TObject<'gc> for ProxyObject<'gc> {
         activation: &mut Activation<'_, 'gc, '_>,
     ) -> Result<Value<'gc>, Error> {
         self.call_property(
-            &QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), "nextValue").into(),
+            &flash_proxy_method("nextValue"),
             &[index.into()],
             activation,
         )
     }
 }
+
+/// Name one of the `flash_proxy` methods that subclasses override.
+fn flash_proxy_method<'gc>(local_name: &'static str) -> Multiname<'gc> {
+    QName::new(Namespace::Namespace(NS_FLASH_PROXY.into()), local_name).into()
+}
+
+/// Box the name of a property access into a `QName` for a `flash_proxy`
+/// method.
+///
+/// Returns `None` if the multiname has no local name or no namespace that a
+/// proxy can be asked about.
+///
+/// NOTE: This is incorrect behavior.
+/// `QName` should instead store the whole multiname's namespace set,
+/// so that it can be used to index other objects using the same
+/// namespace set.
+fn proxy_name<'gc>(
+    activation: &mut Activation<'_, 'gc, '_>,
+    multiname: &Multiname<'gc>,
+) -> Result<Option<Object<'gc>>, Error> {
+    if let Some(local_name) = multiname.local_name() {
+        for namespace in multiname.namespace_set() {
+            if namespace.is_any() || namespace.is_public() || namespace.is_namespace() {
+                let qname =
+                    QNameObject::from_qname(activation, QName::new(*namespace, local_name))?;
+
+                return Ok(Some(qname));
+            }
+        }
+    }
+
+    Ok(None)
+}
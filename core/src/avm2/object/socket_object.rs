@@ -0,0 +1,167 @@
+//! Object representation for sockets
+
+use crate::avm2::activation::Activation;
+use crate::avm2::bytearray::{ByteArrayStorage, Endian};
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::backend::socket::SocketHandle;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+
+/// A class instance allocator that allocates `Socket` and `XMLSocket` objects.
+pub fn socket_allocator<'gc>(
+    class: ClassObject<'gc>,
+    proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+    Ok(SocketObject(GcCell::allocate(
+        activation.context.gc_context,
+        SocketObjectData {
+            base,
+            socket: SocketStorage::new(),
+        },
+    ))
+    .into())
+}
+
+/// The state of a socket that script can access.
+#[derive(Clone, Collect, Debug)]
+#[collect(require_static)]
+pub struct SocketStorage {
+    /// The handle of the connection, if it was opened.
+    handle: Option<SocketHandle>,
+
+    /// Data received from the remote end that hasn't been read yet.
+    input: ByteArrayStorage,
+
+    /// Data written by script that hasn't been sent yet.
+    output: ByteArrayStorage,
+
+    /// The connection timeout in milliseconds.
+    timeout: u32,
+}
+
+impl SocketStorage {
+    pub fn new() -> Self {
+        Self {
+            handle: None,
+            input: ByteArrayStorage::new(),
+            output: ByteArrayStorage::new(),
+            timeout: 20000,
+        }
+    }
+
+    pub fn handle(&self) -> Option<SocketHandle> {
+        self.handle
+    }
+
+    /// Set the handle of a new connection, discarding the data of the old one.
+    pub fn set_handle(&mut self, handle: Option<SocketHandle>) {
+        self.handle = handle;
+        self.input.clear();
+        self.output.clear();
+    }
+
+    /// The data that was received and not read yet.
+    pub fn input(&self) -> &ByteArrayStorage {
+        &self.input
+    }
+
+    /// The number of bytes that were written and not sent yet.
+    pub fn bytes_pending(&self) -> usize {
+        self.output.len()
+    }
+
+    /// The data that was written and not sent yet.
+    pub fn output_mut(&mut self) -> &mut ByteArrayStorage {
+        &mut self.output
+    }
+
+    pub fn endian(&self) -> Endian {
+        self.input.endian()
+    }
+
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.input.set_endian(endian);
+        self.output.set_endian(endian);
+    }
+
+    pub fn timeout(&self) -> u32 {
+        self.timeout
+    }
+
+    pub fn set_timeout(&mut self, timeout: u32) {
+        self.timeout = timeout;
+    }
+
+    /// Append data received from the remote end to the unread input.
+    pub fn receive(&mut self, data: &[u8]) {
+        // Drop the data that was already read, so that the buffer doesn't
+        // grow for the whole lifetime of the connection.
+        let start = self.input.position().min(self.input.len());
+        let mut bytes = self.input.bytes()[start..].to_vec();
+        bytes.extend_from_slice(data);
+
+        let endian = self.input.endian();
+        self.input = ByteArrayStorage::from_vec(bytes);
+        self.input.set_endian(endian);
+    }
+
+    /// Take the data that was written, to send it.
+    pub fn take_output(&mut self) -> Vec<u8> {
+        let data = self.output.bytes().to_vec();
+        self.output.clear();
+        data
+    }
+}
+
+impl Default for SocketStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Collect, Debug, Copy)]
+#[collect(no_drop)]
+pub struct SocketObject<'gc>(GcCell<'gc, SocketObjectData<'gc>>);
+
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct SocketObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    socket: SocketStorage,
+}
+
+impl<'gc> TObject<'gc> for SocketObject<'gc> {
+    fn base(&self) -> Ref<ScriptObjectData<'gc>> {
+        Ref::map(self.0.read(), |read| &read.base)
+    }
+
+    fn base_mut(&self, mc: MutationContext<'gc, '_>) -> RefMut<ScriptObjectData<'gc>> {
+        RefMut::map(self.0.write(mc), |write| &mut write.base)
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Value::Object(Object::from(*self)))
+    }
+
+    /// Unwrap this object as a socket.
+    fn as_socket(&self) -> Option<Ref<SocketStorage>> {
+        Some(Ref::map(self.0.read(), |d| &d.socket))
+    }
+
+    /// Unwrap this object as a mutable socket.
+    fn as_socket_mut(&self, mc: MutationContext<'gc, '_>) -> Option<RefMut<SocketStorage>> {
+        Some(RefMut::map(self.0.write(mc), |d| &mut d.socket))
+    }
+}
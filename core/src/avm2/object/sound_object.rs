@@ -2,12 +2,15 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::SoundChannelObject;
 use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::backend::audio::SoundHandle;
+use crate::display_object::SoundTransform;
 use gc_arena::{Collect, GcCell, MutationContext};
 use std::cell::{Ref, RefMut};
+use swf::SoundInfo;
 
 /// A class instance allocator that allocates Sound objects.
 pub fn sound_allocator<'gc>(
@@ -19,7 +22,16 @@ pub fn sound_allocator<'gc>(
 
     Ok(SoundObject(GcCell::allocate(
         activation.context.gc_context,
-        SoundObjectData { base, sound: None },
+        SoundObjectData {
+            base,
+            sound: None,
+            url: None,
+            is_loading: false,
+            bytes_loaded: 0,
+            bytes_total: 0,
+            id3: None,
+            queued_plays: Vec::new(),
+        },
     ))
     .into())
 }
@@ -37,6 +49,39 @@ pub struct SoundObjectData<'gc> {
     /// The sound this object holds.
     #[collect(require_static)]
     sound: Option<SoundHandle>,
+
+    /// The URL passed to `Sound.load`, if any.
+    #[collect(require_static)]
+    url: Option<String>,
+
+    /// Whether a `Sound.load` call is still in progress.
+    is_loading: bool,
+
+    /// The number of bytes of the loaded file that have arrived.
+    bytes_loaded: u32,
+
+    /// The size of the loaded file in bytes.
+    bytes_total: u32,
+
+    /// The `ID3Info` of the loaded file, if it had ID3 tags.
+    id3: Option<Object<'gc>>,
+
+    /// Calls to `play` made while the sound was loading, which start playing
+    /// once it has loaded.
+    queued_plays: Vec<QueuedPlay<'gc>>,
+}
+
+/// A call to `Sound.play` that waits for the sound to finish loading.
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct QueuedPlay<'gc> {
+    /// The channel returned by `play`, which the sound instance is attached to.
+    pub channel: SoundChannelObject<'gc>,
+
+    #[collect(require_static)]
+    pub sound_info: SoundInfo,
+
+    pub sound_transform: Option<SoundTransform>,
 }
 
 impl<'gc> SoundObject<'gc> {
@@ -58,6 +103,12 @@ impl<'gc> SoundObject<'gc> {
             SoundObjectData {
                 base,
                 sound: Some(sound),
+                url: None,
+                is_loading: false,
+                bytes_loaded: 0,
+                bytes_total: 0,
+                id3: None,
+                queued_plays: Vec::new(),
             },
         ))
         .into();
@@ -67,6 +118,60 @@ impl<'gc> SoundObject<'gc> {
 
         Ok(sound_object)
     }
+
+    pub fn url(self) -> Option<String> {
+        self.0.read().url.clone()
+    }
+
+    /// Start loading the sound from a URL.
+    pub fn start_loading(self, mc: MutationContext<'gc, '_>, url: String) {
+        let mut write = self.0.write(mc);
+        write.url = Some(url);
+        write.is_loading = true;
+    }
+
+    /// Stop loading the sound, whether it has finished or not.
+    pub fn stop_loading(self, mc: MutationContext<'gc, '_>) {
+        let mut write = self.0.write(mc);
+        write.is_loading = false;
+        write.queued_plays.clear();
+    }
+
+    pub fn is_loading(self) -> bool {
+        self.0.read().is_loading
+    }
+
+    pub fn bytes_loaded(self) -> u32 {
+        self.0.read().bytes_loaded
+    }
+
+    pub fn bytes_total(self) -> u32 {
+        self.0.read().bytes_total
+    }
+
+    pub fn set_progress(self, mc: MutationContext<'gc, '_>, bytes_loaded: u32, bytes_total: u32) {
+        let mut write = self.0.write(mc);
+        write.bytes_loaded = bytes_loaded;
+        write.bytes_total = bytes_total;
+    }
+
+    pub fn id3(self) -> Option<Object<'gc>> {
+        self.0.read().id3
+    }
+
+    pub fn set_id3(self, mc: MutationContext<'gc, '_>, id3: Option<Object<'gc>>) {
+        self.0.write(mc).id3 = id3;
+    }
+
+    /// Start a play call once the sound has loaded.
+    pub fn queue_play(self, mc: MutationContext<'gc, '_>, play: QueuedPlay<'gc>) {
+        self.0.write(mc).queued_plays.push(play);
+    }
+
+    /// Take the play calls that were waiting for the sound to load.
+    pub fn take_queued_plays(self, mc: MutationContext<'gc, '_>) -> Vec<QueuedPlay<'gc>> {
+        std::mem::take(&mut self.0.write(mc).queued_plays)
+    }
 }
 
 impl<'gc> TObject<'gc> for SoundObject<'gc> {
@@ -96,4 +201,8 @@ impl<'gc> TObject<'gc> for SoundObject<'gc> {
     fn set_sound(self, mc: MutationContext<'gc, '_>, sound: SoundHandle) {
         self.0.write(mc).sound = Some(sound);
     }
+
+    fn as_sound_object(self) -> Option<SoundObject<'gc>> {
+        Some(self)
+    }
 }
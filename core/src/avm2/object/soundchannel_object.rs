@@ -51,6 +51,20 @@ impl<'gc> SoundChannelObject<'gc> {
     pub fn from_sound_instance(
         activation: &mut Activation<'_, 'gc, '_>,
         sound: SoundInstanceHandle,
+    ) -> Result<Self, Error> {
+        Self::new(activation, Some(sound))
+    }
+
+    /// Create a channel for a sound that hasn't started playing yet.
+    ///
+    /// The instance is attached with `set_sound_instance` once it starts.
+    pub fn empty(activation: &mut Activation<'_, 'gc, '_>) -> Result<Self, Error> {
+        Self::new(activation, None)
+    }
+
+    fn new(
+        activation: &mut Activation<'_, 'gc, '_>,
+        sound: Option<SoundInstanceHandle>,
     ) -> Result<Self, Error> {
         let class = activation.avm2().classes().soundchannel;
         let proto = class.prototype();
@@ -60,7 +74,7 @@ impl<'gc> SoundChannelObject<'gc> {
             activation.context.gc_context,
             SoundChannelObjectData {
                 base,
-                sound: Some(sound),
+                sound,
                 position: 0.0,
             },
         ));
@@ -0,0 +1,69 @@
+//! Object representation for StyleSheet
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::html::StyleSheet;
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+
+/// A class instance allocator that allocates StyleSheet objects.
+pub fn stylesheet_allocator<'gc>(
+    class: ClassObject<'gc>,
+    proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+    Ok(StyleSheetObject(GcCell::allocate(
+        activation.context.gc_context,
+        StyleSheetObjectData {
+            base,
+            style_sheet: Default::default(),
+        },
+    ))
+    .into())
+}
+
+#[derive(Clone, Collect, Debug, Copy)]
+#[collect(no_drop)]
+pub struct StyleSheetObject<'gc>(GcCell<'gc, StyleSheetObjectData<'gc>>);
+
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct StyleSheetObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    style_sheet: StyleSheet,
+}
+
+impl<'gc> TObject<'gc> for StyleSheetObject<'gc> {
+    fn base(&self) -> Ref<ScriptObjectData<'gc>> {
+        Ref::map(self.0.read(), |read| &read.base)
+    }
+
+    fn base_mut(&self, mc: MutationContext<'gc, '_>) -> RefMut<ScriptObjectData<'gc>> {
+        RefMut::map(self.0.write(mc), |write| &mut write.base)
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Value::Object(Object::from(*self)))
+    }
+
+    /// Unwrap this object as a style sheet.
+    fn as_style_sheet(&self) -> Option<Ref<StyleSheet>> {
+        Some(Ref::map(self.0.read(), |d| &d.style_sheet))
+    }
+
+    /// Unwrap this object as a mutable style sheet.
+    fn as_style_sheet_mut(&self, mc: MutationContext<'gc, '_>) -> Option<RefMut<StyleSheet>> {
+        Some(RefMut::map(self.0.write(mc), |d| &mut d.style_sheet))
+    }
+}
@@ -0,0 +1,477 @@
+//! Object representation for XMLList objects
+
+use crate::avm2::activation::Activation;
+use crate::avm2::e4x::E4XNode;
+use crate::avm2::names::Multiname;
+use crate::avm2::object::script_object::ScriptObjectData;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject, XmlObject};
+use crate::avm2::value::{Hint, Value};
+use crate::avm2::Error;
+use crate::string::{AvmString, WString};
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::cell::{Ref, RefMut};
+
+/// A class instance allocator that allocates XMLList objects.
+pub fn xml_list_allocator<'gc>(
+    class: ClassObject<'gc>,
+    proto: Object<'gc>,
+    activation: &mut Activation<'_, 'gc, '_>,
+) -> Result<Object<'gc>, Error> {
+    let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+    Ok(XmlListObject(GcCell::allocate(
+        activation.context.gc_context,
+        XmlListObjectData {
+            base,
+            children: Vec::new(),
+            target_object: None,
+            target_property: None,
+        },
+    ))
+    .into())
+}
+
+#[derive(Clone, Collect, Debug, Copy)]
+#[collect(no_drop)]
+pub struct XmlListObject<'gc>(GcCell<'gc, XmlListObjectData<'gc>>);
+
+#[derive(Clone, Collect, Debug)]
+#[collect(no_drop)]
+pub struct XmlListObjectData<'gc> {
+    /// Base script object
+    base: ScriptObjectData<'gc>,
+
+    /// The nodes contained in this list.
+    children: Vec<E4XNode<'gc>>,
+
+    /// The element this list was read from, if it is the result of a
+    /// property access on an element.
+    ///
+    /// Items assigned past the end of the list are also inserted into it.
+    target_object: Option<E4XNode<'gc>>,
+
+    /// The name this list was read with from `target_object`.
+    target_property: Option<Multiname<'gc>>,
+}
+
+impl<'gc> XmlListObject<'gc> {
+    /// Construct a new `XMLList` containing a given set of nodes.
+    pub fn new(
+        activation: &mut Activation<'_, 'gc, '_>,
+        children: Vec<E4XNode<'gc>>,
+    ) -> Result<Object<'gc>, Error> {
+        Self::new_with_target(activation, children, None, None)
+    }
+
+    /// Construct a new `XMLList` of the children of `target_object` that
+    /// match `target_property`.
+    pub fn new_with_target(
+        activation: &mut Activation<'_, 'gc, '_>,
+        children: Vec<E4XNode<'gc>>,
+        target_object: Option<E4XNode<'gc>>,
+        target_property: Option<Multiname<'gc>>,
+    ) -> Result<Object<'gc>, Error> {
+        let class = activation.avm2().classes().xml_list;
+        let proto = activation.avm2().prototypes().xml_list;
+        let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+        let mut this: Object<'gc> = XmlListObject(GcCell::allocate(
+            activation.context.gc_context,
+            XmlListObjectData {
+                base,
+                children,
+                target_object,
+                target_property,
+            },
+        ))
+        .into();
+        this.install_instance_slots(activation);
+
+        class.call_native_init(Some(this), &[], activation)?;
+
+        Ok(this)
+    }
+
+    pub fn children(&self) -> Ref<Vec<E4XNode<'gc>>> {
+        Ref::map(self.0.read(), |read| &read.children)
+    }
+
+    pub fn set_children(self, mc: MutationContext<'gc, '_>, children: Vec<E4XNode<'gc>>) {
+        self.0.write(mc).children = children;
+    }
+
+    pub fn length(&self) -> usize {
+        self.0.read().children.len()
+    }
+
+    /// Indicates if this list contains simple content, as per the E4X
+    /// `hasSimpleContent` algorithm.
+    pub fn has_simple_content(&self) -> bool {
+        let children = self.children();
+
+        match &children[..] {
+            [] => true,
+            [child] => child.has_simple_content(),
+            children => !children.iter().any(|c| c.is_element()),
+        }
+    }
+
+    /// Collect the matching children (or attributes) of every element in
+    /// this list.
+    pub fn matching_children(&self, name: &Multiname<'gc>) -> Vec<E4XNode<'gc>> {
+        let mut out = Vec::new();
+
+        for child in self.children().iter() {
+            let candidates = if name.is_attribute() {
+                child.attributes()
+            } else {
+                child.children()
+            };
+
+            out.extend(candidates.into_iter().filter(|c| c.matches_name(name)));
+        }
+
+        out
+    }
+
+    /// Add a value to the end of this list, as per the E4X `[[Put]]` algorithm
+    /// for an index past the end of the list.
+    ///
+    /// If the list was read from an element, the new items are also inserted
+    /// into that element, after the last of the existing items.
+    fn append(
+        self,
+        value: Value<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<(), Error> {
+        let mc = activation.context.gc_context;
+        let (target_object, target_property) = {
+            let read = self.0.read();
+            (read.target_object, read.target_property.clone())
+        };
+
+        let target = target_object.filter(|target| target.is_element());
+
+        // Nodes are only copied if they are about to be inserted into the
+        // target; otherwise the list refers to the original nodes, which is
+        // what filter predicates rely on to build their results.
+        let copy = |node: E4XNode<'gc>| {
+            if target.is_some() {
+                node.deep_copy(mc)
+            } else {
+                node
+            }
+        };
+        let new_nodes = match value.as_object() {
+            Some(o) if o.as_xml_object().is_some() => {
+                vec![copy(o.as_xml_object().unwrap().node())]
+            }
+            Some(o) if o.as_xml_list_object().is_some() => o
+                .as_xml_list_object()
+                .unwrap()
+                .children()
+                .iter()
+                .map(|child| copy(*child))
+                .collect(),
+            _ => {
+                let text = value.coerce_to_string(activation)?;
+                let local_name = target_property
+                    .as_ref()
+                    .and_then(|name| name.local_name())
+                    .filter(|name| name != b"*");
+                match local_name {
+                    Some(local_name) => {
+                        let element = E4XNode::element(mc, None, local_name, None);
+                        element.replace_with_text(mc, text);
+                        vec![element]
+                    }
+                    None => vec![E4XNode::text(mc, text, None)],
+                }
+            }
+        };
+
+        if let Some(target) = target {
+            let siblings = target.children();
+            let last = self.children().last().copied();
+            let mut index = last
+                .and_then(|last| siblings.iter().position(|c| E4XNode::ptr_eq(*c, last)))
+                .map_or(siblings.len(), |i| i + 1);
+
+            for node in new_nodes.iter() {
+                target.insert_child(mc, index, *node);
+                index += 1;
+            }
+        }
+
+        self.0.write(mc).children.extend(new_nodes);
+
+        Ok(())
+    }
+
+    /// Convert this list to a string, as per the E4X `ToString` algorithm.
+    pub fn xml_list_to_string(&self, activation: &mut Activation<'_, 'gc, '_>) -> AvmString<'gc> {
+        if !self.has_simple_content() {
+            return self.xml_list_to_xml_string(activation);
+        }
+
+        let children = self.children().clone();
+        let mut out = WString::new();
+        for child in children {
+            if matches!(child.node_kind(), "comment" | "processing-instruction") {
+                continue;
+            }
+
+            out.push_str(&child.xml_to_string(activation));
+        }
+
+        AvmString::new(activation.context.gc_context, out)
+    }
+
+    /// Convert this list to markup, as per the E4X `ToXMLString` algorithm.
+    pub fn xml_list_to_xml_string(
+        &self,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> AvmString<'gc> {
+        let pretty_printing = activation.avm2().xml_settings.pretty_printing;
+        let children = self.children().clone();
+        let mut out = WString::new();
+
+        for (i, child) in children.into_iter().enumerate() {
+            if i > 0 && pretty_printing {
+                out.push_byte(b'\n');
+            }
+
+            out.push_str(&child.xml_to_xml_string(activation));
+        }
+
+        AvmString::new(activation.context.gc_context, out)
+    }
+}
+
+impl<'gc> TObject<'gc> for XmlListObject<'gc> {
+    fn base(&self) -> Ref<ScriptObjectData<'gc>> {
+        Ref::map(self.0.read(), |read| &read.base)
+    }
+
+    fn base_mut(&self, mc: MutationContext<'gc, '_>) -> RefMut<ScriptObjectData<'gc>> {
+        RefMut::map(self.0.write(mc), |write| &mut write.base)
+    }
+
+    fn as_ptr(&self) -> *const ObjectPtr {
+        self.0.as_ptr() as *const ObjectPtr
+    }
+
+    fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
+        Ok(Value::Object(Object::from(*self)))
+    }
+
+    fn default_hint(&self) -> Hint {
+        Hint::String
+    }
+
+    fn as_xml_list_object(&self) -> Option<XmlListObject<'gc>> {
+        Some(*self)
+    }
+
+    fn get_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        if !multiname.is_attribute() {
+            if let Some(Ok(index)) = multiname.local_name().map(|name| name.parse::<usize>()) {
+                let child = self.children().get(index).copied();
+                return match child {
+                    Some(child) => Ok(XmlObject::from_node(activation, child)?.into()),
+                    None => Ok(Value::Undefined),
+                };
+            }
+        }
+
+        let children = self.matching_children(multiname);
+
+        Ok(XmlListObject::new(activation, children)?.into())
+    }
+
+    fn set_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        value: Value<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<(), Error> {
+        if !multiname.is_attribute() {
+            if let Some(Ok(index)) = multiname.local_name().map(|name| name.parse::<usize>()) {
+                let child = self.children().get(index).copied();
+                if let Some(child) = child {
+                    match value {
+                        Value::Object(o) if o.as_xml_object().is_some() => {
+                            let mc = activation.context.gc_context;
+                            let new_node = o.as_xml_object().unwrap().node().deep_copy(mc);
+
+                            if let Some(parent) = child.parent() {
+                                parent.replace_child(mc, child, new_node);
+                            }
+
+                            self.0.write(mc).children[index] = new_node;
+                        }
+                        value => {
+                            let text = value.coerce_to_string(activation)?;
+                            child.replace_with_text(activation.context.gc_context, text);
+                        }
+                    }
+
+                    return Ok(());
+                }
+
+                return self.append(value, activation);
+            }
+        }
+
+        // Assigning a named property of a list is only possible if it has
+        // exactly one item, in which case it acts upon that item.
+        let child = match &self.children()[..] {
+            [child] => *child,
+            _ => {
+                return Err(
+                    "TypeError: Error #1089: Assignment to lists with more than one item is not supported."
+                        .into(),
+                )
+            }
+        };
+
+        XmlObject::from_node(activation, child)?.set_property_local(multiname, value, activation)
+    }
+
+    fn call_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        arguments: &[Value<'gc>],
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let method = match self.proto() {
+            Some(proto) if multiname.contains_public_namespace() => {
+                proto.get_property_local(multiname, activation)?
+            }
+            _ => Value::Undefined,
+        };
+
+        if let Value::Object(method) = method {
+            return method.call(Some(self.into()), arguments, activation);
+        }
+
+        // Lists with a single item forward method calls to that item.
+        let child = self.children().first().copied();
+        if let (1, Some(child)) = (self.length(), child) {
+            return XmlObject::from_node(activation, child)?
+                .call_property(multiname, arguments, activation);
+        }
+
+        Err(format!(
+            "TypeError: Error #1006: {} is not a function.",
+            multiname.local_name().unwrap_or_else(|| "value".into())
+        )
+        .into())
+    }
+
+    fn delete_property_local(
+        self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<bool, Error> {
+        let mc = activation.context.gc_context;
+
+        if !multiname.is_attribute() {
+            if let Some(Ok(index)) = multiname.local_name().map(|name| name.parse::<usize>()) {
+                if index < self.length() {
+                    let child = self.0.write(mc).children.remove(index);
+                    if let Some(parent) = child.parent() {
+                        parent.remove_child(mc, child);
+                    }
+                }
+
+                return Ok(true);
+            }
+        }
+
+        let children = self.children().clone();
+        for child in children {
+            child.remove_matching(mc, multiname);
+        }
+
+        Ok(true)
+    }
+
+    fn has_property_via_in(
+        self,
+        _activation: &mut Activation<'_, 'gc, '_>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<bool, Error> {
+        if let Some(Ok(index)) = multiname.local_name().map(|name| name.parse::<usize>()) {
+            return Ok(index < self.length());
+        }
+
+        Ok(!self.matching_children(multiname).is_empty() || self.has_property(multiname))
+    }
+
+    fn has_own_property(self, name: &Multiname<'gc>) -> bool {
+        if !name.is_attribute() {
+            if let Some(Ok(index)) = name.local_name().map(|name| name.parse::<usize>()) {
+                return index < self.length();
+            }
+        }
+
+        !self.matching_children(name).is_empty() || self.0.read().base.has_own_property(name)
+    }
+
+    fn get_next_enumerant(
+        self,
+        last_index: u32,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Option<u32>, Error> {
+        if (last_index as usize) < self.length() {
+            Ok(Some(last_index + 1))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn get_enumerant_name(
+        self,
+        index: u32,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        if index == 0 || index as usize > self.length() {
+            return Ok(Value::Undefined);
+        }
+
+        Ok((index - 1).into())
+    }
+
+    fn get_enumerant_value(
+        self,
+        index: u32,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let child = index
+            .checked_sub(1)
+            .and_then(|i| self.children().get(i as usize).copied());
+
+        match child {
+            Some(child) => Ok(XmlObject::from_node(activation, child)?.into()),
+            None => Ok(Value::Undefined),
+        }
+    }
+
+    fn get_descendants(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let mut descendants = Vec::new();
+        for child in self.children().iter() {
+            child.descendants(multiname, &mut descendants);
+        }
+
+        Ok(XmlListObject::new(activation, descendants)?.into())
+    }
+}
@@ -1,9 +1,11 @@
 //! Object representation for XML objects
 
 use crate::avm2::activation::Activation;
+use crate::avm2::e4x::E4XNode;
+use crate::avm2::names::Multiname;
 use crate::avm2::object::script_object::ScriptObjectData;
-use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject};
-use crate::avm2::value::Value;
+use crate::avm2::object::{ClassObject, Object, ObjectPtr, TObject, XmlListObject};
+use crate::avm2::value::{Hint, Value};
 use crate::avm2::Error;
 use gc_arena::{Collect, GcCell, MutationContext};
 use std::cell::{Ref, RefMut};
@@ -15,10 +17,11 @@ pub fn xml_allocator<'gc>(
     activation: &mut Activation<'_, 'gc, '_>,
 ) -> Result<Object<'gc>, Error> {
     let base = ScriptObjectData::base_new(Some(proto), Some(class));
+    let node = E4XNode::text(activation.context.gc_context, "".into(), None);
 
     Ok(XmlObject(GcCell::allocate(
         activation.context.gc_context,
-        XmlObjectData { base },
+        XmlObjectData { base, node },
     ))
     .into())
 }
@@ -32,6 +35,56 @@ pub struct XmlObject<'gc>(GcCell<'gc, XmlObjectData<'gc>>);
 pub struct XmlObjectData<'gc> {
     /// Base script object
     base: ScriptObjectData<'gc>,
+
+    /// The XML node this object represents.
+    node: E4XNode<'gc>,
+}
+
+impl<'gc> XmlObject<'gc> {
+    /// Box an E4X node into an `XML` object.
+    pub fn from_node(
+        activation: &mut Activation<'_, 'gc, '_>,
+        node: E4XNode<'gc>,
+    ) -> Result<Object<'gc>, Error> {
+        let class = activation.avm2().classes().xml;
+        let proto = activation.avm2().prototypes().xml;
+        let base = ScriptObjectData::base_new(Some(proto), Some(class));
+
+        let mut this: Object<'gc> = XmlObject(GcCell::allocate(
+            activation.context.gc_context,
+            XmlObjectData { base, node },
+        ))
+        .into();
+        this.install_instance_slots(activation);
+
+        class.call_native_init(Some(this), &[], activation)?;
+
+        Ok(this)
+    }
+
+    pub fn node(self) -> E4XNode<'gc> {
+        self.0.read().node
+    }
+
+    pub fn set_node(self, mc: MutationContext<'gc, '_>, node: E4XNode<'gc>) {
+        self.0.write(mc).node = node;
+    }
+
+    /// Collect all children (or attributes) of this node matching a given
+    /// name.
+    pub fn matching_children(self, name: &Multiname<'gc>) -> Vec<E4XNode<'gc>> {
+        let node = self.node();
+        let candidates = if name.is_attribute() {
+            node.attributes()
+        } else {
+            node.children()
+        };
+
+        candidates
+            .into_iter()
+            .filter(|child| child.matches_name(name))
+            .collect()
+    }
 }
 
 impl<'gc> TObject<'gc> for XmlObject<'gc> {
@@ -50,4 +103,190 @@ impl<'gc> TObject<'gc> for XmlObject<'gc> {
     fn value_of(&self, _mc: MutationContext<'gc, '_>) -> Result<Value<'gc>, Error> {
         Ok(Value::Object(Object::from(*self)))
     }
+
+    fn default_hint(&self) -> Hint {
+        Hint::String
+    }
+
+    fn as_xml_object(&self) -> Option<XmlObject<'gc>> {
+        Some(*self)
+    }
+
+    fn get_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        // `xml[0]` refers to the XML object itself.
+        if !multiname.is_attribute() {
+            if let Some(Ok(index)) = multiname.local_name().map(|name| name.parse::<u32>()) {
+                return Ok(if index == 0 {
+                    self.into()
+                } else {
+                    Value::Undefined
+                });
+            }
+        }
+
+        let children = self.matching_children(multiname);
+        // Items appended to a list of attributes aren't inserted anywhere.
+        let (target_object, target_property) = if multiname.is_attribute() {
+            (None, None)
+        } else {
+            (Some(self.node()), Some(multiname.clone()))
+        };
+
+        Ok(
+            XmlListObject::new_with_target(activation, children, target_object, target_property)?
+                .into(),
+        )
+    }
+
+    fn set_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        value: Value<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<(), Error> {
+        let node = self.node();
+        let mc = activation.context.gc_context;
+
+        if multiname.is_attribute() {
+            let value = value.coerce_to_string(activation)?;
+            node.set_attribute(mc, multiname, value);
+            return Ok(());
+        }
+
+        // Assigning XML replaces the first matching child with a copy of the
+        // value; assigning anything else replaces its text. Any other
+        // matching children are removed.
+        let xml_value = match value {
+            Value::Object(o) => o.as_xml_object(),
+            _ => None,
+        };
+        let existing = self.matching_children(multiname);
+
+        if let Some((first, rest)) = existing.split_first() {
+            for extra in rest {
+                node.remove_child(mc, *extra);
+            }
+
+            match xml_value {
+                Some(xml) => node.replace_child(mc, *first, xml.node().deep_copy(mc)),
+                None => first.replace_with_text(mc, value.coerce_to_string(activation)?),
+            }
+
+            return Ok(());
+        }
+
+        let new_node = match xml_value {
+            Some(xml) => xml.node().deep_copy(mc),
+            None => {
+                let local_name = multiname
+                    .local_name()
+                    .ok_or("Cannot assign to an XML child without a name")?;
+                let element = E4XNode::element(mc, None, local_name, None);
+                element.replace_with_text(mc, value.coerce_to_string(activation)?);
+                element
+            }
+        };
+
+        node.append_child(mc, new_node);
+
+        Ok(())
+    }
+
+    fn call_property_local(
+        self,
+        multiname: &Multiname<'gc>,
+        arguments: &[Value<'gc>],
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        // Methods are looked up on the prototype, rather than as children.
+        let method = match self.proto() {
+            Some(proto) if multiname.contains_public_namespace() => {
+                proto.get_property_local(multiname, activation)?
+            }
+            _ => Value::Undefined,
+        };
+
+        if let Value::Object(method) = method {
+            return method.call(Some(self.into()), arguments, activation);
+        }
+
+        Err(format!(
+            "TypeError: Error #1006: {} is not a function.",
+            multiname.local_name().unwrap_or_else(|| "value".into())
+        )
+        .into())
+    }
+
+    fn delete_property_local(
+        self,
+        activation: &mut Activation<'_, 'gc, '_>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<bool, Error> {
+        self.node()
+            .remove_matching(activation.context.gc_context, multiname);
+
+        Ok(true)
+    }
+
+    fn has_property_via_in(
+        self,
+        _activation: &mut Activation<'_, 'gc, '_>,
+        multiname: &Multiname<'gc>,
+    ) -> Result<bool, Error> {
+        Ok(!self.matching_children(multiname).is_empty() || self.has_property(multiname))
+    }
+
+    fn has_own_property(self, name: &Multiname<'gc>) -> bool {
+        // Children and attributes are visible to `with` scopes, which is how
+        // filter predicates such as `list.(@id == 1)` look them up.
+        !self.matching_children(name).is_empty() || self.0.read().base.has_own_property(name)
+    }
+
+    fn get_next_enumerant(
+        self,
+        last_index: u32,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Option<u32>, Error> {
+        // An XML object enumerates as a list containing only itself.
+        Ok(if last_index == 0 { Some(1) } else { None })
+    }
+
+    fn get_enumerant_name(
+        self,
+        index: u32,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        Ok(if index == 1 {
+            0.into()
+        } else {
+            Value::Undefined
+        })
+    }
+
+    fn get_enumerant_value(
+        self,
+        index: u32,
+        _activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        Ok(if index == 1 {
+            self.into()
+        } else {
+            Value::Undefined
+        })
+    }
+
+    fn get_descendants(
+        self,
+        multiname: &Multiname<'gc>,
+        activation: &mut Activation<'_, 'gc, '_>,
+    ) -> Result<Value<'gc>, Error> {
+        let mut descendants = Vec::new();
+        self.node().descendants(multiname, &mut descendants);
+
+        Ok(XmlListObject::new(activation, descendants)?.into())
+    }
 }
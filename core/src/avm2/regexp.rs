@@ -90,8 +90,9 @@ impl<'gc> RegExp<'gc> {
         F: FnOnce(&mut CachedText<'gc>, regress::Match) -> T,
     {
         if self.cached_regex.is_none() {
+            let source = translate_pattern(&self.source.to_utf8_lossy());
             let re = regress::Regex::with_flags(
-                &self.source.to_utf8_lossy(),
+                &source,
                 regress::Flags {
                     icase: self.flags.contains(RegExpFlags::IGNORE_CASE),
                     multiline: self.flags.contains(RegExpFlags::MULTILINE),
@@ -140,14 +141,36 @@ impl<'gc> RegExp<'gc> {
                 true
             }
             Some(None) => true,
-            None => false,
+            None => {
+                if global {
+                    self.last_index = 0;
+                }
+                false
+            }
         }
     }
 
     pub fn exec(&mut self, text: AvmString<'gc>) -> Option<regress::Match> {
         let global = self.flags.contains(RegExpFlags::GLOBAL);
         let start = if global { self.last_index } else { 0 };
-        let re_match = self.find_utf8_match_at(text, start, |text, mut re_match| {
+        let re_match = self.find_at(text, start);
+
+        if global {
+            self.last_index = re_match
+                .as_ref()
+                .map(|re_match| re_match.end())
+                .unwrap_or(0);
+        }
+
+        re_match
+    }
+
+    /// Find the first match at or after the UTF-16 index `start`, ignoring
+    /// and leaving untouched `lastIndex`.
+    ///
+    /// All indices in the returned match are UTF-16 indices into `text`.
+    pub fn find_at(&mut self, text: AvmString<'gc>, start: usize) -> Option<regress::Match> {
+        self.find_utf8_match_at(text, start, |text, mut re_match| {
             // Sort the capture endpoints by increasing index, so that CachedText::utf16_index is efficient.
             let mut utf8_indices = re_match
                 .captures
@@ -164,14 +187,44 @@ impl<'gc> RegExp<'gc> {
             }
 
             re_match
-        })?;
+        })
+    }
+}
 
-        if global {
-            self.last_index = re_match.end();
+/// Convert an AS3 regular expression pattern into one that `regress` accepts.
+///
+/// AS3 spells named groups the Python way, as `(?P<name>...)`. Only groups
+/// are rewritten, so escaped parentheses and character classes that happen
+/// to contain `(?P<` keep their meaning.
+fn translate_pattern(source: &str) -> String {
+    let mut translated = String::with_capacity(source.len());
+    let mut is_escaped = false;
+    let mut in_class = false;
+    let mut group_start = None;
+
+    for (i, c) in source.char_indices() {
+        if is_escaped {
+            is_escaped = false;
+        } else {
+            match c {
+                '\\' => is_escaped = true,
+                '[' => in_class = true,
+                ']' => in_class = false,
+                '(' if !in_class => group_start = Some(i),
+                // Drop the `P` of a `(?P<` group opening.
+                'P' if group_start.map_or(false, |start| start + 2 == i)
+                    && source[i - 1..].starts_with("?P<") =>
+                {
+                    continue
+                }
+                _ => {}
+            }
         }
 
-        Some(re_match)
+        translated.push(c);
     }
+
+    translated
 }
 
 #[derive(Collect, Debug)]
@@ -11,10 +11,12 @@ use crate::avm2::value::Value;
 use crate::avm2::{Avm2, Error};
 use crate::context::UpdateContext;
 use crate::string::AvmString;
+use crate::tag_utils::SwfMovie;
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
 use std::cell::Ref;
 use std::mem::drop;
 use std::rc::Rc;
+use std::sync::Arc;
 use swf::avm2::types::{AbcFile, Index, Method as AbcMethod, Script as AbcScript};
 
 #[derive(Copy, Clone, Debug, Collect)]
@@ -43,6 +45,10 @@ pub struct TranslationUnitData<'gc> {
     #[collect(require_static)]
     abc: Rc<AbcFile>,
 
+    /// The movie that the ABC file was loaded from.
+    #[collect(require_static)]
+    movie: Arc<SwfMovie>,
+
     /// All classes loaded from the ABC's class list.
     classes: Vec<Option<GcCell<'gc, Class<'gc>>>>,
 
@@ -60,7 +66,12 @@ pub struct TranslationUnitData<'gc> {
 impl<'gc> TranslationUnit<'gc> {
     /// Construct a new `TranslationUnit` for a given ABC file intended to
     /// execute within a particular domain.
-    pub fn from_abc(abc: Rc<AbcFile>, domain: Domain<'gc>, mc: MutationContext<'gc, '_>) -> Self {
+    pub fn from_abc(
+        abc: Rc<AbcFile>,
+        movie: Arc<SwfMovie>,
+        domain: Domain<'gc>,
+        mc: MutationContext<'gc, '_>,
+    ) -> Self {
         let classes = vec![None; abc.classes.len()];
         let methods = vec![None; abc.methods.len()];
         let scripts = vec![None; abc.scripts.len()];
@@ -71,6 +82,7 @@ impl<'gc> TranslationUnit<'gc> {
             TranslationUnitData {
                 domain,
                 abc,
+                movie,
                 classes,
                 methods,
                 scripts,
@@ -84,6 +96,11 @@ impl<'gc> TranslationUnit<'gc> {
         self.0.read().abc.clone()
     }
 
+    /// Retrieve the movie that this translation unit was loaded from.
+    pub fn movie(self) -> Arc<SwfMovie> {
+        self.0.read().movie.clone()
+    }
+
     /// Load a method from the ABC file and return its method definition.
     pub fn load_method(
         self,
@@ -734,7 +734,21 @@ impl<'gc> Value<'gc> {
             }
             (Value::String(a), Value::String(b)) => Ok(a == b),
             (Value::Bool(a), Value::Bool(b)) => Ok(a == b),
-            (Value::Object(a), Value::Object(b)) => Ok(Object::ptr_eq(*a, *b)),
+            (Value::Object(a), Value::Object(b)) => {
+                // ECMA-357 extends equality to compare XML structurally.
+                if let (Some(a), Some(b)) = (a.as_xml_object(), b.as_xml_object()) {
+                    return Ok(a.node().equals(b.node()));
+                }
+
+                if let (Some(a), Some(b)) = (a.as_xml_list_object(), b.as_xml_list_object()) {
+                    let (a, b) = (a.children(), b.children());
+                    return Ok(
+                        a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.equals(*b))
+                    );
+                }
+
+                Ok(Object::ptr_eq(*a, *b))
+            }
             (Value::Undefined, Value::Null) => Ok(true),
             (Value::Null, Value::Undefined) => Ok(true),
             (Value::Number(_) | Value::Unsigned(_) | Value::Integer(_), Value::String(_)) => {
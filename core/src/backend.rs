@@ -1,7 +1,9 @@
 pub mod audio;
+pub mod font;
 pub mod log;
 pub mod navigator;
 pub mod render;
+pub mod socket;
 pub mod storage;
 pub mod ui;
 pub mod video;
@@ -10,6 +10,7 @@ use gc_arena::Collect;
 use generational_arena::{Arena, Index};
 
 pub mod decoders;
+pub mod mp3;
 pub mod swf {
     pub use swf::{
         read, AudioCompression, CharacterId, Sound, SoundEnvelope, SoundEnvelopePoint, SoundEvent,
@@ -31,6 +32,31 @@ pub trait AudioBackend: Downcast {
     fn pause(&mut self);
     fn register_sound(&mut self, swf_sound: &swf::Sound) -> Result<SoundHandle, Error>;
 
+    /// Registers the contents of an MP3 file loaded at runtime.
+    ///
+    /// By default, the file is registered as if it were an MP3 `DefineSound`
+    /// tag with the format and length read from its frame headers.
+    fn register_mp3(&mut self, data: &[u8]) -> Result<SoundHandle, Error> {
+        let metadata = mp3::scan(data).ok_or("No MP3 frames found")?;
+
+        // `DefineSound` MP3 data starts with the number of samples to skip.
+        let mut sound_data = Vec::with_capacity(metadata.frames.len() + 2);
+        sound_data.extend_from_slice(&[0, 0]);
+        sound_data.extend_from_slice(&data[metadata.frames]);
+
+        self.register_sound(&swf::Sound {
+            id: 0,
+            format: swf::SoundFormat {
+                compression: swf::AudioCompression::Mp3,
+                sample_rate: metadata.sample_rate,
+                is_stereo: metadata.is_stereo,
+                is_16_bit: true,
+            },
+            num_samples: metadata.num_sample_frames,
+            data: &sound_data,
+        })
+    }
+
     /// Used by the web backend to pre-decode sound streams.
     /// Returns the sound handle to be used to add data to the stream.
     /// Other backends return `None`.
@@ -77,6 +103,27 @@ pub trait AudioBackend: Downcast {
         handle: &swf::SoundStreamHead,
     ) -> Result<SoundInstanceHandle, Error>;
 
+    /// Starts playing an MP3 file that is still loading.
+    ///
+    /// `data` is the part of the file that has loaded so far, starting at its
+    /// first MPEG frame. The rest is added with `append_mp3_stream`.
+    /// Returns `None` if the backend can't play partially loaded files, in
+    /// which case the file is played once it has loaded.
+    fn start_mp3_stream(&mut self, _data: &[u8]) -> Option<SoundInstanceHandle> {
+        None
+    }
+
+    /// Adds data that has loaded to an MP3 file started with `start_mp3_stream`.
+    ///
+    /// `is_complete` is set once the whole file has loaded.
+    fn append_mp3_stream(
+        &mut self,
+        _instance: SoundInstanceHandle,
+        _data: &[u8],
+        _is_complete: bool,
+    ) {
+    }
+
     /// Stops a playing sound instance.
     /// No-op if the sound is not playing.
     fn stop_sound(&mut self, sound: SoundInstanceHandle);
@@ -356,6 +403,45 @@ impl<'gc> AudioManager<'gc> {
         }
     }
 
+    /// Starts playing an MP3 file that is still loading.
+    pub fn start_mp3_stream(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        data: &[u8],
+        display_object: Option<DisplayObject<'gc>>,
+        avm1_object: Option<SoundObject<'gc>>,
+    ) -> Option<SoundInstanceHandle> {
+        if self.sounds.len() < Self::MAX_SOUNDS {
+            let handle = audio.start_mp3_stream(data)?;
+            let instance = SoundInstance {
+                sound: None,
+                instance: handle,
+                display_object,
+                transform: display_object::SoundTransform::default(),
+                avm1_object,
+                avm2_object: None,
+                stream_start_frame: None,
+            };
+            audio.set_sound_transform(handle, self.transform_for_sound(&instance));
+            self.sounds.push(instance);
+            Some(handle)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the sound played by an MP3 file started with `start_mp3_stream`,
+    /// once the file has loaded and been registered.
+    pub fn set_mp3_stream_sound(&mut self, instance: SoundInstanceHandle, sound: SoundHandle) {
+        if let Some(instance) = self
+            .sounds
+            .iter_mut()
+            .find(|other| other.instance == instance)
+        {
+            instance.sound = Some(sound);
+        }
+    }
+
     pub fn attach_avm2_sound_channel(
         &mut self,
         instance: SoundInstanceHandle,
@@ -598,7 +684,8 @@ pub struct SoundInstance<'gc> {
     instance: SoundInstanceHandle,
 
     /// The handle to the sound definition in the audio backend.
-    /// This will be `None` for stream sounds.
+    /// This will be `None` for stream sounds, and for MP3 files until they
+    /// have loaded.
     #[collect(require_static)]
     sound: Option<SoundHandle>,
 
@@ -4,6 +4,7 @@ use super::decoders::{
 use super::{SoundHandle, SoundInstanceHandle, SoundTransform};
 use crate::tag_utils::SwfSlice;
 use generational_arena::Arena;
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::{Arc, Mutex};
 use swf::AudioCompression;
@@ -27,6 +28,9 @@ pub struct AudioMixer {
 
     /// The sample rate of the output stream in Hz.
     output_sample_rate: u32,
+
+    /// The data of the MP3 files that are playing while they load.
+    loading_files: HashMap<SoundInstanceHandle, Arc<Mutex<LoadingFile>>>,
 }
 
 type Error = Box<dyn std::error::Error>;
@@ -96,6 +100,85 @@ impl<D: Decoder + Send> dasp::signal::Signal for DecoderStream<D> {
     }
 }
 
+/// The data of a file that is played while it loads.
+#[derive(Default)]
+#[cfg_attr(not(feature = "minimp3"), allow(dead_code))]
+struct LoadingFile {
+    /// The data that has loaded so far.
+    data: Vec<u8>,
+
+    /// How much of `data` has been read by the decoder.
+    read_position: usize,
+
+    /// Whether the whole file has loaded.
+    is_complete: bool,
+}
+
+/// Reads the data of a loading file as it arrives.
+#[cfg(feature = "minimp3")]
+struct LoadingFileReader(Arc<Mutex<LoadingFile>>);
+
+#[cfg(feature = "minimp3")]
+impl std::io::Read for LoadingFileReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut file = self.0.lock().unwrap();
+        let start = file.read_position;
+        let len = buf.len().min(file.data.len() - start);
+        buf[..len].copy_from_slice(&file.data[start..start + len]);
+        file.read_position += len;
+        Ok(len)
+    }
+}
+
+/// A stream that plays a file while it loads.
+///
+/// When playback catches up with the data that has loaded, this plays silence until more
+/// arrives. The stream ends once the whole file has loaded and been played.
+#[cfg(feature = "minimp3")]
+struct LoadingFileStream<D> {
+    decoder: D,
+    file: Arc<Mutex<LoadingFile>>,
+    position: u32,
+    is_exhausted: bool,
+}
+
+#[cfg(feature = "minimp3")]
+impl<D: Decoder + Send> Stream for LoadingFileStream<D> {
+    #[inline]
+    fn source_position(&self) -> u32 {
+        self.position
+    }
+
+    #[inline]
+    fn source_sample_rate(&self) -> u16 {
+        self.decoder.sample_rate()
+    }
+}
+
+#[cfg(feature = "minimp3")]
+impl<D: Decoder + Send> dasp::signal::Signal for LoadingFileStream<D> {
+    type Frame = [i16; 2];
+
+    #[inline]
+    fn next(&mut self) -> [i16; 2] {
+        if let Some(frame) = self.decoder.next() {
+            self.position += 1;
+            frame
+        } else {
+            let file = self.file.lock().unwrap();
+            if file.is_complete && file.read_position == file.data.len() {
+                self.is_exhausted = true;
+            }
+            Default::default()
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.is_exhausted
+    }
+}
+
 /// Contains the data and metadata for a sound in an SWF file.
 ///
 /// A sound is defined by the `DefineSound` SWF tags and contains the audio data for the sound.
@@ -154,6 +237,7 @@ impl AudioMixer {
             sound_instances: Arc::new(Mutex::new(Arena::new())),
             num_output_channels,
             output_sample_rate,
+            loading_files: HashMap::new(),
         }
     }
 
@@ -429,10 +513,76 @@ impl AudioMixer {
         Ok(handle)
     }
 
+    /// Starts playing an MP3 file while it loads.
+    ///
+    /// `data` is the part of the file that has loaded so far, and must start with an MPEG
+    /// frame. The rest of the file is added with `AudioMixer::append_mp3_stream`.
+    ///
+    /// Returns `None` if the MP3 decoder can't decode partial files.
+    pub fn start_mp3_stream(&mut self, data: &[u8]) -> Option<SoundInstanceHandle> {
+        #[cfg(feature = "minimp3")]
+        {
+            let file = Arc::new(Mutex::new(LoadingFile {
+                data: data.to_vec(),
+                ..Default::default()
+            }));
+            let decoder = match decoders::Mp3Decoder::new(LoadingFileReader(Arc::clone(&file))) {
+                Ok(decoder) => decoder,
+                Err(e) => {
+                    log::error!("Unable to start loading MP3 stream: {}", e);
+                    return None;
+                }
+            };
+            let stream = self.make_resampler(LoadingFileStream {
+                decoder,
+                file: Arc::clone(&file),
+                position: 0,
+                is_exhausted: false,
+            });
+
+            let mut sound_instances = self.sound_instances.lock().unwrap();
+            let handle = sound_instances.insert(SoundInstance {
+                handle: None,
+                stream: Box::new(stream),
+                active: true,
+                left_transform: [1.0, 0.0],
+                right_transform: [0.0, 1.0],
+            });
+            self.loading_files.insert(handle, file);
+            Some(handle)
+        }
+
+        #[cfg(not(feature = "minimp3"))]
+        {
+            let _ = data;
+            None
+        }
+    }
+
+    /// Adds the data that has loaded to an MP3 file started by `AudioMixer::start_mp3_stream`.
+    ///
+    /// `is_complete` is set once the whole file has loaded.
+    pub fn append_mp3_stream(
+        &mut self,
+        instance: SoundInstanceHandle,
+        data: &[u8],
+        is_complete: bool,
+    ) {
+        if let Some(file) = self.loading_files.get(&instance) {
+            let mut file = file.lock().unwrap();
+            file.data.extend_from_slice(data);
+            file.is_complete = is_complete;
+        }
+        if is_complete {
+            self.loading_files.remove(&instance);
+        }
+    }
+
     /// Stops a playing sound instance.
     pub fn stop_sound(&mut self, sound: SoundInstanceHandle) {
         let mut sound_instances = self.sound_instances.lock().unwrap();
         sound_instances.remove(sound);
+        self.loading_files.remove(&sound);
     }
 
     pub fn stop_all_sounds(&mut self) {
@@ -445,6 +595,7 @@ impl AudioMixer {
             sound_instances.remove(i);
         }
         sound_instances.clear();
+        self.loading_files.clear();
     }
 
     /// Returns the position of a playing sound in milliseconds.
@@ -863,6 +1014,21 @@ macro_rules! impl_audio_mixer_backend {
             self.$mixer.start_sound(sound_handle, settings)
         }
 
+        #[inline]
+        fn start_mp3_stream(&mut self, data: &[u8]) -> Option<SoundInstanceHandle> {
+            self.$mixer.start_mp3_stream(data)
+        }
+
+        #[inline]
+        fn append_mp3_stream(
+            &mut self,
+            instance: SoundInstanceHandle,
+            data: &[u8],
+            is_complete: bool,
+        ) {
+            self.$mixer.append_mp3_stream(instance, data, is_complete)
+        }
+
         #[inline]
         fn stop_sound(&mut self, sound: SoundInstanceHandle) {
             self.$mixer.stop_sound(sound)
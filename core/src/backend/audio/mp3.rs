@@ -0,0 +1,186 @@
+//! Scanning of MP3 files loaded at runtime.
+//!
+//! Sounds embedded in a SWF carry their format and length in the
+//! `DefineSound` tag. Standalone MP3 files don't, so it has to be read from
+//! the MPEG frame headers instead.
+
+use std::ops::Range;
+
+/// Bitrates of MPEG-1 Layer III frames in kbit/s, by bitrate index.
+const MPEG1_BITRATES: [u32; 15] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+];
+
+/// Bitrates of MPEG-2 and MPEG-2.5 Layer III frames in kbit/s, by bitrate index.
+const MPEG2_BITRATES: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+
+/// Sample rates of MPEG-1 frames, by sample rate index.
+/// MPEG-2 halves these, and MPEG-2.5 quarters them.
+const MPEG1_SAMPLE_RATES: [u32; 3] = [44100, 48000, 32000];
+
+/// The format and length of the audio in an MP3 file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mp3Metadata {
+    pub sample_rate: u16,
+    pub is_stereo: bool,
+    pub num_sample_frames: u32,
+
+    /// The byte range of the MPEG frames, excluding any ID3 tags.
+    pub frames: Range<usize>,
+}
+
+/// A parsed MPEG Layer III frame header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FrameHeader {
+    sample_rate: u32,
+    is_stereo: bool,
+    samples_per_frame: u32,
+    frame_size: usize,
+}
+
+impl FrameHeader {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let header = data.get(..4)?;
+        if header[0] != 0xFF || header[1] & 0xE0 != 0xE0 {
+            return None;
+        }
+
+        // Only Layer III is valid in MP3 files.
+        if (header[1] >> 1) & 0b11 != 0b01 {
+            return None;
+        }
+
+        let version = (header[1] >> 3) & 0b11;
+        let (bitrates, sample_rate_divisor, samples_per_frame, slot_factor) = match version {
+            0b11 => (&MPEG1_BITRATES, 1, 1152, 144),
+            0b10 => (&MPEG2_BITRATES, 2, 576, 72),
+            0b00 => (&MPEG2_BITRATES, 4, 576, 72),
+            _ => return None,
+        };
+
+        // Free-format streams have no fixed frame size and aren't supported.
+        let bitrate = *bitrates.get(usize::from(header[2] >> 4))?;
+        if bitrate == 0 {
+            return None;
+        }
+
+        let sample_rate =
+            MPEG1_SAMPLE_RATES.get(usize::from((header[2] >> 2) & 0b11))? / sample_rate_divisor;
+        let padding = u32::from((header[2] >> 1) & 1);
+        let frame_size = (slot_factor * bitrate * 1000 / sample_rate + padding) as usize;
+
+        Some(Self {
+            sample_rate,
+            is_stereo: header[3] >> 6 != 0b11,
+            samples_per_frame,
+            frame_size,
+        })
+    }
+}
+
+/// Returns the length of an ID3v2 tag at the start of `data`, or 0 if there is none.
+pub fn id3v2_tag_len(data: &[u8]) -> usize {
+    match data.get(..10) {
+        Some(header) if &header[..3] == b"ID3" => {
+            let size = header[6..10]
+                .iter()
+                .fold(0, |size, byte| (size << 7) | usize::from(byte & 0x7F));
+            let footer_len = if header[5] & 0x10 != 0 { 10 } else { 0 };
+            10 + size + footer_len
+        }
+        _ => 0,
+    }
+}
+
+/// Reads the format and length of an MP3 file.
+///
+/// Returns `None` if no MPEG Layer III frames were found.
+pub fn scan(data: &[u8]) -> Option<Mp3Metadata> {
+    let mut start = 0;
+    loop {
+        let tag_len = id3v2_tag_len(&data[start..]);
+        if tag_len == 0 {
+            break;
+        }
+        start = (start + tag_len).min(data.len());
+    }
+
+    // Skip any junk before the first frame. A frame header is only trusted
+    // if it is followed by another one, or by the end of the data.
+    let is_frame_at = |pos: usize| match FrameHeader::parse(&data[pos..]) {
+        Some(header) => {
+            let next = pos + header.frame_size;
+            next >= data.len()
+                || FrameHeader::parse(&data[next..]).is_some()
+                || data[next..].starts_with(b"TAG")
+        }
+        None => false,
+    };
+    let start = (start..data.len()).find(|&pos| is_frame_at(pos))?;
+    let first = FrameHeader::parse(&data[start..])?;
+
+    let mut end = start;
+    let mut num_sample_frames = 0;
+    while let Some(header) = FrameHeader::parse(&data[end..]) {
+        if end + header.frame_size > data.len() {
+            break;
+        }
+        end += header.frame_size;
+        num_sample_frames += header.samples_per_frame;
+    }
+
+    if num_sample_frames == 0 {
+        return None;
+    }
+
+    Some(Mp3Metadata {
+        sample_rate: first.sample_rate as u16,
+        is_stereo: first.is_stereo,
+        num_sample_frames,
+        frames: start..end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An MPEG-1 Layer III, 128kbit/s, 44.1kHz, joint stereo frame.
+    fn frame() -> Vec<u8> {
+        let mut frame = vec![0; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x44]);
+        frame
+    }
+
+    #[test]
+    fn scan_frames() {
+        let data = [frame(), frame(), frame()].concat();
+        assert_eq!(
+            scan(&data),
+            Some(Mp3Metadata {
+                sample_rate: 44100,
+                is_stereo: true,
+                num_sample_frames: 3 * 1152,
+                frames: 0..3 * 417,
+            })
+        );
+    }
+
+    #[test]
+    fn scan_skips_tags() {
+        let id3v2 = [b"ID3\x03\x00\x00\x00\x00\x00\x05".as_ref(), &[0; 5]].concat();
+        let mut id3v1 = vec![0; 128];
+        id3v1[..3].copy_from_slice(b"TAG");
+        let data = [id3v2, frame(), frame(), id3v1].concat();
+
+        let metadata = scan(&data).unwrap();
+        assert_eq!(metadata.num_sample_frames, 2 * 1152);
+        assert_eq!(metadata.frames, 15..15 + 2 * 417);
+    }
+
+    #[test]
+    fn scan_rejects_non_mp3() {
+        assert_eq!(scan(b"RIFF\x00\x00\x00\x00WAVEfmt "), None);
+        assert_eq!(scan(&[]), None);
+    }
+}
@@ -0,0 +1,316 @@
+//! Font backends, which provide the system fonts that device fonts are rendered with.
+
+use std::ops::RangeInclusive;
+
+/// The contents of a TrueType or OpenType font file, or of a collection of them.
+pub struct FontFile {
+    /// The raw data of the file.
+    pub data: Vec<u8>,
+
+    /// The index of the face to use within a font collection, or 0 for a
+    /// file containing a single face.
+    pub index: u32,
+}
+
+pub trait FontBackend {
+    /// Load the face that best matches a font name and style.
+    ///
+    /// `name` may also be one of the generic device fonts, `_sans`, `_serif`
+    /// or `_typewriter`. Returns `None` if there is no font with that name,
+    /// in which case the embedded device font is used instead.
+    fn load_font(&mut self, name: &str, is_bold: bool, is_italic: bool) -> Option<FontFile>;
+
+    /// Load a face that has a glyph for `character`.
+    ///
+    /// This is used to display text that the requested font has no glyphs
+    /// for, such as CJK text in a Latin font.
+    fn load_fallback_font(
+        &mut self,
+        character: char,
+        is_bold: bool,
+        is_italic: bool,
+    ) -> Option<FontFile>;
+}
+
+/// Font backend that provides no fonts.
+///
+/// Every device font is rendered with the embedded device font.
+pub struct NullFontBackend {}
+
+impl NullFontBackend {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for NullFontBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FontBackend for NullFontBackend {
+    fn load_font(&mut self, _name: &str, _is_bold: bool, _is_italic: bool) -> Option<FontFile> {
+        None
+    }
+
+    fn load_fallback_font(
+        &mut self,
+        _character: char,
+        _is_bold: bool,
+        _is_italic: bool,
+    ) -> Option<FontFile> {
+        None
+    }
+}
+
+/// Font backend that provides the fonts added to it.
+///
+/// This is used to test device fonts with a known set of fonts.
+#[derive(Default)]
+pub struct MemoryFontBackend {
+    /// The data of every font file that was added.
+    files: Vec<Vec<u8>>,
+
+    /// The file in `files` that each face in `faces` was found in.
+    face_files: Vec<usize>,
+    faces: Vec<FontFaceInfo>,
+}
+
+impl MemoryFontBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every face in a TrueType or OpenType font file.
+    pub fn add_font(&mut self, data: Vec<u8>) {
+        for face in FontFaceInfo::parse_all(&data) {
+            self.face_files.push(self.files.len());
+            self.faces.push(face);
+        }
+        self.files.push(data);
+    }
+
+    fn load_face(&self, i: usize) -> FontFile {
+        FontFile {
+            data: self.files[self.face_files[i]].clone(),
+            index: self.faces[i].index,
+        }
+    }
+}
+
+impl FontBackend for MemoryFontBackend {
+    fn load_font(&mut self, name: &str, is_bold: bool, is_italic: bool) -> Option<FontFile> {
+        let i = find_face(&self.faces, name, is_bold, is_italic)?;
+        Some(self.load_face(i))
+    }
+
+    fn load_fallback_font(
+        &mut self,
+        character: char,
+        is_bold: bool,
+        is_italic: bool,
+    ) -> Option<FontFile> {
+        let i = find_fallback_face(&self.faces, character, is_bold, is_italic)?;
+        Some(self.load_face(i))
+    }
+}
+
+/// The family names, style and character coverage of a face in a font file.
+///
+/// Font backends can index the fonts available to them with this, and then
+/// use `find_face` and `find_fallback_face` to pick the face to load.
+#[derive(Clone, Debug)]
+pub struct FontFaceInfo {
+    /// The index of the face within its file.
+    pub index: u32,
+
+    /// The family names of the face, in every language that it provides a
+    /// name for.
+    pub names: Vec<String>,
+
+    pub is_bold: bool,
+
+    pub is_italic: bool,
+
+    /// The sorted ranges of the code points that the face has glyphs for.
+    coverage: Vec<RangeInclusive<u32>>,
+}
+
+impl FontFaceInfo {
+    /// Read every face in a font file.
+    ///
+    /// Returns an empty list if the data is not a font that can be loaded.
+    pub fn parse_all(data: &[u8]) -> Vec<Self> {
+        let num_faces = ttf_parser::fonts_in_collection(data).unwrap_or(1);
+        (0..num_faces)
+            .filter_map(|index| {
+                let face = ttf_parser::Face::from_slice(data, index).ok()?;
+                Some(Self::from_face(&face, index))
+            })
+            .collect()
+    }
+
+    fn from_face(face: &ttf_parser::Face<'_>, index: u32) -> Self {
+        let mut names: Vec<String> = Vec::new();
+        for name in face.names() {
+            if name.name_id != ttf_parser::name_id::FAMILY {
+                continue;
+            }
+            if let Some(name) = name.to_string() {
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+
+        let mut code_points = Vec::new();
+        if let Some(cmap) = face.tables().cmap {
+            for subtable in cmap.subtables {
+                if subtable.is_unicode() {
+                    subtable.codepoints(|c| {
+                        if subtable.glyph_index(c).map_or(false, |id| id.0 != 0) {
+                            code_points.push(c)
+                        }
+                    });
+                }
+            }
+        }
+        code_points.sort_unstable();
+        code_points.dedup();
+
+        let mut coverage: Vec<RangeInclusive<u32>> = Vec::new();
+        for c in code_points {
+            match coverage.last_mut() {
+                Some(range) if *range.end() + 1 == c => *range = *range.start()..=c,
+                _ => coverage.push(c..=c),
+            }
+        }
+
+        Self {
+            index,
+            names,
+            is_bold: face.is_bold(),
+            is_italic: face.is_italic() || face.is_oblique(),
+            coverage,
+        }
+    }
+
+    /// Returns whether this face has a family name, ignoring case.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.names.iter().any(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns whether this face has a glyph for a character.
+    pub fn has_char(&self, character: char) -> bool {
+        let c = u32::from(character);
+        self.coverage
+            .binary_search_by(|range| {
+                if *range.end() < c {
+                    std::cmp::Ordering::Less
+                } else if *range.start() > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// How far the style of this face is from the requested style.
+    ///
+    /// A face in the wrong slant is considered a worse match than a face of
+    /// the wrong weight.
+    fn style_distance(&self, is_bold: bool, is_italic: bool) -> u8 {
+        u8::from(self.is_bold != is_bold) + 2 * u8::from(self.is_italic != is_italic)
+    }
+}
+
+/// The families that a generic device font is rendered with, in order of
+/// preference.
+fn generic_families(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "_sans" => Some(&[
+            "Arial",
+            "Helvetica",
+            "Liberation Sans",
+            "Arimo",
+            "DejaVu Sans",
+            "Noto Sans",
+            "Verdana",
+        ]),
+        "_serif" => Some(&[
+            "Times New Roman",
+            "Times",
+            "Liberation Serif",
+            "Tinos",
+            "DejaVu Serif",
+            "Noto Serif",
+            "Georgia",
+        ]),
+        "_typewriter" => Some(&[
+            "Courier New",
+            "Courier",
+            "Liberation Mono",
+            "Cousine",
+            "DejaVu Sans Mono",
+            "Noto Sans Mono",
+        ]),
+        _ => None,
+    }
+}
+
+/// Pick the face that best matches a font name and style, returning its
+/// position in `faces`.
+///
+/// `name` may be a comma-separated list of font names, which are tried in
+/// order. Generic device font names are replaced with the families that
+/// they are rendered with.
+pub fn find_face(
+    faces: &[FontFaceInfo],
+    name: &str,
+    is_bold: bool,
+    is_italic: bool,
+) -> Option<usize> {
+    let names = name.split(',').map(str::trim).filter(|n| !n.is_empty());
+    let families = names.flat_map(|name| match generic_families(name) {
+        Some(families) => families.to_vec(),
+        None => vec![name],
+    });
+
+    for family in families {
+        let best = faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| face.has_name(family))
+            .min_by_key(|(_, face)| face.style_distance(is_bold, is_italic));
+        if let Some((i, _)) = best {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// Pick a face with a glyph for `character`, returning its position in
+/// `faces`.
+///
+/// Faces of the generic sans-serif families are preferred, followed by the
+/// face closest to the requested style.
+pub fn find_fallback_face(
+    faces: &[FontFaceInfo],
+    character: char,
+    is_bold: bool,
+    is_italic: bool,
+) -> Option<usize> {
+    let sans_families = generic_families("_sans").unwrap_or_default();
+    faces
+        .iter()
+        .enumerate()
+        .filter(|(_, face)| face.has_char(character))
+        .min_by_key(|(_, face)| {
+            let is_sans = sans_families.iter().any(|family| face.has_name(family));
+            (!is_sans, face.style_distance(is_bold, is_italic))
+        })
+        .map(|(i, _)| i)
+}
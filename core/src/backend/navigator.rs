@@ -139,6 +139,39 @@ impl RequestOptions {
 /// result of type `Result<T, E>`.
 pub type OwnedFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + 'static>>;
 
+/// A future reading the next chunk of a `ChunkedResponse`.
+pub type ChunkFuture<'a> = Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, Error>> + 'a>>;
+
+/// The body of a response that is read in chunks as it arrives.
+pub trait ChunkedResponse {
+    /// The length of the whole body, if it is known before it has arrived.
+    fn expected_length(&self) -> Option<u64>;
+
+    /// Read the next chunk of the body, or `None` once all of it has been
+    /// read.
+    fn next_chunk(&mut self) -> ChunkFuture<'_>;
+}
+
+/// A response that arrives all at once, read as a single chunk.
+pub struct WholeResponse(Option<Vec<u8>>);
+
+impl WholeResponse {
+    pub fn new(data: Vec<u8>) -> Self {
+        Self(Some(data))
+    }
+}
+
+impl ChunkedResponse for WholeResponse {
+    fn expected_length(&self) -> Option<u64> {
+        self.0.as_ref().map(|data| data.len() as u64)
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        let chunk = self.0.take();
+        Box::pin(async move { Ok(chunk) })
+    }
+}
+
 /// A backend interacting with a browser environment.
 pub trait NavigatorBackend {
     /// Cause a browser navigation to a given URL.
@@ -171,8 +204,27 @@ pub trait NavigatorBackend {
     );
 
     /// Fetch data at a given URL and return it some time in the future.
+    ///
+    /// Loads are checked against the security sandbox of the requesting movie
+    /// (see `crate::security`) before they reach the backend, so this does not
+    /// need to enforce it.
     fn fetch(&self, url: &str, request_options: RequestOptions) -> OwnedFuture<Vec<u8>, Error>;
 
+    /// Fetch data at a given URL, allowing it to be read while it arrives.
+    ///
+    /// The default implementation waits for the whole body using `fetch`.
+    fn fetch_chunked(
+        &self,
+        url: &str,
+        request_options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ChunkedResponse>, Error> {
+        let fetch = self.fetch(url, request_options);
+        Box::pin(async move {
+            let data = fetch.await?;
+            Ok(Box::new(WholeResponse::new(data)) as Box<dyn ChunkedResponse>)
+        })
+    }
+
     /// Arrange for a future to be run at some point in the... well, future.
     ///
     /// This function must be called to ensure a future is actually computed.
@@ -60,6 +60,31 @@ pub trait RenderBackend: Downcast {
         height: u32,
         rgba: Vec<u8>,
     ) -> Result<BitmapHandle, Error>;
+
+    /// Begin rendering into an offscreen target of the given size, cleared to `clear`.
+    ///
+    /// All draw calls are directed to this target until the matching call to
+    /// `end_offscreen`. Offscreen passes may be started outside of a frame, and
+    /// may be nested.
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color);
+
+    /// Finish the current offscreen pass and read back its contents.
+    ///
+    /// The returned pixels have pre-multiplied alpha. `None` is returned if the
+    /// backend was unable to render or read back the target.
+    fn end_offscreen(&mut self) -> Option<Bitmap>;
+
+    /// Blend everything drawn from now on with the given blend mode, until the
+    /// matching call to `pop_blend_mode`.
+    ///
+    /// Blend modes are applied to each draw call on its own; callers that need
+    /// a group of draws to be blended as one should render the group offscreen
+    /// first. Backends that can't represent a blend mode draw normally instead.
+    fn push_blend_mode(&mut self, blend: swf::BlendMode);
+
+    /// Return to the blend mode that was in effect before the last call to
+    /// `push_blend_mode`.
+    fn pop_blend_mode(&mut self);
 }
 impl_downcast!(RenderBackend);
 
@@ -95,11 +120,16 @@ impl BitmapSource for NullBitmapSource {
     }
 }
 
-pub struct NullRenderer;
+pub struct NullRenderer {
+    /// The size and clear color of each offscreen pass in progress.
+    offscreen_stack: Vec<(u32, u32, Color)>,
+}
 
 impl NullRenderer {
     pub fn new() -> Self {
-        Self
+        Self {
+            offscreen_stack: Vec::new(),
+        }
     }
 }
 
@@ -198,6 +228,32 @@ impl RenderBackend for NullRenderer {
     ) -> Result<BitmapHandle, Error> {
         Ok(BitmapHandle(0))
     }
+
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color) {
+        self.offscreen_stack.push((width, height, clear));
+    }
+
+    fn end_offscreen(&mut self) -> Option<Bitmap> {
+        // Nothing is ever drawn, so the target only ever contains its clear color.
+        let (width, height, clear) = self.offscreen_stack.pop()?;
+        let alpha = u16::from(clear.a);
+        let premultiply = |c: u8| (u16::from(c) * alpha / 255) as u8;
+        let pixel = [
+            premultiply(clear.r),
+            premultiply(clear.g),
+            premultiply(clear.b),
+            clear.a,
+        ];
+
+        Some(Bitmap {
+            width,
+            height,
+            data: BitmapFormat::Rgba(pixel.repeat(width as usize * height as usize)),
+        })
+    }
+
+    fn push_blend_mode(&mut self, _blend: swf::BlendMode) {}
+    fn pop_blend_mode(&mut self) {}
 }
 
 /// The format of image data in a DefineBitsJpeg2/3 tag.
@@ -0,0 +1,329 @@
+//! Socket backends, which provide the TCP connections used by `XMLSocket` and `Socket`.
+
+use downcast_rs::Downcast;
+use generational_arena::Index;
+use std::collections::VecDeque;
+
+pub type SocketHandle = Index;
+
+/// Something that happened to a socket, reported by `SocketBackend::poll`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketEvent {
+    /// A connection attempt finished, either successfully or not.
+    ///
+    /// A socket that failed to connect is closed; no further events are
+    /// reported for it.
+    Connect {
+        handle: SocketHandle,
+        is_success: bool,
+    },
+
+    /// Data was received from the remote end.
+    Data { handle: SocketHandle, data: Vec<u8> },
+
+    /// The connection was closed by the remote end, or was lost.
+    Close { handle: SocketHandle },
+}
+
+pub trait SocketBackend: Downcast {
+    /// Start connecting a new socket to `host` on `port`.
+    ///
+    /// The handle is chosen by the player and identifies the socket in every
+    /// later call and event. The outcome of the attempt is reported by a
+    /// `SocketEvent::Connect` from a later call to `poll`.
+    fn connect(&mut self, handle: SocketHandle, host: &str, port: u16);
+
+    /// Send data to the remote end of a connected socket.
+    fn send(&mut self, handle: SocketHandle, data: Vec<u8>);
+
+    /// Close a socket.
+    ///
+    /// This may be called while the socket is still connecting. No events are
+    /// reported for a socket after it is closed.
+    fn close(&mut self, handle: SocketHandle);
+
+    /// Return every event that happened since the last call.
+    ///
+    /// This is called once per tick of the player.
+    fn poll(&mut self) -> Vec<SocketEvent>;
+}
+
+impl_downcast!(SocketBackend);
+
+/// Socket backend that cannot connect to anything.
+///
+/// Every connection attempt fails.
+pub struct NullSocketBackend {
+    events: Vec<SocketEvent>,
+}
+
+impl NullSocketBackend {
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+}
+
+impl Default for NullSocketBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SocketBackend for NullSocketBackend {
+    fn connect(&mut self, handle: SocketHandle, _host: &str, _port: u16) {
+        self.events.push(SocketEvent::Connect {
+            handle,
+            is_success: false,
+        });
+    }
+
+    fn send(&mut self, _handle: SocketHandle, _data: Vec<u8>) {}
+
+    fn close(&mut self, handle: SocketHandle) {
+        self.events.retain(|event| match event {
+            SocketEvent::Connect { handle: other, .. } => *other != handle,
+            _ => true,
+        });
+    }
+
+    fn poll(&mut self) -> Vec<SocketEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// A step of the conversation that a `ScriptedSocketBackend` server has with
+/// a client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SocketStep {
+    /// The server sends this data to the client.
+    Send(Vec<u8>),
+
+    /// The server waits for the client to send exactly this data.
+    ///
+    /// If the client sends something else, the server closes the connection.
+    Expect(Vec<u8>),
+
+    /// The server closes the connection.
+    Close,
+}
+
+/// Socket backend with in-memory servers that follow a fixed script.
+///
+/// This is used to test movies that talk to a server, without a network.
+/// Connecting to a host and port that no script was added for fails.
+#[derive(Default)]
+pub struct ScriptedSocketBackend {
+    /// The scripts of every server, by host and port.
+    scripts: Vec<(String, u16, Vec<SocketStep>)>,
+
+    /// The open connections and the steps that they have left.
+    connections: Vec<ScriptedConnection>,
+
+    /// Events that have not been polled yet.
+    events: Vec<SocketEvent>,
+}
+
+struct ScriptedConnection {
+    handle: SocketHandle,
+
+    /// The steps of the script that have not run yet.
+    steps: VecDeque<SocketStep>,
+
+    /// Data sent by the client that has not been expected yet.
+    received: Vec<u8>,
+}
+
+impl ScriptedSocketBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a server at `host` and `port` that runs `steps` for every client
+    /// that connects to it.
+    pub fn add_script(&mut self, host: &str, port: u16, steps: Vec<SocketStep>) {
+        self.scripts.push((host.to_string(), port, steps));
+    }
+
+    /// Run the steps of a connection until it has to wait for the client.
+    ///
+    /// Returns `false` if the connection was closed.
+    fn advance(connection: &mut ScriptedConnection, events: &mut Vec<SocketEvent>) -> bool {
+        let handle = connection.handle;
+        while let Some(step) = connection.steps.front() {
+            match step {
+                SocketStep::Send(data) => {
+                    events.push(SocketEvent::Data {
+                        handle,
+                        data: data.clone(),
+                    });
+                }
+                SocketStep::Expect(data) => {
+                    if connection.received.starts_with(data) {
+                        connection.received.drain(..data.len());
+                    } else if data.starts_with(&connection.received) {
+                        // Wait for the rest of the data.
+                        return true;
+                    } else {
+                        log::warn!(
+                            "Scripted socket expected {:?}, but received {:?}",
+                            data,
+                            connection.received
+                        );
+                        events.push(SocketEvent::Close { handle });
+                        return false;
+                    }
+                }
+                SocketStep::Close => {
+                    events.push(SocketEvent::Close { handle });
+                    return false;
+                }
+            }
+            connection.steps.pop_front();
+        }
+
+        true
+    }
+}
+
+impl SocketBackend for ScriptedSocketBackend {
+    fn connect(&mut self, handle: SocketHandle, host: &str, port: u16) {
+        let script = self
+            .scripts
+            .iter()
+            .find(|(script_host, script_port, _)| script_host == host && *script_port == port);
+        let is_success = if let Some((_, _, steps)) = script {
+            self.connections.push(ScriptedConnection {
+                handle,
+                steps: steps.iter().cloned().collect(),
+                received: Vec::new(),
+            });
+            true
+        } else {
+            false
+        };
+
+        self.events
+            .push(SocketEvent::Connect { handle, is_success });
+    }
+
+    fn send(&mut self, handle: SocketHandle, data: Vec<u8>) {
+        if let Some(connection) = self.connections.iter_mut().find(|c| c.handle == handle) {
+            connection.received.extend(data);
+        }
+    }
+
+    fn close(&mut self, handle: SocketHandle) {
+        self.connections.retain(|c| c.handle != handle);
+        self.events.retain(|event| match event {
+            SocketEvent::Connect { handle: other, .. }
+            | SocketEvent::Data { handle: other, .. }
+            | SocketEvent::Close { handle: other } => *other != handle,
+        });
+    }
+
+    fn poll(&mut self) -> Vec<SocketEvent> {
+        let mut events = std::mem::take(&mut self.events);
+        let connections = std::mem::take(&mut self.connections);
+        for mut connection in connections {
+            if Self::advance(&mut connection, &mut events) {
+                self.connections.push(connection);
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use generational_arena::Arena;
+
+    #[test]
+    fn scripted_conversation() {
+        let mut arena = Arena::new();
+        let handle = arena.insert(());
+        let mut backend = ScriptedSocketBackend::new();
+        backend.add_script(
+            "localhost",
+            1234,
+            vec![
+                SocketStep::Send(b"hello\0".to_vec()),
+                SocketStep::Expect(b"ping\0".to_vec()),
+                SocketStep::Send(b"pong\0".to_vec()),
+                SocketStep::Close,
+            ],
+        );
+
+        backend.connect(handle, "localhost", 1234);
+        assert_eq!(
+            backend.poll(),
+            vec![
+                SocketEvent::Connect {
+                    handle,
+                    is_success: true
+                },
+                SocketEvent::Data {
+                    handle,
+                    data: b"hello\0".to_vec()
+                },
+            ]
+        );
+
+        backend.send(handle, b"pi".to_vec());
+        assert_eq!(backend.poll(), vec![]);
+
+        backend.send(handle, b"ng\0".to_vec());
+        assert_eq!(
+            backend.poll(),
+            vec![
+                SocketEvent::Data {
+                    handle,
+                    data: b"pong\0".to_vec()
+                },
+                SocketEvent::Close { handle },
+            ]
+        );
+        assert_eq!(backend.poll(), vec![]);
+    }
+
+    #[test]
+    fn scripted_unknown_host() {
+        let mut arena = Arena::new();
+        let handle = arena.insert(());
+        let mut backend = ScriptedSocketBackend::new();
+
+        backend.connect(handle, "example.com", 80);
+        assert_eq!(
+            backend.poll(),
+            vec![SocketEvent::Connect {
+                handle,
+                is_success: false
+            }]
+        );
+    }
+
+    #[test]
+    fn scripted_unexpected_data() {
+        let mut arena = Arena::new();
+        let handle = arena.insert(());
+        let mut backend = ScriptedSocketBackend::new();
+        backend.add_script(
+            "localhost",
+            1234,
+            vec![SocketStep::Expect(b"login\0".to_vec())],
+        );
+
+        backend.connect(handle, "localhost", 1234);
+        backend.send(handle, b"logout\0".to_vec());
+        assert_eq!(
+            backend.poll(),
+            vec![
+                SocketEvent::Connect {
+                    handle,
+                    is_success: true
+                },
+                SocketEvent::Close { handle },
+            ]
+        );
+    }
+}
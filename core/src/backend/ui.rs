@@ -14,6 +14,9 @@ pub trait UiBackend {
     /// Sets the clipboard to the given content.
     fn set_clipboard_content(&mut self, content: String);
 
+    /// Returns the text on the clipboard, or an empty string if there is none.
+    fn clipboard_content(&mut self) -> String;
+
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error>;
 
     /// Displays a warning about unsupported content in Ruffle.
@@ -138,6 +141,10 @@ impl UiBackend for NullUiBackend {
 
     fn set_clipboard_content(&mut self, _content: String) {}
 
+    fn clipboard_content(&mut self) -> String {
+        String::new()
+    }
+
     fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), Error> {
         Ok(())
     }
@@ -0,0 +1,119 @@
+//! Blending of source colors onto a target.
+
+use swf::BlendMode;
+
+/// Blend a source color onto a destination color.
+///
+/// Both colors have pre-multiplied alpha. Unlike the GPU backends, every
+/// blend mode is blended exactly.
+pub fn blend(mode: BlendMode, src: [f32; 4], dst: [f32; 4]) -> [f32; 4] {
+    let (sa, da) = (src[3], dst[3]);
+    let alpha = sa + da * (1.0 - sa);
+    let per_channel = |f: &dyn Fn(f32, f32) -> f32| {
+        [
+            f(src[0], dst[0]),
+            f(src[1], dst[1]),
+            f(src[2], dst[2]),
+            alpha,
+        ]
+    };
+
+    match mode {
+        BlendMode::Normal | BlendMode::Layer => per_channel(&|s, d| s + d * (1.0 - sa)),
+        BlendMode::Multiply => separable(src, dst, |s, d| s * d),
+        BlendMode::Screen => separable(src, dst, |s, d| s + d - s * d),
+        BlendMode::Lighten => separable(src, dst, f32::max),
+        BlendMode::Darken => separable(src, dst, f32::min),
+        BlendMode::Difference => separable(src, dst, |s, d| (s - d).abs()),
+        BlendMode::Overlay => separable(src, dst, |s, d| hard_light(d, s)),
+        BlendMode::HardLight => separable(src, dst, hard_light),
+        BlendMode::Add => per_channel(&|s, d| (s + d).min(alpha)),
+        BlendMode::Subtract => per_channel(&|s, d| (d - s).max(0.0)),
+        // The destination is inverted wherever the source is opaque.
+        BlendMode::Invert => per_channel(&|_, d| d * (1.0 - sa) + (da - d) * sa),
+        BlendMode::Alpha => dst.map(|d| d * sa),
+        BlendMode::Erase => dst.map(|d| d * (1.0 - sa)),
+    }
+}
+
+/// Blend with a function of the un-multiplied source and destination colors,
+/// composited with the usual "source over" rules.
+fn separable(src: [f32; 4], dst: [f32; 4], f: impl Fn(f32, f32) -> f32) -> [f32; 4] {
+    let (sa, da) = (src[3], dst[3]);
+    let unmultiply = |c: f32, a: f32| if a > 0.0 { c / a } else { 0.0 };
+
+    let mut out = [0.0, 0.0, 0.0, sa + da * (1.0 - sa)];
+    for (i, c) in out.iter_mut().take(3).enumerate() {
+        let mixed = f(unmultiply(src[i], sa), unmultiply(dst[i], da));
+        *c = src[i] * (1.0 - da) + dst[i] * (1.0 - sa) + sa * da * mixed;
+    }
+    out
+}
+
+fn hard_light(src: f32, dst: f32) -> f32 {
+    if src <= 0.5 {
+        2.0 * src * dst
+    } else {
+        let src = 2.0 * src - 1.0;
+        src + dst - src * dst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_color_eq(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn opaque_modes() {
+        let src = [0.25, 0.5, 1.0, 1.0];
+        let dst = [0.5, 0.5, 0.5, 1.0];
+        assert_color_eq(blend(BlendMode::Normal, src, dst), src);
+        assert_color_eq(
+            blend(BlendMode::Multiply, src, dst),
+            [0.125, 0.25, 0.5, 1.0],
+        );
+        assert_color_eq(blend(BlendMode::Screen, src, dst), [0.625, 0.75, 1.0, 1.0]);
+        assert_color_eq(blend(BlendMode::Add, src, dst), [0.75, 1.0, 1.0, 1.0]);
+        assert_color_eq(blend(BlendMode::Subtract, src, dst), [0.25, 0.0, 0.0, 1.0]);
+        assert_color_eq(
+            blend(BlendMode::Difference, src, dst),
+            [0.25, 0.0, 0.5, 1.0],
+        );
+        assert_color_eq(blend(BlendMode::Invert, src, dst), [0.5, 0.5, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn transparent_source_keeps_destination() {
+        let dst = [0.2, 0.4, 0.6, 0.8];
+        for mode in [
+            BlendMode::Normal,
+            BlendMode::Multiply,
+            BlendMode::Screen,
+            BlendMode::Lighten,
+            BlendMode::Darken,
+            BlendMode::Difference,
+            BlendMode::Add,
+            BlendMode::Subtract,
+            BlendMode::Invert,
+            BlendMode::Erase,
+            BlendMode::Overlay,
+            BlendMode::HardLight,
+        ] {
+            assert_color_eq(blend(mode, [0.0; 4], dst), dst);
+        }
+    }
+
+    #[test]
+    fn alpha_and_erase() {
+        let src = [0.0, 0.0, 0.0, 0.25];
+        let dst = [0.4, 0.4, 0.4, 0.8];
+        assert_color_eq(blend(BlendMode::Alpha, src, dst), [0.1, 0.1, 0.1, 0.2]);
+        assert_color_eq(blend(BlendMode::Erase, src, dst), [0.3, 0.3, 0.3, 0.6]);
+    }
+}
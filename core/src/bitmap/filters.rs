@@ -0,0 +1,761 @@
+//! Software implementations of display object filters.
+//!
+//! Filters operate in place on RGBA buffers with premultiplied alpha, which is
+//! the format that renderers produce for offscreen passes. A buffer must
+//! already include the padding reported by `filter_padding`, so that effects
+//! which spread outwards (such as glows and drop shadows) are not clipped.
+
+use swf::{Color, Filter, GradientRecord};
+
+/// The number of pixels that a list of filters may extend an image by on
+/// each side.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FilterPadding {
+    pub left: u32,
+    pub top: u32,
+    pub right: u32,
+    pub bottom: u32,
+}
+
+impl FilterPadding {
+    fn uniform(x: u32, y: u32) -> Self {
+        Self {
+            left: x,
+            top: y,
+            right: x,
+            bottom: y,
+        }
+    }
+
+    /// Extend this padding in the direction of an offset.
+    fn offset_by(mut self, (dx, dy): (isize, isize)) -> Self {
+        if dx > 0 {
+            self.right += dx as u32;
+        } else {
+            self.left += (-dx) as u32;
+        }
+
+        if dy > 0 {
+            self.bottom += dy as u32;
+        } else {
+            self.top += (-dy) as u32;
+        }
+
+        self
+    }
+}
+
+/// Calculate how far a list of filters may extend an image past its bounds.
+///
+/// Each filter spreads the output of the previous one, so the padding of
+/// every filter in the list accumulates.
+pub fn filter_padding(filters: &[Filter]) -> FilterPadding {
+    let mut padding = FilterPadding::default();
+    for filter in filters {
+        let next = single_filter_padding(filter);
+        padding.left += next.left;
+        padding.top += next.top;
+        padding.right += next.right;
+        padding.bottom += next.bottom;
+    }
+
+    padding
+}
+
+fn single_filter_padding(filter: &Filter) -> FilterPadding {
+    match filter {
+        Filter::BlurFilter(f) => blur_padding(f.blur_x.to_f64(), f.blur_y.to_f64(), f.num_passes),
+        Filter::GlowFilter(f) if !f.is_inner => {
+            blur_padding(f.blur_x.to_f64(), f.blur_y.to_f64(), f.num_passes)
+        }
+        Filter::DropShadowFilter(f) if !f.is_inner => {
+            blur_padding(f.blur_x.to_f64(), f.blur_y.to_f64(), f.num_passes)
+                .offset_by(offset(f.angle.to_f64(), f.distance.to_f64()))
+        }
+        Filter::GradientGlowFilter(f) if f.is_on_top || !f.is_inner => {
+            blur_padding(f.blur_x.to_f64(), f.blur_y.to_f64(), f.num_passes)
+                .offset_by(offset(f.angle.to_f64(), f.distance.to_f64()))
+        }
+        Filter::BevelFilter(f) if f.is_on_top || !f.is_inner => bevel_padding(
+            f.blur_x.to_f64(),
+            f.blur_y.to_f64(),
+            f.num_passes,
+            f.angle.to_f64(),
+            f.distance.to_f64(),
+        ),
+        Filter::GradientBevelFilter(f) if f.is_on_top || !f.is_inner => bevel_padding(
+            f.blur_x.to_f64(),
+            f.blur_y.to_f64(),
+            f.num_passes,
+            f.angle.to_f64(),
+            f.distance.to_f64(),
+        ),
+        _ => FilterPadding::default(),
+    }
+}
+
+fn blur_padding(blur_x: f64, blur_y: f64, passes: u8) -> FilterPadding {
+    FilterPadding::uniform(
+        (blur_radius(blur_x) * passes as usize) as u32,
+        (blur_radius(blur_y) * passes as usize) as u32,
+    )
+}
+
+fn bevel_padding(blur_x: f64, blur_y: f64, passes: u8, angle: f64, distance: f64) -> FilterPadding {
+    let (dx, dy) = offset(angle, distance);
+    blur_padding(blur_x, blur_y, passes)
+        .offset_by((dx.abs(), dy.abs()))
+        .offset_by((-dx.abs(), -dy.abs()))
+}
+
+/// Apply a list of filters to an image, in order.
+pub fn apply_filters(filters: &[Filter], width: u32, height: u32, rgba: &mut [u8]) {
+    for filter in filters {
+        apply_filter(filter, width, height, rgba);
+    }
+}
+
+/// Apply a single filter to an image.
+pub fn apply_filter(filter: &Filter, width: u32, height: u32, rgba: &mut [u8]) {
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let mut image = Image::from_rgba(width as usize, height as usize, rgba);
+
+    match filter {
+        Filter::BlurFilter(f) => {
+            image.blur(f.blur_x.to_f64(), f.blur_y.to_f64(), f.num_passes);
+        }
+        Filter::GlowFilter(f) => {
+            let color = premultiplied(&f.color);
+            image.shadow(
+                &Shadow {
+                    blur_x: f.blur_x.to_f64(),
+                    blur_y: f.blur_y.to_f64(),
+                    passes: f.num_passes,
+                    strength: f.strength.to_f64() as f32,
+                    offset: (0, 0),
+                    mode: FilterMode::inner_or_outer(f.is_inner),
+                    knockout: f.is_knockout,
+                },
+                |v| scale(color, v),
+            );
+        }
+        Filter::DropShadowFilter(f) => {
+            let color = premultiplied(&f.color);
+            image.shadow(
+                &Shadow {
+                    blur_x: f.blur_x.to_f64(),
+                    blur_y: f.blur_y.to_f64(),
+                    passes: f.num_passes,
+                    strength: f.strength.to_f64() as f32,
+                    offset: offset(f.angle.to_f64(), f.distance.to_f64()),
+                    mode: FilterMode::inner_or_outer(f.is_inner),
+                    knockout: f.is_knockout,
+                },
+                |v| scale(color, v),
+            );
+        }
+        Filter::GradientGlowFilter(f) => {
+            let gradient = gradient_lut(&f.colors);
+            image.shadow(
+                &Shadow {
+                    blur_x: f.blur_x.to_f64(),
+                    blur_y: f.blur_y.to_f64(),
+                    passes: f.num_passes,
+                    strength: f.strength.to_f64() as f32,
+                    offset: offset(f.angle.to_f64(), f.distance.to_f64()),
+                    mode: FilterMode::from_flags(f.is_inner, f.is_on_top),
+                    knockout: f.is_knockout,
+                },
+                |v| gradient[(v * 255.0) as usize],
+            );
+        }
+        Filter::BevelFilter(f) => {
+            let highlight = premultiplied(&f.highlight_color);
+            let shadow = premultiplied(&f.shadow_color);
+            image.bevel(
+                &Shadow {
+                    blur_x: f.blur_x.to_f64(),
+                    blur_y: f.blur_y.to_f64(),
+                    passes: f.num_passes,
+                    strength: f.strength.to_f64() as f32,
+                    offset: offset(f.angle.to_f64(), f.distance.to_f64()),
+                    mode: FilterMode::from_flags(f.is_inner, f.is_on_top),
+                    knockout: f.is_knockout,
+                },
+                |h, s| add(scale(highlight, h), scale(shadow, s)),
+            );
+        }
+        Filter::GradientBevelFilter(f) => {
+            // The start of the gradient lights the highlighted edges, and
+            // the end of it shades the shadowed edges.
+            let gradient = gradient_lut(&f.colors);
+            image.bevel(
+                &Shadow {
+                    blur_x: f.blur_x.to_f64(),
+                    blur_y: f.blur_y.to_f64(),
+                    passes: f.num_passes,
+                    strength: f.strength.to_f64() as f32,
+                    offset: offset(f.angle.to_f64(), f.distance.to_f64()),
+                    mode: FilterMode::from_flags(f.is_inner, f.is_on_top),
+                    knockout: f.is_knockout,
+                },
+                |h, s| gradient[((s - h) * 127.5 + 127.5) as usize],
+            );
+        }
+        Filter::ColorMatrixFilter(f) => {
+            let mut matrix = [0.0; 20];
+            for (value, fixed) in matrix.iter_mut().zip(f.matrix.iter()) {
+                *value = fixed.to_f32();
+            }
+            image.color_matrix(&matrix);
+        }
+        Filter::ConvolutionFilter(f) => {
+            image.convolve(f);
+        }
+    }
+
+    image.write_rgba(rgba);
+}
+
+/// How a glow, shadow or bevel is combined with the original image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FilterMode {
+    /// The effect is drawn only inside of the image.
+    Inner,
+
+    /// The effect is drawn only outside of (and behind) the image.
+    Outer,
+
+    /// The effect is drawn on top of the whole image.
+    Full,
+}
+
+impl FilterMode {
+    fn inner_or_outer(is_inner: bool) -> Self {
+        if is_inner {
+            Self::Inner
+        } else {
+            Self::Outer
+        }
+    }
+
+    fn from_flags(is_inner: bool, is_on_top: bool) -> Self {
+        if is_on_top {
+            Self::Full
+        } else {
+            Self::inner_or_outer(is_inner)
+        }
+    }
+}
+
+/// Parameters shared by the glow, shadow and bevel families of filters.
+struct Shadow {
+    blur_x: f64,
+    blur_y: f64,
+    passes: u8,
+    strength: f32,
+    offset: (isize, isize),
+    mode: FilterMode,
+    knockout: bool,
+}
+
+/// A premultiplied RGBA color with components in the range `0.0..=1.0`.
+type Rgba = [f32; 4];
+
+/// An image being filtered, stored as premultiplied floating-point pixels.
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgba>,
+}
+
+impl Image {
+    fn from_rgba(width: usize, height: usize, rgba: &[u8]) -> Self {
+        let pixels = rgba
+            .chunks_exact(4)
+            .take(width * height)
+            .map(|p| {
+                [
+                    f32::from(p[0]) / 255.0,
+                    f32::from(p[1]) / 255.0,
+                    f32::from(p[2]) / 255.0,
+                    f32::from(p[3]) / 255.0,
+                ]
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    fn write_rgba(&self, rgba: &mut [u8]) {
+        for (out, p) in rgba.chunks_exact_mut(4).zip(self.pixels.iter()) {
+            let alpha = p[3].max(0.0).min(1.0);
+            out[0] = (p[0].max(0.0).min(alpha) * 255.0).round() as u8;
+            out[1] = (p[1].max(0.0).min(alpha) * 255.0).round() as u8;
+            out[2] = (p[2].max(0.0).min(alpha) * 255.0).round() as u8;
+            out[3] = (alpha * 255.0).round() as u8;
+        }
+    }
+
+    fn alpha_plane(&self) -> Vec<f32> {
+        self.pixels.iter().map(|p| p[3]).collect()
+    }
+
+    /// Sample a plane, returning `outside` for any coordinates past its edges.
+    fn sample(&self, plane: &[f32], x: isize, y: isize, outside: f32) -> f32 {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            outside
+        } else {
+            plane[x as usize + y as usize * self.width]
+        }
+    }
+
+    fn blur(&mut self, blur_x: f64, blur_y: f64, passes: u8) {
+        for channel in 0..4 {
+            let mut plane: Vec<f32> = self.pixels.iter().map(|p| p[channel]).collect();
+            blur_plane(&mut plane, self.width, self.height, blur_x, blur_y, passes);
+            for (p, value) in self.pixels.iter_mut().zip(plane) {
+                p[channel] = value;
+            }
+        }
+    }
+
+    /// Draw a glow or a drop shadow.
+    ///
+    /// `color_of` maps the intensity of the effect at a pixel to the color
+    /// drawn there.
+    fn shadow(&mut self, params: &Shadow, color_of: impl Fn(f32) -> Rgba) {
+        let inner = params.mode == FilterMode::Inner;
+
+        // Inner effects are cast by the transparent area around the image.
+        let mut plane = self.alpha_plane();
+        if inner {
+            for value in plane.iter_mut() {
+                *value = 1.0 - *value;
+            }
+        }
+        blur_plane(
+            &mut plane,
+            self.width,
+            self.height,
+            params.blur_x,
+            params.blur_y,
+            params.passes,
+        );
+
+        let (dx, dy) = params.offset;
+        let outside = if inner { 1.0 } else { 0.0 };
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let source = self.pixels[x + y * self.width];
+                let intensity = self.sample(&plane, x as isize - dx, y as isize - dy, outside);
+                let mut intensity = (intensity * params.strength).clamp(0.0, 1.0);
+                if inner {
+                    intensity *= source[3];
+                }
+
+                let effect = color_of(intensity);
+                self.pixels[x + y * self.width] =
+                    composite(source, effect, params.mode, params.knockout);
+            }
+        }
+    }
+
+    /// Draw a bevel.
+    ///
+    /// `color_of` maps the highlight and shadow intensities at a pixel to the
+    /// color drawn there.
+    fn bevel(&mut self, params: &Shadow, color_of: impl Fn(f32, f32) -> Rgba) {
+        let mut plane = self.alpha_plane();
+        blur_plane(
+            &mut plane,
+            self.width,
+            self.height,
+            params.blur_x,
+            params.blur_y,
+            params.passes,
+        );
+
+        let (dx, dy) = params.offset;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let source = self.pixels[x + y * self.width];
+                let (x, y) = (x as isize, y as isize);
+
+                // Edges facing away from the light are shaded, and edges
+                // facing towards it are highlighted.
+                let lit = self.sample(&plane, x - dx, y - dy, 0.0);
+                let unlit = self.sample(&plane, x + dx, y + dy, 0.0);
+                let shadow = ((lit - unlit) * params.strength).clamp(0.0, 1.0);
+                let highlight = ((unlit - lit) * params.strength).clamp(0.0, 1.0);
+
+                let coverage = match params.mode {
+                    FilterMode::Inner => source[3],
+                    FilterMode::Outer => 1.0 - source[3],
+                    FilterMode::Full => 1.0,
+                };
+                let effect = scale(color_of(highlight, shadow), coverage);
+
+                self.pixels[x as usize + y as usize * self.width] =
+                    composite(source, effect, params.mode, params.knockout);
+            }
+        }
+    }
+
+    fn color_matrix(&mut self, matrix: &[f32; 20]) {
+        for p in self.pixels.iter_mut() {
+            let [r, g, b, a] = unpremultiplied(*p);
+            let mut out = [0.0; 4];
+            for (i, row) in matrix.chunks_exact(5).enumerate() {
+                // The offset column is in the range `0..=255`.
+                out[i] = (row[0] * r + row[1] * g + row[2] * b + row[3] * a + row[4] / 255.0)
+                    .clamp(0.0, 1.0);
+            }
+            *p = premultiply(out);
+        }
+    }
+
+    fn convolve(&mut self, filter: &swf::ConvolutionFilter) {
+        let rows = filter.num_matrix_rows as isize;
+        let cols = filter.num_matrix_cols as isize;
+        let matrix: Vec<f32> = filter.matrix.iter().map(|v| v.to_f32()).collect();
+        if matrix.len() < (rows * cols) as usize {
+            return;
+        }
+
+        let divisor = match filter.divisor.to_f32() {
+            d if d == 0.0 => 1.0,
+            d => d,
+        };
+        let bias = filter.bias.to_f32() / 255.0;
+        let default_color = straight(&filter.default_color);
+
+        let source: Vec<Rgba> = self.pixels.iter().map(|p| unpremultiplied(*p)).collect();
+        let (width, height) = (self.width as isize, self.height as isize);
+        let pixel_at = |x: isize, y: isize| -> Rgba {
+            if filter.is_clamped {
+                let x = x.clamp(0, width - 1);
+                let y = y.clamp(0, height - 1);
+                source[(x + y * width) as usize]
+            } else if x < 0 || y < 0 || x >= width || y >= height {
+                default_color
+            } else {
+                source[(x + y * width) as usize]
+            }
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = [0.0; 4];
+                for row in 0..rows {
+                    for col in 0..cols {
+                        let weight = matrix[(col + row * cols) as usize];
+                        let p = pixel_at(x + col - cols / 2, y + row - rows / 2);
+                        for (sum, value) in sum.iter_mut().zip(p.iter()) {
+                            *sum += value * weight;
+                        }
+                    }
+                }
+
+                let mut out = [0.0; 4];
+                for (out, sum) in out.iter_mut().zip(sum.iter()) {
+                    *out = (sum / divisor + bias).clamp(0.0, 1.0);
+                }
+                if filter.is_preserve_alpha {
+                    out[3] = source[(x + y * width) as usize][3];
+                }
+
+                self.pixels[(x + y * width) as usize] = premultiply(out);
+            }
+        }
+    }
+}
+
+/// Combine an image pixel with the effect drawn on it.
+fn composite(source: Rgba, effect: Rgba, mode: FilterMode, knockout: bool) -> Rgba {
+    match (mode, knockout) {
+        (FilterMode::Outer, true) => scale(effect, 1.0 - source[3]),
+        (_, true) => effect,
+        // Outer effects are drawn behind the image.
+        (FilterMode::Outer, false) => add(source, scale(effect, 1.0 - source[3])),
+        (_, false) => add(effect, scale(source, 1.0 - effect[3])),
+    }
+}
+
+/// Box blur a plane of values, repeating the blur once per pass.
+fn blur_plane(
+    plane: &mut [f32],
+    width: usize,
+    height: usize,
+    blur_x: f64,
+    blur_y: f64,
+    passes: u8,
+) {
+    let radius_x = blur_radius(blur_x);
+    let radius_y = blur_radius(blur_y);
+    let mut line = Vec::with_capacity(width.max(height));
+
+    for _ in 0..passes {
+        if radius_x > 0 {
+            for y in 0..height {
+                box_blur_line(plane, y * width, 1, width, radius_x, &mut line);
+            }
+        }
+        if radius_y > 0 {
+            for x in 0..width {
+                box_blur_line(plane, x, width, height, radius_y, &mut line);
+            }
+        }
+    }
+}
+
+/// Box blur a single row or column of a plane.
+///
+/// Values past the ends of the line are treated as zero.
+fn box_blur_line(
+    plane: &mut [f32],
+    start: usize,
+    stride: usize,
+    len: usize,
+    radius: usize,
+    line: &mut Vec<f32>,
+) {
+    line.clear();
+    line.extend((0..len).map(|i| plane[start + i * stride]));
+
+    let scale = 1.0 / (radius * 2 + 1) as f32;
+    let mut sum: f32 = line.iter().take(radius + 1).sum();
+    for i in 0..len {
+        plane[start + i * stride] = sum * scale;
+        if i + radius + 1 < len {
+            sum += line[i + radius + 1];
+        }
+        if i >= radius {
+            sum -= line[i - radius];
+        }
+    }
+}
+
+/// The radius of a single blur pass for a given blur amount.
+fn blur_radius(blur: f64) -> usize {
+    (blur.clamp(0.0, 255.0) / 2.0).floor() as usize
+}
+
+/// Convert an angle (in radians) and a distance into a pixel offset.
+fn offset(angle: f64, distance: f64) -> (isize, isize) {
+    (
+        (angle.cos() * distance).round() as isize,
+        (angle.sin() * distance).round() as isize,
+    )
+}
+
+/// Build a lookup table of the colors along a gradient.
+fn gradient_lut(records: &[GradientRecord]) -> Vec<Rgba> {
+    (0..=255u8)
+        .map(|ratio| {
+            let after = records.iter().position(|r| r.ratio >= ratio);
+            let color = match after {
+                None => records.last().map(|r| straight(&r.color)),
+                Some(0) => Some(straight(&records[0].color)),
+                Some(i) => {
+                    let (a, b) = (&records[i - 1], &records[i]);
+                    let span = f32::from(b.ratio.saturating_sub(a.ratio));
+                    let t = if span == 0.0 {
+                        1.0
+                    } else {
+                        f32::from(ratio.saturating_sub(a.ratio)) / span
+                    };
+                    let (a, b) = (straight(&a.color), straight(&b.color));
+                    let mut color = [0.0; 4];
+                    for ((color, a), b) in color.iter_mut().zip(a.iter()).zip(b.iter()) {
+                        *color = a + (b - a) * t;
+                    }
+                    Some(color)
+                }
+            };
+
+            color.map(premultiply).unwrap_or_default()
+        })
+        .collect()
+}
+
+fn straight(color: &Color) -> Rgba {
+    [
+        f32::from(color.r) / 255.0,
+        f32::from(color.g) / 255.0,
+        f32::from(color.b) / 255.0,
+        f32::from(color.a) / 255.0,
+    ]
+}
+
+fn premultiplied(color: &Color) -> Rgba {
+    premultiply(straight(color))
+}
+
+fn premultiply([r, g, b, a]: Rgba) -> Rgba {
+    [r * a, g * a, b * a, a]
+}
+
+fn unpremultiplied([r, g, b, a]: Rgba) -> Rgba {
+    if a <= 0.0 {
+        [0.0; 4]
+    } else {
+        [r / a, g / a, b / a, a]
+    }
+}
+
+fn scale(color: Rgba, amount: f32) -> Rgba {
+    [
+        color[0] * amount,
+        color[1] * amount,
+        color[2] * amount,
+        color[3] * amount,
+    ]
+}
+
+fn add(a: Rgba, b: Rgba) -> Rgba {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::{BlurFilter, ColorMatrixFilter, DropShadowFilter, Fixed16, Fixed8, GlowFilter};
+
+    /// A `size` by `size` transparent image with an opaque white square in
+    /// its center.
+    fn square(size: u32, inset: u32) -> Vec<u8> {
+        let mut rgba = vec![0; (size * size * 4) as usize];
+        for y in inset..size - inset {
+            for x in inset..size - inset {
+                let i = ((x + y * size) * 4) as usize;
+                rgba[i..i + 4].copy_from_slice(&[255, 255, 255, 255]);
+            }
+        }
+        rgba
+    }
+
+    fn alpha_at(rgba: &[u8], size: u32, x: u32, y: u32) -> u8 {
+        rgba[((x + y * size) * 4 + 3) as usize]
+    }
+
+    #[test]
+    fn blur_spreads_and_preserves_coverage() {
+        let mut rgba = square(16, 6);
+        let before: u32 = rgba.chunks(4).map(|p| u32::from(p[3])).sum();
+
+        apply_filter(
+            &Filter::BlurFilter(Box::new(BlurFilter {
+                blur_x: Fixed16::from_f64(4.0),
+                blur_y: Fixed16::from_f64(4.0),
+                num_passes: 1,
+            })),
+            16,
+            16,
+            &mut rgba,
+        );
+
+        let after: u32 = rgba.chunks(4).map(|p| u32::from(p[3])).sum();
+        assert!(alpha_at(&rgba, 16, 5, 8) > 0);
+        assert!(alpha_at(&rgba, 16, 8, 8) < 255);
+        assert!((before as i32 - after as i32).abs() < 64);
+    }
+
+    #[test]
+    fn outer_glow_is_drawn_behind() {
+        let mut rgba = square(16, 6);
+        apply_filter(
+            &Filter::GlowFilter(Box::new(GlowFilter {
+                color: Color::from_rgb(0xFF0000, 255),
+                blur_x: Fixed16::from_f64(4.0),
+                blur_y: Fixed16::from_f64(4.0),
+                strength: Fixed8::from_f64(2.0),
+                is_inner: false,
+                is_knockout: false,
+                num_passes: 1,
+            })),
+            16,
+            16,
+            &mut rgba,
+        );
+
+        // The source is untouched, and the glow is red.
+        assert_eq!(
+            &rgba[((8 + 8 * 16) * 4) as usize..][..4],
+            &[255, 255, 255, 255]
+        );
+        let glow = &rgba[((5 + 8 * 16) * 4) as usize..][..4];
+        assert!(glow[3] > 0);
+        assert_eq!(glow[0], glow[3]);
+        assert_eq!(glow[1], 0);
+    }
+
+    #[test]
+    fn drop_shadow_is_offset() {
+        let mut rgba = square(16, 5);
+        apply_filter(
+            &Filter::DropShadowFilter(Box::new(DropShadowFilter {
+                color: Color::from_rgb(0, 255),
+                blur_x: Fixed16::ZERO,
+                blur_y: Fixed16::ZERO,
+                angle: Fixed16::ZERO,
+                distance: Fixed16::from_f64(2.0),
+                strength: Fixed8::ONE,
+                is_inner: false,
+                is_knockout: true,
+                num_passes: 1,
+            })),
+            16,
+            16,
+            &mut rgba,
+        );
+
+        assert_eq!(alpha_at(&rgba, 16, 4, 8), 0);
+        assert_eq!(alpha_at(&rgba, 16, 8, 8), 0);
+        assert_eq!(alpha_at(&rgba, 16, 12, 8), 255);
+    }
+
+    #[test]
+    fn color_matrix_swaps_channels() {
+        let mut rgba = vec![255, 0, 0, 255];
+        let mut matrix = [Fixed16::ZERO; 20];
+        matrix[2] = Fixed16::ONE; // R <- B
+        matrix[6] = Fixed16::ONE; // G <- G
+        matrix[10] = Fixed16::ONE; // B <- R
+        matrix[18] = Fixed16::ONE; // A <- A
+        apply_filter(
+            &Filter::ColorMatrixFilter(Box::new(ColorMatrixFilter { matrix })),
+            1,
+            1,
+            &mut rgba,
+        );
+
+        assert_eq!(rgba, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn padding_accumulates() {
+        let blur = Filter::BlurFilter(Box::new(BlurFilter {
+            blur_x: Fixed16::from_f64(8.0),
+            blur_y: Fixed16::from_f64(4.0),
+            num_passes: 2,
+        }));
+
+        assert_eq!(
+            filter_padding(&[blur.clone(), blur]),
+            FilterPadding {
+                left: 16,
+                top: 8,
+                right: 16,
+                bottom: 8
+            }
+        );
+    }
+}
@@ -7,9 +7,11 @@ use crate::avm2::{
 };
 use crate::backend::{
     audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
+    font::FontBackend,
     log::LogBackend,
     navigator::NavigatorBackend,
     render::RenderBackend,
+    socket::SocketBackend,
     storage::StorageBackend,
     ui::{InputManager, UiBackend},
     video::VideoBackend,
@@ -20,8 +22,12 @@ use crate::external::ExternalInterface;
 use crate::focus_tracker::FocusTracker;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::local_connection::LocalConnectionManager;
 use crate::player::Player;
 use crate::prelude::*;
+use crate::security::Security;
+use crate::socket::SocketManager;
+use crate::streams::StreamManager;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::transform::TransformStack;
 use crate::vminterface::AvmType;
@@ -67,6 +73,9 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The audio manager, manging all actively playing sounds.
     pub audio_manager: &'a mut AudioManager<'gc>,
 
+    /// The stream manager, managing all actively playing `NetStream`s.
+    pub stream_manager: &'a mut StreamManager<'gc>,
+
     /// The navigator backend, used by the AVM to make HTTP requests and visit webpages.
     pub navigator: &'a mut (dyn NavigatorBackend + 'a),
 
@@ -87,6 +96,20 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The video backend, used for video decoding
     pub video: &'a mut dyn VideoBackend,
 
+    /// The font backend, used to load the system fonts that device text is
+    /// rendered with.
+    pub fonts: &'a mut dyn FontBackend,
+
+    /// The socket backend, used by `XMLSocket` and `Socket` to make TCP connections.
+    pub sockets: &'a mut dyn SocketBackend,
+
+    /// The socket manager, managing all connecting and connected sockets.
+    pub socket_manager: &'a mut SocketManager<'gc>,
+
+    /// The local connection manager, managing every `LocalConnection` that
+    /// is listening or waiting for the outcome of a message.
+    pub local_connection_manager: &'a mut LocalConnectionManager<'gc>,
+
     /// The RNG, used by the AVM `RandomNumber` opcode,  `Math.random(),` and `random()`.
     pub rng: &'a mut SmallRng,
 
@@ -123,6 +146,9 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The system properties
     pub system: &'a mut SystemProperties,
 
+    /// The security sandbox state, such as the policy files that were loaded.
+    pub security: &'a mut Security,
+
     /// The current instance ID. Used to generate default `instanceN` names.
     pub instance_counter: &'a mut i32,
 
@@ -215,6 +241,29 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             .start_sound(self.audio, sound, settings, owner, avm1_object)
     }
 
+    pub fn start_mp3_stream(
+        &mut self,
+        data: &[u8],
+        owner: Option<DisplayObject<'gc>>,
+        avm1_object: Option<crate::avm1::SoundObject<'gc>>,
+    ) -> Option<SoundInstanceHandle> {
+        self.audio_manager
+            .start_mp3_stream(self.audio, data, owner, avm1_object)
+    }
+
+    pub fn append_mp3_stream(
+        &mut self,
+        instance: SoundInstanceHandle,
+        data: &[u8],
+        is_complete: bool,
+    ) {
+        self.audio.append_mp3_stream(instance, data, is_complete)
+    }
+
+    pub fn set_mp3_stream_sound(&mut self, instance: SoundInstanceHandle, sound: SoundHandle) {
+        self.audio_manager.set_mp3_stream_sound(instance, sound)
+    }
+
     pub fn attach_avm2_sound_channel(
         &mut self,
         instance: SoundInstanceHandle,
@@ -294,11 +343,16 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             swf: self.swf,
             audio: self.audio,
             audio_manager: self.audio_manager,
+            stream_manager: self.stream_manager,
             navigator: self.navigator,
             renderer: self.renderer,
             log: self.log,
             ui: self.ui,
             video: self.video,
+            fonts: self.fonts,
+            sockets: self.sockets,
+            socket_manager: self.socket_manager,
+            local_connection_manager: self.local_connection_manager,
             storage: self.storage,
             rng: self.rng,
             stage: self.stage,
@@ -310,6 +364,7 @@ impl<'a, 'gc, 'gc_context> UpdateContext<'a, 'gc, 'gc_context> {
             player: self.player.clone(),
             load_manager: self.load_manager,
             system: self.system,
+            security: self.security,
             instance_counter: self.instance_counter,
             shared_objects: self.shared_objects,
             unbound_text_fields: self.unbound_text_fields,
@@ -436,6 +491,11 @@ pub struct RenderContext<'a, 'gc> {
     /// Whether to allow pushing a new mask. A masker-inside-a-masker does not work in Flash, instead
     /// causing the inner mask to be included as part of the outer mask. Maskee-inside-a-maskee works as one expects.
     pub allow_mask: bool,
+
+    /// Whether we're rendering offscreen, such as for `BitmapData.draw`.
+    ///
+    /// Objects outside of the stage's view bounds must not be culled in this case.
+    pub is_offscreen: bool,
 }
 
 /// The type of action being run.
@@ -4,22 +4,24 @@ use crate::avm2::{
     EventData as Avm2EventData, Namespace as Avm2Namespace, Object as Avm2Object,
     QName as Avm2QName, TObject as Avm2TObject, Value as Avm2Value,
 };
+use crate::backend::render::{BitmapFormat, BitmapHandle, RenderBackend};
+use crate::bitmap::filters::{apply_filters, filter_padding};
 use crate::context::{RenderContext, UpdateContext};
 use crate::drawing::Drawing;
 use crate::player::NEWEST_PLAYER_VERSION;
 use crate::prelude::*;
 use crate::string::{AvmString, WString};
 use crate::tag_utils::SwfMovie;
-use crate::transform::Transform;
+use crate::transform::{Transform, TransformStack};
 use crate::types::{Degrees, Percent};
 use crate::vminterface::{AvmType, Instantiator};
 use bitflags::bitflags;
 use gc_arena::{Collect, MutationContext};
 use ruffle_macros::enum_trait_object;
-use std::cell::{Ref, RefMut};
+use std::cell::{Cell, Ref, RefMut};
 use std::fmt::Debug;
 use std::sync::Arc;
-use swf::Fixed8;
+use swf::{BlendMode, Filter, Fixed8};
 
 mod avm1_button;
 mod avm2_button;
@@ -30,6 +32,7 @@ mod graphic;
 mod interactive;
 mod morph_shape;
 mod movie_clip;
+mod scaling_grid;
 mod stage;
 mod text;
 mod video;
@@ -91,6 +94,33 @@ pub struct DisplayObjectBase<'gc> {
     /// The display object we are currently masking.
     maskee: Option<DisplayObject<'gc>>,
 
+    /// The blend mode used when compositing this object onto its parent.
+    #[collect(require_static)]
+    blend_mode: BlendMode,
+
+    /// The solid color drawn behind this object, if any.
+    #[collect(require_static)]
+    opaque_background: Option<Color>,
+
+    /// The region of this object that is displayed, in local coordinates.
+    scroll_rect: Option<BoundingBox>,
+
+    /// The grid that splits this object into nine regions when it is scaled,
+    /// in local coordinates.
+    scaling_grid: Option<BoundingBox>,
+
+    /// The filters applied to this object when it is rendered.
+    #[collect(require_static)]
+    filters: Vec<Filter>,
+
+    /// The texture that this object's layer image is drawn from.
+    #[collect(require_static)]
+    layer_texture: LayerTexture,
+
+    /// Changes to this object that cached bitmaps have not been redrawn with.
+    #[collect(require_static)]
+    cache_changes: CacheChanges,
+
     /// Bit flags for various display object properties.
     flags: DisplayObjectFlags,
 }
@@ -113,6 +143,13 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             masker: None,
             maskee: None,
             sound_transform: Default::default(),
+            blend_mode: Default::default(),
+            opaque_background: None,
+            scroll_rect: None,
+            scaling_grid: None,
+            filters: Vec::new(),
+            layer_texture: Default::default(),
+            cache_changes: Default::default(),
             flags: DisplayObjectFlags::VISIBLE,
         }
     }
@@ -389,6 +426,54 @@ impl<'gc> DisplayObjectBase<'gc> {
         self.flags.set(DisplayObjectFlags::LOCK_ROOT, value);
     }
 
+    fn is_bitmap_cached(&self) -> bool {
+        self.flags.contains(DisplayObjectFlags::CACHE_AS_BITMAP)
+    }
+
+    fn set_bitmap_cached(&mut self, value: bool) {
+        self.flags.set(DisplayObjectFlags::CACHE_AS_BITMAP, value);
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn set_blend_mode(&mut self, value: BlendMode) {
+        self.blend_mode = value;
+    }
+
+    fn opaque_background(&self) -> Option<Color> {
+        self.opaque_background.clone()
+    }
+
+    fn set_opaque_background(&mut self, value: Option<Color>) {
+        self.opaque_background = value;
+    }
+
+    fn scroll_rect(&self) -> Option<BoundingBox> {
+        self.scroll_rect.clone()
+    }
+
+    fn set_scroll_rect(&mut self, value: Option<BoundingBox>) {
+        self.scroll_rect = value;
+    }
+
+    fn scaling_grid(&self) -> Option<BoundingBox> {
+        self.scaling_grid.clone()
+    }
+
+    fn set_scaling_grid(&mut self, value: Option<BoundingBox>) {
+        self.scaling_grid = value;
+    }
+
+    fn filters(&self) -> Vec<Filter> {
+        self.filters.clone()
+    }
+
+    fn set_filters(&mut self, value: Vec<Filter>) {
+        self.filters = value;
+    }
+
     fn transformed_by_script(&self) -> bool {
         self.flags
             .contains(DisplayObjectFlags::TRANSFORMED_BY_SCRIPT)
@@ -446,7 +531,20 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     if this.maskee().is_some() {
         return;
     }
-    context.transform_stack.push(&*this.base().transform());
+    context.transform_stack.push(&Transform {
+        matrix: this.local_to_parent_matrix(),
+        color_transform: *this.base().color_transform(),
+    });
+
+    // Objects that are drawn as a layer draw their background as part of the
+    // layer image.
+    let filters = this.filters();
+    let blend_mode = effective_blend_mode(this);
+    let is_layer =
+        !filters.is_empty() || blend_mode != BlendMode::Normal || this.is_bitmap_cached();
+    if !is_layer {
+        render_opaque_background(this, context);
+    }
 
     let mask = this.masker();
     let mut mask_transform = crate::transform::Transform::default();
@@ -461,7 +559,11 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         context.allow_mask = true;
         context.renderer.activate_mask();
     }
-    this.render_self(context);
+    if is_layer {
+        render_layer(this, &filters, blend_mode, context);
+    } else {
+        render_scrolled(this, context);
+    }
     if let Some(m) = mask {
         context.renderer.deactivate_mask();
         context.allow_mask = false;
@@ -475,6 +577,350 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     context.transform_stack.pop();
 }
 
+/// The blend mode that a display object is drawn with.
+///
+/// The alpha and erase blend modes only apply inside a parent with the layer
+/// blend mode. Elsewhere, they blend normally.
+fn effective_blend_mode(this: DisplayObject<'_>) -> BlendMode {
+    match this.blend_mode() {
+        BlendMode::Alpha | BlendMode::Erase
+            if this.parent().map(|p| p.blend_mode()) != Some(BlendMode::Layer) =>
+        {
+            BlendMode::Normal
+        }
+        blend_mode => blend_mode,
+    }
+}
+
+fn render_opaque_background<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_, 'gc>) {
+    if let Some(color) = this.opaque_background() {
+        let bounds = this.bounds();
+        if bounds.valid {
+            let background = Matrix::create_box(
+                bounds.width().to_pixels() as f32,
+                bounds.height().to_pixels() as f32,
+                0.0,
+                bounds.x_min,
+                bounds.y_min,
+            );
+            context.renderer.draw_rect(
+                color,
+                &(context.transform_stack.transform().matrix * background),
+            );
+        }
+    }
+}
+
+/// Render the content of a display object, clipped to its scroll rect.
+///
+/// The content has already been offset by the scroll rect as part of the
+/// object's transform. Masks can't be nested while a mask is being drawn, so
+/// objects drawn as part of a mask are not clipped.
+fn render_scrolled<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_, 'gc>) {
+    let scroll_rect = this.scroll_rect().filter(|_| context.allow_mask);
+    if let Some(rect) = scroll_rect {
+        let clip = context.transform_stack.transform().matrix
+            * Matrix::create_box(
+                rect.width().to_pixels() as f32,
+                rect.height().to_pixels() as f32,
+                0.0,
+                rect.x_min,
+                rect.y_min,
+            );
+        context.renderer.push_mask();
+        context.renderer.draw_rect(Color::from_rgb(0, 255), &clip);
+        context.renderer.activate_mask();
+        this.render_self(context);
+        context.renderer.deactivate_mask();
+        context.renderer.draw_rect(Color::from_rgb(0, 255), &clip);
+        context.renderer.pop_mask();
+    } else {
+        this.render_self(context);
+    }
+}
+
+/// The largest width or height of the image that a layer is rendered into.
+const MAX_LAYER_SIZE: i32 = 8191;
+
+/// Render a display object as a layer, through its filters and blend mode.
+///
+/// The object is drawn into an offscreen target large enough to hold
+/// everything that its filters produce, filtered in software, and then
+/// composited onto the current target with the object's color transform and
+/// blend mode. This makes the object blend as a whole, rather than each of
+/// its shapes blending separately.
+///
+/// Objects with `cacheAsBitmap` set or with filters keep their layer image,
+/// and reuse it until they are invalidated or drawn with a different scale,
+/// rotation or skew. This saves filtering and reading back the layer every
+/// frame. Cached layers are drawn at whole pixel positions, so that moving
+/// them doesn't redraw them.
+fn render_layer<'gc>(
+    this: DisplayObject<'gc>,
+    filters: &[Filter],
+    blend_mode: BlendMode,
+    context: &mut RenderContext<'_, 'gc>,
+) {
+    let transform = context.transform_stack.transform().clone();
+    let is_cached = this.is_bitmap_cached() || !filters.is_empty();
+    let (origin, matrix) = if is_cached {
+        let snap = |twips: Twips| Twips::from_pixels(twips.to_pixels().round());
+        let origin = (snap(transform.matrix.tx), snap(transform.matrix.ty));
+        let matrix = Matrix {
+            tx: Twips::ZERO,
+            ty: Twips::ZERO,
+            ..transform.matrix
+        };
+        (origin, matrix)
+    } else {
+        ((Twips::ZERO, Twips::ZERO), transform.matrix)
+    };
+
+    let cached = if is_cached && !has_cache_changes(this) {
+        this.base().layer_texture.cached(&matrix)
+    } else {
+        None
+    };
+    let (handle, x_min, y_min) = match cached {
+        Some(cached) => cached,
+        None => match draw_layer(this, filters, blend_mode, &matrix, is_cached, context) {
+            Some(layer) => layer,
+            None => return,
+        },
+    };
+
+    context.renderer.push_blend_mode(blend_mode);
+    context.renderer.render_bitmap(
+        handle,
+        &Transform {
+            matrix: Matrix::translate(
+                origin.0 + Twips::from_pixels(x_min.into()),
+                origin.1 + Twips::from_pixels(y_min.into()),
+            ),
+            color_transform: transform.color_transform,
+        },
+        false,
+    );
+    context.renderer.pop_blend_mode();
+}
+
+/// Draw the layer image of a display object with the given transform, and
+/// upload it to the object's layer texture. The image is kept as the object's
+/// cached bitmap if `is_cached` is set.
+///
+/// Returns the texture and the position of the image within the target.
+fn draw_layer<'gc>(
+    this: DisplayObject<'gc>,
+    filters: &[Filter],
+    blend_mode: BlendMode,
+    matrix: &Matrix,
+    is_cached: bool,
+    context: &mut RenderContext<'_, 'gc>,
+) -> Option<(BitmapHandle, i32, i32)> {
+    let bounds = this.bounds_with_transform(matrix);
+    if !bounds.valid {
+        return None;
+    }
+
+    let padding = filter_padding(filters);
+    let x_min = bounds.x_min.to_pixels().floor() as i32 - padding.left as i32;
+    let y_min = bounds.y_min.to_pixels().floor() as i32 - padding.top as i32;
+    let x_max = bounds.x_max.to_pixels().ceil() as i32 + padding.right as i32;
+    let y_max = bounds.y_max.to_pixels().ceil() as i32 + padding.bottom as i32;
+    let width = (x_max - x_min).clamp(0, MAX_LAYER_SIZE) as u32;
+    let height = (y_max - y_min).clamp(0, MAX_LAYER_SIZE) as u32;
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    context
+        .renderer
+        .begin_offscreen(width, height, Color::from_rgb(0, 0));
+
+    let mut transform_stack = TransformStack::new();
+    transform_stack.push(&Transform {
+        matrix: Matrix::translate(
+            Twips::from_pixels(-x_min as f64),
+            Twips::from_pixels(-y_min as f64),
+        ) * *matrix,
+        color_transform: Default::default(),
+    });
+    let mut offscreen_context = RenderContext {
+        renderer: &mut *context.renderer,
+        ui: &mut *context.ui,
+        library: context.library,
+        transform_stack: &mut transform_stack,
+        stage: context.stage,
+        clip_depth_stack: vec![],
+        allow_mask: context.allow_mask,
+        is_offscreen: true,
+    };
+    render_opaque_background(this, &mut offscreen_context);
+    render_scrolled(this, &mut offscreen_context);
+
+    let rendered = match context.renderer.end_offscreen() {
+        Some(rendered) => rendered,
+        None => {
+            log::warn!("Renderer could not render a layer offscreen");
+            return None;
+        }
+    };
+
+    let (width, height) = (rendered.width, rendered.height);
+    let mut rgba = match rendered.data {
+        BitmapFormat::Rgba(rgba) => rgba,
+        BitmapFormat::Rgb(rgb) => rgb
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+    };
+    apply_filters(filters, width, height, &mut rgba);
+    if blend_mode == BlendMode::Invert {
+        // Inverting ignores the color of the layer, so make the layer white.
+        // This lets backends invert with `1 - dst` blending.
+        for pixel in rgba.chunks_exact_mut(4) {
+            let alpha = pixel[3];
+            pixel[..3].fill(alpha);
+        }
+    }
+
+    let base = this.base();
+    let handle = base
+        .layer_texture
+        .upload(context.renderer, width, height, rgba)?;
+    if is_cached {
+        base.layer_texture.set_cached(*matrix, x_min, y_min);
+        clear_cache_changes(this);
+    }
+    Some((handle, x_min, y_min))
+}
+
+/// Changes to a display object that cached bitmaps have not been redrawn
+/// with.
+///
+/// Objects only record their own changes, which are collected from the whole
+/// subtree when a cached bitmap is about to be drawn. This lets objects be
+/// changed while the objects containing them are borrowed.
+#[derive(Clone, Debug, Default)]
+struct CacheChanges {
+    /// The object's own appearance changed.
+    content: Cell<bool>,
+
+    /// The object was moved or recolored within its parent.
+    placement: Cell<bool>,
+}
+
+/// Whether the cached bitmap of a display object is out of date, because the
+/// object or any of its descendants changed since it was drawn.
+fn has_cache_changes(this: DisplayObject<'_>) -> bool {
+    if this.base().cache_changes.content.get() {
+        return true;
+    }
+
+    this.as_container().map_or(false, |container| {
+        container
+            .iter_render_list()
+            .any(|child| child.base().cache_changes.placement.get() || has_cache_changes(child))
+    })
+}
+
+/// Forget the changes to a display object and its descendants, once its
+/// cached bitmap has been redrawn.
+fn clear_cache_changes(this: DisplayObject<'_>) {
+    this.base().cache_changes.content.set(false);
+    if let Some(container) = this.as_container() {
+        for child in container.iter_render_list() {
+            child.base().cache_changes.placement.set(false);
+            clear_cache_changes(child);
+        }
+    }
+}
+
+/// The texture that a display object's layer image is drawn from.
+///
+/// Renderers can't free textures, so a texture is reused for as long as it is
+/// large enough to hold the layer image. Clones of a display object get a
+/// texture of their own.
+#[derive(Debug, Default)]
+struct LayerTexture {
+    texture: Cell<Option<(BitmapHandle, u32, u32)>>,
+
+    /// The transform and position of the image in the texture, if it is kept
+    /// as the cached bitmap of an object with `cacheAsBitmap` set.
+    cached: Cell<Option<(Matrix, i32, i32)>>,
+}
+
+impl LayerTexture {
+    /// Upload a layer image into the top-left corner of this texture,
+    /// creating a larger texture if the image does not fit.
+    fn upload(
+        &self,
+        renderer: &mut dyn RenderBackend,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    ) -> Option<BitmapHandle> {
+        // Uploading replaces whatever image was cached.
+        self.cached.set(None);
+
+        // New textures are rounded up in size, so that objects whose bounds
+        // change slightly every frame don't create a texture every frame.
+        let (handle, texture_width, texture_height) = match self.texture.get() {
+            Some((handle, texture_width, texture_height))
+                if width <= texture_width && height <= texture_height =>
+            {
+                (Some(handle), texture_width, texture_height)
+            }
+            _ => (None, width.next_power_of_two(), height.next_power_of_two()),
+        };
+
+        let mut pixels = vec![0; texture_width as usize * texture_height as usize * 4];
+        for (y, row) in rgba.chunks_exact(width as usize * 4).enumerate() {
+            let start = y * texture_width as usize * 4;
+            pixels[start..start + row.len()].copy_from_slice(row);
+        }
+
+        let result = match handle {
+            Some(handle) => renderer.update_texture(handle, texture_width, texture_height, pixels),
+            None => renderer.register_bitmap_raw(texture_width, texture_height, pixels),
+        };
+        match result {
+            Ok(handle) => {
+                self.texture
+                    .set(Some((handle, texture_width, texture_height)));
+                Some(handle)
+            }
+            Err(e) => {
+                log::warn!("Failed to upload layer image: {}", e);
+                None
+            }
+        }
+    }
+
+    /// The texture and position of the cached image, if it was drawn with the
+    /// given transform.
+    fn cached(&self, matrix: &Matrix) -> Option<(BitmapHandle, i32, i32)> {
+        let (handle, _, _) = self.texture.get()?;
+        match self.cached.get() {
+            Some((cached_matrix, x_min, y_min)) if cached_matrix == *matrix => {
+                Some((handle, x_min, y_min))
+            }
+            _ => None,
+        }
+    }
+
+    /// Keep the image that was last uploaded as a cached bitmap.
+    fn set_cached(&self, matrix: Matrix, x_min: i32, y_min: i32) {
+        self.cached.set(Some((matrix, x_min, y_min)));
+    }
+}
+
+impl Clone for LayerTexture {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
 #[enum_trait_object(
     #[derive(Clone, Collect, Debug, Copy)]
     #[collect(no_drop)]
@@ -522,7 +968,7 @@ pub trait TDisplayObject<'gc>:
 
     /// The local bounding box of this object including children, in its parent's coordinate system.
     fn local_bounds(&self) -> BoundingBox {
-        self.bounds_with_transform(self.base().matrix())
+        self.bounds_with_transform(&self.local_to_parent_matrix())
     }
 
     /// The world bounding box of this object including children, relative to the stage.
@@ -534,12 +980,18 @@ pub trait TDisplayObject<'gc>:
     /// This function recurses down and transforms the AABB each child before adding
     /// it to the bounding box. This gives a tighter AABB then if we simply transformed
     /// the overall AABB.
+    ///
+    /// Objects with a scroll rect are cropped to it.
     fn bounds_with_transform(&self, matrix: &Matrix) -> BoundingBox {
+        if let Some(scroll_rect) = self.scroll_rect() {
+            return scroll_rect.transform(matrix);
+        }
+
         let mut bounds = self.self_bounds().transform(matrix);
 
         if let Some(ctr) = self.as_container() {
             for child in ctr.iter_render_list() {
-                let matrix = *matrix * *child.base().matrix();
+                let matrix = *matrix * child.local_to_parent_matrix();
                 bounds.union(&child.bounds_with_transform(&matrix));
             }
         }
@@ -556,6 +1008,7 @@ pub trait TDisplayObject<'gc>:
 
     fn set_matrix(&self, gc_context: MutationContext<'gc, '_>, matrix: &Matrix) {
         self.base_mut(gc_context).set_matrix(matrix);
+        self.invalidate_parent_cached_bitmap();
     }
 
     fn set_color_transform(
@@ -564,13 +1017,28 @@ pub trait TDisplayObject<'gc>:
         color_transform: &ColorTransform,
     ) {
         self.base_mut(gc_context)
-            .set_color_transform(color_transform)
+            .set_color_transform(color_transform);
+        self.invalidate_parent_cached_bitmap();
+    }
+
+    /// Returns the matrix for transforming from this object's local space to
+    /// its parent's space.
+    ///
+    /// This is the object's own matrix, offset by its scroll rect.
+    fn local_to_parent_matrix(&self) -> Matrix {
+        let matrix = *self.base().matrix();
+        match self.scroll_rect() {
+            Some(rect) => {
+                matrix * Matrix::translate(Twips::ZERO - rect.x_min, Twips::ZERO - rect.y_min)
+            }
+            None => matrix,
+        }
     }
 
     /// Returns the matrix for transforming from this object's local space to global stage space.
     fn local_to_global_matrix(&self) -> Matrix {
         let mut node = self.parent();
-        let mut matrix = *self.base().matrix();
+        let mut matrix = self.local_to_parent_matrix();
         while let Some(display_object) = node {
             // TODO: We don't want to include the stage transform because it includes the scale
             // mode and alignment transform, but the AS APIs expect "global" to be relative to the
@@ -581,7 +1049,7 @@ pub trait TDisplayObject<'gc>:
             if display_object.as_stage().is_some() {
                 break;
             }
-            matrix = *display_object.base().matrix() * matrix;
+            matrix = display_object.local_to_parent_matrix() * matrix;
             node = display_object.parent();
         }
         matrix
@@ -614,6 +1082,7 @@ pub trait TDisplayObject<'gc>:
     /// Set by the `_x`/`x` ActionScript properties.
     fn set_x(&self, gc_context: MutationContext<'gc, '_>, value: f64) {
         self.base_mut(gc_context).set_x(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// The `y` position in pixels of this display object in local space.
@@ -626,6 +1095,7 @@ pub trait TDisplayObject<'gc>:
     /// Set by the `_y`/`y` ActionScript properties.
     fn set_y(&self, gc_context: MutationContext<'gc, '_>, value: f64) {
         self.base_mut(gc_context).set_y(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// The rotation in degrees this display object in local space.
@@ -638,6 +1108,7 @@ pub trait TDisplayObject<'gc>:
     /// Set by the `_rotation`/`rotation` ActionScript properties.
     fn set_rotation(&self, gc_context: MutationContext<'gc, '_>, radians: Degrees) {
         self.base_mut(gc_context).set_rotation(radians);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// The X axis scale for this display object in local space.
@@ -652,6 +1123,7 @@ pub trait TDisplayObject<'gc>:
     /// Set by the `_xscale`/`scaleX` ActionScript properties.
     fn set_scale_x(&self, gc_context: MutationContext<'gc, '_>, value: Percent) {
         self.base_mut(gc_context).set_scale_x(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// The Y axis scale for this display object in local space.
@@ -666,6 +1138,7 @@ pub trait TDisplayObject<'gc>:
     /// Returned by the `_yscale`/`scaleY` ActionScript properties.
     fn set_scale_y(&self, gc_context: MutationContext<'gc, '_>, value: Percent) {
         self.base_mut(gc_context).set_scale_y(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// Sets the pixel width of this display object in local space.
@@ -777,7 +1250,8 @@ pub trait TDisplayObject<'gc>:
     /// 1 is fully opaque.
     /// Set by the `_alpha`/`alpha` ActionScript properties.
     fn set_alpha(&self, gc_context: MutationContext<'gc, '_>, value: f64) {
-        self.base_mut(gc_context).set_alpha(value)
+        self.base_mut(gc_context).set_alpha(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     fn name(&self) -> AvmString<'gc> {
@@ -833,6 +1307,7 @@ pub trait TDisplayObject<'gc>:
     }
     fn set_clip_depth(&self, gc_context: MutationContext<'gc, '_>, depth: Depth) {
         self.base_mut(gc_context).set_clip_depth(depth);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// Retrieve the parent of this display object.
@@ -900,6 +1375,7 @@ pub trait TDisplayObject<'gc>:
             }
         }
         self.base_mut(gc_context).set_masker(node);
+        self.invalidate_parent_cached_bitmap();
     }
     fn maskee(&self) -> Option<DisplayObject<'gc>> {
         self.base().maskee()
@@ -918,6 +1394,98 @@ pub trait TDisplayObject<'gc>:
         self.base_mut(gc_context).set_maskee(node);
     }
 
+    /// Whether this object is rendered from a cached bitmap.
+    /// Returned by the `cacheAsBitmap` ActionScript property.
+    fn is_bitmap_cached(&self) -> bool {
+        self.base().is_bitmap_cached()
+    }
+    fn set_bitmap_cached(&self, gc_context: MutationContext<'gc, '_>, value: bool) {
+        self.base_mut(gc_context).set_bitmap_cached(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The blend mode used when compositing this object onto its parent.
+    /// Returned by the `blendMode` ActionScript property.
+    fn blend_mode(&self) -> BlendMode {
+        self.base().blend_mode()
+    }
+    fn set_blend_mode(&self, gc_context: MutationContext<'gc, '_>, value: BlendMode) {
+        self.base_mut(gc_context).set_blend_mode(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The solid background color drawn behind this object.
+    /// Returned by the `opaqueBackground` ActionScript property.
+    fn opaque_background(&self) -> Option<Color> {
+        self.base().opaque_background()
+    }
+    fn set_opaque_background(&self, gc_context: MutationContext<'gc, '_>, value: Option<Color>) {
+        self.base_mut(gc_context).set_opaque_background(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The region of this object that is displayed, in local coordinates.
+    /// Returned by the `scrollRect` ActionScript property.
+    fn scroll_rect(&self) -> Option<BoundingBox> {
+        self.base().scroll_rect()
+    }
+    fn set_scroll_rect(&self, gc_context: MutationContext<'gc, '_>, value: Option<BoundingBox>) {
+        self.base_mut(gc_context).set_scroll_rect(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The grid that splits this object into nine regions when it is scaled,
+    /// in local coordinates.
+    /// Returned by the `scale9Grid` ActionScript property.
+    fn scaling_grid(&self) -> Option<BoundingBox> {
+        self.base().scaling_grid()
+    }
+    fn set_scaling_grid(&self, gc_context: MutationContext<'gc, '_>, value: Option<BoundingBox>) {
+        self.base_mut(gc_context).set_scaling_grid(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// The regions that the content of this object's children is split into
+    /// by its scaling grid, if it has one that applies.
+    fn scaling_grid_slices(&self) -> Option<Vec<scaling_grid::Slice>> {
+        let grid = self.scaling_grid()?;
+        let bounds = self.bounds_with_transform(&Matrix::default());
+        scaling_grid::slices(&grid, &bounds, self.base().matrix())
+    }
+
+    /// The filters applied to this object when it is rendered.
+    /// Returned by the `filters` ActionScript property.
+    fn filters(&self) -> Vec<Filter> {
+        self.base().filters()
+    }
+    fn set_filters(&self, gc_context: MutationContext<'gc, '_>, value: Vec<Filter>) {
+        self.base_mut(gc_context).set_filters(value);
+        self.invalidate_cached_bitmap();
+    }
+
+    /// Record that the appearance of this object changed, so that its cached
+    /// bitmap and those of the objects containing it are redrawn.
+    fn invalidate_cached_bitmap(&self) {
+        self.base().cache_changes.content.set(true);
+    }
+
+    /// Record that this object was moved or recolored within its parent, so
+    /// that the cached bitmaps of the objects containing it are redrawn.
+    ///
+    /// This doesn't affect the object's own cached bitmap.
+    fn invalidate_parent_cached_bitmap(&self) {
+        self.base().cache_changes.placement.set(true);
+    }
+
+    /// Whether a stage position is within this object's scroll rect.
+    /// Objects without a scroll rect contain every position.
+    fn scroll_rect_contains(&self, point: (Twips, Twips)) -> bool {
+        match self.scroll_rect() {
+            Some(rect) => rect.contains(self.global_to_local(point)),
+            None => true,
+        }
+    }
+
     fn removed(&self) -> bool {
         self.base().removed()
     }
@@ -937,6 +1505,7 @@ pub trait TDisplayObject<'gc>:
     /// Returned by the `_visible`/`visible` ActionScript properties.
     fn set_visible(&self, gc_context: MutationContext<'gc, '_>, value: bool) {
         self.base_mut(gc_context).set_visible(value);
+        self.invalidate_parent_cached_bitmap();
     }
 
     /// Whether this display object represents the root of loaded content.
@@ -1232,6 +1801,12 @@ pub trait TDisplayObject<'gc>:
                     log::error!("No movie when trying to set clip event");
                 }
             }
+            if let Some(filters) = &place_object.filters {
+                self.set_filters(context.gc_context, filters.clone());
+            }
+            if let Some(blend_mode) = place_object.blend_mode {
+                self.set_blend_mode(context.gc_context, blend_mode);
+            }
             if self.swf_version() >= 11 {
                 if let Some(visible) = place_object.is_visible {
                     self.set_visible(context.gc_context, visible);
@@ -1440,7 +2015,7 @@ bitflags! {
     /// Bit flags used by `DisplayObject`.
     #[derive(Collect)]
     #[collect(no_drop)]
-    struct DisplayObjectFlags: u8 {
+    struct DisplayObjectFlags: u16 {
         /// Whether this object has been removed from the display list.
         /// Necessary in AVM1 to throw away queued actions from removed movie clips.
         const REMOVED                  = 1 << 0;
@@ -1470,6 +2045,9 @@ bitflags! {
         /// Whether this object has `_lockroot` set to true, in which case
         /// it becomes the _root of itself and of any children
         const LOCK_ROOT                = 1 << 7;
+
+        /// Whether this object should be rendered from a cached bitmap (`cacheAsBitmap` property).
+        const CACHE_AS_BITMAP          = 1 << 8;
     }
 }
 
@@ -180,6 +180,7 @@ impl<'gc> Avm1Button<'gc> {
                     context.gc_context,
                     &record.color_transform.clone().into(),
                 );
+                child.set_blend_mode(context.gc_context, record.blend_mode);
             }
         }
         drop(write);
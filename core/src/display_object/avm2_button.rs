@@ -10,6 +10,7 @@ use crate::display_object::container::{dispatch_added_event, dispatch_removed_ev
 use crate::display_object::interactive::{
     InteractiveObject, InteractiveObjectBase, TInteractiveObject,
 };
+use crate::display_object::scaling_grid::{self, can_render_slices, render_slices};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, MovieClip, TDisplayObject};
 use crate::events::{ClipEvent, ClipEventResult};
 use crate::prelude::*;
@@ -203,6 +204,7 @@ impl<'gc> Avm2Button<'gc> {
                                 context.gc_context,
                                 &record.color_transform.clone().into(),
                             );
+                            child.set_blend_mode(context.gc_context, record.blend_mode);
                         }
 
                         children.push((child, record.depth));
@@ -258,6 +260,7 @@ impl<'gc> Avm2Button<'gc> {
     /// Change the rendered state of the button.
     pub fn set_state(self, context: &mut UpdateContext<'_, 'gc, '_>, state: ButtonState) {
         self.0.write(context.gc_context).state = state;
+        self.invalidate_cached_bitmap();
         let button = self.0.read();
         if let Some(state) = button.up_state {
             state.set_parent(context.gc_context, None);
@@ -294,6 +297,7 @@ impl<'gc> Avm2Button<'gc> {
         state: swf::ButtonState,
         child: Option<DisplayObject<'gc>>,
     ) {
+        self.invalidate_cached_bitmap();
         let child_was_on_stage = child.map(|c| c.is_on_stage(context)).unwrap_or(false);
         let old_state_child = self.get_state_child(state);
         let is_cur_state = swf::ButtonState::from(self.0.read().state) == state;
@@ -611,7 +615,21 @@ impl<'gc> TDisplayObject<'gc> for Avm2Button<'gc> {
         let current_state = self.get_state_child(state.into());
 
         if let Some(state) = current_state {
-            state.render(context);
+            // The state is split by the button's own scaling grid, as the
+            // state isn't one of the button's children.
+            let slices = self
+                .scaling_grid()
+                .filter(|_| can_render_slices(context))
+                .and_then(|grid| {
+                    let bounds = state.bounds_with_transform(&state.local_to_parent_matrix());
+                    scaling_grid::slices(&grid, &bounds, self.base().matrix())
+                });
+            match slices {
+                Some(slices) => render_slices(context, &slices, &Matrix::default(), |context| {
+                    state.render(context)
+                }),
+                None => state.render(context),
+            }
         }
     }
 
@@ -261,12 +261,13 @@ impl<'gc> TDisplayObject<'gc> for Bitmap<'gc> {
                 );
                 drop(bd);
                 bitmap_data.write(context.gc_context).set_dirty(false);
+                self.invalidate_cached_bitmap();
             }
         }
     }
 
     fn render_self(&self, context: &mut RenderContext) {
-        if !self.world_bounds().intersects(&context.stage.view_bounds()) {
+        if !context.is_offscreen && !self.world_bounds().intersects(&context.stage.view_bounds()) {
             // Off-screen; culled
             return;
         }
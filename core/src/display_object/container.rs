@@ -434,6 +434,7 @@ macro_rules! impl_display_object_container {
                 removed_child.unload(context);
                 removed_child.set_parent(context.gc_context, None);
             }
+            self.invalidate_cached_bitmap();
 
             removed_child
         }
@@ -458,6 +459,7 @@ macro_rules! impl_display_object_container {
                 child,
                 depth,
             );
+            self.invalidate_cached_bitmap();
         }
 
         fn insert_at_index(
@@ -490,6 +492,7 @@ macro_rules! impl_display_object_container {
                 .write(context.gc_context)
                 .$field
                 .insert_at_id(child, index);
+            self.invalidate_cached_bitmap();
 
             if parent_changed {
                 dispatch_added_event(
@@ -511,6 +514,7 @@ macro_rules! impl_display_object_container {
                 .write(context.gc_context)
                 .$field
                 .swap_at_id(index1, index2);
+            self.invalidate_cached_bitmap();
         }
 
         fn remove_child(
@@ -535,6 +539,7 @@ macro_rules! impl_display_object_container {
                 && write.$field.remove_child_from_render_list(child);
 
             drop(write);
+            self.invalidate_cached_bitmap();
 
             if removed_from_depth_list || removed_from_render_list {
                 child.unload(context);
@@ -585,6 +590,8 @@ macro_rules! impl_display_object_container {
 
                 write = self.0.write(context.gc_context);
             }
+            drop(write);
+            self.invalidate_cached_bitmap();
         }
 
         fn clear(&mut self, context: &mut UpdateContext<'_, 'gc, '_>) {
@@ -595,7 +602,8 @@ macro_rules! impl_display_object_container {
                 dispatch_removed_event(removed, context);
             }
 
-            self.0.write(context.gc_context).$field.clear()
+            self.0.write(context.gc_context).$field.clear();
+            self.invalidate_cached_bitmap();
         }
 
         fn is_empty(self) -> bool {
@@ -1,5 +1,7 @@
 //! `EditText` display object and support code.
 
+mod restrict;
+
 use crate::avm1::activation::{Activation as Avm1Activation, ActivationIdentifier};
 use crate::avm1::{
     Avm1, Object as Avm1Object, StageObject as Avm1StageObject, TObject as Avm1TObject,
@@ -17,7 +19,7 @@ use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject}
 use crate::drawing::Drawing;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode};
 use crate::font::{round_down_to_pixel, Glyph, TextRenderSettings};
-use crate::html::{BoxBounds, FormatSpans, LayoutBox, LayoutContent, TextFormat};
+use crate::html::{BoxBounds, FormatSpans, LayoutBox, LayoutContent, StyleSheet, TextFormat};
 use crate::prelude::*;
 use crate::shape_utils::DrawCommand;
 use crate::string::{utils as string_utils, AvmString, WStr, WString};
@@ -26,6 +28,7 @@ use crate::transform::Transform;
 use crate::vminterface::{AvmObject, AvmType, Instantiator};
 use chrono::Utc;
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
+use restrict::TextRestrict;
 use std::{cell::Ref, cell::RefMut, sync::Arc};
 use swf::Twips;
 
@@ -74,6 +77,23 @@ pub struct EditTextData<'gc> {
     /// rendering.
     text_spans: FormatSpans,
 
+    /// The HTML that the text was last set from.
+    ///
+    /// The text spans are lowered from this again when the style sheet or the
+    /// hovered link changes, and it is returned as `htmlText` while a style
+    /// sheet is present. It is cleared when the text is changed in any other
+    /// way.
+    html_source: Option<WString>,
+
+    /// The style sheet that HTML text is styled with.
+    style_sheet: Option<StyleSheet>,
+
+    /// The VM object that `style_sheet` was set from.
+    style_sheet_object: Option<AvmObject<'gc>>,
+
+    /// The start of the link under the mouse, which is styled with `a:hover`.
+    hovered_link: Option<usize>,
+
     /// If the text is in multi-line mode or single-line mode.
     is_multiline: bool,
 
@@ -89,6 +109,14 @@ pub struct EditTextData<'gc> {
     /// If this is a password input field
     is_password: bool,
 
+    /// The characters that the user is allowed to enter, or `None` if any
+    /// character is allowed.
+    restrict: Option<TextRestrict>,
+
+    /// The maximum number of characters that the user can enter, or 0 if
+    /// there is no limit.
+    max_chars: i32,
+
     /// If the text field should have a background. Only applied when has_border.
     has_background: bool,
 
@@ -155,6 +183,22 @@ pub struct EditTextData<'gc> {
     scroll: usize,
 }
 
+impl<'gc> EditTextData<'gc> {
+    /// Lower the HTML source into text spans, styled with the style sheet.
+    fn lower_html(&mut self) {
+        if let Some(html_source) = &self.html_source {
+            let default_format = self.text_spans.default_format().clone();
+            self.text_spans = FormatSpans::from_html(
+                html_source,
+                default_format,
+                self.style_sheet.as_ref(),
+                self.hovered_link,
+                self.is_multiline,
+            );
+        }
+    }
+}
+
 // TODO: would be nicer to compute (and return) this during layout, instead of afterwards
 /// Compute line (index, offset, extent) from the layout data.
 fn get_line_data(layout: &[LayoutBox]) -> Vec<LineData> {
@@ -214,6 +258,7 @@ impl<'gc> EditText<'gc> {
         let is_word_wrap = swf_tag.is_word_wrap;
         let is_selectable = swf_tag.is_selectable;
         let is_password = swf_tag.is_password;
+        let max_chars = swf_tag.max_length.map_or(0, i32::from);
         let is_editable = !swf_tag.is_read_only;
         let is_html = swf_tag.is_html;
         let text = swf_tag.initial_text.unwrap_or_default();
@@ -221,8 +266,9 @@ impl<'gc> EditText<'gc> {
         let encoding = swf_movie.encoding();
 
         let text = WString::from_utf8(&text.to_str_lossy(encoding));
+        let html_source = is_html.then(|| text.clone());
         let mut text_spans = if is_html {
-            FormatSpans::from_html(&text, default_format, is_multiline)
+            FormatSpans::from_html(&text, default_format, None, None, is_multiline)
         } else {
             FormatSpans::from_text(text, default_format)
         };
@@ -271,6 +317,10 @@ impl<'gc> EditText<'gc> {
             EditTextData {
                 base,
                 text_spans,
+                html_source,
+                style_sheet: None,
+                style_sheet_object: None,
+                hovered_link: None,
                 static_data: gc_arena::Gc::allocate(
                     context.gc_context,
                     EditTextStatic {
@@ -310,6 +360,8 @@ impl<'gc> EditText<'gc> {
                 is_editable,
                 is_word_wrap,
                 is_password,
+                restrict: None,
+                max_chars,
                 has_background,
                 background_color,
                 has_border,
@@ -369,7 +421,7 @@ impl<'gc> EditText<'gc> {
                 b: 0,
                 a: 0xFF,
             }),
-            max_length: Some(width as u16),
+            max_length: None,
             layout: Some(swf::TextLayout {
                 align: swf::TextAlign::Left,
                 left_margin: Twips::from_pixels(0.0),
@@ -416,6 +468,8 @@ impl<'gc> EditText<'gc> {
         let mut edit_text = self.0.write(context.gc_context);
         let default_format = edit_text.text_spans.default_format().clone();
         edit_text.text_spans = FormatSpans::from_text(text.into(), default_format);
+        edit_text.html_source = None;
+        edit_text.hovered_link = None;
         drop(edit_text);
 
         self.relayout(context);
@@ -425,7 +479,12 @@ impl<'gc> EditText<'gc> {
 
     pub fn html_text(self) -> WString {
         if self.is_html() {
-            self.0.read().text_spans.to_html()
+            let edit_text = self.0.read();
+            match (&edit_text.style_sheet, &edit_text.html_source) {
+                // Styled text can't be raised back to HTML, so the source is returned as-is.
+                (Some(_), Some(html_source)) => html_source.clone(),
+                _ => edit_text.text_spans.to_html(),
+            }
         } else {
             // Non-HTML text fields always return plain text.
             self.text()
@@ -439,8 +498,9 @@ impl<'gc> EditText<'gc> {
     ) -> Result<(), Error> {
         if self.is_html() {
             let mut write = self.0.write(context.gc_context);
-            let default_format = write.text_spans.default_format().clone();
-            write.text_spans = FormatSpans::from_html(text, default_format, write.is_multiline);
+            write.html_source = Some(text.into());
+            write.hovered_link = None;
+            write.lower_html();
             drop(write);
 
             self.relayout(context);
@@ -478,11 +538,15 @@ impl<'gc> EditText<'gc> {
         tf: TextFormat,
         context: &mut UpdateContext<'_, 'gc, '_>,
     ) {
+        let mut edit_text = self.0.write(context.gc_context);
+        // Text styled with a style sheet can't be formatted directly.
+        if edit_text.style_sheet.is_some() {
+            return;
+        }
+
         // TODO: Convert to byte indices
-        self.0
-            .write(context.gc_context)
-            .text_spans
-            .set_text_format(from, to, &tf);
+        edit_text.text_spans.set_text_format(from, to, &tf);
+        drop(edit_text);
         self.relayout(context);
     }
 
@@ -507,6 +571,78 @@ impl<'gc> EditText<'gc> {
         self.relayout(context);
     }
 
+    /// The characters that the user is allowed to enter, as set by
+    /// ActionScript.
+    pub fn restrict(self) -> Option<WString> {
+        self.0
+            .read()
+            .restrict
+            .as_ref()
+            .map(|restrict| restrict.source().into())
+    }
+
+    pub fn set_restrict(self, text: Option<&WStr>, context: &mut UpdateContext<'_, 'gc, '_>) {
+        self.0.write(context.gc_context).restrict = text.map(TextRestrict::from_string);
+    }
+
+    pub fn max_chars(self) -> i32 {
+        self.0.read().max_chars
+    }
+
+    pub fn set_max_chars(self, value: i32, context: &mut UpdateContext<'_, 'gc, '_>) {
+        self.0.write(context.gc_context).max_chars = value;
+    }
+
+    /// The VM object of the style sheet that HTML text is styled with.
+    pub fn style_sheet(self) -> Option<AvmObject<'gc>> {
+        self.0.read().style_sheet_object
+    }
+
+    /// Set the style sheet that HTML text is styled with, along with the VM
+    /// object that it came from.
+    ///
+    /// The style sheet is copied, so later changes to the object only apply
+    /// once it is set again. HTML text that is already in the field is
+    /// restyled.
+    pub fn set_style_sheet(
+        self,
+        style_sheet: Option<(AvmObject<'gc>, StyleSheet)>,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) {
+        let mut edit_text = self.0.write(context.gc_context);
+        let (object, style_sheet) = match style_sheet {
+            Some((object, style_sheet)) => (Some(object), Some(style_sheet)),
+            None => (None, None),
+        };
+        edit_text.style_sheet_object = object;
+        edit_text.style_sheet = style_sheet;
+        if edit_text.is_html && edit_text.html_source.is_some() {
+            edit_text.lower_html();
+            drop(edit_text);
+            self.relayout(context);
+        }
+    }
+
+    /// Update the link under the mouse, restyling the text if the style sheet
+    /// has a style for hovered links.
+    fn set_hovered_link(self, link: Option<usize>, context: &mut UpdateContext<'_, 'gc, '_>) {
+        let mut edit_text = self.0.write(context.gc_context);
+        if edit_text.hovered_link == link {
+            return;
+        }
+        edit_text.hovered_link = link;
+
+        let has_hover_style = edit_text
+            .style_sheet
+            .as_ref()
+            .map_or(false, |s| s.style(WStr::from_units(b"a:hover")).is_some());
+        if has_hover_style && edit_text.is_html && edit_text.html_source.is_some() {
+            edit_text.lower_html();
+            drop(edit_text);
+            self.relayout(context);
+        }
+    }
+
     pub fn set_multiline(self, is_multiline: bool, context: &mut UpdateContext<'_, 'gc, '_>) {
         self.0.write(context.gc_context).is_multiline = is_multiline;
         self.relayout(context);
@@ -602,10 +738,11 @@ impl<'gc> EditText<'gc> {
         text: &WStr,
         context: &mut UpdateContext<'_, 'gc, '_>,
     ) {
-        self.0
-            .write(context.gc_context)
-            .text_spans
-            .replace_text(from, to, text, None);
+        let mut edit_text = self.0.write(context.gc_context);
+        edit_text.text_spans.replace_text(from, to, text, None);
+        edit_text.html_source = None;
+        edit_text.hovered_link = None;
+        drop(edit_text);
         self.relayout(context);
     }
 
@@ -695,6 +832,7 @@ impl<'gc> EditText<'gc> {
 
     /// Redraw the border of this `EditText`.
     fn redraw_border(self, gc_context: MutationContext<'gc, '_>) {
+        self.invalidate_cached_bitmap();
         let mut write = self.0.write(gc_context);
 
         write.drawing.clear();
@@ -756,6 +894,7 @@ impl<'gc> EditText<'gc> {
     /// have already been calculated and applied to HTML trees lowered into the
     /// text-span representation.
     fn relayout(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        self.invalidate_cached_bitmap();
         let mut edit_text = self.0.write(context.gc_context);
         let autosize = edit_text.autosize;
         let is_word_wrap = edit_text.is_word_wrap;
@@ -1139,6 +1278,7 @@ impl<'gc> EditText<'gc> {
         selection: Option<TextSelection>,
         gc_context: MutationContext<'gc, '_>,
     ) {
+        self.invalidate_cached_bitmap();
         let mut text = self.0.write(gc_context);
         if let Some(mut selection) = selection {
             selection.clamp(text.text_spans.text().len());
@@ -1162,6 +1302,7 @@ impl<'gc> EditText<'gc> {
 
     pub fn set_hscroll(self, hscroll: f64, context: &mut UpdateContext<'_, 'gc, '_>) {
         self.0.write(context.gc_context).hscroll = hscroll;
+        self.invalidate_cached_bitmap();
     }
 
     pub fn scroll(self) -> usize {
@@ -1179,6 +1320,7 @@ impl<'gc> EditText<'gc> {
         };
         let clamped = scroll_lines.clamp(1, self.maxscroll());
         self.0.write(context.gc_context).scroll = clamped;
+        self.invalidate_cached_bitmap();
     }
 
     pub fn screen_position_to_index(self, position: (Twips, Twips)) -> Option<usize> {
@@ -1235,8 +1377,8 @@ impl<'gc> EditText<'gc> {
 
         if let Some(selection) = self.selection() {
             let mut changed = false;
-            match character as u8 {
-                8 | 127 if !selection.is_caret() => {
+            match character {
+                '\u{8}' | '\u{7f}' if !selection.is_caret() => {
                     // Backspace or delete with multiple characters selected
                     self.replace_text(selection.start(), selection.end(), WStr::empty(), context);
                     self.set_selection(
@@ -1245,7 +1387,7 @@ impl<'gc> EditText<'gc> {
                     );
                     changed = true;
                 }
-                8 => {
+                '\u{8}' => {
                     // Backspace with caret
                     if selection.start() > 0 {
                         // Delete previous character
@@ -1259,7 +1401,7 @@ impl<'gc> EditText<'gc> {
                         changed = true;
                     }
                 }
-                127 => {
+                '\u{7f}' => {
                     // Delete with caret
                     if selection.end() < self.text_length() {
                         // Delete next character
@@ -1270,37 +1412,118 @@ impl<'gc> EditText<'gc> {
                         changed = true;
                     }
                 }
-                code if !(code as char).is_control() => {
-                    self.replace_text(
-                        selection.start(),
-                        selection.end(),
-                        &WString::from_char(character),
-                        context,
-                    );
-                    let new_start = selection.start() + character.len_utf8();
-                    self.set_selection(
-                        Some(TextSelection::for_position(new_start)),
-                        context.gc_context,
-                    );
-                    changed = true;
+                character if !character.is_control() => {
+                    changed = self.input_text(selection, &WString::from_char(character), context);
                 }
                 _ => {}
             }
 
             if changed {
-                let globals = context.avm1.global_object_cell();
-                let swf_version = context.swf.version();
-                let mut activation = Avm1Activation::from_nothing(
-                    context.reborrow(),
-                    ActivationIdentifier::root("[Propagate Text Binding]"),
-                    swf_version,
-                    globals,
-                    self.into(),
-                );
-                self.propagate_text_binding(&mut activation);
-                self.on_changed(&mut activation);
+                self.on_user_input(context);
+            }
+        }
+    }
+
+    /// Paste text from the clipboard over the current selection.
+    pub fn paste(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        if !self.0.read().is_editable {
+            return;
+        }
+
+        if let Some(selection) = self.selection() {
+            // Flash uses carriage returns for line breaks.
+            let content = context
+                .ui
+                .clipboard_content()
+                .replace("\r\n", "\r")
+                .replace('\n', "\r");
+            if self.input_text(selection, &WString::from_utf8(&content), context) {
+                self.on_user_input(context);
+            }
+        }
+    }
+
+    /// Replace the selection with text that the user entered.
+    ///
+    /// Control characters are left out, except for line breaks in multiline
+    /// fields, and the text is then limited by `restrict_text`. Returns
+    /// whether any text was entered.
+    fn input_text(
+        self,
+        selection: TextSelection,
+        text: &WStr,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> bool {
+        let is_multiline = self.0.read().is_multiline;
+        let mut input = WString::new();
+        for c in text.chars() {
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            if !c.is_control() || (is_multiline && c == '\r') {
+                input.push_char(c);
             }
         }
+
+        let input = self.restrict_text(selection.start(), selection.end(), &input);
+        if input.is_empty() {
+            return false;
+        }
+
+        self.replace_text(selection.start(), selection.end(), &input, context);
+        self.set_selection(
+            Some(TextSelection::for_position(selection.start() + input.len())),
+            context.gc_context,
+        );
+        true
+    }
+
+    /// Limit text that is about to replace the range `from..to` to what
+    /// `restrict` and `maxChars` allow.
+    ///
+    /// Characters that `restrict` does not allow are left out, and the text
+    /// is cut short so that the field holds no more than `maxChars`
+    /// characters afterwards.
+    pub fn restrict_text(self, from: usize, to: usize, text: &WStr) -> WString {
+        let edit_text = self.0.read();
+        let length = edit_text.text_spans.text().len();
+        let replaced = to.min(length).saturating_sub(from);
+        let mut remaining = match usize::try_from(edit_text.max_chars) {
+            Ok(max_chars) if max_chars > 0 => max_chars.saturating_sub(length - replaced),
+            _ => usize::MAX,
+        };
+
+        let mut restricted = WString::new();
+        for c in text.chars() {
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            let c = match &edit_text.restrict {
+                Some(restrict) => match restrict.to_allowed(c) {
+                    Some(c) => c,
+                    None => continue,
+                },
+                None => c,
+            };
+            if c.len_utf16() > remaining {
+                break;
+            }
+            remaining -= c.len_utf16();
+            restricted.push_char(c);
+        }
+        restricted
+    }
+
+    /// Update bound variables and notify listeners after the user changed
+    /// the text.
+    fn on_user_input(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        let globals = context.avm1.global_object_cell();
+        let swf_version = context.swf.version();
+        let mut activation = Avm1Activation::from_nothing(
+            context.reborrow(),
+            ActivationIdentifier::root("[Propagate Text Binding]"),
+            swf_version,
+            globals,
+            self.into(),
+        );
+        self.propagate_text_binding(&mut activation);
+        self.on_changed(&mut activation);
     }
 
     /// Listens for keyboard text control commands.
@@ -1620,7 +1843,7 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
     }
 
     fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
-        if !self.world_bounds().intersects(&context.stage.view_bounds()) {
+        if !context.is_offscreen && !self.world_bounds().intersects(&context.stage.view_bounds()) {
             // Off-screen; culled
             return;
         }
@@ -1781,11 +2004,12 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
     }
 
     fn filter_clip_event(self, event: ClipEvent) -> ClipEventResult {
-        if event != ClipEvent::Press {
-            return ClipEventResult::NotHandled;
+        match event {
+            ClipEvent::Press | ClipEvent::MouseMoveInside | ClipEvent::RollOut { .. } => {
+                ClipEventResult::Handled
+            }
+            _ => ClipEventResult::NotHandled,
         }
-
-        ClipEventResult::Handled
     }
 
     fn event_dispatch(
@@ -1793,6 +2017,25 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         context: &mut UpdateContext<'_, 'gc, '_>,
         event: ClipEvent<'gc>,
     ) -> ClipEventResult {
+        // Mouse movement only tracks the hovered link for `a:hover` styles.
+        match event {
+            ClipEvent::MouseMoveInside => {
+                let link = if self.0.read().style_sheet.is_some() {
+                    self.screen_position_to_index(*context.mouse_position)
+                        .and_then(|position| self.0.read().text_spans.link_start(position))
+                } else {
+                    None
+                };
+                self.set_hovered_link(link, context);
+                return ClipEventResult::Handled;
+            }
+            ClipEvent::RollOut { .. } => {
+                self.set_hovered_link(None, context);
+                return ClipEventResult::Handled;
+            }
+            _ => {}
+        }
+
         let tracker = context.focus_tracker;
         tracker.set(Some(self.into()), context);
         if let Some(position) = self
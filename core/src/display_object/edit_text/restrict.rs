@@ -0,0 +1,165 @@
+//! The `restrict` property of text fields.
+
+use crate::string::{WStr, WString};
+use gc_arena::Collect;
+use std::ops::RangeInclusive;
+
+/// The characters that a user is allowed to enter into a text field.
+///
+/// A restriction lists the allowed characters, such as `"0-9A-F"`. A `^`
+/// switches to listing disallowed characters and back, so `"^a-z"` allows
+/// anything but lowercase letters and `"A-Z^Q"` allows uppercase letters
+/// except for `Q`. A `\` escapes the next character, so that `-`, `^` and
+/// `\` can be listed themselves.
+#[derive(Clone, Debug, Collect)]
+#[collect(require_static)]
+pub struct TextRestrict {
+    /// The restriction as it was set, which is returned by ActionScript.
+    source: WString,
+
+    /// The listed characters and whether they are allowed. When ranges
+    /// overlap, the last one takes precedence.
+    ranges: Vec<(RangeInclusive<char>, bool)>,
+
+    /// Whether characters that aren't listed are allowed, which is the case
+    /// when the restriction starts with `^`.
+    allow_unlisted: bool,
+}
+
+impl TextRestrict {
+    pub fn from_string(source: &WStr) -> Self {
+        let chars: Vec<char> = source
+            .chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+
+        // Read the character at `i`, unescaping it. Returns the character,
+        // whether it was escaped, and the index of the next character.
+        let read = |i: usize| match chars.get(i) {
+            Some('\\') => chars.get(i + 1).map(|&c| (c, true, i + 2)),
+            Some(&c) => Some((c, false, i + 1)),
+            None => None,
+        };
+
+        let mut ranges = Vec::new();
+        let mut allow = true;
+        let mut allow_unlisted = None;
+        let mut i = 0;
+        while let Some((start, is_escaped, next)) = read(i) {
+            i = next;
+            if start == '^' && !is_escaped {
+                allow = !allow;
+                continue;
+            }
+            allow_unlisted.get_or_insert(!allow);
+
+            // A `-` between two characters lists all the characters between
+            // them, otherwise it is listed itself.
+            let mut end = start;
+            if let Some(('-', false, next)) = read(i) {
+                match read(next) {
+                    Some(('^', false, _)) | None => (),
+                    Some((range_end, _, next)) => {
+                        end = range_end;
+                        i = next;
+                    }
+                }
+            }
+            ranges.push((start..=end, allow));
+        }
+
+        Self {
+            source: source.into(),
+            ranges,
+            allow_unlisted: allow_unlisted.unwrap_or(false),
+        }
+    }
+
+    /// The restriction as it was set.
+    pub fn source(&self) -> &WStr {
+        &self.source
+    }
+
+    /// Returns whether a character is allowed.
+    pub fn is_allowed(&self, character: char) -> bool {
+        self.ranges
+            .iter()
+            .rev()
+            .find(|(range, _)| range.contains(&character))
+            .map_or(self.allow_unlisted, |(_, allow)| *allow)
+    }
+
+    /// Returns the character to enter when a user types `character`.
+    ///
+    /// A letter that isn't allowed is entered in the other case if that is
+    /// allowed instead, so that a field restricted to `"A-Z"` turns typed
+    /// lowercase letters into uppercase.
+    pub fn to_allowed(&self, character: char) -> Option<char> {
+        if self.is_allowed(character) {
+            return Some(character);
+        }
+
+        let other_case: Vec<char> = if character.is_lowercase() {
+            character.to_uppercase().collect()
+        } else {
+            character.to_lowercase().collect()
+        };
+        match other_case[..] {
+            [c] if c != character && self.is_allowed(c) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restrict(source: &str) -> TextRestrict {
+        TextRestrict::from_string(&WString::from_utf8(source))
+    }
+
+    #[test]
+    fn ranges_and_negation() {
+        let digits = restrict("0-9");
+        assert!(digits.is_allowed('5'));
+        assert!(!digits.is_allowed('a'));
+
+        let not_lowercase = restrict("^a-z");
+        assert!(not_lowercase.is_allowed('A'));
+        assert!(!not_lowercase.is_allowed('q'));
+
+        let uppercase_except_q = restrict("A-Z^Q");
+        assert!(uppercase_except_q.is_allowed('A'));
+        assert!(!uppercase_except_q.is_allowed('Q'));
+        assert!(!uppercase_except_q.is_allowed('1'));
+
+        let nothing = restrict("");
+        assert!(!nothing.is_allowed('a'));
+    }
+
+    #[test]
+    fn escapes_and_literal_dashes() {
+        let special = restrict("\\^\\-\\\\");
+        assert!(special.is_allowed('^'));
+        assert!(special.is_allowed('-'));
+        assert!(special.is_allowed('\\'));
+        assert!(!special.is_allowed('a'));
+
+        let trailing_dash = restrict("a-");
+        assert!(trailing_dash.is_allowed('a'));
+        assert!(trailing_dash.is_allowed('-'));
+        assert!(!trailing_dash.is_allowed('b'));
+    }
+
+    #[test]
+    fn letters_change_case() {
+        let uppercase = restrict("A-Z");
+        assert_eq!(uppercase.to_allowed('a'), Some('A'));
+        assert_eq!(uppercase.to_allowed('B'), Some('B'));
+        assert_eq!(uppercase.to_allowed('1'), None);
+
+        let both = restrict("a-zA-Z");
+        assert_eq!(both.to_allowed('a'), Some('a'));
+    }
+}
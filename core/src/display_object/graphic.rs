@@ -4,6 +4,7 @@ use crate::avm2::{
 };
 use crate::backend::render::ShapeHandle;
 use crate::context::{RenderContext, UpdateContext};
+use crate::display_object::scaling_grid::{can_render_slices, render_slices};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
 use crate::drawing::Drawing;
 use crate::prelude::*;
@@ -161,18 +162,35 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         // Noop
     }
 
-    fn render_self(&self, context: &mut RenderContext) {
-        if !self.world_bounds().intersects(&context.stage.view_bounds()) {
+    fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
+        // Shapes are split by the scaling grid of the clip they are placed in.
+        let slices = self
+            .parent()
+            .filter(|_| can_render_slices(context))
+            .and_then(|parent| parent.scaling_grid_slices());
+
+        if slices.is_none()
+            && !context.is_offscreen
+            && !self.world_bounds().intersects(&context.stage.view_bounds())
+        {
             // Off-screen; culled
             return;
         }
 
-        if let Some(drawing) = &self.0.read().drawing {
-            drawing.render(context);
-        } else if let Some(render_handle) = self.0.read().static_data.render_handle {
-            context
-                .renderer
-                .render_shape(render_handle, context.transform_stack.transform())
+        let read = self.0.read();
+        let render = |context: &mut RenderContext<'_, 'gc>| {
+            if let Some(drawing) = &read.drawing {
+                drawing.render(context);
+            } else if let Some(render_handle) = read.static_data.render_handle {
+                context
+                    .renderer
+                    .render_shape(render_handle, context.transform_stack.transform())
+            }
+        };
+        if let Some(slices) = slices {
+            render_slices(context, &slices, &self.local_to_parent_matrix(), render);
+        } else {
+            render(context);
         }
     }
 
@@ -183,7 +201,7 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         _options: HitTestOptions,
     ) -> bool {
         // Transform point to local coordinates and test.
-        if self.world_bounds().contains(point) {
+        if self.world_bounds().contains(point) && self.scroll_rect_contains(point) {
             let local_matrix = self.global_to_local_matrix();
             let point = local_matrix * point;
             if let Some(drawing) = &self.0.read().drawing {
@@ -236,6 +254,7 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
     }
 
     fn as_drawing(&self, gc_context: MutationContext<'gc, '_>) -> Option<RefMut<'_, Drawing>> {
+        self.invalidate_cached_bitmap();
         let mut write = self.0.write(gc_context);
         if write.drawing.is_none() {
             write.drawing = Some(Drawing::new());
@@ -23,6 +23,7 @@ use crate::display_object::container::{
 use crate::display_object::interactive::{
     InteractiveObject, InteractiveObjectBase, TInteractiveObject,
 };
+use crate::display_object::scaling_grid::{can_render_slices, render_slices};
 use crate::display_object::{
     Avm1Button, Avm2Button, Bitmap, DisplayObjectBase, DisplayObjectPtr, EditText, Graphic,
     MorphShape, TDisplayObject, Text, Video,
@@ -306,6 +307,10 @@ impl<'gc> MovieClip<'gc> {
                 .0
                 .write(context.gc_context)
                 .define_button_cxform(context, reader, tag_len),
+            TagCode::DefineScalingGrid => self
+                .0
+                .write(context.gc_context)
+                .define_scaling_grid(context, reader),
             TagCode::DefineButtonSound => self
                 .0
                 .write(context.gc_context)
@@ -1811,7 +1816,17 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     }
 
     fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
-        self.0.read().drawing.render(context);
+        let slices = self
+            .scaling_grid_slices()
+            .filter(|_| can_render_slices(context));
+        if let Some(slices) = slices {
+            let read = self.0.read();
+            render_slices(context, &slices, &Matrix::default(), |context| {
+                read.drawing.render(context)
+            });
+        } else {
+            self.0.read().drawing.render(context);
+        }
         self.render_children(context);
     }
 
@@ -1836,6 +1851,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
             return false;
         }
 
+        if !self.scroll_rect_contains(point) {
+            return false;
+        }
+
         if self.world_bounds().contains(point) {
             if let Some(masker) = self.masker() {
                 if !masker.hit_test_shape(context, point, HitTestOptions::SKIP_INVISIBLE) {
@@ -1886,6 +1905,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     }
 
     fn as_drawing(&self, gc_context: MutationContext<'gc, '_>) -> Option<RefMut<'_, Drawing>> {
+        self.invalidate_cached_bitmap();
         Some(RefMut::map(self.0.write(gc_context), |s| &mut s.drawing))
     }
 
@@ -2093,7 +2113,7 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
         point: (Twips, Twips),
         require_button_mode: bool,
     ) -> Option<InteractiveObject<'gc>> {
-        if self.visible() && self.mouse_enabled() {
+        if self.visible() && self.mouse_enabled() && self.scroll_rect_contains(point) {
             let this: InteractiveObject<'gc> = (*self).into();
 
             if let Some(masker) = self.masker() {
@@ -2658,6 +2678,45 @@ impl<'gc, 'a> MovieClipData<'gc> {
         Ok(())
     }
 
+    #[inline]
+    fn define_scaling_grid(
+        &mut self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        reader: &mut SwfStream<'a>,
+    ) -> DecodeResult {
+        let id = reader.read_u16()?;
+        let rect = reader.read_rectangle()?;
+        let grid = BoundingBox {
+            x_min: rect.x_min,
+            y_min: rect.y_min,
+            x_max: rect.x_max,
+            y_max: rect.y_max,
+            valid: true,
+        };
+        match context
+            .library
+            .library_for_movie_mut(self.movie())
+            .character_by_id(id)
+        {
+            Some(Character::MovieClip(clip)) => {
+                clip.set_scaling_grid(context.gc_context, Some(grid));
+            }
+            Some(Character::Avm1Button(button)) => {
+                button.set_scaling_grid(context.gc_context, Some(grid));
+            }
+            Some(Character::Avm2Button(button)) => {
+                button.set_scaling_grid(context.gc_context, Some(grid));
+            }
+            Some(_) => {
+                log::warn!("DefineScalingGrid: Tried to apply on non-sprite ID {}", id);
+            }
+            None => {
+                log::warn!("DefineScalingGrid: Character ID {} doesn't exist", id);
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn define_button_sound(
         &mut self,
@@ -3354,6 +3413,12 @@ impl<'a> GotoPlaceObject<'a> {
         if next_place.is_visible.is_some() {
             cur_place.is_visible = next_place.is_visible.take();
         }
+        if next_place.filters.is_some() {
+            cur_place.filters = next_place.filters.take();
+        }
+        if next_place.blend_mode.is_some() {
+            cur_place.blend_mode = next_place.blend_mode.take();
+        }
         // TODO: Other stuff.
     }
 }
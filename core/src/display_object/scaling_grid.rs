@@ -0,0 +1,232 @@
+//! 9-slice scaling of display objects with a `scale9Grid`.
+
+use crate::context::RenderContext;
+use crate::prelude::*;
+use crate::transform::Transform;
+
+/// One of the nine regions that a scaling grid splits an object into.
+#[derive(Clone, Debug)]
+pub struct Slice {
+    /// The area that this region is drawn into, in the object's coordinates.
+    pub bounds: BoundingBox,
+
+    /// Maps the content of this region to where it is drawn, in the
+    /// object's coordinates.
+    pub matrix: Matrix,
+}
+
+/// Split the content of an object into the nine regions of its scaling grid.
+///
+/// `bounds` are the bounds of the object's content in its own coordinates,
+/// and `matrix` is the object's transform. The corners keep their size
+/// regardless of the object's scale, the edges stretch along one axis and
+/// the center stretches along both. If the object is shrunk too far for its
+/// corners to fit, the corners shrink as well and the center disappears.
+///
+/// Returns `None` if the grid does not apply, which is the case for rotated
+/// or skewed objects and for grids without any area.
+pub fn slices(grid: &BoundingBox, bounds: &BoundingBox, matrix: &Matrix) -> Option<Vec<Slice>> {
+    if !grid.valid || !bounds.valid || matrix.b != 0.0 || matrix.c != 0.0 {
+        return None;
+    }
+
+    let xs = axis_slices(
+        (grid.x_min, grid.x_max),
+        (bounds.x_min, bounds.x_max),
+        matrix.a,
+    )?;
+    let ys = axis_slices(
+        (grid.y_min, grid.y_max),
+        (bounds.y_min, bounds.y_max),
+        matrix.d,
+    )?;
+
+    let mut slices = Vec::with_capacity(9);
+    for y in &ys {
+        for x in &xs {
+            slices.push(Slice {
+                bounds: BoundingBox {
+                    x_min: x.to.0,
+                    y_min: y.to.0,
+                    x_max: x.to.1,
+                    y_max: y.to.1,
+                    valid: true,
+                },
+                matrix: Matrix {
+                    a: x.scale,
+                    b: 0.0,
+                    c: 0.0,
+                    d: y.scale,
+                    tx: x.translate,
+                    ty: y.translate,
+                },
+            });
+        }
+    }
+    Some(slices)
+}
+
+/// The mapping of one region of a scaling grid along a single axis.
+struct AxisSlice {
+    to: (Twips, Twips),
+    scale: f32,
+    translate: Twips,
+}
+
+fn axis_slices(
+    (grid_min, grid_max): (Twips, Twips),
+    (min, max): (Twips, Twips),
+    scale: f32,
+) -> Option<Vec<AxisSlice>> {
+    let scale = f64::from(scale.abs());
+    if grid_min >= grid_max || min >= max || scale == 0.0 {
+        return None;
+    }
+    let grid_min = grid_min.clamp(min, max);
+    let grid_max = grid_max.clamp(min, max);
+
+    // The corners are sized in the parent's coordinates, so they are divided
+    // by the object's own scale to cancel it out.
+    let length = f64::from((max - min).get());
+    let start = f64::from((grid_min - min).get()) / scale;
+    let end = f64::from((max - grid_max).get()) / scale;
+    let shrink = if start + end > length {
+        length / (start + end)
+    } else {
+        1.0
+    };
+    let center_min = min + Twips::new((start * shrink).round() as i32);
+    let center_max = max - Twips::new((end * shrink).round() as i32);
+
+    let regions = [
+        ((min, grid_min), (min, center_min)),
+        ((grid_min, grid_max), (center_min, center_max)),
+        ((grid_max, max), (center_max, max)),
+    ];
+    Some(
+        regions
+            .iter()
+            .filter(|((from_min, from_max), (to_min, to_max))| {
+                from_min < from_max && to_min < to_max
+            })
+            .map(|&((from_min, from_max), (to_min, to_max))| {
+                let scale =
+                    f64::from((to_max - to_min).get()) / f64::from((from_max - from_min).get());
+                AxisSlice {
+                    to: (to_min, to_max),
+                    scale: scale as f32,
+                    translate: to_min
+                        - Twips::new((f64::from(from_min.get()) * scale).round() as i32),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Whether content can be drawn through the regions of a scaling grid.
+///
+/// The regions are clipped with masks, and masks can't be nested while a mask
+/// is being drawn. Objects drawn as part of a mask are scaled as a whole
+/// instead, the same as their scroll rects are ignored.
+pub fn can_render_slices(context: &RenderContext<'_, '_>) -> bool {
+    context.allow_mask
+}
+
+/// Render content through the regions of a scaling grid.
+///
+/// Every region is drawn with `render`, masked to the area that the region
+/// covers. `content_matrix` is the transform from the content to the object
+/// with the scaling grid, which has already been applied to the current
+/// transform.
+pub fn render_slices<'gc>(
+    context: &mut RenderContext<'_, 'gc>,
+    slices: &[Slice],
+    content_matrix: &Matrix,
+    mut render: impl FnMut(&mut RenderContext<'_, 'gc>),
+) {
+    let mut to_content = *content_matrix;
+    to_content.invert();
+    let object_matrix = context.transform_stack.transform().matrix * to_content;
+
+    for slice in slices {
+        let mask = object_matrix
+            * Matrix::create_box(
+                slice.bounds.width().to_pixels() as f32,
+                slice.bounds.height().to_pixels() as f32,
+                0.0,
+                slice.bounds.x_min,
+                slice.bounds.y_min,
+            );
+
+        context.renderer.push_mask();
+        context.renderer.draw_rect(Color::from_rgb(0, 255), &mask);
+        context.renderer.activate_mask();
+        context.transform_stack.push(&Transform {
+            matrix: to_content * slice.matrix * *content_matrix,
+            color_transform: Default::default(),
+        });
+        render(context);
+        context.transform_stack.pop();
+        context.renderer.deactivate_mask();
+        context.renderer.draw_rect(Color::from_rgb(0, 255), &mask);
+        context.renderer.pop_mask();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x_min: f64, y_min: f64, x_max: f64, y_max: f64) -> BoundingBox {
+        BoundingBox {
+            x_min: Twips::from_pixels(x_min),
+            y_min: Twips::from_pixels(y_min),
+            x_max: Twips::from_pixels(x_max),
+            y_max: Twips::from_pixels(y_max),
+            valid: true,
+        }
+    }
+
+    #[test]
+    fn corners_keep_their_size() {
+        let grid = bounds(10.0, 10.0, 90.0, 90.0);
+        let content = bounds(0.0, 0.0, 100.0, 100.0);
+        let slices = slices(&grid, &content, &Matrix::scale(4.0, 0.5)).unwrap();
+        assert_eq!(slices.len(), 9);
+
+        // Scaled by 4, the 10 pixel corners are 2.5 pixels wide before scaling.
+        let top_left = &slices[0];
+        assert_eq!(top_left.bounds, bounds(0.0, 0.0, 2.5, 20.0));
+        let (x, y) = top_left.matrix * (Twips::from_pixels(10.0), Twips::from_pixels(10.0));
+        assert_eq!((x, y), (Twips::from_pixels(2.5), Twips::from_pixels(20.0)));
+
+        let bottom_right = &slices[8];
+        assert_eq!(bottom_right.bounds, bounds(97.5, 80.0, 100.0, 100.0));
+        let (x, y) = bottom_right.matrix * (Twips::from_pixels(100.0), Twips::from_pixels(100.0));
+        assert_eq!(
+            (x, y),
+            (Twips::from_pixels(100.0), Twips::from_pixels(100.0))
+        );
+    }
+
+    #[test]
+    fn corners_shrink_when_they_do_not_fit() {
+        let grid = bounds(10.0, 10.0, 70.0, 70.0);
+        let content = bounds(0.0, 0.0, 100.0, 100.0);
+        let slices = slices(&grid, &content, &Matrix::scale(0.2, 1.0)).unwrap();
+
+        // The center column collapses, so only two columns remain, split in
+        // the same 1:3 ratio as the corners.
+        assert_eq!(slices.len(), 6);
+        assert_eq!(slices[0].bounds, bounds(0.0, 0.0, 25.0, 10.0));
+        assert_eq!(slices[1].bounds, bounds(25.0, 0.0, 100.0, 10.0));
+    }
+
+    #[test]
+    fn rotated_objects_are_not_sliced() {
+        let grid = bounds(10.0, 10.0, 90.0, 90.0);
+        let content = bounds(0.0, 0.0, 100.0, 100.0);
+        assert!(slices(&grid, &content, &Matrix::rotate(1.0)).is_none());
+        assert!(slices(&grid, &content, &Matrix::IDENTITY).is_some());
+    }
+}
@@ -10,6 +10,7 @@ use crate::bounding_box::BoundingBox;
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
 use crate::prelude::*;
+use crate::streams::NetStream;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::vminterface::{AvmObject, AvmType, Instantiator};
 use gc_arena::{Collect, GcCell, MutationContext};
@@ -37,7 +38,7 @@ pub struct VideoData<'gc> {
     base: DisplayObjectBase<'gc>,
 
     /// The source of the video data (e.g. an external file, a SWF bitstream)
-    source: GcCell<'gc, VideoSource>,
+    source: GcCell<'gc, VideoSource<'gc>>,
 
     /// The decoder stream that this video source is associated to.
     stream: VideoStream,
@@ -73,22 +74,34 @@ pub enum VideoStream {
 }
 
 #[derive(Clone, Debug, Collect)]
-#[collect(require_static)]
-pub enum VideoSource {
+#[collect(no_drop)]
+pub enum VideoSource<'gc> {
     /// A video bitstream embedded inside of a SWF movie.
     Swf {
         /// The movie that defined this video stream.
+        #[collect(require_static)]
         movie: Arc<SwfMovie>,
 
         /// The video stream definition.
+        #[collect(require_static)]
         streamdef: DefineVideoStream,
 
         /// The locations of each embedded sub-bitstream for each video frame.
         ///
         /// Each frame consists of a start and end parameter which can be used
         /// to reconstruct a reference to the embedded bitstream.
+        #[collect(require_static)]
         frames: BTreeMap<u32, (usize, usize)>,
     },
+
+    /// An external video file played by a `NetStream`.
+    NetStream {
+        /// The stream whose frames are shown, if one is attached.
+        stream: Option<NetStream<'gc>>,
+
+        /// The size that the frames are scaled to.
+        size: (u16, u16),
+    },
 }
 
 impl<'gc> Video<'gc> {
@@ -120,6 +133,71 @@ impl<'gc> Video<'gc> {
         ))
     }
 
+    /// Construct a Video object for an AVM2 `Video` with no video attached.
+    pub fn new_with_avm2(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        avm2_object: Avm2Object<'gc>,
+        width: u16,
+        height: u16,
+    ) -> Self {
+        let source = GcCell::allocate(
+            context.gc_context,
+            VideoSource::NetStream {
+                stream: None,
+                size: (width, height),
+            },
+        );
+
+        Video(GcCell::allocate(
+            context.gc_context,
+            VideoData {
+                base: Default::default(),
+                source,
+                stream: VideoStream::Uninstantiated(0),
+                decoded_frame: None,
+                object: Some(avm2_object.into()),
+                keyframes: BTreeSet::new(),
+            },
+        ))
+    }
+
+    /// Show the frames of a `NetStream` in this video, or stop showing them
+    /// if `stream` is `None`.
+    ///
+    /// The video keeps its current size, and any video embedded in a SWF that
+    /// it was showing is replaced.
+    pub fn attach_netstream(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        stream: Option<NetStream<'gc>>,
+    ) {
+        let bounds = self.self_bounds();
+        let size = (
+            bounds.width().to_pixels() as u16,
+            bounds.height().to_pixels() as u16,
+        );
+
+        let old_stream = match &*self.0.read().source.read() {
+            VideoSource::NetStream { stream, .. } => *stream,
+            _ => None,
+        };
+        if let Some(old_stream) = old_stream {
+            old_stream.detach_video(gc_context, self);
+        }
+        if let Some(stream) = stream {
+            stream.attach_video(gc_context, self);
+        }
+
+        // The source is shared with other instances of the same SWF video, so
+        // it is replaced rather than modified.
+        let mut write = self.0.write(gc_context);
+        write.source = GcCell::allocate(gc_context, VideoSource::NetStream { stream, size });
+        write.decoded_frame = None;
+        drop(write);
+
+        self.invalidate_cached_bitmap();
+    }
+
     /// Preload frame data from an SWF.
     ///
     /// This function yields an error if this video player is not playing an
@@ -149,6 +227,9 @@ impl<'gc> Video<'gc> {
                     log::warn!("Invalid bitstream subslice on frame {}", tag.frame_num);
                 }
             }
+            VideoSource::NetStream { .. } => {
+                log::warn!("Preloading SWF frame of video playing a stream");
+            }
         }
     }
 
@@ -161,6 +242,11 @@ impl<'gc> Video<'gc> {
     /// order. This matches Flash Player behavior.
     pub fn seek(self, context: &mut UpdateContext<'_, 'gc, '_>, mut frame_id: u32) {
         let read = self.0.read();
+        if let VideoSource::NetStream { .. } = &*read.source.read() {
+            // Streams are seeked through their `NetStream`.
+            return;
+        }
+
         if let VideoStream::Uninstantiated(_) = &read.stream {
             drop(read);
 
@@ -172,6 +258,7 @@ impl<'gc> Video<'gc> {
 
         let num_frames = match &*read.source.read() {
             VideoSource::Swf { streamdef, .. } => Some(streamdef.num_frames),
+            VideoSource::NetStream { .. } => None,
         };
 
         if let Some(num_frames) = num_frames {
@@ -187,6 +274,7 @@ impl<'gc> Video<'gc> {
         if last_frame == Some(frame_id) {
             return; // we are already there, no-op
         }
+        self.invalidate_cached_bitmap();
 
         let is_ordered_seek = frame_id == 0 || Some(frame_id) == last_frame.map(|lf| lf + 1);
 
@@ -268,6 +356,7 @@ impl<'gc> Video<'gc> {
                     }
                 }
             },
+            VideoSource::NetStream { .. } => return,
         };
 
         drop(read);
@@ -315,6 +404,15 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 .add_to_exec_list(context.gc_context, (*self).into());
         }
 
+        let source = self.0.read().source;
+        if let VideoSource::NetStream { .. } = &*source.read() {
+            // The frames of a stream are decoded by its `NetStream`.
+            if run_frame {
+                self.run_frame(context);
+            }
+            return;
+        }
+
         let mut write = self.0.write(context.gc_context);
 
         let (stream, movie, keyframes) = match &*write.source.read() {
@@ -363,6 +461,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
 
                 (stream, movie.clone(), keyframes)
             }
+            VideoSource::NetStream { .. } => return,
         };
 
         let starting_seek = if let VideoStream::Uninstantiated(seek_to) = write.stream {
@@ -421,6 +520,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
     fn id(&self) -> CharacterId {
         match (*self.0.read().source.read()).borrow() {
             VideoSource::Swf { streamdef, .. } => streamdef.id,
+            VideoSource::NetStream { .. } => 0,
         }
     }
 
@@ -432,13 +532,20 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 bounding_box.set_width(Twips::from_pixels(streamdef.width as f64));
                 bounding_box.set_height(Twips::from_pixels(streamdef.height as f64));
             }
+            VideoSource::NetStream {
+                size: (width, height),
+                ..
+            } => {
+                bounding_box.set_width(Twips::from_pixels(*width as f64));
+                bounding_box.set_height(Twips::from_pixels(*height as f64));
+            }
         }
 
         bounding_box
     }
 
     fn render(&self, context: &mut RenderContext) {
-        if !self.world_bounds().intersects(&context.stage.view_bounds()) {
+        if !context.is_offscreen && !self.world_bounds().intersects(&context.stage.view_bounds()) {
             // Off-screen; culled
             return;
         }
@@ -446,8 +553,12 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
         context.transform_stack.push(&*self.base().transform());
 
         let read = self.0.read();
+        let frame = match &*read.source.read() {
+            VideoSource::Swf { .. } => read.decoded_frame.map(|(_frame_id, bitmap)| bitmap),
+            VideoSource::NetStream { stream, .. } => stream.and_then(|stream| stream.last_frame()),
+        };
 
-        if let Some((_frame_id, ref bitmap)) = read.decoded_frame {
+        if let Some(ref bitmap) = frame {
             let mut transform = context.transform_stack.transform().clone();
             let bounds = self.self_bounds();
 
@@ -458,20 +569,19 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 bounds.height().to_pixels() as f32 / bitmap.height as f32,
             );
 
-            let (smoothed_flag, num_frames, version) = match &*read.source.read() {
+            let smoothing = match &*read.source.read() {
                 VideoSource::Swf {
                     streamdef,
                     frames,
                     movie,
-                } => (streamdef.is_smoothed, frames.len(), movie.version()),
-            };
-
-            let smoothing = match (context.stage.quality(), version) {
-                (StageQuality::Low, _) => false,
-                (_, 8..) => smoothed_flag,
-                (StageQuality::Medium, _) => false,
-                (StageQuality::High, _) => num_frames == 1,
-                (_, _) => true,
+                } => match (context.stage.quality(), movie.version()) {
+                    (StageQuality::Low, _) => false,
+                    (_, 8..) => streamdef.is_smoothed,
+                    (StageQuality::Medium, _) => false,
+                    (StageQuality::High, _) => frames.len() == 1,
+                    (_, _) => true,
+                },
+                VideoSource::NetStream { .. } => context.stage.quality() != StageQuality::Low,
             };
 
             context
@@ -1,3 +1,4 @@
+use crate::backend::font::FontFile;
 use crate::backend::render::{RenderBackend, ShapeHandle};
 use crate::html::TextSpan;
 use crate::prelude::*;
@@ -153,9 +154,11 @@ impl<'gc> Font<'gc> {
                 glyphs,
                 code_point_to_glyph,
 
-                /// DefineFont3 stores coordinates at 20x the scale of DefineFont1/2.
-                /// (SWF19 p.164)
-                scale: if tag_version >= 3 { 20480.0 } else { 1024.0 },
+                scale: if tag_version >= 3 {
+                    DEFINE_FONT_3_EM_SQUARE
+                } else {
+                    1024.0
+                },
                 kerning_pairs,
                 ascent,
                 descent,
@@ -165,6 +168,112 @@ impl<'gc> Font<'gc> {
         )))
     }
 
+    /// Load a font from a TrueType or OpenType font file, such as a system
+    /// font provided by the font backend.
+    ///
+    /// The outlines of the font are converted into glyph shapes, as if it
+    /// were embedded in a `DefineFont3` tag. Only characters in the Basic
+    /// Multilingual Plane are loaded.
+    pub fn from_font_file(
+        gc_context: MutationContext<'gc, '_>,
+        renderer: &mut dyn RenderBackend,
+        file: &FontFile,
+    ) -> Result<Font<'gc>, Error> {
+        let face = ttf_parser::Face::from_slice(&file.data, file.index)?;
+        let scale = DEFINE_FONT_3_EM_SQUARE / f32::from(face.units_per_em());
+        let to_em = |value: f32| (value * scale).round();
+
+        let mut code_points = Vec::new();
+        if let Some(cmap) = face.tables().cmap {
+            for subtable in cmap.subtables {
+                if subtable.is_unicode() {
+                    subtable.codepoints(|c| {
+                        if let Ok(code) = u16::try_from(c) {
+                            code_points.push(code);
+                        }
+                    });
+                }
+            }
+        }
+        code_points.sort_unstable();
+        code_points.dedup();
+
+        let mut glyphs = Vec::with_capacity(code_points.len());
+        let mut glyph_ids = fnv::FnvHashMap::default();
+        for code in code_points {
+            let glyph_id = match char::from_u32(code.into()).and_then(|c| face.glyph_index(c)) {
+                Some(glyph_id) if glyph_id.0 != 0 => glyph_id,
+                _ => continue,
+            };
+
+            let mut outline = GlyphOutline::new(scale);
+            face.outline_glyph(glyph_id, &mut outline);
+            let advance = face.glyph_hor_advance(glyph_id).unwrap_or_default();
+            glyphs.push(swf::Glyph {
+                shape_records: outline.records,
+                code,
+                advance: to_em(advance.into()).clamp(0.0, i16::MAX.into()) as i16,
+                bounds: None,
+            });
+            glyph_ids.insert(code, glyph_id);
+        }
+
+        // Kerning tables can't be enumerated by character, so only the pairs
+        // within Latin-1 are looked up.
+        let mut kerning = Vec::new();
+        if let Some(kern) = face.tables().kern {
+            let kerned_codes: Vec<_> = (0x20..=0xFF)
+                .filter_map(|code| Some((code, *glyph_ids.get(&code)?)))
+                .collect();
+            for subtable in kern.subtables {
+                if !subtable.horizontal || subtable.variable || subtable.has_cross_stream {
+                    continue;
+                }
+                for &(left_code, left) in &kerned_codes {
+                    for &(right_code, right) in &kerned_codes {
+                        if let Some(adjustment) = subtable.glyphs_kerning(left, right) {
+                            kerning.push(swf::KerningRecord {
+                                left_code,
+                                right_code,
+                                adjustment: Twips::new(to_em(adjustment.into()) as i32),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        let name = face
+            .names()
+            .into_iter()
+            .filter(|name| name.name_id == ttf_parser::name_id::FAMILY)
+            .find_map(|name| name.to_string())
+            .unwrap_or_default();
+        let mut flags = swf::FontFlag::HAS_LAYOUT | swf::FontFlag::HAS_WIDE_CODES;
+        flags.set(swf::FontFlag::IS_BOLD, face.is_bold());
+        flags.set(
+            swf::FontFlag::IS_ITALIC,
+            face.is_italic() || face.is_oblique(),
+        );
+
+        let tag = swf::Font {
+            version: 3,
+            id: 0,
+            name: swf::SwfStr::from_utf8_str(&name),
+            language: swf::Language::Unknown,
+            layout: Some(swf::FontLayout {
+                ascent: to_em(face.ascender().into()).clamp(0.0, u16::MAX.into()) as u16,
+                descent: to_em(-f32::from(face.descender())).clamp(0.0, u16::MAX.into()) as u16,
+                leading: to_em(face.line_gap().into()).clamp(i16::MIN.into(), i16::MAX.into())
+                    as i16,
+                kerning,
+            }),
+            glyphs,
+            flags,
+        };
+        Self::from_swf_tag(gc_context, renderer, tag, swf::UTF_8)
+    }
+
     /// Returns whether this font contains glyph shapes.
     /// If not, this font should be rendered as a device font.
     pub fn has_glyphs(&self) -> bool {
@@ -201,6 +310,17 @@ impl<'gc> Font<'gc> {
         true
     }
 
+    /// Determine if this font can display a string.
+    ///
+    /// Unlike `has_glyphs_for_str`, this ignores control characters such as
+    /// line breaks, which are never drawn.
+    pub fn can_display_str(&self, target_str: &WStr) -> bool {
+        target_str.chars().all(|character| {
+            let c = character.unwrap_or(char::REPLACEMENT_CHARACTER);
+            c.is_control() || self.get_glyph_for_char(c).is_some()
+        })
+    }
+
     /// Given a pair of characters, applies the offset that should be applied
     /// to the advance value between these two characters.
     /// Returns 0 twips if no kerning offset exists between these two characters.
@@ -440,6 +560,144 @@ impl Glyph {
     }
 }
 
+/// The size of the EM square of a `DefineFont3` font, in glyph coordinates.
+///
+/// DefineFont3 stores coordinates at 20x the scale of DefineFont1/2.
+/// (SWF19 p.164)
+const DEFINE_FONT_3_EM_SQUARE: f32 = 20480.0;
+
+/// Collects the outline of a glyph in a font file as SWF shape records.
+struct GlyphOutline {
+    records: Vec<swf::ShapeRecord>,
+
+    /// The scale from font units to glyph coordinates.
+    scale: f32,
+
+    /// The current point of the outline, in font units.
+    current: (f32, f32),
+
+    /// The start of the current contour, in font units.
+    start: (f32, f32),
+}
+
+impl GlyphOutline {
+    fn new(scale: f32) -> Self {
+        Self {
+            records: Vec::new(),
+            scale,
+            current: (0.0, 0.0),
+            start: (0.0, 0.0),
+        }
+    }
+
+    /// Convert a point in font units to glyph coordinates.
+    ///
+    /// The Y axis of font files points up, while glyph shapes point down.
+    fn to_twips(&self, (x, y): (f32, f32)) -> (Twips, Twips) {
+        (
+            Twips::new((x * self.scale).round() as i32),
+            Twips::new((-y * self.scale).round() as i32),
+        )
+    }
+
+    fn line(&mut self, to: (f32, f32)) {
+        let (x0, y0) = self.to_twips(self.current);
+        let (x1, y1) = self.to_twips(to);
+        if (x0, y0) != (x1, y1) {
+            self.records.push(swf::ShapeRecord::StraightEdge {
+                delta_x: x1 - x0,
+                delta_y: y1 - y0,
+            });
+        }
+        self.current = to;
+    }
+
+    fn quad(&mut self, control: (f32, f32), to: (f32, f32)) {
+        let (x0, y0) = self.to_twips(self.current);
+        let (cx, cy) = self.to_twips(control);
+        let (x1, y1) = self.to_twips(to);
+        self.records.push(swf::ShapeRecord::CurvedEdge {
+            control_delta_x: cx - x0,
+            control_delta_y: cy - y0,
+            anchor_delta_x: x1 - cx,
+            anchor_delta_y: y1 - cy,
+        });
+        self.current = to;
+    }
+}
+
+impl ttf_parser::OutlineBuilder for GlyphOutline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let move_to = self.to_twips((x, y));
+        // Only the first contour needs to set the fill style.
+        let fill_style_0 = if self.records.is_empty() {
+            Some(1)
+        } else {
+            None
+        };
+        self.records.push(swf::ShapeRecord::StyleChange(Box::new(
+            swf::StyleChangeData {
+                move_to: Some(move_to),
+                fill_style_0,
+                fill_style_1: None,
+                line_style: None,
+                new_styles: None,
+            },
+        )));
+        self.current = (x, y);
+        self.start = (x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.line((x, y));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.quad((x1, y1), (x, y));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        // SWF shapes only have quadratic curves, so cubic curves are split
+        // into pieces that are each approximated by a quadratic curve.
+        const PIECES: usize = 4;
+        let (x0, y0) = self.current;
+        let point = |t: f32| {
+            let u = 1.0 - t;
+            let (a, b, c, d) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+            (
+                a * x0 + b * x1 + c * x2 + d * x,
+                a * y0 + b * y1 + c * y2 + d * y,
+            )
+        };
+        let tangent = |t: f32| {
+            let u = 1.0 - t;
+            let (a, b, c) = (3.0 * u * u, 6.0 * u * t, 3.0 * t * t);
+            (
+                a * (x1 - x0) + b * (x2 - x1) + c * (x - x2),
+                a * (y1 - y0) + b * (y2 - y1) + c * (y - y2),
+            )
+        };
+
+        for i in 0..PIECES {
+            let (t0, t1) = (i as f32 / PIECES as f32, (i + 1) as f32 / PIECES as f32);
+            let (start, end) = (point(t0), point(t1));
+            let (d0, d1) = (tangent(t0), tangent(t1));
+            // The control point of the quadratic curve is the average of the
+            // control points that would match the tangent at either end.
+            let dt = (t1 - t0) / 2.0;
+            let control = (
+                (start.0 + d0.0 * dt + end.0 - d1.0 * dt) / 2.0,
+                (start.1 + d0.1 * dt + end.1 - d1.1 * dt) / 2.0,
+            );
+            self.quad(control, end);
+        }
+    }
+
+    fn close(&mut self) {
+        self.line(self.start);
+    }
+}
+
 /// Structure which identifies a particular font by name and properties.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Collect)]
 #[collect(require_static)]
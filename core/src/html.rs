@@ -3,12 +3,14 @@
 mod dimensions;
 mod iterators;
 mod layout;
+mod style_sheet;
 mod text_format;
 
 pub use dimensions::BoxBounds;
 pub use dimensions::Position;
 pub use dimensions::Size;
 pub use layout::{LayoutBox, LayoutContent};
+pub use style_sheet::{Style, StyleSheet};
 pub use text_format::{FormatSpans, TextFormat, TextSpan};
 
 #[cfg(test)]
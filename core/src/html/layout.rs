@@ -392,23 +392,51 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
         &mut self,
         context: &mut UpdateContext<'_, 'gc, '_>,
         span: &TextSpan,
+        text: &WStr,
         is_device_font: bool,
     ) -> Option<Font<'gc>> {
         let library = context.library.library_for_movie_mut(self.movie.clone());
+        let font_name = span.font.to_utf8_lossy();
 
-        // If this text field is set to use device fonts, fallback to using our embedded Noto Sans.
+        // If this text field is set to use device fonts, or the font isn't embedded, render it
+        // with a matching system font from the font backend.
         // Note that the SWF can still contain a DefineFont tag with no glyphs/layout info in this case (see #451).
-        // In an ideal world, device fonts would search for a matching font on the system and render it in some way.
         if let Some(font) = library
-            .get_font_by_name(&span.font.to_utf8_lossy(), span.bold, span.italic)
+            .get_font_by_name(&font_name, span.bold, span.italic)
             .filter(|f| !is_device_font && f.has_glyphs())
-            .or_else(|| context.library.device_font())
         {
             self.font = Some(font);
             return self.font;
         }
 
-        None
+        let system_font = context.library.get_or_load_system_font(
+            &font_name,
+            span.bold,
+            span.italic,
+            context.gc_context,
+            context.renderer,
+            context.fonts,
+        );
+
+        // Text that the system font can't display, such as CJK text in a Latin font, is rendered
+        // with a fallback font instead. Without any system fonts, fallback to using our embedded
+        // Noto Sans.
+        self.font = match system_font {
+            Some(font) if font.can_display_str(text) => Some(font),
+            _ => context
+                .library
+                .get_or_load_fallback_font(
+                    text,
+                    span.bold,
+                    span.italic,
+                    context.gc_context,
+                    context.renderer,
+                    context.fonts,
+                )
+                .or(system_font)
+                .or_else(|| context.library.device_font()),
+        };
+        self.font
     }
 
     /// Append text to the current line of the ongoing layout operation.
@@ -677,7 +705,9 @@ impl<'gc> LayoutBox<'gc> {
         let mut layout_context = LayoutContext::new(movie, bounds, fs.displayed_text());
 
         for (span_start, _end, span_text, span) in fs.iter_spans() {
-            if let Some(font) = layout_context.resolve_font(context, span, is_device_font) {
+            if let Some(font) =
+                layout_context.resolve_font(context, span, span_text, is_device_font)
+            {
                 layout_context.newspan(span);
 
                 let params = EvalParameters::from_span(span);
@@ -0,0 +1,253 @@
+//! CSS style sheets for HTML text
+
+use crate::html::TextFormat;
+use crate::string::{WStr, WString};
+use gc_arena::Collect;
+
+/// The declarations of a single style, as pairs of property names and values.
+///
+/// Property names are stored in camel case, the way that ActionScript sees
+/// them, so the CSS property `font-family` is stored as `fontFamily`.
+pub type Style = Vec<(WString, WString)>;
+
+/// A set of styles that HTML text is formatted with.
+///
+/// Styles are looked up by selector, which is either a tag name such as `p`,
+/// a class name such as `.title`, or a link state such as `a:hover`.
+/// Selectors are not case sensitive.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(require_static)]
+pub struct StyleSheet {
+    /// The styles and their lowercase selectors, in the order that they were
+    /// first defined.
+    styles: Vec<(WString, Style)>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove every style.
+    pub fn clear(&mut self) {
+        self.styles.clear();
+    }
+
+    /// The selectors of every style, in the order that they were defined.
+    pub fn style_names(&self) -> impl Iterator<Item = &WStr> {
+        self.styles.iter().map(|(name, _)| &name[..])
+    }
+
+    pub fn style(&self, name: &WStr) -> Option<&Style> {
+        let name = name.to_ascii_lowercase();
+        self.styles
+            .iter()
+            .find(|(selector, _)| *selector == name)
+            .map(|(_, style)| style)
+    }
+
+    /// Replace the style of a selector, or remove it if `style` is `None`.
+    pub fn set_style(&mut self, name: &WStr, style: Option<Style>) {
+        let name = name.to_ascii_lowercase();
+        let index = self
+            .styles
+            .iter()
+            .position(|(selector, _)| *selector == name);
+        match (index, style) {
+            (Some(i), Some(style)) => self.styles[i].1 = style,
+            (Some(i), None) => {
+                self.styles.remove(i);
+            }
+            (None, Some(style)) => self.styles.push((name, style)),
+            (None, None) => (),
+        }
+    }
+
+    /// Add the styles of a CSS document.
+    ///
+    /// Declarations for a selector that already has a style are merged into
+    /// it. Returns `false` without changing any styles if the document could
+    /// not be parsed.
+    pub fn parse_css(&mut self, css: &WStr) -> bool {
+        let rules = match parse_rules(&css.to_utf8_lossy()) {
+            Some(rules) => rules,
+            None => return false,
+        };
+
+        for (selectors, declarations) in rules {
+            for selector in selectors {
+                let selector = WString::from_utf8(&selector);
+                let mut style = self.style(&selector).cloned().unwrap_or_default();
+                for (name, value) in &declarations {
+                    let name = WString::from_utf8(name);
+                    let value = WString::from_utf8(value);
+                    match style.iter_mut().find(|(n, _)| *n == name) {
+                        Some((_, v)) => *v = value,
+                        None => style.push((name, value)),
+                    }
+                }
+                self.set_style(&selector, Some(style));
+            }
+        }
+        true
+    }
+
+    /// The text format that a selector applies, if it has a style.
+    pub fn text_format(&self, name: &WStr) -> Option<TextFormat> {
+        self.style(name).map(|style| Self::transform(style))
+    }
+
+    /// Convert the declarations of a style into the text format that they
+    /// apply.
+    ///
+    /// Properties and values that have no text format equivalent are ignored.
+    pub fn transform(style: &[(WString, WString)]) -> TextFormat {
+        let mut format = TextFormat::default();
+        for (name, value) in style {
+            let value = value.to_utf8_lossy();
+            let value = value.trim();
+            match &name.to_utf8_lossy()[..] {
+                "color" => format.color = parse_color(value),
+                "fontFamily" => format.font = Some(WString::from_utf8(&parse_font_family(value))),
+                "fontSize" => format.size = parse_number(value),
+                "fontStyle" => match value {
+                    "italic" => format.italic = Some(true),
+                    "normal" => format.italic = Some(false),
+                    _ => (),
+                },
+                "fontWeight" => match value {
+                    "bold" => format.bold = Some(true),
+                    "normal" => format.bold = Some(false),
+                    _ => (),
+                },
+                "kerning" => match value {
+                    "true" => format.kerning = Some(true),
+                    "false" => format.kerning = Some(false),
+                    _ => (),
+                },
+                "leading" => format.leading = parse_number(value),
+                "letterSpacing" => format.letter_spacing = parse_number(value),
+                "marginLeft" => format.left_margin = parse_number(value),
+                "marginRight" => format.right_margin = parse_number(value),
+                "textAlign" => match value {
+                    "left" => format.align = Some(swf::TextAlign::Left),
+                    "center" => format.align = Some(swf::TextAlign::Center),
+                    "right" => format.align = Some(swf::TextAlign::Right),
+                    "justify" => format.align = Some(swf::TextAlign::Justify),
+                    _ => (),
+                },
+                "textDecoration" => match value {
+                    "underline" => format.underline = Some(true),
+                    "none" => format.underline = Some(false),
+                    _ => (),
+                },
+                "textIndent" => format.indent = parse_number(value),
+                _ => (),
+            }
+        }
+        format
+    }
+}
+
+/// Parse a color in the `#RRGGBB` form.
+fn parse_color(value: &str) -> Option<swf::Color> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let rgb = u32::from_str_radix(hex, 16).ok()?;
+    Some(swf::Color::from_rgb(rgb, 0))
+}
+
+/// Parse a length such as `12` or `12px`.
+fn parse_number(value: &str) -> Option<f64> {
+    value
+        .strip_suffix("px")
+        .unwrap_or(value)
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Replace the generic CSS font families with their device font names.
+fn parse_font_family(value: &str) -> String {
+    value
+        .split(',')
+        .map(
+            |family| match family.trim().trim_matches(|c| c == '"' || c == '\'') {
+                "mono" | "monospace" => "_typewriter",
+                "sans-serif" => "_sans",
+                "serif" => "_serif",
+                family => family,
+            },
+        )
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// A rule of a CSS document: its selectors and the declarations that apply to
+/// them.
+type Rule = (Vec<String>, Vec<(String, String)>);
+
+/// Parse the rules of a CSS document, returning `None` if it is malformed.
+fn parse_rules(css: &str) -> Option<Vec<Rule>> {
+    let css = strip_comments(css)?;
+    let mut rules = Vec::new();
+    let mut rest = css.trim_start();
+    while !rest.is_empty() {
+        let (selectors, after) = rest.split_once('{')?;
+        let (block, after) = after.split_once('}')?;
+        if selectors.contains('}') || block.contains('{') {
+            return None;
+        }
+
+        let selectors: Vec<String> = selectors.split(',').map(|s| s.trim().into()).collect();
+        if selectors.iter().any(|s| s.is_empty()) {
+            return None;
+        }
+
+        let mut declarations = Vec::new();
+        for declaration in block.split(';') {
+            let declaration = declaration.trim();
+            if declaration.is_empty() {
+                continue;
+            }
+            let (name, value) = declaration.split_once(':')?;
+            let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+            declarations.push((camel_case(name.trim()), value.into()));
+        }
+
+        rules.push((selectors, declarations));
+        rest = after.trim_start();
+    }
+    Some(rules)
+}
+
+/// Remove the `/* ... */` comments of a CSS document, returning `None` if a
+/// comment is not closed.
+fn strip_comments(css: &str) -> Option<String> {
+    let mut stripped = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        stripped.push_str(&rest[..start]);
+        let end = rest[start + 2..].find("*/")?;
+        rest = &rest[start + 2 + end + 2..];
+    }
+    stripped.push_str(rest);
+    Some(stripped)
+}
+
+/// Convert a CSS property name such as `font-family` into the camel case
+/// name that ActionScript uses, `fontFamily`.
+fn camel_case(name: &str) -> String {
+    let mut parts = name.split('-').filter(|part| !part.is_empty());
+    let mut camel_case = parts.next().unwrap_or_default().to_owned();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel_case.push(first.to_ascii_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+    }
+    camel_case
+}
@@ -1,6 +1,7 @@
 //! Tests for HTML module
 
 use crate::html::dimensions::{BoxBounds, Position, Size};
+use crate::html::style_sheet::StyleSheet;
 use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
 use crate::string::{WStr, WString};
 use swf::{Rectangle, Twips};
@@ -864,3 +865,75 @@ fn formatspans_replace_text_degenerate() {
     assert_eq!((0, 1), fs.get_span_boundaries(0, 5));
     assert_eq!((1, 2), fs.get_span_boundaries(5, 9));
 }
+
+#[test]
+fn stylesheet_parse_css() {
+    let mut style_sheet = StyleSheet::new();
+    let css = WString::from_utf8(
+        "/* Headings */ H1, .title { font-size: 24px; font-weight: bold }\n\
+         a:hover { color: #FF0000; text-decoration: underline; }",
+    );
+    assert!(style_sheet.parse_css(&css));
+
+    let names: Vec<_> = style_sheet.style_names().map(WString::from).collect();
+    assert_eq!(
+        names,
+        vec![
+            WString::from_utf8("h1"),
+            WString::from_utf8(".title"),
+            WString::from_utf8("a:hover"),
+        ]
+    );
+
+    let title = style_sheet.style(WStr::from_units(b".TITLE")).unwrap();
+    assert_eq!(title[0].0, WString::from_utf8("fontSize"));
+    assert_eq!(title[0].1, WString::from_utf8("24px"));
+
+    let hover = style_sheet
+        .text_format(WStr::from_units(b"a:hover"))
+        .unwrap();
+    assert_eq!(hover.color, Some(swf::Color::from_rgb(0xFF0000, 0)));
+    assert_eq!(hover.underline, Some(true));
+    assert_eq!(hover.bold, None);
+
+    // Malformed CSS leaves the existing styles alone.
+    assert!(!style_sheet.parse_css(&WString::from_utf8("p { color: #000000;")));
+    assert_eq!(style_sheet.style_names().count(), 3);
+}
+
+#[test]
+fn formatspans_from_html_with_stylesheet() {
+    let mut style_sheet = StyleSheet::new();
+    style_sheet.parse_css(&WString::from_utf8(
+        "p { font-size: 20 } .note { font-style: italic } a:hover { font-weight: bold }",
+    ));
+
+    let html =
+        WString::from_utf8("<p>Intro <span class=\"note\">note</span> <a href=\"x\">link</a></p>");
+    let fs = FormatSpans::from_html(&html, TextFormat::default(), Some(&style_sheet), None, true);
+    let spans: Vec<_> = fs
+        .iter_spans()
+        .map(|(_, _, text, span)| (text, span))
+        .collect();
+    assert_eq!(spans[0].0, WStr::from_units(b"Intro "));
+    assert_eq!(spans[0].1.size, 20.0);
+    assert!(!spans[0].1.italic);
+    assert_eq!(spans[1].0, WStr::from_units(b"note"));
+    assert!(spans[1].1.italic);
+    assert!(!spans[3].1.bold);
+
+    let link_start = fs.link_start(13).unwrap();
+    assert_eq!(link_start, 11);
+    let fs = FormatSpans::from_html(
+        &html,
+        TextFormat::default(),
+        Some(&style_sheet),
+        Some(link_start),
+        true,
+    );
+    let link = fs
+        .iter_spans()
+        .find(|(start, _, _, _)| *start == 11)
+        .unwrap();
+    assert!(link.3.bold);
+}
@@ -2,6 +2,7 @@
 
 use crate::context::UpdateContext;
 use crate::html::iterators::TextSpanIter;
+use crate::html::StyleSheet;
 use crate::string::{Integer, Units, WStr, WString};
 use crate::tag_utils::SwfMovie;
 use gc_arena::Collect;
@@ -564,11 +565,17 @@ impl FormatSpans {
 
     /// Lower an HTML tree into text-span representation.
     ///
-    /// This is the "legacy" implementation of this process: it only looks for
-    /// a handful of presentational attributes in the HTML tree to generate
-    /// styling. There's also a `lower_from_css` that respects both
-    /// presentational markup and CSS stylesheets.
-    pub fn from_html(html: &WStr, default_format: TextFormat, is_multiline: bool) -> Self {
+    /// Styling is generated from a handful of presentational attributes in the
+    /// HTML tree, and from the tag, class and link styles of `style_sheet`.
+    /// `hovered_link` is the start of the link under the mouse, which gets
+    /// the `a:hover` style.
+    pub fn from_html(
+        html: &WStr,
+        default_format: TextFormat,
+        style_sheet: Option<&StyleSheet>,
+        hovered_link: Option<usize>,
+        is_multiline: bool,
+    ) -> Self {
         let mut format_stack = vec![default_format.clone()];
         let mut text = WString::new();
         let mut spans: Vec<TextSpan> = Vec::new();
@@ -603,6 +610,13 @@ impl FormatSpans {
         let mut opened_buffer: Vec<u8> = Vec::new();
         let mut opened_starts = Vec::new();
 
+        // Apply the style of a selector on top of a format.
+        let apply_style = |format: &mut TextFormat, selector: &WStr| {
+            if let Some(style) = style_sheet.and_then(|s| s.text_format(selector)) {
+                *format = style.mix_with(std::mem::take(format));
+            }
+        };
+
         let mut reader = Reader::from_reader(&raw_bytes[..]);
         reader.expand_empty_elements(true);
         reader.check_end_names(false);
@@ -759,6 +773,23 @@ impl FormatSpans {
                         }
                         _ => {}
                     }
+
+                    if style_sheet.is_some() {
+                        apply_style(&mut format, &decode_to_wstr(e.name()));
+                        if let Some(class) = attribute(b"class") {
+                            let mut selector = WString::from_char('.');
+                            selector.push_str(&class);
+                            apply_style(&mut format, &selector);
+                        }
+                        if e.name().eq_ignore_ascii_case(b"a") {
+                            if attribute(b"href").is_some() {
+                                apply_style(&mut format, WStr::from_units(b"a:link"));
+                            }
+                            if hovered_link == Some(text.len()) {
+                                apply_style(&mut format, WStr::from_units(b"a:hover"));
+                            }
+                        }
+                    }
                     format_stack.push(format);
                 }
                 Ok(Event::Text(e)) if !e.is_empty() => {
@@ -877,6 +908,31 @@ impl FormatSpans {
         None
     }
 
+    /// Find the start of the link that covers a given position.
+    ///
+    /// A link is a run of spans with the same URL. Returns `None` if the
+    /// position is not part of a link.
+    pub fn link_start(&self, search_pos: usize) -> Option<usize> {
+        let mut position = 0;
+        let mut link_start = 0;
+        let mut link_url = WStr::empty();
+
+        for span in self.spans.iter() {
+            if span.url[..] != *link_url {
+                link_start = position;
+                link_url = &span.url;
+            }
+
+            if search_pos < position + span.span_length {
+                return (!span.url.is_empty()).then(|| link_start);
+            }
+
+            position += span.span_length;
+        }
+
+        None
+    }
+
     /// Create a text-span break at a particular position, if one does not
     /// already exist.
     ///
@@ -0,0 +1,412 @@
+//! ID3 tags of MP3 files
+//!
+//! Both the ID3v1 trailer and ID3v2.2, 2.3 and 2.4 headers are read. Only the
+//! text and comment frames are kept, since those are all that Flash exposes.
+
+use crate::backend::audio::mp3::id3v2_tag_len;
+
+/// ID3v2.3 frame IDs of the ID3v2.2 frames that are kept.
+const ID3V22_FRAME_IDS: &[(&[u8; 3], &str)] = &[
+    (b"COM", "COMM"),
+    (b"TAL", "TALB"),
+    (b"TBP", "TBPM"),
+    (b"TCM", "TCOM"),
+    (b"TCO", "TCON"),
+    (b"TCR", "TCOP"),
+    (b"TDA", "TDAT"),
+    (b"TDY", "TDLY"),
+    (b"TEN", "TENC"),
+    (b"TFT", "TFLT"),
+    (b"TIM", "TIME"),
+    (b"TKE", "TKEY"),
+    (b"TLA", "TLAN"),
+    (b"TLE", "TLEN"),
+    (b"TMT", "TMED"),
+    (b"TOA", "TOPE"),
+    (b"TOF", "TOFN"),
+    (b"TOL", "TOLY"),
+    (b"TOR", "TORY"),
+    (b"TOT", "TOAL"),
+    (b"TP1", "TPE1"),
+    (b"TP2", "TPE2"),
+    (b"TP3", "TPE3"),
+    (b"TP4", "TPE4"),
+    (b"TPA", "TPOS"),
+    (b"TPB", "TPUB"),
+    (b"TRC", "TSRC"),
+    (b"TRD", "TRDA"),
+    (b"TRK", "TRCK"),
+    (b"TSI", "TSIZ"),
+    (b"TSS", "TSSE"),
+    (b"TT1", "TIT1"),
+    (b"TT2", "TIT2"),
+    (b"TT3", "TIT3"),
+    (b"TXT", "TEXT"),
+    (b"TYE", "TYER"),
+];
+
+/// The text frames of an MP3 file's ID3 tags, keyed by their ID3v2.3 frame ID.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Id3Tag {
+    frames: Vec<(String, String)>,
+}
+
+impl Id3Tag {
+    /// Reads the ID3 tags of an MP3 file.
+    ///
+    /// Frames of an ID3v2 tag take precedence over the ID3v1 fields.
+    /// Returns `None` if the file has neither.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let mut tag = Self::default();
+        let has_v2 = tag.read_id3v2(data);
+        let has_v1 = tag.read_id3v1(data);
+        if has_v2 || has_v1 {
+            Some(tag)
+        } else {
+            None
+        }
+    }
+
+    /// The frame IDs and texts of all frames, in the order they were read.
+    pub fn frames(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.frames
+            .iter()
+            .map(|(id, text)| (id.as_str(), text.as_str()))
+    }
+
+    /// The text of the frame with the given ID3v2.3 ID.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.frames
+            .iter()
+            .find(|(frame_id, _)| frame_id == id)
+            .map(|(_, text)| text.as_str())
+    }
+
+    pub fn song_name(&self) -> Option<&str> {
+        self.get("TIT2")
+    }
+
+    pub fn artist(&self) -> Option<&str> {
+        self.get("TPE1")
+    }
+
+    pub fn album(&self) -> Option<&str> {
+        self.get("TALB")
+    }
+
+    pub fn year(&self) -> Option<&str> {
+        self.get("TYER")
+    }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.get("COMM")
+    }
+
+    pub fn genre(&self) -> Option<&str> {
+        self.get("TCON")
+    }
+
+    pub fn track(&self) -> Option<&str> {
+        self.get("TRCK")
+    }
+
+    /// Adds a frame, unless one with the same ID was already read.
+    fn insert(&mut self, id: &str, text: String) {
+        if !text.is_empty() && self.get(id).is_none() {
+            self.frames.push((id.to_string(), text));
+        }
+    }
+
+    fn read_id3v1(&mut self, data: &[u8]) -> bool {
+        let tag = match data.len().checked_sub(128).map(|start| &data[start..]) {
+            Some(tag) if tag.starts_with(b"TAG") => tag,
+            _ => return false,
+        };
+
+        let field = |range: std::ops::Range<usize>| {
+            decode_latin1(&tag[range])
+                .trim_end_matches([' ', '\0'])
+                .to_string()
+        };
+        self.insert("TIT2", field(3..33));
+        self.insert("TPE1", field(33..63));
+        self.insert("TALB", field(63..93));
+        self.insert("TYER", field(93..97));
+
+        // ID3v1.1 stores the track number in the last byte of the comment.
+        if tag[125] == 0 && tag[126] != 0 {
+            self.insert("COMM", field(97..125));
+            self.insert("TRCK", tag[126].to_string());
+        } else {
+            self.insert("COMM", field(97..127));
+        }
+
+        if tag[127] != 0xFF {
+            self.insert("TCON", tag[127].to_string());
+        }
+
+        true
+    }
+
+    fn read_id3v2(&mut self, data: &[u8]) -> bool {
+        let tag_len = id3v2_tag_len(data);
+        if tag_len == 0 {
+            return false;
+        }
+
+        let version = data[3];
+        let flags = data[5];
+        let mut body = data[10..tag_len.min(data.len())].to_vec();
+        if flags & 0x80 != 0 {
+            body = remove_unsynchronisation(&body);
+        }
+
+        let mut pos = 0;
+        match version {
+            // ID3v2.2 uses this flag for compression, which has no defined scheme.
+            2 if flags & 0x40 != 0 => return true,
+            3 if flags & 0x40 != 0 => pos = 4 + read_u32(&body, 0, false).unwrap_or(0),
+            4 if flags & 0x40 != 0 => pos = read_u32(&body, 0, true).unwrap_or(0),
+            2..=4 => (),
+            _ => return true,
+        }
+
+        let header_len = if version == 2 { 6 } else { 10 };
+        while let Some(header) = body.get(pos..pos + header_len) {
+            // The rest of the tag is padding.
+            if header[0] == 0 {
+                break;
+            }
+
+            let (id, size, format_flags) = if version == 2 {
+                let size = header[3..6]
+                    .iter()
+                    .fold(0, |size, byte| (size << 8) | usize::from(*byte));
+                let id = ID3V22_FRAME_IDS
+                    .iter()
+                    .find(|(v22_id, _)| &header[..3] == v22_id.as_ref())
+                    .map(|(_, id)| id.to_string());
+                (id, size, 0)
+            } else {
+                let size = read_u32(header, 4, version == 4).unwrap_or(0);
+                let id = std::str::from_utf8(&header[..4]).ok().map(str::to_string);
+                (id, size, header[9])
+            };
+
+            let start = pos + header_len;
+            pos = start + size;
+            let mut frame = match body.get(start..pos) {
+                Some(frame) => frame.to_vec(),
+                None => break,
+            };
+            let id = match id {
+                Some(id) => id,
+                None => continue,
+            };
+
+            // Compressed and encrypted frames are skipped.
+            let (is_skipped, is_unsynchronised, has_data_length) = match version {
+                3 => (format_flags & 0xC0 != 0, false, false),
+                4 => (
+                    format_flags & 0x0C != 0,
+                    format_flags & 0x02 != 0,
+                    format_flags & 0x01 != 0,
+                ),
+                _ => (false, false, false),
+            };
+            if is_skipped {
+                continue;
+            }
+            if is_unsynchronised {
+                frame = remove_unsynchronisation(&frame);
+            }
+            if has_data_length {
+                frame.drain(..4.min(frame.len()));
+            }
+
+            if id == "COMM" {
+                if let Some(text) = decode_comment(&frame) {
+                    self.insert(&id, text);
+                }
+            } else if id.starts_with('T') && id != "TXXX" {
+                if let Some((encoding, text)) = frame.split_first() {
+                    let text = decode_text(*encoding, text);
+                    let values: Vec<&str> =
+                        text.split('\0').filter(|value| !value.is_empty()).collect();
+                    self.insert(&id, values.join("/"));
+                }
+            }
+        }
+
+        true
+    }
+}
+
+/// Reads a big-endian 32-bit integer, which may be stored as a "sync-safe"
+/// integer with 7 bits per byte.
+fn read_u32(data: &[u8], pos: usize, sync_safe: bool) -> Option<usize> {
+    let bytes = data.get(pos..pos + 4)?;
+    let (bits, mask) = if sync_safe { (7, 0x7F) } else { (8, 0xFF) };
+    Some(
+        bytes
+            .iter()
+            .fold(0, |value, byte| (value << bits) | usize::from(byte & mask)),
+    )
+}
+
+/// Reverts the unsynchronisation scheme, which inserts a zero byte after
+/// every `0xFF` byte of the original data.
+fn remove_unsynchronisation(data: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(data.len());
+    let mut previous = 0;
+    for &byte in data {
+        if !(previous == 0xFF && byte == 0) {
+            result.push(byte);
+        }
+        previous = byte;
+    }
+    result
+}
+
+fn decode_latin1(data: &[u8]) -> String {
+    data.iter().map(|&byte| char::from(byte)).collect()
+}
+
+/// Decodes a string in one of the text encodings of ID3v2.
+fn decode_text(encoding: u8, data: &[u8]) -> String {
+    let utf16 = |data: &[u8], big_endian: bool| {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|unit| {
+                if big_endian {
+                    u16::from_be_bytes([unit[0], unit[1]])
+                } else {
+                    u16::from_le_bytes([unit[0], unit[1]])
+                }
+            })
+            .collect();
+        String::from_utf16_lossy(&units)
+    };
+
+    match encoding {
+        1 => match data {
+            [0xFF, 0xFE, rest @ ..] => utf16(rest, false),
+            [0xFE, 0xFF, rest @ ..] => utf16(rest, true),
+            _ => utf16(data, true),
+        },
+        2 => utf16(data, true),
+        3 => String::from_utf8_lossy(data).into_owned(),
+        _ => decode_latin1(data),
+    }
+}
+
+/// Decodes the text of a `COMM` frame, which follows a language code and a
+/// null-terminated description.
+fn decode_comment(frame: &[u8]) -> Option<String> {
+    let encoding = *frame.first()?;
+    let content = frame.get(4..)?;
+
+    let text_start = if encoding == 1 || encoding == 2 {
+        content
+            .chunks_exact(2)
+            .position(|unit| unit == [0, 0])
+            .map(|i| i * 2 + 2)
+    } else {
+        content.iter().position(|&byte| byte == 0).map(|i| i + 1)
+    }?;
+
+    let text = decode_text(encoding, &content[text_start..]);
+    Some(text.trim_end_matches('\0').to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id3v23_frame(id: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let size = (content.len() as u32).to_be_bytes();
+        [id.as_ref(), &size, &[0, 0], content].concat()
+    }
+
+    fn id3v2_tag(version: u8, frames: &[u8]) -> Vec<u8> {
+        let size = frames.len();
+        let size = [
+            (size >> 21) as u8 & 0x7F,
+            (size >> 14) as u8 & 0x7F,
+            (size >> 7) as u8 & 0x7F,
+            size as u8 & 0x7F,
+        ];
+        [b"ID3".as_ref(), &[version, 0, 0], &size, frames].concat()
+    }
+
+    fn id3v1_tag(title: &str, comment: &str, track: u8, genre: u8) -> Vec<u8> {
+        let mut tag = vec![0; 128];
+        tag[..3].copy_from_slice(b"TAG");
+        tag[3..3 + title.len()].copy_from_slice(title.as_bytes());
+        tag[97..97 + comment.len()].copy_from_slice(comment.as_bytes());
+        tag[126] = track;
+        tag[127] = genre;
+        tag
+    }
+
+    #[test]
+    fn parse_id3v1() {
+        let data = [vec![0xFF; 10], id3v1_tag("Song", "Nice", 3, 17)].concat();
+        let tag = Id3Tag::parse(&data).unwrap();
+        assert_eq!(tag.song_name(), Some("Song"));
+        assert_eq!(tag.comment(), Some("Nice"));
+        assert_eq!(tag.track(), Some("3"));
+        assert_eq!(tag.genre(), Some("17"));
+        assert_eq!(tag.artist(), None);
+    }
+
+    #[test]
+    fn parse_id3v23() {
+        let frames = [
+            id3v23_frame(b"TIT2", b"\x00Caf\xE9"),
+            id3v23_frame(b"TPE1", b"\x01\xFF\xFEA\x00b\x00"),
+            id3v23_frame(b"COMM", b"\x00engdesc\x00Comment"),
+            vec![0; 16],
+        ]
+        .concat();
+        let tag = Id3Tag::parse(&id3v2_tag(3, &frames)).unwrap();
+        assert_eq!(tag.song_name(), Some("Café"));
+        assert_eq!(tag.artist(), Some("Ab"));
+        assert_eq!(tag.comment(), Some("Comment"));
+        assert_eq!(
+            tag.frames().collect::<Vec<_>>(),
+            vec![("TIT2", "Café"), ("TPE1", "Ab"), ("COMM", "Comment")]
+        );
+    }
+
+    #[test]
+    fn parse_id3v22() {
+        let frames = [
+            b"TT2\x00\x00\x05\x00Song".as_ref(),
+            b"TAL\x00\x00\x03\x00LP",
+        ]
+        .concat();
+        let tag = Id3Tag::parse(&id3v2_tag(2, &frames)).unwrap();
+        assert_eq!(tag.song_name(), Some("Song"));
+        assert_eq!(tag.album(), Some("LP"));
+    }
+
+    #[test]
+    fn id3v2_takes_precedence() {
+        let frames = id3v23_frame(b"TIT2", b"\x03New");
+        let data = [
+            id3v2_tag(3, &frames),
+            vec![0xFF; 10],
+            id3v1_tag("Old", "", 0, 0xFF),
+        ]
+        .concat();
+        let tag = Id3Tag::parse(&data).unwrap();
+        assert_eq!(tag.song_name(), Some("New"));
+        assert_eq!(tag.genre(), None);
+    }
+
+    #[test]
+    fn parse_untagged() {
+        assert_eq!(Id3Tag::parse(&[0xFF; 200]), None);
+    }
+}
@@ -27,13 +27,18 @@ pub mod events;
 pub mod focus_tracker;
 mod font;
 mod html;
+mod id3;
 mod library;
 pub mod loader;
+pub mod local_connection;
 mod locale;
 pub mod matrix;
 mod player;
 mod prelude;
+pub mod security;
 pub mod shape_utils;
+pub mod socket;
+pub mod streams;
 pub mod string;
 pub mod tag_utils;
 mod transform;
@@ -1,15 +1,16 @@
 use crate::avm1::property_map::PropertyMap as Avm1PropertyMap;
 use crate::avm2::{ClassObject as Avm2ClassObject, Domain as Avm2Domain};
+use crate::backend::font::{FontBackend, FontFile};
 use crate::backend::{audio::SoundHandle, render};
 use crate::character::Character;
 use crate::display_object::{Bitmap, Graphic, MorphShape, TDisplayObject, Text};
 use crate::font::{Font, FontDescriptor};
 use crate::prelude::*;
-use crate::string::AvmString;
+use crate::string::{AvmString, WStr};
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::AvmType;
 use gc_arena::{Collect, MutationContext};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Weak};
 use swf::CharacterId;
 use weak_table::{traits::WeakElement, PtrWeakKeyHashMap, WeakValueHashMap};
@@ -350,6 +351,18 @@ pub struct Library<'gc> {
     /// The embedded device font.
     device_font: Option<Font<'gc>>,
 
+    /// System fonts loaded from the font backend to render device text, by
+    /// the name and style they were requested with. `None` records that the
+    /// backend has no font with that name.
+    system_fonts: HashMap<FontDescriptor, Option<Font<'gc>>>,
+
+    /// System fonts loaded to display characters that the requested fonts
+    /// have no glyphs for.
+    fallback_fonts: Vec<Font<'gc>>,
+
+    /// Characters that the font backend has no fallback font for.
+    missing_characters: HashSet<char>,
+
     /// A list of the symbols associated with specific AVM2 constructor
     /// prototypes.
     avm2_class_registry: Avm2ClassRegistry<'gc>,
@@ -362,6 +375,10 @@ unsafe impl<'gc> gc_arena::Collect for Library<'gc> {
             val.trace(cc);
         }
         self.device_font.trace(cc);
+        for font in self.system_fonts.values() {
+            font.trace(cc);
+        }
+        self.fallback_fonts.trace(cc);
         self.avm2_class_registry.trace(cc);
     }
 }
@@ -371,6 +388,9 @@ impl<'gc> Library<'gc> {
         Self {
             movie_libraries: PtrWeakKeyHashMap::new(),
             device_font: None,
+            system_fonts: HashMap::new(),
+            fallback_fonts: Vec::new(),
+            missing_characters: HashSet::new(),
             avm2_class_registry: Default::default(),
         }
     }
@@ -396,6 +416,92 @@ impl<'gc> Library<'gc> {
         self.device_font = font;
     }
 
+    /// Returns the system font to render device text with for a font name
+    /// and style, loading it from the font backend the first time it is
+    /// requested.
+    pub fn get_or_load_system_font(
+        &mut self,
+        name: &str,
+        is_bold: bool,
+        is_italic: bool,
+        gc_context: MutationContext<'gc, '_>,
+        renderer: &mut dyn render::RenderBackend,
+        fonts: &mut dyn FontBackend,
+    ) -> Option<Font<'gc>> {
+        let descriptor = FontDescriptor::from_parts(name, is_bold, is_italic);
+        if let Some(font) = self.system_fonts.get(&descriptor) {
+            return *font;
+        }
+
+        let font = fonts
+            .load_font(name, is_bold, is_italic)
+            .and_then(|file| Self::load_font_file(gc_context, renderer, &file));
+        self.system_fonts.insert(descriptor, font);
+        font
+    }
+
+    /// Returns a system font to display text that the requested font has no
+    /// glyphs for.
+    ///
+    /// A font that was already loaded is reused if it can display all of the
+    /// text, regardless of its style. Otherwise, the font backend is asked for
+    /// a font with the first character that no loaded font has a glyph for.
+    pub fn get_or_load_fallback_font(
+        &mut self,
+        text: &WStr,
+        is_bold: bool,
+        is_italic: bool,
+        gc_context: MutationContext<'gc, '_>,
+        renderer: &mut dyn render::RenderBackend,
+        fonts: &mut dyn FontBackend,
+    ) -> Option<Font<'gc>> {
+        if let Some(font) = self
+            .fallback_fonts
+            .iter()
+            .find(|font| font.can_display_str(text))
+        {
+            return Some(*font);
+        }
+
+        let missing = text
+            .chars()
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .find(|&c| {
+                !c.is_control()
+                    && !self.missing_characters.contains(&c)
+                    && !self
+                        .fallback_fonts
+                        .iter()
+                        .any(|font| font.get_glyph_for_char(c).is_some())
+            })?;
+
+        let font = fonts
+            .load_fallback_font(missing, is_bold, is_italic)
+            .and_then(|file| Self::load_font_file(gc_context, renderer, &file))
+            .filter(|font| font.get_glyph_for_char(missing).is_some());
+        match font {
+            Some(font) => self.fallback_fonts.push(font),
+            None => {
+                self.missing_characters.insert(missing);
+            }
+        }
+        font
+    }
+
+    fn load_font_file(
+        gc_context: MutationContext<'gc, '_>,
+        renderer: &mut dyn render::RenderBackend,
+        file: &FontFile,
+    ) -> Option<Font<'gc>> {
+        match Font::from_font_file(gc_context, renderer, file) {
+            Ok(font) => Some(font),
+            Err(e) => {
+                log::error!("Unable to load system font: {}", e);
+                None
+            }
+        }
+    }
+
     /// Get the AVM2 class registry.
     pub fn avm2_class_registry(&self) -> &Avm2ClassRegistry<'gc> {
         &self.avm2_class_registry
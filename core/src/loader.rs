@@ -1,13 +1,25 @@
 //! Management of async loaders
 
 use crate::avm1::activation::{Activation, ActivationIdentifier};
-use crate::avm1::{Avm1, Object, TObject, Value};
-use crate::avm2::{Activation as Avm2Activation, Domain as Avm2Domain};
-use crate::backend::navigator::{OwnedFuture, RequestOptions};
+use crate::avm1::{Avm1, Object, ScriptObject, SoundObject, TObject, Value};
+use crate::avm2::object::{ByteArrayObject as Avm2ByteArrayObject, LoaderStream};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, ByteArrayStorage as Avm2ByteArrayStorage,
+    Domain as Avm2Domain, Event as Avm2Event, EventData as Avm2EventData,
+    Namespace as Avm2Namespace, Object as Avm2Object, QName as Avm2QName, TObject as _,
+    Value as Avm2Value,
+};
+use crate::backend::audio::{mp3, SoundHandle, SoundInstanceHandle};
+use crate::backend::navigator::{ChunkedResponse, OwnedFuture, RequestOptions};
 use crate::backend::render::{determine_jpeg_tag_format, JpegTagFormat};
 use crate::context::{ActionQueue, ActionType, UpdateContext};
-use crate::display_object::{Bitmap, DisplayObject, TDisplayObject, TDisplayObjectContainer};
+use crate::display_object::{
+    Bitmap, DisplayObject, MovieClip, TDisplayObject, TDisplayObjectContainer,
+};
+use crate::id3::Id3Tag;
 use crate::player::{Player, NEWEST_PLAYER_VERSION};
+use crate::security::{DataAccess, PolicyFile};
+use crate::streams::NetStream;
 use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
@@ -17,11 +29,16 @@ use generational_arena::{Arena, Index};
 use std::fmt;
 use std::sync::{Arc, Mutex, Weak};
 use swf::read::read_compression_type;
+use swf::{SoundEvent, SoundInfo};
 use thiserror::Error;
 use url::form_urlencoded;
 
 pub type Handle = Index;
 
+/// The length of audio in milliseconds that has to load before a sound starts
+/// streaming. This is the default `SoundLoaderContext.bufferTime`.
+const STREAM_BUFFER_TIME: u32 = 1000;
+
 /// Enumeration of all content types that `Loader` can handle.
 ///
 /// This is a superset of `JpegTagFormat`.
@@ -93,9 +110,27 @@ pub enum Error {
     #[error("Non-load vars loader spawned as load vars loader")]
     NotLoadVarsLoader,
 
+    #[error("Non-URLLoader loader spawned as URLLoader loader")]
+    NotUrlLoader,
+
+    #[error("Non-AVM2 Loader loader spawned as AVM2 Loader loader")]
+    NotAvm2Loader,
+
+    #[error("Non-NetStream loader spawned as NetStream loader")]
+    NotNetStreamLoader,
+
+    #[error("Non-sound loader spawned as sound loader")]
+    NotSoundLoader,
+
     #[error("Could not fetch: {0}")]
     FetchError(String),
 
+    #[error("Security sandbox violation: cannot load {0}")]
+    SecurityViolation(String),
+
+    #[error("Invalid sound: {0}")]
+    InvalidSound(String),
+
     #[error("Invalid SWF")]
     InvalidSwf(#[from] crate::tag_utils::Error),
 
@@ -115,6 +150,9 @@ impl From<crate::avm1::error::Error<'_>> for Error {
 }
 
 /// Holds all in-progress loads for the player.
+///
+/// Every load is checked against the security sandbox of the movie that
+/// requested it.
 pub struct LoadManager<'gc>(Arena<Loader<'gc>>);
 
 unsafe impl<'gc> Collect for LoadManager<'gc> {
@@ -142,7 +180,12 @@ impl<'gc> LoadManager<'gc> {
             Loader::RootMovie { self_handle, .. }
             | Loader::Movie { self_handle, .. }
             | Loader::Form { self_handle, .. }
-            | Loader::LoadVars { self_handle, .. } => *self_handle = Some(handle),
+            | Loader::LoadVars { self_handle, .. }
+            | Loader::LoadUrlLoader { self_handle, .. }
+            | Loader::Avm2Loader { self_handle, .. }
+            | Loader::NetStream { self_handle, .. }
+            | Loader::Sound { self_handle, .. }
+            | Loader::Avm2Sound { self_handle, .. } => *self_handle = Some(handle),
         }
         handle
     }
@@ -183,6 +226,7 @@ impl<'gc> LoadManager<'gc> {
     pub fn load_movie_into_clip(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         target_clip: DisplayObject<'gc>,
         url: &str,
         options: RequestOptions,
@@ -197,7 +241,13 @@ impl<'gc> LoadManager<'gc> {
         };
         let handle = self.add_loader(loader);
         let loader = self.get_loader_mut(handle).unwrap();
-        loader.movie_loader(player, url.to_owned(), options, loader_url)
+        loader.movie_loader(
+            player,
+            requesting_movie,
+            url.to_owned(),
+            options,
+            loader_url,
+        )
     }
 
     /// Indicates that a movie clip has initialized (ran its first frame).
@@ -223,6 +273,7 @@ impl<'gc> LoadManager<'gc> {
     pub fn load_form_into_object(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         target_object: Object<'gc>,
         url: &str,
         options: RequestOptions,
@@ -233,7 +284,7 @@ impl<'gc> LoadManager<'gc> {
         };
         let handle = self.add_loader(loader);
         let loader = self.get_loader_mut(handle).unwrap();
-        loader.form_loader(player, url.to_owned(), options)
+        loader.form_loader(player, requesting_movie, url.to_owned(), options)
     }
 
     /// Kick off a form data load into an AVM1 object.
@@ -242,6 +293,7 @@ impl<'gc> LoadManager<'gc> {
     pub fn load_form_into_load_vars(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         target_object: Object<'gc>,
         url: &str,
         options: RequestOptions,
@@ -252,7 +304,167 @@ impl<'gc> LoadManager<'gc> {
         };
         let handle = self.add_loader(loader);
         let loader = self.get_loader_mut(handle).unwrap();
-        loader.load_vars_loader(player, url.to_owned(), options)
+        loader.load_vars_loader(player, requesting_movie, url.to_owned(), options)
+    }
+
+    /// Kick off a data load into an AVM2 `URLLoader`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_data_into_url_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        target_object: Avm2Object<'gc>,
+        url: &str,
+        options: RequestOptions,
+        data_format: DataFormat,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::LoadUrlLoader {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.url_loader(
+            player,
+            requesting_movie,
+            url.to_owned(),
+            options,
+            data_format,
+        )
+    }
+
+    /// Cancel any in-progress loads into an AVM2 `URLLoader`.
+    pub fn cancel_url_loader(&mut self, target: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::LoadUrlLoader { target_object, .. } => {
+                !Avm2Object::ptr_eq(*target_object, target)
+            }
+            _ => true,
+        });
+    }
+
+    /// Kick off a SWF or image load into an AVM2 `Loader`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_movie_into_avm2_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        target_loader: Avm2Object<'gc>,
+        content_loader_info: Avm2Object<'gc>,
+        domain: Avm2Domain<'gc>,
+        source: Avm2LoaderSource,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::Avm2Loader {
+            self_handle: None,
+            target_loader,
+            content_loader_info,
+            domain,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.avm2_loader(player, requesting_movie, source)
+    }
+
+    /// Cancel any in-progress loads into an AVM2 `Loader`.
+    pub fn cancel_avm2_loader(&mut self, target: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::Avm2Loader { target_loader, .. } => !Avm2Object::ptr_eq(*target_loader, target),
+            _ => true,
+        });
+    }
+
+    /// Kick off an FLV file load into a `NetStream`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_netstream(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        target_stream: NetStream<'gc>,
+        url: &str,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::NetStream {
+            self_handle: None,
+            target_stream,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.netstream_loader(player, requesting_movie, url.to_owned())
+    }
+
+    /// Cancel any in-progress loads into a `NetStream`.
+    pub fn cancel_netstream(&mut self, target: NetStream<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::NetStream { target_stream, .. } => !NetStream::ptr_eq(*target_stream, target),
+            _ => true,
+        });
+    }
+
+    /// Kick off an MP3 file load into an AVM1 `Sound`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_sound_into_object(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        target_object: SoundObject<'gc>,
+        url: &str,
+        is_streaming: bool,
+    ) -> OwnedFuture<(), Error> {
+        // Starting a new load abandons any previous one.
+        self.0.retain(|_, loader| match loader {
+            Loader::Sound {
+                target_object: other,
+                ..
+            } => !Object::ptr_eq((*other).into(), target_object.into()),
+            _ => true,
+        });
+
+        let loader = Loader::Sound {
+            self_handle: None,
+            target_object,
+            is_streaming,
+            stream: None,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.sound_loader(
+            player,
+            requesting_movie,
+            url.to_owned(),
+            RequestOptions::get(),
+        )
+    }
+
+    /// Kick off an MP3 file load into an AVM2 `Sound`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_sound_into_avm2_sound(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        target_object: Avm2Object<'gc>,
+        url: &str,
+        options: RequestOptions,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::Avm2Sound {
+            self_handle: None,
+            target_object,
+            streams: Vec::new(),
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.sound_loader(player, requesting_movie, url.to_owned(), options)
+    }
+
+    /// Cancel any in-progress loads into an AVM2 `Sound`.
+    pub fn cancel_avm2_sound(&mut self, target: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| match loader {
+            Loader::Avm2Sound { target_object, .. } => !Avm2Object::ptr_eq(*target_object, target),
+            _ => true,
+        });
     }
 }
 
@@ -262,6 +474,28 @@ impl<'gc> Default for LoadManager<'gc> {
     }
 }
 
+/// The format that a `URLLoader` should present its loaded data in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DataFormat {
+    /// The data is provided as a `ByteArray`.
+    Binary,
+
+    /// The data is provided as a UTF-8 decoded `String`.
+    Text,
+
+    /// The data is parsed as form variables into a `URLVariables` object.
+    Variables,
+}
+
+/// Where an AVM2 `Loader` gets the content it loads from.
+pub enum Avm2LoaderSource {
+    /// The content is fetched from a URL.
+    Url(String, RequestOptions),
+
+    /// The content is already in memory, as with `Loader.loadBytes`.
+    Bytes(Vec<u8>),
+}
+
 /// The completion status of a `Loader` loading a movie.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Collect)]
 #[collect(require_static)]
@@ -328,6 +562,80 @@ pub enum Loader<'gc> {
         /// The target AVM1 object to load form data into.
         target_object: Object<'gc>,
     },
+
+    /// Loader that is loading data into an AVM2 URLLoader object.
+    LoadUrlLoader {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The target AVM2 `URLLoader` to load data into.
+        target_object: Avm2Object<'gc>,
+    },
+
+    /// Loader that is loading a SWF or image into an AVM2 `Loader` object.
+    Avm2Loader {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The target AVM2 `Loader` to place the loaded content into.
+        target_loader: Avm2Object<'gc>,
+
+        /// The `LoaderInfo` object that load events are dispatched on.
+        content_loader_info: Avm2Object<'gc>,
+
+        /// The application domain that loaded SWF code is placed in.
+        domain: Avm2Domain<'gc>,
+    },
+
+    /// Loader that is loading an FLV file into a `NetStream`.
+    NetStream {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The target stream to play the file with.
+        target_stream: NetStream<'gc>,
+    },
+
+    /// Loader that is loading an MP3 file into an AVM1 `Sound` object.
+    Sound {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The target `Sound` to attach the loaded sound to.
+        target_object: SoundObject<'gc>,
+
+        /// Whether the sound starts playing as soon as enough of it has loaded.
+        is_streaming: bool,
+
+        /// The sound instance playing the sound while it loads, if it has started.
+        #[collect(require_static)]
+        stream: Option<SoundInstanceHandle>,
+    },
+
+    /// Loader that is loading an MP3 file into an AVM2 `Sound` object.
+    Avm2Sound {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The target `Sound` to attach the loaded sound to.
+        target_object: Avm2Object<'gc>,
+
+        /// The sound instances of `play` calls that started while the sound loads.
+        #[collect(require_static)]
+        streams: Vec<SoundInstanceHandle>,
+    },
+}
+
+/// An MP3 file that was loaded and registered with the audio backend.
+struct LoadedSound {
+    sound: SoundHandle,
+    length: u32,
+    id3: Option<Id3Tag>,
 }
 
 impl<'gc> Loader<'gc> {
@@ -390,6 +698,7 @@ impl<'gc> Loader<'gc> {
     fn movie_loader(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         url: String,
         options: RequestOptions,
         loader_url: Option<String>,
@@ -411,7 +720,7 @@ impl<'gc> Loader<'gc> {
             let url = {
                 let player_lock = player.lock().unwrap();
                 let url = player_lock.navigator().resolve_relative_url(&url);
-                fetch = player_lock.navigator().fetch(&url, options);
+                fetch = fetch_content(&player_lock, &requesting_movie, &url, options);
                 url
             };
 
@@ -509,6 +818,7 @@ impl<'gc> Loader<'gc> {
     fn form_loader(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         url: String,
         options: RequestOptions,
     ) -> OwnedFuture<(), Error> {
@@ -525,13 +835,19 @@ impl<'gc> Loader<'gc> {
             // clippy reports a false positive for explicitly dropped guards:
             // https://github.com/rust-lang/rust-clippy/issues/6446
             // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
-            let fetch;
-            {
+            let url = {
                 let player_lock = player.lock().unwrap();
-                let url = player_lock.navigator().resolve_relative_url(&url);
-                fetch = player_lock.navigator().fetch(&url, options);
+                player_lock
+                    .navigator()
+                    .resolve_relative_url(&url)
+                    .into_owned()
+            };
+
+            if !can_load_data(&player, &requesting_movie, &url).await {
+                return Err(Error::SecurityViolation(url));
             }
 
+            let fetch = player.lock().unwrap().navigator().fetch(&url, options);
             let data = fetch.await?;
 
             // Fire the load handler.
@@ -563,6 +879,7 @@ impl<'gc> Loader<'gc> {
     fn load_vars_loader(
         &mut self,
         player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
         url: String,
         options: RequestOptions,
     ) -> OwnedFuture<(), Error> {
@@ -581,14 +898,20 @@ impl<'gc> Loader<'gc> {
             // clippy reports a false positive for explicitly dropped guards:
             // https://github.com/rust-lang/rust-clippy/issues/6446
             // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
-            let fetch;
-            {
+            let url = {
                 let player_lock = player.lock().unwrap();
-                let url = player_lock.navigator().resolve_relative_url(&url);
-                fetch = player_lock.navigator().fetch(&url, options);
-            }
+                player_lock
+                    .navigator()
+                    .resolve_relative_url(&url)
+                    .into_owned()
+            };
 
-            let data = fetch.await;
+            let data = if can_load_data(&player, &requesting_movie, &url).await {
+                let fetch = player.lock().unwrap().navigator().fetch(&url, options);
+                fetch.await
+            } else {
+                Err(Error::SecurityViolation(url))
+            };
 
             // Fire the load handler.
             player.lock().unwrap().update(|uc| {
@@ -618,12 +941,17 @@ impl<'gc> Loader<'gc> {
                             &mut activation,
                         );
                     }
-                    Err(_) => {
+                    Err(e) => {
                         // TODO: Log "Error opening URL" trace similar to the Flash Player?
                         // Simulate 404 HTTP status. This should probably be fired elsewhere
                         // because a failed local load doesn't fire a 404.
-                        let _ =
-                            that.call_method("onHTTPStatus".into(), &[404.into()], &mut activation);
+                        if !matches!(e, Error::SecurityViolation(_)) {
+                            let _ = that.call_method(
+                                "onHTTPStatus".into(),
+                                &[404.into()],
+                                &mut activation,
+                            );
+                        }
 
                         // Fire the onData method with no data to indicate an unsuccessful load.
                         let _ =
@@ -636,6 +964,884 @@ impl<'gc> Loader<'gc> {
         })
     }
 
+    /// Creates a future for a `NetStream` play call.
+    fn netstream_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        url: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::NetStream { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotNetStreamLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            // clippy reports a false positive for explicitly dropped guards:
+            // https://github.com/rust-lang/rust-clippy/issues/6446
+            // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
+            let fetch;
+            {
+                let player_lock = player.lock().unwrap();
+                let url = player_lock.navigator().resolve_relative_url(&url);
+                fetch = fetch_content(&player_lock, &requesting_movie, &url, RequestOptions::get());
+            }
+
+            let data = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let stream = match uc.load_manager.get_loader(handle) {
+                    Some(&Loader::NetStream { target_stream, .. }) => target_stream,
+                    None => return Err(Error::Cancelled),
+                    _ => return Err(Error::NotNetStreamLoader),
+                };
+                uc.load_manager.0.remove(handle);
+
+                let is_loaded = match data {
+                    Ok(data) => stream.load_buffer(uc, data),
+                    Err(_) => false,
+                };
+                if !is_loaded {
+                    stream.report_error(uc);
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Creates a future for a `Sound.loadSound` or `Sound.load` call.
+    fn sound_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        url: String,
+        options: RequestOptions,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::Sound { self_handle, .. } | Loader::Avm2Sound { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotSoundLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            // clippy reports a false positive for explicitly dropped guards:
+            // https://github.com/rust-lang/rust-clippy/issues/6446
+            // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
+            let fetch;
+            let url = {
+                let player_lock = player.lock().unwrap();
+                let url = player_lock
+                    .navigator()
+                    .resolve_relative_url(&url)
+                    .into_owned();
+                fetch = fetch_content_chunked(&player_lock, &requesting_movie, &url, options);
+                url
+            };
+
+            player
+                .lock()
+                .unwrap()
+                .update(|uc| match uc.load_manager.get_loader(handle) {
+                    Some(&Loader::Avm2Sound { target_object, .. }) => {
+                        let open_evt = Avm2Event::new("open", Avm2EventData::Empty);
+                        Loader::dispatch_avm2_event(uc, open_evt, target_object);
+                        Ok(())
+                    }
+                    Some(Loader::Sound { .. }) => Ok(()),
+                    None => Err(Error::Cancelled),
+                    _ => Err(Error::NotSoundLoader),
+                })?;
+
+            let data = async {
+                let mut response = fetch.await?;
+                let bytes_total = response.expected_length().map(|len| len as u32);
+                let mut data = Vec::new();
+                while let Some(chunk) = response.next_chunk().await? {
+                    data.extend_from_slice(&chunk);
+                    player.lock().unwrap().update(|uc| {
+                        Loader::sound_loader_progress(uc, handle, &data, &chunk, bytes_total)
+                    })?;
+                }
+                Ok::<_, Error>(data)
+            }
+            .await;
+
+            player
+                .lock()
+                .unwrap()
+                .update(|uc| match uc.load_manager.0.remove(handle) {
+                    Some(Loader::Sound {
+                        target_object,
+                        is_streaming,
+                        stream,
+                        ..
+                    }) => {
+                        let loaded = Loader::register_loaded_sound(uc, &url, data);
+                        Loader::sound_loader_complete(
+                            uc,
+                            target_object,
+                            is_streaming,
+                            stream,
+                            loaded,
+                        )
+                    }
+                    Some(Loader::Avm2Sound {
+                        target_object,
+                        streams,
+                        ..
+                    }) => {
+                        let loaded = Loader::register_loaded_sound(uc, &url, data);
+                        Loader::avm2_sound_loader_complete(
+                            uc,
+                            target_object,
+                            &url,
+                            &streams,
+                            loaded,
+                        )
+                    }
+                    None => Err(Error::Cancelled),
+                    _ => Err(Error::NotSoundLoader),
+                })
+        })
+    }
+
+    /// Report the progress of a sound load, and stream the sound once enough
+    /// of it has loaded to be played.
+    fn sound_loader_progress(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        handle: Index,
+        data: &[u8],
+        chunk: &[u8],
+        bytes_total: Option<u32>,
+    ) -> Result<(), Error> {
+        let bytes_loaded = data.len() as u32;
+
+        // The MPEG frames that have loaded, once they are long enough to start
+        // playing.
+        let stream_data = || {
+            mp3::scan(data)
+                .filter(|metadata| {
+                    u64::from(metadata.num_sample_frames) * 1000
+                        >= u64::from(metadata.sample_rate) * u64::from(STREAM_BUFFER_TIME)
+                })
+                .map(|metadata| &data[metadata.frames.start..])
+        };
+
+        match uc.load_manager.get_loader(handle) {
+            Some(&Loader::Sound {
+                target_object,
+                is_streaming,
+                stream,
+                ..
+            }) => {
+                target_object.set_progress(uc.gc_context, Some(bytes_loaded), bytes_total);
+
+                if let Some(instance) = stream {
+                    uc.append_mp3_stream(instance, chunk, false);
+                } else if let Some(stream_data) = stream_data().filter(|_| is_streaming) {
+                    let instance = uc.start_mp3_stream(
+                        stream_data,
+                        target_object.owner(),
+                        Some(target_object),
+                    );
+                    target_object.set_sound_instance(uc.gc_context, instance);
+                    if let Some(Loader::Sound { stream, .. }) =
+                        uc.load_manager.get_loader_mut(handle)
+                    {
+                        *stream = instance;
+                    }
+                }
+
+                Ok(())
+            }
+            Some(Loader::Avm2Sound {
+                target_object,
+                streams,
+                ..
+            }) => {
+                let target = *target_object;
+                let sound_object = match target.as_sound_object() {
+                    Some(sound_object) => sound_object,
+                    None => return Err(Error::NotSoundLoader),
+                };
+                sound_object.set_progress(uc.gc_context, bytes_loaded, bytes_total.unwrap_or(0));
+
+                for instance in streams.clone() {
+                    uc.append_mp3_stream(instance, chunk, false);
+                }
+
+                // Plays that seek or loop wait for the whole sound to load.
+                let (simple_plays, queued_plays): (Vec<_>, Vec<_>) = sound_object
+                    .take_queued_plays(uc.gc_context)
+                    .into_iter()
+                    .partition(|play| {
+                        play.sound_info.in_sample.is_none() && play.sound_info.num_loops <= 1
+                    });
+                let mut new_streams = Vec::new();
+                match stream_data().filter(|_| !simple_plays.is_empty()) {
+                    Some(stream_data) => {
+                        for play in simple_plays {
+                            match uc.start_mp3_stream(stream_data, None, None) {
+                                Some(instance) => {
+                                    if let Some(sound_transform) = play.sound_transform {
+                                        uc.set_local_sound_transform(instance, sound_transform);
+                                    }
+                                    play.channel.set_sound_instance(uc.gc_context, instance);
+                                    uc.attach_avm2_sound_channel(instance, play.channel);
+                                    new_streams.push(instance);
+                                }
+                                None => sound_object.queue_play(uc.gc_context, play),
+                            }
+                        }
+                    }
+                    None => {
+                        for play in simple_plays {
+                            sound_object.queue_play(uc.gc_context, play);
+                        }
+                    }
+                }
+                for play in queued_plays {
+                    sound_object.queue_play(uc.gc_context, play);
+                }
+                if let Some(Loader::Avm2Sound { streams, .. }) =
+                    uc.load_manager.get_loader_mut(handle)
+                {
+                    streams.extend(new_streams);
+                }
+
+                let progress_evt = Avm2Event::new(
+                    "progress",
+                    Avm2EventData::Progress {
+                        bytes_loaded: bytes_loaded.into(),
+                        bytes_total: bytes_total.unwrap_or(0).into(),
+                    },
+                );
+                Loader::dispatch_avm2_event(uc, progress_evt, target);
+
+                Ok(())
+            }
+            None => Err(Error::Cancelled),
+            _ => Err(Error::NotSoundLoader),
+        }
+    }
+
+    /// Register a fetched MP3 file with the audio backend.
+    ///
+    /// Fails if the file couldn't be fetched or isn't an MP3 file.
+    fn register_loaded_sound(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        url: &str,
+        data: Result<Vec<u8>, Error>,
+    ) -> Result<LoadedSound, Error> {
+        let data = data?;
+        match uc.audio.register_mp3(&data) {
+            Ok(sound) => Ok(LoadedSound {
+                sound,
+                length: data.len() as u32,
+                id3: Id3Tag::parse(&data),
+            }),
+            Err(e) => {
+                log::error!("Unable to register sound loaded from {}: {}", url, e);
+                Err(Error::InvalidSound(e.to_string()))
+            }
+        }
+    }
+
+    /// Attach a loaded sound to an AVM1 `Sound` and fire its events.
+    fn sound_loader_complete(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        sound_object: SoundObject<'gc>,
+        is_streaming: bool,
+        stream: Option<SoundInstanceHandle>,
+        loaded: Result<LoadedSound, Error>,
+    ) -> Result<(), Error> {
+        if let Some(instance) = stream {
+            uc.append_mp3_stream(instance, &[], true);
+        }
+
+        let mut activation =
+            Activation::from_stub(uc.reborrow(), ActivationIdentifier::root("[Sound Loader]"));
+        let gc_context = activation.context.gc_context;
+
+        let loaded = match loaded {
+            Ok(loaded) => loaded,
+            Err(_) => {
+                let _ = sound_object.call_method("onLoad".into(), &[false.into()], &mut activation);
+                return Ok(());
+            }
+        };
+
+        let duration = activation.context.audio.get_sound_duration(loaded.sound);
+        sound_object.set_sound(gc_context, Some(loaded.sound));
+        sound_object.set_duration(gc_context, duration.map(|d| d.round() as u32));
+        sound_object.set_progress(gc_context, Some(loaded.length), Some(loaded.length));
+
+        if let Some(tag) = loaded.id3 {
+            // The ID3v2 frames are exposed by ID, and the common ones by name too.
+            let id3 =
+                ScriptObject::object(gc_context, Some(activation.context.avm1.prototypes.object));
+            let named_frames = [
+                ("songname", tag.song_name()),
+                ("artist", tag.artist()),
+                ("album", tag.album()),
+                ("year", tag.year()),
+                ("comment", tag.comment()),
+                ("genre", tag.genre()),
+                ("track", tag.track()),
+            ];
+            let frames = tag.frames().map(|(id, text)| (id, Some(text)));
+            for (name, text) in frames.chain(named_frames) {
+                if let Some(text) = text {
+                    let name = AvmString::new_utf8(gc_context, name);
+                    let text = AvmString::new_utf8(gc_context, text);
+                    id3.set(name, text.into(), &mut activation)?;
+                }
+            }
+            sound_object.set_id3(gc_context, Some(id3.into()));
+
+            let _ = sound_object.call_method("onID3".into(), &[], &mut activation);
+        }
+
+        if let Some(instance) = stream {
+            activation
+                .context
+                .set_mp3_stream_sound(instance, loaded.sound);
+        } else if is_streaming {
+            let sound_info = SoundInfo {
+                event: SoundEvent::Start,
+                in_sample: None,
+                out_sample: None,
+                num_loops: 1,
+                envelope: None,
+            };
+            let instance = activation.context.start_sound(
+                loaded.sound,
+                &sound_info,
+                sound_object.owner(),
+                Some(sound_object),
+            );
+            sound_object.set_sound_instance(gc_context, instance);
+        }
+
+        let _ = sound_object.call_method("onLoad".into(), &[true.into()], &mut activation);
+
+        Ok(())
+    }
+
+    /// Attach a loaded sound to an AVM2 `Sound` and dispatch its events.
+    ///
+    /// Any `play` calls made while the sound was loading that haven't started
+    /// streaming start here.
+    fn avm2_sound_loader_complete(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        target: Avm2Object<'gc>,
+        url: &str,
+        streams: &[SoundInstanceHandle],
+        loaded: Result<LoadedSound, Error>,
+    ) -> Result<(), Error> {
+        for &instance in streams {
+            uc.append_mp3_stream(instance, &[], true);
+        }
+
+        let sound_object = match target.as_sound_object() {
+            Some(sound_object) => sound_object,
+            None => return Err(Error::NotSoundLoader),
+        };
+        let queued_plays = sound_object.take_queued_plays(uc.gc_context);
+        sound_object.stop_loading(uc.gc_context);
+
+        let loaded = match loaded {
+            Ok(loaded) => loaded,
+            Err(Error::SecurityViolation(_)) => {
+                Loader::avm2_security_error(uc, target, url);
+                return Ok(());
+            }
+            Err(_) => {
+                let text = format!("Error #2032: Stream Error. URL: {}", url);
+                let io_error_evt = Avm2Event::new(
+                    "ioError",
+                    Avm2EventData::IOError {
+                        text: AvmString::new_utf8(uc.gc_context, text),
+                        error_id: 2032,
+                    },
+                );
+                Loader::dispatch_avm2_event(uc, io_error_evt, target);
+                return Ok(());
+            }
+        };
+
+        target.set_sound(uc.gc_context, loaded.sound);
+        sound_object.set_progress(uc.gc_context, loaded.length, loaded.length);
+        for &instance in streams {
+            uc.set_mp3_stream_sound(instance, loaded.sound);
+        }
+
+        if let Some(tag) = loaded.id3 {
+            let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+            match Loader::avm2_id3_info(&mut activation, &tag) {
+                Ok(id3) => sound_object.set_id3(uc.gc_context, Some(id3)),
+                Err(e) => log::error!("Encountered AVM2 error when storing ID3 tags: {}", e),
+            }
+
+            let id3_evt = Avm2Event::new("id3", Avm2EventData::Empty);
+            Loader::dispatch_avm2_event(uc, id3_evt, target);
+        }
+
+        for play in queued_plays {
+            if let Some(instance) = uc.start_sound(loaded.sound, &play.sound_info, None, None) {
+                if let Some(sound_transform) = play.sound_transform {
+                    uc.set_local_sound_transform(instance, sound_transform);
+                }
+                play.channel.set_sound_instance(uc.gc_context, instance);
+                uc.attach_avm2_sound_channel(instance, play.channel);
+            }
+        }
+
+        let complete_evt = Avm2Event::new("complete", Avm2EventData::Empty);
+        Loader::dispatch_avm2_event(uc, complete_evt, target);
+
+        Ok(())
+    }
+
+    /// Construct the `ID3Info` object for the ID3 tags of a loaded sound.
+    ///
+    /// Frames without a named property are set as dynamic properties.
+    fn avm2_id3_info(
+        activation: &mut Avm2Activation<'_, 'gc, '_>,
+        tag: &Id3Tag,
+    ) -> Result<Avm2Object<'gc>, crate::avm2::Error> {
+        let mut id3info = activation
+            .avm2()
+            .classes()
+            .id3info
+            .construct(activation, &[])?;
+
+        let named_frames = [
+            ("songName", tag.song_name()),
+            ("artist", tag.artist()),
+            ("album", tag.album()),
+            ("year", tag.year()),
+            ("comment", tag.comment()),
+            ("genre", tag.genre()),
+            ("track", tag.track()),
+        ];
+        let frames = tag.frames().map(|(id, text)| (id, Some(text)));
+        for (name, text) in frames.chain(named_frames) {
+            if let Some(text) = text {
+                let name = AvmString::new_utf8(activation.context.gc_context, name);
+                let text = AvmString::new_utf8(activation.context.gc_context, text);
+                id3info.set_property(
+                    &Avm2QName::new(Avm2Namespace::public(), name).into(),
+                    text.into(),
+                    activation,
+                )?;
+            }
+        }
+
+        Ok(id3info)
+    }
+
+    /// Creates a future for a `URLLoader` load call.
+    fn url_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        url: String,
+        options: RequestOptions,
+        data_format: DataFormat,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::LoadUrlLoader { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotUrlLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            // clippy reports a false positive for explicitly dropped guards:
+            // https://github.com/rust-lang/rust-clippy/issues/6446
+            // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
+            let url = {
+                let player_lock = player.lock().unwrap();
+                player_lock
+                    .navigator()
+                    .resolve_relative_url(&url)
+                    .into_owned()
+            };
+
+            let fetch = if can_load_data(&player, &requesting_movie, &url).await {
+                Some(player.lock().unwrap().navigator().fetch(&url, options))
+            } else {
+                None
+            };
+
+            player.lock().unwrap().update(|uc| -> Result<(), Error> {
+                let target = Loader::url_loader_target(handle, uc)?;
+
+                if fetch.is_some() {
+                    let open_evt = Avm2Event::new("open", Avm2EventData::Empty);
+                    Loader::dispatch_avm2_event(uc, open_evt, target);
+                } else {
+                    uc.load_manager.0.remove(handle);
+                    Loader::avm2_security_error(uc, target, &url);
+                }
+
+                Ok(())
+            })?;
+
+            let data = match fetch {
+                Some(fetch) => fetch.await,
+                None => return Ok(()),
+            };
+
+            // Fire the load handler.
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::url_loader_target(handle, uc)?;
+                uc.load_manager.0.remove(handle);
+
+                match data {
+                    Ok(data) => {
+                        let length = data.len();
+                        let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                        if let Err(e) =
+                            Loader::url_loader_set_data(&mut activation, target, data, data_format)
+                        {
+                            log::error!("Encountered AVM2 error when storing loaded data: {}", e);
+                        }
+
+                        let progress_evt = Avm2Event::new(
+                            "progress",
+                            Avm2EventData::Progress {
+                                bytes_loaded: length as u64,
+                                bytes_total: length as u64,
+                            },
+                        );
+                        Loader::dispatch_avm2_event(uc, progress_evt, target);
+
+                        let http_status_evt =
+                            Avm2Event::new("httpStatus", Avm2EventData::HttpStatus { status: 200 });
+                        Loader::dispatch_avm2_event(uc, http_status_evt, target);
+
+                        let complete_evt = Avm2Event::new("complete", Avm2EventData::Empty);
+                        Loader::dispatch_avm2_event(uc, complete_evt, target);
+                    }
+                    Err(_) => {
+                        // Simulate 404 HTTP status. This should probably be fired elsewhere
+                        // because a failed local load doesn't fire a 404.
+                        let http_status_evt =
+                            Avm2Event::new("httpStatus", Avm2EventData::HttpStatus { status: 404 });
+                        Loader::dispatch_avm2_event(uc, http_status_evt, target);
+
+                        let text = format!("Error #2032: Stream Error. URL: {}", url);
+                        let io_error_evt = Avm2Event::new(
+                            "ioError",
+                            Avm2EventData::IOError {
+                                text: AvmString::new_utf8(uc.gc_context, text),
+                                error_id: 2032,
+                            },
+                        );
+                        Loader::dispatch_avm2_event(uc, io_error_evt, target);
+                    }
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Retrieve the `URLLoader` object that a loader is loading into.
+    fn url_loader_target(
+        handle: Index,
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> Result<Avm2Object<'gc>, Error> {
+        match uc.load_manager.get_loader(handle) {
+            Some(&Loader::LoadUrlLoader { target_object, .. }) => Ok(target_object),
+            None => Err(Error::Cancelled),
+            _ => Err(Error::NotUrlLoader),
+        }
+    }
+
+    /// Dispatch a load event on an AVM2 object, logging any errors it throws.
+    fn dispatch_avm2_event(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        event: Avm2Event<'gc>,
+        target: Avm2Object<'gc>,
+    ) {
+        if let Err(e) = Avm2::dispatch_event(uc, event, target) {
+            log::error!("Encountered AVM2 error when dispatching load event: {}", e);
+        }
+    }
+
+    /// Store loaded data on a `URLLoader` in the requested format.
+    fn url_loader_set_data(
+        activation: &mut Avm2Activation<'_, 'gc, '_>,
+        mut target: Avm2Object<'gc>,
+        data: Vec<u8>,
+        data_format: DataFormat,
+    ) -> Result<(), crate::avm2::Error> {
+        let length = data.len();
+        let data_value: Avm2Value<'gc> = match data_format {
+            DataFormat::Binary => {
+                let storage = Avm2ByteArrayStorage::from_vec(data);
+                Avm2ByteArrayObject::from_storage(activation, storage)?.into()
+            }
+            DataFormat::Text => {
+                AvmString::new_utf8(activation.context.gc_context, UTF_8.decode(&data).0).into()
+            }
+            DataFormat::Variables => {
+                let string_data =
+                    AvmString::new_utf8(activation.context.gc_context, UTF_8.decode(&data).0);
+                let urlvariables = activation.avm2().classes().urlvariables;
+                urlvariables
+                    .construct(activation, &[string_data.into()])?
+                    .into()
+            }
+        };
+
+        let public = Avm2Namespace::public();
+        target.set_property(
+            &Avm2QName::new(public, "data").into(),
+            data_value,
+            activation,
+        )?;
+        target.set_property(
+            &Avm2QName::new(public, "bytesLoaded").into(),
+            length.into(),
+            activation,
+        )?;
+        target.set_property(
+            &Avm2QName::new(public, "bytesTotal").into(),
+            length.into(),
+            activation,
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates a future for an AVM2 `Loader` load call.
+    fn avm2_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        requesting_movie: Arc<SwfMovie>,
+        source: Avm2LoaderSource,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::Avm2Loader { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotAvm2Loader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            // clippy reports a false positive for explicitly dropped guards:
+            // https://github.com/rust-lang/rust-clippy/issues/6446
+            // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
+            let (url, fetch): (Option<String>, OwnedFuture<Vec<u8>, Error>) = match source {
+                Avm2LoaderSource::Url(url, options) => {
+                    let player_lock = player.lock().unwrap();
+                    let url = player_lock
+                        .navigator()
+                        .resolve_relative_url(&url)
+                        .into_owned();
+                    let fetch = fetch_content(&player_lock, &requesting_movie, &url, options);
+                    (Some(url), fetch)
+                }
+                Avm2LoaderSource::Bytes(bytes) => (None, Box::pin(async move { Ok(bytes) })),
+            };
+
+            player.lock().unwrap().update(|uc| -> Result<(), Error> {
+                let (_, loader_info, _) = Loader::avm2_loader_targets(handle, uc)?;
+
+                let open_evt = Avm2Event::new("open", Avm2EventData::Empty);
+                Loader::dispatch_avm2_event(uc, open_evt, loader_info);
+
+                Ok(())
+            })?;
+
+            let data = fetch.await;
+
+            player.lock().unwrap().update(|uc| -> Result<(), Error> {
+                let (target_loader, loader_info, domain) = Loader::avm2_loader_targets(handle, uc)?;
+                uc.load_manager.0.remove(handle);
+
+                let data = match data {
+                    Ok(data) => data,
+                    Err(Error::SecurityViolation(url)) => {
+                        Loader::avm2_security_error(uc, loader_info, &url);
+                        return Ok(());
+                    }
+                    Err(_) => {
+                        let text = format!(
+                            "Error #2035: URL Not Found. URL: {}",
+                            url.as_deref().unwrap_or_default()
+                        );
+                        Loader::avm2_loader_io_error(uc, loader_info, text, 2035);
+                        return Ok(());
+                    }
+                };
+
+                let length = data.len();
+                let (content, stream): (DisplayObject, _) = match ContentType::sniff(&data) {
+                    ContentType::Swf => {
+                        // Movies loaded from bytes share the sandbox of the movie loading them.
+                        let movie_url = url
+                            .clone()
+                            .or_else(|| requesting_movie.url().map(|url| url.to_owned()));
+                        let loader_url = uc.swf.url().map(|url| url.to_owned());
+                        let movie = Arc::new(SwfMovie::from_data(&data, movie_url, loader_url)?);
+
+                        // Movies from another sandbox never share the classes of
+                        // the movie loading them, so that their code can be told apart.
+                        let domain = if uc.security.can_access(&requesting_movie, &movie) {
+                            domain
+                        } else {
+                            let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                            let global_domain = activation.avm2().global_domain();
+                            Avm2Domain::movie_domain(&mut activation, global_domain)
+                        };
+                        uc.library
+                            .library_for_movie_mut(movie.clone())
+                            .set_avm2_domain(domain);
+
+                        let clip = MovieClip::from_movie(uc.gc_context, movie.clone());
+                        (clip.into(), LoaderStream::Swf(movie, clip.into()))
+                    }
+                    ContentType::Gif | ContentType::Jpeg | ContentType::Png => {
+                        let bitmap = uc.renderer.register_bitmap_jpeg_2(&data)?;
+                        let bitmap_obj: DisplayObject =
+                            Bitmap::new(uc, 0, bitmap.handle, bitmap.width, bitmap.height).into();
+                        let movie = Arc::new(SwfMovie::empty(uc.swf.version()));
+                        (bitmap_obj, LoaderStream::Swf(movie, bitmap_obj))
+                    }
+                    ContentType::Unknown => {
+                        let text = "Error #2124: Loaded file is an unknown type.".to_string();
+                        Loader::avm2_loader_io_error(uc, loader_info, text, 2124);
+                        return Ok(());
+                    }
+                };
+
+                if let Some(loader_info) = loader_info.as_loader_info_object() {
+                    loader_info.set_loader_stream(uc.gc_context, stream);
+                }
+
+                content.post_instantiation(uc, None, Instantiator::Movie, false);
+                if let Some(mc) = content.as_movie_clip() {
+                    mc.preload(uc);
+                }
+
+                // The content is constructed by the time `init` fires, so
+                // that `Loader.content` refers to its document class.
+                content.construct_frame(uc);
+
+                if let Some(mut container) = target_loader
+                    .as_display_object()
+                    .and_then(|dobj| dobj.as_container())
+                {
+                    container.clear(uc);
+                    container.insert_at_index(uc, content, 0);
+                }
+
+                let progress_evt = Avm2Event::new(
+                    "progress",
+                    Avm2EventData::Progress {
+                        bytes_loaded: length as u64,
+                        bytes_total: length as u64,
+                    },
+                );
+                Loader::dispatch_avm2_event(uc, progress_evt, loader_info);
+
+                let init_evt = Avm2Event::new("init", Avm2EventData::Empty);
+                Loader::dispatch_avm2_event(uc, init_evt, loader_info);
+
+                if url.is_some() {
+                    let http_status_evt =
+                        Avm2Event::new("httpStatus", Avm2EventData::HttpStatus { status: 200 });
+                    Loader::dispatch_avm2_event(uc, http_status_evt, loader_info);
+                }
+
+                let complete_evt = Avm2Event::new("complete", Avm2EventData::Empty);
+                Loader::dispatch_avm2_event(uc, complete_evt, loader_info);
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Retrieve the `Loader`, `LoaderInfo` and application domain that an
+    /// AVM2 loader is loading into.
+    fn avm2_loader_targets(
+        handle: Index,
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+    ) -> Result<(Avm2Object<'gc>, Avm2Object<'gc>, Avm2Domain<'gc>), Error> {
+        match uc.load_manager.get_loader(handle) {
+            Some(&Loader::Avm2Loader {
+                target_loader,
+                content_loader_info,
+                domain,
+                ..
+            }) => Ok((target_loader, content_loader_info, domain)),
+            None => Err(Error::Cancelled),
+            _ => Err(Error::NotAvm2Loader),
+        }
+    }
+
+    /// Report a load that the security sandbox denied to AVM2 script code.
+    fn avm2_security_error(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        target: Avm2Object<'gc>,
+        url: &str,
+    ) {
+        let text = format!(
+            "Error #2048: Security sandbox violation: cannot load data from {}.",
+            url
+        );
+        let security_error_evt = Avm2Event::new(
+            "securityError",
+            Avm2EventData::SecurityError {
+                text: AvmString::new_utf8(uc.gc_context, text),
+                error_id: 2048,
+            },
+        );
+        Loader::dispatch_avm2_event(uc, security_error_evt, target);
+    }
+
+    /// Report a failed AVM2 `Loader` load to script code.
+    fn avm2_loader_io_error(
+        uc: &mut UpdateContext<'_, 'gc, '_>,
+        loader_info: Avm2Object<'gc>,
+        text: String,
+        error_id: i32,
+    ) {
+        let io_error_evt = Avm2Event::new(
+            "ioError",
+            Avm2EventData::IOError {
+                text: AvmString::new_utf8(uc.gc_context, text),
+                error_id,
+            },
+        );
+        Loader::dispatch_avm2_event(uc, io_error_evt, loader_info);
+    }
+
     /// Report a movie loader start event to script code.
     fn movie_loader_start(handle: Index, uc: &mut UpdateContext<'_, 'gc, '_>) -> Result<(), Error> {
         let me = uc.load_manager.get_loader_mut(handle);
@@ -824,3 +2030,84 @@ impl<'gc> Loader<'gc> {
         }
     }
 }
+
+/// Fetch content, such as a movie, an image or a sound, that `movie`
+/// requested.
+///
+/// The fetch fails with `Error::SecurityViolation` if the sandbox of `movie`
+/// doesn't allow loading content from `url`.
+fn fetch_content(
+    player: &Player,
+    movie: &SwfMovie,
+    url: &str,
+    options: RequestOptions,
+) -> OwnedFuture<Vec<u8>, Error> {
+    match check_content_access(player, movie, url) {
+        Ok(()) => player.navigator().fetch(url, options),
+        Err(e) => Box::pin(async move { Err(e) }),
+    }
+}
+
+/// Like `fetch_content`, but allowing the content to be read while it arrives.
+fn fetch_content_chunked(
+    player: &Player,
+    movie: &SwfMovie,
+    url: &str,
+    options: RequestOptions,
+) -> OwnedFuture<Box<dyn ChunkedResponse>, Error> {
+    match check_content_access(player, movie, url) {
+        Ok(()) => player.navigator().fetch_chunked(url, options),
+        Err(e) => Box::pin(async move { Err(e) }),
+    }
+}
+
+/// Check whether `movie` may load content from `url`.
+fn check_content_access(player: &Player, movie: &SwfMovie, url: &str) -> Result<(), Error> {
+    if player.security().can_load_content(movie, url) {
+        return Ok(());
+    }
+
+    log::warn!(
+        "Security sandbox violation: {} cannot load content from {}",
+        movie.url().unwrap_or_default(),
+        url
+    );
+    Err(Error::SecurityViolation(url.to_owned()))
+}
+
+/// Check whether `movie` may read data from `url`, loading any cross-domain
+/// policy files that are needed to decide.
+async fn can_load_data(player: &Mutex<Player>, movie: &SwfMovie, url: &str) -> bool {
+    loop {
+        // clippy reports a false positive for explicitly dropped guards:
+        // https://github.com/rust-lang/rust-clippy/issues/6446
+        // A workaround for this is to wrap the `.lock()` call in a block instead of explicitly dropping the guard.
+        let (policy_url, fetch) = {
+            let player_lock = player.lock().unwrap();
+            match player_lock.security().data_access(movie, url) {
+                DataAccess::Allowed => return true,
+                DataAccess::Denied => break,
+                DataAccess::NeedsPolicyFile(policy_url) => {
+                    let fetch = player_lock
+                        .navigator()
+                        .fetch(&policy_url, RequestOptions::get());
+                    (policy_url, fetch)
+                }
+            }
+        };
+
+        let policy = fetch.await.ok().and_then(|data| PolicyFile::parse(&data));
+        player
+            .lock()
+            .unwrap()
+            .security_mut()
+            .add_policy_file(policy_url, policy);
+    }
+
+    log::warn!(
+        "Security sandbox violation: {} cannot load data from {}",
+        movie.url().unwrap_or_default(),
+        url
+    );
+    false
+}
@@ -0,0 +1,468 @@
+//! `LocalConnection` messaging between movies
+
+use crate::avm1::activation::{Activation as Avm1Activation, ActivationIdentifier};
+use crate::avm1::{ScriptObject as Avm1ScriptObject, TObject as _, Value as Avm1Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Event as Avm2Event, EventData as Avm2EventData, QName,
+    TObject as _, Value as Avm2Value,
+};
+use crate::context::UpdateContext;
+use crate::external::Value as ExternalValue;
+use crate::string::AvmString;
+use crate::tag_utils::SwfMovie;
+use crate::vminterface::AvmObject;
+use gc_arena::{Collect, CollectionContext};
+use generational_arena::{Arena, Index};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use url::Url;
+
+pub type LocalConnectionHandle = Index;
+
+/// Methods of `LocalConnection` itself, which can't be invoked by a sender.
+const RESERVED_METHODS: &[&str] = &[
+    "send",
+    "connect",
+    "close",
+    "allowDomain",
+    "allowInsecureDomain",
+    "client",
+    "domain",
+];
+
+/// Messages waiting to be delivered to the connections of a player.
+type Mailbox = Arc<Mutex<Vec<Delivery>>>;
+
+/// Where to report whether a message was delivered.
+struct Reply {
+    mailbox: Weak<Mutex<Vec<Delivery>>>,
+    handle: LocalConnectionHandle,
+}
+
+enum Delivery {
+    /// Invoke a method on a listening connection.
+    Call {
+        handle: LocalConnectionHandle,
+        sender_domain: String,
+        method: String,
+        args: Vec<ExternalValue>,
+        reply: Reply,
+    },
+
+    /// Report to a sender whether its message was delivered.
+    Status {
+        handle: LocalConnectionHandle,
+        is_success: bool,
+    },
+}
+
+/// A connection that is listening under a name.
+struct Listener {
+    mailbox: Weak<Mutex<Vec<Delivery>>>,
+    handle: LocalConnectionHandle,
+}
+
+/// The names that connections are listening under, shared by every player
+/// that can send messages to the others.
+///
+/// Every player starts with its own registry. Players running in the same
+/// process can talk to each other once they share one, using
+/// `Player::set_local_connections`.
+#[derive(Clone, Default)]
+pub struct LocalConnections(Arc<Mutex<HashMap<String, Listener>>>);
+
+impl LocalConnections {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// A connection that is listening for messages.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct Connection<'gc> {
+    /// The script object that receives the messages.
+    target: AvmObject<'gc>,
+
+    /// The full name that this connection listens under.
+    #[collect(require_static)]
+    name: String,
+
+    /// The domain of the movie that made this connection.
+    #[collect(require_static)]
+    domain: String,
+}
+
+/// Holds the `LocalConnection`s of a player that are listening, or that
+/// are waiting to hear whether their message was delivered.
+pub struct LocalConnectionManager<'gc> {
+    connections: Arena<Connection<'gc>>,
+
+    /// The senders of messages that have not been delivered yet.
+    senders: Arena<AvmObject<'gc>>,
+
+    mailbox: Mailbox,
+
+    registry: LocalConnections,
+}
+
+unsafe impl<'gc> Collect for LocalConnectionManager<'gc> {
+    fn trace(&self, cc: CollectionContext) {
+        for (_, connection) in self.connections.iter() {
+            connection.trace(cc);
+        }
+        for (_, sender) in self.senders.iter() {
+            sender.trace(cc);
+        }
+    }
+}
+
+impl<'gc> Default for LocalConnectionManager<'gc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'gc> LocalConnectionManager<'gc> {
+    pub fn new() -> Self {
+        Self {
+            connections: Arena::new(),
+            senders: Arena::new(),
+            mailbox: Arc::new(Mutex::new(Vec::new())),
+            registry: LocalConnections::new(),
+        }
+    }
+
+    /// Share a registry with other players.
+    ///
+    /// Connections that are already listening are moved to the new registry.
+    pub fn set_registry(&mut self, registry: LocalConnections) {
+        {
+            let mut old = self.registry.0.lock().unwrap();
+            let mut new = registry.0.lock().unwrap();
+            for (_, connection) in self.connections.iter() {
+                if let Some(listener) = old.remove(&connection.name) {
+                    new.insert(connection.name.clone(), listener);
+                }
+            }
+        }
+        self.registry = registry;
+    }
+
+    pub fn registry(&self) -> &LocalConnections {
+        &self.registry
+    }
+
+    /// Start listening for messages sent to `name`.
+    ///
+    /// Names starting with an underscore are shared by every domain; other
+    /// names are only reachable as `domain:name`. Returns `None` if another
+    /// connection is already listening under that name.
+    pub fn connect(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        domain: &str,
+        name: &str,
+    ) -> Option<LocalConnectionHandle> {
+        let name = if name.starts_with('_') {
+            name.to_lowercase()
+        } else {
+            format!("{}:{}", domain, name).to_lowercase()
+        };
+
+        let manager = &mut *context.local_connection_manager;
+        let mut registry = manager.registry.0.lock().unwrap();
+        if let Some(listener) = registry.get(&name) {
+            if listener.mailbox.upgrade().is_some() {
+                return None;
+            }
+        }
+
+        let handle = manager.connections.insert(Connection {
+            target,
+            name: name.clone(),
+            domain: domain.to_string(),
+        });
+        registry.insert(
+            name,
+            Listener {
+                mailbox: Arc::downgrade(&manager.mailbox),
+                handle,
+            },
+        );
+        Some(handle)
+    }
+
+    /// Stop listening for messages.
+    pub fn close(context: &mut UpdateContext<'_, 'gc, '_>, handle: LocalConnectionHandle) {
+        let manager = &mut *context.local_connection_manager;
+        if let Some(connection) = manager.connections.remove(handle) {
+            let mut registry = manager.registry.0.lock().unwrap();
+            let is_ours = registry.get(&connection.name).map_or(false, |listener| {
+                listener.handle == handle
+                    && listener.mailbox.ptr_eq(&Arc::downgrade(&manager.mailbox))
+            });
+            if is_ours {
+                registry.remove(&connection.name);
+            }
+        }
+    }
+
+    /// Send a message that invokes `method` on the connection listening
+    /// under `name`.
+    ///
+    /// Names that neither start with an underscore nor contain a domain are
+    /// sent to a connection of the sender's own domain. The message is
+    /// delivered on the next update of the receiving player, and `sender`
+    /// then hears whether it was.
+    pub fn send(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        sender: AvmObject<'gc>,
+        sender_domain: &str,
+        name: &str,
+        method: &str,
+        args: Vec<ExternalValue>,
+    ) {
+        let name = if name.starts_with('_') || name.contains(':') {
+            name.to_lowercase()
+        } else {
+            format!("{}:{}", sender_domain, name).to_lowercase()
+        };
+
+        let manager = &mut *context.local_connection_manager;
+        let handle = manager.senders.insert(sender);
+        let reply = Reply {
+            mailbox: Arc::downgrade(&manager.mailbox),
+            handle,
+        };
+
+        let listener = manager
+            .registry
+            .0
+            .lock()
+            .unwrap()
+            .get(&name)
+            .and_then(|listener| Some((listener.mailbox.upgrade()?, listener.handle)));
+        match listener {
+            Some((mailbox, listener)) if !RESERVED_METHODS.contains(&method) => {
+                mailbox.lock().unwrap().push(Delivery::Call {
+                    handle: listener,
+                    sender_domain: sender_domain.to_string(),
+                    method: method.to_string(),
+                    args,
+                    reply,
+                });
+            }
+            _ => {
+                manager.mailbox.lock().unwrap().push(Delivery::Status {
+                    handle,
+                    is_success: false,
+                });
+            }
+        }
+    }
+
+    /// Deliver the messages that were sent to the connections of this player,
+    /// and report to senders whether their messages were delivered.
+    pub fn update_local_connections(context: &mut UpdateContext<'_, 'gc, '_>) {
+        let deliveries =
+            std::mem::take(&mut *context.local_connection_manager.mailbox.lock().unwrap());
+        for delivery in deliveries {
+            match delivery {
+                Delivery::Call {
+                    handle,
+                    sender_domain,
+                    method,
+                    args,
+                    reply,
+                } => {
+                    let connection = context
+                        .local_connection_manager
+                        .connections
+                        .get(handle)
+                        .map(|connection| (connection.target, connection.domain.clone()));
+                    let is_success = match connection {
+                        Some((target, domain)) => {
+                            if Self::is_domain_allowed(context, target, &domain, &sender_domain) {
+                                Self::invoke(context, target, &method, args);
+                                true
+                            } else {
+                                false
+                            }
+                        }
+                        None => false,
+                    };
+                    if let Some(mailbox) = reply.mailbox.upgrade() {
+                        mailbox.lock().unwrap().push(Delivery::Status {
+                            handle: reply.handle,
+                            is_success,
+                        });
+                    }
+                }
+                Delivery::Status { handle, is_success } => {
+                    if let Some(sender) = context.local_connection_manager.senders.remove(handle) {
+                        Self::status_event(context, sender, is_success);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether a connection accepts messages from movies of `sender_domain`.
+    ///
+    /// Movies of the connection's own domain may always send to it. In AVM1,
+    /// others are accepted if the connection's `allowDomain` callback returns
+    /// `true`; in AVM2, if the domain was passed to `allowDomain`.
+    fn is_domain_allowed(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        domain: &str,
+        sender_domain: &str,
+    ) -> bool {
+        if domain.eq_ignore_ascii_case(sender_domain) {
+            return true;
+        }
+
+        match target {
+            AvmObject::Avm1(object) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    ActivationIdentifier::root("[LocalConnection]"),
+                );
+                if !object.has_property(&mut activation, "allowDomain".into()) {
+                    return false;
+                }
+                let sender_domain =
+                    AvmString::new_utf8(activation.context.gc_context, sender_domain);
+                match object.call_method(
+                    "allowDomain".into(),
+                    &[sender_domain.into()],
+                    &mut activation,
+                ) {
+                    Ok(allowed) => allowed.as_bool(activation.swf_version()),
+                    Err(e) => {
+                        log::error!("Got error when calling LocalConnection.allowDomain: {}", e);
+                        false
+                    }
+                }
+            }
+            AvmObject::Avm2(object) => object.as_local_connection().map_or(false, |connection| {
+                connection.is_domain_allowed(sender_domain)
+            }),
+        }
+    }
+
+    /// Invoke the method that a message was sent to.
+    fn invoke(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        method: &str,
+        args: Vec<ExternalValue>,
+    ) {
+        match target {
+            AvmObject::Avm1(object) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    ActivationIdentifier::root("[LocalConnection]"),
+                );
+                let args: Vec<Avm1Value<'gc>> = args
+                    .into_iter()
+                    .map(|arg| arg.into_avm1(&mut activation))
+                    .collect();
+                let method = AvmString::new_utf8(activation.context.gc_context, method);
+                if let Err(e) = object.call_method(method, &args, &mut activation) {
+                    log::error!("Got error when calling LocalConnection.{}: {}", method, e);
+                }
+            }
+            AvmObject::Avm2(object) => {
+                let client = object
+                    .as_local_connection()
+                    .and_then(|connection| connection.client())
+                    .unwrap_or(object);
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                let args: Vec<Avm2Value<'gc>> = args
+                    .into_iter()
+                    .map(|arg| arg.into_avm2(&mut activation))
+                    .collect();
+                let method = AvmString::new_utf8(activation.context.gc_context, method);
+                if let Err(e) = client.call_property(
+                    &QName::dynamic_name(method).into(),
+                    &args,
+                    &mut activation,
+                ) {
+                    log::error!(
+                        "Got error when calling LocalConnection client.{}: {}",
+                        method,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Report to a sender whether its message was delivered.
+    fn status_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        sender: AvmObject<'gc>,
+        is_success: bool,
+    ) {
+        let level = if is_success { "status" } else { "error" };
+        match sender {
+            AvmObject::Avm1(object) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    ActivationIdentifier::root("[LocalConnection]"),
+                );
+                let info = Avm1ScriptObject::object(
+                    activation.context.gc_context,
+                    Some(activation.context.avm1.prototypes().object),
+                );
+                let _ = info.set("level", level.into(), &mut activation);
+                if let Err(e) =
+                    object.call_method("onStatus".into(), &[info.into()], &mut activation)
+                {
+                    log::error!("Got error when calling LocalConnection.onStatus: {}", e);
+                }
+            }
+            AvmObject::Avm2(object) => {
+                let event = Avm2Event::new(
+                    "status",
+                    Avm2EventData::Status {
+                        code: None,
+                        level: level.into(),
+                    },
+                );
+                if let Err(e) = Avm2::dispatch_event(context, event, object) {
+                    log::error!("Got error when dispatching LocalConnection status: {}", e);
+                }
+            }
+        }
+    }
+}
+
+/// The domain of a movie, as reported by `LocalConnection.domain`.
+///
+/// Movies loaded from the local file system are in the `localhost` domain.
+/// Before SWF 7, the domain is the superdomain of the movie's host.
+pub fn movie_domain(movie: &SwfMovie) -> String {
+    let host = movie
+        .url()
+        .and_then(|url| Url::parse(url).ok())
+        .filter(|url| url.scheme() != "file")
+        .and_then(|url| url.host_str().map(str::to_lowercase));
+    let host = match host {
+        Some(host) => host,
+        None => return "localhost".to_string(),
+    };
+
+    if movie.version() >= 7 || host.parse::<std::net::IpAddr>().is_ok() {
+        return host;
+    }
+
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() > 2 {
+        labels[labels.len() - 2..].join(".")
+    } else {
+        host
+    }
+}
@@ -7,9 +7,11 @@ use crate::avm1::{Avm1, ScriptObject, TObject, Timers, Value};
 use crate::avm2::{Activation as Avm2Activation, Avm2, Domain as Avm2Domain};
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
+    font::FontBackend,
     log::LogBackend,
     navigator::{NavigatorBackend, RequestOptions},
     render::RenderBackend,
+    socket::SocketBackend,
     storage::StorageBackend,
     ui::{InputManager, MouseCursor, UiBackend},
     video::VideoBackend,
@@ -27,7 +29,11 @@ use crate::external::{ExternalInterface, ExternalInterfaceProvider};
 use crate::focus_tracker::FocusTracker;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::local_connection::{LocalConnectionManager, LocalConnections};
 use crate::prelude::*;
+use crate::security::Security;
+use crate::socket::SocketManager;
+use crate::streams::StreamManager;
 use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
 use crate::transform::TransformStack;
@@ -102,6 +108,15 @@ struct GcRootData<'gc> {
 
     /// Manager of active sound instances.
     audio_manager: AudioManager<'gc>,
+
+    /// Manager of playing `NetStream`s.
+    stream_manager: StreamManager<'gc>,
+
+    /// Manager of connecting and connected sockets.
+    socket_manager: SocketManager<'gc>,
+
+    /// Manager of listening `LocalConnection`s and of undelivered messages.
+    local_connection_manager: LocalConnectionManager<'gc>,
 }
 
 impl<'gc> GcRootData<'gc> {
@@ -124,6 +139,9 @@ impl<'gc> GcRootData<'gc> {
         &mut Option<ContextMenuState<'gc>>,
         &mut ExternalInterface<'gc>,
         &mut AudioManager<'gc>,
+        &mut StreamManager<'gc>,
+        &mut SocketManager<'gc>,
+        &mut LocalConnectionManager<'gc>,
     ) {
         (
             self.stage,
@@ -139,6 +157,9 @@ impl<'gc> GcRootData<'gc> {
             &mut self.current_context_menu,
             &mut self.external_interface,
             &mut self.audio_manager,
+            &mut self.stream_manager,
+            &mut self.socket_manager,
+            &mut self.local_connection_manager,
         )
     }
 }
@@ -153,6 +174,8 @@ type Storage = Box<dyn StorageBackend>;
 type Log = Box<dyn LogBackend>;
 type Ui = Box<dyn UiBackend>;
 type Video = Box<dyn VideoBackend>;
+type Fonts = Box<dyn FontBackend>;
+type Sockets = Box<dyn SocketBackend>;
 
 pub struct Player {
     /// The version of the player we're emulating.
@@ -181,6 +204,8 @@ pub struct Player {
     log: Log,
     ui: Ui,
     video: Video,
+    fonts: Fonts,
+    sockets: Sockets,
 
     transform_stack: TransformStack,
 
@@ -210,6 +235,8 @@ pub struct Player {
 
     system: SystemProperties,
 
+    security: Security,
+
     /// The current instance ID. Used to generate default `instanceN` names.
     instance_counter: i32,
 
@@ -236,6 +263,7 @@ pub struct Player {
 }
 
 impl Player {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         renderer: Renderer,
         audio: Audio,
@@ -244,6 +272,8 @@ impl Player {
         video: Video,
         log: Log,
         ui: Ui,
+        fonts: Fonts,
+        sockets: Sockets,
     ) -> Result<Arc<Mutex<Self>>, Error> {
         let fake_movie = Arc::new(SwfMovie::empty(NEWEST_PLAYER_VERSION));
         let movie_width = 550;
@@ -286,6 +316,9 @@ impl Player {
                         external_interface: ExternalInterface::new(),
                         focus_tracker: FocusTracker::new(gc_context),
                         audio_manager: AudioManager::new(),
+                        stream_manager: StreamManager::new(),
+                        socket_manager: SocketManager::new(),
+                        local_connection_manager: LocalConnectionManager::new(),
                     },
                 ))
             }),
@@ -307,8 +340,11 @@ impl Player {
             log,
             ui,
             video,
+            fonts,
+            sockets,
             self_reference: None,
             system: SystemProperties::default(),
+            security: Security::default(),
             instance_counter: 0,
             time_til_next_timer: None,
             storage,
@@ -551,6 +587,9 @@ impl Player {
             });
 
             self.update_timers(dt);
+            self.update_streams(dt);
+            self.update_sockets();
+            self.update_local_connections();
             self.audio.tick();
         }
     }
@@ -963,6 +1002,21 @@ impl Player {
                 }
             }
 
+            // Ctrl+V pastes into the focused text field.
+            if let PlayerEvent::KeyDown {
+                key_code: KeyCode::V,
+                ..
+            } = event
+            {
+                if context.input.is_key_down(KeyCode::Control)
+                    && !context.input.is_key_down(KeyCode::Alt)
+                {
+                    if let Some(text) = context.focus_tracker.get().and_then(|o| o.as_edit_text()) {
+                        text.paste(context);
+                    }
+                }
+            }
+
             // Propagate clip events.
             let (clip_event, listener) = match event {
                 PlayerEvent::KeyDown { .. } => {
@@ -1376,6 +1430,7 @@ impl Player {
                 stage: root_data.stage,
                 clip_depth_stack: vec![],
                 allow_mask: true,
+                is_offscreen: false,
             };
 
             root_data.stage.render(&mut render_context);
@@ -1402,6 +1457,14 @@ impl Player {
         &self.navigator
     }
 
+    pub fn security(&self) -> &Security {
+        &self.security
+    }
+
+    pub fn security_mut(&mut self) -> &mut Security {
+        &mut self.security
+    }
+
     // The frame rate of the current movie in FPS.
     pub fn frame_rate(&self) -> f64 {
         self.frame_rate
@@ -1423,6 +1486,10 @@ impl Player {
         &mut self.storage
     }
 
+    pub fn sockets_mut(&mut self) -> &mut Sockets {
+        &mut self.sockets
+    }
+
     pub fn destroy(self) -> Renderer {
         self.renderer
     }
@@ -1581,6 +1648,9 @@ impl Player {
                 current_context_menu,
                 external_interface,
                 audio_manager,
+                stream_manager,
+                socket_manager,
+                local_connection_manager,
             ) = root_data.update_context_params();
 
             let mut update_context = UpdateContext {
@@ -1603,10 +1673,15 @@ impl Player {
                 player: self.self_reference.clone(),
                 load_manager,
                 system: &mut self.system,
+                security: &mut self.security,
                 instance_counter: &mut self.instance_counter,
                 storage: self.storage.deref_mut(),
                 log: self.log.deref_mut(),
                 video: self.video.deref_mut(),
+                fonts: self.fonts.deref_mut(),
+                sockets: self.sockets.deref_mut(),
+                socket_manager,
+                local_connection_manager,
                 shared_objects,
                 unbound_text_fields,
                 timers,
@@ -1622,6 +1697,7 @@ impl Player {
                 times_get_time_called: 0,
                 time_offset: &mut self.time_offset,
                 audio_manager,
+                stream_manager,
                 frame_rate: &mut self.frame_rate,
             };
 
@@ -1710,6 +1786,8 @@ impl Player {
             for so in shared_objects.values() {
                 let _ = crate::avm1::globals::shared_object::flush(&mut activation, *so, &[]);
             }
+
+            Avm2::flush_shared_objects(&mut activation.context);
         });
     }
 
@@ -1720,6 +1798,38 @@ impl Player {
             self.mutate_with_update_context(|context| Timers::update_timers(context, dt));
     }
 
+    /// Advance every playing `NetStream` by `dt` milliseconds.
+    pub fn update_streams(&mut self, dt: f64) {
+        self.mutate_with_update_context(|context| StreamManager::tick(context, dt));
+    }
+
+    /// Deliver the events of every socket to script.
+    pub fn update_sockets(&mut self) {
+        self.mutate_with_update_context(|context| SocketManager::update_sockets(context));
+    }
+
+    /// Deliver the messages sent to every `LocalConnection` of this player.
+    pub fn update_local_connections(&mut self) {
+        self.mutate_with_update_context(|context| {
+            LocalConnectionManager::update_local_connections(context)
+        });
+    }
+
+    /// The registry of `LocalConnection` names that this player uses.
+    pub fn local_connections(&mut self) -> LocalConnections {
+        self.mutate_with_update_context(|context| {
+            context.local_connection_manager.registry().clone()
+        })
+    }
+
+    /// Share a registry of `LocalConnection` names with other players, so
+    /// that their movies can send messages to each other.
+    pub fn set_local_connections(&mut self, registry: LocalConnections) {
+        self.mutate_with_update_context(|context| {
+            context.local_connection_manager.set_registry(registry)
+        });
+    }
+
     /// Returns whether this player consumes mouse wheel events.
     /// Used by web to prevent scrolling.
     pub fn should_prevent_scrolling(&mut self) -> bool {
@@ -0,0 +1,506 @@
+//! The security sandbox that movies run in.
+//!
+//! Every movie is placed in a sandbox based on where it was loaded from. The
+//! sandbox decides which URLs the movie may load content and data from, and
+//! which other movies it may access. Reading data from another domain also
+//! requires that domain to grant access in a cross-domain policy file.
+
+use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
+use crate::tag_utils::SwfMovie;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use url::Url;
+
+/// The type of sandbox that a movie runs in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxType {
+    /// A movie loaded from the network.
+    Remote,
+
+    /// A local movie that may load local files, but may not use the network.
+    LocalWithFile,
+
+    /// A local movie that may use the network, but may not load local files.
+    LocalWithNetwork,
+
+    /// A local movie that may load anything.
+    LocalTrusted,
+}
+
+impl fmt::Display for SandboxType {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            SandboxType::Remote => "remote",
+            SandboxType::LocalWithFile => "localWithFile",
+            SandboxType::LocalWithNetwork => "localWithNetwork",
+            SandboxType::LocalTrusted => "localTrusted",
+        })
+    }
+}
+
+impl SandboxType {
+    /// Whether movies in this sandbox may load anything from `url`.
+    fn can_load_from(self, url: &Url) -> bool {
+        match url.scheme() {
+            "file" => matches!(self, Self::LocalWithFile | Self::LocalTrusted),
+            "http" | "https" => self != Self::LocalWithFile,
+            "data" => true,
+            _ => false,
+        }
+    }
+}
+
+/// Whether a movie may read data from a URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataAccess {
+    Allowed,
+    Denied,
+
+    /// Access depends on the policy file at this URL, which hasn't been
+    /// loaded yet.
+    NeedsPolicyFile(String),
+}
+
+/// Which policy files a server permits, as set by the `site-control`
+/// element of its master policy file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetaPolicy {
+    /// No policy files are permitted, not even the master policy file.
+    None,
+
+    /// Only the master policy file at `/crossdomain.xml` is permitted.
+    MasterOnly,
+
+    /// Any policy file on the server is permitted.
+    All,
+}
+
+/// A domain that a policy file grants access to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AllowAccessFrom {
+    /// The domain pattern, such as `example.com`, `*.example.com` or `*`.
+    domain: String,
+
+    /// Whether movies that weren't loaded over HTTPS are denied access to
+    /// data served over HTTPS.
+    secure: bool,
+}
+
+/// A cross-domain policy file, which grants movies from other domains
+/// access to the data of the server it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyFile {
+    meta_policy: MetaPolicy,
+    allow_access_from: Vec<AllowAccessFrom>,
+}
+
+impl PolicyFile {
+    /// Parse a policy file, returning `None` if `data` isn't one.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let mut reader = Reader::from_reader(data);
+        let mut buf = Vec::new();
+        let mut depth = 0;
+        let mut is_policy_file = false;
+        let mut policy = Self {
+            meta_policy: MetaPolicy::MasterOnly,
+            allow_access_from: Vec::new(),
+        };
+
+        loop {
+            match reader.read_event(&mut buf).ok()? {
+                Event::Start(element) => {
+                    is_policy_file |= depth == 0 && element.name() == b"cross-domain-policy";
+                    if depth == 1 && is_policy_file {
+                        policy.read_element(&element)?;
+                    }
+                    depth += 1;
+                }
+                Event::Empty(element) => {
+                    is_policy_file |= depth == 0 && element.name() == b"cross-domain-policy";
+                    if depth == 1 && is_policy_file {
+                        policy.read_element(&element)?;
+                    }
+                }
+                Event::End(_) => depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        if is_policy_file {
+            Some(policy)
+        } else {
+            None
+        }
+    }
+
+    /// Read a child element of the `cross-domain-policy` element.
+    fn read_element(&mut self, element: &BytesStart<'_>) -> Option<()> {
+        let attribute = |name: &[u8]| -> Option<Option<String>> {
+            for attribute in element.attributes() {
+                let attribute = attribute.ok()?;
+                if attribute.key == name {
+                    let value = attribute.unescaped_value().ok()?;
+                    return Some(Some(String::from_utf8_lossy(&value).trim().to_lowercase()));
+                }
+            }
+            Some(None)
+        };
+
+        match element.name() {
+            b"site-control" => {
+                let meta_policy = attribute(b"permitted-cross-domain-policies")?;
+                self.meta_policy = match meta_policy.as_deref() {
+                    Some("none") => MetaPolicy::None,
+                    Some("master-only") | None => MetaPolicy::MasterOnly,
+                    Some(_) => MetaPolicy::All,
+                };
+            }
+            b"allow-access-from" => {
+                if let Some(domain) = attribute(b"domain")? {
+                    let secure = attribute(b"secure")?.as_deref() != Some("false");
+                    self.allow_access_from
+                        .push(AllowAccessFrom { domain, secure });
+                }
+            }
+            _ => {}
+        }
+
+        Some(())
+    }
+
+    /// Whether this policy file, served from `url`, grants access to movies
+    /// loaded from `movie_url`.
+    fn allows(&self, url: &Url, movie_url: Option<&Url>) -> bool {
+        let host = movie_url.and_then(Url::host_str);
+        let is_insecure = url.scheme() == "https" && movie_url.map(Url::scheme) != Some("https");
+        self.allow_access_from
+            .iter()
+            .any(|rule| domain_matches(&rule.domain, host) && !(rule.secure && is_insecure))
+    }
+}
+
+/// Whether a domain pattern, such as `example.com`, `*.example.com` or `*`,
+/// matches a host. Local movies have no host and only match `*`.
+fn domain_matches(pattern: &str, host: Option<&str>) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+
+    let host = match host {
+        Some(host) => host.to_lowercase(),
+        None => return false,
+    };
+    match pattern.strip_prefix("*.") {
+        Some(suffix) => {
+            host == suffix
+                || host
+                    .strip_suffix(suffix)
+                    .map_or(false, |subdomain| subdomain.ends_with('.'))
+        }
+        None => host == pattern,
+    }
+}
+
+/// Parse a URL that `movie` requested.
+///
+/// URLs that the navigator left relative, such as those of players without
+/// a base URL, are relative to the movie itself.
+fn resolve_url(movie: &SwfMovie, url: &str) -> Option<Url> {
+    match Url::parse(url) {
+        Ok(url) => Some(url),
+        Err(url::ParseError::RelativeUrlWithoutBase) => movie
+            .url()
+            .and_then(|movie_url| Url::parse(movie_url).ok())
+            .and_then(|movie_url| movie_url.join(url).ok()),
+        Err(_) => None,
+    }
+}
+
+/// A domain that a movie granted access to with `Security.allowDomain`.
+#[derive(Debug, Clone)]
+struct AllowedDomain {
+    domain: String,
+
+    /// Whether movies that weren't loaded over HTTPS are granted access too,
+    /// as with `Security.allowInsecureDomain`.
+    allow_insecure: bool,
+}
+
+/// The security state of a player, shared by all of its movies.
+#[derive(Debug, Default)]
+pub struct Security {
+    /// Whether local movies are placed in the local-trusted sandbox.
+    /// Local content is untrusted unless the player opts in.
+    trust_local_content: bool,
+
+    /// Every policy file that has been loaded, by URL.
+    /// This is `None` for policy files that failed to load.
+    policy_files: HashMap<String, Option<PolicyFile>>,
+
+    /// Policy files other than `/crossdomain.xml` that movies asked to be
+    /// consulted with `Security.loadPolicyFile`.
+    custom_policy_files: Vec<Url>,
+
+    /// The domains that movies granted access to themselves, by movie URL.
+    allowed_domains: HashMap<String, Vec<AllowedDomain>>,
+}
+
+impl Security {
+    /// Set whether local movies are placed in the local-trusted sandbox.
+    ///
+    /// Untrusted local movies are placed in the local-with-network or
+    /// local-with-file sandbox, depending on their `FileAttributes`.
+    pub fn set_trust_local_content(&mut self, trust_local_content: bool) {
+        self.trust_local_content = trust_local_content;
+    }
+
+    /// The sandbox that `movie` runs in.
+    ///
+    /// Movies without a URL, such as those given to the player as raw data,
+    /// are treated as remote content of an unknown origin.
+    pub fn sandbox_type(&self, movie: &SwfMovie) -> SandboxType {
+        let is_local = movie
+            .url()
+            .and_then(|url| Url::parse(url).ok())
+            .map_or(false, |url| url.scheme() == "file");
+
+        if !is_local {
+            SandboxType::Remote
+        } else if self.trust_local_content {
+            SandboxType::LocalTrusted
+        } else if movie.header().use_network_sandbox() {
+            SandboxType::LocalWithNetwork
+        } else {
+            SandboxType::LocalWithFile
+        }
+    }
+
+    /// Whether `movie` may load content, such as other movies, images or
+    /// sounds, from `url`.
+    ///
+    /// Unlike reading data, loading content from another domain doesn't
+    /// require a policy file.
+    pub fn can_load_content(&self, movie: &SwfMovie, url: &str) -> bool {
+        resolve_url(movie, url).map_or(false, |url| self.sandbox_type(movie).can_load_from(&url))
+    }
+
+    /// Whether `movie` may read data from `url`.
+    pub fn data_access(&self, movie: &SwfMovie, url: &str) -> DataAccess {
+        let url = match resolve_url(movie, url) {
+            Some(url) => url,
+            None => return DataAccess::Denied,
+        };
+
+        let sandbox_type = self.sandbox_type(movie);
+        if !sandbox_type.can_load_from(&url) {
+            return DataAccess::Denied;
+        }
+
+        // Only network data from another domain needs a policy file.
+        let movie_url = movie.url().and_then(|url| Url::parse(url).ok());
+        let is_network = matches!(url.scheme(), "http" | "https");
+        let is_same_origin = sandbox_type == SandboxType::Remote
+            && movie_url.as_ref().map(Url::origin) == Some(url.origin());
+        if sandbox_type == SandboxType::LocalTrusted || !is_network || is_same_origin {
+            return DataAccess::Allowed;
+        }
+
+        self.policy_file_access(&url, movie_url.as_ref())
+    }
+
+    /// Whether the policy files of the server at `url` grant access to
+    /// movies loaded from `movie_url`.
+    fn policy_file_access(&self, url: &Url, movie_url: Option<&Url>) -> DataAccess {
+        let master_url = match url.join("/crossdomain.xml") {
+            Ok(master_url) => master_url,
+            Err(_) => return DataAccess::Denied,
+        };
+        let master = match self.policy_files.get(master_url.as_str()) {
+            Some(master) => master.as_ref(),
+            None => return DataAccess::NeedsPolicyFile(master_url.into()),
+        };
+
+        // Without a master policy file, no other policy file is permitted.
+        let meta_policy = master.map_or(MetaPolicy::MasterOnly, |master| master.meta_policy);
+        if meta_policy == MetaPolicy::None {
+            return DataAccess::Denied;
+        }
+        if master.map_or(false, |master| master.allows(url, movie_url)) {
+            return DataAccess::Allowed;
+        }
+        if meta_policy == MetaPolicy::MasterOnly {
+            return DataAccess::Denied;
+        }
+
+        // Other policy files only apply to their own directory.
+        for policy_url in &self.custom_policy_files {
+            let path = policy_url.path();
+            let directory = &path[..path.rfind('/').map_or(0, |slash| slash + 1)];
+            if policy_url.origin() != url.origin() || !url.path().starts_with(directory) {
+                continue;
+            }
+
+            match self.policy_files.get(policy_url.as_str()) {
+                Some(Some(policy)) if policy.allows(url, movie_url) => return DataAccess::Allowed,
+                Some(_) => {}
+                None => return DataAccess::NeedsPolicyFile(policy_url.to_string()),
+            }
+        }
+
+        DataAccess::Denied
+    }
+
+    /// Store a policy file that was loaded from `url`, or `None` if it
+    /// failed to load.
+    pub fn add_policy_file(&mut self, url: String, policy: Option<PolicyFile>) {
+        self.policy_files.insert(url, policy);
+    }
+
+    /// Consult the policy file at `url` for data loads from its server, as
+    /// with `Security.loadPolicyFile`.
+    pub fn load_policy_file(&mut self, url: &str) {
+        if let Ok(url) = Url::parse(url) {
+            let is_network = matches!(url.scheme(), "http" | "https");
+            if is_network && !self.custom_policy_files.contains(&url) {
+                self.custom_policy_files.push(url);
+            }
+        }
+    }
+
+    /// Grant movies from `domain` access to `movie`, as with
+    /// `Security.allowDomain` and `Security.allowInsecureDomain`.
+    ///
+    /// `domain` may also be the URL of a movie, or `*` for every domain.
+    pub fn allow_domain(&mut self, movie: &SwfMovie, domain: &str, allow_insecure: bool) {
+        let domain = match Url::parse(domain) {
+            Ok(url) if url.has_host() => url.host_str().unwrap_or_default().to_lowercase(),
+            _ => domain.trim().to_lowercase(),
+        };
+
+        self.allowed_domains
+            .entry(movie.url().unwrap_or_default().to_owned())
+            .or_default()
+            .push(AllowedDomain {
+                domain,
+                allow_insecure,
+            });
+    }
+
+    /// Whether `movie` may access the content of `other`, such as its
+    /// scripts or its graphics.
+    pub fn can_access(&self, movie: &SwfMovie, other: &SwfMovie) -> bool {
+        let sandbox_type = self.sandbox_type(movie);
+        if sandbox_type == SandboxType::LocalTrusted || std::ptr::eq(movie, other) {
+            return true;
+        }
+
+        // Local movies may access each other as long as they share a sandbox.
+        let movie_url = movie.url().and_then(|url| Url::parse(url).ok());
+        let other_url = other.url().and_then(|url| Url::parse(url).ok());
+        let is_same_origin = match (&movie_url, &other_url) {
+            (Some(movie_url), Some(other_url)) => movie_url.origin() == other_url.origin(),
+            _ => false,
+        };
+        if sandbox_type == self.sandbox_type(other)
+            && (sandbox_type != SandboxType::Remote || is_same_origin)
+        {
+            return true;
+        }
+
+        let host = movie_url.as_ref().and_then(Url::host_str);
+        let is_insecure = other_url.as_ref().map(Url::scheme) == Some("https")
+            && movie_url.as_ref().map(Url::scheme) != Some("https");
+        self.allowed_domains
+            .get(other.url().unwrap_or_default())
+            .map_or(false, |allowed_domains| {
+                allowed_domains.iter().any(|allowed| {
+                    domain_matches(&allowed.domain, host)
+                        && (allowed.allow_insecure || !is_insecure)
+                })
+            })
+    }
+
+    /// Find a movie in `object` or its descendants that `movie` may not
+    /// access, such as when drawing `object` into a `BitmapData`.
+    pub fn find_inaccessible_movie<'gc>(
+        &self,
+        movie: &SwfMovie,
+        object: DisplayObject<'gc>,
+    ) -> Option<Arc<SwfMovie>> {
+        if let Some(other) = object.movie() {
+            if !self.can_access(movie, &other) {
+                return Some(other);
+            }
+        }
+
+        object.as_container().and_then(|container| {
+            container
+                .iter_render_list()
+                .find_map(|child| self.find_inaccessible_movie(movie, child))
+        })
+    }
+
+    /// Whether `movie` may call into its host, such as JavaScript on the
+    /// page it is embedded in, with `ExternalInterface`.
+    ///
+    /// The host is assumed to share the sandbox of the root movie, as with
+    /// the default `allowScriptAccess` of `sameDomain`.
+    pub fn can_use_external_interface(&self, movie: &SwfMovie, root: &SwfMovie) -> bool {
+        self.sandbox_type(movie) != SandboxType::LocalWithFile && self.can_access(movie, root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POLICY_FILE: &[u8] = br#"<?xml version="1.0"?>
+<!DOCTYPE cross-domain-policy SYSTEM "http://www.adobe.com/xml/dtds/cross-domain-policy.dtd">
+<cross-domain-policy>
+    <site-control permitted-cross-domain-policies="master-only"/>
+    <allow-access-from domain="*.example.com"/>
+    <allow-access-from domain="other.org" secure="false"/>
+</cross-domain-policy>"#;
+
+    fn url(url: &str) -> Url {
+        Url::parse(url).unwrap()
+    }
+
+    #[test]
+    fn parse_policy_file() {
+        let policy = PolicyFile::parse(POLICY_FILE).unwrap();
+        assert_eq!(policy.meta_policy, MetaPolicy::MasterOnly);
+        assert_eq!(policy.allow_access_from.len(), 2);
+
+        assert_eq!(PolicyFile::parse(b"<html><body/></html>"), None);
+        assert_eq!(PolicyFile::parse(b"not a policy file"), None);
+    }
+
+    #[test]
+    fn policy_file_domains() {
+        let policy = PolicyFile::parse(POLICY_FILE).unwrap();
+        let data = url("http://data.net/file.txt");
+        let allows = |movie_url: &str| policy.allows(&data, Some(&url(movie_url)));
+        assert!(allows("http://example.com/movie.swf"));
+        assert!(allows("http://www.example.com/movie.swf"));
+        assert!(allows("http://other.org/movie.swf"));
+        assert!(!allows("http://www.other.org/movie.swf"));
+        assert!(!allows("http://notexample.com/movie.swf"));
+        assert!(!policy.allows(&data, None));
+    }
+
+    #[test]
+    fn secure_policy_file() {
+        let policy = PolicyFile::parse(POLICY_FILE).unwrap();
+        let data = url("https://data.net/file.txt");
+        let allows = |movie_url: &str| policy.allows(&data, Some(&url(movie_url)));
+        assert!(allows("https://www.example.com/movie.swf"));
+        assert!(!allows("http://www.example.com/movie.swf"));
+        assert!(allows("http://other.org/movie.swf"));
+    }
+}
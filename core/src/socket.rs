@@ -0,0 +1,311 @@
+//! Network connections of `XMLSocket` and `flash.net.Socket`
+
+use crate::avm1::activation::{Activation as Avm1Activation, ActivationIdentifier};
+use crate::avm1::{Object as Avm1Object, TObject as _, Value as Avm1Value};
+use crate::avm2::{
+    Avm2, Event as Avm2Event, EventData as Avm2EventData, Object as Avm2Object, TObject as _,
+};
+use crate::backend::socket::{SocketEvent, SocketHandle};
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use gc_arena::{Collect, CollectionContext};
+use generational_arena::Arena;
+use url::Url;
+
+/// How the data of a socket is delivered to script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Collect)]
+#[collect(require_static)]
+pub enum SocketKind {
+    /// An `XMLSocket`, which exchanges null-terminated strings.
+    Xml,
+
+    /// A `flash.net.Socket`, which exchanges raw bytes.
+    Binary,
+}
+
+/// A socket that is connecting or connected.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct Socket<'gc> {
+    /// The script object that receives the events of this socket.
+    target: AvmObject<'gc>,
+
+    kind: SocketKind,
+
+    is_connected: bool,
+
+    /// The start of an `XMLSocket` message that has not been terminated yet.
+    partial_message: Vec<u8>,
+}
+
+/// Holds every socket that is connecting or connected.
+pub struct SocketManager<'gc>(Arena<Socket<'gc>>);
+
+unsafe impl<'gc> Collect for SocketManager<'gc> {
+    fn trace(&self, cc: CollectionContext) {
+        for (_, socket) in self.0.iter() {
+            socket.trace(cc)
+        }
+    }
+}
+
+impl<'gc> Default for SocketManager<'gc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'gc> SocketManager<'gc> {
+    pub fn new() -> Self {
+        Self(Arena::new())
+    }
+
+    /// Start connecting a socket to `host` on `port`.
+    ///
+    /// If no host is given, the socket connects to the host that the movie
+    /// was loaded from. The events of the socket are delivered to `target`,
+    /// starting with a connect event once the attempt finishes.
+    pub fn connect(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        kind: SocketKind,
+        host: Option<&str>,
+        port: u16,
+    ) -> SocketHandle {
+        let host = match host {
+            Some(host) => host.to_string(),
+            None => context
+                .swf
+                .url()
+                .and_then(|url| Url::parse(url).ok())
+                .and_then(|url| url.host_str().map(str::to_string))
+                .unwrap_or_else(|| "localhost".to_string()),
+        };
+
+        let handle = context.socket_manager.0.insert(Socket {
+            target,
+            kind,
+            is_connected: false,
+            partial_message: Vec::new(),
+        });
+        context.sockets.connect(handle, &host, port);
+        handle
+    }
+
+    /// Whether the socket with this handle is connected.
+    pub fn is_connected(&self, handle: SocketHandle) -> bool {
+        self.0
+            .get(handle)
+            .map(|socket| socket.is_connected)
+            .unwrap_or(false)
+    }
+
+    /// Send data over a connected socket.
+    ///
+    /// Returns `false` if the socket isn't connected.
+    pub fn send(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        handle: SocketHandle,
+        data: Vec<u8>,
+    ) -> bool {
+        if !context.socket_manager.is_connected(handle) {
+            return false;
+        }
+
+        context.sockets.send(handle, data);
+        true
+    }
+
+    /// Close a socket at the request of script.
+    ///
+    /// No close event is delivered for a socket that script closed itself.
+    pub fn close(context: &mut UpdateContext<'_, 'gc, '_>, handle: SocketHandle) {
+        if context.socket_manager.0.remove(handle).is_some() {
+            context.sockets.close(handle);
+        }
+    }
+
+    /// Deliver the events of every socket to script.
+    pub fn update_sockets(context: &mut UpdateContext<'_, 'gc, '_>) {
+        Self::flush_avm2_sockets(context);
+
+        for event in context.sockets.poll() {
+            match event {
+                SocketEvent::Connect { handle, is_success } => {
+                    let target = match context.socket_manager.0.get_mut(handle) {
+                        Some(socket) => {
+                            socket.is_connected = is_success;
+                            socket.target
+                        }
+                        None => continue,
+                    };
+                    if !is_success {
+                        context.socket_manager.0.remove(handle);
+                    }
+                    Self::connect_event(context, target, is_success);
+                }
+                SocketEvent::Data { handle, data } => {
+                    let socket = match context.socket_manager.0.get_mut(handle) {
+                        Some(socket) => socket,
+                        None => continue,
+                    };
+                    let target = socket.target;
+                    let kind = socket.kind;
+                    match kind {
+                        SocketKind::Xml => {
+                            socket.partial_message.extend(data);
+                            let mut messages = Vec::new();
+                            while let Some(end) =
+                                socket.partial_message.iter().position(|b| *b == 0)
+                            {
+                                let message: Vec<u8> =
+                                    socket.partial_message.drain(..=end).collect();
+                                messages.push(message);
+                            }
+                            for message in messages {
+                                let message =
+                                    String::from_utf8_lossy(&message[..message.len() - 1]);
+                                Self::xml_data_event(context, target, &message);
+                            }
+                        }
+                        SocketKind::Binary => Self::binary_data_event(context, target, &data),
+                    }
+                }
+                SocketEvent::Close { handle } => {
+                    if let Some(socket) = context.socket_manager.0.remove(handle) {
+                        Self::close_event(context, socket.target);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Send the data that was written to `flash.net.Socket`s but not flushed.
+    ///
+    /// Flash Player flushes every socket once per frame.
+    fn flush_avm2_sockets(context: &mut UpdateContext<'_, 'gc, '_>) {
+        let sockets: Vec<(SocketHandle, Avm2Object<'gc>)> = context
+            .socket_manager
+            .0
+            .iter()
+            .filter_map(|(handle, socket)| match (socket.kind, socket.target) {
+                (SocketKind::Binary, AvmObject::Avm2(object)) if socket.is_connected => {
+                    Some((handle, object))
+                }
+                _ => None,
+            })
+            .collect();
+        for (handle, object) in sockets {
+            let data = object
+                .as_socket_mut(context.gc_context)
+                .map(|mut socket| socket.take_output())
+                .unwrap_or_default();
+            if !data.is_empty() {
+                context.sockets.send(handle, data);
+            }
+        }
+    }
+
+    fn connect_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        is_success: bool,
+    ) {
+        match target {
+            AvmObject::Avm1(object) => {
+                Self::call_avm1_handler(context, object, "onConnect", &[is_success.into()]);
+            }
+            AvmObject::Avm2(object) => {
+                let event = if is_success {
+                    Avm2Event::new("connect", Avm2EventData::Empty)
+                } else {
+                    let text =
+                        AvmString::new_utf8(context.gc_context, "Error #2031: Socket Error.");
+                    Avm2Event::new(
+                        "ioError",
+                        Avm2EventData::IOError {
+                            text,
+                            error_id: 2031,
+                        },
+                    )
+                };
+                Self::dispatch_avm2_event(context, object, event);
+            }
+        }
+    }
+
+    fn xml_data_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        message: &str,
+    ) {
+        let message = AvmString::new_utf8(context.gc_context, message);
+        match target {
+            AvmObject::Avm1(object) => {
+                Self::call_avm1_handler(context, object, "onData", &[message.into()]);
+            }
+            AvmObject::Avm2(object) => {
+                let event = Avm2Event::new("data", Avm2EventData::Data { data: message });
+                Self::dispatch_avm2_event(context, object, event);
+            }
+        }
+    }
+
+    fn binary_data_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        target: AvmObject<'gc>,
+        data: &[u8],
+    ) {
+        if let AvmObject::Avm2(object) = target {
+            if let Some(mut socket) = object.as_socket_mut(context.gc_context) {
+                socket.receive(data);
+            }
+            let event = Avm2Event::new(
+                "socketData",
+                Avm2EventData::Progress {
+                    bytes_loaded: data.len() as u64,
+                    bytes_total: 0,
+                },
+            );
+            Self::dispatch_avm2_event(context, object, event);
+        }
+    }
+
+    fn close_event(context: &mut UpdateContext<'_, 'gc, '_>, target: AvmObject<'gc>) {
+        match target {
+            AvmObject::Avm1(object) => {
+                Self::call_avm1_handler(context, object, "onClose", &[]);
+            }
+            AvmObject::Avm2(object) => {
+                let event = Avm2Event::new("close", Avm2EventData::Empty);
+                Self::dispatch_avm2_event(context, object, event);
+            }
+        }
+    }
+
+    fn call_avm1_handler(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        object: Avm1Object<'gc>,
+        name: &'static str,
+        args: &[Avm1Value<'gc>],
+    ) {
+        let mut activation = Avm1Activation::from_stub(
+            context.reborrow(),
+            ActivationIdentifier::root("[XMLSocket]"),
+        );
+        if let Err(e) = object.call_method(name.into(), args, &mut activation) {
+            log::error!("Got error when calling XMLSocket.{}: {}", name, e);
+        }
+    }
+
+    fn dispatch_avm2_event(
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        object: Avm2Object<'gc>,
+        event: Avm2Event<'gc>,
+    ) {
+        if let Err(e) = Avm2::dispatch_event(context, event, object) {
+            log::error!("Got error when dispatching socket event: {}", e);
+        }
+    }
+}
@@ -0,0 +1,843 @@
+//! Playback of external FLV files with `NetStream`
+
+use crate::avm1::activation::{Activation as Avm1Activation, ActivationIdentifier};
+use crate::avm1::object::date_object::DateObject as Avm1DateObject;
+use crate::avm1::property::Attribute;
+use crate::avm1::{
+    ArrayObject as Avm1ArrayObject, Object as Avm1Object, ScriptObject as Avm1ScriptObject,
+    TObject as _, Value as Avm1Value,
+};
+use crate::avm2::object::ArrayObject as Avm2ArrayObject;
+use crate::avm2::{
+    Activation as Avm2Activation, ArrayStorage as Avm2ArrayStorage, Avm2, Error as Avm2Error,
+    Event as Avm2Event, EventData as Avm2EventData, Object as Avm2Object, QName as Avm2QName,
+    TObject as _, Value as Avm2Value,
+};
+use crate::backend::audio::{SoundHandle, SoundInstanceHandle};
+use crate::backend::render::BitmapInfo;
+use crate::backend::video::{EncodedFrame, VideoStreamHandle};
+use crate::context::UpdateContext;
+use crate::display_object::{DisplayObject, TDisplayObject, Video};
+use crate::string::AvmString;
+use crate::tag_utils::SwfMovie;
+use crate::vminterface::AvmObject;
+use chrono::{LocalResult, TimeZone, Utc};
+use gc_arena::{Collect, GcCell, MutationContext};
+use std::fmt;
+use std::sync::Arc;
+use swf::flv;
+
+/// Manages the playback of every `NetStream`.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct StreamManager<'gc> {
+    /// The streams that are currently playing, which are advanced on every
+    /// tick.
+    playing_streams: Vec<NetStream<'gc>>,
+}
+
+impl<'gc> StreamManager<'gc> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Advance every playing stream by `dt` milliseconds.
+    pub fn tick(context: &mut UpdateContext<'_, 'gc, '_>, dt: f64) {
+        let streams = context.stream_manager.playing_streams.clone();
+        for stream in streams {
+            stream.tick(context, dt);
+        }
+    }
+
+    fn set_playing(&mut self, stream: NetStream<'gc>, is_playing: bool) {
+        let index = self
+            .playing_streams
+            .iter()
+            .position(|other| NetStream::ptr_eq(*other, stream));
+        match (index, is_playing) {
+            (None, true) => self.playing_streams.push(stream),
+            (Some(index), false) => {
+                self.playing_streams.swap_remove(index);
+            }
+            _ => (),
+        }
+    }
+}
+
+/// A stream of audio and video, which is played from an FLV file.
+///
+/// The whole file is loaded before playback begins. Video frames are decoded
+/// as the playhead reaches them and shown by any `Video` that the stream is
+/// attached to, while the audio of the file is played as a single sound.
+#[derive(Clone, Copy, Collect)]
+#[collect(no_drop)]
+pub struct NetStream<'gc>(GcCell<'gc, NetStreamData<'gc>>);
+
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct NetStreamData<'gc> {
+    /// The FLV file being played, once it has loaded.
+    #[collect(require_static)]
+    buffer: Vec<u8>,
+
+    /// Whether `buffer` holds the file that was last asked to be played.
+    is_loaded: bool,
+
+    /// The video and script tags of the file, in the order that they play.
+    #[collect(require_static)]
+    tags: Vec<TagInfo>,
+
+    /// The index of the next tag to play.
+    next_tag: usize,
+
+    /// The time of the last tag of the file, in milliseconds.
+    duration: f64,
+
+    /// The position of the playhead, in milliseconds.
+    time: f64,
+
+    /// How many seconds of the stream should be buffered before it plays.
+    buffer_time: f64,
+
+    /// Whether playback was paused by script.
+    is_paused: bool,
+
+    /// The decoder that video frames are decoded with.
+    #[collect(require_static)]
+    video_stream: Option<VideoStreamHandle>,
+
+    /// The most recently decoded video frame.
+    #[collect(require_static)]
+    last_frame: Option<BitmapInfo>,
+
+    /// The audio of the whole file.
+    #[collect(require_static)]
+    sound: Option<SoundHandle>,
+
+    /// The currently playing instance of `sound`.
+    #[collect(require_static)]
+    sound_instance: Option<SoundInstanceHandle>,
+
+    /// The videos that show the frames of this stream.
+    attached_videos: Vec<Video<'gc>>,
+
+    /// The VM object of this stream.
+    avm_object: Option<AvmObject<'gc>>,
+
+    /// The movie that created this stream. Files are loaded in its sandbox.
+    #[collect(require_static)]
+    movie: Option<Arc<SwfMovie>>,
+
+    /// The object that AVM2 script data callbacks, such as `onMetaData`, are
+    /// called on. When this isn't set, they are called on the stream itself.
+    avm2_client: Option<Avm2Object<'gc>>,
+}
+
+/// The position of a tag within an FLV file.
+#[derive(Clone, Copy, Debug)]
+struct TagInfo {
+    /// The time that the tag plays at, in milliseconds.
+    timestamp: u32,
+
+    /// The offset of the tag within the file.
+    offset: usize,
+
+    /// Whether the tag is a video frame that playback can be seeked to.
+    is_keyframe: bool,
+}
+
+impl fmt::Debug for NetStream<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NetStream")
+            .field("ptr", &self.0.as_ptr())
+            .finish()
+    }
+}
+
+impl<'gc> NetStream<'gc> {
+    /// The default number of seconds that a stream buffers before it plays.
+    pub const DEFAULT_BUFFER_TIME: f64 = 0.1;
+
+    pub fn new(gc_context: MutationContext<'gc, '_>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            NetStreamData {
+                buffer: Vec::new(),
+                is_loaded: false,
+                tags: Vec::new(),
+                next_tag: 0,
+                duration: 0.0,
+                time: 0.0,
+                buffer_time: Self::DEFAULT_BUFFER_TIME,
+                is_paused: false,
+                video_stream: None,
+                last_frame: None,
+                sound: None,
+                sound_instance: None,
+                attached_videos: Vec::new(),
+                avm_object: None,
+                movie: None,
+                avm2_client: None,
+            },
+        ))
+    }
+
+    pub fn ptr_eq(a: NetStream<'gc>, b: NetStream<'gc>) -> bool {
+        GcCell::ptr_eq(a.0, b.0)
+    }
+
+    pub fn set_avm_object(self, gc_context: MutationContext<'gc, '_>, object: AvmObject<'gc>) {
+        self.0.write(gc_context).avm_object = Some(object);
+    }
+
+    pub fn set_movie(self, gc_context: MutationContext<'gc, '_>, movie: Arc<SwfMovie>) {
+        self.0.write(gc_context).movie = Some(movie);
+    }
+
+    pub fn avm2_client(self) -> Option<Avm2Object<'gc>> {
+        self.0.read().avm2_client
+    }
+
+    pub fn set_avm2_client(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        client: Option<Avm2Object<'gc>>,
+    ) {
+        self.0.write(gc_context).avm2_client = client;
+    }
+
+    /// The position of the playhead, in seconds.
+    pub fn time(self) -> f64 {
+        self.0.read().time / 1000.0
+    }
+
+    pub fn buffer_time(self) -> f64 {
+        self.0.read().buffer_time
+    }
+
+    pub fn set_buffer_time(self, gc_context: MutationContext<'gc, '_>, buffer_time: f64) {
+        self.0.write(gc_context).buffer_time = buffer_time.max(0.0);
+    }
+
+    /// The number of seconds of the stream that are buffered ahead of the
+    /// playhead.
+    pub fn buffer_length(self) -> f64 {
+        let read = self.0.read();
+        ((read.duration - read.time) / 1000.0).max(0.0)
+    }
+
+    pub fn bytes_loaded(self) -> usize {
+        self.0.read().buffer.len()
+    }
+
+    pub fn bytes_total(self) -> usize {
+        self.0.read().buffer.len()
+    }
+
+    /// The most recently decoded video frame.
+    pub fn last_frame(self) -> Option<BitmapInfo> {
+        self.0.read().last_frame
+    }
+
+    /// Show the frames of this stream in a video.
+    pub fn attach_video(self, gc_context: MutationContext<'gc, '_>, video: Video<'gc>) {
+        let mut write = self.0.write(gc_context);
+        if !write
+            .attached_videos
+            .iter()
+            .any(|other| DisplayObject::ptr_eq((*other).into(), video.into()))
+        {
+            write.attached_videos.push(video);
+        }
+    }
+
+    pub fn detach_video(self, gc_context: MutationContext<'gc, '_>, video: Video<'gc>) {
+        self.0
+            .write(gc_context)
+            .attached_videos
+            .retain(|other| !DisplayObject::ptr_eq((*other).into(), video.into()));
+    }
+
+    /// Start loading and playing an FLV file.
+    pub fn play(self, context: &mut UpdateContext<'_, 'gc, '_>, url: &str) {
+        self.close(context);
+        self.0.write(context.gc_context).is_paused = false;
+
+        if let Some(player) = context.player.clone() {
+            let movie = self
+                .0
+                .read()
+                .movie
+                .clone()
+                .unwrap_or_else(|| context.swf.clone());
+            let future = context
+                .load_manager
+                .load_netstream(player, movie, self, url);
+            context.navigator.spawn_future(future);
+        }
+    }
+
+    /// Start playing a loaded FLV file.
+    ///
+    /// Returns `false` if the data is not an FLV file.
+    pub fn load_buffer(self, context: &mut UpdateContext<'_, 'gc, '_>, buffer: Vec<u8>) -> bool {
+        let mut reader = match flv::Reader::new(&buffer) {
+            Ok(reader) => reader,
+            Err(e) => {
+                log::warn!("Unable to play stream: {}", e);
+                return false;
+            }
+        };
+
+        let mut tags = Vec::new();
+        let mut metadata = None;
+        let mut video_codec = None;
+        let mut audio_format = None;
+        let mut audio_data = Vec::new();
+        let mut audio_end = 0;
+        let mut duration = 0;
+        loop {
+            let offset = reader.position();
+            let tag = match reader.read_tag() {
+                Ok(Some(tag)) => tag,
+                Ok(None) => break,
+                Err(e) => {
+                    log::warn!("Skipping invalid FLV tag: {}", e);
+                    continue;
+                }
+            };
+
+            duration = duration.max(tag.timestamp);
+            let tag_info = TagInfo {
+                timestamp: tag.timestamp,
+                offset,
+                is_keyframe: false,
+            };
+            match tag.data {
+                flv::TagData::Video(video) => {
+                    video_codec.get_or_insert(video.codec);
+                    tags.push(TagInfo {
+                        is_keyframe: video.frame_type.is_keyframe(),
+                        ..tag_info
+                    });
+                }
+                flv::TagData::Audio(audio) => {
+                    let format = audio_format.get_or_insert_with(|| audio.format.clone());
+                    if *format == audio.format {
+                        audio_data.extend_from_slice(audio.data);
+                        audio_end = tag.timestamp;
+                    }
+                }
+                flv::TagData::Script(script) if script.name == "onMetaData" => {
+                    metadata.get_or_insert(script.value);
+                }
+                flv::TagData::Script(_) => tags.push(tag_info),
+                flv::TagData::Unknown { tag_type, .. } => {
+                    log::warn!("Unsupported FLV tag of type {}", tag_type)
+                }
+            }
+        }
+
+        let size = metadata
+            .as_ref()
+            .and_then(|metadata| {
+                let width = metadata.get("width")?.as_number()?;
+                let height = metadata.get("height")?.as_number()?;
+                Some((width as u16, height as u16))
+            })
+            .or_else(|| {
+                let read = self.0.read();
+                let video = read.attached_videos.first()?;
+                let bounds = video.self_bounds();
+                Some((
+                    bounds.width().to_pixels() as u16,
+                    bounds.height().to_pixels() as u16,
+                ))
+            })
+            .unwrap_or((320, 240));
+        let video_stream = video_codec.and_then(|codec| {
+            match context.video.register_video_stream(
+                tags.len() as u32,
+                size,
+                codec,
+                swf::VideoDeblocking::UseVideoPacketValue,
+            ) {
+                Ok(stream) => Some(stream),
+                Err(e) => {
+                    log::error!("Unable to decode stream video: {}", e);
+                    None
+                }
+            }
+        });
+
+        let sound = audio_format.and_then(|format| {
+            if format.compression == swf::AudioCompression::Adpcm {
+                // Each ADPCM packet starts over, so they can't be joined together.
+                log::warn!("Unsupported ADPCM stream audio");
+                return None;
+            }
+            if format.compression == swf::AudioCompression::Mp3 {
+                // MP3 sounds begin with the number of samples to skip.
+                audio_data.splice(0..0, [0, 0]);
+            }
+            // Allow for the length of the last packet of audio.
+            let num_samples = (f64::from(audio_end.saturating_add(1000)) / 1000.0
+                * f64::from(format.sample_rate)) as u32;
+            let sound = swf::Sound {
+                id: 0,
+                format,
+                num_samples,
+                data: &audio_data,
+            };
+            match context.audio.register_sound(&sound) {
+                Ok(sound) => Some(sound),
+                Err(e) => {
+                    log::error!("Unable to play stream audio: {}", e);
+                    None
+                }
+            }
+        });
+
+        let mut write = self.0.write(context.gc_context);
+        write.buffer = buffer;
+        write.is_loaded = true;
+        write.tags = tags;
+        write.next_tag = 0;
+        write.duration = f64::from(duration);
+        write.time = 0.0;
+        write.video_stream = video_stream;
+        write.sound = sound;
+        let is_paused = write.is_paused;
+        drop(write);
+
+        self.trigger_status_event(context, "NetStream.Play.Start", "status");
+        if let Some(metadata) = metadata {
+            self.call_script_callback(context, "onMetaData", &metadata);
+        }
+        self.run_tags(context);
+        if !is_paused {
+            self.start_playing(context);
+        }
+
+        true
+    }
+
+    /// Report that the file to be played could not be loaded.
+    pub fn report_error(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        self.trigger_status_event(context, "NetStream.Play.StreamNotFound", "error");
+    }
+
+    /// Pause or resume playback, or toggle it if `is_paused` is `None`.
+    pub fn pause(self, context: &mut UpdateContext<'_, 'gc, '_>, is_paused: Option<bool>) {
+        let mut write = self.0.write(context.gc_context);
+        let is_paused = is_paused.unwrap_or(!write.is_paused);
+        if write.is_paused == is_paused {
+            return;
+        }
+        write.is_paused = is_paused;
+        let is_loaded = write.is_loaded;
+        drop(write);
+
+        if is_paused {
+            self.stop_playing(context);
+            self.trigger_status_event(context, "NetStream.Pause.Notify", "status");
+        } else {
+            if is_loaded {
+                self.start_playing(context);
+            }
+            self.trigger_status_event(context, "NetStream.Unpause.Notify", "status");
+        }
+    }
+
+    /// Move the playhead to the keyframe closest to, and no later than, a
+    /// time in seconds.
+    pub fn seek(self, context: &mut UpdateContext<'_, 'gc, '_>, offset: f64) {
+        let mut write = self.0.write(context.gc_context);
+        if !write.is_loaded {
+            return;
+        }
+
+        let offset = (offset * 1000.0).max(0.0).min(write.duration);
+        let keyframe = write
+            .tags
+            .iter()
+            .rposition(|tag| tag.is_keyframe && f64::from(tag.timestamp) <= offset);
+        if let Some(keyframe) = keyframe {
+            write.next_tag = keyframe;
+            write.time = f64::from(write.tags[keyframe].timestamp);
+        } else {
+            // Streams without video can be seeked to any time.
+            write.next_tag = write
+                .tags
+                .iter()
+                .position(|tag| f64::from(tag.timestamp) >= offset)
+                .unwrap_or(write.tags.len());
+            write.time = offset;
+        }
+        let is_paused = write.is_paused;
+        drop(write);
+
+        self.stop_playing(context);
+        self.run_tags(context);
+        if !is_paused {
+            self.start_playing(context);
+        }
+        self.trigger_status_event(context, "NetStream.Seek.Notify", "status");
+    }
+
+    /// Stop playback and discard the loaded file.
+    pub fn close(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        self.stop_playing(context);
+        context.load_manager.cancel_netstream(self);
+
+        let mut write = self.0.write(context.gc_context);
+        write.buffer = Vec::new();
+        write.is_loaded = false;
+        write.tags = Vec::new();
+        write.next_tag = 0;
+        write.duration = 0.0;
+        write.time = 0.0;
+        write.video_stream = None;
+        write.sound = None;
+    }
+
+    fn start_playing(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        context.stream_manager.set_playing(self, true);
+
+        let read = self.0.read();
+        let (sound, time) = (read.sound, read.time);
+        drop(read);
+
+        if let Some(sound) = sound {
+            let settings = swf::SoundInfo {
+                event: swf::SoundEvent::Event,
+                // Sound positions are in 44.1KHz samples.
+                in_sample: Some((time / 1000.0 * 44100.0) as u32),
+                out_sample: None,
+                num_loops: 1,
+                envelope: None,
+            };
+            let instance =
+                context
+                    .audio_manager
+                    .start_sound(context.audio, sound, &settings, None, None);
+            self.0.write(context.gc_context).sound_instance = instance;
+        }
+    }
+
+    fn stop_playing(self, context: &mut UpdateContext<'_, 'gc, '_>) {
+        context.stream_manager.set_playing(self, false);
+
+        if let Some(instance) = self.0.write(context.gc_context).sound_instance.take() {
+            context.audio_manager.stop_sound(context.audio, instance);
+        }
+    }
+
+    /// Advance the playhead by `dt` milliseconds.
+    fn tick(self, context: &mut UpdateContext<'_, 'gc, '_>, dt: f64) {
+        self.0.write(context.gc_context).time += dt;
+
+        if self.run_tags(context) {
+            self.stop_playing(context);
+            self.trigger_status_event(context, "NetStream.Play.Stop", "status");
+        }
+    }
+
+    /// Play every tag up to the playhead.
+    ///
+    /// Returns `true` once the end of the stream is reached.
+    fn run_tags(self, context: &mut UpdateContext<'_, 'gc, '_>) -> bool {
+        loop {
+            let mut write = self.0.write(context.gc_context);
+            let index = write.next_tag;
+            let tag = match write.tags.get(index) {
+                Some(tag) if f64::from(tag.timestamp) <= write.time => *tag,
+                Some(_) => return false,
+                // Audio may continue after the last video frame.
+                None => return write.time >= write.duration,
+            };
+            write.next_tag += 1;
+            drop(write);
+
+            self.run_tag(context, tag, index as u32);
+        }
+    }
+
+    fn run_tag(self, context: &mut UpdateContext<'_, 'gc, '_>, tag: TagInfo, frame_id: u32) {
+        let read = self.0.read();
+        let mut frame = None;
+        let mut script = None;
+        let mut reader = match flv::Reader::new(&read.buffer) {
+            Ok(reader) => reader,
+            Err(_) => return,
+        };
+        reader.seek(tag.offset);
+        match reader.read_tag() {
+            Ok(Some(flv::Tag {
+                data: flv::TagData::Video(video),
+                ..
+            })) => {
+                if let Some(stream) = read.video_stream {
+                    frame = Some(context.video.decode_video_stream_frame(
+                        stream,
+                        EncodedFrame {
+                            codec: video.codec,
+                            data: video.data,
+                            frame_id,
+                        },
+                        context.renderer,
+                    ));
+                }
+            }
+            Ok(Some(flv::Tag {
+                data: flv::TagData::Script(data),
+                ..
+            })) => script = Some(data),
+            _ => (),
+        }
+        drop(read);
+
+        match frame {
+            Some(Ok(frame)) => {
+                let mut write = self.0.write(context.gc_context);
+                write.last_frame = Some(frame);
+                for video in &write.attached_videos {
+                    video.invalidate_cached_bitmap();
+                }
+                *context.needs_render = true;
+            }
+            Some(Err(e)) => log::error!("Got error when decoding stream video frame: {}", e),
+            None => (),
+        }
+
+        if let Some(script) = script {
+            self.call_script_callback(context, &script.name, &script.value);
+        }
+    }
+
+    /// Notify script of a change in the status of the stream.
+    fn trigger_status_event(
+        self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        code: &'static str,
+        level: &'static str,
+    ) {
+        let avm_object = self.0.read().avm_object;
+        match avm_object {
+            Some(AvmObject::Avm1(object)) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    ActivationIdentifier::root("[NetStream]"),
+                );
+                let info = Avm1ScriptObject::object(
+                    activation.context.gc_context,
+                    Some(activation.context.avm1.prototypes().object),
+                );
+                info.define_value(
+                    activation.context.gc_context,
+                    "code",
+                    code.into(),
+                    Attribute::empty(),
+                );
+                info.define_value(
+                    activation.context.gc_context,
+                    "level",
+                    level.into(),
+                    Attribute::empty(),
+                );
+                if let Err(e) =
+                    object.call_method("onStatus".into(), &[info.into()], &mut activation)
+                {
+                    log::error!("Got error when calling NetStream.onStatus: {}", e);
+                }
+            }
+            Some(AvmObject::Avm2(object)) => {
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                let info = match avm2_status_info(&mut activation, code, level) {
+                    Ok(info) => info,
+                    Err(e) => {
+                        log::error!("Got error when creating NetStatusEvent info: {}", e);
+                        return;
+                    }
+                };
+                let event = Avm2Event::new("netStatus", Avm2EventData::NetStatus { info });
+                if let Err(e) = Avm2::dispatch_event(&mut activation.context, event, object) {
+                    log::error!("Got error when dispatching NetStatusEvent: {}", e);
+                }
+            }
+            None => (),
+        }
+    }
+
+    /// Call a script data callback, such as `onMetaData` or `onCuePoint`.
+    fn call_script_callback(
+        self,
+        context: &mut UpdateContext<'_, 'gc, '_>,
+        name: &str,
+        value: &flv::Value,
+    ) {
+        let read = self.0.read();
+        let (avm_object, avm2_client) = (read.avm_object, read.avm2_client);
+        drop(read);
+
+        match avm_object {
+            Some(AvmObject::Avm1(object)) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    ActivationIdentifier::root("[NetStream]"),
+                );
+                let name = AvmString::new_utf8(activation.context.gc_context, name);
+                let value = avm1_value(&mut activation, value);
+                if let Err(e) = object.call_method(name, &[value], &mut activation) {
+                    log::error!("Got error when calling NetStream.{}: {}", name, e);
+                }
+            }
+            Some(AvmObject::Avm2(object)) => {
+                let client = avm2_client.unwrap_or(object);
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                if let Err(e) = avm2_call_client(&mut activation, client, name, value) {
+                    log::error!("Got error when calling NetStream client {}: {}", name, e);
+                }
+            }
+            None => (),
+        }
+    }
+}
+
+fn avm2_status_info<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    code: &'static str,
+    level: &'static str,
+) -> Result<Avm2Object<'gc>, Avm2Error> {
+    let mut info = activation
+        .avm2()
+        .classes()
+        .object
+        .construct(activation, &[])?;
+    info.set_property(
+        &Avm2QName::dynamic_name("code").into(),
+        code.into(),
+        activation,
+    )?;
+    info.set_property(
+        &Avm2QName::dynamic_name("level").into(),
+        level.into(),
+        activation,
+    )?;
+    Ok(info)
+}
+
+fn avm2_call_client<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    client: Avm2Object<'gc>,
+    name: &str,
+    value: &flv::Value,
+) -> Result<(), Avm2Error> {
+    let name = AvmString::new_utf8(activation.context.gc_context, name);
+    if let Avm2Value::Object(callback) =
+        client.get_property(&Avm2QName::dynamic_name(name).into(), activation)?
+    {
+        let value = avm2_value(activation, value)?;
+        callback.call(Some(client), &[value], activation)?;
+    }
+    Ok(())
+}
+
+/// Convert FLV script data into an AVM1 value.
+fn avm1_value<'gc>(
+    activation: &mut Avm1Activation<'_, 'gc, '_>,
+    value: &flv::Value,
+) -> Avm1Value<'gc> {
+    let gc_context = activation.context.gc_context;
+    match value {
+        flv::Value::Number(n) => (*n).into(),
+        flv::Value::Boolean(b) => (*b).into(),
+        flv::Value::String(s) => AvmString::new_utf8(gc_context, s.as_str()).into(),
+        flv::Value::Object(properties) => {
+            let object: Avm1Object<'gc> = Avm1ScriptObject::object(
+                gc_context,
+                Some(activation.context.avm1.prototypes().object),
+            )
+            .into();
+            for (name, value) in properties {
+                let value = avm1_value(activation, value);
+                object.define_value(
+                    gc_context,
+                    AvmString::new_utf8(gc_context, name.as_str()),
+                    value,
+                    Attribute::empty(),
+                );
+            }
+            object.into()
+        }
+        flv::Value::Null => Avm1Value::Null,
+        flv::Value::Undefined => Avm1Value::Undefined,
+        flv::Value::StrictArray(values) => {
+            let values: Vec<_> = values
+                .iter()
+                .map(|value| avm1_value(activation, value))
+                .collect();
+            Avm1ArrayObject::new(
+                gc_context,
+                activation.context.avm1.prototypes().array,
+                values,
+            )
+            .into()
+        }
+        flv::Value::Date(time) => {
+            let date_time = match Utc.timestamp_millis_opt(*time as i64) {
+                LocalResult::Single(date_time) => Some(date_time),
+                _ => None,
+            };
+            Avm1DateObject::with_date_time(
+                gc_context,
+                Some(activation.context.avm1.prototypes().date),
+                date_time,
+            )
+            .into()
+        }
+    }
+}
+
+/// Convert FLV script data into an AVM2 value.
+fn avm2_value<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc, '_>,
+    value: &flv::Value,
+) -> Result<Avm2Value<'gc>, Avm2Error> {
+    Ok(match value {
+        flv::Value::Number(n) => (*n).into(),
+        flv::Value::Boolean(b) => (*b).into(),
+        flv::Value::String(s) => {
+            AvmString::new_utf8(activation.context.gc_context, s.as_str()).into()
+        }
+        flv::Value::Object(properties) => {
+            let mut object = activation
+                .avm2()
+                .classes()
+                .object
+                .construct(activation, &[])?;
+            for (name, value) in properties {
+                let name = AvmString::new_utf8(activation.context.gc_context, name.as_str());
+                let value = avm2_value(activation, value)?;
+                object.set_property(&Avm2QName::dynamic_name(name).into(), value, activation)?;
+            }
+            object.into()
+        }
+        flv::Value::Null => Avm2Value::Null,
+        flv::Value::Undefined => Avm2Value::Undefined,
+        flv::Value::StrictArray(values) => {
+            let values = values
+                .iter()
+                .map(|value| avm2_value(activation, value))
+                .collect::<Result<Vec<_>, _>>()?;
+            let storage = Avm2ArrayStorage::from_args(&values);
+            Avm2ArrayObject::from_storage(activation, storage)?.into()
+        }
+        flv::Value::Date(time) => activation
+            .avm2()
+            .classes()
+            .date
+            .construct(activation, &[(*time).into()])?
+            .into(),
+    })
+}
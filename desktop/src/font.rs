@@ -0,0 +1,148 @@
+use ruffle_core::backend::font::{
+    find_face, find_fallback_face, FontBackend, FontFaceInfo, FontFile,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Loads device fonts from the TrueType and OpenType fonts in a list of
+/// directories.
+///
+/// The directories are only searched the first time a font is requested, as
+/// reading every font on the system takes a moment.
+pub struct DirectoryFontBackend {
+    directories: Vec<PathBuf>,
+    is_indexed: bool,
+
+    /// The file that each face in `faces` was found in.
+    paths: Vec<PathBuf>,
+    faces: Vec<FontFaceInfo>,
+}
+
+impl DirectoryFontBackend {
+    /// Create a font backend that searches the given directories, followed
+    /// by the font directories of the system.
+    pub fn new(directories: &[PathBuf]) -> Self {
+        let mut directories = directories.to_vec();
+        directories.extend(system_font_directories());
+        Self {
+            directories,
+            is_indexed: false,
+            paths: Vec::new(),
+            faces: Vec::new(),
+        }
+    }
+
+    fn index(&mut self) {
+        if self.is_indexed {
+            return;
+        }
+        self.is_indexed = true;
+
+        let mut files = Vec::new();
+        for directory in &self.directories {
+            find_font_files(directory, &mut files);
+        }
+        for path in files {
+            let data = match fs::read(&path) {
+                Ok(data) => data,
+                Err(e) => {
+                    log::warn!("Unable to read font {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            for face in FontFaceInfo::parse_all(&data) {
+                self.paths.push(path.clone());
+                self.faces.push(face);
+            }
+        }
+        log::info!("Found {} system font faces", self.faces.len());
+    }
+
+    fn load_face(&self, i: usize) -> Option<FontFile> {
+        let path = &self.paths[i];
+        match fs::read(path) {
+            Ok(data) => Some(FontFile {
+                data,
+                index: self.faces[i].index,
+            }),
+            Err(e) => {
+                log::warn!("Unable to read font {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+}
+
+impl FontBackend for DirectoryFontBackend {
+    fn load_font(&mut self, name: &str, is_bold: bool, is_italic: bool) -> Option<FontFile> {
+        self.index();
+        let i = find_face(&self.faces, name, is_bold, is_italic)?;
+        self.load_face(i)
+    }
+
+    fn load_fallback_font(
+        &mut self,
+        character: char,
+        is_bold: bool,
+        is_italic: bool,
+    ) -> Option<FontFile> {
+        self.index();
+        let i = find_fallback_face(&self.faces, character, is_bold, is_italic)?;
+        self.load_face(i)
+    }
+}
+
+/// Recursively collect the font files in a directory, in a stable order.
+fn find_font_files(directory: &Path, files: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let mut entries: Vec<_> = entries.filter_map(Result::ok).collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        // Symbolic links to directories aren't followed, as they may loop.
+        if entry.file_type().map_or(false, |t| t.is_dir()) {
+            find_font_files(&path, files);
+        } else if path.is_file() && is_font_file(&path) {
+            files.push(path);
+        }
+    }
+}
+
+fn is_font_file(path: &Path) -> bool {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    ["ttf", "otf", "ttc", "otc"]
+        .iter()
+        .any(|e| extension.eq_ignore_ascii_case(e))
+}
+
+/// The directories that fonts are installed to on this system.
+fn system_font_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+    if let Some(directory) = dirs::font_dir() {
+        directories.push(directory);
+    }
+
+    if cfg!(target_os = "windows") {
+        if let Some(windows) = std::env::var_os("WINDIR") {
+            directories.push(Path::new(&windows).join("Fonts"));
+        }
+    } else if cfg!(target_os = "macos") {
+        directories.push("/Library/Fonts".into());
+        directories.push("/System/Library/Fonts".into());
+    } else {
+        if let Some(home) = dirs::home_dir() {
+            directories.push(home.join(".fonts"));
+        }
+        directories.push("/usr/local/share/fonts".into());
+        directories.push("/usr/share/fonts".into());
+    }
+
+    directories
+}
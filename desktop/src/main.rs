@@ -8,7 +8,9 @@
 mod audio;
 mod custom_event;
 mod executor;
+mod font;
 mod navigator;
+mod socket;
 mod storage;
 mod task;
 mod ui;
@@ -21,8 +23,10 @@ use rfd::FileDialog;
 use ruffle_core::{
     backend::{
         audio::{AudioBackend, NullAudioBackend},
+        font::NullFontBackend,
         log as log_backend,
         navigator::NullNavigatorBackend,
+        socket::NullSocketBackend,
         storage::MemoryStorageBackend,
         ui::NullUiBackend,
         video,
@@ -100,6 +104,21 @@ struct Opt {
 
     #[clap(long, takes_value = false)]
     dont_warn_on_unsupported_content: bool,
+
+    /// Trust local movies, allowing them to load and access content from anywhere.
+    /// Otherwise they run in the local-with-file or local-with-network sandbox.
+    #[clap(long, takes_value = false)]
+    trust_local: bool,
+
+    /// A directory to load device fonts from, searched before the system's font directories.
+    /// This can be repeated multiple times, for example --font-dir fonts --font-dir more_fonts.
+    #[clap(
+        long = "font-dir",
+        parse(from_os_str),
+        number_of_values = 1,
+        multiple_occurrences = true
+    )]
+    font_dirs: Vec<PathBuf>,
 }
 
 #[cfg(feature = "render_trace")]
@@ -268,13 +287,20 @@ impl App {
         let video = Box::new(video::SoftwareVideoBackend::new());
         let log = Box::new(log_backend::NullLogBackend::new());
         let ui = Box::new(ui::DesktopUiBackend::new(window.clone()));
-        let player = Player::new(renderer, audio, navigator, storage, video, log, ui)?;
+        let fonts = Box::new(font::DirectoryFontBackend::new(&opt.font_dirs));
+        let sockets = Box::new(socket::TcpSocketBackend::new());
+        let player = Player::new(
+            renderer, audio, navigator, storage, video, log, ui, fonts, sockets,
+        )?;
 
         let loaded = movie.is_some();
 
         {
             let mut player_lock = player.lock().unwrap();
             player_lock.set_warn_on_unsupported_content(!opt.dont_warn_on_unsupported_content);
+            player_lock
+                .security_mut()
+                .set_trust_local_content(opt.trust_local);
             if let Some(movie) = movie {
                 player_lock.set_root_movie(Arc::new(movie));
                 player_lock.set_is_playing(true); // Desktop player will auto-play.
@@ -758,7 +784,11 @@ fn run_timedemo(opt: Opt) -> Result<(), Box<dyn std::error::Error>> {
     let video = Box::new(video::SoftwareVideoBackend::new());
     let log = Box::new(log_backend::NullLogBackend::new());
     let ui = Box::new(NullUiBackend::new());
-    let player = Player::new(renderer, audio, navigator, storage, video, log, ui)?;
+    let fonts = Box::new(NullFontBackend::new());
+    let sockets = Box::new(NullSocketBackend::new());
+    let player = Player::new(
+        renderer, audio, navigator, storage, video, log, ui, fonts, sockets,
+    )?;
 
     let mut player_lock = player.lock().unwrap();
     player_lock.set_root_movie(Arc::new(movie));
@@ -1,9 +1,14 @@
 //! Navigator backend for web
 
 use crate::custom_event::RuffleEvent;
-use isahc::{config::RedirectPolicy, prelude::*, AsyncReadResponseExt, HttpClient, Request};
+use futures_lite::AsyncReadExt;
+use isahc::{
+    config::RedirectPolicy, prelude::*, AsyncBody, AsyncReadResponseExt, HttpClient, Request,
+    Response,
+};
 use ruffle_core::backend::navigator::{
-    NavigationMethod, NavigatorBackend, OwnedFuture, RequestOptions,
+    ChunkFuture, ChunkedResponse, NavigationMethod, NavigatorBackend, OwnedFuture, RequestOptions,
+    WholeResponse,
 };
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
@@ -106,7 +111,6 @@ impl NavigatorBackend for ExternalNavigatorBackend {
     }
 
     fn fetch(&self, url: &str, options: RequestOptions) -> OwnedFuture<Vec<u8>, Error> {
-        // TODO: honor sandbox type (local-with-filesystem, local-with-network, remote, ...)
         let full_url = match self.movie_url.clone().join(url) {
             Ok(url) => url,
             Err(e) => {
@@ -157,30 +161,7 @@ impl NavigatorBackend for ExternalNavigatorBackend {
                 })
             }),
             _ => Box::pin(async move {
-                let client =
-                    client.ok_or_else(|| Error::FetchError("Network unavailable".to_string()))?;
-
-                let request = match options.method() {
-                    NavigationMethod::Get => Request::get(processed_url.to_string()),
-                    NavigationMethod::Post => Request::post(processed_url.to_string()),
-                };
-
-                let (body_data, _) = options.body().clone().unwrap_or_default();
-                let body = request
-                    .body(body_data)
-                    .map_err(|e| Error::FetchError(e.to_string()))?;
-
-                let mut response = client
-                    .send_async(body)
-                    .await
-                    .map_err(|e| Error::FetchError(e.to_string()))?;
-
-                if !response.status().is_success() {
-                    return Err(Error::FetchError(format!(
-                        "HTTP status is not ok, got {}",
-                        response.status()
-                    )));
-                }
+                let mut response = send_request(client, processed_url, options).await?;
 
                 let mut buffer = vec![];
                 response
@@ -192,6 +173,40 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         }
     }
 
+    fn fetch_chunked(
+        &self,
+        url: &str,
+        options: RequestOptions,
+    ) -> OwnedFuture<Box<dyn ChunkedResponse>, Error> {
+        let full_url = match self.movie_url.clone().join(url) {
+            Ok(url) => url,
+            Err(e) => {
+                let msg = format!("Invalid URL {}: {}", url, e);
+                return Box::pin(async move { Err(Error::FetchError(msg)) });
+            }
+        };
+
+        let processed_url = self.pre_process_url(full_url);
+        if processed_url.scheme() == "file" {
+            // Local files are read all at once.
+            let fetch = self.fetch(url, options);
+            return Box::pin(async move {
+                let data = fetch.await?;
+                Ok(Box::new(WholeResponse::new(data)) as Box<dyn ChunkedResponse>)
+            });
+        }
+
+        let client = self.client.clone();
+        Box::pin(async move {
+            let response = send_request(client, processed_url, options).await?;
+            let body = response.into_body();
+            Ok(Box::new(HttpChunks {
+                expected_length: body.len(),
+                body,
+            }) as Box<dyn ChunkedResponse>)
+        })
+    }
+
     fn spawn_future(&mut self, future: OwnedFuture<(), Error>) {
         self.channel.send(future).expect("working channel send");
 
@@ -218,3 +233,67 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         url
     }
 }
+
+/// The size of the chunks that HTTP responses are read in.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Send a network request, failing if the response isn't successful.
+async fn send_request(
+    client: Option<Rc<HttpClient>>,
+    url: Url,
+    options: RequestOptions,
+) -> Result<Response<AsyncBody>, Error> {
+    let client = client.ok_or_else(|| Error::FetchError("Network unavailable".to_string()))?;
+
+    let request = match options.method() {
+        NavigationMethod::Get => Request::get(url.to_string()),
+        NavigationMethod::Post => Request::post(url.to_string()),
+    };
+
+    let (body_data, _) = options.body().clone().unwrap_or_default();
+    let body = request
+        .body(body_data)
+        .map_err(|e| Error::FetchError(e.to_string()))?;
+
+    let response = client
+        .send_async(body)
+        .await
+        .map_err(|e| Error::FetchError(e.to_string()))?;
+
+    if !response.status().is_success() {
+        return Err(Error::FetchError(format!(
+            "HTTP status is not ok, got {}",
+            response.status()
+        )));
+    }
+
+    Ok(response)
+}
+
+/// The body of an HTTP response, read as it arrives.
+struct HttpChunks {
+    body: AsyncBody,
+    expected_length: Option<u64>,
+}
+
+impl ChunkedResponse for HttpChunks {
+    fn expected_length(&self) -> Option<u64> {
+        self.expected_length
+    }
+
+    fn next_chunk(&mut self) -> ChunkFuture<'_> {
+        Box::pin(async move {
+            let mut chunk = vec![0; CHUNK_SIZE];
+            let len = self
+                .body
+                .read(&mut chunk)
+                .await
+                .map_err(|e| Error::FetchError(e.to_string()))?;
+            if len == 0 {
+                return Ok(None);
+            }
+            chunk.truncate(len);
+            Ok(Some(chunk))
+        })
+    }
+}
@@ -0,0 +1,197 @@
+use ruffle_core::backend::socket::{SocketBackend, SocketEvent, SocketHandle};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// How long to wait for a connection, which is the default `Socket.timeout`.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+
+type ConnectResult = (SocketHandle, io::Result<TcpStream>);
+
+/// Socket backend that makes real TCP connections.
+///
+/// Connections are made on a separate thread so that they don't block the
+/// player. Once connected, sockets are non-blocking and are read from and
+/// written to whenever the player polls them.
+pub struct TcpSocketBackend {
+    /// Sockets that are still connecting.
+    connecting: HashSet<SocketHandle>,
+
+    /// Sockets that are connected.
+    connections: HashMap<SocketHandle, Connection>,
+
+    /// Receives the outcome of connection attempts.
+    connect_receiver: Receiver<ConnectResult>,
+
+    connect_sender: Sender<ConnectResult>,
+
+    /// Events that happened outside of `poll`, such as failed writes.
+    events: Vec<SocketEvent>,
+}
+
+struct Connection {
+    stream: TcpStream,
+
+    /// Data that was sent by the movie but not written to the stream yet.
+    pending: Vec<u8>,
+}
+
+impl Connection {
+    /// Write as much of the pending data as the stream accepts.
+    fn write_pending(&mut self) -> io::Result<()> {
+        while !self.pending.is_empty() {
+            match self.stream.write(&self.pending) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(written) => {
+                    self.pending.drain(..written);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Read everything that was received.
+    ///
+    /// Returns the data and whether the connection was closed.
+    fn read_available(&mut self) -> (Vec<u8>, bool) {
+        let mut data = Vec::new();
+        let mut buffer = [0; 4096];
+        loop {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => return (data, true),
+                Ok(read) => data.extend_from_slice(&buffer[..read]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return (data, false),
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(e) => {
+                    log::warn!("Socket read failed: {}", e);
+                    return (data, true);
+                }
+            }
+        }
+    }
+}
+
+impl TcpSocketBackend {
+    pub fn new() -> Self {
+        let (connect_sender, connect_receiver) = channel();
+        Self {
+            connecting: HashSet::new(),
+            connections: HashMap::new(),
+            connect_receiver,
+            connect_sender,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl SocketBackend for TcpSocketBackend {
+    fn connect(&mut self, handle: SocketHandle, host: &str, port: u16) {
+        let address = (host.to_string(), port);
+        let sender = self.connect_sender.clone();
+        self.connecting.insert(handle);
+        thread::spawn(move || {
+            let result = address.to_socket_addrs().and_then(|mut addrs| {
+                let mut result = Err(io::Error::new(ErrorKind::NotFound, "Host has no addresses"));
+                for addr in &mut addrs {
+                    result = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT);
+                    if result.is_ok() {
+                        break;
+                    }
+                }
+                result
+            });
+            // The backend is gone if the player was closed.
+            let _ = sender.send((handle, result));
+        });
+    }
+
+    fn send(&mut self, handle: SocketHandle, data: Vec<u8>) {
+        if let Some(connection) = self.connections.get_mut(&handle) {
+            connection.pending.extend(data);
+            if let Err(e) = connection.write_pending() {
+                log::warn!("Socket write failed: {}", e);
+                self.connections.remove(&handle);
+                self.events.push(SocketEvent::Close { handle });
+            }
+        }
+    }
+
+    fn close(&mut self, handle: SocketHandle) {
+        self.connecting.remove(&handle);
+        self.connections.remove(&handle);
+        self.events.retain(|event| match event {
+            SocketEvent::Connect { handle: other, .. }
+            | SocketEvent::Data { handle: other, .. }
+            | SocketEvent::Close { handle: other } => *other != handle,
+        });
+    }
+
+    fn poll(&mut self) -> Vec<SocketEvent> {
+        let mut events = std::mem::take(&mut self.events);
+
+        while let Ok((handle, result)) = self.connect_receiver.try_recv() {
+            if !self.connecting.remove(&handle) {
+                // The socket was closed while it was connecting.
+                continue;
+            }
+
+            let stream = result.and_then(|stream| {
+                stream.set_nonblocking(true)?;
+                stream.set_nodelay(true)?;
+                Ok(stream)
+            });
+            match stream {
+                Ok(stream) => {
+                    self.connections.insert(
+                        handle,
+                        Connection {
+                            stream,
+                            pending: Vec::new(),
+                        },
+                    );
+                    events.push(SocketEvent::Connect {
+                        handle,
+                        is_success: true,
+                    });
+                }
+                Err(e) => {
+                    log::warn!("Socket connection failed: {}", e);
+                    events.push(SocketEvent::Connect {
+                        handle,
+                        is_success: false,
+                    });
+                }
+            }
+        }
+
+        let mut closed = Vec::new();
+        for (handle, connection) in self.connections.iter_mut() {
+            let (data, mut is_closed) = connection.read_available();
+            if !data.is_empty() {
+                events.push(SocketEvent::Data {
+                    handle: *handle,
+                    data,
+                });
+            }
+            if let Err(e) = connection.write_pending() {
+                log::warn!("Socket write failed: {}", e);
+                is_closed = true;
+            }
+            if is_closed {
+                events.push(SocketEvent::Close { handle: *handle });
+                closed.push(*handle);
+            }
+        }
+        for handle in closed {
+            self.connections.remove(&handle);
+        }
+
+        events
+    }
+}
@@ -54,6 +54,10 @@ impl UiBackend for DesktopUiBackend {
         self.clipboard.set_contents(content).unwrap();
     }
 
+    fn clipboard_content(&mut self) -> String {
+        self.clipboard.get_contents().unwrap_or_default()
+    }
+
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error> {
         self.window.set_fullscreen(if is_full {
             Some(Fullscreen::Borderless(None))
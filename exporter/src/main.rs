@@ -2,13 +2,17 @@ use clap::Parser;
 use image::RgbaImage;
 use indicatif::{ProgressBar, ProgressStyle};
 use ruffle_core::backend::audio::NullAudioBackend;
+use ruffle_core::backend::font::NullFontBackend;
 use ruffle_core::backend::log::NullLogBackend;
 use ruffle_core::backend::navigator::NullNavigatorBackend;
+use ruffle_core::backend::render::{BitmapFormat, RenderBackend};
+use ruffle_core::backend::socket::NullSocketBackend;
 use ruffle_core::backend::storage::MemoryStorageBackend;
 use ruffle_core::backend::ui::NullUiBackend;
 use ruffle_core::backend::video::SoftwareVideoBackend;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::Player;
+use ruffle_render_software::SoftwareRenderBackend;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use ruffle_render_wgpu::target::TextureTarget;
 use ruffle_render_wgpu::{wgpu, Descriptors, WgpuRenderBackend};
@@ -73,20 +77,42 @@ struct Opt {
     #[clap(long, short, ignore_case = true, default_value = "high", arg_enum)]
     power: PowerPreference,
 
+    /// Render on the CPU instead of a graphics device.
+    /// This is much slower, but works on machines without a GPU.
+    #[clap(long)]
+    software: bool,
+
     /// Location to store a wgpu trace output
     #[clap(long, parse(from_os_str))]
     #[cfg(feature = "render_trace")]
     trace_path: Option<PathBuf>,
 }
 
+/// Read back the last rendered frame of a player.
+fn capture(renderer: &mut dyn RenderBackend) -> Option<RgbaImage> {
+    if let Some(renderer) = renderer.downcast_mut::<WgpuRenderBackend<TextureTarget>>() {
+        let target = renderer.target();
+        return target.capture(renderer.device());
+    }
+
+    let renderer = renderer.downcast_ref::<SoftwareRenderBackend>()?;
+    let bitmap = renderer.capture();
+    match bitmap.data {
+        BitmapFormat::Rgba(rgba) => RgbaImage::from_raw(bitmap.width, bitmap.height, rgba),
+        BitmapFormat::Rgb(_) => None,
+    }
+}
+
+/// Export frames of a movie, using the software renderer if no graphics
+/// device is given.
 fn take_screenshot(
-    descriptors: Descriptors,
+    descriptors: Option<Descriptors>,
     swf_path: &Path,
     frames: u32,
     skipframes: u32,
     progress: &Option<ProgressBar>,
     size: SizeOpt,
-) -> Result<(Descriptors, Vec<RgbaImage>), Box<dyn std::error::Error>> {
+) -> Result<(Option<Descriptors>, Vec<RgbaImage>), Box<dyn std::error::Error>> {
     let movie = SwfMovie::from_path(&swf_path, None)?;
 
     let width = size
@@ -101,15 +127,23 @@ fn take_screenshot(
         .unwrap_or_else(|| movie.height().to_pixels());
     let height = (height * size.scale).round() as u32;
 
-    let target = TextureTarget::new(&descriptors.device, (width, height));
+    let renderer: Box<dyn RenderBackend> = match descriptors {
+        Some(descriptors) => {
+            let target = TextureTarget::new(&descriptors.device, (width, height));
+            Box::new(WgpuRenderBackend::new(descriptors, target)?)
+        }
+        None => Box::new(SoftwareRenderBackend::new(width, height)),
+    };
     let player = Player::new(
-        Box::new(WgpuRenderBackend::new(descriptors, target)?),
+        renderer,
         Box::new(NullAudioBackend::new()),
         Box::new(NullNavigatorBackend::new()),
         Box::new(MemoryStorageBackend::default()),
         Box::new(SoftwareVideoBackend::new()),
         Box::new(NullLogBackend::new()),
         Box::new(NullUiBackend::new()),
+        Box::new(NullFontBackend::new()),
+        Box::new(NullSocketBackend::new()),
     )?;
 
     player
@@ -133,12 +167,7 @@ fn take_screenshot(
         if i >= skipframes {
             player.lock().unwrap().render();
             let mut player = player.lock().unwrap();
-            let renderer = player
-                .renderer_mut()
-                .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
-                .unwrap();
-            let target = renderer.target();
-            if let Some(image) = target.capture(renderer.device()) {
+            if let Some(image) = capture(player.renderer_mut()) {
                 result.push(image);
             } else {
                 return Err(format!("Unable to capture frame {} of {:?}", i, swf_path).into());
@@ -157,8 +186,7 @@ fn take_screenshot(
         .destroy()
         .downcast::<WgpuRenderBackend<TextureTarget>>()
         .ok()
-        .unwrap()
-        .descriptors();
+        .map(|renderer| renderer.descriptors());
     Ok((descriptors, result))
 }
 
@@ -192,7 +220,7 @@ fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
     results
 }
 
-fn capture_single_swf(descriptors: Descriptors, opt: &Opt) -> Result<(), Box<dyn Error>> {
+fn capture_single_swf(descriptors: Option<Descriptors>, opt: &Opt) -> Result<(), Box<dyn Error>> {
     let output = opt.output_path.clone().unwrap_or_else(|| {
         let mut result = PathBuf::new();
         result.set_file_name(opt.swf.file_stem().unwrap());
@@ -268,7 +296,10 @@ fn capture_single_swf(descriptors: Descriptors, opt: &Opt) -> Result<(), Box<dyn
 }
 
 #[allow(clippy::branches_sharing_code)]
-fn capture_multiple_swfs(mut descriptors: Descriptors, opt: &Opt) -> Result<(), Box<dyn Error>> {
+fn capture_multiple_swfs(
+    mut descriptors: Option<Descriptors>,
+    opt: &Opt,
+) -> Result<(), Box<dyn Error>> {
     let output = opt.output_path.clone().unwrap();
     let files = find_files(&opt.swf, !opt.silent);
 
@@ -375,15 +406,20 @@ fn trace_path(_opt: &Opt) -> Option<&Path> {
 
 fn main() -> Result<(), Box<dyn Error>> {
     let opt: Opt = Opt::parse();
-    let instance = wgpu::Instance::new(opt.graphics.into());
-    let descriptors =
-        futures::executor::block_on(WgpuRenderBackend::<TextureTarget>::build_descriptors(
+    let descriptors = if opt.software {
+        None
+    } else {
+        let instance = wgpu::Instance::new(opt.graphics.into());
+        Some(futures::executor::block_on(WgpuRenderBackend::<
+            TextureTarget,
+        >::build_descriptors(
             opt.graphics.into(),
             instance,
             None,
             opt.power.into(),
             trace_path(&opt),
-        ))?;
+        ))?)
+    };
 
     if opt.swf.is_file() {
         capture_single_swf(descriptors, &opt)?;
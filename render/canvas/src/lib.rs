@@ -30,6 +30,13 @@ pub struct WebCanvasRenderBackend {
     use_color_transform_hack: bool,
     pixelated_property_value: &'static str,
     deactivating_mask: bool,
+
+    /// The size of each offscreen pass in progress, along with the mask state
+    /// to restore once it ends.
+    offscreen_stack: Vec<(u32, u32, bool)>,
+
+    /// The blend modes pushed by `push_blend_mode`, innermost last.
+    blend_modes: Vec<swf::BlendMode>,
 }
 
 /// Canvas-drawable shape data extracted from an SWF file.
@@ -329,6 +336,8 @@ impl WebCanvasRenderBackend {
             viewport_height: 0,
             use_color_transform_hack: is_firefox,
             deactivating_mask: false,
+            offscreen_stack: vec![],
+            blend_modes: vec![],
 
             // For rendering non-smoothed bitmaps.
             // crisp-edges works in Firefox, pixelated works in Chrome (and others)?
@@ -341,8 +350,16 @@ impl WebCanvasRenderBackend {
         Ok(renderer)
     }
 
+    /// The size of the render target currently being drawn to.
+    fn render_target_size(&self) -> (u32, u32) {
+        match self.offscreen_stack.last() {
+            Some((width, height, _)) => (*width, *height),
+            None => (self.viewport_width, self.viewport_height),
+        }
+    }
+
     // Pushes a fresh canvas onto the stack to use as a render target.
-    fn push_render_target(&mut self) {
+    fn push_render_target(&mut self, width: u32, height: u32) {
         self.cur_render_target += 1;
         if self.cur_render_target >= self.render_targets.len() {
             // Create offscreen canvas to use as the render target.
@@ -368,14 +385,15 @@ impl WebCanvasRenderBackend {
         }
 
         let (canvas, context) = &self.render_targets[self.cur_render_target];
-        canvas.set_width(self.viewport_width);
-        canvas.set_height(self.viewport_height);
+        canvas.set_width(width);
+        canvas.set_height(height);
         self.canvas = canvas.clone();
         self.context = context.clone();
         let width = self.canvas.width();
         let height = self.canvas.height();
         self.context
             .clear_rect(0.0, 0.0, width.into(), height.into());
+        self.apply_blend_mode();
     }
 
     fn pop_render_target(&mut self) -> (HtmlCanvasElement, CanvasRenderingContext2d) {
@@ -385,6 +403,7 @@ impl WebCanvasRenderBackend {
             let (canvas, context) = &self.render_targets[self.cur_render_target];
             self.canvas = canvas.clone();
             self.context = context.clone();
+            self.apply_blend_mode();
             out
         } else {
             log::error!("Render target stack underflow");
@@ -392,6 +411,19 @@ impl WebCanvasRenderBackend {
         }
     }
 
+    /// The blend mode that draws are currently blended with.
+    fn blend_mode(&self) -> swf::BlendMode {
+        self.blend_modes.last().copied().unwrap_or_default()
+    }
+
+    /// Set the composite operation of the current render target to match the
+    /// current blend mode.
+    fn apply_blend_mode(&self) {
+        self.context
+            .set_global_composite_operation(composite_operation(self.blend_mode()))
+            .warn_on_error();
+    }
+
     #[allow(clippy::float_cmp)]
     #[inline]
     fn set_transform(&mut self, matrix: &Matrix) {
@@ -658,6 +690,16 @@ impl RenderBackend for WebCanvasRenderBackend {
         self.set_transform(&transform.matrix);
         self.set_color_filter(transform);
         if let Some(bitmap) = self.bitmaps.get(bitmap.0) {
+            // "destination-in" would also clear everything outside of the
+            // bitmap, so clip it to the bitmap's bounds.
+            let clip = self.blend_mode() == swf::BlendMode::Alpha;
+            if clip {
+                self.context.save();
+                self.context.begin_path();
+                self.context
+                    .rect(0.0, 0.0, bitmap.width.into(), bitmap.height.into());
+                self.context.clip();
+            }
             match &bitmap.image {
                 BitmapDataStorage::ImageElement(image) => {
                     let _ = self
@@ -670,6 +712,9 @@ impl RenderBackend for WebCanvasRenderBackend {
                         .draw_image_with_html_canvas_element(canvas, 0.0, 0.0);
                 }
             }
+            if clip {
+                self.context.restore();
+            }
         }
         self.clear_color_filter();
     }
@@ -768,11 +813,13 @@ impl RenderBackend for WebCanvasRenderBackend {
     fn push_mask(&mut self) {
         // In the canvas backend, masks are implemented using two render targets.
         // We render the masker clips to the first render target.
-        self.push_render_target();
+        let (width, height) = self.render_target_size();
+        self.push_render_target(width, height);
     }
     fn activate_mask(&mut self) {
         // We render the maskee clips to the second render target.
-        self.push_render_target();
+        let (width, height) = self.render_target_size();
+        self.push_render_target(width, height);
     }
     fn deactivate_mask(&mut self) {
         self.deactivating_mask = true;
@@ -860,6 +907,77 @@ impl RenderBackend for WebCanvasRenderBackend {
 
         Ok(handle)
     }
+
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color) {
+        self.offscreen_stack
+            .push((width, height, self.deactivating_mask));
+        self.deactivating_mask = false;
+        self.push_render_target(width, height);
+
+        self.context.reset_transform().warn_on_error();
+        let color = format!(
+            "rgba({},{},{},{})",
+            clear.r,
+            clear.g,
+            clear.b,
+            f32::from(clear.a) / 255.0
+        );
+        self.context.set_fill_style(&color.into());
+        self.context
+            .fill_rect(0.0, 0.0, width.into(), height.into());
+    }
+
+    fn end_offscreen(&mut self) -> Option<Bitmap> {
+        let (width, height, deactivating_mask) = self.offscreen_stack.pop()?;
+        let image_data = self
+            .context
+            .get_image_data(0.0, 0.0, width.into(), height.into());
+        self.pop_render_target();
+        self.deactivating_mask = deactivating_mask;
+
+        // Canvas image data is not premultiplied.
+        let mut rgba = image_data.ok()?.data().to_vec();
+        ruffle_core::backend::render::premultiply_alpha_rgba(&mut rgba);
+
+        Some(Bitmap {
+            width,
+            height,
+            data: BitmapFormat::Rgba(rgba),
+        })
+    }
+
+    fn push_blend_mode(&mut self, blend: swf::BlendMode) {
+        self.blend_modes.push(blend);
+        self.apply_blend_mode();
+    }
+
+    fn pop_blend_mode(&mut self) {
+        self.blend_modes.pop();
+        self.apply_blend_mode();
+    }
+}
+
+/// The canvas composite operation that implements a blend mode.
+fn composite_operation(blend: swf::BlendMode) -> &'static str {
+    use swf::BlendMode;
+    match blend {
+        BlendMode::Normal | BlendMode::Layer => "source-over",
+        BlendMode::Multiply => "multiply",
+        BlendMode::Screen => "screen",
+        BlendMode::Lighten => "lighten",
+        BlendMode::Darken => "darken",
+        BlendMode::Difference => "difference",
+        BlendMode::Add => "lighter",
+        // Canvas can't subtract, but the difference is the same wherever the
+        // destination is brighter than the source.
+        BlendMode::Subtract => "difference",
+        // Inverted layers are drawn in white, so the difference is `1 - dst`.
+        BlendMode::Invert => "difference",
+        BlendMode::Alpha => "destination-in",
+        BlendMode::Erase => "destination-out",
+        BlendMode::Overlay => "overlay",
+        BlendMode::HardLight => "hard-light",
+    }
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -0,0 +1,474 @@
+//! A render backend that rasterizes entirely on the CPU.
+//!
+//! Shapes are tessellated the same way as on the GPU backends, and drawn into
+//! multisampled targets in memory. This allows frames to be rendered on
+//! machines without a GPU, such as CI runners.
+
+use ruffle_core::backend::render::{
+    Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSource, Color, RenderBackend,
+    ShapeHandle, Transform,
+};
+use ruffle_core::matrix::Matrix;
+use ruffle_core::shape_utils::DistilledShape;
+use ruffle_core::swf;
+use ruffle_render_common_tess::{Draw, DrawType as TessDrawType, ShapeTessellator};
+
+use crate::paint::{gradient_color, transform_point, Texture};
+use crate::raster::Point;
+use crate::target::{DrawState, DrawVertex, MaskState, Target};
+
+type Error = Box<dyn std::error::Error>;
+
+mod paint;
+mod raster;
+mod target;
+
+pub struct SoftwareRenderBackend {
+    /// The target currently being drawn to.
+    target: Target,
+
+    /// The size of the stage, used for every new frame.
+    viewport_dimensions: (u32, u32),
+
+    shape_tessellator: ShapeTessellator,
+    meshes: Vec<Vec<Draw>>,
+    textures: Vec<Texture>,
+
+    /// The targets that were being drawn to before each offscreen pass in
+    /// progress, the outermost being the stage.
+    offscreen_parents: Vec<OffscreenParent>,
+
+    mask_state: MaskState,
+    num_masks: u8,
+    blend_modes: Vec<swf::BlendMode>,
+}
+
+/// The state of the renderer before an offscreen pass began.
+struct OffscreenParent {
+    target: Target,
+    mask_state: MaskState,
+    num_masks: u8,
+}
+
+impl SoftwareRenderBackend {
+    pub fn new(width: u32, height: u32) -> Self {
+        let width = width.max(1);
+        let height = height.max(1);
+
+        Self {
+            target: Target::new(width, height, Color::from_rgb(0, 0)),
+            viewport_dimensions: (width, height),
+            shape_tessellator: ShapeTessellator::new(),
+            meshes: Vec::new(),
+            textures: Vec::new(),
+            offscreen_parents: Vec::new(),
+            mask_state: MaskState::NoMask,
+            num_masks: 0,
+            blend_modes: Vec::new(),
+        }
+    }
+
+    /// Read back the pixels of the most recently rendered frame.
+    ///
+    /// Like captures from the wgpu backend, the pixels have pre-multiplied alpha.
+    pub fn capture(&self) -> Bitmap {
+        let target = self
+            .offscreen_parents
+            .first()
+            .map_or(&self.target, |parent| &parent.target);
+
+        Bitmap {
+            width: target.width(),
+            height: target.height(),
+            data: BitmapFormat::Rgba(target.resolve()),
+        }
+    }
+
+    fn draw_state(&self) -> DrawState {
+        DrawState {
+            blend_mode: self.blend_modes.last().copied().unwrap_or_default(),
+            mask_state: self.mask_state,
+            num_masks: self.num_masks,
+        }
+    }
+
+    fn register_shape_internal(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> Vec<Draw> {
+        self.shape_tessellator
+            .tessellate_shape(shape, bitmap_source)
+    }
+
+    fn register_bitmap(&mut self, bitmap: Bitmap) -> BitmapInfo {
+        let handle = BitmapHandle(self.textures.len());
+        let width = bitmap.width;
+        let height = bitmap.height;
+        self.textures.push(Texture {
+            width,
+            height,
+            rgba: into_rgba(bitmap.data),
+        });
+
+        BitmapInfo {
+            handle,
+            width: width as u16,
+            height: height as u16,
+        }
+    }
+}
+
+/// Expand bitmap data to RGBA.
+fn into_rgba(data: BitmapFormat) -> Vec<u8> {
+    match data {
+        BitmapFormat::Rgba(data) => data,
+        BitmapFormat::Rgb(data) => data
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect(),
+    }
+}
+
+/// Transform a point in object space, in pixels, to target space.
+fn to_target(matrix: &Matrix, x: f32, y: f32) -> Point {
+    Point {
+        x: matrix.a * x + matrix.c * y + matrix.tx.to_pixels() as f32,
+        y: matrix.b * x + matrix.d * y + matrix.ty.to_pixels() as f32,
+    }
+}
+
+fn normalize_color(color: &Color) -> [f32; 4] {
+    [
+        f32::from(color.r) / 255.0,
+        f32::from(color.g) / 255.0,
+        f32::from(color.b) / 255.0,
+        f32::from(color.a) / 255.0,
+    ]
+}
+
+/// Apply the color transform of an object to a color without pre-multiplied
+/// alpha, and then pre-multiply it.
+fn transform_color(color: [f32; 4], mult: [f32; 4], add: [f32; 4]) -> [f32; 4] {
+    let mut out = color;
+    for (i, c) in out.iter_mut().enumerate() {
+        *c = (*c * mult[i] + add[i]).clamp(0.0, 1.0);
+    }
+    [out[0] * out[3], out[1] * out[3], out[2] * out[3], out[3]]
+}
+
+/// Apply the color transform of an object to a color with pre-multiplied
+/// alpha, such as a bitmap texel.
+///
+/// Fully transparent texels are treated as transparent black, so that the
+/// add terms of the transform (such as `alphaOffset`) still apply to them.
+fn transform_premultiplied_color(color: [f32; 4], mult: [f32; 4], add: [f32; 4]) -> [f32; 4] {
+    let alpha = color[3];
+    let color = if alpha > 0.0 {
+        [color[0] / alpha, color[1] / alpha, color[2] / alpha, alpha]
+    } else {
+        [0.0; 4]
+    };
+
+    transform_color(color, mult, add)
+}
+
+/// The vertices of a quad covering `0..width` and `0..height` in
+/// object space.
+fn quad(matrix: &Matrix, width: f32, height: f32, color: [f32; 4]) -> [DrawVertex; 4] {
+    let vertex = |x: f32, y: f32| DrawVertex {
+        position: to_target(matrix, x, y),
+        local: Point { x, y },
+        color,
+    };
+    [
+        vertex(0.0, 0.0),
+        vertex(width, 0.0),
+        vertex(width, height),
+        vertex(0.0, height),
+    ]
+}
+
+const QUAD_INDICES: [u32; 6] = [0, 1, 2, 0, 2, 3];
+
+impl RenderBackend for SoftwareRenderBackend {
+    fn set_viewport_dimensions(&mut self, width: u32, height: u32) {
+        let width = width.max(1);
+        let height = height.max(1);
+        self.viewport_dimensions = (width, height);
+
+        let stage = Target::new(width, height, Color::from_rgb(0, 0));
+        match self.offscreen_parents.first_mut() {
+            Some(parent) => parent.target = stage,
+            None => self.target = stage,
+        }
+    }
+
+    fn register_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> ShapeHandle {
+        let handle = ShapeHandle(self.meshes.len());
+        let mesh = self.register_shape_internal(shape, bitmap_source);
+        self.meshes.push(mesh);
+        handle
+    }
+
+    fn replace_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+        handle: ShapeHandle,
+    ) {
+        let mesh = self.register_shape_internal(shape, bitmap_source);
+        self.meshes[handle.0] = mesh;
+    }
+
+    fn register_glyph_shape(&mut self, glyph: &swf::Glyph) -> ShapeHandle {
+        let shape = ruffle_core::shape_utils::swf_glyph_to_shape(glyph);
+        let handle = ShapeHandle(self.meshes.len());
+        let mesh = self.register_shape_internal(
+            (&shape).into(),
+            &ruffle_core::backend::render::NullBitmapSource,
+        );
+        self.meshes.push(mesh);
+        handle
+    }
+
+    fn register_bitmap_jpeg(
+        &mut self,
+        data: &[u8],
+        jpeg_tables: Option<&[u8]>,
+    ) -> Result<BitmapInfo, Error> {
+        let data = ruffle_core::backend::render::glue_tables_to_jpeg(data, jpeg_tables);
+        self.register_bitmap_jpeg_2(&data[..])
+    }
+
+    fn register_bitmap_jpeg_2(&mut self, data: &[u8]) -> Result<BitmapInfo, Error> {
+        let bitmap = ruffle_core::backend::render::decode_define_bits_jpeg(data, None)?;
+        Ok(self.register_bitmap(bitmap))
+    }
+
+    fn register_bitmap_jpeg_3_or_4(
+        &mut self,
+        jpeg_data: &[u8],
+        alpha_data: &[u8],
+    ) -> Result<BitmapInfo, Error> {
+        let bitmap =
+            ruffle_core::backend::render::decode_define_bits_jpeg(jpeg_data, Some(alpha_data))?;
+        Ok(self.register_bitmap(bitmap))
+    }
+
+    fn register_bitmap_png(
+        &mut self,
+        swf_tag: &swf::DefineBitsLossless,
+    ) -> Result<BitmapInfo, Error> {
+        let bitmap = ruffle_core::backend::render::decode_define_bits_lossless(swf_tag)?;
+        Ok(self.register_bitmap(bitmap))
+    }
+
+    fn begin_frame(&mut self, clear: Color) {
+        self.mask_state = MaskState::NoMask;
+        self.num_masks = 0;
+
+        let (width, height) = self.viewport_dimensions;
+        self.target = Target::new(width, height, clear);
+    }
+
+    fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: &Transform, smoothing: bool) {
+        let texture = match self.textures.get(bitmap.0) {
+            Some(texture) => texture,
+            None => return,
+        };
+
+        let state = self.draw_state();
+        let mult = transform.color_transform.mult_rgba_normalized();
+        let add = transform.color_transform.add_rgba_normalized();
+        let (width, height) = (texture.width as f32, texture.height as f32);
+        let vertices = quad(&transform.matrix, width, height, [1.0; 4]);
+
+        self.target
+            .draw_triangles(state, &vertices, &QUAD_INDICES, |local, _| {
+                let color = texture.sample(local.x / width, local.y / height, smoothing, false);
+                transform_premultiplied_color(color, mult, add)
+            });
+    }
+
+    fn render_shape(&mut self, shape: ShapeHandle, transform: &Transform) {
+        let mesh = match self.meshes.get(shape.0) {
+            Some(mesh) => mesh,
+            None => return,
+        };
+
+        let state = self.draw_state();
+        let mult = transform.color_transform.mult_rgba_normalized();
+        let add = transform.color_transform.add_rgba_normalized();
+
+        for draw in mesh {
+            let vertices: Vec<_> = draw
+                .vertices
+                .iter()
+                .map(|vertex| DrawVertex {
+                    position: to_target(&transform.matrix, vertex.x, vertex.y),
+                    local: Point {
+                        x: vertex.x,
+                        y: vertex.y,
+                    },
+                    color: normalize_color(&vertex.color),
+                })
+                .collect();
+
+            match &draw.draw_type {
+                TessDrawType::Color => {
+                    self.target
+                        .draw_triangles(state, &vertices, &draw.indices, |_, color| {
+                            transform_color(color, mult, add)
+                        });
+                }
+                TessDrawType::Gradient(gradient) => {
+                    self.target
+                        .draw_triangles(state, &vertices, &draw.indices, |local, _| {
+                            let (u, v) = transform_point(&gradient.matrix, local.x, local.y);
+                            transform_color(gradient_color(gradient, u, v), mult, add)
+                        });
+                }
+                TessDrawType::Bitmap(bitmap) => {
+                    let texture = match self.textures.get(bitmap.bitmap.0) {
+                        Some(texture) => texture,
+                        None => continue,
+                    };
+                    self.target
+                        .draw_triangles(state, &vertices, &draw.indices, |local, _| {
+                            let (u, v) = transform_point(&bitmap.matrix, local.x, local.y);
+                            let color =
+                                texture.sample(u, v, bitmap.is_smoothed, bitmap.is_repeating);
+                            transform_premultiplied_color(color, mult, add)
+                        });
+                }
+            }
+        }
+    }
+
+    fn draw_rect(&mut self, color: Color, matrix: &Matrix) {
+        let state = self.draw_state();
+        let vertices = quad(matrix, 1.0, 1.0, normalize_color(&color));
+        self.target
+            .draw_triangles(state, &vertices, &QUAD_INDICES, |_, color| {
+                transform_color(color, [1.0; 4], [0.0; 4])
+            });
+    }
+
+    fn end_frame(&mut self) {
+        if !self.offscreen_parents.is_empty() {
+            log::warn!("end_frame: Offscreen render was never ended");
+        }
+    }
+
+    fn push_mask(&mut self) {
+        debug_assert!(
+            self.mask_state == MaskState::NoMask || self.mask_state == MaskState::DrawMaskedContent
+        );
+        self.num_masks = self.num_masks.saturating_add(1);
+        self.mask_state = MaskState::DrawMaskStencil;
+    }
+
+    fn activate_mask(&mut self) {
+        debug_assert!(self.num_masks > 0 && self.mask_state == MaskState::DrawMaskStencil);
+        self.mask_state = MaskState::DrawMaskedContent;
+    }
+
+    fn deactivate_mask(&mut self) {
+        debug_assert!(self.num_masks > 0 && self.mask_state == MaskState::DrawMaskedContent);
+        self.mask_state = MaskState::ClearMaskStencil;
+    }
+
+    fn pop_mask(&mut self) {
+        debug_assert!(self.num_masks > 0 && self.mask_state == MaskState::ClearMaskStencil);
+        self.num_masks = self.num_masks.saturating_sub(1);
+        self.mask_state = if self.num_masks == 0 {
+            MaskState::NoMask
+        } else {
+            MaskState::DrawMaskedContent
+        };
+    }
+
+    fn get_bitmap_pixels(&mut self, bitmap: BitmapHandle) -> Option<Bitmap> {
+        let texture = self.textures.get(bitmap.0)?;
+        Some(Bitmap {
+            width: texture.width,
+            height: texture.height,
+            data: BitmapFormat::Rgba(texture.rgba.clone()),
+        })
+    }
+
+    fn register_bitmap_raw(
+        &mut self,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    ) -> Result<BitmapHandle, Error> {
+        Ok(self
+            .register_bitmap(Bitmap {
+                width,
+                height,
+                data: BitmapFormat::Rgba(rgba),
+            })
+            .handle)
+    }
+
+    fn update_texture(
+        &mut self,
+        handle: BitmapHandle,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    ) -> Result<BitmapHandle, Error> {
+        let texture = match self.textures.get_mut(handle.0) {
+            Some(texture) => texture,
+            None => return Err("update_texture: Bitmap not registered".into()),
+        };
+
+        *texture = Texture {
+            width,
+            height,
+            rgba,
+        };
+        Ok(handle)
+    }
+
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color) {
+        let width = width.max(1);
+        let height = height.max(1);
+
+        let target = std::mem::replace(&mut self.target, Target::new(width, height, clear));
+        self.offscreen_parents.push(OffscreenParent {
+            target,
+            mask_state: self.mask_state,
+            num_masks: self.num_masks,
+        });
+        self.mask_state = MaskState::NoMask;
+        self.num_masks = 0;
+    }
+
+    fn end_offscreen(&mut self) -> Option<Bitmap> {
+        let parent = self.offscreen_parents.pop()?;
+        let target = std::mem::replace(&mut self.target, parent.target);
+        self.mask_state = parent.mask_state;
+        self.num_masks = parent.num_masks;
+
+        Some(Bitmap {
+            width: target.width(),
+            height: target.height(),
+            data: BitmapFormat::Rgba(target.resolve()),
+        })
+    }
+
+    fn push_blend_mode(&mut self, blend: swf::BlendMode) {
+        self.blend_modes.push(blend);
+    }
+
+    fn pop_blend_mode(&mut self) {
+        self.blend_modes.pop();
+    }
+}
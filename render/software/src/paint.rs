@@ -0,0 +1,166 @@
+//! Evaluation of gradient and bitmap fills.
+
+use ruffle_core::swf::{GradientInterpolation, GradientSpread};
+use ruffle_render_common_tess::{Gradient, GradientType};
+
+/// Transform a point by a column-major texture matrix, as used by the
+/// tessellator for gradient and bitmap fills.
+pub fn transform_point(matrix: &[[f32; 3]; 3], x: f32, y: f32) -> (f32, f32) {
+    (
+        matrix[0][0] * x + matrix[1][0] * y + matrix[2][0],
+        matrix[0][1] * x + matrix[1][1] * y + matrix[2][1],
+    )
+}
+
+/// Calculate the color of a gradient at the given gradient space position.
+///
+/// The returned color does not have pre-multiplied alpha.
+pub fn gradient_color(gradient: &Gradient, u: f32, v: f32) -> [f32; 4] {
+    let num_colors = gradient.num_colors.min(gradient.colors.len());
+    if num_colors == 0 {
+        return [0.0; 4];
+    }
+    let last = num_colors - 1;
+
+    // Calculate normalized `t` position in gradient, [0.0, 1.0] being the bounds of the ratios.
+    let (x, y) = (u * 2.0 - 1.0, v * 2.0 - 1.0);
+    let t = match gradient.gradient_type {
+        GradientType::Linear => u,
+        GradientType::Radial => (x * x + y * y).sqrt(),
+        GradientType::Focal => {
+            let focal_point = gradient.focal_point.to_f32();
+            let (dx, dy) = (focal_point - x, -y);
+            let l = (dx * dx + dy * dy).sqrt();
+            let (dx, dy) = (dx / l, dy / l);
+            l / ((1.0 - focal_point * focal_point * dy * dy).sqrt() + focal_point * dx)
+        }
+    };
+
+    // Tweak out-of-bounds `t` based on the repeat mode.
+    let t = match gradient.repeat_mode {
+        GradientSpread::Repeat => t.rem_euclid(1.0),
+        GradientSpread::Reflect => {
+            let t = t.abs();
+            if (t as i32) & 1 == 0 {
+                t.fract()
+            } else {
+                1.0 - t.fract()
+            }
+        }
+        GradientSpread::Pad => t.clamp(0.0, 1.0),
+    };
+    // NaN positions (such as the focal point itself) take the first color.
+    let t = if t.is_nan() { 0.0 } else { t };
+
+    let ratios = &gradient.ratios[..num_colors];
+    let colors = &gradient.colors[..num_colors];
+    let t = t.clamp(ratios[0], ratios[last]);
+
+    // Find the two gradient colors bordering our position.
+    let j = ratios
+        .iter()
+        .skip(1)
+        .position(|&ratio| t <= ratio)
+        .map_or(last, |i| i + 1);
+    let i = j.saturating_sub(1);
+
+    // Lerp between the two colors.
+    let span = ratios[j] - ratios[i];
+    let a = if span > 0.0 {
+        (t - ratios[i]) / span
+    } else {
+        0.0
+    };
+    let mut color = [0.0; 4];
+    for (c, (from, to)) in color.iter_mut().zip(colors[i].iter().zip(colors[j].iter())) {
+        *c = from + (to - from) * a;
+    }
+
+    if gradient.interpolation == GradientInterpolation::LinearRgb {
+        color = linear_to_srgb(color);
+    }
+    color
+}
+
+/// Converts a color from linear to sRGB color space.
+fn linear_to_srgb(color: [f32; 4]) -> [f32; 4] {
+    fn to_srgb_channel(n: f32) -> f32 {
+        if n < 0.0031308 {
+            n * 12.92
+        } else {
+            1.055 * n.powf(1.0 / 2.4) - 0.055
+        }
+    }
+    [
+        to_srgb_channel(color[0]),
+        to_srgb_channel(color[1]),
+        to_srgb_channel(color[2]),
+        color[3],
+    ]
+}
+
+/// A registered bitmap.
+pub struct Texture {
+    pub width: u32,
+    pub height: u32,
+
+    /// The pixels of the bitmap, with pre-multiplied alpha.
+    pub rgba: Vec<u8>,
+}
+
+impl Texture {
+    /// Sample the bitmap at the given normalized position.
+    ///
+    /// Smoothed bitmaps are sampled bilinearly, and others take the nearest
+    /// pixel. Positions outside of the bitmap either wrap around or are
+    /// clamped to its edges. The returned color has pre-multiplied alpha.
+    pub fn sample(&self, u: f32, v: f32, is_smoothed: bool, is_repeating: bool) -> [f32; 4] {
+        if self.width == 0 || self.height == 0 || !u.is_finite() || !v.is_finite() {
+            return [0.0; 4];
+        }
+
+        let x = u * self.width as f32;
+        let y = v * self.height as f32;
+        if !is_smoothed {
+            return self.texel(x.floor() as i64, y.floor() as i64, is_repeating);
+        }
+
+        let (x, y) = (x - 0.5, y - 0.5);
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+
+        let top_left = self.texel(x0, y0, is_repeating);
+        let top_right = self.texel(x0 + 1, y0, is_repeating);
+        let bottom_left = self.texel(x0, y0 + 1, is_repeating);
+        let bottom_right = self.texel(x0 + 1, y0 + 1, is_repeating);
+
+        let mut color = [0.0; 4];
+        for (i, c) in color.iter_mut().enumerate() {
+            let top = top_left[i] + (top_right[i] - top_left[i]) * fx;
+            let bottom = bottom_left[i] + (bottom_right[i] - bottom_left[i]) * fx;
+            *c = top + (bottom - top) * fy;
+        }
+        color
+    }
+
+    fn texel(&self, x: i64, y: i64, is_repeating: bool) -> [f32; 4] {
+        let (width, height) = (i64::from(self.width), i64::from(self.height));
+        let (x, y) = if is_repeating {
+            (x.rem_euclid(width), y.rem_euclid(height))
+        } else {
+            (x.clamp(0, width - 1), y.clamp(0, height - 1))
+        };
+
+        let i = (y * width + x) as usize * 4;
+        match self.rgba.get(i..i + 4) {
+            Some(texel) => [
+                f32::from(texel[0]) / 255.0,
+                f32::from(texel[1]) / 255.0,
+                f32::from(texel[2]) / 255.0,
+                f32::from(texel[3]) / 255.0,
+            ],
+            None => [0.0; 4],
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Triangle rasterization.
+
+/// The number of samples taken for each pixel.
+pub const SAMPLE_COUNT: usize = 4;
+
+/// The offset of each sample from the top-left corner of its pixel.
+///
+/// This is the standard 4x MSAA pattern, so that edges are antialiased the
+/// same way as on GPU backends.
+const SAMPLE_POSITIONS: [(f32, f32); SAMPLE_COUNT] = [
+    (0.375, 0.125),
+    (0.875, 0.375),
+    (0.125, 0.625),
+    (0.625, 0.875),
+];
+
+/// A point in target space, in pixels.
+#[derive(Copy, Clone, Debug)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Calculate twice the signed area of the triangle `a`, `b`, `(x, y)`.
+///
+/// This is positive when the point is to the right of the edge from `a` to `b`.
+fn edge(a: Point, b: Point, x: f32, y: f32) -> f32 {
+    (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+}
+
+/// Whether samples lying exactly on the edge from `a` to `b` belong to the
+/// triangle.
+///
+/// An edge shared by two triangles runs in opposite directions in each of
+/// them, so exactly one of them owns samples that lie on it.
+fn owns_edge(a: Point, b: Point) -> bool {
+    let dy = b.y - a.y;
+    dy > 0.0 || (dy == 0.0 && b.x > a.x)
+}
+
+/// Rasterize a triangle onto a `width` by `height` target.
+///
+/// `f` is called for every pixel with at least one sample inside the triangle,
+/// with the pixel's position, a mask with a bit set for each covered sample,
+/// and the barycentric weights of the pixel center relative to each vertex.
+/// Pixel centers are not clamped to the triangle, so the weights may be
+/// outside of `0.0..=1.0` along its edges.
+pub fn rasterize_triangle(
+    width: u32,
+    height: u32,
+    vertices: [Point; 3],
+    mut f: impl FnMut(u32, u32, u8, [f32; 3]),
+) {
+    let [v0, mut v1, mut v2] = vertices;
+    let mut area = edge(v0, v1, v2.x, v2.y);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+
+    // Wind every triangle the same way, so that the edge functions of
+    // interior samples are all positive.
+    let flipped = area < 0.0;
+    if flipped {
+        std::mem::swap(&mut v1, &mut v2);
+        area = -area;
+    }
+    let owns = [owns_edge(v1, v2), owns_edge(v2, v0), owns_edge(v0, v1)];
+
+    let min_x = v0.x.min(v1.x).min(v2.x).floor().max(0.0);
+    let min_y = v0.y.min(v1.y).min(v2.y).floor().max(0.0);
+    let max_x = v0.x.max(v1.x).max(v2.x).ceil().min(width as f32);
+    let max_y = v0.y.max(v1.y).max(v2.y).ceil().min(height as f32);
+    if min_x >= max_x || min_y >= max_y {
+        return;
+    }
+
+    let inside = |w: f32, owned: bool| w > 0.0 || (w == 0.0 && owned);
+    for y in min_y as u32..max_y as u32 {
+        for x in min_x as u32..max_x as u32 {
+            let mut mask = 0;
+            for (i, (sx, sy)) in SAMPLE_POSITIONS.iter().enumerate() {
+                let (px, py) = (x as f32 + sx, y as f32 + sy);
+                if inside(edge(v1, v2, px, py), owns[0])
+                    && inside(edge(v2, v0, px, py), owns[1])
+                    && inside(edge(v0, v1, px, py), owns[2])
+                {
+                    mask |= 1 << i;
+                }
+            }
+            if mask == 0 {
+                continue;
+            }
+
+            let (cx, cy) = (x as f32 + 0.5, y as f32 + 0.5);
+            let w0 = edge(v1, v2, cx, cy) / area;
+            let w1 = edge(v2, v0, cx, cy) / area;
+            let w2 = 1.0 - w0 - w1;
+            let weights = if flipped { [w0, w2, w1] } else { [w0, w1, w2] };
+            f(x, y, mask, weights);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f32, y: f32) -> Point {
+        Point { x, y }
+    }
+
+    #[test]
+    fn shared_edges_are_covered_once() {
+        // Two triangles forming a square, split along a diagonal that passes
+        // exactly through samples.
+        let quad = [
+            point(0.0, 0.0),
+            point(8.0, 0.0),
+            point(8.0, 8.0),
+            point(0.0, 8.0),
+        ];
+        let mut coverage = [0u8; 64];
+        for [a, b, c] in [[0, 1, 2], [0, 2, 3]] {
+            rasterize_triangle(8, 8, [quad[a], quad[b], quad[c]], |x, y, mask, _| {
+                let index = (y * 8 + x) as usize;
+                assert_eq!(coverage[index] & mask, 0, "sample covered twice");
+                coverage[index] |= mask;
+            });
+        }
+        assert!(coverage.iter().all(|&mask| mask == 0b1111));
+    }
+
+    #[test]
+    fn winding_does_not_change_weights() {
+        let vertices = [point(0.0, 0.0), point(4.0, 0.0), point(0.0, 4.0)];
+        let mut clockwise = Vec::new();
+        rasterize_triangle(4, 4, vertices, |x, y, mask, weights| {
+            clockwise.push((x, y, mask, weights))
+        });
+
+        let mut counter_clockwise = Vec::new();
+        rasterize_triangle(
+            4,
+            4,
+            [vertices[0], vertices[2], vertices[1]],
+            |x, y, mask, [w0, w1, w2]| counter_clockwise.push((x, y, mask, [w0, w2, w1])),
+        );
+
+        assert!(!clockwise.is_empty());
+        assert_eq!(clockwise.len(), counter_clockwise.len());
+        for (a, b) in clockwise.iter().zip(counter_clockwise.iter()) {
+            assert_eq!((a.0, a.1, a.2), (b.0, b.1, b.2));
+            for (wa, wb) in a.3.iter().zip(b.3.iter()) {
+                assert!((wa - wb).abs() < 1e-5);
+            }
+        }
+    }
+}
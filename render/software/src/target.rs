@@ -0,0 +1,181 @@
+//! Multisampled render targets.
+
+use crate::raster::{rasterize_triangle, Point, SAMPLE_COUNT};
+use ruffle_core::backend::render::Color;
+use ruffle_core::bitmap::blend::blend;
+use ruffle_core::swf::BlendMode;
+
+/// How draws interact with the stencil buffer, mirroring the stencil states
+/// of the wgpu backend.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MaskState {
+    NoMask,
+    DrawMaskStencil,
+    DrawMaskedContent,
+    ClearMaskStencil,
+}
+
+/// The state that a draw is written to a target with.
+#[derive(Copy, Clone, Debug)]
+pub struct DrawState {
+    pub blend_mode: BlendMode,
+    pub mask_state: MaskState,
+    pub num_masks: u8,
+}
+
+/// A vertex of a mesh being drawn.
+#[derive(Copy, Clone, Debug)]
+pub struct DrawVertex {
+    /// The position of the vertex in target space.
+    pub position: Point,
+
+    /// The position of the vertex in object space.
+    pub local: Point,
+
+    /// The color of the vertex, without pre-multiplied alpha.
+    pub color: [f32; 4],
+}
+
+/// An image that draws are rendered into.
+///
+/// Every pixel holds `SAMPLE_COUNT` samples, which are averaged together when
+/// the target is resolved.
+pub struct Target {
+    width: u32,
+    height: u32,
+
+    /// The color of every sample, with pre-multiplied alpha.
+    samples: Vec<[u8; 4]>,
+
+    /// The mask depth of every sample.
+    stencil: Vec<u8>,
+}
+
+impl Target {
+    pub fn new(width: u32, height: u32, clear: Color) -> Self {
+        let alpha = u16::from(clear.a);
+        let premultiply = |c: u8| (u16::from(c) * alpha / 255) as u8;
+        let clear = [
+            premultiply(clear.r),
+            premultiply(clear.g),
+            premultiply(clear.b),
+            clear.a,
+        ];
+
+        let num_samples = width as usize * height as usize * SAMPLE_COUNT;
+        Self {
+            width,
+            height,
+            samples: vec![clear; num_samples],
+            stencil: vec![0; num_samples],
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Draw a list of triangles.
+    ///
+    /// `shade` is called once for every covered pixel, with the interpolated
+    /// object space position and color at the pixel center, and returns the
+    /// color of the pixel with pre-multiplied alpha.
+    pub fn draw_triangles(
+        &mut self,
+        state: DrawState,
+        vertices: &[DrawVertex],
+        indices: &[u32],
+        mut shade: impl FnMut(Point, [f32; 4]) -> [f32; 4],
+    ) {
+        let (width, height) = (self.width, self.height);
+        for triangle in indices.chunks_exact(3) {
+            let vertex = |i: u32| vertices.get(i as usize);
+            let (v0, v1, v2) = match (
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+            ) {
+                (Some(v0), Some(v1), Some(v2)) => (v0, v1, v2),
+                _ => continue,
+            };
+
+            let positions = [v0.position, v1.position, v2.position];
+            rasterize_triangle(width, height, positions, |x, y, mask, [w0, w1, w2]| {
+                let writes_color = match state.mask_state {
+                    MaskState::NoMask | MaskState::DrawMaskedContent => true,
+                    MaskState::DrawMaskStencil | MaskState::ClearMaskStencil => false,
+                };
+                let color = if writes_color {
+                    let local = Point {
+                        x: v0.local.x * w0 + v1.local.x * w1 + v2.local.x * w2,
+                        y: v0.local.y * w0 + v1.local.y * w1 + v2.local.y * w2,
+                    };
+                    let mut color = [0.0; 4];
+                    for (i, c) in color.iter_mut().enumerate() {
+                        *c = (v0.color[i] * w0 + v1.color[i] * w1 + v2.color[i] * w2)
+                            .clamp(0.0, 1.0);
+                    }
+                    shade(local, color)
+                } else {
+                    [0.0; 4]
+                };
+
+                self.write_pixel(x, y, mask, color, state);
+            });
+        }
+    }
+
+    /// Write a color to the covered samples of a pixel.
+    fn write_pixel(&mut self, x: u32, y: u32, mask: u8, color: [f32; 4], state: DrawState) {
+        let pixel = (y as usize * self.width as usize + x as usize) * SAMPLE_COUNT;
+        for sample in 0..SAMPLE_COUNT {
+            if mask & (1 << sample) == 0 {
+                continue;
+            }
+
+            let i = pixel + sample;
+            let stencil = &mut self.stencil[i];
+            match state.mask_state {
+                MaskState::NoMask => (),
+                MaskState::DrawMaskStencil => {
+                    if state.num_masks > 0 && *stencil == state.num_masks - 1 {
+                        *stencil = stencil.saturating_add(1);
+                    }
+                    continue;
+                }
+                MaskState::DrawMaskedContent => {
+                    if *stencil != state.num_masks {
+                        continue;
+                    }
+                }
+                MaskState::ClearMaskStencil => {
+                    if *stencil == state.num_masks {
+                        *stencil = stencil.saturating_sub(1);
+                    }
+                    continue;
+                }
+            }
+
+            let dst = self.samples[i].map(|c| f32::from(c) / 255.0);
+            let out = blend(state.blend_mode, color, dst);
+            self.samples[i] = out.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        }
+    }
+
+    /// Average the samples of every pixel together, returning the pixels of
+    /// the target with pre-multiplied alpha.
+    pub fn resolve(&self) -> Vec<u8> {
+        let mut rgba = Vec::with_capacity(self.width as usize * self.height as usize * 4);
+        for pixel in self.samples.chunks_exact(SAMPLE_COUNT) {
+            for channel in 0..4 {
+                let sum: u32 = pixel.iter().map(|sample| u32::from(sample[channel])).sum();
+                rgba.push(((sum + SAMPLE_COUNT as u32 / 2) / SAMPLE_COUNT as u32) as u8);
+            }
+        }
+        rgba
+    }
+}
@@ -86,7 +86,10 @@ pub struct WebGlRenderBackend {
     mask_state_dirty: bool,
 
     active_program: *const ShaderProgram,
-    blend_func: (u32, u32),
+    blend_func: BlendFunc,
+    blend_modes: Vec<swf::BlendMode>,
+    /// Whether the `MIN` and `MAX` blend equations are available.
+    has_blend_minmax: bool,
     mult_color: Option<[f32; 4]>,
     add_color: Option<[f32; 4]>,
 
@@ -95,6 +98,9 @@ pub struct WebGlRenderBackend {
     view_matrix: [[f32; 4]; 4],
 
     bitmap_registry: HashMap<BitmapHandle, Bitmap>,
+
+    /// The offscreen passes in progress, innermost last.
+    offscreen_targets: Vec<OffscreenTarget>,
 }
 
 const MAX_GRADIENT_COLORS: usize = 15;
@@ -115,7 +121,7 @@ impl WebGlRenderBackend {
         }
 
         // Attempt to create a WebGL2 context, but fall back to WebGL1 if unavailable.
-        let (gl, gl2, vao_ext, msaa_sample_count) = if let Ok(Some(gl)) =
+        let (gl, gl2, vao_ext, msaa_sample_count, has_blend_minmax) = if let Ok(Some(gl)) =
             canvas.get_context_with_context_options("webgl2", &context_options)
         {
             log::info!("Creating WebGL2 context.");
@@ -145,6 +151,7 @@ impl WebGlRenderBackend {
                 Some(gl2),
                 JsValue::UNDEFINED.unchecked_into(),
                 msaa_sample_count,
+                true,
             )
         } else {
             // Fall back to WebGL1.
@@ -173,7 +180,10 @@ impl WebGlRenderBackend {
                     .get_extension("OES_element_index_uint")
                     .into_js_result()?
                     .ok_or("OES_element_index_uint extension not available")?;
-                (gl, None, vao, 1)
+
+                // Used for the darken and lighten blend modes.
+                let has_blend_minmax = matches!(gl.get_extension("EXT_blend_minmax"), Ok(Some(_)));
+                (gl, None, vao, 1, has_blend_minmax)
             } else {
                 return Err("Unable to create WebGL rendering context".into());
             }
@@ -201,8 +211,9 @@ impl WebGlRenderBackend {
         let bitmap_program = ShaderProgram::new(&gl, &texture_vertex, &bitmap_fragment)?;
         let gradient_program = ShaderProgram::new(&gl, &texture_vertex, &gradient_fragment)?;
 
+        let blend_func = blend_func(swf::BlendMode::Normal, Gl::SRC_ALPHA, has_blend_minmax);
         gl.enable(Gl::BLEND);
-        gl.blend_func(Gl::SRC_ALPHA, Gl::ONE_MINUS_SRC_ALPHA);
+        apply_blend_func(&gl, blend_func);
 
         // Necessary to load RGB textures (alignment defaults to 4).
         gl.pixel_storei(Gl::UNPACK_ALIGNMENT, 1);
@@ -234,10 +245,13 @@ impl WebGlRenderBackend {
             mask_state_dirty: true,
 
             active_program: std::ptr::null(),
-            blend_func: (Gl::SRC_ALPHA, Gl::ONE_MINUS_SRC_ALPHA),
+            blend_func,
+            blend_modes: vec![],
+            has_blend_minmax,
             mult_color: None,
             add_color: None,
             bitmap_registry: HashMap::new(),
+            offscreen_targets: vec![],
         };
 
         let color_quad_mesh = renderer.build_quad_mesh(&renderer.color_program)?;
@@ -608,6 +622,11 @@ impl WebGlRenderBackend {
         };
     }
 
+    /// The blend mode that draws are currently blended with.
+    fn blend_mode(&self) -> swf::BlendMode {
+        self.blend_modes.last().copied().unwrap_or_default()
+    }
+
     fn set_stencil_state(&mut self) {
         // Set stencil state for masking, if necessary.
         if self.mask_state_dirty {
@@ -928,8 +947,7 @@ impl RenderBackend for WebGlRenderBackend {
 
             self.bind_vertex_array(Some(&draw.vao));
 
-            let (program, src_blend, dst_blend) =
-                (&self.bitmap_program, Gl::ONE, Gl::ONE_MINUS_SRC_ALPHA);
+            let (program, src_blend) = (&self.bitmap_program, Gl::ONE);
 
             // Set common render state, while minimizing unnecessary state changes.
             // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
@@ -942,11 +960,12 @@ impl RenderBackend for WebGlRenderBackend {
 
                 self.mult_color = None;
                 self.add_color = None;
+            }
 
-                if (src_blend, dst_blend) != self.blend_func {
-                    self.gl.blend_func(src_blend, dst_blend);
-                    self.blend_func = (src_blend, dst_blend);
-                }
+            let blend_func = blend_func(self.blend_mode(), src_blend, self.has_blend_minmax);
+            if blend_func != self.blend_func {
+                apply_blend_func(&self.gl, blend_func);
+                self.blend_func = blend_func;
             }
 
             program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
@@ -1011,15 +1030,11 @@ impl RenderBackend for WebGlRenderBackend {
         for draw in &mesh.draws {
             self.bind_vertex_array(Some(&draw.vao));
 
-            let (program, src_blend, dst_blend) = match &draw.draw_type {
-                DrawType::Color => (&self.color_program, Gl::SRC_ALPHA, Gl::ONE_MINUS_SRC_ALPHA),
-                DrawType::Gradient(_) => (
-                    &self.gradient_program,
-                    Gl::SRC_ALPHA,
-                    Gl::ONE_MINUS_SRC_ALPHA,
-                ),
+            let (program, src_blend) = match &draw.draw_type {
+                DrawType::Color => (&self.color_program, Gl::SRC_ALPHA),
+                DrawType::Gradient(_) => (&self.gradient_program, Gl::SRC_ALPHA),
                 // Bitmaps use pre-multiplied alpha.
-                DrawType::Bitmap { .. } => (&self.bitmap_program, Gl::ONE, Gl::ONE_MINUS_SRC_ALPHA),
+                DrawType::Bitmap { .. } => (&self.bitmap_program, Gl::ONE),
             };
 
             // Set common render state, while minimizing unnecessary state changes.
@@ -1033,11 +1048,12 @@ impl RenderBackend for WebGlRenderBackend {
 
                 self.mult_color = None;
                 self.add_color = None;
+            }
 
-                if (src_blend, dst_blend) != self.blend_func {
-                    self.gl.blend_func(src_blend, dst_blend);
-                    self.blend_func = (src_blend, dst_blend);
-                }
+            let blend_func = blend_func(self.blend_mode(), src_blend, self.has_blend_minmax);
+            if blend_func != self.blend_func {
+                apply_blend_func(&self.gl, blend_func);
+                self.blend_func = blend_func;
             }
 
             program.uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
@@ -1164,7 +1180,6 @@ impl RenderBackend for WebGlRenderBackend {
 
         let program = &self.color_program;
         let src_blend = Gl::SRC_ALPHA;
-        let dst_blend = Gl::ONE_MINUS_SRC_ALPHA;
 
         // Set common render state, while minimizing unnecessary state changes.
         // TODO: Using designated layout specifiers in WebGL2/OpenGL ES 3, we could guarantee that uniforms
@@ -1177,13 +1192,14 @@ impl RenderBackend for WebGlRenderBackend {
 
             self.mult_color = None;
             self.add_color = None;
-
-            if (src_blend, dst_blend) != self.blend_func {
-                self.gl.blend_func(src_blend, dst_blend);
-                self.blend_func = (src_blend, dst_blend);
-            }
         };
 
+        let blend_func = blend_func(self.blend_mode(), src_blend, self.has_blend_minmax);
+        if blend_func != self.blend_func {
+            apply_blend_func(&self.gl, blend_func);
+            self.blend_func = blend_func;
+        }
+
         self.color_program
             .uniform_matrix4fv(&self.gl, ShaderUniform::WorldMatrix, &world_matrix);
         if Some(mult_color) != self.mult_color {
@@ -1290,6 +1306,268 @@ impl RenderBackend for WebGlRenderBackend {
 
         Ok(handle)
     }
+
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color) {
+        let target = match OffscreenTarget::new(
+            &self.gl,
+            width,
+            height,
+            self.view_matrix,
+            self.mask_state,
+            self.num_masks,
+        ) {
+            Ok(target) => target,
+            Err(e) => {
+                log::error!("Couldn't create offscreen render target: {}", e);
+                return;
+            }
+        };
+
+        self.gl
+            .bind_framebuffer(Gl::FRAMEBUFFER, Some(&target.framebuffer));
+        self.gl.viewport(0, 0, width as i32, height as i32);
+        self.offscreen_targets.push(target);
+
+        self.view_matrix = [
+            [1.0 / (width as f32 / 2.0), 0.0, 0.0, 0.0],
+            [0.0, -1.0 / (height as f32 / 2.0), 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [-1.0, 1.0, 0.0, 1.0],
+        ];
+        self.active_program = std::ptr::null();
+        self.mask_state = MaskState::NoMask;
+        self.num_masks = 0;
+        self.mask_state_dirty = true;
+        self.mult_color = None;
+        self.add_color = None;
+
+        self.set_stencil_state();
+        self.gl.clear_color(
+            clear.r as f32 / 255.0,
+            clear.g as f32 / 255.0,
+            clear.b as f32 / 255.0,
+            clear.a as f32 / 255.0,
+        );
+        self.gl.stencil_mask(0xff);
+        self.gl.clear(Gl::COLOR_BUFFER_BIT | Gl::STENCIL_BUFFER_BIT);
+    }
+
+    fn end_offscreen(&mut self) -> Option<Bitmap> {
+        let target = self.offscreen_targets.pop()?;
+
+        let row_len = target.width as usize * 4;
+        let mut pixels = vec![0; row_len * target.height as usize];
+        let result = self.gl.read_pixels_with_opt_u8_array(
+            0,
+            0,
+            target.width as i32,
+            target.height as i32,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            Some(&mut pixels),
+        );
+
+        // Return to whatever we were rendering to before.
+        let (framebuffer, width, height) = match self.offscreen_targets.last() {
+            Some(parent) => (
+                Some(&parent.framebuffer),
+                parent.width as i32,
+                parent.height as i32,
+            ),
+            None => (
+                self.msaa_buffers.as_ref().map(|b| &b.render_framebuffer),
+                self.renderbuffer_width,
+                self.renderbuffer_height,
+            ),
+        };
+        self.gl.bind_framebuffer(Gl::FRAMEBUFFER, framebuffer);
+        self.gl.viewport(0, 0, width, height);
+
+        self.view_matrix = target.view_matrix;
+        self.mask_state = target.mask_state;
+        self.num_masks = target.num_masks;
+        self.mask_state_dirty = true;
+        self.active_program = std::ptr::null();
+        self.mult_color = None;
+        self.add_color = None;
+        target.delete(&self.gl);
+
+        if let Err(e) = result {
+            log::error!("Couldn't read back offscreen render target: {:?}", e);
+            return None;
+        }
+
+        // GL reads rows from the bottom up.
+        let pixels = pixels
+            .chunks_exact(row_len)
+            .rev()
+            .flatten()
+            .copied()
+            .collect();
+
+        Some(Bitmap {
+            width: target.width,
+            height: target.height,
+            data: BitmapFormat::Rgba(pixels),
+        })
+    }
+
+    fn push_blend_mode(&mut self, blend: swf::BlendMode) {
+        self.blend_modes.push(blend);
+    }
+
+    fn pop_blend_mode(&mut self) {
+        self.blend_modes.pop();
+    }
+}
+
+/// The blend equations and factors for a draw, as
+/// `(rgb_equation, alpha_equation, src_rgb, dst_rgb, src_alpha, dst_alpha)`.
+type BlendFunc = (u32, u32, u32, u32, u32, u32);
+
+/// The blend function that implements a blend mode.
+///
+/// `src_factor` is the factor that blends source colors normally: `ONE` for
+/// pre-multiplied colors, or `SRC_ALPHA` otherwise. Difference, overlay and
+/// hard light depend on the destination color in ways that blend functions
+/// can't express, so they blend normally. So does darken: `MIN` ignores the
+/// blend factors, which would darken the destination under transparent
+/// source pixels. Lighten uses `MAX`, which is only exact where the source is
+/// either opaque or fully transparent.
+fn blend_func(blend: swf::BlendMode, src_factor: u32, has_blend_minmax: bool) -> BlendFunc {
+    use swf::BlendMode;
+    let normal = (
+        Gl::FUNC_ADD,
+        Gl::FUNC_ADD,
+        src_factor,
+        Gl::ONE_MINUS_SRC_ALPHA,
+        src_factor,
+        Gl::ONE_MINUS_SRC_ALPHA,
+    );
+    let (rgb_equation, src_rgb, dst_rgb) = match blend {
+        BlendMode::Multiply => (Gl::FUNC_ADD, Gl::DST_COLOR, Gl::ONE_MINUS_SRC_ALPHA),
+        BlendMode::Screen => (Gl::FUNC_ADD, src_factor, Gl::ONE_MINUS_SRC_COLOR),
+        BlendMode::Add => (Gl::FUNC_ADD, src_factor, Gl::ONE),
+        BlendMode::Subtract => (Gl::FUNC_REVERSE_SUBTRACT, src_factor, Gl::ONE),
+        BlendMode::Lighten if has_blend_minmax => (Gl2::MAX, Gl::ONE, Gl::ONE),
+        BlendMode::Invert => (
+            Gl::FUNC_ADD,
+            Gl::ONE_MINUS_DST_COLOR,
+            Gl::ONE_MINUS_SRC_ALPHA,
+        ),
+        BlendMode::Alpha => (Gl::FUNC_ADD, Gl::ZERO, Gl::SRC_ALPHA),
+        BlendMode::Erase => (Gl::FUNC_ADD, Gl::ZERO, Gl::ONE_MINUS_SRC_ALPHA),
+        _ => return normal,
+    };
+    match blend {
+        // These modes change the destination alpha as well.
+        BlendMode::Alpha | BlendMode::Erase => (
+            rgb_equation,
+            rgb_equation,
+            src_rgb,
+            dst_rgb,
+            src_rgb,
+            dst_rgb,
+        ),
+        _ => (rgb_equation, normal.1, src_rgb, dst_rgb, normal.4, normal.5),
+    }
+}
+
+fn apply_blend_func(gl: &Gl, blend_func: BlendFunc) {
+    let (rgb_equation, alpha_equation, src_rgb, dst_rgb, src_alpha, dst_alpha) = blend_func;
+    gl.blend_equation_separate(rgb_equation, alpha_equation);
+    gl.blend_func_separate(src_rgb, dst_rgb, src_alpha, dst_alpha);
+}
+
+/// A framebuffer used for an offscreen pass, along with the state to restore
+/// once the pass ends.
+struct OffscreenTarget {
+    framebuffer: WebGlFramebuffer,
+    texture: WebGlTexture,
+    stencil_renderbuffer: WebGlRenderbuffer,
+    width: u32,
+    height: u32,
+    view_matrix: [[f32; 4]; 4],
+    mask_state: MaskState,
+    num_masks: u32,
+}
+
+impl OffscreenTarget {
+    fn new(
+        gl: &Gl,
+        width: u32,
+        height: u32,
+        view_matrix: [[f32; 4]; 4],
+        mask_state: MaskState,
+        num_masks: u32,
+    ) -> Result<Self, Error> {
+        let texture = gl.create_texture().ok_or("Unable to create texture")?;
+        gl.bind_texture(Gl::TEXTURE_2D, Some(&texture));
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAG_FILTER, Gl::NEAREST as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MIN_FILTER, Gl::NEAREST as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
+        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
+        gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array(
+            Gl::TEXTURE_2D,
+            0,
+            Gl::RGBA as i32,
+            width as i32,
+            height as i32,
+            0,
+            Gl::RGBA,
+            Gl::UNSIGNED_BYTE,
+            None,
+        )
+        .into_js_result()?;
+        gl.bind_texture(Gl::TEXTURE_2D, None);
+
+        let stencil_renderbuffer = gl
+            .create_renderbuffer()
+            .ok_or("Unable to create renderbuffer")?;
+        gl.bind_renderbuffer(Gl::RENDERBUFFER, Some(&stencil_renderbuffer));
+        gl.renderbuffer_storage(
+            Gl::RENDERBUFFER,
+            Gl::STENCIL_INDEX8,
+            width as i32,
+            height as i32,
+        );
+        gl.bind_renderbuffer(Gl::RENDERBUFFER, None);
+
+        let framebuffer = gl
+            .create_framebuffer()
+            .ok_or("Unable to create framebuffer")?;
+        gl.bind_framebuffer(Gl::FRAMEBUFFER, Some(&framebuffer));
+        gl.framebuffer_texture_2d(
+            Gl::FRAMEBUFFER,
+            Gl::COLOR_ATTACHMENT0,
+            Gl::TEXTURE_2D,
+            Some(&texture),
+            0,
+        );
+        gl.framebuffer_renderbuffer(
+            Gl::FRAMEBUFFER,
+            Gl::STENCIL_ATTACHMENT,
+            Gl::RENDERBUFFER,
+            Some(&stencil_renderbuffer),
+        );
+
+        Ok(Self {
+            framebuffer,
+            texture,
+            stencil_renderbuffer,
+            width,
+            height,
+            view_matrix,
+            mask_state,
+            num_masks,
+        })
+    }
+
+    fn delete(&self, gl: &Gl) {
+        gl.delete_framebuffer(Some(&self.framebuffer));
+        gl.delete_renderbuffer(Some(&self.stencil_renderbuffer));
+        gl.delete_texture(Some(&self.texture));
+    }
 }
 
 struct Texture {
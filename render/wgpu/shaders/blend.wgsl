@@ -0,0 +1,84 @@
+/// Shader used for drawing a layer with a complex blend mode.
+/// The blend is selected by `blend_mode`, which is prepended to this shader.
+/// The color beneath the layer is read from a copy of the target.
+
+struct VertexOutput {
+    [[builtin(position)]] position: vec4<f32>;
+    [[location(0)]] uv: vec2<f32>;
+};
+
+[[group(2), binding(0)]]
+var<uniform> textureTransforms: TextureTransforms;
+[[group(2), binding(1)]]
+var texture: texture_2d<f32>;
+[[group(2), binding(2)]]
+var parent_texture: texture_2d<f32>;
+[[group(3), binding(0)]]
+var texture_sampler: sampler;
+
+[[stage(vertex)]]
+fn main_vertex(in: VertexInput) -> VertexOutput {
+    let matrix = textureTransforms.matrix;
+    let uv = (mat3x3<f32>(matrix[0].xyz, matrix[1].xyz, matrix[2].xyz) * vec3<f32>(in.position, 1.0)).xy;
+    let pos = globals.view_matrix * transforms.world_matrix * vec4<f32>(in.position.x, in.position.y, 0.0, 1.0);
+    return VertexOutput(pos, uv);
+}
+
+fn unmultiply(color: vec4<f32>) -> vec3<f32> {
+    if (color.a > 0.0) {
+        return color.rgb / color.a;
+    }
+    return vec3<f32>(0.0);
+}
+
+fn hard_light(src: vec3<f32>, dst: vec3<f32>) -> vec3<f32> {
+    let screen_src = 2.0 * src - 1.0;
+    let screen = screen_src + dst - screen_src * dst;
+    return select(screen, 2.0 * src * dst, src <= vec3<f32>(0.5));
+}
+
+/// Mix the un-multiplied source and destination colors.
+fn blend(src: vec3<f32>, dst: vec3<f32>) -> vec3<f32> {
+    // Multiply
+    if (blend_mode == 0) {
+        return src * dst;
+    }
+    // Lighten
+    if (blend_mode == 1) {
+        return max(src, dst);
+    }
+    // Darken
+    if (blend_mode == 2) {
+        return min(src, dst);
+    }
+    // Difference
+    if (blend_mode == 3) {
+        return abs(src - dst);
+    }
+    // Overlay
+    if (blend_mode == 4) {
+        return hard_light(dst, src);
+    }
+    // Hard light
+    return hard_light(src, dst);
+}
+
+[[stage(fragment)]]
+fn main_fragment(in: VertexOutput) -> [[location(0)]] vec4<f32> {
+    var src: vec4<f32> = textureSample(texture, texture_sampler, in.uv);
+    // Texture is premultiplied by alpha.
+    // Unmultiply alpha, apply color transform, remultiply alpha.
+    if( src.a > 0.0 ) {
+        src = vec4<f32>(src.rgb / src.a, src.a);
+        src = src * transforms.mult_color + transforms.add_color;
+        src = vec4<f32>(src.rgb * src.a, src.a);
+    }
+    let dst = input(textureLoad(parent_texture, vec2<i32>(in.position.xy), 0));
+
+    // Composite with the usual "source over" rules, using the blended color
+    // where both the source and destination are present.
+    let mixed = blend(unmultiply(src), unmultiply(dst));
+    let color = src.rgb * (1.0 - dst.a) + dst.rgb * (1.0 - src.a) + src.a * dst.a * mixed;
+    let out = vec4<f32>(color, src.a + dst.a * (1.0 - src.a));
+    return output(out);
+}
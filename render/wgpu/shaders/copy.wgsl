@@ -0,0 +1,16 @@
+/// Shader used for copying the stage onto the render target, texel for texel.
+
+[[group(0), binding(0)]]
+var stage_texture: texture_2d<f32>;
+
+[[stage(vertex)]]
+fn main_vertex([[builtin(vertex_index)]] index: u32) -> [[builtin(position)]] vec4<f32> {
+    // A single triangle covering the whole target.
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+[[stage(fragment)]]
+fn main_fragment([[builtin(position)]] position: vec4<f32>) -> [[location(0)]] vec4<f32> {
+    return textureLoad(stage_texture, vec2<i32>(position.xy), 0);
+}
@@ -1,3 +1,8 @@
 fn output(srgb: vec4<f32>) -> vec4<f32> {
     return srgb_to_linear(srgb);
 }
+
+/// Converts a color read from the target back to sRGB.
+fn input(linear: vec4<f32>) -> vec4<f32> {
+    return linear_to_srgb(linear);
+}
@@ -1,3 +1,8 @@
 fn output(srgb: vec4<f32>) -> vec4<f32> {
     return srgb;
 }
+
+/// Converts a color read from the target back to sRGB.
+fn input(srgb: vec4<f32>) -> vec4<f32> {
+    return srgb;
+}
@@ -8,8 +8,8 @@ use std::{borrow::Cow, num::NonZeroU32};
 
 use bytemuck::{Pod, Zeroable};
 
-use crate::pipelines::Pipelines;
-use crate::target::{RenderTarget, RenderTargetFrame, SwapChainTarget};
+use crate::pipelines::{Blend, BlendType, Pipelines};
+use crate::target::{OffscreenTarget, RenderTarget, RenderTargetFrame, SwapChainTarget};
 use crate::utils::{create_buffer_with_data, format_list, get_backend_names};
 use enum_map::Enum;
 use ruffle_core::color_transform::ColorTransform;
@@ -110,6 +110,11 @@ impl Descriptors {
 pub struct WgpuRenderBackend<T: RenderTarget> {
     descriptors: Descriptors,
     target: T,
+    /// The texture that the stage is drawn into, which is copied onto the
+    /// target at the end of each frame. Unlike the target's own textures, it
+    /// can be read from while drawing with a complex blend mode.
+    frame_texture: wgpu::Texture,
+    frame_texture_view: wgpu::TextureView,
     frame_buffer_view: wgpu::TextureView,
     depth_texture_view: wgpu::TextureView,
     current_frame: Option<Frame<'static, T>>,
@@ -122,11 +127,18 @@ pub struct WgpuRenderBackend<T: RenderTarget> {
     quad_ibo: wgpu::Buffer,
     quad_tex_transforms: wgpu::Buffer,
     bitmap_registry: HashMap<BitmapHandle, Bitmap>,
+    offscreen_parents: Vec<OffscreenParent<T>>,
+    blend_modes: Vec<swf::BlendMode>,
+    /// How to draw with the innermost blend mode.
+    blend: Blend,
+    /// The bind groups of complex blends drawn in the current frame. Render
+    /// passes refer to their bind groups, so they're kept until it ends.
+    blend_bind_groups: Vec<wgpu::BindGroup>,
 }
 
 #[allow(dead_code)]
 struct Frame<'a, T: RenderTarget> {
-    frame_data: Box<(wgpu::CommandEncoder, T::Frame, wgpu::CommandEncoder)>,
+    frame_data: Box<(wgpu::CommandEncoder, FrameTarget<T>, wgpu::CommandEncoder)>,
 
     // TODO: This is a self-reference to the above, so we
     // use some unsafe to cast the lifetime away. We know this
@@ -144,6 +156,32 @@ impl<'a, T: RenderTarget> Frame<'static, T> {
     }
 }
 
+/// The texture that a frame is rendering into.
+enum FrameTarget<T: RenderTarget> {
+    /// The next texture of the render target.
+    Target(T::Frame),
+
+    /// An offscreen texture, which will be read back once rendering is done.
+    Offscreen(OffscreenTarget),
+}
+
+impl<T: RenderTarget> FrameTarget<T> {
+    fn view(&self) -> &wgpu::TextureView {
+        match self {
+            FrameTarget::Target(frame) => frame.view(),
+            FrameTarget::Offscreen(target) => target.view(),
+        }
+    }
+}
+
+/// The state that was interrupted by beginning an offscreen render, to be
+/// restored once it ends.
+struct OffscreenParent<T: RenderTarget> {
+    frame: Option<Frame<'static, T>>,
+    mask_state: MaskState,
+    num_masks: u32,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
 pub enum MaskState {
     NoMask,
@@ -364,6 +402,9 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             depth_or_array_layers: 1,
         };
 
+        let (frame_texture, frame_texture_view) =
+            create_frame_texture(&descriptors.device, target.format(), extent);
+
         let frame_buffer_label = create_debug_label!("Framebuffer texture");
         let frame_buffer = descriptors.device.create_texture(&wgpu::TextureDescriptor {
             label: frame_buffer_label.as_deref(),
@@ -398,6 +439,8 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
         Ok(Self {
             descriptors,
             target,
+            frame_texture,
+            frame_texture_view,
             frame_buffer_view,
             depth_texture_view,
             current_frame: None,
@@ -412,6 +455,10 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             quad_ibo,
             quad_tex_transforms,
             bitmap_registry: HashMap::new(),
+            offscreen_parents: Vec::new(),
+            blend_modes: Vec::new(),
+            blend: Blend::Trivial(BlendType::Normal),
+            blend_bind_groups: Vec::new(),
         })
     }
 
@@ -757,6 +804,202 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
     pub fn device(&self) -> &wgpu::Device {
         &self.descriptors.device
     }
+
+    /// The view that the stage is drawn into, and the view to resolve it to
+    /// when using MSAA.
+    fn stage_color_views(&self) -> (&wgpu::TextureView, Option<&wgpu::TextureView>) {
+        if self.descriptors.msaa_sample_count >= 2 {
+            (&self.frame_buffer_view, Some(&self.frame_texture_view))
+        } else {
+            (&self.frame_texture_view, None)
+        }
+    }
+
+    /// Copy everything drawn onto the current target so far into a new
+    /// texture, so that it can be read while drawing onto the target.
+    ///
+    /// The render pass has to end for its drawing to land in the target's
+    /// texture, so a new render pass is begun which carries on where it left
+    /// off.
+    fn copy_current_target(&mut self) -> Option<wgpu::TextureView> {
+        let Frame {
+            mut frame_data,
+            render_pass,
+        } = self.current_frame.take()?;
+        drop(render_pass);
+
+        let (texture, format, size, (color_view, resolve_target), depth_view) = match &frame_data.1
+        {
+            FrameTarget::Target(_) => (
+                &self.frame_texture,
+                self.target.format(),
+                (self.target.width(), self.target.height()),
+                self.stage_color_views(),
+                &self.depth_texture_view,
+            ),
+            FrameTarget::Offscreen(target) => (
+                target.texture(),
+                self.descriptors.surface_format,
+                (target.width(), target.height()),
+                target.color_views(),
+                target.depth_view(),
+            ),
+        };
+        let size = wgpu::Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        };
+
+        let label = create_debug_label!("Blend parent texture");
+        let parent = self
+            .descriptors
+            .device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: label.as_deref(),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            });
+        frame_data.0.copy_texture_to_texture(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyTexture {
+                texture: &parent,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            size,
+        );
+
+        let load = wgpu::Operations {
+            load: wgpu::LoadOp::Load,
+            store: true,
+        };
+        let render_pass = frame_data.0.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view: color_view,
+                ops: load,
+                resolve_target,
+            }],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: depth_view,
+                depth_ops: Some(load),
+                stencil_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: true,
+                }),
+            }),
+            label: create_debug_label!("Continued render pass").as_deref(),
+        });
+
+        // See `begin_frame` for why this is sound.
+        self.current_frame = Some(Frame {
+            render_pass: unsafe {
+                std::mem::transmute::<_, wgpu::RenderPass<'static>>(render_pass)
+            },
+            frame_data,
+        });
+
+        Some(parent.create_view(&Default::default()))
+    }
+
+    /// Create the bind group for drawing a texture with a complex blend,
+    /// onto the copy of the target in `parent_view`.
+    fn complex_blend_bind_group(
+        &self,
+        texture: &Texture,
+        parent_view: &wgpu::TextureView,
+    ) -> wgpu::BindGroup {
+        let texture_view = texture.texture.create_view(&Default::default());
+        self.descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                layout: &self.descriptors.pipelines.complex_blend_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                            buffer: &self.quad_tex_transforms,
+                            offset: 0,
+                            size: wgpu::BufferSize::new(
+                                std::mem::size_of::<TextureTransforms>() as u64
+                            ),
+                        }),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&texture_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::TextureView(parent_view),
+                    },
+                ],
+                label: create_debug_label!("Complex blend bind group").as_deref(),
+            })
+    }
+
+    /// Record drawing the stage onto the texture of the render target.
+    fn copy_stage_to_target(&self, encoder: &mut wgpu::CommandEncoder, view: &wgpu::TextureView) {
+        let bind_group = self
+            .descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                layout: &self.descriptors.pipelines.copy_layout,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&self.frame_texture_view),
+                }],
+                label: create_debug_label!("Stage copy bind group").as_deref(),
+            });
+
+        let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                    store: true,
+                },
+                resolve_target: None,
+            }],
+            depth_stencil_attachment: None,
+            label: create_debug_label!("Stage copy render pass").as_deref(),
+        });
+        render_pass.set_pipeline(&self.descriptors.pipelines.copy_pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+}
+
+/// Create the texture that the stage is drawn into.
+fn create_frame_texture(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    size: wgpu::Extent3d,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let label = create_debug_label!("Frame texture");
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: label.as_deref(),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_SRC,
+    });
+    let view = texture.create_view(&Default::default());
+    (texture, view)
 }
 
 impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
@@ -767,6 +1010,18 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
 
         self.target.resize(&self.descriptors.device, width, height);
 
+        let (frame_texture, frame_texture_view) = create_frame_texture(
+            &self.descriptors.device,
+            self.target.format(),
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.frame_texture = frame_texture;
+        self.frame_texture_view = frame_texture_view;
+
         let label = create_debug_label!("Framebuffer texture");
         let frame_buffer = self
             .descriptors
@@ -875,6 +1130,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
         self.mask_state = MaskState::NoMask;
         self.num_masks = 0;
         self.descriptors.uniform_buffers.reset();
+        self.blend_bind_groups.clear();
 
         let frame_output = match self.target.get_next_texture() {
             Ok(frame) => frame,
@@ -904,17 +1160,17 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                 .create_command_encoder(&wgpu::CommandEncoderDescriptor {
                     label: uniform_encoder_label.as_deref(),
                 });
-        let mut frame_data = Box::new((draw_encoder, frame_output, uniform_encoder));
+        let mut frame_data = Box::new((
+            draw_encoder,
+            FrameTarget::Target(frame_output),
+            uniform_encoder,
+        ));
 
         self.descriptors
             .globals
             .update_uniform(&self.descriptors.device, &mut frame_data.0);
 
-        let (color_view, resolve_target) = if self.descriptors.msaa_sample_count >= 2 {
-            (&self.frame_buffer_view, Some(frame_data.1.view()))
-        } else {
-            (frame_data.1.view(), None)
-        };
+        let (color_view, resolve_target) = self.stage_color_views();
 
         let render_pass = frame_data.0.begin_render_pass(&wgpu::RenderPassDescriptor {
             color_attachments: &[wgpu::RenderPassColorAttachment {
@@ -956,7 +1212,37 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     }
 
     fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: &Transform, smoothing: bool) {
+        // Complex blends read what's been drawn so far from a copy of the
+        // target, and are otherwise drawn normally.
+        let complex_blend = match self.blend {
+            Blend::Complex(blend) if bitmap.0 < self.textures.len() => self
+                .copy_current_target()
+                .map(|parent_view| (blend, parent_view)),
+            _ => None,
+        };
+
         if let Some(texture) = self.textures.get(bitmap.0) {
+            let (pipeline, bind_group) = match complex_blend {
+                Some((blend, parent_view)) => {
+                    let bind_group = self.complex_blend_bind_group(texture, &parent_view);
+                    self.blend_bind_groups.push(bind_group);
+                    (
+                        self.descriptors
+                            .pipelines
+                            .complex_blend_pipelines
+                            .pipeline_for(blend, self.mask_state),
+                        self.blend_bind_groups.last().unwrap(),
+                    )
+                }
+                None => (
+                    self.descriptors
+                        .pipelines
+                        .bitmap_pipelines
+                        .pipeline_for(self.blend.trivial(), self.mask_state),
+                    &texture.bind_group,
+                ),
+            };
+
             let frame = if let Some(frame) = &mut self.current_frame {
                 frame.get()
             } else {
@@ -985,12 +1271,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                 ],
             ];
 
-            frame.render_pass.set_pipeline(
-                self.descriptors
-                    .pipelines
-                    .bitmap_pipelines
-                    .pipeline_for(self.mask_state),
-            );
+            frame.render_pass.set_pipeline(pipeline);
             frame
                 .render_pass
                 .set_bind_group(0, self.descriptors.globals.bind_group(), &[]);
@@ -1006,9 +1287,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                 },
             );
 
-            frame
-                .render_pass
-                .set_bind_group(2, &texture.bind_group, &[]);
+            frame.render_pass.set_bind_group(2, bind_group, &[]);
             frame.render_pass.set_bind_group(
                 3,
                 self.descriptors
@@ -1082,7 +1361,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                         self.descriptors
                             .pipelines
                             .color_pipelines
-                            .pipeline_for(self.mask_state),
+                            .pipeline_for(self.blend.trivial(), self.mask_state),
                     );
                 }
                 DrawType::Gradient { bind_group, .. } => {
@@ -1090,7 +1369,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                         self.descriptors
                             .pipelines
                             .gradient_pipelines
-                            .pipeline_for(self.mask_state),
+                            .pipeline_for(self.blend.trivial(), self.mask_state),
                     );
                     frame.render_pass.set_bind_group(2, bind_group, &[]);
                 }
@@ -1104,7 +1383,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
                         self.descriptors
                             .pipelines
                             .bitmap_pipelines
-                            .pipeline_for(self.mask_state),
+                            .pipeline_for(self.blend.trivial(), self.mask_state),
                     );
                     frame.render_pass.set_bind_group(2, bind_group, &[]);
                     frame.render_pass.set_bind_group(
@@ -1171,7 +1450,7 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             self.descriptors
                 .pipelines
                 .color_pipelines
-                .pipeline_for(self.mask_state),
+                .pipeline_for(self.blend.trivial(), self.mask_state),
         );
 
         frame
@@ -1219,15 +1498,169 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             // Finalize render pass.
             drop(frame.render_pass);
             self.descriptors.uniform_buffers.finish();
-            let draw_encoder = frame.frame_data.0;
-            let uniform_encoder = frame.frame_data.2;
-            self.target.submit(
-                &self.descriptors.device,
-                &self.descriptors.queue,
-                vec![uniform_encoder.finish(), draw_encoder.finish()],
-                frame.frame_data.1,
-            );
+            let (mut draw_encoder, frame_target, uniform_encoder) = *frame.frame_data;
+            match frame_target {
+                FrameTarget::Target(frame_output) => {
+                    self.copy_stage_to_target(&mut draw_encoder, frame_output.view());
+                    self.target.submit(
+                        &self.descriptors.device,
+                        &self.descriptors.queue,
+                        vec![uniform_encoder.finish(), draw_encoder.finish()],
+                        frame_output,
+                    )
+                }
+                FrameTarget::Offscreen(_) => {
+                    log::warn!("end_frame: Offscreen render was never ended");
+                }
+            }
+        }
+    }
+
+    fn begin_offscreen(&mut self, width: u32, height: u32, clear: Color) {
+        let width = width.max(1);
+        let height = height.max(1);
+
+        if self.current_frame.is_none() {
+            self.descriptors.uniform_buffers.reset();
+        }
+
+        self.offscreen_parents.push(OffscreenParent {
+            frame: self.current_frame.take(),
+            mask_state: self.mask_state,
+            num_masks: self.num_masks,
+        });
+        self.mask_state = MaskState::NoMask;
+        self.num_masks = 0;
+
+        let offscreen_target = OffscreenTarget::new(
+            &self.descriptors.device,
+            self.descriptors.surface_format,
+            self.descriptors.msaa_sample_count,
+            (width, height),
+        );
+
+        let label = create_debug_label!("Offscreen draw encoder");
+        let draw_encoder =
+            self.descriptors
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: label.as_deref(),
+                });
+        let uniform_encoder_label = create_debug_label!("Offscreen uniform upload command encoder");
+        let uniform_encoder =
+            self.descriptors
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: uniform_encoder_label.as_deref(),
+                });
+        let mut frame_data = Box::new((
+            draw_encoder,
+            FrameTarget::Offscreen(offscreen_target),
+            uniform_encoder,
+        ));
+
+        self.descriptors.globals.set_resolution(width, height);
+        self.descriptors
+            .globals
+            .update_uniform(&self.descriptors.device, &mut frame_data.0);
+
+        let offscreen_target = match &frame_data.1 {
+            FrameTarget::Offscreen(target) => target,
+            FrameTarget::Target(_) => unreachable!(),
+        };
+        let (color_view, resolve_target) = offscreen_target.color_views();
+
+        let render_pass = frame_data.0.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view: color_view,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color {
+                        r: f64::from(clear.r) / 255.0,
+                        g: f64::from(clear.g) / 255.0,
+                        b: f64::from(clear.b) / 255.0,
+                        a: f64::from(clear.a) / 255.0,
+                    }),
+                    store: true,
+                },
+                resolve_target,
+            }],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: offscreen_target.depth_view(),
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(0.0),
+                    store: true,
+                }),
+                stencil_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(0),
+                    store: true,
+                }),
+            }),
+            label: create_debug_label!("Offscreen render pass").as_deref(),
+        });
+
+        // See `begin_frame` for why this is sound.
+        self.current_frame = Some(Frame {
+            render_pass: unsafe {
+                std::mem::transmute::<_, wgpu::RenderPass<'static>>(render_pass)
+            },
+            frame_data,
+        });
+    }
+
+    fn end_offscreen(&mut self) -> Option<Bitmap> {
+        let parent = self.offscreen_parents.pop()?;
+        let frame = std::mem::replace(&mut self.current_frame, parent.frame);
+        self.mask_state = parent.mask_state;
+        self.num_masks = parent.num_masks;
+
+        // Restore the resolution of whatever we were rendering to before.
+        let (width, height) = match self.current_frame.as_ref().map(|f| &f.frame_data.1) {
+            Some(FrameTarget::Offscreen(target)) => (target.width(), target.height()),
+            _ => (self.target.width(), self.target.height()),
+        };
+        self.descriptors.globals.set_resolution(width, height);
+
+        let frame = frame?;
+        drop(frame.render_pass);
+        // The staging belt must be unmapped before the uploads can be submitted.
+        self.descriptors.uniform_buffers.finish();
+
+        let (mut draw_encoder, frame_target, uniform_encoder) = *frame.frame_data;
+        let offscreen_target = match frame_target {
+            FrameTarget::Offscreen(target) => target,
+            FrameTarget::Target(_) => {
+                log::warn!("end_offscreen: Current frame is not an offscreen render");
+                return None;
+            }
+        };
+        offscreen_target.copy_to_buffer(&mut draw_encoder);
+
+        if let Some(parent_frame) = &mut self.current_frame {
+            self.descriptors
+                .globals
+                .update_uniform(&self.descriptors.device, &mut parent_frame.frame_data.2);
         }
+
+        self.descriptors
+            .queue
+            .submit([uniform_encoder.finish(), draw_encoder.finish()]);
+
+        let rgba = offscreen_target.capture(&self.descriptors.device)?;
+        Some(Bitmap {
+            width: offscreen_target.width(),
+            height: offscreen_target.height(),
+            data: BitmapFormat::Rgba(rgba),
+        })
+    }
+
+    fn push_blend_mode(&mut self, blend: swf::BlendMode) {
+        self.blend_modes.push(blend);
+        self.blend = blend.into();
+    }
+
+    fn pop_blend_mode(&mut self) {
+        self.blend_modes.pop();
+        self.blend = self.blend_modes.last().copied().unwrap_or_default().into();
     }
 
     fn push_mask(&mut self) {
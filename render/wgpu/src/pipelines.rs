@@ -1,10 +1,81 @@
 use crate::{Error, MaskState, Vertex};
-use enum_map::{enum_map, EnumMap};
+use enum_map::{enum_map, Enum, EnumMap};
+use ruffle_core::swf;
 use wgpu::vertex_attr_array;
 
+/// The blend modes that can be drawn with a blend state, which pipelines are
+/// created for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum BlendType {
+    Normal,
+    Screen,
+    Add,
+    Subtract,
+    Invert,
+    Alpha,
+    Erase,
+}
+
+/// The blend modes that mix the source and destination colors in ways that
+/// blend states can't express, weighted by the alpha of both.
+///
+/// These are drawn by a shader that reads a copy of the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enum)]
+pub enum ComplexBlend {
+    Multiply,
+    Lighten,
+    Darken,
+    Difference,
+    Overlay,
+    HardLight,
+}
+
+/// How a blend mode is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blend {
+    Trivial(BlendType),
+    Complex(ComplexBlend),
+}
+
+impl Blend {
+    /// The blend type to draw with when the destination can't be read, such
+    /// as for shapes.
+    pub fn trivial(self) -> BlendType {
+        match self {
+            Blend::Trivial(blend_type) => blend_type,
+            Blend::Complex(_) => BlendType::Normal,
+        }
+    }
+}
+
+impl From<swf::BlendMode> for Blend {
+    fn from(blend: swf::BlendMode) -> Self {
+        match blend {
+            swf::BlendMode::Normal | swf::BlendMode::Layer => Blend::Trivial(BlendType::Normal),
+            swf::BlendMode::Screen => Blend::Trivial(BlendType::Screen),
+            swf::BlendMode::Add => Blend::Trivial(BlendType::Add),
+            swf::BlendMode::Subtract => Blend::Trivial(BlendType::Subtract),
+            swf::BlendMode::Invert => Blend::Trivial(BlendType::Invert),
+            swf::BlendMode::Alpha => Blend::Trivial(BlendType::Alpha),
+            swf::BlendMode::Erase => Blend::Trivial(BlendType::Erase),
+            swf::BlendMode::Multiply => Blend::Complex(ComplexBlend::Multiply),
+            swf::BlendMode::Lighten => Blend::Complex(ComplexBlend::Lighten),
+            swf::BlendMode::Darken => Blend::Complex(ComplexBlend::Darken),
+            swf::BlendMode::Difference => Blend::Complex(ComplexBlend::Difference),
+            swf::BlendMode::Overlay => Blend::Complex(ComplexBlend::Overlay),
+            swf::BlendMode::HardLight => Blend::Complex(ComplexBlend::HardLight),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ShapePipeline {
-    pub mask_pipelines: EnumMap<MaskState, wgpu::RenderPipeline>,
+    pub pipelines: EnumMap<BlendType, EnumMap<MaskState, wgpu::RenderPipeline>>,
+}
+
+#[derive(Debug)]
+pub struct ComplexBlendPipeline {
+    pub pipelines: EnumMap<ComplexBlend, EnumMap<MaskState, wgpu::RenderPipeline>>,
 }
 
 #[derive(Debug)]
@@ -16,11 +87,31 @@ pub struct Pipelines {
 
     pub gradient_pipelines: ShapePipeline,
     pub gradient_layout: wgpu::BindGroupLayout,
+
+    pub complex_blend_pipelines: ComplexBlendPipeline,
+    pub complex_blend_layout: wgpu::BindGroupLayout,
+
+    pub copy_pipeline: wgpu::RenderPipeline,
+    pub copy_layout: wgpu::BindGroupLayout,
 }
 
 impl ShapePipeline {
-    pub fn pipeline_for(&self, mask_state: MaskState) -> &wgpu::RenderPipeline {
-        &self.mask_pipelines[mask_state]
+    pub fn pipeline_for(
+        &self,
+        blend_type: BlendType,
+        mask_state: MaskState,
+    ) -> &wgpu::RenderPipeline {
+        &self.pipelines[blend_type][mask_state]
+    }
+}
+
+impl ComplexBlendPipeline {
+    pub fn pipeline_for(
+        &self,
+        blend: ComplexBlend,
+        mask_state: MaskState,
+    ) -> &wgpu::RenderPipeline {
+        &self.pipelines[blend][mask_state]
     }
 }
 
@@ -64,14 +155,16 @@ impl Pipelines {
             ],
         }];
 
-        let color_pipelines = create_color_pipelines(
+        let color_pipelines = create_shape_pipeline(
             device,
+            "Color",
             surface_format,
             &color_shader,
             msaa_sample_count,
             &vertex_buffers_description,
-            globals_layout,
-            dynamic_uniforms_layout,
+            &[globals_layout, dynamic_uniforms_layout],
+            wgpu::BlendFactor::SrcAlpha,
+            wgpu::CompareFunction::Always,
         );
 
         let bitmap_bind_layout_label = create_debug_label!("Bitmap shape bind group layout");
@@ -102,16 +195,22 @@ impl Pipelines {
                 label: bitmap_bind_layout_label.as_deref(),
             });
 
-        let bitmap_pipelines = create_bitmap_pipeline(
+        // Bitmaps use pre-multiplied alpha.
+        let bitmap_pipelines = create_shape_pipeline(
             device,
+            "Bitmap",
             surface_format,
             &bitmap_shader,
             msaa_sample_count,
             &vertex_buffers_description,
-            sampler_layout,
-            globals_layout,
-            dynamic_uniforms_layout,
-            &bitmap_bind_layout,
+            &[
+                globals_layout,
+                dynamic_uniforms_layout,
+                &bitmap_bind_layout,
+                sampler_layout,
+            ],
+            wgpu::BlendFactor::One,
+            wgpu::CompareFunction::Equal,
         );
 
         let gradient_bind_layout_label = create_debug_label!("Gradient shape bind group");
@@ -142,23 +241,101 @@ impl Pipelines {
                 label: gradient_bind_layout_label.as_deref(),
             });
 
-        let gradient_pipelines = create_gradient_pipeline(
+        let gradient_pipelines = create_shape_pipeline(
             device,
+            "Gradient",
             surface_format,
             &gradient_shader,
             msaa_sample_count,
             &vertex_buffers_description,
-            globals_layout,
-            dynamic_uniforms_layout,
-            &gradient_bind_layout,
+            &[
+                globals_layout,
+                dynamic_uniforms_layout,
+                &gradient_bind_layout,
+            ],
+            wgpu::BlendFactor::SrcAlpha,
+            wgpu::CompareFunction::Equal,
+        );
+
+        let complex_blend_bind_layout_label =
+            create_debug_label!("Complex blend bind group layout");
+        let complex_blend_bind_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::VERTEX,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                ],
+                label: complex_blend_bind_layout_label.as_deref(),
+            });
+
+        let complex_blend_pipelines = create_complex_blend_pipeline(
+            device,
+            surface_format,
+            msaa_sample_count,
+            output_srgb,
+            &vertex_buffers_description,
+            &[
+                globals_layout,
+                dynamic_uniforms_layout,
+                &complex_blend_bind_layout,
+                sampler_layout,
+            ],
         );
 
+        let copy_bind_layout_label = create_debug_label!("Copy bind group layout");
+        let copy_bind_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    multisampled: false,
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                },
+                count: None,
+            }],
+            label: copy_bind_layout_label.as_deref(),
+        });
+        let copy_pipeline = create_copy_pipeline(device, surface_format, &copy_bind_layout);
+
         Ok(Self {
             color_pipelines,
             bitmap_pipelines,
             bitmap_layout: bitmap_bind_layout,
             gradient_pipelines,
             gradient_layout: gradient_bind_layout,
+            complex_blend_pipelines,
+            complex_blend_layout: complex_blend_bind_layout,
+            copy_pipeline,
+            copy_layout: copy_bind_layout,
         })
     }
 }
@@ -170,8 +347,8 @@ impl Pipelines {
 /// possibly does this as an offline build step instead.
 fn create_shader(
     device: &wgpu::Device,
-    name: &'static str,
-    src: &'static str,
+    name: &str,
+    src: &str,
     output_srgb: bool,
 ) -> wgpu::ShaderModule {
     const COMMON_SRC: &str = include_str!("../shaders/common.wgsl");
@@ -239,504 +416,215 @@ fn create_pipeline_descriptor<'a>(
     }
 }
 
+/// Create the pipelines for every blend type and mask state of a kind of shape.
+///
+/// `src_factor` is the factor that blends the shader's output normally: `One`
+/// if it outputs pre-multiplied colors, or `SrcAlpha` otherwise.
 #[allow(clippy::too_many_arguments)]
-fn create_color_pipelines(
+fn create_shape_pipeline(
     device: &wgpu::Device,
+    name: &str,
     format: wgpu::TextureFormat,
     shader: &wgpu::ShaderModule,
     msaa_sample_count: u32,
-    vertex_buffers_description: &[wgpu::VertexBufferLayout<'_>],
-    globals_layout: &wgpu::BindGroupLayout,
-    dynamic_uniforms_layout: &wgpu::BindGroupLayout,
+    vertex_buffers_layout: &[wgpu::VertexBufferLayout<'_>],
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    src_factor: wgpu::BlendFactor,
+    masked_content_depth_compare: wgpu::CompareFunction,
 ) -> ShapePipeline {
-    let pipeline_layout_label = create_debug_label!("Color shape pipeline layout");
+    let pipeline_layout_label = create_debug_label!("{} shape pipeline layout", name);
     let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: pipeline_layout_label.as_deref(),
-        bind_group_layouts: &[globals_layout, dynamic_uniforms_layout],
+        bind_group_layouts,
         push_constant_ranges: &[],
     });
 
-    let mask_pipelines = enum_map! {
-        MaskState::NoMask => {
-            let (stencil, write_mask) = mask_render_state(MaskState::NoMask);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Color pipeline no mask").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-
-                        }
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_description,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::DrawMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Color pipeline draw mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_description,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::DrawMaskedContent => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskedContent);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Color pipeline draw masked content").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_description,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::ClearMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::ClearMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Color pipeline clear mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
+    let pipelines = enum_map! {
+        blend_type => enum_map! {
+            mask_state => {
+                let (stencil, write_mask) = mask_render_state(mask_state);
+                let depth_compare = if mask_state == MaskState::DrawMaskedContent {
+                    masked_content_depth_compare
+                } else {
+                    wgpu::CompareFunction::Always
+                };
+                device.create_render_pipeline(&create_pipeline_descriptor(
+                    create_debug_label!("{} pipeline {:?} {:?}", name, blend_type, mask_state)
+                        .as_deref(),
+                    shader,
+                    shader,
+                    &pipeline_layout,
+                    Some(wgpu::DepthStencilState {
+                        format: wgpu::TextureFormat::Depth24PlusStencil8,
+                        depth_write_enabled: true,
+                        depth_compare,
+                        stencil,
+                        bias: Default::default(),
                     }),
-                    write_mask,
-                }],
-                vertex_buffers_description,
-                msaa_sample_count,
-            ))
-        },
+                    &[wgpu::ColorTargetState {
+                        format,
+                        blend: Some(blend_state(blend_type, src_factor)),
+                        write_mask,
+                    }],
+                    vertex_buffers_layout,
+                    msaa_sample_count,
+                ))
+            }
+        }
     };
 
-    ShapePipeline { mask_pipelines }
+    ShapePipeline { pipelines }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn create_bitmap_pipeline(
+/// Create the pipelines for every complex blend and mask state.
+///
+/// Each blend gets its own shader, with the blend selected by a constant.
+/// They replace the target's color with the blended color outright, since the
+/// shader has already mixed in the destination.
+fn create_complex_blend_pipeline(
     device: &wgpu::Device,
     format: wgpu::TextureFormat,
-    shader: &wgpu::ShaderModule,
     msaa_sample_count: u32,
+    output_srgb: bool,
     vertex_buffers_layout: &[wgpu::VertexBufferLayout<'_>],
-    sampler_layout: &wgpu::BindGroupLayout,
-    globals_layout: &wgpu::BindGroupLayout,
-    dynamic_uniforms_layout: &wgpu::BindGroupLayout,
-    bitmap_bind_layout: &wgpu::BindGroupLayout,
-) -> ShapePipeline {
-    let pipeline_layout_label = create_debug_label!("Bitmap shape pipeline layout");
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+) -> ComplexBlendPipeline {
+    const BLEND_SRC: &str = include_str!("../shaders/blend.wgsl");
+
+    let pipeline_layout_label = create_debug_label!("Complex blend pipeline layout");
     let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: pipeline_layout_label.as_deref(),
-        bind_group_layouts: &[
-            globals_layout,
-            dynamic_uniforms_layout,
-            bitmap_bind_layout,
-            sampler_layout,
-        ],
+        bind_group_layouts,
         push_constant_ranges: &[],
     });
 
-    let mask_pipelines = enum_map! {
-        MaskState::NoMask => {
-            let (stencil, write_mask) = mask_render_state(MaskState::NoMask);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Bitmap pipeline no mask").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::DrawMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Bitmap pipeline draw mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::DrawMaskedContent => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskedContent);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Bitmap pipeline draw masked content").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Equal,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::One,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
-
-        MaskState::ClearMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::ClearMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Bitmap pipeline clear mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
+    let pipelines = enum_map! {
+        blend => {
+            let src = format!("let blend_mode: i32 = {};\n{}", blend as i32, BLEND_SRC);
+            let shader = create_shader(device, &format!("blend {:?}", blend), &src, output_srgb);
+            enum_map! {
+                mask_state => {
+                    let (stencil, write_mask) = mask_render_state(mask_state);
+                    let depth_compare = if mask_state == MaskState::DrawMaskedContent {
+                        wgpu::CompareFunction::Equal
+                    } else {
+                        wgpu::CompareFunction::Always
+                    };
+                    device.create_render_pipeline(&create_pipeline_descriptor(
+                        create_debug_label!("Complex blend pipeline {:?} {:?}", blend, mask_state)
+                            .as_deref(),
+                        &shader,
+                        &shader,
+                        &pipeline_layout,
+                        Some(wgpu::DepthStencilState {
+                            format: wgpu::TextureFormat::Depth24PlusStencil8,
+                            depth_write_enabled: true,
+                            depth_compare,
+                            stencil,
+                            bias: Default::default(),
+                        }),
+                        &[wgpu::ColorTargetState {
+                            format,
+                            blend: None,
+                            write_mask,
+                        }],
+                        vertex_buffers_layout,
+                        msaa_sample_count,
+                    ))
+                }
+            }
         }
     };
 
-    ShapePipeline { mask_pipelines }
+    ComplexBlendPipeline { pipelines }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn create_gradient_pipeline(
+/// Create the pipeline that copies the stage onto the render target.
+///
+/// The stage is copied texel for texel, so the shader doesn't convert colors
+/// and isn't prepended with the common code.
+fn create_copy_pipeline(
     device: &wgpu::Device,
     format: wgpu::TextureFormat,
-    shader: &wgpu::ShaderModule,
-    msaa_sample_count: u32,
-    vertex_buffers_layout: &[wgpu::VertexBufferLayout<'_>],
-    globals_layout: &wgpu::BindGroupLayout,
-    dynamic_uniforms_layout: &wgpu::BindGroupLayout,
-    gradient_bind_layout: &wgpu::BindGroupLayout,
-) -> ShapePipeline {
-    let pipeline_layout_label = create_debug_label!("Gradient shape pipeline layout");
+    bind_group_layout: &wgpu::BindGroupLayout,
+) -> wgpu::RenderPipeline {
+    let shader = device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+        label: create_debug_label!("Shader copy").as_deref(),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/copy.wgsl").into()),
+    });
+
+    let pipeline_layout_label = create_debug_label!("Copy pipeline layout");
     let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: pipeline_layout_label.as_deref(),
-        bind_group_layouts: &[
-            globals_layout,
-            dynamic_uniforms_layout,
-            gradient_bind_layout,
-        ],
+        bind_group_layouts: &[bind_group_layout],
         push_constant_ranges: &[],
     });
 
-    let mask_pipelines = enum_map! {
-        MaskState::NoMask => {
-            let (stencil, write_mask) = mask_render_state(MaskState::NoMask);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Gradient pipeline no mask").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
+    device.create_render_pipeline(&create_pipeline_descriptor(
+        create_debug_label!("Copy pipeline").as_deref(),
+        &shader,
+        &shader,
+        &pipeline_layout,
+        None,
+        &[wgpu::ColorTargetState {
+            format,
+            blend: None,
+            write_mask: wgpu::ColorWrites::ALL,
+        }],
+        &[],
+        1,
+    ))
+}
 
-        MaskState::DrawMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Gradient pipeline draw mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
+/// The blend state that implements a blend type.
+fn blend_state(blend_type: BlendType, src_factor: wgpu::BlendFactor) -> wgpu::BlendState {
+    use wgpu::{BlendComponent, BlendFactor, BlendOperation};
 
+    let component = |src_factor, dst_factor, operation| BlendComponent {
+        src_factor,
+        dst_factor,
+        operation,
+    };
+    let normal = component(
+        src_factor,
+        BlendFactor::OneMinusSrcAlpha,
+        BlendOperation::Add,
+    );
 
-        MaskState::DrawMaskedContent => {
-            let (stencil, write_mask) = mask_render_state(MaskState::DrawMaskedContent);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Gradient pipeline draw masked content").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Equal,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        },
+    let color = match blend_type {
+        BlendType::Normal => normal,
+        BlendType::Screen => component(src_factor, BlendFactor::OneMinusSrc, BlendOperation::Add),
+        BlendType::Add => component(src_factor, BlendFactor::One, BlendOperation::Add),
+        BlendType::Subtract => component(
+            src_factor,
+            BlendFactor::One,
+            BlendOperation::ReverseSubtract,
+        ),
+        BlendType::Invert => component(
+            BlendFactor::OneMinusDst,
+            BlendFactor::OneMinusSrcAlpha,
+            BlendOperation::Add,
+        ),
+        BlendType::Alpha => component(
+            BlendFactor::Zero,
+            BlendFactor::SrcAlpha,
+            BlendOperation::Add,
+        ),
+        BlendType::Erase => component(
+            BlendFactor::Zero,
+            BlendFactor::OneMinusSrcAlpha,
+            BlendOperation::Add,
+        ),
+    };
 
-        MaskState::ClearMaskStencil => {
-            let (stencil, write_mask) = mask_render_state(MaskState::ClearMaskStencil);
-            device.create_render_pipeline(&create_pipeline_descriptor(
-                create_debug_label!("Gradient pipeline clear mask stencil").as_deref(),
-                shader,
-                shader,
-                &pipeline_layout,
-                Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth24PlusStencil8,
-                    depth_write_enabled: true,
-                    depth_compare: wgpu::CompareFunction::Always,
-                    stencil,
-                    bias: Default::default(),
-                }),
-                &[wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState {
-                        color: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                        alpha: wgpu::BlendComponent {
-                            src_factor: wgpu::BlendFactor::SrcAlpha,
-                            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                            operation: wgpu::BlendOperation::Add,
-                        },
-                    }),
-                    write_mask,
-                }],
-                vertex_buffers_layout,
-                msaa_sample_count,
-            ))
-        }
+    let alpha = match blend_type {
+        // These modes change the destination alpha as well. They're only
+        // used inside layers, which start out transparent.
+        BlendType::Alpha | BlendType::Erase => color,
+        _ => normal,
     };
 
-    ShapePipeline { mask_pipelines }
+    wgpu::BlendState { color, alpha }
 }
 
 fn mask_render_state(state: MaskState) -> (wgpu::StencilState, wgpu::ColorWrites) {
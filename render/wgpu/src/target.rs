@@ -1,4 +1,3 @@
-#[cfg(not(target_family = "wasm"))]
 use crate::utils::BufferDimensions;
 use std::fmt::Debug;
 
@@ -271,3 +270,191 @@ impl RenderTarget for TextureTarget {
         queue.submit(command_buffers.into_iter().chain(Some(encoder.finish())));
     }
 }
+
+/// A texture used as the target of an offscreen render pass, such as for
+/// `BitmapData.draw`.
+#[derive(Debug)]
+pub struct OffscreenTarget {
+    size: wgpu::Extent3d,
+    format: wgpu::TextureFormat,
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    msaa_view: Option<wgpu::TextureView>,
+    depth_view: wgpu::TextureView,
+    buffer: wgpu::Buffer,
+    buffer_dimensions: BufferDimensions,
+}
+
+impl OffscreenTarget {
+    pub fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        msaa_sample_count: u32,
+        size: (u32, u32),
+    ) -> Self {
+        let buffer_dimensions = BufferDimensions::new(size.0 as usize, size.1 as usize);
+        let size = wgpu::Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        };
+
+        let texture_label = create_debug_label!("Offscreen texture");
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: texture_label.as_deref(),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        });
+        let view = texture.create_view(&Default::default());
+
+        let msaa_view = if msaa_sample_count >= 2 {
+            let msaa_label = create_debug_label!("Offscreen MSAA texture");
+            let msaa_texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: msaa_label.as_deref(),
+                size,
+                mip_level_count: 1,
+                sample_count: msaa_sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            });
+            Some(msaa_texture.create_view(&Default::default()))
+        } else {
+            None
+        };
+
+        let depth_label = create_debug_label!("Offscreen depth texture");
+        let depth_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: depth_label.as_deref(),
+            size,
+            mip_level_count: 1,
+            sample_count: msaa_sample_count,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Depth24PlusStencil8,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        });
+        let depth_view = depth_texture.create_view(&Default::default());
+
+        let buffer_label = create_debug_label!("Offscreen buffer");
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: buffer_label.as_deref(),
+            size: (buffer_dimensions.padded_bytes_per_row.get() as u64
+                * buffer_dimensions.height as u64),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            size,
+            format,
+            texture,
+            view,
+            msaa_view,
+            depth_view,
+            buffer,
+            buffer_dimensions,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.size.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.size.height
+    }
+
+    /// The final, resolved texture.
+    pub fn texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    /// The view of the final, resolved texture.
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// The view to render into, and the view to resolve it to when using MSAA.
+    pub fn color_views(&self) -> (&wgpu::TextureView, Option<&wgpu::TextureView>) {
+        match &self.msaa_view {
+            Some(msaa_view) => (msaa_view, Some(&self.view)),
+            None => (&self.view, None),
+        }
+    }
+
+    pub fn depth_view(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    /// Record a copy of the rendered texture into the readback buffer.
+    pub fn copy_to_buffer(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &self.buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(self.buffer_dimensions.padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            self.size,
+        );
+    }
+
+    /// Read back the RGBA pixels of the texture, once the copy recorded by
+    /// `copy_to_buffer` has been submitted.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn capture(&self, device: &wgpu::Device) -> Option<Vec<u8>> {
+        let buffer_future = self.buffer.slice(..).map_async(wgpu::MapMode::Read);
+        device.poll(wgpu::Maintain::Wait);
+        match futures::executor::block_on(buffer_future) {
+            Ok(()) => {
+                let map = self.buffer.slice(..).get_mapped_range();
+                let mut buffer = Vec::with_capacity(
+                    self.buffer_dimensions.height * self.buffer_dimensions.unpadded_bytes_per_row,
+                );
+
+                for chunk in map.chunks(self.buffer_dimensions.padded_bytes_per_row.get() as usize)
+                {
+                    buffer
+                        .extend_from_slice(&chunk[..self.buffer_dimensions.unpadded_bytes_per_row]);
+                }
+
+                drop(map);
+                self.buffer.unmap();
+
+                if matches!(
+                    self.format,
+                    wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
+                ) {
+                    for pixel in buffer.chunks_exact_mut(4) {
+                        pixel.swap(0, 2);
+                    }
+                }
+
+                Some(buffer)
+            }
+            Err(e) => {
+                log::error!("Unknown error reading offscreen buffer: {:?}", e);
+                None
+            }
+        }
+    }
+
+    /// Reading back buffers requires blocking on the GPU, which is not possible on the web.
+    #[cfg(target_family = "wasm")]
+    pub fn capture(&self, _device: &wgpu::Device) -> Option<Vec<u8>> {
+        log::warn!("Reading back offscreen render targets is not supported on this platform");
+        None
+    }
+}
@@ -4,8 +4,10 @@ use crate::cli_options::ExecuteReportOpt;
 use crate::file_results::{AvmType, FileResults, Step};
 use crate::logging::{ScanLogBackend, ThreadLocalScanLogger, LOCAL_LOGGER};
 use ruffle_core::backend::audio::NullAudioBackend;
+use ruffle_core::backend::font::NullFontBackend;
 use ruffle_core::backend::navigator::{NullExecutor, NullNavigatorBackend};
 use ruffle_core::backend::render::NullRenderer;
+use ruffle_core::backend::socket::NullSocketBackend;
 use ruffle_core::backend::storage::MemoryStorageBackend;
 use ruffle_core::backend::ui::NullUiBackend;
 use ruffle_core::backend::video::NullVideoBackend;
@@ -32,6 +34,8 @@ fn execute_swf(file: &Path) {
         Box::new(NullVideoBackend::new()),
         Box::new(ScanLogBackend::new()),
         Box::new(NullUiBackend::new()),
+        Box::new(NullFontBackend::new()),
+        Box::new(NullSocketBackend::new()),
     )
     .unwrap();
 
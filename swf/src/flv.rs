@@ -0,0 +1,533 @@
+//! Reading of FLV (Flash Video) files.
+//!
+//! An FLV file is a short header followed by a sequence of tags. Each tag
+//! holds a packet of audio, a frame of video, or script data such as the
+//! `onMetaData` event that describes the whole file.
+
+use crate::error::{Error, Result};
+use crate::types::{AudioCompression, SoundFormat, VideoCodec};
+
+/// The header at the start of an FLV file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Header {
+    pub version: u8,
+    pub has_audio: bool,
+    pub has_video: bool,
+}
+
+/// A single tag of an FLV file.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tag<'a> {
+    /// The time that this tag applies at, in milliseconds from the start of
+    /// the file.
+    pub timestamp: u32,
+
+    pub data: TagData<'a>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TagData<'a> {
+    Audio(AudioData<'a>),
+    Video(VideoData<'a>),
+    Script(ScriptData),
+
+    /// A tag that is either of an unknown type, or that uses a codec that
+    /// can't be represented.
+    Unknown {
+        tag_type: u8,
+        data: &'a [u8],
+    },
+}
+
+/// A packet of audio.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AudioData<'a> {
+    pub format: SoundFormat,
+    pub data: &'a [u8],
+}
+
+/// A frame of video.
+///
+/// The data is in the same form as the `VideoFrame` tags of a SWF, so that it
+/// can be handed to the same decoders. For VP6, this means that the leading
+/// byte holding the crop adjustment has been removed.
+#[derive(Debug, PartialEq, Clone)]
+pub struct VideoData<'a> {
+    pub frame_type: FrameType,
+    pub codec: VideoCodec,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromPrimitive)]
+pub enum FrameType {
+    Keyframe = 1,
+    Interframe = 2,
+    DisposableInterframe = 3,
+    GeneratedKeyframe = 4,
+    InfoFrame = 5,
+}
+
+impl FrameType {
+    pub fn from_u8(n: u8) -> Option<Self> {
+        num_traits::FromPrimitive::from_u8(n)
+    }
+
+    /// Returns whether the frame can be decoded without any prior frames.
+    pub fn is_keyframe(self) -> bool {
+        matches!(self, FrameType::Keyframe | FrameType::GeneratedKeyframe)
+    }
+}
+
+/// A named script event, such as `onMetaData` or `onCuePoint`, along with
+/// its argument.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ScriptData {
+    pub name: String,
+    pub value: Value,
+}
+
+/// A value of script data, encoded as AMF0.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Value {
+    Number(f64),
+    Boolean(bool),
+    String(String),
+
+    /// An anonymous object or an ECMA array, as a list of property names and
+    /// values.
+    Object(Vec<(String, Value)>),
+    Null,
+    Undefined,
+    StrictArray(Vec<Value>),
+
+    /// A date, as the number of milliseconds since the Unix epoch.
+    Date(f64),
+}
+
+impl Value {
+    /// Look up a property of an object by name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        match self {
+            Value::Object(properties) => properties
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+const TAG_TYPE_AUDIO: u8 = 8;
+const TAG_TYPE_VIDEO: u8 = 9;
+const TAG_TYPE_SCRIPT: u8 = 18;
+
+/// The size of the header that precedes the data of each tag.
+const TAG_HEADER_LENGTH: usize = 11;
+
+/// The deepest nesting of script data values that will be read.
+const MAX_VALUE_DEPTH: usize = 64;
+
+/// Reads the tags of an FLV file.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    header: Header,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Start reading an FLV file, returning an error if it doesn't begin with
+    /// a valid header.
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        if data.len() < 9 || &data[0..3] != b"FLV" {
+            return Err(Error::invalid_data("Not an FLV file"));
+        }
+
+        let flags = data[4];
+        let header = Header {
+            version: data[3],
+            has_audio: flags & 0b100 != 0,
+            has_video: flags & 0b1 != 0,
+        };
+        let data_offset = u32::from_be_bytes([data[5], data[6], data[7], data[8]]) as usize;
+
+        Ok(Self {
+            data,
+            header,
+            // The first tag is preceded by the size of the non-existent tag before it.
+            pos: data_offset + 4,
+        })
+    }
+
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// The byte offset of the next tag to be read.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Continue reading from a byte offset previously returned by `position`.
+    pub fn seek(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Read the next tag.
+    ///
+    /// Returns `None` once the end of the data is reached, or if the data
+    /// ends part of the way through a tag.
+    pub fn read_tag(&mut self) -> Result<Option<Tag<'a>>> {
+        let header = match self.data.get(self.pos..self.pos + TAG_HEADER_LENGTH) {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        // The upper bits of the tag type are reserved, aside from a flag for
+        // encrypted content.
+        let tag_type = header[0] & 0b1_1111;
+        let data_size = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+        let timestamp = u32::from_be_bytes([header[7], header[4], header[5], header[6]]);
+
+        let start = self.pos + TAG_HEADER_LENGTH;
+        let data = match self.data.get(start..start + data_size) {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+
+        // Each tag is followed by its total size.
+        self.pos = start + data_size + 4;
+
+        let data = match tag_type {
+            TAG_TYPE_AUDIO => read_audio_data(data)?,
+            TAG_TYPE_VIDEO => read_video_data(data)?,
+            TAG_TYPE_SCRIPT => TagData::Script(read_script_data(data)?),
+            _ => TagData::Unknown { tag_type, data },
+        };
+
+        Ok(Some(Tag { timestamp, data }))
+    }
+}
+
+fn read_audio_data(data: &[u8]) -> Result<TagData<'_>> {
+    let flags = *data
+        .first()
+        .ok_or_else(|| Error::invalid_data("Empty FLV audio tag"))?;
+
+    let compression = match AudioCompression::from_u8(flags >> 4) {
+        Some(compression) => compression,
+        // AAC and the other codecs that don't appear in SWFs.
+        None => {
+            return Ok(TagData::Unknown {
+                tag_type: TAG_TYPE_AUDIO,
+                data,
+            })
+        }
+    };
+    let sample_rate = match compression {
+        AudioCompression::Nellymoser8Khz => 8000,
+        AudioCompression::Nellymoser16Khz => 16000,
+        _ => [5512, 11025, 22050, 44100][usize::from((flags >> 2) & 0b11)],
+    };
+
+    Ok(TagData::Audio(AudioData {
+        format: SoundFormat {
+            compression,
+            sample_rate,
+            is_16_bit: flags & 0b10 != 0,
+            is_stereo: flags & 0b1 != 0,
+        },
+        data: &data[1..],
+    }))
+}
+
+fn read_video_data(data: &[u8]) -> Result<TagData<'_>> {
+    let flags = *data
+        .first()
+        .ok_or_else(|| Error::invalid_data("Empty FLV video tag"))?;
+
+    let frame_type = FrameType::from_u8(flags >> 4)
+        .ok_or_else(|| Error::invalid_data("Invalid FLV video frame type"))?;
+    let codec = match VideoCodec::from_u8(flags & 0b1111) {
+        Some(codec) => codec,
+        // AVC, which doesn't appear in SWFs.
+        None => {
+            return Ok(TagData::Unknown {
+                tag_type: TAG_TYPE_VIDEO,
+                data,
+            })
+        }
+    };
+
+    let data = match codec {
+        // Skip the crop adjustment, which SWF video frames don't have.
+        VideoCodec::Vp6 | VideoCodec::Vp6WithAlpha => data.get(2..).unwrap_or_default(),
+        _ => &data[1..],
+    };
+
+    Ok(TagData::Video(VideoData {
+        frame_type,
+        codec,
+        data,
+    }))
+}
+
+fn read_script_data(data: &[u8]) -> Result<ScriptData> {
+    let mut reader = ValueReader { data, pos: 0 };
+    let name = match reader.read_value(0)? {
+        Value::String(name) => name,
+        _ => return Err(Error::invalid_data("FLV script data has no name")),
+    };
+    // Some encoders write the name without a value.
+    let value = if reader.pos < data.len() {
+        reader.read_value(0)?
+    } else {
+        Value::Undefined
+    };
+
+    Ok(ScriptData { name, value })
+}
+
+/// Reads AMF0-encoded script data values.
+struct ValueReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ValueReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let bytes = self
+            .data
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| Error::invalid_data("Unexpected end of FLV script data"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16> {
+        let bytes = self.read_bytes(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_f64(&mut self) -> Result<f64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(self.read_bytes(8)?);
+        Ok(f64::from_be_bytes(bytes))
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let len = self.read_u16()?.into();
+        Ok(String::from_utf8_lossy(self.read_bytes(len)?).into_owned())
+    }
+
+    fn read_long_string(&mut self) -> Result<String> {
+        let len = self.read_u32()? as usize;
+        Ok(String::from_utf8_lossy(self.read_bytes(len)?).into_owned())
+    }
+
+    /// Read the properties of an object up to its end marker.
+    fn read_properties(&mut self, depth: usize) -> Result<Vec<(String, Value)>> {
+        let mut properties = Vec::new();
+        loop {
+            let name = self.read_string()?;
+            // The end marker is an empty name followed by the end type. Some
+            // encoders leave it out at the end of the data.
+            if name.is_empty() && self.data.get(self.pos).map_or(true, |&t| t == 9) {
+                self.pos += 1;
+                return Ok(properties);
+            }
+            let value = self.read_value(depth + 1)?;
+            properties.push((name, value));
+        }
+    }
+
+    fn read_value(&mut self, depth: usize) -> Result<Value> {
+        if depth > MAX_VALUE_DEPTH {
+            return Err(Error::invalid_data("FLV script data is nested too deeply"));
+        }
+
+        Ok(match self.read_u8()? {
+            0 => Value::Number(self.read_f64()?),
+            1 => Value::Boolean(self.read_u8()? != 0),
+            2 => Value::String(self.read_string()?),
+            3 => Value::Object(self.read_properties(depth)?),
+            // A movie clip path; unused.
+            4 => {
+                self.read_string()?;
+                Value::Undefined
+            }
+            5 => Value::Null,
+            6 => Value::Undefined,
+            // A reference to an earlier object; unsupported by FLV readers.
+            7 => {
+                self.read_u16()?;
+                Value::Undefined
+            }
+            8 => {
+                // The array length is only a hint.
+                self.read_u32()?;
+                Value::Object(self.read_properties(depth)?)
+            }
+            10 => {
+                let len = self.read_u32()?;
+                let mut values = Vec::new();
+                for _ in 0..len {
+                    values.push(self.read_value(depth + 1)?);
+                }
+                Value::StrictArray(values)
+            }
+            11 => {
+                let time = self.read_f64()?;
+                // The time zone offset is unused.
+                self.read_u16()?;
+                Value::Date(time)
+            }
+            12 => Value::String(self.read_long_string()?),
+            value_type => {
+                return Err(Error::invalid_data(format!(
+                    "Unknown FLV script data type {}",
+                    value_type
+                )))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build an FLV file out of tags of the given types and data.
+    fn flv(tags: &[(u8, u32, Vec<u8>)]) -> Vec<u8> {
+        let mut data = b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00".to_vec();
+        for (tag_type, timestamp, tag_data) in tags {
+            let size = tag_data.len() as u32;
+            data.push(*tag_type);
+            data.extend_from_slice(&size.to_be_bytes()[1..]);
+            data.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+            data.push((timestamp >> 24) as u8);
+            data.extend_from_slice(&[0, 0, 0]);
+            data.extend_from_slice(tag_data);
+            data.extend_from_slice(&(size + 11).to_be_bytes());
+        }
+        data
+    }
+
+    fn amf_string(s: &str) -> Vec<u8> {
+        let mut data = vec![2];
+        data.extend_from_slice(&(s.len() as u16).to_be_bytes());
+        data.extend_from_slice(s.as_bytes());
+        data
+    }
+
+    #[test]
+    fn read_audio_and_video_tags() {
+        let data = flv(&[
+            (9, 0, vec![0x14, 0xAA, 0xBB, 0xCC]),
+            (8, 40, vec![0x2F, 0x11, 0x22]),
+            (9, 0x0100_0000, vec![0x22, 0x33]),
+        ]);
+        let mut reader = Reader::new(&data).unwrap();
+        assert_eq!(
+            reader.header(),
+            Header {
+                version: 1,
+                has_audio: true,
+                has_video: true
+            }
+        );
+
+        let tag = reader.read_tag().unwrap().unwrap();
+        assert_eq!(tag.timestamp, 0);
+        assert_eq!(
+            tag.data,
+            TagData::Video(VideoData {
+                frame_type: FrameType::Keyframe,
+                codec: VideoCodec::Vp6,
+                data: &[0xBB, 0xCC],
+            })
+        );
+
+        let tag = reader.read_tag().unwrap().unwrap();
+        assert_eq!(tag.timestamp, 40);
+        assert_eq!(
+            tag.data,
+            TagData::Audio(AudioData {
+                format: SoundFormat {
+                    compression: AudioCompression::Mp3,
+                    sample_rate: 44100,
+                    is_16_bit: true,
+                    is_stereo: true,
+                },
+                data: &[0x11, 0x22],
+            })
+        );
+
+        let tag = reader.read_tag().unwrap().unwrap();
+        assert_eq!(tag.timestamp, 0x0100_0000);
+        assert_eq!(
+            tag.data,
+            TagData::Video(VideoData {
+                frame_type: FrameType::Interframe,
+                codec: VideoCodec::H263,
+                data: &[0x33],
+            })
+        );
+
+        assert_eq!(reader.read_tag().unwrap(), None);
+    }
+
+    #[test]
+    fn read_truncated_tag() {
+        let mut data = flv(&[(9, 0, vec![0x12, 0x00, 0x00, 0x00])]);
+        data.truncate(data.len() - 6);
+        let mut reader = Reader::new(&data).unwrap();
+        assert_eq!(reader.read_tag().unwrap(), None);
+    }
+
+    #[test]
+    fn read_metadata() {
+        let mut script = amf_string("onMetaData");
+        script.extend_from_slice(&[8, 0, 0, 0, 2]);
+        script.extend_from_slice(&[0, 8]);
+        script.extend_from_slice(b"duration");
+        script.push(0);
+        script.extend_from_slice(&12.5f64.to_be_bytes());
+        script.extend_from_slice(&[0, 5]);
+        script.extend_from_slice(b"title");
+        script.extend_from_slice(&amf_string("Intro"));
+        script.extend_from_slice(&[0, 0, 9]);
+
+        let data = flv(&[(18, 0, script)]);
+        let mut reader = Reader::new(&data).unwrap();
+        let tag = reader.read_tag().unwrap().unwrap();
+        let script = match tag.data {
+            TagData::Script(script) => script,
+            _ => panic!("Expected script data"),
+        };
+        assert_eq!(script.name, "onMetaData");
+        assert_eq!(
+            script.value.get("duration").and_then(Value::as_number),
+            Some(12.5)
+        );
+        assert_eq!(
+            script.value.get("title"),
+            Some(&Value::String("Intro".to_string()))
+        );
+    }
+}
@@ -20,6 +20,7 @@ pub mod avm2;
 pub mod error;
 // TODO: Make this private?
 pub mod extensions;
+pub mod flv;
 pub mod read;
 mod string;
 mod tag_code;
@@ -3,14 +3,16 @@
 //! Trace output can be compared with correct output from the official Flash Player.
 
 use approx::assert_relative_eq;
-use ruffle_core::backend::render::RenderBackend;
+use ruffle_core::backend::render::{BitmapFormat, RenderBackend};
 use ruffle_core::backend::video::SoftwareVideoBackend;
 use ruffle_core::backend::video::VideoBackend;
 use ruffle_core::backend::{
     audio::NullAudioBackend,
+    font::MemoryFontBackend,
     log::LogBackend,
     navigator::{NullExecutor, NullNavigatorBackend},
     render::NullRenderer,
+    socket::{ScriptedSocketBackend, SocketStep},
     storage::{MemoryStorageBackend, StorageBackend},
     ui::NullUiBackend,
     video::NullVideoBackend,
@@ -19,7 +21,8 @@ use ruffle_core::context::UpdateContext;
 use ruffle_core::external::Value as ExternalValue;
 use ruffle_core::external::{ExternalInterfaceMethod, ExternalInterfaceProvider};
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::Player;
+use ruffle_core::{Player, PlayerEvent};
+use ruffle_render_software::SoftwareRenderBackend;
 use ruffle_render_wgpu::target::TextureTarget;
 use ruffle_render_wgpu::wgpu;
 use ruffle_render_wgpu::WgpuRenderBackend;
@@ -35,6 +38,16 @@ fn get_img_platform_suffix(info: &wgpu::AdapterInfo) -> String {
 }
 
 const RUN_IMG_TESTS: bool = cfg!(feature = "imgtests");
+const USE_SOFTWARE_RENDERER: bool = cfg!(feature = "software_renderer");
+
+/// How far apart each channel of a pixel may be for it to match, when
+/// comparing the software renderer's output against a hardware image.
+const SOFTWARE_RENDERER_CHANNEL_TOLERANCE: i16 = 8;
+
+/// The fraction of pixels that may not match at all, when comparing the
+/// software renderer's output against a hardware image. Antialiased edges
+/// are rasterized differently, so they can't be expected to match.
+const SOFTWARE_RENDERER_MISMATCH_TOLERANCE: f64 = 0.005;
 
 fn set_logger() {
     let _ = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info"))
@@ -167,6 +180,9 @@ swf_tests! {
     (as3_bitmap_timeline, "avm2/bitmap_timeline", 1),
     (as3_bitmapdata_constr, "avm2/bitmapdata_constr", 1),
     #[ignore] (as3_bitmapdata_embedded, "avm2/bitmapdata_embedded", 1),
+    (as3_bitmapdata_large_rect, "avm2/bitmapdata_large_rect", 1),
+    (as3_bitmapdata_methods, "avm2/bitmapdata_methods", 1),
+    (as3_bitmapdata_threshold_dissolve, "avm2/bitmapdata_threshold_dissolve", 1),
     (as3_bitnot, "avm2/bitnot", 1),
     (as3_bitor, "avm2/bitor", 1),
     (as3_bitxor, "avm2/bitxor", 1),
@@ -208,11 +224,16 @@ swf_tests! {
     (as3_dictionary_in, "avm2/dictionary_in", 1),
     (as3_dictionary_namespaces, "avm2/dictionary_namespaces", 1),
     (as3_displayobject_alpha, "avm2/displayobject_alpha", 1),
+    (as3_displayobject_blendmode, "avm2/displayobject_blendmode", 1),
+    (as3_displayobject_blendmode_render, "avm2/displayobject_blendmode_render", 1, img = true),
+    (as3_displayobject_filters_render, "avm2/displayobject_filters_render", 1, img = true),
     (as3_displayobject_hittestobject, "avm2/displayobject_hittestobject", 1),
     (as3_displayobject_hittestpoint, "avm2/displayobject_hittestpoint", 2),
     (as3_displayobject_name, "avm2/displayobject_name", 4),
     (as3_displayobject_parent, "avm2/displayobject_parent", 4),
     (as3_displayobject_root, "avm2/displayobject_root", 4),
+    (as3_displayobject_scrollrect_cacheasbitmap, "avm2/displayobject_scrollrect_cacheasbitmap", 1),
+    (as3_displayobject_transform, "avm2/displayobject_transform", 1),
     (as3_displayobject_visible, "avm2/displayobject_visible", 4),
     (as3_displayobject_x, "avm2/displayobject_x", 1),
     (as3_displayobject_y, "avm2/displayobject_y", 1),
@@ -267,6 +288,7 @@ swf_tests! {
     (as3_eventdispatcher_tostring, "avm2/eventdispatcher_tostring", 1),
     (as3_eventdispatcher_willtrigger, "avm2/eventdispatcher_willtrigger", 1),
     (as3_falsiness, "avm2/falsiness", 1),
+    (as3_filters, "avm2/filters", 1),
     (as3_font_embedded, "avm2/font_embedded", 1),
     (as3_font_hasglyphs, "avm2/font_hasglyphs", 1),
     (as3_framelabel_constr, "avm2/framelabel_constr", 5),
@@ -327,8 +349,10 @@ swf_tests! {
     (as3_lazyinit, "avm2/lazyinit", 1),
     (as3_lessequals, "avm2/lessequals", 1),
     (as3_lessthan, "avm2/lessthan", 1),
+    (as3_loader_load, "avm2/loader_load", 1),
     (as3_loaderinfo_properties, "avm2/loaderinfo_properties", 2),
     (as3_loaderinfo_quine, "avm2/loaderinfo_quine", 2),
+    (as3_local_connection, "avm2/local_connection", 6),
     (as3_lshift, "avm2/lshift", 1),
     (as3_modulo, "avm2/modulo", 1),
     (as3_mouseevent_constr, "avm2/mouseevent_constr", 1),
@@ -364,6 +388,7 @@ swf_tests! {
     (as3_multiply, "avm2/multiply", 1),
     (as3_nan_scale, "avm2/nan_scale", 1),
     (as3_negate, "avm2/negate", 1),
+    (as3_netstream_flv, "avm2/netstream_flv", 15),
     (as3_nonconflicting_declarations, "avm2/nonconflicting_declarations", 1),
     (as3_number_constr, "avm2/number_constr", 1),
     #[ignore] (as3_number_tostring, "avm2/number_tostring", 1), //Ignored because Flash Player adds extra x, W, and/or ° symbols randomly
@@ -385,6 +410,7 @@ swf_tests! {
     (as3_propertyisenumerable_namespaces, "avm2/propertyisenumerable_namespaces", 1),
     (as3_proxy_callproperty, "avm2/proxy_callproperty", 1),
     (as3_proxy_deleteproperty, "avm2/proxy_deleteproperty", 1),
+    (as3_proxy_dispatch, "avm2/proxy_dispatch", 1),
     (as3_proxy_enumeration, "avm2/proxy_enumeration", 1),
     (as3_proxy_getproperty, "avm2/proxy_getproperty", 1),
     (as3_proxy_hasproperty, "avm2/proxy_hasproperty", 1),
@@ -401,6 +427,7 @@ swf_tests! {
     (as3_scene_constr, "avm2/scene_constr", 5),
     (as3_set_property_is_enumerable, "avm2/set_property_is_enumerable", 1),
     (as3_shape_drawrect, "avm2/shape_drawrect", 1),
+    (as3_shared_object, "avm2/shared_object", 1),
     (as3_simplebutton_childevents_nested, "avm2/simplebutton_childevents_nested", 2),
     (as3_simplebutton_childevents, "avm2/simplebutton_childevents", 2),
     (as3_simplebutton_childprops, "avm2/simplebutton_childprops", 1),
@@ -409,10 +436,12 @@ swf_tests! {
     (as3_simplebutton_constr_params, "avm2/simplebutton_constr_params", 1),
     (as3_simplebutton_constr, "avm2/simplebutton_constr", 2),
     (as3_simplebutton_mouseenabled, "avm2/simplebutton_mouseenabled", 1),
+    (as3_simplebutton_scale9grid, "avm2/simplebutton_scale9grid", 1, img = true),
     (as3_simplebutton_soundtransform, "avm2/simplebutton_soundtransform", 49),
     (as3_simplebutton_structure, "avm2/simplebutton_structure", 2),
     (as3_simplebutton_symbolclass, "avm2/simplebutton_symbolclass", 3),
     (as3_sound_embeddedprops, "avm2/sound_embeddedprops", 1),
+    (as3_sound_load, "avm2/sound_load", 3),
     (as3_sound_play, "avm2/sound_play", 1),
     (as3_sound_valueof, "avm2/sound_valueof", 1),
     #[ignore] (as3_soundchannel_position, "avm2/soundchannel_position", 75),
@@ -439,12 +468,16 @@ swf_tests! {
     (as3_string_length, "avm2/string_length", 1),
     (as3_string_match, "avm2/string_match", 1),
     (as3_string_replace, "avm2/string_replace", 1),
+    (as3_string_replace_split_regexp, "avm2/string_replace_split_regexp", 1),
     (as3_string_slice_substr_substring, "avm2/string_slice_substr_substring", 1),
     (as3_string_split, "avm2/string_split", 1),
+    (as3_stylesheet, "avm2/stylesheet", 1),
     (as3_subtract, "avm2/subtract", 1),
     (as3_symbol_class_binary_data, "avm2/symbol_class_binary_data", 1),
+    (as3_textfield_device_fonts, "avm2/textfield_device_fonts", 1),
     (as3_textformat, "avm2/textformat", 1),
     (as3_timeline_scripts, "avm2/timeline_scripts", 3),
+    (as3_timer_events, "avm2/timer_events", 20),
     (as3_trace, "avm2/trace", 1),
     (as3_truthiness, "avm2/truthiness", 1),
     (as3_typeof, "avm2/typeof", 1),
@@ -454,6 +487,7 @@ swf_tests! {
     #[ignore] (as3_uint_toprecision, "avm2/uint_toprecision", 1), //Ignored because Flash Player has a print routine that adds extraneous zeros to things
     (as3_uint_tostring, "avm2/uint_tostring", 1),
     (as3_urshift, "avm2/urshift", 1),
+    (as3_url_loader, "avm2/url_loader", 1),
     (as3_vector_coercion, "avm2/vector_coercion", 1),
     (as3_vector_concat, "avm2/vector_concat", 1),
     (as3_vector_constr, "avm2/vector_constr", 1),
@@ -479,6 +513,8 @@ swf_tests! {
     (as3_vector_tostring, "avm2/vector_tostring", 1),
     (as3_virtual_properties, "avm2/virtual_properties", 1),
     (as3_with, "avm2/with", 1),
+    (as3_xml_e4x, "avm2/xml_e4x", 1),
+    (as3_xml_methods, "avm2/xml_methods", 1),
     (attach_movie, "avm1/attach_movie", 1),
     (bad_placeobject_clipaction, "avm1/bad_placeobject_clipaction", 2),
     (bad_swf_tag_past_eof, "avm1/bad_swf_tag_past_eof", 1),
@@ -971,6 +1007,70 @@ fn timeout_avm1() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn as3_socket() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/socket/test.swf",
+        6,
+        "tests/swfs/avm2/socket/output.txt",
+        |player| {
+            let mut player = player.lock().unwrap();
+            let sockets = player
+                .sockets_mut()
+                .downcast_mut::<ScriptedSocketBackend>()
+                .unwrap();
+            sockets.add_script(
+                "localhost",
+                1234,
+                vec![
+                    SocketStep::Send(
+                        b"\x00\x2a\x00\x05hello\x3f\xf8\x00\x00\x00\x00\x00\x00".to_vec(),
+                    ),
+                    SocketStep::Expect(b"\x00\x00\x00\x07\x00\x04ping".to_vec()),
+                    SocketStep::Send(b"\xff".to_vec()),
+                    SocketStep::Close,
+                ],
+            );
+            sockets.add_script(
+                "localhost",
+                1235,
+                vec![
+                    SocketStep::Send(b"<hello/>\0<two/>\0<par".to_vec()),
+                    SocketStep::Expect(b"<ping/>\0".to_vec()),
+                    SocketStep::Send(b"tial/>\0".to_vec()),
+                    SocketStep::Close,
+                ],
+            );
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+    )
+}
+
+#[test]
+fn as3_textfield_restrict() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/textfield_restrict/test.swf",
+        2,
+        "tests/swfs/avm2/textfield_restrict/output.txt",
+        |_| Ok(()),
+        |player| {
+            // Type into the text field that the movie focused, then run a frame so that it can
+            // trace the result.
+            let mut player = player.lock().unwrap();
+            for codepoint in "1a2b34".chars() {
+                player.handle_event(PlayerEvent::TextInput { codepoint });
+            }
+            player.run_frame();
+            Ok(())
+        },
+        false,
+    )
+}
+
 #[test]
 fn stage_scale_mode() -> Result<(), Error> {
     set_logger();
@@ -1134,7 +1234,17 @@ fn run_swf(
     let backend_bit = wgpu::Backends::PRIMARY;
 
     let (render_backend, video_backend): (Box<dyn RenderBackend>, Box<dyn VideoBackend>) =
-        if check_img {
+        if check_img && USE_SOFTWARE_RENDERER {
+            // The software renderer draws the same on every machine.
+            platform_id = Some("software".to_string());
+
+            let render_backend = Box::new(SoftwareRenderBackend::new(
+                movie.width().to_pixels() as u32,
+                movie.height().to_pixels() as u32,
+            ));
+            let video_backend = Box::new(SoftwareVideoBackend::new());
+            (render_backend, video_backend)
+        } else if check_img {
             let instance = wgpu::Instance::new(backend_bit);
 
             let descriptors = futures::executor::block_on(
@@ -1161,9 +1271,25 @@ fn run_swf(
             let video_backend = Box::new(SoftwareVideoBackend::new());
             (render_backend, video_backend)
         } else {
-            (Box::new(NullRenderer), Box::new(NullVideoBackend::new()))
+            (
+                Box::new(NullRenderer::new()),
+                Box::new(NullVideoBackend::new()),
+            )
         };
 
+    // Device text is rendered with the fonts next to the test movie, so that it draws the same on
+    // every machine.
+    let mut font_backend = MemoryFontBackend::new();
+    for entry in std::fs::read_dir(base_path)? {
+        let path = entry?.path();
+        let is_font = path
+            .extension()
+            .map_or(false, |extension| extension == "ttf" || extension == "otf");
+        if is_font {
+            font_backend.add_font(std::fs::read(path)?);
+        }
+    }
+
     let player = Player::new(
         render_backend,
         Box::new(NullAudioBackend::new()),
@@ -1172,7 +1298,15 @@ fn run_swf(
         video_backend,
         Box::new(TestLogBackend::new(trace_output.clone())),
         Box::new(NullUiBackend::new()),
+        Box::new(font_backend),
+        Box::new(ScriptedSocketBackend::new()),
     )?;
+    // Test movies are trusted local files, as in the standalone Flash Player.
+    player
+        .lock()
+        .unwrap()
+        .security_mut()
+        .set_trust_local_content(true);
     player.lock().unwrap().set_root_movie(Arc::new(movie));
     player
         .lock()
@@ -1184,6 +1318,9 @@ fn run_swf(
     for _ in 0..num_frames {
         player.lock().unwrap().run_frame();
         player.lock().unwrap().update_timers(frame_time);
+        player.lock().unwrap().update_streams(frame_time);
+        player.lock().unwrap().update_sockets();
+        player.lock().unwrap().update_local_connections();
         executor.run();
     }
 
@@ -1192,14 +1329,22 @@ fn run_swf(
     if check_img {
         player.lock().unwrap().render();
         let mut player_lock = player.lock().unwrap();
-        let renderer = player_lock
-            .renderer_mut()
-            .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
-            .unwrap();
-        let target = renderer.target();
-        let image = target
-            .capture(renderer.device())
-            .expect("Failed to capture image");
+        let renderer = player_lock.renderer_mut();
+        let image = if let Some(renderer) = renderer.downcast_ref::<SoftwareRenderBackend>() {
+            let bitmap = renderer.capture();
+            match bitmap.data {
+                BitmapFormat::Rgba(rgba) => {
+                    image::RgbaImage::from_raw(bitmap.width, bitmap.height, rgba)
+                }
+                BitmapFormat::Rgb(_) => None,
+            }
+        } else {
+            let renderer = renderer
+                .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
+                .unwrap();
+            renderer.target().capture(renderer.device())
+        }
+        .expect("Failed to capture image");
 
         // The swf path ends in '<swf_name>/test.swf' - extract `swf_name`
         let mut swf_path_buf = PathBuf::from(swf_path);
@@ -1212,6 +1357,9 @@ fn run_swf(
         img_path.push(&img_name);
 
         let result = match image::open(&img_path) {
+            Err(_) if USE_SOFTWARE_RENDERER && !img_path.exists() => {
+                compare_with_hardware_images(&swf_path_buf, &swf_name, &image)
+            }
             Ok(existing_img) => {
                 if existing_img
                     .as_rgba8()
@@ -1251,6 +1399,63 @@ fn run_swf(
     Ok(trace)
 }
 
+/// Compare the software renderer's output against the images of the
+/// hardware renderers, for tests without a `-software.png` image of their own.
+fn compare_with_hardware_images(
+    dir: &Path,
+    swf_name: &str,
+    image: &image::RgbaImage,
+) -> Result<(), String> {
+    let prefix = format!("{}-", swf_name);
+    let mut img_paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|err| format!("Error occured when trying to read `{:?}`: {}", dir, err))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            name.starts_with(&prefix) && name.ends_with(".png")
+        })
+        .collect();
+    img_paths.sort();
+
+    if img_paths.is_empty() {
+        return Err(format!("No existing image to compare with in `{:?}`", dir));
+    }
+
+    for img_path in &img_paths {
+        let existing_img = image::open(img_path)
+            .map_err(|err| {
+                format!(
+                    "Error occured when trying to read existing image `{:?}`: {}",
+                    img_path, err
+                )
+            })?
+            .to_rgba8();
+        if existing_img.dimensions() != image.dimensions() {
+            continue;
+        }
+
+        let mismatched = existing_img
+            .pixels()
+            .zip(image.pixels())
+            .filter(|(a, b)| {
+                a.0.iter().zip(b.0.iter()).any(|(a, b)| {
+                    (i16::from(*a) - i16::from(*b)).abs() > SOFTWARE_RENDERER_CHANNEL_TOLERANCE
+                })
+            })
+            .count();
+        let allowed =
+            f64::from(image.width() * image.height()) * SOFTWARE_RENDERER_MISMATCH_TOLERANCE;
+        if mismatched as f64 <= allowed {
+            return Ok(());
+        }
+    }
+
+    Err(format!(
+        "Test output does not match any existing image `{:?}`",
+        img_paths
+    ))
+}
+
 struct TestLogBackend {
     trace_output: Rc<RefCell<Vec<String>>>,
 }
@@ -0,0 +1,61 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.BitmapData;
+import flash.geom.ColorTransform;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+import flash.utils.ByteArray;
+
+trace("///var bd = new BitmapData(4, 4, false, 0xFF000000);");
+var bd = new BitmapData(4, 4, false, 0xFF000000);
+
+trace("///bd.fillRect(new Rectangle(1, 1, int.MAX_VALUE, 1), 0xFF0000FF);");
+bd.fillRect(new Rectangle(1, 1, int.MAX_VALUE, 1), 0xFF0000FF);
+
+trace("///bd.getPixel(0, 1);");
+trace(bd.getPixel(0, 1));
+
+trace("///bd.getPixel(3, 1);");
+trace(bd.getPixel(3, 1));
+
+trace("///bd.getPixel(3, 2);");
+trace(bd.getPixel(3, 2));
+
+trace("///bd.fillRect(new Rectangle(int.MAX_VALUE, 0, int.MAX_VALUE, 1), 0xFFFFFFFF);");
+bd.fillRect(new Rectangle(int.MAX_VALUE, 0, int.MAX_VALUE, 1), 0xFFFFFFFF);
+
+trace("///bd.getPixel(3, 0);");
+trace(bd.getPixel(3, 0));
+
+trace("///var bytes = bd.getPixels(new Rectangle(1, 1, int.MAX_VALUE, 1));");
+var bytes = bd.getPixels(new Rectangle(1, 1, int.MAX_VALUE, 1));
+
+trace("///bytes.length;");
+trace(bytes.length);
+
+trace("///bd.setPixels(new Rectangle(1, 3, int.MAX_VALUE, int.MAX_VALUE), bytes);");
+bd.setPixels(new Rectangle(1, 3, int.MAX_VALUE, int.MAX_VALUE), bytes);
+
+trace("///bd.getPixel(0, 3);");
+trace(bd.getPixel(0, 3));
+
+trace("///bd.getPixel(3, 3);");
+trace(bd.getPixel(3, 3));
+
+trace("///bd.colorTransform(new Rectangle(2, 0, int.MAX_VALUE, int.MAX_VALUE), new ColorTransform(0, 0, 0, 1, 255, 0, 0, 0));");
+bd.colorTransform(new Rectangle(2, 0, int.MAX_VALUE, int.MAX_VALUE), new ColorTransform(0, 0, 0, 1, 255, 0, 0, 0));
+
+trace("///bd.getPixel(1, 1);");
+trace(bd.getPixel(1, 1));
+
+trace("///bd.getPixel(3, 3);");
+trace(bd.getPixel(3, 3));
+
+trace("///bd.hitTest(new Point(0, 0), 0xFF, new Rectangle(1, 1, int.MAX_VALUE, int.MAX_VALUE));");
+trace(bd.hitTest(new Point(0, 0), 0xFF, new Rectangle(1, 1, int.MAX_VALUE, int.MAX_VALUE)));
+
+trace("///bd.hitTest(new Point(int.MIN_VALUE, 0), 0xFF, new Rectangle(int.MAX_VALUE, 0, 1, 1));");
+trace(bd.hitTest(new Point(int.MIN_VALUE, 0), 0xFF, new Rectangle(int.MAX_VALUE, 0, 1, 1)));
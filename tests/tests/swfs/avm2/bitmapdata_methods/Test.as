@@ -0,0 +1,169 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.BitmapData;
+import flash.geom.ColorTransform;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+import flash.utils.ByteArray;
+
+trace("///var bd = new BitmapData(4, 4, false, 0xFF000000);");
+var bd = new BitmapData(4, 4, false, 0xFF000000);
+
+trace("///bd.rect;");
+trace(bd.rect);
+
+trace("///bd.setPixel32(1, 2, 0xFFFF0000);");
+bd.setPixel32(1, 2, 0xFFFF0000);
+
+trace("///bd.getPixel32(1, 2);");
+trace(bd.getPixel32(1, 2));
+
+trace("///bd.getPixel(1, 2);");
+trace(bd.getPixel(1, 2));
+
+trace("///bd.getPixel(10, 10);");
+trace(bd.getPixel(10, 10));
+
+trace("///bd.getPixel32(-1, 0);");
+trace(bd.getPixel32(-1, 0));
+
+trace("///bd.setPixel(0, 0, 0x00FF00);");
+bd.setPixel(0, 0, 0x00FF00);
+
+trace("///bd.getPixel32(0, 0);");
+trace(bd.getPixel32(0, 0));
+
+trace("///bd.fillRect(new Rectangle(2, 2, 5, 5), 0xFF0000FF);");
+bd.fillRect(new Rectangle(2, 2, 5, 5), 0xFF0000FF);
+
+trace("///bd.getPixel(3, 3);");
+trace(bd.getPixel(3, 3));
+
+trace("///bd.getPixel(1, 2);");
+trace(bd.getPixel(1, 2));
+
+trace("///bd.getColorBoundsRect(0xFFFFFFFF, 0xFF0000FF, true);");
+trace(bd.getColorBoundsRect(0xFFFFFFFF, 0xFF0000FF, true));
+
+trace("///bd.getColorBoundsRect(0xFFFFFFFF, 0xFF000000, false);");
+trace(bd.getColorBoundsRect(0xFFFFFFFF, 0xFF000000, false));
+
+trace("///var copy = bd.clone();");
+var copy = bd.clone();
+
+trace("///copy.getPixel(1, 2);");
+trace(copy.getPixel(1, 2));
+
+trace("///bd.compare(copy);");
+trace(bd.compare(copy));
+
+trace("///copy.setPixel(1, 2, 0x000010);");
+copy.setPixel(1, 2, 0x000010);
+
+trace("///var diff = bd.compare(copy);");
+var diff = bd.compare(copy);
+
+trace("///diff.transparent;");
+trace(diff.transparent);
+
+trace("///diff.getPixel32(1, 2);");
+trace(diff.getPixel32(1, 2));
+
+trace("///diff.getPixel32(0, 0);");
+trace(diff.getPixel32(0, 0));
+
+trace("///bd.compare(new BitmapData(3, 4));");
+trace(bd.compare(new BitmapData(3, 4)));
+
+trace("///bd.compare(new BitmapData(4, 3));");
+trace(bd.compare(new BitmapData(4, 3)));
+
+trace("///var dst = new BitmapData(4, 4, false, 0xFFFFFFFF);");
+var dst = new BitmapData(4, 4, false, 0xFFFFFFFF);
+
+trace("///dst.copyPixels(bd, new Rectangle(1, 2, 2, 1), new Point(0, 0));");
+dst.copyPixels(bd, new Rectangle(1, 2, 2, 1), new Point(0, 0));
+
+trace("///dst.getPixel(0, 0);");
+trace(dst.getPixel(0, 0));
+
+trace("///dst.getPixel(1, 0);");
+trace(dst.getPixel(1, 0));
+
+trace("///dst.getPixel(2, 0);");
+trace(dst.getPixel(2, 0));
+
+trace("///var t = new BitmapData(4, 4, false, 0xFF000000);");
+var t = new BitmapData(4, 4, false, 0xFF000000);
+
+trace("///t.threshold(bd, bd.rect, new Point(0, 0), \"==\", 0xFF0000FF, 0xFF00FFFF);");
+trace(t.threshold(bd, bd.rect, new Point(0, 0), "==", 0xFF0000FF, 0xFF00FFFF));
+
+trace("///t.getPixel(2, 2);");
+trace(t.getPixel(2, 2));
+
+trace("///t.getPixel(1, 2);");
+trace(t.getPixel(1, 2));
+
+trace("///var bytes = bd.getPixels(new Rectangle(0, 0, 2, 1));");
+var bytes = bd.getPixels(new Rectangle(0, 0, 2, 1));
+
+trace("///bytes.length;");
+trace(bytes.length);
+
+trace("///bytes.readUnsignedInt();");
+trace(bytes.readUnsignedInt());
+
+trace("///bytes.readUnsignedInt();");
+trace(bytes.readUnsignedInt());
+
+trace("///bytes.position = 0;");
+bytes.position = 0;
+
+trace("///bd.setPixels(new Rectangle(2, 0, 2, 1), bytes);");
+bd.setPixels(new Rectangle(2, 0, 2, 1), bytes);
+
+trace("///bd.getPixel(2, 0);");
+trace(bd.getPixel(2, 0));
+
+trace("///bd.scroll(1, 0);");
+bd.scroll(1, 0);
+
+trace("///bd.getPixel(1, 2);");
+trace(bd.getPixel(1, 2));
+
+trace("///bd.getPixel(2, 2);");
+trace(bd.getPixel(2, 2));
+
+trace("///bd.getPixel(3, 2);");
+trace(bd.getPixel(3, 2));
+
+trace("///bd.colorTransform(new Rectangle(3, 3, 1, 1), new ColorTransform(0, 0, 0, 1, 0, 128, 0, 0));");
+bd.colorTransform(new Rectangle(3, 3, 1, 1), new ColorTransform(0, 0, 0, 1, 0, 128, 0, 0));
+
+trace("///bd.getPixel(3, 3);");
+trace(bd.getPixel(3, 3));
+
+trace("///bd.getPixel(3, 2);");
+trace(bd.getPixel(3, 2));
+
+trace("///var h = new BitmapData(4, 4, true, 0);");
+var h = new BitmapData(4, 4, true, 0);
+
+trace("///h.setPixel32(1, 1, 0xFF000000);");
+h.setPixel32(1, 1, 0xFF000000);
+
+trace("///h.hitTest(new Point(0, 0), 255, new Point(1, 1));");
+trace(h.hitTest(new Point(0, 0), 255, new Point(1, 1)));
+
+trace("///h.hitTest(new Point(0, 0), 255, new Point(2, 2));");
+trace(h.hitTest(new Point(0, 0), 255, new Point(2, 2)));
+
+trace("///h.hitTest(new Point(0, 0), 255, new Rectangle(0, 0, 2, 2));");
+trace(h.hitTest(new Point(0, 0), 255, new Rectangle(0, 0, 2, 2)));
+
+trace("///h.hitTest(new Point(0, 0), 255, new Rectangle(2, 0, 2, 4));");
+trace(h.hitTest(new Point(0, 0), 255, new Rectangle(2, 0, 2, 4)));
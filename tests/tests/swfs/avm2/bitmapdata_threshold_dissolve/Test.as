@@ -0,0 +1,107 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.BitmapData;
+import flash.filters.BlurFilter;
+import flash.filters.ColorMatrixFilter;
+import flash.filters.GlowFilter;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+
+var src = new BitmapData(4, 4, false, 0x000000);
+src.setPixel(1, 1, 0x808080);
+src.setPixel(2, 2, 0xFF0000);
+var dst = new BitmapData(4, 4, false, 0xFFFFFF);
+
+trace("///dst.threshold(src, src.rect, new Point(0, 0), \">\", 0, 0xFF00FF00, 0x00FFFFFF, false);");
+trace(dst.threshold(src, src.rect, new Point(0, 0), ">", 0, 0xFF00FF00, 0x00FFFFFF, false));
+
+trace("///dst.getPixel(0, 0), dst.getPixel(1, 1), dst.getPixel(2, 2);");
+trace(dst.getPixel(0, 0), dst.getPixel(1, 1), dst.getPixel(2, 2));
+
+trace("///dst.threshold(src, src.rect, new Point(0, 0), \"==\", 0xFF0000, 0xFF0000FF, 0x00FFFFFF, true);");
+trace(dst.threshold(src, src.rect, new Point(0, 0), "==", 0xFF0000, 0xFF0000FF, 0x00FFFFFF, true));
+
+trace("///dst.getPixel(0, 0), dst.getPixel(1, 1), dst.getPixel(2, 2);");
+trace(dst.getPixel(0, 0), dst.getPixel(1, 1), dst.getPixel(2, 2));
+
+var dissolved = new BitmapData(4, 4, false, 0x000000);
+
+trace("///var seed = dissolved.pixelDissolve(dissolved, dissolved.rect, new Point(0, 0), 0, 8, 0xFFFFFF);");
+var seed = dissolved.pixelDissolve(dissolved, dissolved.rect, new Point(0, 0), 0, 8, 0xFFFFFF);
+
+trace("///seed;");
+trace(seed);
+
+var white = 0;
+for (var y = 0; y < 4; y++) {
+	for (var x = 0; x < 4; x++) {
+		if (dissolved.getPixel(x, y) == 0xFFFFFF) {
+			white++;
+		}
+	}
+}
+trace("///// White pixels");
+trace(white);
+
+trace("///seed = dissolved.pixelDissolve(dissolved, dissolved.rect, new Point(0, 0), seed, 8, 0xFFFFFF);");
+seed = dissolved.pixelDissolve(dissolved, dissolved.rect, new Point(0, 0), seed, 8, 0xFFFFFF);
+
+trace("///seed;");
+trace(seed);
+
+white = 0;
+for (y = 0; y < 4; y++) {
+	for (x = 0; x < 4; x++) {
+		if (dissolved.getPixel(x, y) == 0xFFFFFF) {
+			white++;
+		}
+	}
+}
+trace("///// White pixels");
+trace(white);
+
+var a = new BitmapData(4, 4, true, 0);
+a.setPixel32(2, 2, 0xFF000000);
+var b = new BitmapData(2, 2, true, 0xFF000000);
+
+trace("///a.hitTest(new Point(0, 0), 255, new Point(2, 2));");
+trace(a.hitTest(new Point(0, 0), 255, new Point(2, 2)));
+
+trace("///a.hitTest(new Point(0, 0), 255, new Point(1, 1));");
+trace(a.hitTest(new Point(0, 0), 255, new Point(1, 1)));
+
+trace("///a.hitTest(new Point(10, 10), 255, new Rectangle(11, 11, 2, 2));");
+trace(a.hitTest(new Point(10, 10), 255, new Rectangle(11, 11, 2, 2)));
+
+trace("///a.hitTest(new Point(0, 0), 255, b, new Point(3, 3), 255);");
+trace(a.hitTest(new Point(0, 0), 255, b, new Point(3, 3), 255));
+
+trace("///a.hitTest(new Point(0, 0), 255, b, new Point(1, 1), 255);");
+trace(a.hitTest(new Point(0, 0), 255, b, new Point(1, 1), 255));
+
+var red = new BitmapData(2, 2, false, 0xFF0000);
+var swapped = new BitmapData(2, 2, false, 0);
+var swap = new ColorMatrixFilter([
+	0, 0, 1, 0, 0,
+	0, 1, 0, 0, 0,
+	1, 0, 0, 0, 0,
+	0, 0, 0, 1, 0
+]);
+
+trace("///swapped.applyFilter(red, red.rect, new Point(0, 0), swap);");
+swapped.applyFilter(red, red.rect, new Point(0, 0), swap);
+
+trace("///swapped.getPixel(0, 0), swapped.getPixel(1, 1);");
+trace(swapped.getPixel(0, 0), swapped.getPixel(1, 1));
+
+trace("///red.generateFilterRect(new Rectangle(0, 0, 10, 10), new BlurFilter(4, 4, 1));");
+trace(red.generateFilterRect(new Rectangle(0, 0, 10, 10), new BlurFilter(4, 4, 1)));
+
+trace("///red.generateFilterRect(new Rectangle(0, 0, 10, 10), new GlowFilter());");
+trace(red.generateFilterRect(new Rectangle(0, 0, 10, 10), new GlowFilter()));
+
+trace("///red.generateFilterRect(new Rectangle(0, 0, 10, 10), swap);");
+trace(red.generateFilterRect(new Rectangle(0, 0, 10, 10), swap));
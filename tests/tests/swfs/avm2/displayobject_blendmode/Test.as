@@ -0,0 +1,55 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.BlendMode;
+import flash.display.Shape;
+import flash.display.Sprite;
+
+trace("///var sprite = new Sprite();");
+var sprite = new Sprite();
+
+trace("///sprite.blendMode;");
+trace(sprite.blendMode);
+
+trace("///new Shape().blendMode;");
+trace(new Shape().blendMode);
+
+var modes = [
+	BlendMode.NORMAL,
+	BlendMode.LAYER,
+	BlendMode.MULTIPLY,
+	BlendMode.SCREEN,
+	BlendMode.LIGHTEN,
+	BlendMode.DARKEN,
+	BlendMode.DIFFERENCE,
+	BlendMode.ADD,
+	BlendMode.SUBTRACT,
+	BlendMode.INVERT,
+	BlendMode.ALPHA,
+	BlendMode.ERASE,
+	BlendMode.OVERLAY,
+	BlendMode.HARDLIGHT
+];
+
+trace("///for each (var mode in modes) { sprite.blendMode = mode; trace(sprite.blendMode); }");
+for each (var mode in modes) {
+	sprite.blendMode = mode;
+	trace(sprite.blendMode);
+}
+
+trace("///sprite.blendMode = \"add\";");
+sprite.blendMode = "add";
+
+trace("///var child = new Shape();");
+var child = new Shape();
+
+trace("///sprite.addChild(child);");
+sprite.addChild(child);
+
+trace("///child.blendMode;");
+trace(child.blendMode);
+
+trace("///sprite.blendMode;");
+trace(sprite.blendMode);
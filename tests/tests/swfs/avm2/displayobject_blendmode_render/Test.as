@@ -0,0 +1,59 @@
+package {
+	import flash.display.BlendMode;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// Modes that mix the source and destination colors, with an
+			// opaque left half and a half transparent right half.
+			var modes = [BlendMode.DIFFERENCE, BlendMode.OVERLAY, BlendMode.HARDLIGHT];
+			for (var i = 0; i < modes.length; i++) {
+				addChild(background(i * 50));
+
+				var shape = new Shape();
+				shape.graphics.beginFill(0xCC9933, 1);
+				shape.graphics.drawRect(10, 10, 15, 30);
+				shape.graphics.endFill();
+				shape.graphics.beginFill(0xCC9933, 0.5);
+				shape.graphics.drawRect(25, 10, 15, 30);
+				shape.graphics.endFill();
+				shape.x = i * 50;
+				shape.blendMode = modes[i];
+				addChild(shape);
+			}
+
+			// Alpha and erase only apply inside a layer.
+			addChild(alphaCell(150, BlendMode.LAYER, BlendMode.ALPHA));
+			addChild(alphaCell(200, BlendMode.LAYER, BlendMode.ERASE));
+			addChild(alphaCell(250, BlendMode.NORMAL, BlendMode.ALPHA));
+
+			trace("Rendered blend modes");
+		}
+
+		function background(x) {
+			var shape = new Shape();
+			shape.graphics.beginFill(0x3366CC, 1);
+			shape.graphics.drawRect(0, 0, 50, 50);
+			shape.graphics.endFill();
+			shape.x = x;
+			return shape;
+		}
+
+		function alphaCell(x, containerMode, mode) {
+			var container = new Sprite();
+			container.x = x;
+			container.blendMode = containerMode;
+			container.addChild(background(0));
+
+			var shape = new Shape();
+			shape.graphics.beginFill(0x000000, 0.25);
+			shape.graphics.drawRect(10, 10, 30, 30);
+			shape.graphics.endFill();
+			shape.blendMode = mode;
+			container.addChild(shape);
+			return container;
+		}
+	}
+}
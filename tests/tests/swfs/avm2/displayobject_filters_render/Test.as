@@ -0,0 +1,56 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.filters.BevelFilter;
+	import flash.filters.BlurFilter;
+	import flash.filters.ColorMatrixFilter;
+	import flash.filters.ConvolutionFilter;
+	import flash.filters.DropShadowFilter;
+	import flash.filters.GlowFilter;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var filters = [
+				new BlurFilter(4, 4, 1),
+				new GlowFilter(0x00FF00, 1, 6, 6, 2, 1),
+				new DropShadowFilter(4, 45, 0x000000, 1, 4, 4, 1, 1),
+				new BevelFilter(4, 45, 0xFFFFFF, 1, 0x000000, 1, 4, 4, 1, 1, "inner"),
+				new ColorMatrixFilter([
+					0, 0, 1, 0, 0,
+					0, 1, 0, 0, 0,
+					1, 0, 0, 0, 0,
+					0, 0, 0, 1, 0
+				]),
+				new ConvolutionFilter(3, 3, [0, -1, 0, -1, 5, -1, 0, -1, 0])
+			];
+			for (var i = 0; i < filters.length; i++) {
+				var shape = square();
+				shape.x = i * 50;
+				shape.filters = [filters[i]];
+				addChild(shape);
+			}
+
+			// The cached layer image is redrawn when the filters change.
+			var container = new Sprite();
+			container.x = 300;
+			var changed = square();
+			changed.filters = [new BlurFilter(4, 4, 1)];
+			container.addChild(changed);
+			addChild(container);
+			new BitmapData(50, 50, true, 0).draw(container);
+			changed.filters = [new GlowFilter(0x0000FF, 1, 6, 6, 2, 1)];
+
+			trace("Rendered filters");
+		}
+
+		function square() {
+			var shape = new Shape();
+			shape.graphics.beginFill(0xCC3333, 1);
+			shape.graphics.drawRect(15, 15, 20, 20);
+			shape.graphics.endFill();
+			return shape;
+		}
+	}
+}
@@ -0,0 +1,93 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.Shape;
+import flash.display.Sprite;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+
+trace("///var container = new Sprite();");
+var container = new Sprite();
+
+trace("///var sprite = new Sprite();");
+var sprite = new Sprite();
+sprite.graphics.beginFill(0xFF0000);
+sprite.graphics.drawRect(0, 0, 100, 100);
+sprite.graphics.endFill();
+sprite.x = 50;
+sprite.y = 50;
+container.addChild(sprite);
+
+trace("///sprite.scrollRect;");
+trace(sprite.scrollRect);
+
+trace("///sprite.width, sprite.height;");
+trace(sprite.width, sprite.height);
+
+trace("///sprite.scrollRect = new Rectangle(10, 20, 30, 40);");
+sprite.scrollRect = new Rectangle(10, 20, 30, 40);
+
+trace("///sprite.scrollRect;");
+trace(sprite.scrollRect);
+
+trace("///sprite.scrollRect === sprite.scrollRect;");
+trace(sprite.scrollRect === sprite.scrollRect);
+
+trace("///sprite.scrollRect.x = 99;");
+sprite.scrollRect.x = 99;
+
+trace("///sprite.scrollRect.x;");
+trace(sprite.scrollRect.x);
+
+trace("///sprite.x, sprite.y;");
+trace(sprite.x, sprite.y);
+
+trace("///sprite.width, sprite.height;");
+trace(sprite.width, sprite.height);
+
+trace("///sprite.localToGlobal(new Point(10, 20));");
+trace(sprite.localToGlobal(new Point(10, 20)));
+
+trace("///sprite.globalToLocal(new Point(60, 60));");
+trace(sprite.globalToLocal(new Point(60, 60)));
+
+trace("///sprite.scrollRect = null;");
+sprite.scrollRect = null;
+
+trace("///sprite.scrollRect;");
+trace(sprite.scrollRect);
+
+trace("///sprite.width, sprite.height;");
+trace(sprite.width, sprite.height);
+
+trace("///sprite.localToGlobal(new Point(10, 20));");
+trace(sprite.localToGlobal(new Point(10, 20)));
+
+trace("///sprite.cacheAsBitmap;");
+trace(sprite.cacheAsBitmap);
+
+trace("///sprite.cacheAsBitmap = true;");
+sprite.cacheAsBitmap = true;
+
+trace("///sprite.cacheAsBitmap;");
+trace(sprite.cacheAsBitmap);
+
+trace("///sprite.width, sprite.height;");
+trace(sprite.width, sprite.height);
+
+trace("///sprite.cacheAsBitmap = false;");
+sprite.cacheAsBitmap = false;
+
+trace("///sprite.cacheAsBitmap;");
+trace(sprite.cacheAsBitmap);
+
+trace("///var shape = new Shape();");
+var shape = new Shape();
+
+trace("///shape.cacheAsBitmap = 1;");
+shape.cacheAsBitmap = 1;
+
+trace("///shape.cacheAsBitmap;");
+trace(shape.cacheAsBitmap);
@@ -0,0 +1,87 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.Sprite;
+import flash.geom.ColorTransform;
+import flash.geom.Matrix;
+import flash.geom.Point;
+
+trace("///var outer = new Sprite();");
+var outer = new Sprite();
+outer.x = 100;
+outer.y = 50;
+outer.scaleX = 2;
+
+trace("///var inner = new Sprite();");
+var inner = new Sprite();
+inner.x = 10;
+inner.y = 20;
+inner.graphics.beginFill(0xFF0000);
+inner.graphics.drawRect(0, 0, 30, 40);
+inner.graphics.endFill();
+outer.addChild(inner);
+
+trace("///inner.localToGlobal(new Point(5, 5));");
+trace(inner.localToGlobal(new Point(5, 5)));
+
+trace("///inner.globalToLocal(new Point(130, 75));");
+trace(inner.globalToLocal(new Point(130, 75)));
+
+trace("///inner.getBounds(inner);");
+trace(inner.getBounds(inner));
+
+trace("///inner.getBounds(outer);");
+trace(inner.getBounds(outer));
+
+trace("///outer.getBounds(outer);");
+trace(outer.getBounds(outer));
+
+trace("///inner.getRect(outer);");
+trace(inner.getRect(outer));
+
+trace("///var m = inner.transform.matrix;");
+var m = inner.transform.matrix;
+
+trace("///m.a, m.b, m.c, m.d, m.tx, m.ty;");
+trace(m.a, m.b, m.c, m.d, m.tx, m.ty);
+
+trace("///inner.transform.matrix = new Matrix(1, 0, 0, 1, 5, 6);");
+inner.transform.matrix = new Matrix(1, 0, 0, 1, 5, 6);
+
+trace("///inner.x, inner.y;");
+trace(inner.x, inner.y);
+
+trace("///m = inner.transform.concatenatedMatrix;");
+m = inner.transform.concatenatedMatrix;
+
+trace("///m.a, m.d, m.tx, m.ty;");
+trace(m.a, m.d, m.tx, m.ty);
+
+trace("///inner.transform.colorTransform;");
+trace(inner.transform.colorTransform);
+
+trace("///inner.transform.colorTransform = new ColorTransform(0.5, 1, 1, 1, 10, 0, 0, 0);");
+inner.transform.colorTransform = new ColorTransform(0.5, 1, 1, 1, 10, 0, 0, 0);
+
+trace("///inner.transform.colorTransform;");
+trace(inner.transform.colorTransform);
+
+trace("///var mask = new Sprite();");
+var mask = new Sprite();
+
+trace("///inner.mask;");
+trace(inner.mask);
+
+trace("///inner.mask = mask;");
+inner.mask = mask;
+
+trace("///inner.mask === mask;");
+trace(inner.mask === mask);
+
+trace("///inner.mask = null;");
+inner.mask = null;
+
+trace("///inner.mask;");
+trace(inner.mask);
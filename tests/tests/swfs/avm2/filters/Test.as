@@ -0,0 +1,163 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.BitmapData;
+import flash.display.Sprite;
+import flash.filters.BevelFilter;
+import flash.filters.BitmapFilter;
+import flash.filters.BitmapFilterQuality;
+import flash.filters.BitmapFilterType;
+import flash.filters.BlurFilter;
+import flash.filters.ColorMatrixFilter;
+import flash.filters.ConvolutionFilter;
+import flash.filters.DisplacementMapFilter;
+import flash.filters.DropShadowFilter;
+import flash.filters.GlowFilter;
+import flash.filters.GradientBevelFilter;
+import flash.filters.GradientGlowFilter;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+import flash.utils.getQualifiedClassName;
+
+trace("///BitmapFilterQuality.LOW, BitmapFilterQuality.MEDIUM, BitmapFilterQuality.HIGH;");
+trace(BitmapFilterQuality.LOW, BitmapFilterQuality.MEDIUM, BitmapFilterQuality.HIGH);
+
+trace("///BitmapFilterType.INNER, BitmapFilterType.OUTER, BitmapFilterType.FULL;");
+trace(BitmapFilterType.INNER, BitmapFilterType.OUTER, BitmapFilterType.FULL);
+
+trace("///var blur = new BlurFilter();");
+var blur = new BlurFilter();
+
+trace("///blur is BitmapFilter;");
+trace(blur is BitmapFilter);
+
+trace("///blur.blurX, blur.blurY, blur.quality;");
+trace(blur.blurX, blur.blurY, blur.quality);
+
+trace("///var glow = new GlowFilter();");
+var glow = new GlowFilter();
+
+trace("///glow.color, glow.alpha, glow.blurX, glow.blurY, glow.strength, glow.quality, glow.inner, glow.knockout;");
+trace(glow.color, glow.alpha, glow.blurX, glow.blurY, glow.strength, glow.quality, glow.inner, glow.knockout);
+
+trace("///var shadow = new DropShadowFilter();");
+var shadow = new DropShadowFilter();
+
+trace("///shadow.distance, shadow.angle, shadow.color, shadow.alpha, shadow.blurX, shadow.blurY, shadow.strength, shadow.quality;");
+trace(shadow.distance, shadow.angle, shadow.color, shadow.alpha, shadow.blurX, shadow.blurY, shadow.strength, shadow.quality);
+
+trace("///shadow.inner, shadow.knockout, shadow.hideObject;");
+trace(shadow.inner, shadow.knockout, shadow.hideObject);
+
+trace("///var bevel = new BevelFilter();");
+var bevel = new BevelFilter();
+
+trace("///bevel.distance, bevel.angle, bevel.highlightColor, bevel.highlightAlpha, bevel.shadowColor, bevel.shadowAlpha;");
+trace(bevel.distance, bevel.angle, bevel.highlightColor, bevel.highlightAlpha, bevel.shadowColor, bevel.shadowAlpha);
+
+trace("///bevel.blurX, bevel.blurY, bevel.strength, bevel.quality, bevel.type, bevel.knockout;");
+trace(bevel.blurX, bevel.blurY, bevel.strength, bevel.quality, bevel.type, bevel.knockout);
+
+trace("///new ColorMatrixFilter().matrix;");
+trace(new ColorMatrixFilter().matrix);
+
+trace("///var convolution = new ConvolutionFilter(3, 3, [0, 0, 0, 0, 1, 0, 0, 0, 0], 2);");
+var convolution = new ConvolutionFilter(3, 3, [0, 0, 0, 0, 1, 0, 0, 0, 0], 2);
+
+trace("///convolution.matrixX, convolution.matrixY, convolution.matrix, convolution.divisor;");
+trace(convolution.matrixX, convolution.matrixY, convolution.matrix, convolution.divisor);
+
+trace("///var gradient = new GradientGlowFilter(2, 0, [0xFF0000, 0x0000FF], [1, 0.5], [0, 255]);");
+var gradient = new GradientGlowFilter(2, 0, [0xFF0000, 0x0000FF], [1, 0.5], [0, 255]);
+
+trace("///gradient.colors, gradient.alphas, gradient.ratios, gradient.type;");
+trace(gradient.colors, gradient.alphas, gradient.ratios, gradient.type);
+
+trace("///new GradientBevelFilter() is BitmapFilter;");
+trace(new GradientBevelFilter() is BitmapFilter);
+
+trace("///new DisplacementMapFilter() is BitmapFilter;");
+trace(new DisplacementMapFilter() is BitmapFilter);
+
+trace("///var blurClone = new BlurFilter(8, 2, BitmapFilterQuality.HIGH).clone();");
+var blurClone = new BlurFilter(8, 2, BitmapFilterQuality.HIGH).clone();
+
+trace("///getQualifiedClassName(blurClone);");
+trace(getQualifiedClassName(blurClone));
+
+trace("///blurClone.blurX, blurClone.blurY, blurClone.quality;");
+trace(blurClone.blurX, blurClone.blurY, blurClone.quality);
+
+trace("///var gradientClone = gradient.clone();");
+var gradientClone = gradient.clone();
+
+trace("///gradientClone === gradient;");
+trace(gradientClone === gradient);
+
+trace("///getQualifiedClassName(gradientClone);");
+trace(getQualifiedClassName(gradientClone));
+
+trace("///gradientClone.distance, gradientClone.colors, gradientClone.alphas, gradientClone.ratios;");
+trace(gradientClone.distance, gradientClone.colors, gradientClone.alphas, gradientClone.ratios);
+
+trace("///var sprite = new Sprite();");
+var sprite = new Sprite();
+
+trace("///sprite.filters.length;");
+trace(sprite.filters.length);
+
+trace("///sprite.filters = [new BlurFilter(8, 2, 2), new GlowFilter(0x00FF00, 1, 4, 4, 2, 3, true, false)];");
+sprite.filters = [new BlurFilter(8, 2, 2), new GlowFilter(0x00FF00, 1, 4, 4, 2, 3, true, false)];
+
+trace("///var filters = sprite.filters;");
+var filters = sprite.filters;
+
+trace("///filters.length;");
+trace(filters.length);
+
+trace("///getQualifiedClassName(filters[0]), getQualifiedClassName(filters[1]);");
+trace(getQualifiedClassName(filters[0]), getQualifiedClassName(filters[1]));
+
+trace("///filters[0].blurX, filters[0].blurY, filters[0].quality;");
+trace(filters[0].blurX, filters[0].blurY, filters[0].quality);
+
+trace("///filters[1].color, filters[1].alpha, filters[1].blurX, filters[1].strength, filters[1].quality, filters[1].inner, filters[1].knockout;");
+trace(filters[1].color, filters[1].alpha, filters[1].blurX, filters[1].strength, filters[1].quality, filters[1].inner, filters[1].knockout);
+
+trace("///sprite.filters === filters;");
+trace(sprite.filters === filters);
+
+trace("///filters[0].blurX = 20;");
+filters[0].blurX = 20;
+
+trace("///sprite.filters[0].blurX;");
+trace(sprite.filters[0].blurX);
+
+trace("///sprite.filters = null;");
+sprite.filters = null;
+
+trace("///sprite.filters.length;");
+trace(sprite.filters.length);
+
+trace("///var swap = new ColorMatrixFilter([0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0]);");
+var swap = new ColorMatrixFilter([0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0]);
+
+trace("///var source = new BitmapData(4, 4, false, 0xFF0000);");
+var source = new BitmapData(4, 4, false, 0xFF0000);
+
+trace("///var dest = new BitmapData(4, 4, false, 0x00FF00);");
+var dest = new BitmapData(4, 4, false, 0x00FF00);
+
+trace("///dest.applyFilter(source, new Rectangle(0, 0, 2, 2), new Point(2, 2), swap);");
+dest.applyFilter(source, new Rectangle(0, 0, 2, 2), new Point(2, 2), swap);
+
+trace("///dest.getPixel(0, 0).toString(16), dest.getPixel(1, 1).toString(16), dest.getPixel(2, 2).toString(16), dest.getPixel(3, 3).toString(16);");
+trace(dest.getPixel(0, 0).toString(16), dest.getPixel(1, 1).toString(16), dest.getPixel(2, 2).toString(16), dest.getPixel(3, 3).toString(16));
+
+trace("///dest.generateFilterRect(new Rectangle(1, 2, 3, 4), swap);");
+trace(dest.generateFilterRect(new Rectangle(1, 2, 3, 4), swap));
+
+trace("///dest.generateFilterRect(new Rectangle(1, 2, 3, 4), new GlowFilter(0, 1, 4, 4, 2, 1, true));");
+trace(dest.generateFilterRect(new Rectangle(1, 2, 3, 4), new GlowFilter(0, 1, 4, 4, 2, 1, true)));
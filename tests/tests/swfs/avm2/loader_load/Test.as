@@ -0,0 +1,104 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.display.Loader;
+import flash.events.Event;
+import flash.events.IOErrorEvent;
+import flash.events.ProgressEvent;
+import flash.net.URLRequest;
+import flash.utils.ByteArray;
+import flash.utils.getQualifiedClassName;
+
+trace("///var loader = new Loader();");
+var loader = new Loader();
+
+trace("///loader.content;");
+trace(loader.content);
+
+trace("///loader.contentLoaderInfo.loader === loader;");
+trace(loader.contentLoaderInfo.loader === loader);
+
+trace("///loader.contentLoaderInfo.bytesTotal;");
+trace(loader.contentLoaderInfo.bytesTotal);
+
+var errors = 0;
+
+function onProgress(e) {
+	trace("///(progress) e.bytesLoaded, e.bytesTotal;");
+	trace(e.bytesLoaded, e.bytesTotal);
+}
+
+function onInit(e) {
+	var info = loader.contentLoaderInfo;
+
+	trace("///(init) loader.content != null;");
+	trace(loader.content != null);
+
+	trace("///getQualifiedClassName(loader.content);");
+	trace(getQualifiedClassName(loader.content));
+
+	trace("///loader.numChildren;");
+	trace(loader.numChildren);
+
+	trace("///info.content === loader.content;");
+	trace(info.content === loader.content);
+
+	trace("///info.actionScriptVersion;");
+	trace(info.actionScriptVersion);
+
+	trace("///info.swfVersion;");
+	trace(info.swfVersion);
+
+	trace("///info.frameRate;");
+	trace(info.frameRate);
+
+	trace("///info.width, info.height;");
+	trace(info.width, info.height);
+
+	trace("///info.contentType;");
+	trace(info.contentType);
+}
+
+function onComplete(e) {
+	trace("///(complete) e.target === loader.contentLoaderInfo;");
+	trace(e.target === loader.contentLoaderInfo);
+
+	trace("///loader.unload();");
+	loader.unload();
+}
+
+function onUnload(e) {
+	trace("///(unload) loader.content;");
+	trace(loader.content);
+
+	trace("///loader.numChildren;");
+	trace(loader.numChildren);
+
+	trace("///loader.loadBytes(bytes);");
+	var bytes = new ByteArray();
+	bytes.writeUTFBytes("not a movie");
+	loader.loadBytes(bytes);
+}
+
+function onError(e) {
+	errors++;
+
+	trace("///(ioError) e.errorID;");
+	trace(e.errorID);
+
+	if (errors == 1) {
+		trace("///loader.load(new URLRequest(\"missing.swf\"));");
+		loader.load(new URLRequest("missing.swf"));
+	}
+}
+
+loader.contentLoaderInfo.addEventListener(ProgressEvent.PROGRESS, onProgress);
+loader.contentLoaderInfo.addEventListener(Event.INIT, onInit);
+loader.contentLoaderInfo.addEventListener(Event.COMPLETE, onComplete);
+loader.contentLoaderInfo.addEventListener(Event.UNLOAD, onUnload);
+loader.contentLoaderInfo.addEventListener(IOErrorEvent.IO_ERROR, onError);
+
+trace("///loader.load(new URLRequest(\"child.swf\"));");
+loader.load(new URLRequest("child.swf"));
@@ -0,0 +1,54 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.StatusEvent;
+	import flash.net.LocalConnection;
+
+	public class Test extends MovieClip {
+		public var receiver: LocalConnection;
+		public var sender: LocalConnection;
+		public var statusCount: int = 0;
+
+		public function Test() {
+			receiver = new LocalConnection();
+			receiver.client = this;
+			trace("///receiver.connect(\"_test\");");
+			receiver.connect("_test");
+			trace("///receiver.domain;");
+			trace(receiver.domain);
+			trace("///receiver.client == this;");
+			trace(receiver.client == this);
+
+			sender = new LocalConnection();
+			sender.addEventListener(StatusEvent.STATUS, onStatus);
+			trace("///sender.send(\"_test\", \"greet\", \"hello\", 42);");
+			sender.send("_test", "greet", "hello", 42);
+			trace("///sender.send(\"_nobody\", \"greet\", \"lost\", 0);");
+			sender.send("_nobody", "greet", "lost", 0);
+			trace("///sender.send(\"_test\", \"close\");");
+			sender.send("_test", "close");
+		}
+
+		public function greet(message: String, count: Number): void {
+			trace("///greet");
+			trace(message);
+			trace(count);
+		}
+
+		public function onStatus(event: StatusEvent): void {
+			statusCount += 1;
+			trace("///sender status");
+			trace(event.level);
+			trace(event.code);
+
+			if (statusCount == 3) {
+				trace("///receiver.close(); sender.send(\"_test\", \"greet\", \"again\", 1);");
+				receiver.close();
+				sender.send("_test", "greet", "again", 1);
+			} else if (statusCount == 4) {
+				trace("///receiver.connect(\"plain\"); sender.send(\"plain\", \"greet\", \"plain\", 2);");
+				receiver.connect("plain");
+				sender.send("plain", "greet", "plain", 2);
+			}
+		}
+	}
+}
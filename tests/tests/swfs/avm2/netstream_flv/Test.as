@@ -0,0 +1,74 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.NetStatusEvent;
+	import flash.media.Video;
+	import flash.net.NetConnection;
+	import flash.net.NetStream;
+
+	public class Test extends MovieClip {
+		public var connection: NetConnection;
+		public var stream: NetStream;
+		public var missingStream: NetStream;
+
+		public function Test() {
+			connection = new NetConnection();
+			connection.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+			trace("///connection.connect(null);");
+			connection.connect(null);
+			trace(connection.connected);
+
+			stream = new NetStream(connection);
+			trace("///stream.bufferTime;");
+			trace(stream.bufferTime);
+			stream.bufferTime = 2;
+			trace("///stream.bufferTime = 2;");
+			trace(stream.bufferTime);
+
+			// test.flv has a keyframe at 0ms and 200ms, other frames at 100ms and 300ms,
+			// and a cue point at 150ms.
+			stream.client = this;
+			stream.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+			var video: Video = new Video(32, 16);
+			video.attachNetStream(stream);
+			addChild(video);
+			trace("///stream.play(\"test.flv\");");
+			stream.play("test.flv");
+		}
+
+		public function onStatus(event: NetStatusEvent): void {
+			trace(event.info.code + " " + event.info.level);
+			if (event.info.code == "NetStream.Play.Stop") {
+				trace(stream.time >= 0.3);
+
+				missingStream = new NetStream(connection);
+				missingStream.addEventListener(NetStatusEvent.NET_STATUS, onStatus);
+				trace("///missingStream.play(\"missing.flv\");");
+				missingStream.play("missing.flv");
+			}
+		}
+
+		public function onMetaData(info: Object): void {
+			trace("///onMetaData");
+			trace(info.duration);
+			trace(info.width);
+			trace(info.height);
+			trace(info.canSeekToEnd);
+			trace(info.encoder);
+			trace(info.keyframeTimes);
+		}
+
+		public function onCuePoint(info: Object): void {
+			trace("///onCuePoint");
+			trace(info.name);
+			trace(info.time);
+			trace(info.type);
+			trace(stream.time >= 0.15);
+
+			trace("///stream.pause(); stream.seek(0.25); stream.resume();");
+			stream.pause();
+			stream.seek(0.25);
+			trace(stream.time);
+			stream.resume();
+		}
+	}
+}
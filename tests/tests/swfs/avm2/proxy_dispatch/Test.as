@@ -0,0 +1,125 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.utils.Proxy;
+import flash.utils.flash_proxy;
+
+class Bag extends Proxy {
+	var keys;
+	var values;
+
+	function Bag() {
+		keys = [];
+		values = {};
+	}
+
+	flash_proxy override function getProperty(name:*):* {
+		trace("///(getProperty)", name);
+		return values["" + name];
+	}
+
+	flash_proxy override function setProperty(name:*, value:*):void {
+		trace("///(setProperty)", name, value);
+		if (!(("" + name) in values)) {
+			keys.push("" + name);
+		}
+		values["" + name] = value;
+	}
+
+	flash_proxy override function hasProperty(name:*):Boolean {
+		trace("///(hasProperty)", name);
+		return ("" + name) in values;
+	}
+
+	flash_proxy override function deleteProperty(name:*):Boolean {
+		trace("///(deleteProperty)", name);
+		var index = keys.indexOf("" + name);
+		if (index < 0) {
+			return false;
+		}
+		keys.splice(index, 1);
+		return delete values["" + name];
+	}
+
+	flash_proxy override function callProperty(name:*, ...rest):* {
+		trace("///(callProperty)", name, rest);
+		return name + "(" + rest.join(", ") + ")";
+	}
+
+	flash_proxy override function getDescendants(name:*):* {
+		trace("///(getDescendants)", name);
+		return "descendants of " + name;
+	}
+
+	flash_proxy override function nextNameIndex(index:int):int {
+		return index < keys.length ? index + 1 : 0;
+	}
+
+	flash_proxy override function nextName(index:int):String {
+		return keys[index - 1];
+	}
+
+	flash_proxy override function nextValue(index:int):* {
+		return values[keys[index - 1]];
+	}
+
+	function size() {
+		return keys.length;
+	}
+}
+
+trace("///var bag = new Bag();");
+var bag = new Bag();
+
+trace("///bag.first = 1;");
+bag.first = 1;
+
+trace("///bag[\"second\"] = \"two\";");
+bag["second"] = "two";
+
+trace("///bag.third = true;");
+bag.third = true;
+
+trace("///bag.first;");
+trace(bag.first);
+
+trace("///bag.missing;");
+trace(bag.missing);
+
+trace("///\"second\" in bag;");
+trace("second" in bag);
+
+trace("///\"missing\" in bag;");
+trace("missing" in bag);
+
+trace("///bag.size();");
+trace(bag.size());
+
+trace("///bag.greet(\"hello\", 2);");
+trace(bag.greet("hello", 2));
+
+trace("///bag..item;");
+trace(bag..item);
+
+trace("///for (var key in bag)");
+for (var key in bag) {
+	trace(key);
+}
+
+trace("///for each (var value in bag)");
+for each (var value in bag) {
+	trace(value);
+}
+
+trace("///delete bag.second;");
+trace(delete bag.second);
+
+trace("///delete bag.missing;");
+trace(delete bag.missing);
+
+trace("///for (var key in bag)");
+for (var key in bag) {
+	trace(key);
+}
@@ -0,0 +1,75 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.events.EventDispatcher;
+import flash.net.SharedObject;
+
+function countKeys(obj) {
+	var count = 0;
+	for (var key in obj) {
+		count++;
+	}
+	return count;
+}
+
+trace("///var so = SharedObject.getLocal(\"test\");");
+var so = SharedObject.getLocal("test");
+
+trace("///so is EventDispatcher;");
+trace(so is EventDispatcher);
+
+trace("///countKeys(so.data);");
+trace(countKeys(so.data));
+
+trace("///SharedObject.getLocal(\"test\") === so;");
+trace(SharedObject.getLocal("test") === so);
+
+trace("///SharedObject.getLocal(\"test\", \"/\") === so;");
+trace(SharedObject.getLocal("test", "/") === so);
+
+trace("///SharedObject.getLocal(\"other\") === so;");
+trace(SharedObject.getLocal("other") === so);
+
+trace("///so.data.str = \"hello\";");
+so.data.str = "hello";
+
+trace("///so.data.num = 1.5;");
+so.data.num = 1.5;
+
+trace("///so.data.arr = [1, \"two\", true];");
+so.data.arr = [1, "two", true];
+
+trace("///so.data.obj = {nested: null};");
+so.data.obj = {nested: null};
+
+trace("///countKeys(so.data);");
+trace(countKeys(so.data));
+
+trace("///so.flush();");
+trace(so.flush());
+
+trace("///so.data.str, so.data.num, so.data.arr, so.data.obj.nested;");
+trace(so.data.str, so.data.num, so.data.arr, so.data.obj.nested);
+
+trace("///var data = so.data;");
+var data = so.data;
+
+trace("///so.clear();");
+so.clear();
+
+trace("///so.data === data;");
+trace(so.data === data);
+
+trace("///countKeys(so.data);");
+trace(countKeys(so.data));
+
+trace("///so.data.str;");
+trace(so.data.str);
+
+trace("///SharedObject.getLocal(\"test\").data.str;");
+trace(SharedObject.getLocal("test").data.str);
+
+trace("///so.close();");
+so.close();
@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+	import flash.display.SimpleButton;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// A 40x40 skin with a 4 pixel border, which keeps its width when
+			// the button is stretched.
+			var skin = new Shape();
+			skin.graphics.beginFill(0x336699, 1);
+			skin.graphics.drawRect(0, 0, 40, 40);
+			skin.graphics.endFill();
+			skin.graphics.beginFill(0xFFCC00, 1);
+			skin.graphics.drawRect(4, 4, 32, 32);
+			skin.graphics.endFill();
+
+			var button = new SimpleButton(skin);
+			button.scale9Grid = new Rectangle(8, 8, 24, 24);
+			button.x = 20;
+			button.y = 20;
+			button.scaleX = 4;
+			button.scaleY = 0.5;
+			addChild(button);
+
+			trace("///button.scale9Grid;");
+			trace(button.scale9Grid);
+		}
+	}
+}
@@ -0,0 +1,95 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.DataEvent;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.events.ProgressEvent;
+	import flash.net.Socket;
+	import flash.net.XMLSocket;
+
+	// The test harness runs scripted servers on localhost:
+	// - Port 1234 sends a short, the UTF string "hello" and the double 1.5,
+	//   waits for the int 7 and the UTF string "ping", sends the byte 0xFF and closes.
+	// - Port 1235 sends "<hello/>", "<two/>" and the start of "<partial/>",
+	//   waits for "<ping/>", sends the rest of "<partial/>" and closes.
+	public class Test extends MovieClip {
+		public var socket: Socket;
+		public var xmlSocket: XMLSocket;
+		public var failedSocket: Socket;
+
+		public function Test() {
+			socket = new Socket();
+			socket.addEventListener(Event.CONNECT, onConnect);
+			socket.addEventListener(ProgressEvent.SOCKET_DATA, onSocketData);
+			socket.addEventListener(Event.CLOSE, onClose);
+			trace("///socket.connected;");
+			trace(socket.connected);
+			trace("///socket.connect(\"localhost\", 1234);");
+			socket.connect("localhost", 1234);
+		}
+
+		public function onConnect(event: Event): void {
+			trace("///Socket connect");
+			trace(socket.connected);
+			socket.writeInt(7);
+			socket.writeUTF("ping");
+			trace(socket.bytesPending);
+			socket.flush();
+			trace(socket.bytesPending);
+		}
+
+		public function onSocketData(event: ProgressEvent): void {
+			trace("///Socket socketData");
+			trace(event.bytesLoaded);
+			trace(socket.bytesAvailable);
+			if (socket.bytesAvailable > 1) {
+				trace(socket.readShort());
+				trace(socket.readUTF());
+				trace(socket.readDouble());
+			} else {
+				trace(socket.readByte());
+			}
+			trace(socket.bytesAvailable);
+		}
+
+		public function onClose(event: Event): void {
+			trace("///Socket close");
+			trace(socket.connected);
+
+			xmlSocket = new XMLSocket();
+			xmlSocket.addEventListener(Event.CONNECT, onXmlConnect);
+			xmlSocket.addEventListener(DataEvent.DATA, onXmlData);
+			xmlSocket.addEventListener(Event.CLOSE, onXmlClose);
+			trace("///xmlSocket.connect(\"localhost\", 1235);");
+			xmlSocket.connect("localhost", 1235);
+		}
+
+		public function onXmlConnect(event: Event): void {
+			trace("///XMLSocket connect");
+			trace(xmlSocket.connected);
+			xmlSocket.send("<ping/>");
+		}
+
+		public function onXmlData(event: DataEvent): void {
+			trace("///XMLSocket data");
+			trace(event.data);
+		}
+
+		public function onXmlClose(event: Event): void {
+			trace("///XMLSocket close");
+			trace(xmlSocket.connected);
+
+			failedSocket = new Socket();
+			failedSocket.addEventListener(IOErrorEvent.IO_ERROR, onIOError);
+			trace("///failedSocket.connect(\"example.com\", 80);");
+			failedSocket.connect("example.com", 80);
+		}
+
+		public function onIOError(event: IOErrorEvent): void {
+			trace("///Socket ioError");
+			trace(event.text);
+			trace(event.errorID);
+			trace(failedSocket.connected);
+		}
+	}
+}
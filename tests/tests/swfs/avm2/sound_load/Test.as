@@ -0,0 +1,83 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.events.ProgressEvent;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.net.URLRequest;
+
+	// test.mp3 holds ten silent MPEG-1 Layer III frames at 44.1kHz, an
+	// ID3v2.3 tag with a song name and artist, and an ID3v1.1 tag.
+	public class Test extends MovieClip {
+		public var sound: Sound;
+		public var missingSound: Sound;
+
+		public function Test() {
+			sound = new Sound();
+			sound.addEventListener(Event.OPEN, onOpen);
+			sound.addEventListener(ProgressEvent.PROGRESS, onProgress);
+			sound.addEventListener(Event.ID3, onId3);
+			sound.addEventListener(Event.COMPLETE, onComplete);
+			trace("///sound.url;");
+			trace(sound.url);
+			trace("///sound.load(new URLRequest(\"test.mp3\"));");
+			sound.load(new URLRequest("test.mp3"));
+			trace("///sound.url;");
+			trace(sound.url);
+			trace("///sound.isBuffering;");
+			trace(sound.isBuffering);
+			trace("///sound.bytesLoaded;");
+			trace(sound.bytesLoaded);
+			trace("///sound.play() != null;");
+			var channel: SoundChannel = sound.play();
+			trace(channel != null);
+		}
+
+		public function onOpen(event: Event): void {
+			trace("///Sound open");
+		}
+
+		public function onProgress(event: ProgressEvent): void {
+			trace("///Sound progress");
+			trace(event.bytesLoaded);
+			trace(event.bytesTotal);
+		}
+
+		public function onId3(event: Event): void {
+			trace("///Sound id3");
+			trace(sound.id3.songName);
+			trace(sound.id3.artist);
+			trace(sound.id3.album);
+			trace(sound.id3.year);
+			trace(sound.id3.comment);
+			trace(sound.id3.track);
+			trace(sound.id3.genre);
+			trace(sound.id3.TIT2);
+		}
+
+		public function onComplete(event: Event): void {
+			trace("///Sound complete");
+			trace(sound.isBuffering);
+			trace(sound.bytesLoaded);
+			trace(sound.bytesTotal);
+			trace(sound.length);
+
+			missingSound = new Sound();
+			missingSound.addEventListener(Event.OPEN, onMissingOpen);
+			missingSound.addEventListener(IOErrorEvent.IO_ERROR, onMissingIOError);
+			trace("///missingSound.load(new URLRequest(\"missing.mp3\"));");
+			missingSound.load(new URLRequest("missing.mp3"));
+		}
+
+		public function onMissingOpen(event: Event): void {
+			trace("///missingSound open");
+		}
+
+		public function onMissingIOError(event: IOErrorEvent): void {
+			trace("///missingSound ioError");
+			trace(event.errorID);
+			trace(missingSound.isBuffering);
+		}
+	}
+}
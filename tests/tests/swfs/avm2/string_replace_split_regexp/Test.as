@@ -0,0 +1,91 @@
+package {
+	public class Test {
+	}
+}
+
+function onMatch(matched, digit, index, str) {
+	trace("///(onMatch) matched, digit, index, str;");
+	trace(matched, digit, index, str);
+	return "[" + matched + "]";
+}
+
+function onOptional(matched, group, index, str) {
+	trace("///(onOptional) matched, group, index, str;");
+	trace(matched, group, index, str);
+	return "_";
+}
+
+trace("///\"abc\".replace(\"b\", \"[$&]\");");
+trace("abc".replace("b", "[$&]"));
+
+trace("///\"abc\".replace(\"b\", \"[$`|$']\");");
+trace("abc".replace("b", "[$`|$']"));
+
+trace("///\"a.b.c\".replace(\".\", \"!\");");
+trace("a.b.c".replace(".", "!"));
+
+trace("///\"abc\".replace(/b/, \"$$\");");
+trace("abc".replace(/b/, "$$"));
+
+trace("///\"abc\".replace(/(b)/, \"$1$1\");");
+trace("abc".replace(/(b)/, "$1$1"));
+
+trace("///\"abc\".replace(/(b)/, \"$10\");");
+trace("abc".replace(/(b)/, "$10"));
+
+trace("///\"abc\".replace(/(b)/, \"$2\");");
+trace("abc".replace(/(b)/, "$2"));
+
+trace("///\"abcdefghijkl\".replace(/(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)/, \"$12-$1\");");
+trace("abcdefghijkl".replace(/(a)(b)(c)(d)(e)(f)(g)(h)(i)(j)(k)(l)/, "$12-$1"));
+
+trace("///\"a-b-c\".replace(/-/g, \"$`\");");
+trace("a-b-c".replace(/-/g, "$`"));
+
+trace("///\"x1y2\".replace(/(\\d)/g, onMatch);");
+trace("x1y2".replace(/(\d)/g, onMatch));
+
+trace("///\"ab\".replace(/a(x)?/, onOptional);");
+trace("ab".replace(/a(x)?/, onOptional));
+
+trace("///\"abc\".replace(/x*/g, \"-\");");
+trace("abc".replace(/x*/g, "-"));
+
+trace("///\"a1b2c3\".split(/\\d/);");
+trace("a1b2c3".split(/\d/));
+
+trace("///\"a1b2c3\".split(/\\d/, 2);");
+trace("a1b2c3".split(/\d/, 2));
+
+trace("///\"a1b2c3\".split(/(\\d)/);");
+trace("a1b2c3".split(/(\d)/));
+
+trace("///\"a1b2c3\".split(/(\\d)/, 3);");
+trace("a1b2c3".split(/(\d)/, 3));
+
+trace("///\"abc\".split(/(x)?b/).length;");
+trace("abc".split(/(x)?b/).length);
+
+trace("///\"abc\".split(/(x)?b/)[1];");
+trace("abc".split(/(x)?b/)[1]);
+
+trace("///\"a, b ,c\".split(/\\s*,\\s*/);");
+trace("a, b ,c".split(/\s*,\s*/));
+
+trace("///\"test\".split(/t/).length;");
+trace("test".split(/t/).length);
+
+trace("///\"abc\".split(/(?:)/);");
+trace("abc".split(/(?:)/));
+
+trace("///\"ab\".split(/a*?/);");
+trace("ab".split(/a*?/));
+
+trace("///\"ab\".split(/a*/);");
+trace("ab".split(/a*/));
+
+trace("///\"\".split(/x/).length;");
+trace("".split(/x/).length);
+
+trace("///\"\".split(/(?:)/).length;");
+trace("".split(/(?:)/).length);
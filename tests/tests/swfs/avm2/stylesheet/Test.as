@@ -0,0 +1,72 @@
+package {
+	import flash.display.MovieClip;
+	import flash.text.StyleSheet;
+	import flash.text.TextField;
+	import flash.text.TextFormat;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var css: StyleSheet = new StyleSheet();
+			css.parseCSS("p { color: #FF0000; font-size: 20px; }\n/* Titles */\n.title { font-weight: bold; font-family: serif; }\na:link { color: #0000FF; }");
+			trace("///css.styleNames;");
+			trace(css.styleNames);
+			trace("///css.getStyle(\"P\").color;");
+			trace(css.getStyle("P").color);
+			trace("///css.getStyle(\"p\").fontSize;");
+			trace(css.getStyle("p").fontSize);
+			trace("///css.getStyle(\"missing\");");
+			trace(css.getStyle("missing"));
+
+			css.parseCSS("p { color: #00FF00");
+			trace("///css.parseCSS(\"p { color: #00FF00\"); // Malformed");
+			trace(css.getStyle("p").color);
+
+			css.setStyle(".Note", {fontStyle: "italic"});
+			trace("///css.setStyle(\".Note\", {fontStyle: \"italic\"});");
+			trace(css.styleNames);
+			trace(css.getStyle(".note").fontStyle);
+
+			css.setStyle(".note", null);
+			trace("///css.setStyle(\".note\", null);");
+			trace(css.styleNames);
+
+			var format: TextFormat = css.transform(css.getStyle(".title"));
+			trace("///css.transform(css.getStyle(\".title\"));");
+			trace(format.bold);
+			trace(format.font);
+			trace(format.size);
+
+			var field: TextField = new TextField();
+			field.styleSheet = css;
+			field.htmlText = "<p>Red <span class=\"title\">Bold</span> <a href=\"event:link\">Link</a></p>";
+			addChild(field);
+			trace("///field.styleSheet == css;");
+			trace(field.styleSheet == css);
+			trace("///field.htmlText;");
+			trace(field.htmlText);
+			trace("///field.text;");
+			trace(field.text);
+			trace("///Red");
+			trace(field.getTextFormat(0, 1).color);
+			trace(field.getTextFormat(0, 1).size);
+			trace(field.getTextFormat(0, 1).bold);
+			trace("///Bold");
+			trace(field.getTextFormat(4, 5).color);
+			trace(field.getTextFormat(4, 5).bold);
+			trace(field.getTextFormat(4, 5).font);
+			trace("///Link");
+			trace(field.getTextFormat(9, 10).color);
+			trace(field.getTextFormat(9, 10).url);
+
+			field.setTextFormat(new TextFormat(null, null, 0x00FF00), 0, 3);
+			trace("///field.setTextFormat(new TextFormat(null, null, 0x00FF00), 0, 3);");
+			trace(field.getTextFormat(0, 1).color);
+
+			field.styleSheet = null;
+			field.htmlText = "<p>Plain</p>";
+			trace("///field.styleSheet = null;");
+			trace(field.styleSheet);
+			trace(field.getTextFormat(0, 1).size);
+		}
+	}
+}
@@ -0,0 +1,27 @@
+package {
+	import flash.display.MovieClip;
+	import flash.text.TextField;
+	import flash.text.TextFormat;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// RuffleTestSans.ttf has a 0.5em "A" and a 1em "Ж", and nothing else.
+			trace("///RuffleTestSans: AAЖ");
+			trace(measure("RuffleTestSans", false, "AAЖ"));
+			trace("///RuffleTestSans bold: AAЖ");
+			trace(measure("RuffleTestSans", true, "AAЖ"));
+			trace("///_sans: Ж");
+			trace(measure("_sans", false, "Ж"));
+			trace("///Missing Font: ЖЖ");
+			trace(measure("Missing Font", false, "ЖЖ"));
+		}
+
+		private function measure(font: String, bold: Boolean, text: String): Number {
+			var field: TextField = new TextField();
+			field.defaultTextFormat = new TextFormat(font, 20, 0, bold);
+			field.text = text;
+			addChild(field);
+			return field.textWidth;
+		}
+	}
+}
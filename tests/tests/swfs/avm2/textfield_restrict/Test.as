@@ -0,0 +1,84 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.text.TextField;
+	import flash.text.TextFieldType;
+
+	public class Test extends MovieClip {
+		public var typed: TextField;
+
+		public function Test() {
+			var field: TextField = new TextField();
+			field.type = TextFieldType.INPUT;
+			addChild(field);
+
+			trace("///field.maxChars;");
+			trace(field.maxChars);
+			trace("///field.restrict;");
+			trace(field.restrict);
+
+			field.restrict = "A-Z0-9";
+			field.maxChars = 5;
+			trace("///field.restrict = \"A-Z0-9\"; field.maxChars = 5;");
+			trace(field.restrict);
+			trace(field.maxChars);
+
+			field.replaceText(0, 0, "ab-12cd");
+			trace("///field.replaceText(0, 0, \"ab-12cd\");");
+			trace(field.text);
+
+			field.replaceText(0, 5, "xyz");
+			trace("///field.replaceText(0, 5, \"xyz\");");
+			trace(field.text);
+
+			field.replaceText(3, 3, "123");
+			trace("///field.replaceText(3, 3, \"123\");");
+			trace(field.text);
+
+			field.text = "too long for maxChars";
+			trace("///field.text = \"too long for maxChars\";");
+			trace(field.text);
+
+			field.text = "";
+			field.maxChars = 0;
+			field.restrict = "^a-z";
+			field.replaceText(0, 0, "Hello World");
+			trace("///field.restrict = \"^a-z\"; field.replaceText(0, 0, \"Hello World\");");
+			trace(field.text);
+
+			field.text = "";
+			field.restrict = "\\-\\^0-9";
+			field.replaceText(0, 0, "1-2^3a\\");
+			trace("///field.restrict = \"\\\\-\\\\^0-9\"; field.replaceText(0, 0, \"1-2^3a\\\\\");");
+			trace(field.text);
+
+			field.restrict = null;
+			field.replaceText(0, 0, "any");
+			trace("///field.restrict = null;");
+			trace(field.restrict);
+			trace(field.text);
+
+			typed = new TextField();
+			typed.type = TextFieldType.INPUT;
+			typed.restrict = "0-9";
+			typed.maxChars = 3;
+			addChild(typed);
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		public function onEnterFrame(event: Event): void {
+			if (stage.focus != typed) {
+				stage.focus = typed;
+				typed.setSelection(0, 0);
+				return;
+			}
+
+			// The test types "1a2b34" into the focused field.
+			if (typed.text.length > 0) {
+				trace("///Typed \"1a2b34\" into a field with restrict \"0-9\" and maxChars 3");
+				trace(typed.text);
+				removeEventListener(Event.ENTER_FRAME, onEnterFrame);
+			}
+		}
+	}
+}
@@ -0,0 +1,100 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.events.TimerEvent;
+import flash.utils.Timer;
+import flash.utils.clearInterval;
+import flash.utils.clearTimeout;
+import flash.utils.setInterval;
+import flash.utils.setTimeout;
+
+trace("///var evt = new TimerEvent(TimerEvent.TIMER);");
+var evt = new TimerEvent(TimerEvent.TIMER);
+
+trace("///evt.type;");
+trace(evt.type);
+
+trace("///evt.bubbles;");
+trace(evt.bubbles);
+
+trace("///TimerEvent.TIMER_COMPLETE;");
+trace(TimerEvent.TIMER_COMPLETE);
+
+trace("///var timer = new Timer(100, 2);");
+var timer = new Timer(100, 2);
+
+trace("///timer.delay;");
+trace(timer.delay);
+
+trace("///timer.repeatCount;");
+trace(timer.repeatCount);
+
+trace("///timer.currentCount;");
+trace(timer.currentCount);
+
+trace("///timer.running;");
+trace(timer.running);
+
+function onTimer(e) {
+	trace("///(timer) e.type, e is TimerEvent, e.target === timer, timer.currentCount;");
+	trace(e.type, e is TimerEvent, e.target === timer, timer.currentCount);
+}
+
+function onTimerComplete(e) {
+	trace("///(timerComplete) e.type, timer.currentCount, timer.running;");
+	trace(e.type, timer.currentCount, timer.running);
+}
+
+timer.addEventListener(TimerEvent.TIMER, onTimer);
+timer.addEventListener(TimerEvent.TIMER_COMPLETE, onTimerComplete);
+
+trace("///timer.start();");
+timer.start();
+
+trace("///timer.running;");
+trace(timer.running);
+
+trace("///var other = new Timer(50);");
+var other = new Timer(50);
+
+trace("///other.start();");
+other.start();
+
+trace("///other.reset();");
+other.reset();
+
+trace("///other.running, other.currentCount;");
+trace(other.running, other.currentCount);
+
+function onTimeout(a, b) {
+	trace("///(timeout) a, b;");
+	trace(a, b);
+}
+
+function onCancelled() {
+	trace("///(cancelled)");
+}
+
+var intervalCalls = 0;
+var intervalId;
+
+function onInterval() {
+	intervalCalls++;
+	trace("///(interval) intervalCalls;");
+	trace(intervalCalls);
+
+	if (intervalCalls == 2) {
+		clearInterval(intervalId);
+	}
+}
+
+trace("///setTimeout(onTimeout, 10, \"a\", 1);");
+setTimeout(onTimeout, 10, "a", 1);
+
+trace("///clearTimeout(setTimeout(onCancelled, 20));");
+clearTimeout(setTimeout(onCancelled, 20));
+
+trace("///intervalId = setInterval(onInterval, 300);");
+intervalId = setInterval(onInterval, 300);
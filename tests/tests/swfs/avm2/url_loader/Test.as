@@ -0,0 +1,98 @@
+package {
+	public class Test {
+	}
+}
+
+import flash.events.Event;
+import flash.events.IOErrorEvent;
+import flash.events.ProgressEvent;
+import flash.net.URLLoader;
+import flash.net.URLLoaderDataFormat;
+import flash.net.URLRequest;
+import flash.net.URLVariables;
+import flash.net.navigateToURL;
+
+trace("///var vars = new URLVariables(\"a=1&b=two\");");
+var vars = new URLVariables("a=1&b=two");
+
+trace("///vars.a;");
+trace(vars.a);
+
+trace("///vars.b;");
+trace(vars.b);
+
+trace("///vars.decode(\"d=x%26y\");");
+vars.decode("d=x%26y");
+
+trace("///vars.d;");
+trace(vars.d);
+
+trace("///var single = new URLVariables();");
+var single = new URLVariables();
+
+trace("///single.answer = 42;");
+single.answer = 42;
+
+trace("///single.toString();");
+trace(single.toString());
+
+trace("///navigateToURL(new URLRequest(\"page.html\"), \"_blank\");");
+navigateToURL(new URLRequest("page.html"), "_blank");
+
+trace("///var loader = new URLLoader();");
+var loader = new URLLoader();
+
+trace("///loader.dataFormat;");
+trace(loader.dataFormat);
+
+trace("///loader.bytesLoaded;");
+trace(loader.bytesLoaded);
+
+function onOpen(e) {
+	trace("///(open)");
+	trace(e.type);
+}
+
+function onProgress(e) {
+	trace("///(progress) e.bytesLoaded, e.bytesTotal;");
+	trace(e.bytesLoaded, e.bytesTotal);
+}
+
+function onComplete(e) {
+	trace("///(complete) loader.bytesLoaded;");
+	trace(loader.bytesLoaded);
+
+	if (loader.dataFormat == URLLoaderDataFormat.TEXT) {
+		trace("///loader.data;");
+		trace(loader.data);
+
+		trace("///loader.dataFormat = URLLoaderDataFormat.VARIABLES;");
+		loader.dataFormat = URLLoaderDataFormat.VARIABLES;
+
+		trace("///loader.load(new URLRequest(\"data.txt\"));");
+		loader.load(new URLRequest("data.txt"));
+	} else {
+		trace("///loader.data.hello;");
+		trace(loader.data.hello);
+
+		trace("///loader.data.answer;");
+		trace(loader.data.answer);
+
+		trace("///var missing = new URLLoader(new URLRequest(\"missing.txt\"));");
+		var missing = new URLLoader(new URLRequest("missing.txt"));
+		missing.addEventListener(Event.COMPLETE, onComplete);
+		missing.addEventListener(IOErrorEvent.IO_ERROR, onError);
+	}
+}
+
+function onError(e) {
+	trace("///(ioError) e.type, e.errorID;");
+	trace(e.type, e.errorID);
+}
+
+loader.addEventListener(Event.OPEN, onOpen);
+loader.addEventListener(ProgressEvent.PROGRESS, onProgress);
+loader.addEventListener(Event.COMPLETE, onComplete);
+
+trace("///loader.load(new URLRequest(\"data.txt\"));");
+loader.load(new URLRequest("data.txt"));
@@ -0,0 +1,64 @@
+package {
+	public class Test {
+	}
+}
+
+trace("///var x = <list>...</list>;");
+var x = <list>
+	<item id="1" kind="fruit">apple</item>
+	<item id="2" kind="veg">carrot</item>
+	<group>
+		<item id="3" kind="fruit">pear</item>
+	</group>
+</list>;
+
+trace("///x.item.length();");
+trace(x.item.length());
+
+trace("///x..item.length();");
+trace(x..item.length());
+
+trace("///x.item[1];");
+trace(x.item[1]);
+
+trace("///x.item[0].@id;");
+trace(x.item[0].@id);
+
+trace("///x.group.item.@id;");
+trace(x.group.item.@id);
+
+trace("///x..item.@kind;");
+trace(x..item.@kind);
+
+trace("///x..item.(@kind == \"fruit\").length();");
+trace(x..item.(@kind == "fruit").length());
+
+trace("///x..item.(@kind == \"fruit\");");
+trace(x..item.(@kind == "fruit"));
+
+trace("///x..item.(@id > 1).length();");
+trace(x..item.(@id > 1).length());
+
+trace("///x.item.(text() == \"carrot\").@id;");
+trace(x.item.(text() == "carrot").@id);
+
+trace("///x.item.(@id == \"2\").@kind = \"root\";");
+x.item.(@id == "2").@kind = "root";
+
+trace("///x.item[1].@kind;");
+trace(x.item[1].@kind);
+
+trace("///x.toXMLString();");
+trace(x.toXMLString());
+
+trace("///var y = new XML(\"<a> <b>fish &amp; chips</b> <!-- comment --> </a>\");");
+var y = new XML("<a> <b>fish &amp; chips</b> <!-- comment --> </a>");
+
+trace("///y.b;");
+trace(y.b);
+
+trace("///y.children().length();");
+trace(y.children().length());
+
+trace("///y.toXMLString();");
+trace(y.toXMLString());
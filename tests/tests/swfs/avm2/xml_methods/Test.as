@@ -0,0 +1,58 @@
+package {
+	public class Test {
+	}
+}
+
+trace("///var x = <root><b/></root>;");
+var x = <root><b/></root>;
+
+trace("///x.appendChild(<c/>);");
+x.appendChild(<c/>);
+
+trace("///x.prependChild(<a/>);");
+x.prependChild(<a/>);
+
+trace("///x.toXMLString();");
+trace(x.toXMLString());
+
+trace("///x.insertChildAfter(x.b[0], <b2/>);");
+x.insertChildAfter(x.b[0], <b2/>);
+
+trace("///x.insertChildBefore(x.a[0], <first/>);");
+x.insertChildBefore(x.a[0], <first/>);
+
+trace("///x.insertChildAfter(null, <zero/>);");
+x.insertChildAfter(null, <zero/>);
+
+trace("///x.insertChildBefore(null, <last/>);");
+x.insertChildBefore(null, <last/>);
+
+trace("///x.children().length();");
+trace(x.children().length());
+
+trace("///x.b2[0].childIndex();");
+trace(x.b2[0].childIndex());
+
+trace("///x.insertChildAfter(<other/>, <never/>);");
+trace(x.insertChildAfter(<other/>, <never/>));
+
+trace("///x.a[0].setName(\"alpha\");");
+x.a[0].setName("alpha");
+
+trace("///x.alpha.length(), x.a.length();");
+trace(x.alpha.length(), x.a.length());
+
+trace("///x.addNamespace(new Namespace(\"ex\", \"http://example.com/ns\"));");
+x.addNamespace(new Namespace("ex", "http://example.com/ns"));
+
+trace("///var decls = x.namespaceDeclarations();");
+var decls = x.namespaceDeclarations();
+
+trace("///decls.length, decls[0].prefix, decls[0].uri;");
+trace(decls.length, decls[0].prefix, decls[0].uri);
+
+trace("///x.namespace(\"ex\").uri;");
+trace(x.namespace("ex").uri);
+
+trace("///x.toXMLString();");
+trace(x.toXMLString());
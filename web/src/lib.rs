@@ -9,7 +9,9 @@ use generational_arena::{Arena, Index};
 use js_sys::{Array, Function, Object, Promise, Uint8Array};
 use ruffle_core::backend::{
     audio::{AudioBackend, NullAudioBackend},
+    font::NullFontBackend,
     render::RenderBackend,
+    socket::NullSocketBackend,
     storage::{MemoryStorageBackend, StorageBackend},
     video::SoftwareVideoBackend,
 };
@@ -19,6 +21,7 @@ use ruffle_core::events::{KeyCode, MouseButton, MouseWheelDelta};
 use ruffle_core::external::{
     ExternalInterfaceMethod, ExternalInterfaceProvider, Value as ExternalValue, Value,
 };
+use ruffle_core::local_connection::LocalConnections;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{Color, Player, PlayerEvent};
 use ruffle_web_common::JsResult;
@@ -43,6 +46,10 @@ thread_local! {
     static INSTANCES: RefCell<Arena<RefCell<RuffleInstance>>> = RefCell::new(Arena::new());
 
     static CURRENT_CONTEXT: RefCell<Option<*mut UpdateContext<'static, 'static, 'static>>> = RefCell::new(None);
+
+    /// The `LocalConnection` names of every instance, so that the movies on a page
+    /// can talk to each other.
+    static LOCAL_CONNECTIONS: LocalConnections = LocalConnections::new();
 }
 
 type AnimationHandler = Closure<dyn FnMut(f64)>;
@@ -498,8 +505,12 @@ impl Ruffle {
         let video = Box::new(SoftwareVideoBackend::new());
         let log = Box::new(log_adapter::WebLogBackend::new(trace_observer.clone()));
         let ui = Box::new(ui::WebUiBackend::new(js_player.clone(), &canvas));
+        let fonts = Box::new(NullFontBackend::new());
+        let sockets = Box::new(NullSocketBackend::new());
 
-        let core = ruffle_core::Player::new(renderer, audio, navigator, storage, video, log, ui)?;
+        let core = ruffle_core::Player::new(
+            renderer, audio, navigator, storage, video, log, ui, fonts, sockets,
+        )?;
         if let Ok(mut core) = core.try_lock() {
             // Set config parameters.
             if let Some(color) = config.background_color.and_then(parse_html_color) {
@@ -512,6 +523,7 @@ impl Ruffle {
             core.set_stage_align(config.salign.as_deref().unwrap_or(""));
             core.set_quality(config.quality.as_deref().unwrap_or("high"));
             core.set_scale_mode(config.scale.as_deref().unwrap_or("showAll"));
+            core.set_local_connections(LOCAL_CONNECTIONS.with(Clone::clone));
 
             // Create the external interface.
             if allow_script_access {
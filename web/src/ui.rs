@@ -75,6 +75,11 @@ impl UiBackend for WebUiBackend {
         log::warn!("set clipboard not implemented");
     }
 
+    fn clipboard_content(&mut self) -> String {
+        log::warn!("get clipboard not implemented");
+        String::new()
+    }
+
     fn set_fullscreen(&mut self, is_full: bool) -> Result<(), Error> {
         match self.js_player.set_fullscreen(is_full) {
             Ok(_) => Ok(()),